@@ -23,6 +23,8 @@ use lambdaworks_math::{
     unsigned_integer::element::UnsignedInteger, cyclic_group::IsGroup,
 };
 
+use field_codec::lambdaworks::to_text;
+
 type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
 type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
 
@@ -57,15 +59,6 @@ fn challenge_polynomial() -> Polynomial<FrElement> {
     ])
 }
 
-fn decode(num: &FrElement) -> String {
-    let binding = num.to_string();
-    let eval_sum_str = binding.strip_prefix("0x").unwrap().to_string();
-    (0..eval_sum_str.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&eval_sum_str[i..i + 2], 16).unwrap_or_default() as char)
-            .collect()
-}
-
 fn print_g1(point: &G1Point) {
     println!("x: {}", point.to_affine().x().to_string());
     println!("y: {}", point.to_affine().y().to_string());
@@ -80,7 +73,7 @@ fn main() {
 
     let p = challenge_polynomial();
 
-    let coeff_str = p.coefficients().into_iter().map(|x| decode(x)).collect::<String>();
+    let coeff_str = p.coefficients().into_iter().map(to_text).collect::<String>();
     println!("{}", coeff_str);
 
     let g = BLS12381Curve::generator();
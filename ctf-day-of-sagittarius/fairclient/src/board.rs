@@ -1,11 +1,11 @@
-use std::{collections::HashMap, io::{self, Write}};
+use std::collections::HashMap;
 
 use rand::{thread_rng, Rng};
-use sagittarius_game::{types::{Position, HitType}, NUM_SHIPS, state::{Ship, GameCheck, ShipDirection, GameState}, BOARD_SIZE, SHIP_SPANS};
+use sagittarius_game::{NUM_SHIPS, state::{Ship, GameCheck, ShipDirection, GameState}, BOARD_SIZE, SHIP_SPANS};
 
-use crate::{interface::update_player_game_board, LOGGER};
+use crate::{interface::update_player_game_board, utils::select_option};
 
-pub type HitMap = HashMap<Position, HitType>;
+pub use sagittarius_render::HitMap;
 
 fn create_random_ships() -> [Ship; NUM_SHIPS] {
     // randomly place 5 ships on the board
@@ -49,24 +49,17 @@ fn create_random_ships() -> [Ship; NUM_SHIPS] {
 
 pub fn create_random_state() -> GameState {
     let ships = create_random_ships();
-    GameState {
-        ships,
-        salt: rand::thread_rng().gen(),
-    }
+    GameState::new(ships, rand::thread_rng().gen())
 }
 
 pub fn choose_board() -> GameState {
     let mut state = create_random_state();
+    let options = ["Keep this board".to_string(), "Generate another one".to_string()];
     loop {
         update_player_game_board(&state);
 
-        LOGGER.log("Are you satisfied with this board? (Y/n)");
-        let mut input = String::new();
-        print!(">: ");
-        io::stdout().flush().unwrap();
-        io::stdin().read_line(&mut input).expect("Failed to read input");
-        if input.trim() != "n" {
-            break;           
+        if select_option("Are you satisfied with this board?", &options) == 0 {
+            break;
         }
         state = create_random_state();
     };
@@ -0,0 +1,329 @@
+use std::io::{Error, ErrorKind};
+
+use snow::{HandshakeState, TransportState};
+use tokio::net::TcpStream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, Result};
+
+use crate::identity::{verify_signature, Identity};
+use crate::transcript::{SignedTranscript, Transcript};
+
+pub const NOISE_PARAMS: &str = "Noise_XX_25519_ChaChaPoly_SHA256";
+
+const NOISE_MAX_MESSAGE: usize = 65535;
+const NOISE_TAG_LEN: usize = 16;
+/// Largest message accepted from the peer, well above the size of a receipt
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+// ed25519 identity key followed by its signature over the Noise static key
+const IDENTITY_PAYLOAD_LEN: usize = 32 + 64;
+
+fn noise_error(e: snow::Error) -> Error {
+    Error::new(ErrorKind::InvalidData, e)
+}
+
+struct Secure {
+    transport: TransportState,
+    initiator: bool,
+    peer: [u8; 32],
+    transcript: Transcript,
+}
+
+/// Connection to the other player, either plaintext TCP (the original wire format)
+/// or a Noise XX channel authenticated with both players' identity keys.
+pub struct Channel {
+    pub stream: TcpStream,
+    secure: Option<Secure>,
+}
+
+impl Channel {
+    pub fn plain(stream: TcpStream) -> Self {
+        Channel { stream, secure: None }
+    }
+
+    pub async fn initiate(stream: TcpStream, identity: &Identity) -> Result<Self> {
+        Self::handshake(stream, identity, true).await
+    }
+
+    pub async fn respond(stream: TcpStream, identity: &Identity) -> Result<Self> {
+        Self::handshake(stream, identity, false).await
+    }
+
+    async fn handshake(mut stream: TcpStream, identity: &Identity, initiator: bool) -> Result<Self> {
+        let builder = snow::Builder::new(NOISE_PARAMS.parse().unwrap())
+            .local_private_key(&identity.noise_private);
+        let mut noise = if initiator {
+            builder.build_initiator()
+        } else {
+            builder.build_responder()
+        }.map_err(noise_error)?;
+
+        let mut payload = identity.public_key().to_vec();
+        payload.extend_from_slice(&identity.sign(&identity.noise_public));
+
+        // -> e
+        // <- e, ee, s, es
+        // -> s, se
+        let peer_payload = if initiator {
+            write_handshake(&mut stream, &mut noise, &[]).await?;
+            let peer_payload = read_handshake(&mut stream, &mut noise).await?;
+            write_handshake(&mut stream, &mut noise, &payload).await?;
+            peer_payload
+        } else {
+            read_handshake(&mut stream, &mut noise).await?;
+            write_handshake(&mut stream, &mut noise, &payload).await?;
+            read_handshake(&mut stream, &mut noise).await?
+        };
+
+        if peer_payload.len() != IDENTITY_PAYLOAD_LEN {
+            return Err(Error::new(ErrorKind::InvalidData, "Malformed identity payload"));
+        }
+        let mut peer = [0u8; 32];
+        peer.copy_from_slice(&peer_payload[..32]);
+        let peer_static = noise.get_remote_static().expect("XX always transmits the static key");
+        if !verify_signature(&peer, peer_static, &peer_payload[32..]) {
+            return Err(Error::new(ErrorKind::InvalidData, "Peer identity does not match its Noise key"));
+        }
+
+        let transcript = if initiator {
+            Transcript::new(identity.public_key(), peer)
+        } else {
+            Transcript::new(peer, identity.public_key())
+        };
+
+        Ok(Channel {
+            stream,
+            secure: Some(Secure {
+                transport: noise.into_transport_mode().map_err(noise_error)?,
+                initiator,
+                peer,
+                transcript,
+            }),
+        })
+    }
+
+    pub fn is_secure(&self) -> bool {
+        self.secure.is_some()
+    }
+
+    /// Identity key of the other player, only known on a secure channel.
+    pub fn peer_identity(&self) -> Option<[u8; 32]> {
+        self.secure.as_ref().map(|s| s.peer)
+    }
+
+    async fn send_frame(&mut self, message: &[u8]) -> Result<()> {
+        let secure = self.secure.as_mut().expect("Frames are only used on a secure channel");
+        let frame = seal_frame(&mut secure.transport, message)?;
+        self.stream.write_all(&frame).await
+    }
+
+    async fn recv_frame(&mut self) -> Result<Vec<u8>> {
+        let secure = self.secure.as_mut().expect("Frames are only used on a secure channel");
+        open_frame(&mut self.stream, &mut secure.transport).await
+    }
+
+    /// Sends one message as a single frame. On a plaintext channel the bytes are written as is.
+    pub async fn write_raw(&mut self, message: &[u8]) -> Result<()> {
+        match self.secure.as_mut() {
+            Some(secure) => secure.transcript.record(secure.initiator, message),
+            None => return self.stream.write_all(message).await,
+        }
+        self.send_frame(message).await
+    }
+
+    /// Receives the next frame. On a plaintext channel this is whatever the socket has buffered.
+    pub async fn read_raw(&mut self) -> Result<Vec<u8>> {
+        if !self.is_secure() {
+            let mut bytes = vec![];
+            self.stream.read_buf(&mut bytes).await?;
+            return Ok(bytes);
+        }
+
+        let message = self.recv_frame().await?;
+        let secure = self.secure.as_mut().unwrap();
+        secure.transcript.record(!secure.initiator, &message);
+        Ok(message)
+    }
+
+    pub async fn write_u8(&mut self, n: u8) -> Result<()> {
+        if self.is_secure() {
+            self.write_raw(&[n]).await
+        } else {
+            self.stream.write_u8(n).await
+        }
+    }
+
+    pub async fn read_u8(&mut self) -> Result<u8> {
+        if !self.is_secure() {
+            return self.stream.read_u8().await;
+        }
+        match self.read_raw().await?.as_slice() {
+            [n] => Ok(*n),
+            _ => Err(Error::new(ErrorKind::InvalidData, "Expected a single byte")),
+        }
+    }
+
+    /// Both players sign the transcript digest and swap signatures.
+    /// Returns `None` on a plaintext channel, which has nothing to attribute moves to.
+    pub async fn sign_transcript(&mut self, identity: &Identity) -> Result<Option<SignedTranscript>> {
+        let (transcript, initiator) = match self.secure.as_ref() {
+            Some(secure) => (secure.transcript.clone(), secure.initiator),
+            None => return Ok(None),
+        };
+        let digest = transcript.digest();
+        let signature = identity.sign(&digest);
+
+        // The signatures themselves are not part of the transcript
+        self.send_frame(&signature).await?;
+        let peer_signature = self.recv_frame().await?;
+
+        let peer = self.peer_identity().unwrap();
+        if !verify_signature(&peer, &digest, &peer_signature) {
+            return Err(Error::new(ErrorKind::InvalidData, "Peer signed a different transcript"));
+        }
+
+        let (initiator_signature, responder_signature) = if initiator {
+            (signature.to_vec(), peer_signature)
+        } else {
+            (peer_signature, signature.to_vec())
+        };
+        Ok(Some(SignedTranscript {
+            transcript,
+            initiator_signature: initiator_signature.into(),
+            responder_signature: responder_signature.into(),
+        }))
+    }
+}
+
+/// Encrypts a message as a run of chunks, each prefixed with its ciphertext length.
+/// The message length leads the plaintext of the first chunk, so it is authenticated along with the message:
+/// frames can't be merged, split or made up by rewriting lengths on the way.
+fn seal_frame(transport: &mut TransportState, message: &[u8]) -> Result<Vec<u8>> {
+    let mut plaintext = (message.len() as u32).to_be_bytes().to_vec();
+    plaintext.extend_from_slice(message);
+
+    let mut frame = vec![];
+    let mut buf = vec![0u8; NOISE_MAX_MESSAGE];
+    for chunk in plaintext.chunks(NOISE_MAX_MESSAGE - NOISE_TAG_LEN) {
+        let len = transport.write_message(chunk, &mut buf).map_err(noise_error)?;
+        frame.extend_from_slice(&(len as u16).to_be_bytes());
+        frame.extend_from_slice(&buf[..len]);
+    }
+    Ok(frame)
+}
+
+async fn open_frame<R: AsyncRead + Unpin>(reader: &mut R, transport: &mut TransportState) -> Result<Vec<u8>> {
+    let mut buf = vec![0u8; NOISE_MAX_MESSAGE];
+    let n = open_chunk(reader, transport, &mut buf).await?;
+    if n < 4 {
+        return Err(Error::new(ErrorKind::InvalidData, "Frame without a length"));
+    }
+    let len = u32::from_be_bytes(buf[..4].try_into().unwrap()) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(Error::new(ErrorKind::InvalidData, "Frame too large"));
+    }
+
+    let mut message = Vec::with_capacity(len);
+    message.extend_from_slice(&buf[4..n]);
+    while message.len() < len {
+        let n = open_chunk(reader, transport, &mut buf).await?;
+        message.extend_from_slice(&buf[..n]);
+    }
+    if message.len() != len {
+        return Err(Error::new(ErrorKind::InvalidData, "Frame length mismatch"));
+    }
+    Ok(message)
+}
+
+/// Decrypts the next chunk into `buf`, returning its plaintext length.
+async fn open_chunk<R: AsyncRead + Unpin>(reader: &mut R, transport: &mut TransportState, buf: &mut [u8]) -> Result<usize> {
+    let mut ciphertext = vec![0u8; reader.read_u16().await? as usize];
+    reader.read_exact(&mut ciphertext).await?;
+    transport.read_message(&ciphertext, buf).map_err(noise_error)
+}
+
+async fn write_handshake(stream: &mut TcpStream, noise: &mut HandshakeState, payload: &[u8]) -> Result<()> {
+    let mut buf = vec![0u8; NOISE_MAX_MESSAGE];
+    let len = noise.write_message(payload, &mut buf).map_err(noise_error)?;
+    stream.write_u16(len as u16).await?;
+    stream.write_all(&buf[..len]).await
+}
+
+async fn read_handshake(stream: &mut TcpStream, noise: &mut HandshakeState) -> Result<Vec<u8>> {
+    let mut message = vec![0u8; stream.read_u16().await? as usize];
+    stream.read_exact(&mut message).await?;
+    let mut payload = vec![0u8; NOISE_MAX_MESSAGE];
+    let len = noise.read_message(&message, &mut payload).map_err(noise_error)?;
+    payload.truncate(len);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Both ends of a Noise XX session, handshaken in memory.
+    fn transports() -> (TransportState, TransportState) {
+        let builder = || snow::Builder::new(NOISE_PARAMS.parse().unwrap());
+        let initiator_key = builder().generate_keypair().unwrap();
+        let responder_key = builder().generate_keypair().unwrap();
+        let mut initiator = builder().local_private_key(&initiator_key.private).build_initiator().unwrap();
+        let mut responder = builder().local_private_key(&responder_key.private).build_responder().unwrap();
+
+        let (mut message, mut payload) = (vec![0u8; NOISE_MAX_MESSAGE], vec![0u8; NOISE_MAX_MESSAGE]);
+        for step in 0..3 {
+            let (writer, reader) = if step == 1 { (&mut responder, &mut initiator) } else { (&mut initiator, &mut responder) };
+            let len = writer.write_message(&[], &mut message).unwrap();
+            reader.read_message(&message[..len], &mut payload).unwrap();
+        }
+        (initiator.into_transport_mode().unwrap(), responder.into_transport_mode().unwrap())
+    }
+
+    async fn open_all(mut wire: &[u8], transport: &mut TransportState) -> Result<Vec<Vec<u8>>> {
+        let mut messages = vec![];
+        while !wire.is_empty() {
+            messages.push(open_frame(&mut wire, transport).await?);
+        }
+        Ok(messages)
+    }
+
+    #[tokio::test]
+    async fn frames_arrive_as_sent() {
+        let (mut sender, mut receiver) = transports();
+        let long = vec![7u8; 2 * NOISE_MAX_MESSAGE];
+        let mut wire = seal_frame(&mut sender, b"fire").unwrap();
+        wire.extend(seal_frame(&mut sender, b"").unwrap());
+        wire.extend(seal_frame(&mut sender, &long).unwrap());
+
+        let messages = open_all(&wire, &mut receiver).await.unwrap();
+        assert_eq!(messages, vec![b"fire".to_vec(), vec![], long]);
+    }
+
+    #[tokio::test]
+    async fn merged_frames_are_rejected() {
+        let (mut sender, mut receiver) = transports();
+        let first = seal_frame(&mut sender, b"fire").unwrap();
+        let second = seal_frame(&mut sender, b"at b2").unwrap();
+
+        // One chunk length covering both ciphertexts, with the second prefix dropped
+        let mut wire = ((first.len() - 2 + second.len() - 2) as u16).to_be_bytes().to_vec();
+        wire.extend_from_slice(&first[2..]);
+        wire.extend_from_slice(&second[2..]);
+        assert!(open_all(&wire, &mut receiver).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn shortened_chunk_is_rejected() {
+        let (mut sender, mut receiver) = transports();
+        let mut wire = seal_frame(&mut sender, b"fire").unwrap();
+        wire[1] -= 1;
+        assert!(open_all(&wire, &mut receiver).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn injected_empty_frame_is_rejected() {
+        let (mut sender, mut receiver) = transports();
+        // A zero length where the plaintext prefix used to be read as an empty message
+        let mut wire = vec![0, 0];
+        wire.extend(seal_frame(&mut sender, b"fire").unwrap());
+        assert!(open_all(&wire, &mut receiver).await.is_err());
+    }
+}
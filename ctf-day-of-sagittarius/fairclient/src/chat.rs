@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Longest chat message, in characters
+pub const MAX_CHAT_LEN: usize = 120;
+/// Chat messages a player may send before taking their action each turn
+pub const MAX_CHAT_PER_TURN: usize = 3;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Emote {
+    Hello,
+    GoodLuck,
+    NiceShot,
+    Oops,
+    GoodGame,
+}
+
+impl Emote {
+    pub const ALL: [Emote; 5] = [Emote::Hello, Emote::GoodLuck, Emote::NiceShot, Emote::Oops, Emote::GoodGame];
+}
+
+impl Display for Emote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Emote::Hello => write!(f, "o/ Hello!"),
+            Emote::GoodLuck => write!(f, "Good luck!"),
+            Emote::NiceShot => write!(f, "Nice shot!"),
+            Emote::Oops => write!(f, "Oops..."),
+            Emote::GoodGame => write!(f, "GG"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ChatMessage {
+    Text(String),
+    Emote(Emote),
+}
+
+impl ChatMessage {
+    /// Builds a text message from user input, dropping control characters and trimming it to `MAX_CHAT_LEN`.
+    /// Returns `None` if nothing is left to send.
+    pub fn text(input: &str) -> Option<Self> {
+        let text = input.trim().chars().filter(|c| !c.is_control()).take(MAX_CHAT_LEN).collect::<String>();
+        (!text.is_empty()).then_some(ChatMessage::Text(text))
+    }
+
+    /// Whether a received message could have been produced by `ChatMessage::text`.
+    /// Control characters would let the peer draw over our terminal.
+    pub fn check(&self) -> bool {
+        match self {
+            ChatMessage::Text(text) => {
+                !text.is_empty() && text.chars().count() <= MAX_CHAT_LEN && !text.chars().any(char::is_control)
+            },
+            ChatMessage::Emote(_) => true,
+        }
+    }
+}
+
+impl Display for ChatMessage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChatMessage::Text(text) => write!(f, "{}", text),
+            ChatMessage::Emote(emote) => write!(f, "*{}*", emote),
+        }
+    }
+}
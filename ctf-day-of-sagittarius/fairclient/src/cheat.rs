@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use sagittarius_game::abilities::ClusterAreaError;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Violation {
+    /// The enemy asked for a scout proof with no scout charges left on our board
+    ScoutsExhausted,
+    /// The enemy fired a cluster charge with no cluster charges left on our board
+    ClusterBombsExhausted,
+    /// The enemy fired a cluster charge at a rectangle the rules don't allow
+    InvalidClusterArea(ClusterAreaError),
+    /// The enemy fired a salvo with more shots than surviving ships, or with repeated or off-board shots
+    InvalidSalvo { fired: usize, allowed: usize },
+    /// The enemy sent a malformed or oversized chat message, or more than the per-turn limit
+    ChatAbuse,
+    /// The enemy sent an action byte we don't know about
+    UnknownAction(u8),
+    /// The enemy sent a receipt that doesn't verify against the expected guest
+    InvalidProof(&'static str),
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::ScoutsExhausted => write!(f, "used scouts with no charges left"),
+            Violation::ClusterBombsExhausted => write!(f, "fired a cluster charge with no charges left"),
+            Violation::InvalidClusterArea(e) => write!(f, "fired a cluster charge at an invalid area: {}", e),
+            Violation::InvalidSalvo { fired, allowed } => write!(f, "fired an invalid salvo of {} shots ({} allowed)", fired, allowed),
+            Violation::ChatAbuse => write!(f, "abused the chat"),
+            Violation::UnknownAction(action) => write!(f, "sent unknown action {}", action),
+            Violation::InvalidProof(kind) => write!(f, "sent an invalid {} proof", kind),
+        }
+    }
+}
+
+/// What the enemy did wrong and when, produced instead of silently disconnecting.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheatReport {
+    pub violation: Violation,
+    /// Number of turns played before the violation
+    pub turn: u32,
+    /// Fingerprint of the enemy identity, when playing over a secure channel
+    pub peer: Option<String>,
+}
+
+impl Display for CheatReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Enemy {} on turn {}", self.violation, self.turn)?;
+        if let Some(peer) = &self.peer {
+            write!(f, " (peer {})", peer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::{fs, io::{Error, ErrorKind, Result, Write}, path::Path};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::channel::NOISE_PARAMS;
+
+pub const IDENTITY_PATH: &str = "identity.key";
+
+/// Long-term identity of a player: an ed25519 key that signs transcripts
+/// and a static X25519 key used in the Noise handshake.
+pub struct Identity {
+    pub signing_key: SigningKey,
+    pub noise_private: [u8; 32],
+    pub noise_public: [u8; 32],
+}
+
+impl Identity {
+    /// Loads the identity from `path`, generating and saving a new one on first use.
+    pub fn load_or_create(path: &Path) -> Result<Self> {
+        if path.exists() {
+            let bytes = fs::read(path)?;
+            if bytes.len() != 96 {
+                return Err(Error::new(ErrorKind::InvalidData, "Corrupted identity file"));
+            }
+            let mut seed = [0u8; 32];
+            let mut noise_private = [0u8; 32];
+            let mut noise_public = [0u8; 32];
+            seed.copy_from_slice(&bytes[..32]);
+            noise_private.copy_from_slice(&bytes[32..64]);
+            noise_public.copy_from_slice(&bytes[64..]);
+            return Ok(Identity {
+                signing_key: SigningKey::from_bytes(&seed),
+                noise_private,
+                noise_public,
+            });
+        }
+
+        let seed: [u8; 32] = rand::thread_rng().gen();
+        let keypair = snow::Builder::new(NOISE_PARAMS.parse().unwrap())
+            .generate_keypair()
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+
+        let mut noise_private = [0u8; 32];
+        let mut noise_public = [0u8; 32];
+        noise_private.copy_from_slice(&keypair.private);
+        noise_public.copy_from_slice(&keypair.public);
+
+        let mut bytes = seed.to_vec();
+        bytes.extend_from_slice(&noise_private);
+        bytes.extend_from_slice(&noise_public);
+        write_private(path, &bytes)?;
+
+        Ok(Identity {
+            signing_key: SigningKey::from_bytes(&seed),
+            noise_private,
+            noise_public,
+        })
+    }
+
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        self.signing_key.sign(message).to_bytes()
+    }
+
+    pub fn fingerprint(&self) -> String {
+        fingerprint(&self.public_key())
+    }
+}
+
+/// Creates the file readable and writable by its owner only, it holds the private keys.
+fn write_private(path: &Path, bytes: &[u8]) -> Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(bytes)
+}
+
+pub fn verify_signature(public_key: &[u8; 32], message: &[u8], signature: &[u8]) -> bool {
+    let key = match VerifyingKey::from_bytes(public_key) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::from_slice(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    key.verify(message, &signature).is_ok()
+}
+
+/// Short human-comparable form of an identity key, e.g. `3f2a:91c0:...`
+pub fn fingerprint(public_key: &[u8; 32]) -> String {
+    let hash = Sha256::digest(public_key);
+    hash[..16]
+        .chunks(2)
+        .map(|c| format!("{:02x}{:02x}", c[0], c[1]))
+        .collect::<Vec<_>>()
+        .join(":")
+}
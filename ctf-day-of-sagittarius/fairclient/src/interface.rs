@@ -1,206 +1,251 @@
-use sagittarius_game::{state::{GameState, ShipDirection}, BOARD_SIZE, SHIP_SPANS, types::HitType};
-use crossterm::{execute, terminal, terminal::Clear, terminal::ClearType};
-use crossterm::style::{Colored, Stylize};
-use std::{io::{stdout}};
+use sagittarius_game::{state::GameState, types::Position, BOARD_SIZE};
+use sagittarius_game::advisor::HeatMap;
+use sagittarius_render::{grid::empty_grid, player_grid, enemy_grid, Cell, Grid};
+use crossterm::{execute, terminal::{self, EnterAlternateScreen, LeaveAlternateScreen}, cursor::Show};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use std::io::{stdout, Stdout};
 use lazy_static::lazy_static;
 use std::sync::Mutex;
-use rand::Rng;
-use std::time::Duration;
-use std::thread;
 
 use crate::{board::HitMap, LOGGER};
 
-lazy_static! {
-    static ref PLAYER_BOARD: Mutex<Vec<String>> = Mutex::new(vec![String::default(); (BOARD_SIZE + 1) * 2 + 3]);
-    static ref ENEMY_BOARD: Mutex<Vec<String>> = Mutex::new(vec![String::default(); (BOARD_SIZE + 1) * 2 + 3]);
+// Row with column letters + board rows + top and bottom borders
+const BOARD_HEIGHT: u16 = BOARD_SIZE as u16 + 3;
+// Row number column + 3 characters per cell + left and right borders
+const BOARD_WIDTH: u16 = BOARD_SIZE as u16 * 3 + 5;
+const MENU_WIDTH: u16 = 44;
+
+#[derive(Default)]
+struct Hud {
+    player: Option<Grid>,
+    enemy: Option<Grid>,
+    /// Enemy board cell currently under the target cursor
+    cursor: Option<Position>,
+    /// Advisor estimate shown over the unexplored enemy cells
+    heat: Option<HeatMap>,
+    menu_title: String,
+    menu: Vec<String>,
+    selected: usize,
+    /// Label and current contents of a text prompt
+    input: Option<(String, String)>,
 }
 
-fn empty_board() -> [[String; BOARD_SIZE]; BOARD_SIZE] {
-    let mut board: [[String; BOARD_SIZE]; BOARD_SIZE] = Default::default();
-
-    for i in 0..BOARD_SIZE {
-        for j in 0..BOARD_SIZE {
-            board[i][j] = "   ".to_string();
-        }
-    }
-
-    board
+lazy_static! {
+    static ref TERMINAL: Mutex<Option<Terminal<CrosstermBackend<Stdout>>>> = Mutex::new(None);
+    static ref HUD: Mutex<Hud> = Mutex::new(Hud::default());
 }
 
-fn render_player_cells(state: &GameState) -> [[String; BOARD_SIZE]; BOARD_SIZE] {
-    let mut board = empty_board();
+pub fn init_terminal() {
+    terminal::enable_raw_mode().unwrap();
+    execute!(stdout(), EnterAlternateScreen).unwrap();
+    let terminal = Terminal::new(CrosstermBackend::new(stdout())).unwrap();
+    *TERMINAL.lock().unwrap() = Some(terminal);
 
-    for (i, ship) in state.ships.iter().enumerate() {
-        let ship_symbol = format!(" {} ", (b'A' + i as u8) as char);
-        for j in 0..SHIP_SPANS[i] {
-            let (x, y) = match ship.dir {
-                ShipDirection::Horizontal => (ship.pos.x as usize + j, ship.pos.y as usize),
-                ShipDirection::Vertical => (ship.pos.x as usize, ship.pos.y as usize + j),
-            };
+    // Leave the alternate screen before the panic message is printed
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore_terminal();
+        hook(info);
+    }));
 
-            if x < BOARD_SIZE && y < BOARD_SIZE {
-                if ship.hit_mask & (1 << j) != 0 {
-                    board[y][x] = " X ".to_string();
-                } else {
-                    board[y][x] = ship_symbol.clone();
-                }
-            }
-        }
-    }
-
-    board
+    update_hud();
 }
 
-fn render_enemy_cells(shots: &HitMap) -> [[String; BOARD_SIZE]; BOARD_SIZE] {
-    let mut board = empty_board();
-
-    for (pos, hit) in shots {
-        if pos.x < BOARD_SIZE as u32 && pos.y < BOARD_SIZE as u32 {
-            board[pos.y as usize][pos.x as usize] = match hit {
-                HitType::Miss => " M ".to_string(),
-                HitType::Hit => " H ".to_string(),
-                HitType::Revealed => " R ".to_string(),
-                HitType::Destroyed(_) => " S ".to_string(),
-            };
-        }
+pub fn restore_terminal() {
+    // The lock is still held if we panicked while drawing
+    if let Ok(mut terminal) = TERMINAL.try_lock() {
+        terminal.take();
     }
-
-    board
+    terminal::disable_raw_mode().ok();
+    execute!(stdout(), LeaveAlternateScreen, Show).ok();
 }
 
-fn create_board_representation(title: String, cells: [[String; BOARD_SIZE]; BOARD_SIZE]) -> Vec<String> {
-    let mut result = Vec::new();
+pub fn update_player_game_board(player_state: &GameState) {
+    HUD.lock().unwrap().player = Some(player_grid(player_state));
+    update_hud();
+}
 
-    // Calculate total width of the board
-    let total_width = BOARD_SIZE * 4 + 4;  // 2 characters per cell + 1 for the vertical divider
+pub fn update_enemy_game_board(enemy_shots: &HitMap) {
+    HUD.lock().unwrap().enemy = Some(enemy_grid(enemy_shots));
+    update_hud();
+}
 
-    // Print title
-    let padded_title = format!("{0: ^1$}", title, total_width - 2);  // Center the title with padding
-    let title_frame = format!("┏{}┓", "━".repeat(total_width - 2));
-    let title_frame_bot = format!("┣━━┳{}━━━┫", "━━━┳".repeat(BOARD_SIZE - 1));
-    let title_line = format!("┃{}┃", padded_title);
-    result.push(title_frame);
-    result.push(title_line);
-    result.push(title_frame_bot);
+pub fn set_heat_map(heat: Option<HeatMap>) {
+    HUD.lock().unwrap().heat = heat;
+    update_hud();
+}
 
-    // Print column letters
-    let letters_line = (b'a'..=b'a' + (BOARD_SIZE - 1) as u8).map(char::from).map(|c| format!(" {} ┃", c)).collect::<String>();
-    result.push(format!("┃  ┃{}", letters_line));
+pub fn set_target_cursor(cursor: Option<Position>) {
+    HUD.lock().unwrap().cursor = cursor;
+    update_hud();
+}
 
-    // Print board
-    for (i, row) in cells.iter().enumerate() {
-        let row_line = format!("┃ {}┃{}┃", i + 1, row.join("│"));
-        result.push("┣━━╋".to_string() + &"───┼".repeat(BOARD_SIZE - 1) + "───┨");
-        result.push(row_line);
+pub fn set_menu(title: &str, options: &[String], selected: usize) {
+    {
+        let mut hud = HUD.lock().unwrap();
+        hud.menu_title = title.to_string();
+        hud.menu = options.to_vec();
+        hud.selected = selected;
     }
+    update_hud();
+}
 
-    // Add bottom border
-    result.push("┗━━┷".to_string() + &"━━━┷".repeat(BOARD_SIZE - 1) + "━━━┛");
+pub fn set_input(input: Option<(&str, &str)>) {
+    HUD.lock().unwrap().input = input.map(|(label, text)| (label.to_string(), text.to_string()));
+    update_hud();
+}
 
-    result
+pub fn update_hud() {
+    let mut terminal = TERMINAL.lock().unwrap();
+    if let Some(terminal) = terminal.as_mut() {
+        let hud = HUD.lock().unwrap();
+        terminal.draw(|f| draw_hud(f, &hud)).unwrap();
+    }
 }
 
-pub fn draw_night_sky(scroll: Option<u16>, sleep: Option<u32>) {
-    let symbols = vec!['⭐', '🌟', '✨', '*'];
-    let mut rng = rand::thread_rng();
+fn draw_hud<B: Backend>(f: &mut Frame<B>, hud: &Hud) {
+    let area = f.size();
+    let banner_height = SAGITTARIUS.lines().count() as u16;
+    let banner_width = SAGITTARIUS.lines().map(|l| l.chars().count()).max().unwrap_or(0) as u16;
+
+    // Only draw the banner when there is room left for the journal
+    let banner = area.height >= banner_height + BOARD_HEIGHT + 8 && area.width >= banner_width;
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(if banner { banner_height } else { 1 }),
+            Constraint::Length(BOARD_HEIGHT),
+            Constraint::Min(3),
+        ])
+        .split(area);
+
+    let title = if banner { SAGITTARIUS } else { "THE DAY OF SAGITTARIUS IV" };
+    f.render_widget(Paragraph::new(title).alignment(Alignment::Center), rows[0]);
+
+    let boards = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(BOARD_WIDTH),
+            Constraint::Length(BOARD_WIDTH),
+            Constraint::Min(0),
+        ])
+        .split(rows[1]);
+    draw_board(f, boards[0], "Player", hud.player.as_ref(), None, None);
+    draw_board(f, boards[1], "Enemy", hud.enemy.as_ref(), hud.cursor.as_ref(), hud.heat.as_ref());
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(20), Constraint::Length(MENU_WIDTH)])
+        .split(rows[2]);
+    draw_journal(f, bottom[0]);
+    draw_menu(f, bottom[1], hud);
+}
 
-    let (width, height) = terminal::size().unwrap();
+fn cell_style(cell: &Cell) -> Style {
+    let style = Style::default().add_modifier(Modifier::BOLD);
+    match cell {
+        Cell::Empty => Style::default(),
+        Cell::Ship(_) => style.fg(Color::Cyan),
+        Cell::Damaged | Cell::Hit => style.fg(Color::Red),
+        Cell::Miss => style.fg(Color::DarkGray),
+        Cell::Revealed => style.fg(Color::Yellow),
+        Cell::Sunk => style.fg(Color::LightRed),
+    }
+}
 
-    for _ in 0..height * scroll.unwrap_or(1) {
-        for _ in 0..width {
-            let symbol = rng.gen_range(0..symbols.len());
-            let prob = rng.gen_range(0..100);
+/// Colour for a cell the advisor thinks holds a ship with probability `p`.
+fn heat_style(p: f64) -> Style {
+    let color = match p {
+        p if p < 0.1 => Color::DarkGray,
+        p if p < 0.3 => Color::Blue,
+        p if p < 0.5 => Color::Green,
+        p if p < 0.7 => Color::Yellow,
+        _ => Color::Magenta,
+    };
+    Style::default().fg(color)
+}
 
-            // To create a realistic night sky, we will only draw a star 10% of the time
-            if prob < 3 {
-                print!("{}", symbols[symbol]);
-            } else {
-                print!(" ");
+fn draw_board<B: Backend>(f: &mut Frame<B>, area: Rect, title: &str, grid: Option<&Grid>, cursor: Option<&Position>, heat: Option<&HeatMap>) {
+    let empty = empty_grid();
+    let grid = grid.unwrap_or(&empty);
+    let best = heat.and_then(|heat| heat.best());
+
+    let mut lines = vec![];
+    let letters = (b'a'..b'a' + BOARD_SIZE as u8).map(|c| format!(" {} ", c as char)).collect::<String>();
+    lines.push(Spans::from(Span::styled(format!("  {}", letters), Style::default().fg(Color::DarkGray))));
+
+    for (y, row) in grid.iter().enumerate() {
+        let mut spans = vec![Span::styled(format!("{:>2}", y + 1), Style::default().fg(Color::DarkGray))];
+        for (x, cell) in row.iter().enumerate() {
+            let pos = Position::new(x as u32, y as u32);
+            let mut style = cell_style(cell);
+            let mut symbol = if *cell == Cell::Empty { '·' } else { cell.symbol() };
+            // Unexplored cells show the chance of a ship in tenths
+            if let (Some(heat), Cell::Empty) = (heat, cell) {
+                let p = heat.probability(&pos);
+                style = heat_style(p);
+                symbol = char::from_digit((p * 10.0).min(9.0) as u32, 10).unwrap_or('·');
             }
+            if best.as_ref() == Some(&pos) {
+                style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            }
+            if cursor == Some(&pos) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::styled(format!(" {} ", symbol), style));
         }
-        println!();
-        if let Some(sleep) = sleep {
-            thread::sleep(Duration::from_millis(sleep as u64));
-        }
+        lines.push(Spans::from(spans));
     }
-}
 
-pub fn update_player_game_board(player_state: &GameState) {
-    {
-        let mut player_board = PLAYER_BOARD.lock().unwrap();
-        *player_board = create_board_representation("Player".to_string(), render_player_cells(player_state));
-    }
-    update_hud();
+    let block = Block::default().borders(Borders::ALL).title(title);
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
-pub fn update_enemy_game_board(enemy_shots: &HitMap) {
-    {
-        let mut enemy_board = ENEMY_BOARD.lock().unwrap();
-        *enemy_board = create_board_representation("Enemy".to_string(), render_enemy_cells(enemy_shots));
-    }
-    update_hud();
+fn draw_journal<B: Backend>(f: &mut Frame<B>, area: Rect) {
+    let visible = area.height.saturating_sub(2) as usize;
+    let messages = LOGGER.get_messages();
+    let skip = messages.len().saturating_sub(visible);
+    let lines = messages.into_iter().skip(skip).map(Spans::from).collect::<Vec<_>>();
+
+    let block = Block::default().borders(Borders::ALL).title("Journal");
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
-pub fn update_hud() {
-    execute!(stdout(), Clear(ClearType::All)).unwrap();
-
-    // draw_night_sky(None, None);
-    println!("{}", DAY);
-    println!("{}", OF);
-    println!("{}", SAGITTARIUS);
-
-    let player_board = PLAYER_BOARD.lock().unwrap();
-    let enemy_board = ENEMY_BOARD.lock().unwrap();
-
-    let mut log_box = vec![];
-    // Calculate total width of the board
-    let total_width = BOARD_SIZE * 10 + 4;  // 2 characters per cell + 1 for the vertical divider
-
-    // Print title
-    let padded_title = format!("{0: ^1$}", "Journal", total_width - 2);  // Center the title with padding
-    let title_frame = format!("┏{}┓", "━".repeat(total_width - 2));
-    let title_frame_bot = format!("┣{}┫", "━".repeat(total_width - 2));
-    let title_line = format!("┃{}┃", padded_title);
-    log_box.push(title_frame);
-    log_box.push(title_line);
-    log_box.push(title_frame_bot);
-
-    let mut entries = LOGGER.get_messages();
-    for _ in 0..(LOGGER.max_size - entries.len()) {
-        entries.push_front("".to_string());
-    }
-    for msg in entries {
-        log_box.push(format!("┃{0: <1$}┃", msg, total_width - 2));
-    }
+fn draw_menu<B: Backend>(f: &mut Frame<B>, area: Rect, hud: &Hud) {
+    let block = Block::default().borders(Borders::ALL).title(hud.menu_title.as_str());
 
-    log_box.push("┗".to_string() + &"━".repeat(total_width - 2) + "┛");
+    if let Some((label, text)) = &hud.input {
+        let lines = vec![
+            Spans::from(label.as_str()),
+            Spans::from(vec![Span::raw(">: "), Span::raw(text.as_str()), Span::styled(" ", Style::default().add_modifier(Modifier::REVERSED))]),
+        ];
+        f.render_widget(Paragraph::new(lines).block(block), area);
+        return;
+    }
 
-    for ((log_line, player_line), enemy_line) in log_box.iter().zip(player_board.iter()).zip(enemy_board.iter()) {
-        println!("{}  {}  {}", log_line, player_line, enemy_line);
+    let items = hud.menu.iter().map(|o| ListItem::new(o.as_str())).collect::<Vec<_>>();
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    let mut state = ListState::default();
+    if !hud.menu.is_empty() {
+        state.select(Some(hud.selected));
     }
+    f.render_stateful_widget(list, area, &mut state);
 }
 
-const DAY: &str = r"
-             ______   __  __     ______        _____     ______     __  __    
-            /\__  _\ /\ \_\ \   /\  ___\      /\  __-.  /\  __ \   /\ \_\ \   
-            \/_/\ \/ \ \  __ \  \ \  __\      \ \ \/\ \ \ \  __ \  \ \____ \  
-               \ \_\  \ \_\ \_\  \ \_____\     \ \____-  \ \_\ \_\  \/\_____\ 
-                \/_/   \/_/\/_/   \/_____/      \/____/   \/_/\/_/   \/_____/ 
-";
-
-const OF: &str = r"
-                                 ______     ______  
-                                /\  __ \   /\  ___\ 
-                                \ \ \/\ \  \ \  __\ 
-                                 \ \_____\  \ \_\   
-                                  \/_____/   \/_/   
-";
-
-const SAGITTARIUS: &str = r"
-    ███████╗ █████╗  ██████╗ ██╗████████╗████████╗ █████╗ ██████╗ ██╗██╗   ██╗███████╗    ██╗██╗   ██╗
-    ██╔════╝██╔══██╗██╔════╝ ██║╚══██╔══╝╚══██╔══╝██╔══██╗██╔══██╗██║██║   ██║██╔════╝    ██║██║   ██║
-    ███████╗███████║██║  ███╗██║   ██║      ██║   ███████║██████╔╝██║██║   ██║███████╗    ██║██║   ██║
-    ╚════██║██╔══██║██║   ██║██║   ██║      ██║   ██╔══██║██╔══██╗██║██║   ██║╚════██║    ██║╚██╗ ██╔╝
-    ███████║██║  ██║╚██████╔╝██║   ██║      ██║   ██║  ██║██║  ██║██║╚██████╔╝███████║    ██║ ╚████╔╝ 
-    ╚══════╝╚═╝  ╚═╝ ╚═════╝ ╚═╝   ╚═╝      ╚═╝   ╚═╝  ╚═╝╚═╝  ╚═╝╚═╝ ╚═════╝ ╚══════╝    ╚═╝  ╚═══╝  
-";
\ No newline at end of file
+const SAGITTARIUS: &str = "\
+███████╗ █████╗  ██████╗ ██╗████████╗████████╗ █████╗ ██████╗ ██╗██╗   ██╗███████╗    ██╗██╗   ██╗
+██╔════╝██╔══██╗██╔════╝ ██║╚══██╔══╝╚══██╔══╝██╔══██╗██╔══██╗██║██║   ██║██╔════╝    ██║██║   ██║
+███████╗███████║██║  ███╗██║   ██║      ██║   ███████║██████╔╝██║██║   ██║███████╗    ██║██║   ██║
+╚════██║██╔══██║██║   ██║██║   ██║      ██║   ██╔══██║██╔══██╗██║██║   ██║╚════██║    ██║╚██╗ ██╔╝
+███████║██║  ██║╚██████╔╝██║   ██║      ██║   ██║  ██║██║  ██║██║╚██████╔╝███████║    ██║ ╚████╔╝
+╚══════╝╚═╝  ╚═╝ ╚═════╝ ╚═╝   ╚═╝      ╚═╝   ╚═╝  ╚═╝╚═╝  ╚═╝╚═╝ ╚═════╝ ╚══════╝    ╚═╝  ╚═══╝  ";
@@ -0,0 +1,296 @@
+use std::{collections::HashMap, fs, io::{Error, ErrorKind, Result}, path::Path, sync::Mutex, time::{SystemTime, UNIX_EPOCH}};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use crate::identity::fingerprint;
+use crate::transcript::{from_hex, hex, SignedTranscript};
+
+pub const LADDER_PATH: &str = "ladder.json";
+
+const INITIAL_RATING: f64 = 1500.0;
+const K_FACTOR: f64 = 32.0;
+
+lazy_static! {
+    // The host plays several games at once, keep their updates from overwriting each other
+    static ref LADDER_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// One finished game. Players are hex encoded ed25519 identity keys.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MatchRecord {
+    /// Hex digest of the signed transcript, which is also its file name in `transcripts/`
+    pub transcript: String,
+    pub winner: String,
+    pub loser: String,
+    pub moves: u32,
+    /// Seconds since the Unix epoch
+    pub played_at: u64,
+}
+
+impl MatchRecord {
+    /// Takes the result and move count from the transcript itself, so the record can be checked against it later.
+    /// Returns `None` for games that were not played to the end.
+    pub fn from_transcript(signed: &SignedTranscript) -> Option<Self> {
+        let transcript = &signed.transcript;
+        let loser = signed.loser()?;
+        let winner = if loser == transcript.initiator { transcript.responder } else { transcript.initiator };
+        let played_at = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+
+        Some(MatchRecord {
+            transcript: hex(&transcript.digest()),
+            winner: hex(&winner),
+            loser: hex(&loser),
+            moves: signed.moves(),
+            played_at,
+        })
+    }
+
+    /// Checks the stored transcript: both signatures, its digest, who lost and how many moves it took.
+    pub fn verify(&self) -> std::result::Result<(), String> {
+        let signed = SignedTranscript::load(&self.transcript).map_err(|e| format!("can't load transcript: {}", e))?;
+        if hex(&signed.transcript.digest()) != self.transcript {
+            return Err("transcript digest does not match its name".to_string());
+        }
+        if !signed.verify() {
+            return Err("bad transcript signature".to_string());
+        }
+        match MatchRecord::from_transcript(&signed) {
+            Some(record) if record.winner != self.winner || record.loser != self.loser => {
+                Err("recorded result does not match the transcript".to_string())
+            },
+            Some(record) if record.moves != self.moves => Err(format!(
+                "recorded {} moves but the transcript has {}", self.moves, record.moves
+            )),
+            Some(_) => Ok(()),
+            None => Err("transcript has no result".to_string()),
+        }
+    }
+}
+
+pub struct Standing {
+    pub player: String,
+    pub rating: f64,
+    pub wins: u32,
+    pub losses: u32,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Ladder {
+    pub matches: Vec<MatchRecord>,
+}
+
+impl Ladder {
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Ladder::default());
+        }
+        let bytes = fs::read(path)?;
+        serde_json::from_slice(&bytes).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_vec_pretty(self).expect("Failed serialization"))
+    }
+
+    /// Adds a match unless it is already recorded. Both players record the same transcript,
+    /// so merged ladders don't count a game twice.
+    pub fn record(&mut self, record: MatchRecord) -> bool {
+        if self.matches.iter().any(|m| m.transcript == record.transcript) {
+            return false;
+        }
+        self.matches.push(record);
+        true
+    }
+
+    /// Matches whose transcript digest starts with `prefix`.
+    pub fn find(&self, prefix: &str) -> Vec<&MatchRecord> {
+        self.matches.iter().filter(|m| m.transcript.starts_with(prefix)).collect()
+    }
+
+    /// Elo ratings from replaying all matches in the order they were played, best first.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut matches = self.matches.iter().collect::<Vec<_>>();
+        matches.sort_by_key(|m| m.played_at);
+
+        let mut standings: HashMap<&str, Standing> = HashMap::new();
+        for m in matches {
+            for player in [&m.winner, &m.loser] {
+                standings.entry(player).or_insert_with(|| Standing {
+                    player: player.clone(),
+                    rating: INITIAL_RATING,
+                    wins: 0,
+                    losses: 0,
+                });
+            }
+            let (winner, loser) = (standings[m.winner.as_str()].rating, standings[m.loser.as_str()].rating);
+            let expected = 1.0 / (1.0 + 10f64.powf((loser - winner) / 400.0));
+            let delta = K_FACTOR * (1.0 - expected);
+
+            let winner = standings.get_mut(m.winner.as_str()).unwrap();
+            winner.rating += delta;
+            winner.wins += 1;
+            let loser = standings.get_mut(m.loser.as_str()).unwrap();
+            loser.rating -= delta;
+            loser.losses += 1;
+        }
+
+        let mut standings = standings.into_values().collect::<Vec<_>>();
+        standings.sort_by(|a, b| b.rating.total_cmp(&a.rating));
+        standings
+    }
+}
+
+/// Adds a finished game to the local ladder. Returns false for unfinished games.
+pub fn record_match(signed: &SignedTranscript) -> Result<bool> {
+    let record = match MatchRecord::from_transcript(signed) {
+        Some(record) => record,
+        None => return Ok(false),
+    };
+
+    let _guard = LADDER_LOCK.lock().unwrap();
+    let path = Path::new(LADDER_PATH);
+    let mut ladder = Ladder::load(path)?;
+    ladder.record(record);
+    ladder.save(path)?;
+    Ok(true)
+}
+
+fn player_name(player: &str) -> String {
+    match from_hex(player).and_then(|key| <[u8; 32]>::try_from(key).ok()) {
+        Some(key) => fingerprint(&key),
+        None => player.to_string(),
+    }
+}
+
+/// `fairclient ladder [verify <transcript>]`, printed to stdout without the game UI.
+/// Fails if any matching record doesn't check out, so scripts can rely on the exit code.
+pub fn run(args: &[String]) -> Result<()> {
+    let ladder = Ladder::load(Path::new(LADDER_PATH))?;
+
+    match args {
+        [] => {
+            println!("{:<4} {:<40} {:>6} {:>4} {:>4}", "#", "Player", "Elo", "W", "L");
+            for (i, standing) in ladder.standings().iter().enumerate() {
+                println!(
+                    "{:<4} {:<40} {:>6.0} {:>4} {:>4}",
+                    i + 1, player_name(&standing.player), standing.rating, standing.wins, standing.losses
+                );
+            }
+            println!("\n{} matches", ladder.matches.len());
+        },
+        [command, prefix] if command == "verify" => {
+            let matches = ladder.find(prefix);
+            if matches.is_empty() {
+                return Err(Error::new(ErrorKind::NotFound, format!("No match with transcript {}", prefix)));
+            }
+            let mut failed = 0;
+            for m in matches {
+                match m.verify() {
+                    Ok(()) => println!("{} OK: {} beat {} in {} moves", m.transcript, player_name(&m.winner), player_name(&m.loser), m.moves),
+                    Err(e) => {
+                        println!("{} FAILED: {}", m.transcript, e);
+                        failed += 1;
+                    },
+                }
+            }
+            if failed > 0 {
+                return Err(Error::new(ErrorKind::InvalidData, format!("{} match(es) failed verification", failed)));
+            }
+        },
+        _ => return Err(Error::new(ErrorKind::InvalidInput, "Usage: fairclient ladder [verify <transcript>]")),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_bytes::ByteBuf;
+
+    use super::*;
+    use crate::net_utils::ACK_MESSAGE;
+    use crate::session::LOSS_MESSAGE;
+    use crate::transcript::Transcript;
+
+    fn record(transcript: &str, winner: &str, loser: &str, played_at: u64) -> MatchRecord {
+        MatchRecord {
+            transcript: transcript.to_string(),
+            winner: winner.to_string(),
+            loser: loser.to_string(),
+            moves: 10,
+            played_at,
+        }
+    }
+
+    #[test]
+    fn even_players_move_half_the_k_factor() {
+        let mut ladder = Ladder::default();
+        ladder.record(record("00", "alice", "bob", 1));
+
+        let standings = ladder.standings();
+        assert_eq!(standings[0].player, "alice");
+        assert_eq!(standings[0].rating, INITIAL_RATING + K_FACTOR / 2.0);
+        assert_eq!(standings[1].rating, INITIAL_RATING - K_FACTOR / 2.0);
+    }
+
+    #[test]
+    fn upset_moves_more_than_expected_win() {
+        let mut ladder = Ladder::default();
+        ladder.record(record("00", "alice", "bob", 1));
+        ladder.record(record("01", "bob", "alice", 2));
+
+        // Bob was rated lower, so his win gains more than Alice's did
+        let standings = ladder.standings();
+        assert_eq!(standings[0].player, "bob");
+        assert!(standings[0].rating > INITIAL_RATING);
+        assert!((standings[0].rating + standings[1].rating - 2.0 * INITIAL_RATING).abs() < 1e-9);
+    }
+
+    #[test]
+    fn standings_are_best_first_and_replayed_in_order() {
+        let mut ladder = Ladder::default();
+        // Recorded out of order, the replay sorts by time
+        ladder.record(record("02", "carol", "bob", 3));
+        ladder.record(record("00", "alice", "bob", 1));
+        ladder.record(record("01", "alice", "carol", 2));
+
+        let standings = ladder.standings();
+        let order = standings.iter().map(|s| s.player.as_str()).collect::<Vec<_>>();
+        assert_eq!(order, ["alice", "carol", "bob"]);
+        assert_eq!((standings[0].wins, standings[0].losses), (2, 0));
+        assert_eq!((standings[1].wins, standings[1].losses), (1, 1));
+        assert_eq!((standings[2].wins, standings[2].losses), (0, 2));
+    }
+
+    #[test]
+    fn same_transcript_is_recorded_once() {
+        let mut ladder = Ladder::default();
+        assert!(ladder.record(record("00", "alice", "bob", 1)));
+        assert!(!ladder.record(record("00", "alice", "bob", 5)));
+        assert!(ladder.record(record("01", "alice", "bob", 1)));
+
+        assert_eq!(ladder.matches.len(), 2);
+        assert_eq!(ladder.find("0").len(), 2);
+        assert_eq!(ladder.find("01").len(), 1);
+    }
+
+    #[test]
+    fn moves_and_result_come_from_the_transcript() {
+        let messages = |messages: &[&[u8]]| messages.iter().map(|m| ByteBuf::from(m.to_vec())).collect();
+        let (ack, loss) = (ACK_MESSAGE.as_bytes(), LOSS_MESSAGE.as_bytes());
+        // The initiator fires, the responder fires back, then the initiator sinks the last ship
+        let mut transcript = Transcript::new([1; 32], [2; 32]);
+        transcript.initiator_messages = messages(&[&[1], b"shot", ack, b"proof", &[1], b"shot", ack]);
+        transcript.responder_messages = messages(&[b"proof", &[1], b"shot", ack, b"proof", loss]);
+        let signed = SignedTranscript {
+            transcript,
+            initiator_signature: ByteBuf::new(),
+            responder_signature: ByteBuf::new(),
+        };
+
+        let record = MatchRecord::from_transcript(&signed).unwrap();
+        assert_eq!(record.moves, 3);
+        assert_eq!(record.winner, hex(&[1; 32]));
+        assert_eq!(record.loser, hex(&[2; 32]));
+    }
+}
@@ -1,103 +1,152 @@
 mod board;
+mod channel;
+mod chat;
+mod cheat;
+mod identity;
 mod logger;
 mod net_utils;
 mod interface;
+mod ladder;
 mod session;
+mod transcript;
 mod utils;
 
-use std::io::Write;
+use std::{path::Path, sync::Arc};
 
 use lazy_static::lazy_static;
 use crate::logger::BufferLogger;
-use interface::draw_night_sky;
+use interface::{init_terminal, restore_terminal};
 use net_utils::{chunk_read, write_message};
 use sagittarius_game::BOARD_SIZE;
 use tokio::net::{TcpListener, TcpStream};
 use tokio::io::Result;
 
-use risc0_zkvm::{Receipt, serde};
 use sagittarius_game::state::GameState;
 use sagittarius_game::types::{Digest};
-use sagittarius_zk::{create_init_proof, check_init_proof};
+use sagittarius_zk::{create_init_proof, check_init_proof, Backend, ReferenceBackend, Risc0Backend};
 
-use crate::session::GameSession;
+use crate::channel::Channel;
+use crate::identity::{fingerprint, Identity, IDENTITY_PATH};
+use crate::session::{GameMode, GameSession};
 use crate::board::choose_board;
+use crate::utils::{read_line, select_option};
 use crate::net_utils::{chunk_write, read_message};
 
 lazy_static! {
     pub static ref LOGGER: BufferLogger = BufferLogger::new((BOARD_SIZE+1) * 2 - 1);
 }
 
-async fn verify_enemy_board_init(socket: &mut TcpStream) -> Result<Digest> {
-    let receipt_bytes = chunk_read(socket).await?;
-    let receipt: Receipt = serde_cbor::from_slice(&receipt_bytes).expect("Unable to deserialize receipt");
+async fn verify_enemy_board_init(backend: &dyn Backend, socket: &mut Channel) -> Result<Digest> {
+    let proof = chunk_read(socket).await?;
 
-    if !check_init_proof(&receipt).is_ok() {
-        write_message(socket, "Invalid init proof!".as_bytes()).await?;
-        return Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Error"));
+    match check_init_proof(backend, &proof) {
+        Ok(digest) => Ok(digest),
+        Err(_) => {
+            write_message(socket, "Invalid init proof!".as_bytes()).await?;
+            Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Error"))
+        }
     }
-
-    let journal = receipt.get_journal_bytes(); 
-    let digest = serde::from_slice::<Digest, u8>(&journal).unwrap();
-
-    Ok(digest)
 }
 
-async fn prove_board_init(state: &GameState, socket: &mut TcpStream) -> Result<()> {
+async fn prove_board_init(backend: &dyn Backend, state: &GameState, socket: &mut Channel) -> Result<()> {
     LOGGER.log("Generating proof... This might take a while");
-    let receipt = create_init_proof(state).expect("Failed");
-    let rec_vec = serde_cbor::to_vec(&receipt).expect("Error while serializing receipt");
-    chunk_write(socket, &rec_vec).await.expect("Error while sending receipt");
+    let proof = create_init_proof(backend, state).expect("Failed");
+    chunk_write(socket, &proof).await.expect("Error while sending receipt");
     Ok(())
 }
 
-async fn join_game() -> Result<()> {
-    LOGGER.log("Enter host:port");
-    let mut input = String::new();
-    print!(">: ");
-    std::io::stdout().flush().unwrap();
-    std::io::stdin().read_line(&mut input).expect("Failed to read input");
+/// Wraps a fresh connection, running the Noise handshake when we have an identity.
+async fn open_channel(stream: TcpStream, identity: Option<&Identity>, initiator: bool) -> Result<Channel> {
+    let identity = match identity {
+        Some(identity) => identity,
+        None => return Ok(Channel::plain(stream)),
+    };
+
+    let channel = if initiator {
+        Channel::initiate(stream, identity).await?
+    } else {
+        Channel::respond(stream, identity).await?
+    };
+
+    LOGGER.log(&format!("Your fingerprint: {}", identity.fingerprint()));
+    LOGGER.log(&format!("Peer fingerprint: {}", fingerprint(&channel.peer_identity().unwrap())));
+    let options = ["Yes".to_string(), "No, disconnect".to_string()];
+    if select_option("Trust this peer?", &options) != 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "Peer rejected"));
+    }
 
-    let mut stream = TcpStream::connect(input.trim()).await?;
+    Ok(channel)
+}
 
-    draw_night_sky(Some(1), Some(20));
+async fn join_game(backend: &'static dyn Backend, identity: Option<Arc<Identity>>) -> Result<()> {
+    let input = read_line("Enter host:port");
+
+    let stream = TcpStream::connect(input.trim()).await?;
+    let mut stream = open_channel(stream, identity.as_deref(), true).await?;
 
     LOGGER.log("Connected to server");
 
+    // The host picks the rules
+    let mode = match GameMode::from_u8(stream.read_u8().await?) {
+        Some(mode) => mode,
+        None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Unknown game mode")),
+    };
+    LOGGER.log(&format!("Game mode: {}", mode));
+
     let state = choose_board();
-    prove_board_init(&state, &mut stream).await?;
+    prove_board_init(backend, &state, &mut stream).await?;
 
     let response = read_message(&mut stream).await?;
     LOGGER.log(&response);
 
-    let enemy_state = verify_enemy_board_init(&mut stream).await?;
+    let enemy_state = verify_enemy_board_init(backend, &mut stream).await?;
 
     LOGGER.log("Server has valid setup. The game is starting!");
-    let mut session = GameSession::new(stream, state, enemy_state, true);
-    session.game_cycle().await?;
+    let mut session = GameSession::new(stream, backend, mode, state, enemy_state, true);
+    if let Some(report) = session.game_cycle().await? {
+        LOGGER.log(&format!("Player is cheating. Disconnecting... {}", report));
+    } else if let Some(identity) = identity {
+        session.save_transcript(&identity).await?;
+    }
 
     Ok(())
 }
 
-async fn host_game() -> Result<()> {
+async fn host_game(backend: &'static dyn Backend, identity: Option<Arc<Identity>>) -> Result<()> {
+    let modes = GameMode::ALL.iter().map(|mode| mode.to_string()).collect::<Vec<_>>();
+    let mode = GameMode::ALL[select_option("Game mode", &modes)];
+
     let listener = TcpListener::bind("127.0.0.1:8080").await.expect("Can't bind to 8080");
     LOGGER.log("Started server at :8080 ... awaiting connections");
 
     loop {
-        let (mut socket, _) = listener.accept().await.unwrap();
+        let (socket, _) = listener.accept().await.unwrap();
 
         LOGGER.log(&format!("Got connection from {:?}", socket.peer_addr().unwrap().ip()));
+        let identity = identity.clone();
         tokio::spawn(async move {
-            let enemy_state = verify_enemy_board_init(&mut socket).await.unwrap();
+            let mut socket = match open_channel(socket, identity.as_deref(), false).await {
+                Ok(channel) => channel,
+                Err(e) => {
+                    LOGGER.log(&format!("Handshake failed: {}", e));
+                    return;
+                }
+            };
+            socket.write_u8(mode.to_u8()).await.unwrap();
+            let enemy_state = verify_enemy_board_init(backend, &mut socket).await.unwrap();
             
             write_message(&mut socket, "Everything good. Sending my board...".as_bytes()).await.unwrap();
             let state = choose_board();
 
             LOGGER.log("Generating init proof...");
-            prove_board_init(&state, &mut socket).await.unwrap();
+            prove_board_init(backend, &state, &mut socket).await.unwrap();
 
-            let mut session = GameSession::new(socket, state, enemy_state, false);
-            session.game_cycle().await.unwrap();
+            let mut session = GameSession::new(socket, backend, mode, state, enemy_state, false);
+            if let Some(report) = session.game_cycle().await.unwrap() {
+                LOGGER.log(&format!("Player is cheating. Disconnecting... {}", report));
+            } else if let Some(identity) = identity {
+                session.save_transcript(&identity).await.unwrap();
+            }
         });
     }
 
@@ -108,36 +157,39 @@ async fn host_game() -> Result<()> {
 async fn main() -> Result<()> {
     log::set_max_level(log::LevelFilter::Info);
 
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("ladder") {
+        return ladder::run(&args[1..]);
+    }
+
+    // With --secure all traffic goes through an authenticated Noise channel
+    let identity = if std::env::args().any(|arg| arg == "--secure") {
+        Some(Arc::new(Identity::load_or_create(Path::new(IDENTITY_PATH))?))
+    } else {
+        None
+    };
+
+    // Both players have to pick the same backend. The reference one reveals boards and is only for local testing
+    let backend: &'static dyn Backend = if std::env::args().any(|arg| arg == "--reference") {
+        &ReferenceBackend
+    } else {
+        &Risc0Backend
+    };
+
+    init_terminal();
+
+    let options = ["Join game".to_string(), "Host game".to_string(), "Exit".to_string()];
     loop {
-        LOGGER.log("1. Join game");
-        LOGGER.log("2. Host game");
-        LOGGER.log("3. Exit");
-        let mut input = String::new();
-        print!(">: ");
-        std::io::stdout().flush().unwrap();
-        std::io::stdin().read_line(&mut input).expect("Failed to read input");
-
-        let option = input.trim().parse::<u8>();
-        
-        match option {
-            Ok(n) => match n {
-                1 => {
-                    join_game().await?;
-                },
-                2 => {
-                    host_game().await?;
-                },
-                3 => {
-                    std::process::exit(0);
-                },
-                _ => {
-                    LOGGER.log("Enter a number from 1 to 3");
-                    continue;
-                }
+        match select_option("Main menu", &options) {
+            0 => {
+                join_game(backend, identity.clone()).await?;
+            },
+            1 => {
+                host_game(backend, identity.clone()).await?;
             },
-            Err(_) => {
-                LOGGER.log("Enter a number from 1 to 3");
-                continue;
+            _ => {
+                restore_terminal();
+                std::process::exit(0);
             }
         }
     }
@@ -1,9 +1,16 @@
+use sagittarius_game::abilities::{ClusterArea, ClusterAreaError};
 use sagittarius_game::types::Position;
 use serde::{Deserialize, Serialize};
-use tokio::net::TcpStream;
-use tokio::io::{AsyncReadExt, AsyncWriteExt, Result, BufReader, AsyncBufReadExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, Result, BufReader, AsyncBufReadExt, Error, ErrorKind};
+
+use crate::channel::{Channel, MAX_FRAME_LEN};
+use crate::chat::{ChatMessage, MAX_CHAT_LEN};
 
 const MESSAGE_DELIMITER: u8 = 0x1E; // "Record Separator" in ASCII
+/// Sent by the shooter once the proof for their turn checks out, so there is one per completed turn
+pub const ACK_MESSAGE: &str = "ok";
+/// Largest encoded chat message we read, room for `MAX_CHAT_LEN` four byte characters and the CBOR framing
+const MAX_CHAT_FRAME: usize = 8 * MAX_CHAT_LEN;
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ClusterMessage {
@@ -13,13 +20,18 @@ pub struct ClusterMessage {
 }
 
 impl ClusterMessage {
-    pub fn check(&self) {
-        self.ul.check();
-        self.dr.check();
+    /// Number of shots the charge gets, or why the rectangle is not allowed.
+    pub fn check(&self) -> std::result::Result<usize, ClusterAreaError> {
+        ClusterArea::new(self.ul.clone(), self.dr.clone()).validate()
     }
 }
 
-pub async fn chunk_write(stream: &mut TcpStream, message: &[u8]) -> Result<()> {
+pub async fn chunk_write(channel: &mut Channel, message: &[u8]) -> Result<()> {
+    if channel.is_secure() {
+        return channel.write_raw(message).await;
+    }
+
+    let stream = &mut channel.stream;
     let len = message.len() as u32;
     stream.write_u32(len).await?;
 
@@ -34,39 +46,109 @@ pub async fn chunk_write(stream: &mut TcpStream, message: &[u8]) -> Result<()> {
     Ok(())
 }
 
-pub async fn chunk_read(stream: &mut TcpStream) -> Result<Vec<u8>> {
-    let len: u32 = stream.read_u32().await?;
-    let mut message = vec![0u8; len as usize];
+pub async fn chunk_read(channel: &mut Channel) -> Result<Vec<u8>> {
+    chunk_read_bounded(channel, MAX_FRAME_LEN)
+        .await?
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Message too large"))
+}
+
+/// Reads a message of at most `max` bytes. A longer one is skipped without being buffered and gives `None`.
+/// A secure channel has already checked it against `MAX_FRAME_LEN`.
+pub async fn chunk_read_bounded(channel: &mut Channel, max: usize) -> Result<Option<Vec<u8>>> {
+    if channel.is_secure() {
+        let message = channel.read_raw().await?;
+        return Ok((message.len() <= max).then_some(message));
+    }
+
+    let stream = &mut channel.stream;
+    let len = stream.read_u32().await? as usize;
+    if len > max {
+        tokio::io::copy(&mut stream.take(len as u64), &mut tokio::io::sink()).await?;
+        return Ok(None);
+    }
+    let mut message = vec![0u8; len];
     stream.read_exact(&mut message).await?;
 
-    Ok(message)
+    Ok(Some(message))
 }
 
-pub async fn write_message(stream: &mut TcpStream, message: &[u8]) -> Result<()> {
+pub async fn write_message(channel: &mut Channel, message: &[u8]) -> Result<()> {
+    if channel.is_secure() {
+        return channel.write_raw(message).await;
+    }
+
     let mut bytes = vec![];
     bytes.extend_from_slice(message);
     bytes.push(MESSAGE_DELIMITER);
-    stream.write_all(&bytes).await
+    channel.stream.write_all(&bytes).await
 }
 
-pub async fn read_message(stream: &mut TcpStream) -> Result<String> {
-    let mut bytes = vec![];
-    let mut reader = BufReader::new(stream);
-    reader.read_until(MESSAGE_DELIMITER, &mut bytes).await?;
-    bytes.pop();
+pub async fn read_message(channel: &mut Channel) -> Result<String> {
+    let bytes = if channel.is_secure() {
+        channel.read_raw().await?
+    } else {
+        let mut bytes = vec![];
+        let mut reader = BufReader::new(&mut channel.stream);
+        reader.read_until(MESSAGE_DELIMITER, &mut bytes).await?;
+        bytes.pop();
+        bytes
+    };
     Ok(String::from_utf8(bytes).expect("Non utf-8 message!"))
 }
 
-pub async fn get_coords_from_server(stream: &mut TcpStream) -> Result<Position> {
-    let mut coords_bytes = vec![];
-    stream.read_buf(&mut coords_bytes).await?;
+pub async fn write_ack(channel: &mut Channel) -> Result<()> {
+    channel.write_raw(ACK_MESSAGE.as_bytes()).await
+}
+
+pub async fn read_ack(channel: &mut Channel) -> Result<String> {
+    let msg_bytes = if channel.is_secure() {
+        channel.read_raw().await?
+    } else {
+        let mut msg_bytes = [0; 2];
+        channel.stream.read(&mut msg_bytes).await?;
+        msg_bytes.to_vec()
+    };
+    Ok(String::from_utf8(msg_bytes).unwrap())
+}
+
+pub async fn get_coords_from_server(channel: &mut Channel) -> Result<Position> {
+    let coords_bytes = channel.read_raw().await?;
 
     Ok(serde_cbor::from_slice(&coords_bytes).expect("Error while deser"))
 }
 
-pub async fn get_cluster_message(stream: &mut TcpStream) -> Result<ClusterMessage> {
-    let mut cluster_bytes = vec![];
-    stream.read_buf(&mut cluster_bytes).await?;
+pub async fn get_cluster_message(channel: &mut Channel) -> Result<ClusterMessage> {
+    let cluster_bytes = channel.read_raw().await?;
 
     Ok(serde_cbor::from_slice(&cluster_bytes).expect("Error while deser"))
+}
+
+pub async fn get_salvo_message(channel: &mut Channel) -> Result<Vec<Position>> {
+    let salvo_bytes = channel.read_raw().await?;
+
+    Ok(serde_cbor::from_slice(&salvo_bytes).expect("Error while deser"))
+}
+
+/// Chat is length prefixed like receipts, so it never runs into the next action on a plaintext channel.
+pub async fn write_chat_message(channel: &mut Channel, message: &ChatMessage) -> Result<()> {
+    let bytes = serde_cbor::to_vec(message).expect("Failed serialization");
+    chunk_write(channel, &bytes).await
+}
+
+/// What the peer sent as a chat message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ChatFrame {
+    Message(ChatMessage),
+    /// Longer than any valid message, it was skipped unread
+    Oversized,
+    /// Doesn't decode as a chat message
+    Malformed,
+}
+
+pub async fn read_chat_message(channel: &mut Channel) -> Result<ChatFrame> {
+    let frame = match chunk_read_bounded(channel, MAX_CHAT_FRAME).await? {
+        Some(bytes) => serde_cbor::from_slice(&bytes).map_or(ChatFrame::Malformed, ChatFrame::Message),
+        None => ChatFrame::Oversized,
+    };
+    Ok(frame)
 }
\ No newline at end of file
@@ -1,50 +1,95 @@
-use risc0_zkvm::Receipt;
-use sagittarius_game::{NUM_SHIPS, SUNK_MASKS};
-use sagittarius_game::abilities::{ShotParams, ClusterBombParams, ScoutParams};
+use std::fmt::Display;
+
+use sagittarius_game::{NUM_SHIPS, SHIP_SPANS, SUNK_MASKS, MAX_SCOUTS, MAX_CLUSTER_BOMBS};
+use sagittarius_game::abilities::{ShotParams, ClusterArea, ClusterBombParams, ScoutParams, SalvoParams};
 use sagittarius_game::types::{Position, HitType};
 use sagittarius_game::{state::GameState, types::Digest};
-use sagittarius_zk::{check_turn_proof, create_turn_proof, check_cluster_proof, create_cluster_proof, create_scout_proof, check_scout_proof};
-use tokio::{net::TcpStream, io::AsyncWriteExt};
-use tokio::io::{Result, AsyncReadExt};
-use std::io::Write;
+use sagittarius_game::advisor::HeatMap;
+use sagittarius_zk::{Backend, check_turn_proof, create_turn_proof, check_cluster_proof, create_cluster_proof, create_scout_proof, check_scout_proof, create_salvo_proof, check_salvo_proof};
+use tokio::io::Result;
+
+use crate::cheat::{CheatReport, Violation};
+use crate::channel::Channel;
+use crate::chat::{ChatMessage, Emote, MAX_CHAT_PER_TURN};
+use crate::identity::{fingerprint, Identity};
+use crate::ladder::{record_match, LADDER_PATH};
+use crate::net_utils::{chunk_read, get_coords_from_server, chunk_write, ClusterMessage, get_cluster_message, get_salvo_message, read_chat_message, write_chat_message, ChatFrame, read_message, write_message, read_ack, write_ack};
+use crate::utils::{read_line, select_option, select_target, suggest_target};
+use crate::{board::{HitMap, create_empty_shots}, interface::{set_heat_map, update_enemy_game_board, update_player_game_board}, LOGGER};
+
+pub const LOSS_MESSAGE: &str = "Thank you for the game!";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameMode {
+    /// One shot per turn
+    Classic,
+    /// One shot per surviving ship each turn
+    Salvo,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 2] = [GameMode::Classic, GameMode::Salvo];
 
-use crate::net_utils::{chunk_read, get_coords_from_server, chunk_write, ClusterMessage, get_cluster_message, read_message, write_message};
-use crate::utils::get_coords_from_stdin;
-use crate::{board::{HitMap, create_empty_shots}, interface::{update_enemy_game_board, update_player_game_board}, LOGGER};
+    pub fn from_u8(n: u8) -> Option<Self> {
+        GameMode::ALL.get(n as usize).copied()
+    }
+
+    pub fn to_u8(self) -> u8 {
+        self as u8
+    }
+}
 
-const MAX_SCOUTS: u8 = 1;
-const MAX_CLUSTER_BOMBS: u8 = 1;
-const LOSS_MESSAGE: &str = "Thank you for the game!";
+impl Display for GameMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameMode::Classic => write!(f, "Classic"),
+            GameMode::Salvo => write!(f, "Salvo"),
+        }
+    }
+}
 
 pub struct GameSession {
-    stream: TcpStream,
+    channel: Channel,
+    backend: &'static dyn Backend,
+    mode: GameMode,
     state: GameState,
     hits: HitMap,
     enemy_state: Digest,
     turn: bool,
+    turns: u32,
+    // Our own charges. The enemy's charges live in `state` and are enforced by the guests
     scouts: u8,
     clusters: u8,
-    enemy_scouts: u8,
-    enemy_cluster: u8,
+    // Chat messages sent during the current turn, by whoever is taking it
+    chat_count: usize,
+    // Show the heat map of likely enemy ships and start targeting from its best cell
+    advisor: bool,
 }
 
 impl GameSession {
-    pub fn new(stream: TcpStream, state: GameState, enemy_state: Digest, turn: bool) -> Self {
+    pub fn new(channel: Channel, backend: &'static dyn Backend, mode: GameMode, state: GameState, enemy_state: Digest, turn: bool) -> Self {
         GameSession {
-            stream,
+            channel,
+            backend,
+            mode,
             state,
             hits: create_empty_shots(),
             enemy_state,
             turn,
+            turns: 0,
             scouts: MAX_SCOUTS,
             clusters: MAX_CLUSTER_BOMBS,
-            enemy_scouts: MAX_SCOUTS,
-            enemy_cluster: MAX_CLUSTER_BOMBS,
+            chat_count: 0,
+            advisor: false,
         }
     }
 
+    fn enemy_ships_destroyed(&self) -> usize {
+        self.hits.values().filter(|h| matches!(h, HitType::Destroyed(_))).count()
+    }
+
     fn check_for_win(&self) -> bool {
-        self.hits.values().filter(|h| matches!(h, HitType::Destroyed(_))).count() == NUM_SHIPS
+        self.enemy_ships_destroyed() == NUM_SHIPS
     }
 
     fn check_for_loss(&self) -> bool {
@@ -52,21 +97,64 @@ impl GameSession {
     }
 
     async fn read_win_message(&mut self) -> Result<()> {
-        let msg = read_message(&mut self.stream).await?;
+        let msg = read_message(&mut self.channel).await?;
         LOGGER.log(format!("Enemy: {}", msg).as_str());
         Ok(())
     }
 
     async fn write_loss_message(&mut self) -> Result<()> {
-        write_message(&mut self.stream, LOSS_MESSAGE.as_bytes()).await
+        write_message(&mut self.channel, LOSS_MESSAGE.as_bytes()).await
+    }
+
+    async fn update_advisor(&self) {
+        if !self.advisor {
+            set_heat_map(None);
+            return;
+        }
+
+        // The exact search can take a while on an open board, keep it off the async workers
+        let hits = self.hits.clone();
+        let heat = tokio::task::spawn_blocking(move || HeatMap::new(&hits, &SHIP_SPANS))
+            .await
+            .expect("Advisor search panicked");
+        if let Some(best) = heat.best() {
+            LOGGER.log(&format!("Advisor: best target {} ({:.0}% chance of a ship)", best, heat.probability(&best) * 100.0));
+            suggest_target(best);
+        }
+        set_heat_map(Some(heat));
+    }
+
+    /// Signs the game transcript together with the peer, stores it and records the result in the ladder.
+    /// Plaintext games have no transcript.
+    pub async fn save_transcript(&mut self, identity: &Identity) -> Result<()> {
+        if let Some(signed) = self.channel.sign_transcript(identity).await? {
+            let path = signed.save()?;
+            LOGGER.log(&format!("Signed transcript saved to {}", path.display()));
+            if record_match(&signed)? {
+                LOGGER.log(&format!("Match recorded in {}", LADDER_PATH));
+            }
+        }
+        Ok(())
+    }
+
+    fn cheat_report(&self, violation: Violation) -> CheatReport {
+        CheatReport {
+            violation,
+            turn: self.turns,
+            peer: self.channel.peer_identity().map(|peer| fingerprint(&peer)),
+        }
     }
 
-    pub async fn game_cycle(&mut self) -> Result<()> {
+    /// Plays until someone wins. Returns a report instead if the enemy breaks the rules.
+    pub async fn game_cycle(&mut self) -> Result<Option<CheatReport>> {
         loop {
             if self.turn {
                 LOGGER.log("Doing player turn...");
-                self.do_player_turn().await?;
+                if let Some(violation) = self.do_player_turn().await? {
+                    return Ok(Some(self.cheat_report(violation)));
+                }
                 update_enemy_game_board(&self.hits);
+                self.update_advisor().await;
 
                 if self.check_for_win() {
                     LOGGER.log("You won! Let's see what the loser has to say:");
@@ -75,7 +163,9 @@ impl GameSession {
                 }
             } else {
                 LOGGER.log("Waiting for enemy turn...");
-                self.do_enemy_turn().await?;
+                if let Some(violation) = self.do_enemy_turn().await? {
+                    return Ok(Some(self.cheat_report(violation)));
+                }
                 update_player_game_board(&self.state);
                 if self.check_for_loss() {
                     LOGGER.log("All our ships have been destroyed... We lost");
@@ -85,82 +175,127 @@ impl GameSession {
             }
 
             self.turn = !self.turn;
+            self.turns += 1;
+            self.chat_count = 0;
         }
 
-        Ok(())
+        Ok(None)
     }
 
-    async fn do_simple_shot(&mut self) -> Result<()> {
-        self.stream.write_u8(1).await?;
+    async fn do_simple_shot(&mut self) -> Result<Option<Violation>> {
+        self.channel.write_u8(1).await?;
 
         // P1 input hit
-        let pos = get_coords_from_stdin(Some(&self.hits));
+        let pos = select_target(Some(&self.hits));
         let pos_vec = serde_cbor::to_vec(&pos).expect("Failed serialization");
         
         // Send pos to P2
-        self.stream.write(&pos_vec).await?;
+        self.channel.write_raw(&pos_vec).await?;
 
         LOGGER.log("The shot is fired. Reading the proof from server...");
         // Recieve proof from P2
-        let receipt_bytes = chunk_read(&mut self.stream).await?;
-        let receipt: Receipt = serde_cbor::from_slice(&receipt_bytes).expect("Unable to deserialize receipt");
+        let proof = chunk_read(&mut self.channel).await?;
 
         // Check proof from P2
-        let (hit, new_enemy_state) = check_turn_proof(receipt, &pos, &self.enemy_state).expect("Invalid proof from server");
-        self.hits.insert(pos, hit);
+        let (hit, new_enemy_state) = match check_turn_proof(self.backend, &proof, &pos, &self.enemy_state) {
+            Ok(result) => result,
+            Err(_) => return Ok(Some(Violation::InvalidProof("turn"))),
+        };
+        if hit == HitType::AlreadyHit {
+            LOGGER.log("That cell was already hit");
+        } else {
+            self.hits.insert(pos, hit);
+        }
         self.enemy_state = new_enemy_state;
 
-        Ok(())
+        Ok(None)
+    }
+
+    async fn do_salvo(&mut self) -> Result<Option<Violation>> {
+        self.channel.write_u8(4).await?;
+
+        let count = self.state.surviving_ships();
+        let mut shots: Vec<Position> = vec![];
+        while shots.len() < count {
+            LOGGER.log(&format!("Salvo: choose target {} of {}", shots.len() + 1, count));
+            let pos = select_target(Some(&self.hits));
+            if shots.contains(&pos) {
+                LOGGER.log("That cell is already targeted in this salvo");
+                continue;
+            }
+            shots.push(pos);
+        }
+        let shots_vec = serde_cbor::to_vec(&shots).expect("Failed serialization");
+        self.channel.write_raw(&shots_vec).await?;
+
+        LOGGER.log("The salvo is fired. Reading the proof from server...");
+        let proof = chunk_read(&mut self.channel).await?;
+
+        let (hits, new_enemy_state) = match check_salvo_proof(self.backend, &proof, &shots, &self.enemy_state) {
+            Ok(result) => result,
+            Err(_) => return Ok(Some(Violation::InvalidProof("salvo"))),
+        };
+        for (shot, hit) in shots.iter().zip(hits.iter()) {
+            LOGGER.log(&format!("Salvo {}: {:?}", shot, hit));
+            if *hit != HitType::AlreadyHit {
+                self.hits.insert(shot.clone(), *hit);
+            }
+        }
+        self.enemy_state = new_enemy_state;
+
+        Ok(None)
     }
 
-    async fn do_scout(&mut self) -> Result<()> {
-        self.stream.write_u8(2).await?;
+    async fn do_scout(&mut self) -> Result<Option<Violation>> {
+        self.channel.write_u8(2).await?;
         self.scouts -= 1;
 
         // P1 input hit
-        let pos = get_coords_from_stdin(Some(&self.hits));
+        let pos = select_target(Some(&self.hits));
         let pos_vec = serde_cbor::to_vec(&pos).expect("Failed serialization");
         
         // Send pos to P2
-        self.stream.write(&pos_vec).await?;
+        self.channel.write_raw(&pos_vec).await?;
 
         LOGGER.log("The scouts are on positions. Reading the proof from server...");
         // Recieve proof from P2
-        let receipt_bytes = chunk_read(&mut self.stream).await?;
-        let receipt: Receipt = serde_cbor::from_slice(&receipt_bytes).expect("Unable to deserialize receipt");
+        let proof = chunk_read(&mut self.channel).await?;
 
-        let hits = check_scout_proof(receipt, &pos).expect("Invalid scout proof from server");
-        let positions = [Position::new(pos.x - 1, pos.y),
-                         Position::new(pos.x, pos.y - 1),
+        let (hits, new_enemy_state) = match check_scout_proof(self.backend, &proof, &pos, &self.enemy_state) {
+            Ok(result) => result,
+            Err(_) => return Ok(Some(Violation::InvalidProof("scout"))),
+        };
+        self.enemy_state = new_enemy_state;
+        let positions = [Position::new(pos.x.wrapping_sub(1), pos.y),
+                         Position::new(pos.x, pos.y.wrapping_sub(1)),
                          Position::new(pos.x + 1, pos.y),
                          Position::new(pos.x, pos.y + 1)];
 
+        // Scouts don't know about damage, so never overwrite an earlier hit with a reveal
         for (pos, hit) in positions.iter().zip(hits.iter()) {
-            self.hits.insert(pos.clone(), hit.clone());
+            self.hits.entry(pos.clone()).or_insert(*hit);
         }
-        Ok(())
+        Ok(None)
     }
 
-    async fn do_cluster_charge(&mut self) -> Result<()> {
-        self.stream.write_u8(3).await?;
+    async fn do_cluster_charge(&mut self) -> Result<Option<Violation>> {
+        self.channel.write_u8(3).await?;
         self.clusters -= 1;
 
         let mut ul: Position;
         let mut dr: Position;
 
         loop {
-            LOGGER.log("You should select a rectangle with area from 4 to 14");
+            LOGGER.log("You should select a rectangle with area from 4 to 14 and both sides at least 2");
             LOGGER.log("Enter coordinates of the upper left corner");
-            ul = get_coords_from_stdin(None);
+            ul = select_target(None);
 
             LOGGER.log("Enter coordinates of the down right corner");
-            dr = get_coords_from_stdin(None);
+            dr = select_target(None);
 
-            let area = (dr.x - ul.x + 1) * (dr.y - ul.y + 1);
-            if area > 14 || area < 4 || dr.x - ul.x == 0 || dr.y - ul.y == 0 {
-                LOGGER.log("ERROR: incorrect area");
-            } else {
-                break;
+            match ClusterArea::new(ul.clone(), dr.clone()).validate() {
+                Ok(_) => break,
+                Err(e) => LOGGER.log(&format!("ERROR: incorrect area, {}", e)),
             }
         }
 
@@ -168,93 +303,135 @@ impl GameSession {
 
         let msg = ClusterMessage { ul: ul.clone(), dr: dr.clone(), seed };
         let msg_vec = serde_cbor::to_vec(&msg).expect("Failed serialization");
-        self.stream.write(&msg_vec).await?;
+        self.channel.write_raw(&msg_vec).await?;
 
         LOGGER.log("Cluster charge is fired. Waiting...");
         // Recieve proof from P2
-        let receipt_bytes = chunk_read(&mut self.stream).await?;
-        let receipt: Receipt = serde_cbor::from_slice(&receipt_bytes).expect("Unable to deserialize receipt");
+        let proof = chunk_read(&mut self.channel).await?;
 
         // Check proof from P2
-        let (shots, hits, new_enemy_state) = check_cluster_proof(receipt, ul, dr, seed, self.enemy_state).expect("Invalid proof from server");
-        
-        for (shot, hit) in shots.iter().zip(hits.iter()) {
+        let (shots, hits, new_enemy_state) = match check_cluster_proof(self.backend, &proof, ul, dr, seed, self.enemy_state) {
+            Ok(result) => result,
+            Err(_) => return Ok(Some(Violation::InvalidProof("cluster"))),
+        };
+
+        // Keep the original result for cells that were already hit
+        for (shot, hit) in shots.iter().zip(hits.iter()).filter(|(_, hit)| **hit != HitType::AlreadyHit) {
             self.hits.insert(shot.clone(), *hit);
         }
         self.enemy_state = new_enemy_state;
 
-        Ok(())
+        Ok(None)
     }
 
-    async fn do_player_turn(&mut self) -> Result<()> {
-        LOGGER.log("");
-        LOGGER.log("It's your turn! What would you like to do?");
-        LOGGER.log("==========================================");
-        LOGGER.log("1. BLIND FIRE - shoot 1 cell on the board");
+    /// Chat is only sent by the player taking the turn, before their action, so it never interleaves with proofs.
+    async fn do_chat(&mut self) -> Result<()> {
+        if self.chat_count >= MAX_CHAT_PER_TURN {
+            LOGGER.log("Chat limit reached, take your turn!");
+            return Ok(());
+        }
 
-        if self.scouts > 0 {
-            LOGGER.log(format!("2. SEND SCOUTS - reveal spaceships in an area ({} left)", self.scouts).as_str());
-        } else {
-            LOGGER.log("2. SEND SCOUTS (0 left)");
+        let mut options = Emote::ALL.iter().map(|emote| emote.to_string()).collect::<Vec<_>>();
+        options.push("Type a message...".to_string());
+        options.push("Back".to_string());
+        let choice = select_option("Chat", &options);
+
+        let message = match Emote::ALL.get(choice) {
+            Some(emote) => ChatMessage::Emote(*emote),
+            None if choice == Emote::ALL.len() => match ChatMessage::text(&read_line("Message")) {
+                Some(message) => message,
+                None => return Ok(()),
+            },
+            None => return Ok(()),
+        };
+
+        self.channel.write_u8(5).await?;
+        write_chat_message(&mut self.channel, &message).await?;
+        self.chat_count += 1;
+        LOGGER.log(&format!("You: {}", message));
+        Ok(())
+    }
+
+    async fn handle_chat(&mut self) -> Result<Option<Violation>> {
+        let frame = read_chat_message(&mut self.channel).await?;
+        // Oversized messages use up the turn's chat allowance like any other
+        self.chat_count += 1;
+        if self.chat_count > MAX_CHAT_PER_TURN {
+            return Ok(Some(Violation::ChatAbuse));
         }
 
-        if self.clusters > 0 {
-            LOGGER.log(format!("3. CLUSTER CHARGE - randomly bombard the area ({} left)", self.clusters).as_str());
-        } else {
-            LOGGER.log("3. CLUSTER CHARGE (0 left)");
+        match frame {
+            ChatFrame::Message(message) if message.check() => {
+                LOGGER.log(&format!("Enemy: {}", message));
+                Ok(None)
+            },
+            ChatFrame::Oversized => {
+                LOGGER.log("Enemy sent an oversized chat message, it was dropped");
+                Ok(None)
+            },
+            _ => Ok(Some(Violation::ChatAbuse)),
         }
+    }
 
-        loop {
-            let mut input = String::new();
-            print!(">: ");
-            std::io::stdout().flush().unwrap();
-            std::io::stdin().read_line(&mut input).expect("Failed to read input");
-
-            let option = input.trim().parse::<u8>();
-            
-            match option {
-                Ok(n) => match n {
-                    1 => {
-                        self.do_simple_shot().await?;
-                        break;
-                    },
-                    2 => {
-                        if self.scouts > 0 {
-                            self.do_scout().await?;
-                            break;
-                        } else {
-                            LOGGER.log("You are out of charges!");
-                            continue;
-                        }
-                    },
-                    3 => {
-                        if self.clusters > 0 {
-                            self.do_cluster_charge().await?;
-                            break;
-                        } else {
-                            LOGGER.log("You are out of charges!");
-                            continue;
-                        }
-                    },
-                    _ => {
-                        LOGGER.log("Enter a number from 1 to 3");
+    async fn do_player_turn(&mut self) -> Result<Option<Violation>> {
+        LOGGER.log("It's your turn! What would you like to do?");
+
+        let fire = match self.mode {
+            GameMode::Classic => "BLIND FIRE - shoot 1 cell on the board".to_string(),
+            GameMode::Salvo => format!("SALVO - shoot {} cells on the board", self.state.surviving_ships()),
+        };
+        let violation = loop {
+            let options = [
+                fire.clone(),
+                format!("SEND SCOUTS - reveal spaceships ({} left)", self.scouts),
+                format!("CLUSTER CHARGE - bombard an area ({} left)", self.clusters),
+                "CHAT - send a message or emote".to_string(),
+                format!("ADVISOR - {} the heat map", if self.advisor { "hide" } else { "show" }),
+            ];
+
+            match select_option("Your turn", &options) {
+                0 => match self.mode {
+                    GameMode::Classic => break self.do_simple_shot().await?,
+                    GameMode::Salvo => break self.do_salvo().await?,
+                },
+                1 => {
+                    if self.scouts > 0 {
+                        break self.do_scout().await?;
+                    } else {
+                        LOGGER.log("You are out of charges!");
                         continue;
                     }
                 },
-                Err(_) => {
-                    LOGGER.log("Enter a number from 1 to 3");
+                2 => {
+                    if self.clusters > 0 {
+                        break self.do_cluster_charge().await?;
+                    } else {
+                        LOGGER.log("You are out of charges!");
+                        continue;
+                    }
+                },
+                3 => {
+                    self.do_chat().await?;
+                    continue;
+                },
+                _ => {
+                    self.advisor = !self.advisor;
+                    self.update_advisor().await;
                     continue;
                 }
             }
+        };
+        if violation.is_some() {
+            return Ok(violation);
         }
-        self.stream.write("ok".as_bytes()).await?;
+        write_ack(&mut self.channel).await?;
 
-        Ok(())
+        Ok(None)
     }
 
-    async fn handle_simple_shot(&mut self) -> Result<Receipt> {
+    async fn handle_simple_shot(&mut self) -> Result<Vec<u8>> {
         // P1 recieves hit
-        let pos = get_coords_from_server(&mut self.stream).await?;
+        let pos = get_coords_from_server(&mut self.channel).await?;
         assert!(pos.check());
 
         // P1 update state
@@ -267,84 +444,111 @@ impl GameSession {
         if let HitType::Destroyed(_) = result.hit {
             LOGGER.log("One of your ships was destroyed!");
         }
+        if let HitType::AlreadyHit = result.hit {
+            LOGGER.log("The enemy fired at a wreck again");
+        }
 
         self.state = result.state;
 
         // P1 generate turn proof
         LOGGER.log("Generating turn proof...");
-        let proof = create_turn_proof(&params).expect("Error while creating the turn proof!");
+        let proof = create_turn_proof(self.backend, &params).expect("Error while creating the turn proof!");
         Ok(proof)
     }
 
-    async fn handle_scout(&mut self) -> Result<Receipt> {
-        self.enemy_scouts -= 1;
+    async fn handle_salvo(&mut self) -> Result<std::result::Result<Vec<u8>, Violation>> {
+        let shots = get_salvo_message(&mut self.channel).await?;
+        let params = SalvoParams::new(self.state.clone(), shots);
+
+        // The guest checks the shots themselves, only we know how many ships the enemy has left
+        let allowed = NUM_SHIPS - self.enemy_ships_destroyed();
+        if !params.check() || params.shots.len() > allowed {
+            return Ok(Err(Violation::InvalidSalvo { fired: params.shots.len(), allowed }));
+        }
+        LOGGER.log(&format!("⚠⚠⚠ SALVO OF {} SHOTS INBOUND ⚠⚠⚠", params.shots.len()));
+
+        let result = params.process();
+        for (shot, hit) in params.shots.iter().zip(result.hits.iter()) {
+            match hit {
+                HitType::Hit => LOGGER.log(&format!("Our spaceship has been hit at {}!", shot)),
+                HitType::Destroyed(_) => LOGGER.log(&format!("One of your ships was destroyed at {}!", shot)),
+                _ => {},
+            }
+        }
+        self.state = result.state;
+
+        LOGGER.log("Generating salvo proof...");
+        let proof = create_salvo_proof(self.backend, &params).expect("Error while creating the salvo proof!");
+        Ok(Ok(proof))
+    }
+
+    async fn handle_scout(&mut self) -> Result<Vec<u8>> {
         LOGGER.log("⚠⚠⚠ The enemy is using scan! ⚠⚠⚠");
 
-        let pos = get_coords_from_server(&mut self.stream).await?;
+        let pos = get_coords_from_server(&mut self.channel).await?;
         assert!(pos.check());
         
         // P1 update state
         let params = ScoutParams{ state: self.state.clone(), shot: pos };
         LOGGER.log("Generating scout proof...");
-        let receipt = create_scout_proof(&params).expect("Failed scout proof");
-        Ok(receipt)
+        let proof = create_scout_proof(self.backend, &params).expect("Failed scout proof");
+        let result = params.process();
+
+        self.state = result.state;
+        Ok(proof)
     }
 
-    async fn handle_cluster(&mut self) -> Result<Receipt> {
-        self.enemy_cluster -= 1;
+    async fn handle_cluster(&mut self) -> Result<std::result::Result<Vec<u8>, Violation>> {
         LOGGER.log("⚠⚠⚠ CLUSTER CHARGE INBOUND ⚠⚠⚠");
 
         // P1 recieves hit
-        let msg = get_cluster_message(&mut self.stream).await?;
-        msg.check();
+        let msg = get_cluster_message(&mut self.channel).await?;
+        if let Err(e) = msg.check() {
+            return Ok(Err(Violation::InvalidClusterArea(e)));
+        }
 
         // P1 update state
         let params = ClusterBombParams::new(self.state.clone(), msg.ul.clone(), msg.dr.clone(), msg.seed);
         LOGGER.log("Generating Cluster proof...");
-        let (receipt, shots) = create_cluster_proof(&params).expect("Failed cluster proof");
+        let (proof, shots) = create_cluster_proof(self.backend, &params).expect("Failed cluster proof");
         let result = params.process(&shots);
 
         self.state = result.state;
-        Ok(receipt)
+        Ok(Ok(proof))
     }
 
-    async fn do_enemy_turn(&mut self) -> Result<()> {
-        let action = self.stream.read_u8().await?;
+    async fn do_enemy_turn(&mut self) -> Result<Option<Violation>> {
+        let mut action = self.channel.read_u8().await?;
+        // Any chat comes before the action
+        while action == 5 {
+            if let Some(violation) = self.handle_chat().await? {
+                return Ok(Some(violation));
+            }
+            action = self.channel.read_u8().await?;
+        }
 
-        let receipt: Receipt;
-        match action {
-            1 => {
-                receipt = self.handle_simple_shot().await?;
+        // The guests refuse to prove an ability without charges, so catch it before proving
+        let proof = match action {
+            1 => self.handle_simple_shot().await?,
+            2 if self.state.scouts == 0 => return Ok(Some(Violation::ScoutsExhausted)),
+            2 => self.handle_scout().await?,
+            3 if self.state.cluster_bombs == 0 => return Ok(Some(Violation::ClusterBombsExhausted)),
+            3 => match self.handle_cluster().await? {
+                Ok(proof) => proof,
+                Err(violation) => return Ok(Some(violation)),
             },
-            2 => {
-                if self.enemy_scouts > 0 {
-                    receipt = self.handle_scout().await?;
-                } else {
-                    println!("Player is cheating. Disconnecting...");
-                    std::process::exit(0);
-                }
+            4 if self.mode == GameMode::Salvo => match self.handle_salvo().await? {
+                Ok(proof) => proof,
+                Err(violation) => return Ok(Some(violation)),
             },
-            3 => {
-                if self.enemy_cluster > 0 {
-                    receipt = self.handle_cluster().await?;
-                } else {
-                    println!("Player is cheating. Disconnecting...");
-                    std::process::exit(0);
-                    
-                }
-            }
-            _ => {
-                return Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "Error"))
-            }
-        }
+            _ => return Ok(Some(Violation::UnknownAction(action))),
+        };
 
-        let proof_bytes = serde_cbor::to_vec(&receipt).expect("Failed serialization for turn proof");
         // P1 send proof
-        chunk_write(&mut self.stream, &proof_bytes).await?;
+        chunk_write(&mut self.channel, &proof).await?;
 
-        let mut msg_bytes = [0; 2];
-        self.stream.read(&mut msg_bytes).await?;
-        LOGGER.log(&format!("Got response: {}", String::from_utf8(msg_bytes.to_vec()).unwrap()));
-        Ok(())
+        let response = read_ack(&mut self.channel).await?;
+        LOGGER.log(&format!("Got response: {}", response));
+        Ok(None)
     }
 }
@@ -0,0 +1,122 @@
+use std::{fs, io::{Error, ErrorKind, Result}, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use sha2::{Digest, Sha256};
+
+use crate::identity::verify_signature;
+use crate::net_utils::ACK_MESSAGE;
+use crate::session::LOSS_MESSAGE;
+
+const TRANSCRIPT_DOMAIN: &[u8] = b"sagittarius-transcript-v1";
+pub const TRANSCRIPT_DIR: &str = "transcripts";
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()).collect()
+}
+
+/// Every application message exchanged over a secure channel, kept per sender.
+/// Each direction of a TCP stream is ordered on its own, so splitting by sender
+/// gives both players the same transcript regardless of how the two directions interleave.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Transcript {
+    pub initiator: [u8; 32],
+    pub responder: [u8; 32],
+    pub initiator_messages: Vec<ByteBuf>,
+    pub responder_messages: Vec<ByteBuf>,
+}
+
+impl Transcript {
+    pub fn new(initiator: [u8; 32], responder: [u8; 32]) -> Self {
+        Transcript {
+            initiator,
+            responder,
+            ..Default::default()
+        }
+    }
+
+    pub fn record(&mut self, from_initiator: bool, message: &[u8]) {
+        let messages = if from_initiator {
+            &mut self.initiator_messages
+        } else {
+            &mut self.responder_messages
+        };
+        messages.push(ByteBuf::from(message.to_vec()));
+    }
+
+    /// The message both players sign at the end of the game.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(TRANSCRIPT_DOMAIN);
+        hasher.update(self.initiator);
+        hasher.update(self.responder);
+        for messages in [&self.initiator_messages, &self.responder_messages] {
+            hasher.update((messages.len() as u64).to_be_bytes());
+            for message in messages {
+                hasher.update((message.len() as u64).to_be_bytes());
+                hasher.update(message);
+            }
+        }
+        hasher.finalize().into()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignedTranscript {
+    pub transcript: Transcript,
+    pub initiator_signature: ByteBuf,
+    pub responder_signature: ByteBuf,
+}
+
+impl SignedTranscript {
+    pub fn verify(&self) -> bool {
+        let digest = self.transcript.digest();
+        verify_signature(&self.transcript.initiator, &digest, &self.initiator_signature)
+            && verify_signature(&self.transcript.responder, &digest, &self.responder_signature)
+    }
+
+    /// Identity of the player who sent the loss message, if the game was played to the end.
+    pub fn loser(&self) -> Option<[u8; 32]> {
+        let is_loss = |messages: &Vec<ByteBuf>| messages.last().map_or(false, |m| m.as_slice() == LOSS_MESSAGE.as_bytes());
+        if is_loss(&self.transcript.initiator_messages) {
+            Some(self.transcript.initiator)
+        } else if is_loss(&self.transcript.responder_messages) {
+            Some(self.transcript.responder)
+        } else {
+            None
+        }
+    }
+
+    /// Number of turns played, counted from the acknowledgements of both players.
+    pub fn moves(&self) -> u32 {
+        let transcript = &self.transcript;
+        let acks = transcript.initiator_messages.iter().chain(transcript.responder_messages.iter());
+        acks.filter(|m| m.as_slice() == ACK_MESSAGE.as_bytes()).count() as u32
+    }
+
+    pub fn path(digest: &str) -> PathBuf {
+        PathBuf::from(TRANSCRIPT_DIR).join(format!("{}.cbor", digest))
+    }
+
+    /// Stores the transcript under `transcripts/` named after its digest and returns the path.
+    pub fn save(&self) -> Result<PathBuf> {
+        fs::create_dir_all(TRANSCRIPT_DIR)?;
+        let path = Self::path(&hex(&self.transcript.digest()));
+        fs::write(&path, serde_cbor::to_vec(self).expect("Failed serialization"))?;
+        Ok(path)
+    }
+
+    /// Loads the transcript with the given hex digest from `transcripts/`.
+    pub fn load(digest: &str) -> Result<Self> {
+        let bytes = fs::read(Self::path(digest))?;
+        serde_cbor::from_slice(&bytes).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
@@ -1,65 +1,113 @@
-use crate::{board::HitMap, LOGGER};
+use crate::{board::HitMap, interface::{restore_terminal, set_input, set_menu, set_target_cursor}, LOGGER};
 
-use sagittarius_game::types::{Position, HitType};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use lazy_static::lazy_static;
+use sagittarius_game::{types::{Position, HitType}, BOARD_SIZE};
 
-use std::io::Write;
+use std::sync::Mutex;
 
-pub fn get_coords_from_stdin(hit_map: Option<&HitMap>) -> Position {
-    loop {
-        let mut input = String::new();
-        print!("Enter coordinates (col row): ");
-        std::io::stdout().flush().unwrap();
-        std::io::stdin().read_line(&mut input).expect("Failed to read input");
+lazy_static! {
+    // Remember where the cursor was so consecutive shots start from the last target
+    static ref LAST_TARGET: Mutex<Position> = Mutex::new(Position::new(BOARD_SIZE as u32 / 2, BOARD_SIZE as u32 / 2));
+}
 
-        let mut parts = input.trim().split_whitespace();
+fn read_key() -> KeyEvent {
+    loop {
+        if let Event::Key(key) = event::read().expect("Failed to read input") {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            // Raw mode swallows SIGINT, so handle Ctrl-C ourselves
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                restore_terminal();
+                std::process::exit(0);
+            }
+            return key;
+        }
+    }
+}
 
-        let col_part = parts.next();
-        let row_part = parts.next();
+/// Shows `options` in the action menu and returns the index of the chosen one.
+/// Options can be picked with the arrow keys and Enter or with their number.
+pub fn select_option(title: &str, options: &[String]) -> usize {
+    let mut selected = 0;
+    loop {
+        set_menu(title, options, selected);
 
-        if col_part.is_none() || row_part.is_none() {
-            LOGGER.log("Please provide exactly 2 values (column letter and row number).");
-            continue;
+        match read_key().code {
+            KeyCode::Up | KeyCode::Char('k') => selected = selected.checked_sub(1).unwrap_or(options.len() - 1),
+            KeyCode::Down | KeyCode::Char('j') => selected = (selected + 1) % options.len(),
+            KeyCode::Enter => break,
+            KeyCode::Char(c) => match c.to_digit(10) {
+                Some(n) if n >= 1 && n as usize <= options.len() => {
+                    selected = n as usize - 1;
+                    break;
+                },
+                _ => {},
+            },
+            _ => {},
         }
+    }
+    set_menu("", &[], 0);
+    selected
+}
 
-        let col_str = col_part.unwrap();
-        let row_str = row_part.unwrap();
+pub fn read_line(label: &str) -> String {
+    let mut input = String::new();
+    loop {
+        set_input(Some((label, &input)));
 
-        if col_str.len() != 1 || !col_str.chars().all(char::is_alphabetic) {
-            LOGGER.log("Column should be a single letter.");
-            continue;
+        match read_key().code {
+            KeyCode::Enter => break,
+            KeyCode::Backspace => {
+                input.pop();
+            },
+            KeyCode::Char(c) => input.push(c),
+            _ => {},
         }
+    }
+    set_input(None);
+    input
+}
 
-        let row: u32 = match row_str.parse() {
-            Ok(n) => n,
-            Err(_) => {
-                LOGGER.log("Row should be a number.");
-                continue;
-            }
-        };
-
-        let x = col_str.chars().next().unwrap().to_ascii_lowercase() as u32 - 'a' as u32;
-        let y = row - 1;
+/// Starts the next target selection from `pos`.
+pub fn suggest_target(pos: Position) {
+    *LAST_TARGET.lock().unwrap() = pos;
+}
 
-        let pos = Position::new(x, y);
+/// Moves a cursor over the enemy board until Enter is pressed and returns the chosen cell.
+/// With a `hit_map`, cells that were already shot (but not only revealed) are rejected.
+pub fn select_target(hit_map: Option<&HitMap>) -> Position {
+    let limit = BOARD_SIZE as u32 - 1;
+    let mut pos = LAST_TARGET.lock().unwrap().clone();
+    set_menu("Target", &["Arrows/hjkl: move cursor".to_string(), "Enter/Space: fire".to_string()], 0);
 
-        if !pos.check() {
-            LOGGER.log("Coords are out of board");
-            continue;
-        }
+    loop {
+        set_target_cursor(Some(pos.clone()));
 
-        if let Some(hit_map) = hit_map {
-            if let Some(hit) = hit_map.get(&pos) {
-                match hit {
-                    HitType::Revealed => {
-                        return pos;
-                    },
-                    _ => {
-                        LOGGER.log("Hit exists");
-                        continue;
+        match read_key().code {
+            KeyCode::Left | KeyCode::Char('h') => pos.x = pos.x.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => pos.x = (pos.x + 1).min(limit),
+            KeyCode::Up | KeyCode::Char('k') => pos.y = pos.y.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => pos.y = (pos.y + 1).min(limit),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(hit_map) = hit_map {
+                    match hit_map.get(&pos) {
+                        None | Some(HitType::Revealed) => {},
+                        Some(_) => {
+                            LOGGER.log("Hit exists");
+                            continue;
+                        }
                     }
                 }
-            }
+                break;
+            },
+            _ => {},
         }
-        return pos;
     }
-}
\ No newline at end of file
+
+    set_target_cursor(None);
+    set_menu("", &[], 0);
+    *LAST_TARGET.lock().unwrap() = pos.clone();
+    pos
+}
@@ -0,0 +1,58 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sagittarius_game::{
+    abilities::{ClusterBombParams, SalvoParams, ScoutParams, ShotParams},
+    state::GameState,
+    MAX_CLUSTER_BOMBS, MAX_SCOUTS,
+};
+
+/// Turns a decoded state into one the init guest could have committed,
+/// or `None` if the fleet itself is invalid.
+fn fresh(state: &GameState) -> Option<GameState> {
+    let mut state = state.clone();
+    for ship in state.ships.iter_mut() {
+        ship.hit_mask = 0;
+    }
+    state.scouts = MAX_SCOUTS;
+    state.cluster_bombs = MAX_CLUSTER_BOMBS;
+    state.check().then_some(state)
+}
+
+fuzz_target!(|data: &[u8]| {
+    // Decoding must never panic, whatever the peer sends
+    if let Ok(params) = serde_cbor::from_slice::<ShotParams>(data) {
+        if let Some(state) = fresh(&params.state) {
+            if params.shot.check() {
+                let result = ShotParams { state, shot: params.shot }.process();
+                assert!(result.state.salt == params.state.salt);
+            }
+        }
+    }
+
+    if let Ok(params) = serde_cbor::from_slice::<ScoutParams>(data) {
+        if let Some(state) = fresh(&params.state) {
+            if params.shot.check() {
+                let result = ScoutParams { state, shot: params.shot }.process();
+                assert!(result.state.scouts == MAX_SCOUTS - 1);
+            }
+        }
+    }
+
+    if let Ok(params) = serde_cbor::from_slice::<ClusterBombParams>(data) {
+        // Validation must reject bad rectangles without panicking
+        if let (Some(state), Ok(number_of_shots)) = (fresh(&params.state), params.check()) {
+            let params = ClusterBombParams { state, ..params };
+            let shots = params.area().positions().into_iter().take(number_of_shots).collect();
+            let result = params.process(&shots);
+            assert!(result.hits.len() == shots.len());
+        }
+    }
+
+    if let Ok(params) = serde_cbor::from_slice::<SalvoParams>(data) {
+        if let (Some(state), true) = (fresh(&params.state), params.check()) {
+            let result = SalvoParams { state, shots: params.shots.clone() }.process();
+            assert!(result.hits.len() == params.shots.len());
+        }
+    }
+});
@@ -1,8 +1,90 @@
 extern crate alloc;
 
+use core::fmt::Display;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{types::{Position, Digest, HitType}, state::{GameState, ShipDirection}, NUM_SHIPS, SHIP_SPANS, SUNK_MASKS};
+use crate::{types::{Position, Digest, HitType}, state::GameState, topology::{Square, Topology}, sunk_mask, NUM_SHIPS, SHIP_SPANS};
+
+/// Largest rectangle a cluster charge can cover
+pub const MAX_CLUSTER_AREA: u32 = 14;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClusterAreaError {
+    /// A corner lies outside the board
+    OffBoard,
+    /// The down right corner is above or left of the upper left one
+    Inverted,
+    /// The rectangle is a single row or column
+    TooThin,
+    /// The rectangle covers more than `MAX_CLUSTER_AREA` cells
+    TooLarge(u32),
+}
+
+impl Display for ClusterAreaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ClusterAreaError::OffBoard => write!(f, "a corner is outside the board"),
+            ClusterAreaError::Inverted => write!(f, "the corners are swapped"),
+            ClusterAreaError::TooThin => write!(f, "both sides must be at least 2 cells long"),
+            ClusterAreaError::TooLarge(area) => write!(f, "area {} is larger than {}", area, MAX_CLUSTER_AREA),
+        }
+    }
+}
+
+/// Rectangle targeted by a cluster charge, corners included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClusterArea {
+    pub ul: Position,
+    pub dr: Position,
+}
+
+impl ClusterArea {
+    pub fn new(ul: Position, dr: Position) -> Self {
+        ClusterArea { ul, dr }
+    }
+
+    /// Checks the rectangle and returns the number of shots it gets.
+    /// This is the only cluster rule, shared by the client prompt, the defending client and the guest.
+    pub fn validate(&self) -> Result<usize, ClusterAreaError> {
+        if !self.ul.check() || !self.dr.check() {
+            return Err(ClusterAreaError::OffBoard);
+        }
+        if self.dr.x < self.ul.x || self.dr.y < self.ul.y {
+            return Err(ClusterAreaError::Inverted);
+        }
+
+        let width = self.dr.x - self.ul.x + 1;
+        let height = self.dr.y - self.ul.y + 1;
+        if width < 2 || height < 2 {
+            return Err(ClusterAreaError::TooThin);
+        }
+
+        // With both sides at least 2 the area is 4 or 6..=14
+        match width * height {
+            4 => Ok(1),
+            6..=8 => Ok(2),
+            area if area <= MAX_CLUSTER_AREA => Ok(3),
+            area => Err(ClusterAreaError::TooLarge(area)),
+        }
+    }
+
+    pub fn contains(&self, position: &Position) -> bool {
+        position.x >= self.ul.x && position.x <= self.dr.x
+            && position.y >= self.ul.y && position.y <= self.dr.y
+    }
+
+    /// Every cell of the rectangle, column by column.
+    pub fn positions(&self) -> alloc::vec::Vec<Position> {
+        let mut positions = alloc::vec![];
+        for x in self.ul.x..=self.dr.x {
+            for y in self.ul.y..=self.dr.y {
+                positions.push(Position::new(x, y));
+            }
+        }
+        positions
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ClusterBombParams {
@@ -22,30 +104,24 @@ impl ClusterBombParams {
         }
     }
 
-    pub fn check(&self) -> usize {
-        let width = self.down_right_coordinates.x - self.upper_left_coordinates.x + 1;
-        let height = self.down_right_coordinates.y - self.upper_left_coordinates.y + 1;
-        assert!(width > 0 && height > 0);
-
-        match width * height {
-            4 => 1,
-            6..=8 => 2,
-            9..=14 => 3,
-            _ => panic!("The area is too big!")
-        }
+    pub fn area(&self) -> ClusterArea {
+        ClusterArea::new(self.upper_left_coordinates.clone(), self.down_right_coordinates.clone())
     }
 
-    fn check_in_range(&self, position: &Position) {
-        assert!(position.x >= self.upper_left_coordinates.x && position.x <= self.down_right_coordinates.x);
-        assert!(position.y >= self.upper_left_coordinates.y && position.y <= self.down_right_coordinates.y);
+    /// Number of shots for this charge, see `ClusterArea::validate`.
+    pub fn check(&self) -> Result<usize, ClusterAreaError> {
+        self.area().validate()
     }
 
     pub fn process(&self, positions: &alloc::vec::Vec<Position>) -> ClusterResults {
+        assert!(self.state.cluster_bombs > 0, "No cluster charges left");
+        let area = self.area();
         let mut state = self.state.clone();
+        state.cluster_bombs -= 1;
         let mut hits = alloc::vec![];
 
         for pos in positions {
-            self.check_in_range(&pos);
+            assert!(area.contains(pos));
 
             let params = ShotParams::new(state.clone(), pos.x, pos.y);
             let result = params.process();
@@ -72,6 +148,8 @@ pub struct ClusterCommit {
     pub hits: alloc::vec::Vec<HitType>,
 }
 
+/// Scouts look at the four orthogonal neighbours of the shot, like cluster rectangles they are only
+/// defined for the square board.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ScoutParams {
     pub state: GameState,
@@ -80,46 +158,32 @@ pub struct ScoutParams {
 
 impl ScoutParams {
     pub fn process(&self) -> ScoutResult {
+        self.process_with(&SHIP_SPANS)
+    }
+
+    /// Scouts over a fleet with other spans than `SHIP_SPANS`, for balance simulations.
+    pub fn process_with(&self, spans: &[usize; NUM_SHIPS]) -> ScoutResult {
+        assert!(self.state.scouts > 0, "No scout charges left");
+        let mut state = self.state.clone();
+        state.scouts -= 1;
+
         let mut cells = [HitType::Miss, HitType::Miss, HitType::Miss, HitType::Miss];
-        let positions = [Position::new(self.shot.x - 1, self.shot.y),
-                         Position::new(self.shot.x, self.shot.y - 1),
+        assert!(self.shot.check());
+        // Neighbours off the left/top edge wrap around to u32::MAX and are never revealed
+        let positions = [Position::new(self.shot.x.wrapping_sub(1), self.shot.y),
+                         Position::new(self.shot.x, self.shot.y.wrapping_sub(1)),
                          Position::new(self.shot.x + 1, self.shot.y),
                          Position::new(self.shot.x, self.shot.y + 1)];
 
         for (pos, cell) in positions.iter().zip(cells.iter_mut()) {
-            for i in 0..NUM_SHIPS {
-                let ship = &self.state.ships[i];
-                let span = SHIP_SPANS[i] as u32;
-                let x = ship.pos.x;
-                let y = ship.pos.y;
-                let hit = match ship.dir {
-                    ShipDirection::Horizontal => {
-                        if pos.y == y && pos.x >= x && pos.x < x + span {
-                            HitType::Revealed
-                        } else {
-                            HitType::Miss
-                        }
-                    }
-                    ShipDirection::Vertical => {
-                        if pos.x == x && pos.y >= y && pos.y < y + span {
-                            HitType::Revealed
-                        } else {
-                            HitType::Miss
-                        }
-                    }
-                };
-
-                match hit {
-                    HitType::Revealed => {
-                        *cell = hit;
-                        break;
-                    },
-                    _ => {},
-                }
+            let revealed = self.state.ships.iter().enumerate().any(|(i, ship)| ship.segment(pos, spans[i]).is_some());
+            if revealed {
+                *cell = HitType::Revealed;
             }
         }
 
         ScoutResult {
+            state,
             cells,
             shot: self.shot.clone()
         }
@@ -128,10 +192,19 @@ impl ScoutParams {
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ScoutResult {
+    pub state: GameState,
     pub cells: [HitType; 4],
     pub shot: Position,
 }
 
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ScoutCommit {
+    pub old_state_digest: Digest,
+    pub new_state_digest: Digest,
+    pub shot: Position,
+    pub cells: [HitType; 4],
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct ShotParams {
     pub state: GameState,
@@ -146,38 +219,28 @@ impl ShotParams {
         }
     }
 
+    /// The shot on the classic square board.
     pub fn process(&self) -> ShotResult {
+        self.process_on(&Square)
+    }
+
+    pub fn process_on(&self, topology: &impl Topology) -> ShotResult {
+        self.process_with(topology, &SHIP_SPANS)
+    }
+
+    /// The shot against a fleet with other spans than `SHIP_SPANS`, for balance simulations.
+    pub fn process_with(&self, topology: &impl Topology, spans: &[usize; NUM_SHIPS]) -> ShotResult {
         let mut state = self.state.clone();
         let shot = &self.shot;
-        assert!(shot.check());
-        enum HitShift {
-            Hit(u32),
-            Miss,
-        }
+        assert!(topology.contains(shot));
         for i in 0..NUM_SHIPS {
             let ship = &mut state.ships[i];
-            let span = SHIP_SPANS[i] as u32;
-            let x = ship.pos.x;
-            let y = ship.pos.y;
-            let hit_shift = match ship.dir {
-                ShipDirection::Horizontal => {
-                    if shot.y == y && shot.x >= x && shot.x < x + span {
-                        HitShift::Hit(shot.x - x)
-                    } else {
-                        HitShift::Miss
-                    }
-                }
-                ShipDirection::Vertical => {
-                    if shot.x == x && shot.y >= y && shot.y < y + span {
-                        HitShift::Hit(shot.y - y)
-                    } else {
-                        HitShift::Miss
-                    }
+            if let Some(shift) = ship.segment(shot, spans[i]) {
+                if ship.hit_mask & (1 << shift) != 0 {
+                    return ShotResult::new(self.state.clone(), HitType::AlreadyHit);
                 }
-            };
-            if let HitShift::Hit(shift) = hit_shift {
                 ship.hit_mask |= 1 << shift;
-                let hit = if ship.hit_mask == SUNK_MASKS[i] {
+                let hit = if ship.hit_mask == sunk_mask(spans[i]) {
                     HitType::Destroyed(i as u8)
                 } else {
                     HitType::Hit
@@ -210,4 +273,54 @@ pub struct ShotCommit {
     pub new_state_digest: Digest,
     pub shot: Position,
     pub hit: HitType,
+}
+
+/// A Salvo turn: several shots fired at once and proven together.
+/// The defender only knows its own board, so it checks the shot count against the attacker's surviving ships.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SalvoParams {
+    pub state: GameState,
+    pub shots: alloc::vec::Vec<Position>,
+}
+
+impl SalvoParams {
+    pub fn new(state: GameState, shots: alloc::vec::Vec<Position>) -> Self {
+        SalvoParams { state, shots }
+    }
+
+    /// Between 1 and `NUM_SHIPS` distinct shots, all on the board.
+    pub fn check(&self) -> bool {
+        !self.shots.is_empty()
+            && self.shots.len() <= NUM_SHIPS
+            && self.shots.iter().all(|shot| shot.check())
+            && self.shots.iter().enumerate().all(|(i, shot)| !self.shots[..i].contains(shot))
+    }
+
+    pub fn process(&self) -> SalvoResults {
+        assert!(self.check());
+        let mut state = self.state.clone();
+        let mut hits = alloc::vec![];
+
+        for shot in &self.shots {
+            let result = ShotParams::new(state, shot.x, shot.y).process();
+            state = result.state;
+            hits.push(result.hit);
+        }
+
+        SalvoResults { state, hits }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SalvoResults {
+    pub state: GameState,
+    pub hits: alloc::vec::Vec<HitType>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SalvoCommit {
+    pub old_state_digest: Digest,
+    pub new_state_digest: Digest,
+    pub shots: alloc::vec::Vec<Position>,
+    pub hits: alloc::vec::Vec<HitType>,
 }
\ No newline at end of file
@@ -0,0 +1,253 @@
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use crate::{types::{HitType, Position}, BOARD_SIZE};
+
+// Cells are tracked as bits of a u64
+const _: () = assert!(BOARD_SIZE * BOARD_SIZE <= 64, "board doesn't fit in a mask");
+
+/// Placements tried before exact enumeration gives up and ships are placed independently instead.
+/// An empty board takes about half a second to enumerate in release builds
+pub const SEARCH_BUDGET: u64 = 100_000_000;
+
+type Mask = u64;
+
+fn bit(x: usize, y: usize) -> Mask {
+    1 << (y * BOARD_SIZE + x)
+}
+
+fn cells(mask: Mask) -> impl Iterator<Item = (usize, usize)> {
+    (0..BOARD_SIZE * BOARD_SIZE)
+        .filter(move |i| mask & (1 << i) != 0)
+        .map(|i| (i % BOARD_SIZE, i / BOARD_SIZE))
+}
+
+/// Every way a ship of the given span fits on the board.
+fn placements(span: usize) -> Vec<Mask> {
+    let mut result = vec![];
+    for y in 0..BOARD_SIZE {
+        for x in 0..BOARD_SIZE {
+            if x + span <= BOARD_SIZE {
+                result.push((0..span).fold(0, |mask, i| mask | bit(x + i, y)));
+            }
+            if y + span <= BOARD_SIZE {
+                result.push((0..span).fold(0, |mask, i| mask | bit(x, y + i)));
+            }
+        }
+    }
+    // A single cell ship is the same in both directions
+    result.sort_unstable();
+    result.dedup();
+    result
+}
+
+/// What the hit map says about each cell.
+#[derive(Default)]
+struct Knowledge {
+    /// Cells known to be empty
+    empty: Mask,
+    /// Cells shot with a ship in them
+    struck: Mask,
+    /// Cells with a ship that hasn't been shot yet
+    revealed: Mask,
+    /// Cell of the shot that destroyed each ship, by ship index
+    wrecks: Vec<(usize, Mask)>,
+}
+
+impl Knowledge {
+    fn new<'a>(hits: impl IntoIterator<Item = (&'a Position, &'a HitType)>) -> Self {
+        let mut knowledge = Knowledge::default();
+        for (pos, hit) in hits.into_iter().filter(|(pos, _)| pos.check()) {
+            let cell = bit(pos.x as usize, pos.y as usize);
+            match hit {
+                HitType::Miss => knowledge.empty |= cell,
+                HitType::Hit | HitType::AlreadyHit => knowledge.struck |= cell,
+                HitType::Revealed => knowledge.revealed |= cell,
+                HitType::Destroyed(i) => {
+                    knowledge.struck |= cell;
+                    knowledge.wrecks.push((*i as usize, cell));
+                },
+            }
+        }
+        knowledge
+    }
+
+    fn wreck_cells(&self) -> Mask {
+        self.wrecks.iter().fold(0, |mask, (_, cell)| mask | cell)
+    }
+
+    /// Where a ship reported destroyed can be: over its wreck and only over struck cells.
+    fn sunk_candidates(&self, ship: usize, span: usize) -> Vec<Mask> {
+        let own = self.wrecks.iter().filter(|(i, _)| *i == ship).fold(0, |mask, (_, cell)| mask | cell);
+        let others = self.wreck_cells() & !own;
+        placements(span)
+            .into_iter()
+            .filter(|p| p & own == own && p & !self.struck == 0 && p & others == 0)
+            .collect()
+    }
+
+    /// Where a surviving ship can be: clear of misses and wrecks, and not already shot in every cell.
+    fn live_candidates(&self, span: usize) -> Vec<Mask> {
+        let blocked = self.empty | self.wreck_cells();
+        placements(span)
+            .into_iter()
+            .filter(|p| p & blocked == 0 && p & !self.struck != 0)
+            .collect()
+    }
+}
+
+struct Search {
+    ships: Vec<Vec<Mask>>,
+    /// Spans of the ships after each depth, for pruning
+    spans_left: Vec<u32>,
+    required: Mask,
+    /// Number of complete fleets using each candidate, by depth
+    tally: Vec<Vec<u64>>,
+    budget: u64,
+}
+
+impl Search {
+    /// Counts the fleets that complete `occupied`, or `None` once the budget runs out.
+    fn count(&mut self, depth: usize, occupied: Mask) -> Option<u64> {
+        if depth == self.ships.len() {
+            return Some((occupied & self.required == self.required) as u64);
+        }
+        // Every known ship cell still uncovered needs one of the remaining ships
+        if (self.required & !occupied).count_ones() > self.spans_left[depth] {
+            return Some(0);
+        }
+
+        let mut total = 0;
+        for i in 0..self.ships[depth].len() {
+            self.budget = self.budget.checked_sub(1)?;
+            let placement = self.ships[depth][i];
+            if placement & occupied != 0 {
+                continue;
+            }
+            let completions = self.count(depth + 1, occupied | placement)?;
+            self.tally[depth][i] += completions;
+            total += completions;
+        }
+        Some(total)
+    }
+}
+
+/// Chance of a ship being in each cell of the enemy board, given everything we have learned so far.
+pub struct HeatMap {
+    probabilities: [[f64; BOARD_SIZE]; BOARD_SIZE],
+    /// Cells that are still worth shooting at
+    open: Mask,
+    /// Whether the probabilities come from whole consistent fleets rather than ships placed independently
+    pub exact: bool,
+}
+
+impl HeatMap {
+    /// `hits` is everything learned about the enemy board so far, usually a map from cell to result.
+    /// `spans` describes the whole enemy fleet, indexed like `HitType::Destroyed`. Ships reported destroyed
+    /// are placed over their wreck, the remaining ones anywhere consistent with the hit map.
+    pub fn new<'a>(hits: impl IntoIterator<Item = (&'a Position, &'a HitType)>, spans: &[usize]) -> Self {
+        HeatMap::with_budget(hits, spans, SEARCH_BUDGET)
+    }
+
+    /// Like `new`, but falls back to independent placement after trying `budget` placements.
+    /// Bots playing many games want a much smaller budget than an interactive player.
+    pub fn with_budget<'a>(hits: impl IntoIterator<Item = (&'a Position, &'a HitType)>, spans: &[usize], budget: u64) -> Self {
+        let knowledge = Knowledge::new(hits);
+        let open = !(knowledge.empty | knowledge.struck) & Mask::MAX >> (64 - BOARD_SIZE * BOARD_SIZE);
+
+        let sunk = |i: usize| knowledge.wrecks.iter().any(|(ship, _)| *ship == i);
+        let mut ships = vec![];
+        let mut live = vec![];
+        for (i, span) in spans.iter().enumerate() {
+            if sunk(i) {
+                ships.push((*span, knowledge.sunk_candidates(i, *span)));
+            } else {
+                live.push((*span, knowledge.live_candidates(*span)));
+            }
+        }
+        // Wrecks have only a few placements, so fixing them first prunes the rest of the search early
+        live.sort_by_key(|(_, candidates)| candidates.len());
+        ships.extend(live.iter().cloned());
+
+        let exact = HeatMap::enumerate(&knowledge, &ships, budget);
+        let probabilities = exact.unwrap_or_else(|| HeatMap::independent(&knowledge, &live));
+
+        HeatMap { probabilities, open, exact: exact.is_some() }
+    }
+
+    /// Counts every fleet consistent with the hit map. `None` if there are too many or none at all.
+    fn enumerate(knowledge: &Knowledge, ships: &[(usize, Vec<Mask>)], budget: u64) -> Option<[[f64; BOARD_SIZE]; BOARD_SIZE]> {
+        let spans_left = (0..=ships.len())
+            .map(|depth| ships[depth..].iter().map(|(span, _)| *span as u32).sum())
+            .collect();
+        let mut search = Search {
+            ships: ships.iter().map(|(_, candidates)| candidates.clone()).collect(),
+            spans_left,
+            required: knowledge.struck | knowledge.revealed,
+            tally: ships.iter().map(|(_, candidates)| vec![0; candidates.len()]).collect(),
+            budget,
+        };
+
+        let total = search.count(0, 0)?;
+        if total == 0 {
+            return None;
+        }
+
+        let mut probabilities = [[0.0; BOARD_SIZE]; BOARD_SIZE];
+        for (candidates, tally) in search.ships.iter().zip(search.tally.iter()) {
+            for (placement, count) in candidates.iter().zip(tally.iter()) {
+                for (x, y) in cells(*placement) {
+                    probabilities[y][x] += *count as f64 / total as f64;
+                }
+            }
+        }
+        Some(probabilities)
+    }
+
+    /// Places every surviving ship on its own, ignoring the others. Placements over cells known to hold
+    /// a ship count for more, so wounded ships still get finished off.
+    fn independent(knowledge: &Knowledge, live: &[(usize, Vec<Mask>)]) -> [[f64; BOARD_SIZE]; BOARD_SIZE] {
+        let known = knowledge.struck | knowledge.revealed;
+        let mut misses = [[1.0; BOARD_SIZE]; BOARD_SIZE];
+
+        for (_, candidates) in live {
+            let weights = candidates.iter().map(|p| 1 + 16 * (p & known).count_ones() as u64).collect::<Vec<_>>();
+            let total = weights.iter().sum::<u64>() as f64;
+            let mut ship = [[0.0; BOARD_SIZE]; BOARD_SIZE];
+            for (placement, weight) in candidates.iter().zip(weights.iter()) {
+                for (x, y) in cells(*placement) {
+                    ship[y][x] += *weight as f64 / total;
+                }
+            }
+            for (y, row) in ship.iter().enumerate() {
+                for (x, p) in row.iter().enumerate() {
+                    misses[y][x] *= 1.0 - p.min(1.0);
+                }
+            }
+        }
+
+        misses.map(|row| row.map(|miss| 1.0 - miss))
+    }
+
+    /// Chance of a ship in the cell. Cells that were already shot are 0, revealed ones 1 when exact.
+    pub fn probability(&self, pos: &Position) -> f64 {
+        if !pos.check() || self.open & bit(pos.x as usize, pos.y as usize) == 0 {
+            return 0.0;
+        }
+        self.probabilities[pos.y as usize][pos.x as usize]
+    }
+
+    /// The open cell most likely to hold a ship, first in reading order on ties.
+    pub fn best(&self) -> Option<Position> {
+        let mut best: Option<(Position, f64)> = None;
+        for (x, y) in cells(self.open) {
+            let pos = Position::new(x as u32, y as u32);
+            let p = self.probability(&pos);
+            if best.as_ref().map_or(true, |(_, q)| p > *q) {
+                best = Some((pos, p));
+            }
+        }
+        best.map(|(pos, _)| pos)
+    }
+}
@@ -0,0 +1,29 @@
+//! A fixed fleet for tests, examples and snapshots that need a known, valid board.
+
+use crate::state::{GameState, Ship, ShipDirection};
+
+/// The mothership along the top edge, the carrier down the left edge, the tempest in the
+/// middle and the void ray in the bottom right corner:
+///
+/// ```text
+///   a b c d e f g h
+/// 1 A A A A
+/// 2
+/// 3 B   C C C
+/// 4 B
+/// 5 B
+/// 6
+/// 7               D
+/// 8               D
+/// ```
+pub fn sample_state(salt: u32) -> GameState {
+    GameState::new(
+        [
+            Ship::new(0, 0, ShipDirection::Horizontal),
+            Ship::new(0, 2, ShipDirection::Vertical),
+            Ship::new(2, 2, ShipDirection::Horizontal),
+            Ship::new(7, 6, ShipDirection::Vertical),
+        ],
+        salt,
+    )
+}
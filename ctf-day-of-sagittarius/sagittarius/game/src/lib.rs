@@ -2,6 +2,10 @@
 pub mod types;
 pub mod state;
 pub mod abilities;
+pub mod topology;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod advisor;
 
 pub const NUM_SHIPS: usize = 4;
 pub const BOARD_SIZE: usize = 8;
@@ -13,4 +17,12 @@ pub const SHIP_SPANS: [usize; NUM_SHIPS] = [
     2, // Void ray
 ];
 
-pub const SUNK_MASKS: [u8; NUM_SHIPS] = [0x0f, 0x07, 0x07, 0x03];
\ No newline at end of file
+pub const SUNK_MASKS: [u8; NUM_SHIPS] = [0x0f, 0x07, 0x07, 0x03];
+
+/// Hit mask of a destroyed ship of the given span, `SUNK_MASKS` for fleets other than `SHIP_SPANS`.
+pub const fn sunk_mask(span: usize) -> u8 {
+    ((1u16 << span) - 1) as u8
+}
+
+pub const MAX_SCOUTS: u8 = 1;
+pub const MAX_CLUSTER_BOMBS: u8 = 1;
\ No newline at end of file
@@ -1,11 +1,24 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{types::Position, BOARD_SIZE, NUM_SHIPS, SHIP_SPANS};
+use crate::{topology::{Square, Topology}, types::Position, BOARD_SIZE, MAX_CLUSTER_BOMBS, MAX_SCOUTS, NUM_SHIPS, SHIP_SPANS, SUNK_MASKS};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub enum ShipDirection {
     Horizontal,
     Vertical,
+    /// Third axis of the hex board, see `topology::Hex`
+    Diagonal,
+}
+
+impl ShipDirection {
+    /// Step from one cell of a ship to the next.
+    pub fn delta(&self) -> (u32, u32) {
+        match self {
+            ShipDirection::Horizontal => (1, 0),
+            ShipDirection::Vertical => (0, 1),
+            ShipDirection::Diagonal => (1, 1),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -32,17 +45,30 @@ impl Ship {
         }
     }
 
+    /// Cell `i` of the ship, counting from `pos`. `None` if it overflows the coordinates.
+    pub fn cell(&self, i: u32) -> Option<Position> {
+        let (dx, dy) = self.dir.delta();
+        let x = self.pos.x.checked_add(dx.checked_mul(i)?)?;
+        let y = self.pos.y.checked_add(dy.checked_mul(i)?)?;
+        Some(Position::new(x, y))
+    }
+
+    /// Which cell of the ship covers `pos`, if any.
+    pub fn segment(&self, pos: &Position, span: usize) -> Option<u32> {
+        (0..span as u32).find(|i| self.cell(*i).as_ref() == Some(pos))
+    }
+
+    /// Undamaged and placed on the classic square board.
     pub fn check(&self, span: usize) -> bool {
-        let limit = BOARD_SIZE - span;
-        self.hit_mask == 0
-            && self.pos.check()
-            && match self.dir {
-                ShipDirection::Horizontal => self.pos.x as usize <= limit,
-                ShipDirection::Vertical => self.pos.y as usize <= limit,
-            }
+        self.check_on(&Square, span)
+    }
+
+    pub fn check_on(&self, topology: &impl Topology, span: usize) -> bool {
+        self.hit_mask == 0 && topology.fits(self, span)
     }
 }
 
+/// Tracks occupied cells while ships are placed one by one.
 pub struct GameCheck {
     board: [[bool; BOARD_SIZE]; BOARD_SIZE],
 }
@@ -53,44 +79,24 @@ impl GameCheck {
         GameCheck { board }
     }
 
+    /// Whether the ship is clear of the ones committed so far. Cells outside the coordinate square
+    /// never are, so check the ship against its topology first.
     pub fn check(&mut self, ship: &Ship, span: usize, commit: bool) -> bool {
-        let x = ship.pos.x as usize;
-        let y = ship.pos.y as usize;
-        for i in 0..span {
-            match ship.dir {
-                ShipDirection::Horizontal => {
-                    if self.board[y][x + i] {
-                        return false;
-                    }
-                    if commit {
-                        self.board[y][x + i] = true;
-                    }
-                }
-                ShipDirection::Vertical => {
-                    if self.board[y + i][x] {
-                        return false;
-                    }
-                    if commit {
-                        self.board[y + i][x] = true;
-                    }
-                }
+        for i in 0..span as u32 {
+            match ship.cell(i) {
+                Some(pos) if pos.check() && !self.board[pos.y as usize][pos.x as usize] => {},
+                _ => return false,
             }
         }
+        if commit {
+            self.commit(ship, span);
+        }
         true
     }
 
     pub fn commit(&mut self, ship: &Ship, span: usize) {
-        let x = ship.pos.x as usize;
-        let y = ship.pos.y as usize;
-        for i in 0..span {
-            match ship.dir {
-                ShipDirection::Horizontal => {
-                    self.board[y][x + i] = true;
-                }
-                ShipDirection::Vertical => {
-                    self.board[y + i][x] = true;
-                }
-            }
+        for pos in (0..span as u32).filter_map(|i| ship.cell(i)).filter(|pos| pos.check()) {
+            self.board[pos.y as usize][pos.x as usize] = true;
         }
     }
 }
@@ -99,15 +105,41 @@ impl GameCheck {
 pub struct GameState {
     pub ships: [Ship; NUM_SHIPS],
     pub salt: u32,
+    /// Scout charges the opponent can still use against this board
+    pub scouts: u8,
+    /// Cluster charges the opponent can still use against this board
+    pub cluster_bombs: u8,
 }
 
 impl GameState {
+    pub fn new(ships: [Ship; NUM_SHIPS], salt: u32) -> Self {
+        GameState {
+            ships,
+            salt,
+            scouts: MAX_SCOUTS,
+            cluster_bombs: MAX_CLUSTER_BOMBS,
+        }
+    }
+
+    /// Ships that are not destroyed yet. In Salvo mode this is how many shots the owner fires per turn.
+    pub fn surviving_ships(&self) -> usize {
+        self.ships.iter().enumerate().filter(|(i, ship)| ship.hit_mask != SUNK_MASKS[*i]).count()
+    }
+
+    /// A fresh fleet on the classic square board, as the init guest proves it.
     pub fn check(&self) -> bool {
+        self.check_on(&Square)
+    }
+
+    pub fn check_on(&self, topology: &impl Topology) -> bool {
+        if self.scouts != MAX_SCOUTS || self.cluster_bombs != MAX_CLUSTER_BOMBS {
+            return false;
+        }
         let mut game_check = GameCheck::new();
         for i in 0..NUM_SHIPS {
             let ship = &self.ships[i];
             let span = SHIP_SPANS[i];
-            if !ship.check(span) {
+            if !ship.check_on(topology, span) {
                 return false;
             }
             if !game_check.check(&ship, span, true) {
@@ -0,0 +1,111 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{state::{Ship, ShipDirection}, types::Position, BOARD_SIZE};
+
+/// The shape of a board: which cells can hold ships and along which axes ships are placed.
+/// Every topology lives inside the `BOARD_SIZE` x `BOARD_SIZE` coordinate square, so positions,
+/// commitments and renderers stay the same whatever the board looks like.
+pub trait Topology {
+    /// Whether the cell is on the board and open for ships.
+    fn contains(&self, pos: &Position) -> bool;
+
+    /// Axes a ship can be placed along.
+    fn directions(&self) -> &[ShipDirection];
+
+    /// Cells adjacent to `pos` that are on the board.
+    fn neighbours(&self, pos: &Position) -> Vec<Position>;
+
+    /// Whether a ship of the given span lies entirely on open cells along an allowed axis.
+    fn fits(&self, ship: &Ship, span: usize) -> bool {
+        self.directions().contains(&ship.dir)
+            && (0..span as u32).all(|i| matches!(ship.cell(i), Some(cell) if self.contains(&cell)))
+    }
+}
+
+/// Cells `pos + delta` for each delta that stay inside the topology.
+fn offsets(topology: &impl Topology, pos: &Position, deltas: &[(i32, i32)]) -> Vec<Position> {
+    deltas
+        .iter()
+        .filter_map(|(dx, dy)| {
+            let x = u32::try_from(pos.x as i64 + *dx as i64).ok()?;
+            let y = u32::try_from(pos.y as i64 + *dy as i64).ok()?;
+            Some(Position::new(x, y))
+        })
+        .filter(|cell| topology.contains(cell))
+        .collect()
+}
+
+/// The classic square board. Ships lie horizontally or vertically.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Square;
+
+impl Topology for Square {
+    fn contains(&self, pos: &Position) -> bool {
+        pos.check()
+    }
+
+    fn directions(&self) -> &[ShipDirection] {
+        &[ShipDirection::Horizontal, ShipDirection::Vertical]
+    }
+
+    fn neighbours(&self, pos: &Position) -> Vec<Position> {
+        offsets(self, pos, &[(-1, 0), (0, -1), (1, 0), (0, 1)])
+    }
+}
+
+/// A rhombus of hexagons. Each row is shifted half a cell right of the one above, so cell `(x, y)`
+/// touches `(x + 1, y + 1)` and ships can also lie along that third axis.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Hex;
+
+impl Topology for Hex {
+    fn contains(&self, pos: &Position) -> bool {
+        pos.check()
+    }
+
+    fn directions(&self) -> &[ShipDirection] {
+        &[ShipDirection::Horizontal, ShipDirection::Vertical, ShipDirection::Diagonal]
+    }
+
+    fn neighbours(&self, pos: &Position) -> Vec<Position> {
+        offsets(self, pos, &[(-1, 0), (0, -1), (-1, -1), (1, 0), (0, 1), (1, 1)])
+    }
+}
+
+/// Any board with some cells blocked by asteroids. Ships can't be placed over asteroids.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Asteroids<T: Topology> {
+    pub base: T,
+    blocked: [[bool; BOARD_SIZE]; BOARD_SIZE],
+}
+
+impl<T: Topology> Asteroids<T> {
+    /// Cells outside the base board are ignored.
+    pub fn new(base: T, asteroids: &[Position]) -> Self {
+        let mut blocked = [[false; BOARD_SIZE]; BOARD_SIZE];
+        for pos in asteroids.iter().filter(|pos| base.contains(pos)) {
+            blocked[pos.y as usize][pos.x as usize] = true;
+        }
+        Asteroids { base, blocked }
+    }
+
+    pub fn is_asteroid(&self, pos: &Position) -> bool {
+        pos.check() && self.blocked[pos.y as usize][pos.x as usize]
+    }
+}
+
+impl<T: Topology> Topology for Asteroids<T> {
+    fn contains(&self, pos: &Position) -> bool {
+        self.base.contains(pos) && !self.is_asteroid(pos)
+    }
+
+    fn directions(&self) -> &[ShipDirection] {
+        self.base.directions()
+    }
+
+    fn neighbours(&self, pos: &Position) -> Vec<Position> {
+        self.base.neighbours(pos).into_iter().filter(|cell| !self.is_asteroid(cell)).collect()
+    }
+}
@@ -34,4 +34,6 @@ pub enum HitType {
     Hit,
     Revealed,
     Destroyed(u8),
+    /// The shot landed on a ship cell that was already hit. The state is left unchanged
+    AlreadyHit,
 }
\ No newline at end of file
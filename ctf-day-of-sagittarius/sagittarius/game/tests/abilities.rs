@@ -0,0 +1,221 @@
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use sagittarius_game::{
+    fixtures::sample_state,
+    abilities::{ClusterArea, ClusterAreaError, ClusterBombParams, SalvoParams, ScoutParams, ShotParams},
+    types::{HitType, Position},
+    BOARD_SIZE, NUM_SHIPS, SHIP_SPANS, SUNK_MASKS,
+};
+
+fn arb_rectangle() -> impl Strategy<Value = (Position, Position)> {
+    (arb_position(), arb_position()).prop_map(|(a, b)| {
+        (
+            Position::new(a.x.min(b.x), a.y.min(b.y)),
+            Position::new(a.x.max(b.x), a.y.max(b.y)),
+        )
+    })
+}
+
+/// Corners anywhere, including off the board and past `u32` wraparound.
+fn arb_corner() -> impl Strategy<Value = Position> {
+    let coordinate = prop_oneof![0..BOARD_SIZE as u32 + 2, Just(u32::MAX)];
+    (coordinate.clone(), coordinate).prop_map(|(x, y)| Position::new(x, y))
+}
+
+proptest! {
+    #[test]
+    fn shot_matches_reference(state in arb_valid_state(), shot in arb_position()) {
+        let board = occupancy(&state).unwrap();
+        let result = ShotParams::new(state.clone(), shot.x, shot.y).process();
+
+        prop_assert_eq!(result.state.salt, state.salt);
+        prop_assert_eq!(result.state.scouts, state.scouts);
+        prop_assert_eq!(result.state.cluster_bombs, state.cluster_bombs);
+
+        match board.get(&(shot.x, shot.y)) {
+            None => {
+                prop_assert_eq!(result.hit, HitType::Miss);
+                prop_assert_eq!(&result.state, &state);
+            }
+            Some(&i) => {
+                prop_assert!(matches!(result.hit, HitType::Hit | HitType::Destroyed(_)));
+                for (j, ship) in result.state.ships.iter().enumerate() {
+                    if j == i {
+                        prop_assert_eq!(ship.hit_mask.count_ones(), 1);
+                    } else {
+                        prop_assert_eq!(ship, &state.ships[j]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn repeated_shot_does_not_change_state(state in arb_valid_state(), shot in arb_position()) {
+        let once = ShotParams::new(state, shot.x, shot.y).process();
+        let twice = ShotParams::new(once.state.clone(), shot.x, shot.y).process();
+        prop_assert_eq!(twice.state, once.state);
+    }
+
+    #[test]
+    fn repeated_hit_reports_already_hit(state in arb_valid_state(), shot in arb_position()) {
+        let once = ShotParams::new(state, shot.x, shot.y).process();
+        let twice = ShotParams::new(once.state.clone(), shot.x, shot.y).process();
+        let expected = match once.hit {
+            HitType::Miss => HitType::Miss,
+            _ => HitType::AlreadyHit,
+        };
+        prop_assert_eq!(twice.hit, expected);
+    }
+
+    #[test]
+    fn sinking_every_cell_destroys_ship(state in arb_valid_state(), i in 0..SHIP_SPANS.len()) {
+        let mut state = state;
+        let ship_cells = cells(&state.ships[i], SHIP_SPANS[i]);
+        let mut last = HitType::Miss;
+        for (x, y) in ship_cells {
+            let result = ShotParams::new(state, x, y).process();
+            state = result.state;
+            last = result.hit;
+        }
+        prop_assert_eq!(last, HitType::Destroyed(i as u8));
+        prop_assert_eq!(state.ships[i].hit_mask, SUNK_MASKS[i]);
+    }
+
+    #[test]
+    fn cluster_check_follows_area(state in arb_valid_state(), (ul, dr) in arb_rectangle(), seed in any::<u8>()) {
+        let (width, height) = (dr.x - ul.x + 1, dr.y - ul.y + 1);
+        prop_assume!(width >= 2 && height >= 2 && width * height <= 14);
+
+        let shots = ClusterBombParams::new(state, ul, dr, seed).check();
+        let expected = match width * height {
+            4 => 1,
+            6..=8 => 2,
+            _ => 3,
+        };
+        prop_assert_eq!(shots, Ok(expected));
+    }
+
+    #[test]
+    fn cluster_area_validation(ul in arb_corner(), dr in arb_corner()) {
+        let result = ClusterArea::new(ul.clone(), dr.clone()).validate();
+        let on_board = ul.check() && dr.check();
+        let ordered = dr.x >= ul.x && dr.y >= ul.y;
+
+        if !on_board {
+            prop_assert_eq!(result, Err(ClusterAreaError::OffBoard));
+        } else if !ordered {
+            prop_assert_eq!(result, Err(ClusterAreaError::Inverted));
+        } else {
+            let (width, height) = (dr.x - ul.x + 1, dr.y - ul.y + 1);
+            match result {
+                Ok(_) => prop_assert!(width >= 2 && height >= 2 && width * height <= 14),
+                Err(ClusterAreaError::TooThin) => prop_assert!(width < 2 || height < 2),
+                Err(ClusterAreaError::TooLarge(area)) => prop_assert!(area == width * height && area > 14),
+                Err(e) => prop_assert!(false, "unexpected {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn cluster_equals_sequential_shots(state in arb_valid_state(), (ul, dr) in arb_rectangle(), picks in prop::collection::vec(any::<prop::sample::Index>(), 1..4)) {
+        let area = (ul.x..=dr.x)
+            .flat_map(|x| (ul.y..=dr.y).map(move |y| Position::new(x, y)))
+            .collect::<Vec<_>>();
+        let shots = picks.iter().map(|i| i.get(&area).clone()).collect::<Vec<_>>();
+
+        let params = ClusterBombParams::new(state.clone(), ul, dr, 0);
+        let result = params.process(&shots);
+
+        let mut expected = state;
+        let mut hits = vec![];
+        for shot in &shots {
+            let shot_result = ShotParams::new(expected, shot.x, shot.y).process();
+            expected = shot_result.state;
+            hits.push(shot_result.hit);
+        }
+        expected.cluster_bombs -= 1;
+
+        prop_assert_eq!(result.state, expected);
+        prop_assert_eq!(result.hits, hits);
+    }
+
+    #[test]
+    fn salvo_equals_sequential_shots(state in arb_valid_state(), shots in prop::collection::hash_set(arb_position(), 1..=NUM_SHIPS)) {
+        let shots = shots.into_iter().collect::<Vec<_>>();
+        let result = SalvoParams::new(state.clone(), shots.clone()).process();
+
+        let mut expected = state;
+        let mut hits = vec![];
+        for shot in &shots {
+            let shot_result = ShotParams::new(expected, shot.x, shot.y).process();
+            expected = shot_result.state;
+            hits.push(shot_result.hit);
+        }
+
+        prop_assert_eq!(result.state, expected);
+        prop_assert_eq!(result.hits, hits);
+    }
+
+    #[test]
+    fn salvo_check_matches_shape(state in arb_valid_state(), shots in prop::collection::vec(arb_position(), 0..=NUM_SHIPS + 1)) {
+        let distinct = shots.iter().collect::<std::collections::HashSet<_>>().len() == shots.len();
+        let expected = !shots.is_empty() && shots.len() <= NUM_SHIPS && distinct;
+        prop_assert_eq!(SalvoParams::new(state, shots).check(), expected);
+    }
+
+    #[test]
+    fn scout_reveals_neighbours(state in arb_valid_state(), shot in arb_position()) {
+        let board = occupancy(&state).unwrap();
+        let result = ScoutParams { state: state.clone(), shot: shot.clone() }.process();
+
+        let neighbours = [
+            (shot.x.wrapping_sub(1), shot.y),
+            (shot.x, shot.y.wrapping_sub(1)),
+            (shot.x + 1, shot.y),
+            (shot.x, shot.y + 1),
+        ];
+        for (cell, hit) in neighbours.iter().zip(result.cells.iter()) {
+            let expected = if board.contains_key(cell) { HitType::Revealed } else { HitType::Miss };
+            prop_assert_eq!(*hit, expected);
+        }
+
+        prop_assert_eq!(result.shot, shot);
+        prop_assert_eq!(result.state.ships, state.ships);
+        prop_assert_eq!(result.state.scouts, state.scouts - 1);
+    }
+}
+
+#[test]
+#[should_panic]
+fn shot_off_the_board_panics() {
+    let state = sample_state(0);
+    ShotParams::new(state, BOARD_SIZE as u32, 0).process();
+}
+
+#[test]
+#[should_panic]
+fn cluster_outside_rectangle_panics() {
+    let state = sample_state(0);
+    let params = ClusterBombParams::new(state, Position::new(0, 0), Position::new(1, 1), 0);
+    params.process(&vec![Position::new(2, 2)]);
+}
+
+#[test]
+#[should_panic]
+fn scout_without_charges_panics() {
+    let mut state = sample_state(0);
+    state.scouts = 0;
+    ScoutParams { state, shot: Position::new(3, 3) }.process();
+}
+
+#[test]
+#[should_panic]
+fn cluster_without_charges_panics() {
+    let mut state = sample_state(0);
+    state.cluster_bombs = 0;
+    let params = ClusterBombParams::new(state, Position::new(0, 0), Position::new(1, 1), 0);
+    params.process(&vec![Position::new(0, 0)]);
+}
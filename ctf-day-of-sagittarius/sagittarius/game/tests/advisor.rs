@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use sagittarius_game::{
+    advisor::HeatMap,
+    types::{HitType, Position},
+    BOARD_SIZE, SHIP_SPANS,
+};
+
+type HitMap = HashMap<Position, HitType>;
+
+fn all_cells() -> impl Iterator<Item = Position> {
+    (0..BOARD_SIZE as u32).flat_map(|y| (0..BOARD_SIZE as u32).map(move |x| Position::new(x, y)))
+}
+
+/// Misses everywhere except the given cells.
+fn only_open(open: &[Position]) -> HitMap {
+    all_cells().filter(|pos| !open.contains(pos)).map(|pos| (pos, HitType::Miss)).collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{} is not {}", actual, expected);
+}
+
+#[test]
+fn one_ship_in_a_square_of_four() {
+    // A ship of span 2 has two horizontal and two vertical placements, each cell is in two of them
+    let square = [Position::new(3, 3), Position::new(4, 3), Position::new(3, 4), Position::new(4, 4)];
+    let heat = HeatMap::new(&only_open(&square), &[2]);
+
+    assert!(heat.exact);
+    for pos in all_cells() {
+        assert_close(heat.probability(&pos), if square.contains(&pos) { 0.5 } else { 0.0 });
+    }
+    assert_eq!(heat.best(), Some(Position::new(3, 3)));
+}
+
+#[test]
+fn two_ships_share_a_row_of_four() {
+    // Both ships of span 2 must fit in the row, which they only do side by side
+    let row = [Position::new(0, 0), Position::new(1, 0), Position::new(2, 0), Position::new(3, 0)];
+    let heat = HeatMap::new(&only_open(&row), &[2, 2]);
+
+    assert!(heat.exact);
+    for pos in &row {
+        assert_close(heat.probability(pos), 1.0);
+    }
+}
+
+#[test]
+fn wounded_ship_is_next_to_the_hit() {
+    let mut hits = HitMap::new();
+    hits.insert(Position::new(5, 5), HitType::Hit);
+    hits.insert(Position::new(4, 5), HitType::Miss);
+    let heat = HeatMap::new(&hits, &[2]);
+
+    // Only the cells around the hit complete the ship, and the hit itself is no longer worth a shot
+    assert!(heat.exact);
+    assert_close(heat.probability(&Position::new(5, 5)), 0.0);
+    assert_close(heat.probability(&Position::new(4, 5)), 0.0);
+    for pos in [Position::new(6, 5), Position::new(5, 4), Position::new(5, 6)] {
+        assert_close(heat.probability(&pos), 1.0 / 3.0);
+    }
+    assert_close(heat.probability(&Position::new(0, 0)), 0.0);
+    assert_eq!(heat.best(), Some(Position::new(5, 4)));
+}
+
+#[test]
+fn sunk_ship_stays_over_its_wreck() {
+    let mut hits = HitMap::new();
+    hits.insert(Position::new(0, 0), HitType::Hit);
+    hits.insert(Position::new(1, 0), HitType::Destroyed(0));
+    hits.insert(Position::new(6, 6), HitType::Hit);
+    let heat = HeatMap::new(&hits, &[2, 2]);
+
+    // The wreck accounts for (0, 0), so the other ship must be the one hit at (6, 6)
+    assert!(heat.exact);
+    assert_close(heat.probability(&Position::new(0, 1)), 0.0);
+    assert_close(heat.probability(&Position::new(2, 0)), 0.0);
+    for pos in [Position::new(5, 6), Position::new(7, 6), Position::new(6, 5), Position::new(6, 7)] {
+        assert_close(heat.probability(&pos), 0.25);
+    }
+}
+
+#[test]
+fn revealed_cell_is_certain() {
+    let mut hits = HitMap::new();
+    hits.insert(Position::new(2, 2), HitType::Revealed);
+    let heat = HeatMap::new(&hits, &[1]);
+
+    assert!(heat.exact);
+    assert_close(heat.probability(&Position::new(2, 2)), 1.0);
+    assert_close(heat.probability(&Position::new(2, 3)), 0.0);
+    assert_eq!(heat.best(), Some(Position::new(2, 2)));
+}
+
+#[test]
+fn exhausted_budget_falls_back_to_independent_ships() {
+    let hits = HitMap::new();
+    let heat = HeatMap::with_budget(&hits, &SHIP_SPANS, 1_000);
+
+    assert!(!heat.exact);
+    for pos in all_cells() {
+        let p = heat.probability(&pos);
+        assert!((0.0..=1.0).contains(&p), "{} at {}", p, pos);
+    }
+    // The middle of the board fits more placements than the corners
+    assert!(heat.probability(&Position::new(3, 3)) > heat.probability(&Position::new(0, 0)));
+    assert!(heat.best().is_some());
+}
+
+#[test]
+fn impossible_hit_map_is_not_exact() {
+    // No room left for a ship of span 3
+    let heat = HeatMap::new(&only_open(&[Position::new(0, 0), Position::new(1, 0)]), &[3]);
+    assert!(!heat.exact);
+}
@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+use sagittarius_game::{
+    state::{GameState, Ship, ShipDirection},
+    types::Position,
+    BOARD_SIZE, NUM_SHIPS, SHIP_SPANS,
+};
+
+pub fn arb_direction() -> impl Strategy<Value = ShipDirection> {
+    prop_oneof![Just(ShipDirection::Horizontal), Just(ShipDirection::Vertical)]
+}
+
+/// Any axis of the hex board.
+pub fn arb_hex_direction() -> impl Strategy<Value = ShipDirection> {
+    prop_oneof![Just(ShipDirection::Horizontal), Just(ShipDirection::Vertical), Just(ShipDirection::Diagonal)]
+}
+
+/// Any ship, including ones hanging off the board.
+pub fn arb_ship() -> impl Strategy<Value = Ship> {
+    (0..BOARD_SIZE as u32 + 2, 0..BOARD_SIZE as u32 + 2, arb_direction())
+        .prop_map(|(x, y, dir)| Ship::new(x, y, dir))
+}
+
+/// A ship of the given span that fits on the board.
+pub fn arb_fitting_ship(span: usize) -> impl Strategy<Value = Ship> {
+    let limit = (BOARD_SIZE - span) as u32;
+    arb_direction().prop_flat_map(move |dir| {
+        let (max_x, max_y) = match dir {
+            ShipDirection::Horizontal => (limit, BOARD_SIZE as u32 - 1),
+            ShipDirection::Vertical => (BOARD_SIZE as u32 - 1, limit),
+            ShipDirection::Diagonal => (limit, limit),
+        };
+        (0..=max_x, 0..=max_y).prop_map(move |(x, y)| Ship::new(x, y, dir.clone()))
+    })
+}
+
+pub fn arb_position() -> impl Strategy<Value = Position> {
+    (0..BOARD_SIZE as u32, 0..BOARD_SIZE as u32).prop_map(|(x, y)| Position::new(x, y))
+}
+
+fn ships_from_vec(ships: Vec<Ship>) -> [Ship; NUM_SHIPS] {
+    ships.try_into().unwrap()
+}
+
+/// Any fleet, valid or not.
+pub fn arb_state() -> impl Strategy<Value = GameState> {
+    (prop::collection::vec(arb_ship(), NUM_SHIPS), any::<u32>())
+        .prop_map(|(ships, salt)| GameState::new(ships_from_vec(ships), salt))
+}
+
+/// A fleet that passes `GameState::check`, as produced by the init guest.
+pub fn arb_valid_state() -> impl Strategy<Value = GameState> {
+    let ships = (
+        arb_fitting_ship(SHIP_SPANS[0]),
+        arb_fitting_ship(SHIP_SPANS[1]),
+        arb_fitting_ship(SHIP_SPANS[2]),
+        arb_fitting_ship(SHIP_SPANS[3]),
+    );
+    (ships, any::<u32>())
+        .prop_map(|((a, b, c, d), salt)| GameState::new([a, b, c, d], salt))
+        .prop_filter("ships overlap", |state| occupancy(state).is_some())
+}
+
+pub fn cells(ship: &Ship, span: usize) -> Vec<(u32, u32)> {
+    (0..span as u32)
+        .map(|i| match ship.dir {
+            ShipDirection::Horizontal => (ship.pos.x + i, ship.pos.y),
+            ShipDirection::Vertical => (ship.pos.x, ship.pos.y + i),
+            ShipDirection::Diagonal => (ship.pos.x + i, ship.pos.y + i),
+        })
+        .collect()
+}
+
+/// Reference model: which ship covers each cell, or `None` if ships overlap.
+pub fn occupancy(state: &GameState) -> Option<HashMap<(u32, u32), usize>> {
+    let mut board = HashMap::new();
+    for (i, ship) in state.ships.iter().enumerate() {
+        for cell in cells(ship, SHIP_SPANS[i]) {
+            if board.insert(cell, i).is_some() {
+                return None;
+            }
+        }
+    }
+    Some(board)
+}
+
+pub fn fits(ship: &Ship, span: usize) -> bool {
+    cells(ship, span)
+        .iter()
+        .all(|&(x, y)| (x as usize) < BOARD_SIZE && (y as usize) < BOARD_SIZE)
+}
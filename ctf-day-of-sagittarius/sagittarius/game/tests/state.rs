@@ -0,0 +1,76 @@
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use sagittarius_game::{state::{GameCheck, Ship}, sunk_mask, MAX_CLUSTER_BOMBS, MAX_SCOUTS, SHIP_SPANS, SUNK_MASKS};
+
+proptest! {
+    #[test]
+    fn ship_check_matches_bounds(ship in arb_ship(), i in 0..SHIP_SPANS.len()) {
+        let span = SHIP_SPANS[i];
+        prop_assert_eq!(ship.check(span), fits(&ship, span));
+    }
+
+    #[test]
+    fn damaged_ship_fails_check(ship in arb_fitting_ship(2), hit_mask in 1..=u8::MAX) {
+        let ship = Ship::with_hit_mask(ship.pos.x, ship.pos.y, ship.dir, hit_mask);
+        prop_assert!(!ship.check(2));
+    }
+
+    #[test]
+    fn valid_states_pass_check(state in arb_valid_state()) {
+        prop_assert!(state.check());
+    }
+
+    #[test]
+    fn state_check_matches_reference(state in arb_state()) {
+        let in_bounds = state.ships.iter().enumerate().all(|(i, ship)| fits(ship, SHIP_SPANS[i]));
+        let expected = in_bounds && occupancy(&state).is_some();
+        prop_assert_eq!(state.check(), expected);
+    }
+
+    #[test]
+    fn only_fresh_charges_pass_check(mut state in arb_valid_state(), scouts in 0..=MAX_SCOUTS, cluster_bombs in 0..=MAX_CLUSTER_BOMBS) {
+        state.scouts = scouts;
+        state.cluster_bombs = cluster_bombs;
+        prop_assert_eq!(state.check(), scouts == MAX_SCOUTS && cluster_bombs == MAX_CLUSTER_BOMBS);
+    }
+
+    #[test]
+    fn damaged_state_fails_check(mut state in arb_valid_state(), i in 0..SHIP_SPANS.len(), hit_mask in 1..=u8::MAX) {
+        state.ships[i].hit_mask = hit_mask;
+        prop_assert!(!state.check());
+    }
+
+    #[test]
+    fn game_check_without_commit_is_pure(state in arb_valid_state(), ship in arb_fitting_ship(3)) {
+        let mut game_check = GameCheck::new();
+        for (i, placed) in state.ships.iter().enumerate() {
+            game_check.commit(placed, SHIP_SPANS[i]);
+        }
+        let first = game_check.check(&ship, 3, false);
+        prop_assert_eq!(game_check.check(&ship, 3, false), first);
+    }
+
+    #[test]
+    fn game_check_rejects_committed_cells(ship in arb_fitting_ship(3)) {
+        let mut game_check = GameCheck::new();
+        prop_assert!(game_check.check(&ship, 3, true));
+        prop_assert!(!game_check.check(&ship, 3, false));
+    }
+
+    #[test]
+    fn game_check_detects_overlap(a in arb_fitting_ship(3), b in arb_fitting_ship(2)) {
+        let mut game_check = GameCheck::new();
+        game_check.commit(&a, 3);
+        let overlap = cells(&a, 3).iter().any(|cell| cells(&b, 2).contains(cell));
+        prop_assert_eq!(game_check.check(&b, 2, false), !overlap);
+    }
+}
+
+#[test]
+fn sunk_masks_match_spans() {
+    for (span, mask) in SHIP_SPANS.iter().zip(SUNK_MASKS.iter()) {
+        assert_eq!(sunk_mask(*span), *mask);
+    }
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use sagittarius_game::{
+    abilities::ShotParams,
+    state::{GameState, Ship, ShipDirection},
+    topology::{Asteroids, Hex, Square, Topology},
+    types::{HitType, Position},
+    BOARD_SIZE, SHIP_SPANS, SUNK_MASKS,
+};
+
+/// Any ship on the hex board, including ones hanging off it.
+fn arb_hex_ship() -> impl Strategy<Value = Ship> {
+    (0..BOARD_SIZE as u32 + 2, 0..BOARD_SIZE as u32 + 2, arb_hex_direction())
+        .prop_map(|(x, y, dir)| Ship::new(x, y, dir))
+}
+
+/// A ship of the given span that fits on the hex board, along any axis.
+fn arb_fitting_hex_ship(span: usize) -> impl Strategy<Value = Ship> {
+    let limit = (BOARD_SIZE - span) as u32;
+    let last = BOARD_SIZE as u32 - 1;
+    arb_hex_direction().prop_flat_map(move |dir| {
+        let (max_x, max_y) = match dir {
+            ShipDirection::Horizontal => (limit, last),
+            ShipDirection::Vertical => (last, limit),
+            ShipDirection::Diagonal => (limit, limit),
+        };
+        (0..=max_x, 0..=max_y).prop_map(move |(x, y)| Ship::new(x, y, dir.clone()))
+    })
+}
+
+/// A fleet on the hex board, with ships along every axis.
+fn arb_hex_state() -> impl Strategy<Value = GameState> {
+    let ships = (
+        arb_fitting_hex_ship(SHIP_SPANS[0]),
+        arb_fitting_hex_ship(SHIP_SPANS[1]),
+        arb_fitting_hex_ship(SHIP_SPANS[2]),
+        arb_fitting_hex_ship(SHIP_SPANS[3]),
+    );
+    (ships, any::<u32>())
+        .prop_map(|((a, b, c, d), salt)| GameState::new([a, b, c, d], salt))
+        .prop_filter("ships overlap", |state| occupancy(state).is_some())
+}
+
+proptest! {
+    #[test]
+    fn square_matches_classic_rules(state in arb_state()) {
+        prop_assert_eq!(state.check_on(&Square), state.check());
+    }
+
+    #[test]
+    fn hex_fits_every_axis(ship in arb_hex_ship(), i in 0..SHIP_SPANS.len()) {
+        let span = SHIP_SPANS[i];
+        prop_assert_eq!(ship.check_on(&Hex, span), fits(&ship, span));
+        if ship.dir == ShipDirection::Diagonal {
+            prop_assert!(!ship.check_on(&Square, span));
+        }
+    }
+
+    #[test]
+    fn hex_states_pass_check(state in arb_hex_state()) {
+        prop_assert!(state.check_on(&Hex));
+    }
+
+    #[test]
+    fn hex_shot_matches_reference(state in arb_hex_state(), shot in arb_position()) {
+        let board = occupancy(&state).unwrap();
+        let result = ShotParams::new(state.clone(), shot.x, shot.y).process_on(&Hex);
+        match board.get(&(shot.x, shot.y)) {
+            None => prop_assert_eq!(result.hit, HitType::Miss),
+            Some(&i) => {
+                prop_assert!(matches!(result.hit, HitType::Hit | HitType::Destroyed(_)));
+                prop_assert_eq!(result.state.ships[i].hit_mask.count_ones(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn sinking_diagonal_ship(state in arb_hex_state(), i in 0..SHIP_SPANS.len()) {
+        let mut state = state;
+        let mut last = HitType::Miss;
+        for (x, y) in cells(&state.ships[i].clone(), SHIP_SPANS[i]) {
+            let result = ShotParams::new(state, x, y).process_on(&Hex);
+            state = result.state;
+            last = result.hit;
+        }
+        prop_assert_eq!(last, HitType::Destroyed(i as u8));
+        prop_assert_eq!(state.ships[i].hit_mask, SUNK_MASKS[i]);
+    }
+
+    #[test]
+    fn asteroids_block_ships(state in arb_valid_state(), asteroid in arb_position()) {
+        let board = Asteroids::new(Square, std::slice::from_ref(&asteroid));
+        let covered = occupancy(&state).unwrap().contains_key(&(asteroid.x, asteroid.y));
+        prop_assert_eq!(state.check_on(&board), !covered);
+    }
+
+    #[test]
+    fn neighbours_are_adjacent(pos in arb_position()) {
+        for (topology, max) in [(&Square as &dyn Topology, 4), (&Hex as &dyn Topology, 6)] {
+            let neighbours = topology.neighbours(&pos);
+            prop_assert!(neighbours.len() <= max);
+            for cell in neighbours {
+                prop_assert!(topology.contains(&cell));
+                prop_assert!(topology.neighbours(&cell).contains(&pos));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn shot_at_asteroid_panics() {
+    let state = GameState::new(
+        [
+            Ship::new(0, 0, ShipDirection::Horizontal),
+            Ship::new(0, 2, ShipDirection::Vertical),
+            Ship::new(2, 2, ShipDirection::Horizontal),
+            Ship::new(7, 6, ShipDirection::Vertical),
+        ],
+        0,
+    );
+    let board = Asteroids::new(Square, &[Position::new(5, 5)]);
+    ShotParams::new(state, 5, 5).process_on(&board);
+}
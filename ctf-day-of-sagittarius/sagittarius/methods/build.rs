@@ -1,15 +1,98 @@
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, env, fs, path::PathBuf};
 
 use risc0_build::GuestOptions;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+mod sources;
+
+use sources::{hex, source_hash, SOURCES};
+
+const MANIFEST_NAME: &str = "methods.manifest.json";
+const GUEST_TARGET: &str = "riscv32im-risc0-zkvm-elf";
+
+fn guest_names() -> Vec<String> {
+    let mut names = fs::read_dir("guest/src/bin")
+        .unwrap()
+        .map(|entry| entry.unwrap().path().file_stem().unwrap().to_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Body of `pub const <name>: <type> = <body>;` in a generated methods file.
+fn const_body<'a>(methods: &'a str, name: &str) -> Option<&'a str> {
+    let start = methods.find(&format!("pub const {}:", name))?;
+    let body = &methods[start..];
+    let body = &body[body.find('=')? + 1..];
+    Some(body[..body.find(';')?].trim())
+}
+
+fn parse_numbers<T: std::str::FromStr>(body: &str) -> Option<Vec<T>> {
+    body.trim_start_matches('&')
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(|n| n.parse().ok())
+        .collect()
+}
+
+fn image(methods: &str, guest: &str) -> Option<(Vec<u32>, String)> {
+    let name = guest.to_uppercase();
+    let id = parse_numbers::<u32>(const_body(methods, &format!("{}_ID", name))?)?;
+    let elf = parse_numbers::<u8>(const_body(methods, &format!("{}_ELF", name))?)?;
+    Some((id, hex(&Sha256::digest(&elf))))
+}
+
+fn toml_value(path: &str, key: &str) -> String {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            (k.trim() == key).then(|| v.trim().trim_matches('"').to_string())
+        })
+        .unwrap_or_default()
+}
+
+/// Records how each image was produced, so anyone can rebuild it and compare.
+fn manifest(methods: &str, source_hash: &str, guest_options: Value) -> Value {
+    let guests = guest_names()
+        .into_iter()
+        .map(|guest| {
+            let (id, elf_sha256) = image(methods, &guest).expect("Generated methods are missing a guest");
+            (guest, json!({ "image_id": id, "elf_sha256": elf_sha256 }))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    json!({
+        "risc0_build": toml_value("Cargo.toml", "risc0-build"),
+        "toolchain": toml_value("../rust-toolchain.toml", "channel"),
+        "target": GUEST_TARGET,
+        "guest_options": guest_options,
+        "source_hash": source_hash,
+        "guests": guests,
+    })
+}
 
 fn main() {
-    let methods_path = Path::new(file!()).parent().unwrap().join("src/methods.rs");
-    if !methods_path.exists() {
-        let mut options = HashMap::new();
-        options.insert("guest", GuestOptions {
-            features: vec![],
-            std: false,
-        });
-        risc0_build::embed_methods_with_options(options);
+    for source in SOURCES {
+        println!("cargo:rerun-if-changed={}", source);
     }
-}
\ No newline at end of file
+
+    // The manifest records the options the images are actually built with
+    let options = GuestOptions {
+        features: vec![],
+        std: false,
+    };
+    let guest_options = json!({ "features": &options.features, "std": options.std });
+    risc0_build::embed_methods_with_options(HashMap::from([("guest", options)]));
+
+    // Written next to the generated methods, never into the source tree
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let methods = fs::read_to_string(out_dir.join("methods.rs")).unwrap();
+    let manifest = serde_json::to_string_pretty(&manifest(&methods, &source_hash(), guest_options)).unwrap();
+    fs::write(out_dir.join(MANIFEST_NAME), manifest + "\n").unwrap();
+}
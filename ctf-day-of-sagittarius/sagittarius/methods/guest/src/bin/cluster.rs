@@ -7,26 +7,18 @@ use risc0_zkvm::sha::Sha256;
 use risc0_zkvm::serde;
 
 use sagittarius_game::abilities::{ClusterBombParams, ClusterCommit};
-use sagittarius_game::types::Position;
 
 risc0_zkvm::guest::entry!(main);
 
 pub fn main() {
     let params: ClusterBombParams = env::read();
-    let width = (params.down_right_coordinates.x - params.upper_left_coordinates.x) as u8;
-    let height = (params.down_right_coordinates.y - params.upper_left_coordinates.y) as u8;
 
     // Generate some random shots
-    let number_of_shots = params.check();
+    let number_of_shots = params.check().expect("Invalid cluster area");
     let mut rand = alloc::vec![0; number_of_shots];
     zkvm_getrandom(rand.as_mut_slice()).unwrap();
 
-    let mut area = alloc::vec::Vec::with_capacity(width as usize * height as usize);
-    for x in params.upper_left_coordinates.x..=params.down_right_coordinates.x {
-        for y in params.upper_left_coordinates.y..=params.down_right_coordinates.y {
-            area.push(Position::new(x, y));
-        }
-    }
+    let mut area = params.area().positions();
 
     let mut shots = alloc::vec::Vec::new();
     for i in 0..number_of_shots {
@@ -2,12 +2,26 @@
 #![no_std]
 
 use risc0_zkvm::guest::env;
-use sagittarius_game::abilities::ScoutParams;
+use risc0_zkvm::guest::sha::Impl;
+use risc0_zkvm::sha::Sha256;
+use risc0_zkvm::serde;
+
+use sagittarius_game::abilities::{ScoutCommit, ScoutParams};
 
 risc0_zkvm::guest::entry!(main);
 
 pub fn main() {
     let params: ScoutParams = env::read();
     let result = params.process();
-    env::commit(&result);
+
+    let mut old_state_digest = [0u32; 8];
+    let mut new_state_digest = [0u32; 8];
+    old_state_digest.as_mut_slice().copy_from_slice(Impl::hash_words(serde::to_vec(&params.state).unwrap().as_slice()).as_words());
+    new_state_digest.as_mut_slice().copy_from_slice(Impl::hash_words(serde::to_vec(&result.state).unwrap().as_slice()).as_words());
+    env::commit(&ScoutCommit {
+        old_state_digest,
+        new_state_digest,
+        shot: params.shot,
+        cells: result.cells,
+    });
 }
\ No newline at end of file
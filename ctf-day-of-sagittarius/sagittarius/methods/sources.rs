@@ -0,0 +1,46 @@
+//! The sources a guest image is built from, shared by the build script and the manifest test.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// Everything that ends up in a guest image: the guest crate, the game rules and the toolchain.
+pub const SOURCES: [&str; 4] = ["guest", "../game/src", "../game/Cargo.toml", "../rust-toolchain.toml"];
+
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).unwrap() {
+            let path = entry.unwrap().path();
+            // Build output of a standalone guest build is not source
+            if path.file_name().unwrap() != "target" {
+                collect_files(&path, files);
+            }
+        }
+    } else if path.is_file() {
+        files.push(path.to_path_buf());
+    }
+}
+
+pub fn source_hash() -> String {
+    let mut files = vec![];
+    for source in SOURCES {
+        collect_files(Path::new(source), &mut files);
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        let contents = fs::read(&file).unwrap();
+        hasher.update(file.to_str().unwrap().as_bytes());
+        hasher.update((contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+    }
+    hex(&hasher.finalize())
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
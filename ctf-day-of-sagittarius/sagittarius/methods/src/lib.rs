@@ -1,2 +1,4 @@
-// include!(concat!(env!("OUT_DIR"), "/methods.rs"));
-include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/methods.rs"));
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/methods.rs"));
+
+/// How the embedded images were built, to compare against another build of the same sources
+pub const MANIFEST: &str = include_str!(concat!(env!("OUT_DIR"), "/methods.manifest.json"));
@@ -1,17 +0,0 @@
-
-pub const INIT_ELF: &'static [u8] = &[127, 69, 76, 70, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 243, 0, 1, 0, 0, 0, 0, 0, 0, 7, 52, 0, 0, 0, 96, 196, 0, 0, 0, 0, 0, 0, 52, 0, 32, 0, 3, 0, 40, 0, 7, 0, 5, 0, 1, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 7, 151, 81, 0, 0, 151, 81, 0, 0, 5, 0, 0, 0, 0, 16, 0, 0, 1, 0, 0, 0, 0, 112, 0, 0, 0, 0, 8, 0, 0, 0, 8, 0, 4, 0, 0, 0, 84, 0, 0, 0, 6, 0, 0, 0, 0, 16, 0, 0, 81, 229, 116, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 17, 8, 249, 147, 129, 1, 128, 23, 1, 128, 249, 19, 1, 65, 255, 239, 0, 64, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 55, 5, 8, 0, 3, 37, 69, 0, 183, 5, 8, 0, 3, 166, 69, 5, 99, 122, 197, 0, 35, 32, 5, 0, 3, 166, 69, 5, 19, 5, 69, 0, 227, 106, 197, 254, 55, 4, 8, 0, 147, 4, 68, 0, 19, 133, 196, 0, 19, 6, 16, 4, 147, 5, 0, 0, 151, 32, 0, 0, 231, 128, 192, 33, 19, 5, 16, 0, 35, 34, 164, 0, 35, 162, 4, 0, 35, 164, 4, 0, 151, 0, 0, 0, 231, 128, 128, 119, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 131, 181, 19, 1, 1, 244, 35, 46, 17, 10, 35, 44, 129, 10, 35, 42, 145, 10, 35, 40, 33, 11, 35, 38, 49, 11, 35, 36, 65, 11, 35, 34, 81, 11, 35, 32, 97, 11, 35, 46, 113, 9, 35, 44, 129, 9, 35, 42, 145, 9, 35, 40, 161, 9, 35, 38, 177, 9, 19, 4, 5, 0, 99, 6, 6, 22, 19, 9, 6, 0, 147, 132, 5, 0, 19, 5, 1, 8, 147, 7, 48, 0, 151, 0, 0, 0, 231, 128, 128, 66, 131, 73, 129, 8, 3, 75, 1, 8, 19, 5, 32, 0, 99, 150, 169, 0, 35, 0, 100, 1, 111, 0, 128, 20, 131, 76, 33, 8, 3, 77, 17, 8, 131, 77, 65, 8, 131, 74, 49, 8, 3, 76, 97, 8, 3, 5, 177, 8, 3, 74, 81, 8, 131, 5, 161, 8, 3, 70, 145, 8, 35, 5, 161, 6, 131, 11, 113, 8, 19, 149, 133, 0, 51, 101, 197, 0, 147, 5, 16, 0, 35, 20, 161, 6, 99, 14, 185, 14, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 59, 3, 71, 129, 8, 131, 71, 1, 8, 19, 5, 32, 0, 99, 22, 167, 0, 35, 0, 244, 0, 111, 0, 192, 13, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 3, 72, 97, 8, 131, 72, 81, 8, 131, 5, 177, 8, 3, 6, 161, 8, 131, 70, 145, 8, 131, 14, 113, 8, 35, 9, 177, 6, 147, 21, 134, 0, 179, 229, 213, 0, 35, 24, 177, 6, 99, 10, 169, 8, 35, 34, 209, 3, 35, 36, 193, 3, 35, 38, 113, 2, 35, 40, 97, 2, 35, 42, 81, 2, 35, 44, 17, 3, 35, 46, 1, 3, 35, 32, 241, 4, 35, 34, 225, 4, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 51, 131, 70, 129, 8, 3, 71, 1, 8, 19, 5, 32, 0, 99, 150, 166, 0, 35, 0, 228, 0, 111, 0, 0, 5, 131, 72, 33, 8, 131, 66, 17, 8, 3, 67, 65, 8, 131, 67, 49, 8, 131, 71, 97, 8, 3, 72, 81, 8, 3, 5, 177, 8, 131, 5, 161, 8, 3, 70, 145, 8, 3, 14, 113, 8, 35, 13, 161, 6, 19, 149, 133, 0, 51, 101, 197, 0, 35, 28, 161, 6, 19, 5, 48, 0, 99, 24, 169, 4, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 11, 3, 36, 129, 11, 131, 36, 65, 11, 3, 41, 1, 11, 131, 41, 193, 10, 3, 42, 129, 10, 131, 42, 65, 10, 3, 43, 1, 10, 131, 43, 193, 9, 3, 44, 129, 9, 131, 44, 65, 9, 3, 45, 1, 9, 131, 45, 193, 8, 19, 1, 1, 12, 103, 128, 0, 0, 35, 34, 193, 1, 35, 36, 113, 0, 35, 38, 97, 0, 35, 40, 81, 0, 19, 137, 8, 0, 35, 44, 1, 1, 35, 42, 241, 0, 35, 46, 225, 0, 35, 32, 209, 2, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 37, 3, 69, 129, 8, 131, 69, 1, 8, 19, 6, 32, 0, 99, 24, 197, 0, 35, 0, 180, 0, 35, 4, 196, 0, 111, 240, 95, 247, 19, 150, 140, 0, 51, 102, 166, 1, 147, 150, 141, 0, 179, 230, 86, 1, 147, 150, 6, 1, 179, 230, 198, 0, 19, 22, 140, 0, 51, 102, 70, 1, 19, 151, 11, 1, 51, 102, 230, 0, 19, 215, 134, 1, 19, 22, 134, 0, 51, 102, 230, 0, 147, 150, 134, 0, 179, 230, 102, 1, 3, 39, 65, 3, 19, 23, 135, 0, 131, 39, 1, 3, 51, 103, 247, 0, 131, 39, 193, 2, 147, 151, 135, 0, 3, 40, 129, 2, 179, 231, 7, 1, 147, 151, 7, 1, 179, 231, 231, 0, 3, 39, 193, 3, 19, 23, 135, 0, 3, 40, 129, 3, 51, 103, 7, 1, 3, 40, 65, 2, 19, 24, 8, 1, 51, 103, 7, 1, 19, 216, 135, 1, 19, 23, 135, 0, 51, 103, 7, 1, 147, 151, 135, 0, 3, 40, 1, 4, 179, 231, 7, 1, 19, 24, 137, 0, 131, 40, 1, 1, 51, 104, 24, 1, 131, 40, 193, 0, 147, 152, 136, 0, 131, 34, 129, 0, 179, 232, 88, 0, 147, 152, 8, 1, 179, 232, 8, 1, 3, 40, 65, 1, 19, 24, 136, 0, 131, 34, 129, 1, 51, 104, 88, 0, 131, 34, 65, 0, 147, 146, 2, 1, 51, 104, 88, 0, 147, 210, 136, 1, 19, 24, 136, 0, 51, 104, 88, 0, 147, 152, 136, 0, 131, 34, 193, 1, 179, 232, 88, 0, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 147, 146, 130, 0, 179, 226, 98, 0, 19, 147, 131, 0, 51, 99, 195, 1, 19, 19, 3, 1, 131, 67, 97, 8, 3, 78, 81, 8, 131, 14, 113, 8, 179, 98, 83, 0, 19, 147, 131, 0, 51, 99, 195, 1, 147, 147, 14, 1, 51, 99, 115, 0, 147, 211, 130, 1, 19, 19, 131, 0, 51, 99, 115, 0, 131, 3, 177, 8, 3, 14, 161, 8, 131, 78, 145, 8, 147, 146, 130, 0, 35, 5, 113, 4, 147, 19, 142, 0, 179, 227, 211, 1, 3, 30, 129, 6, 131, 14, 161, 6, 35, 20, 113, 4, 179, 229, 178, 0, 35, 16, 193, 7, 35, 1, 209, 7, 131, 18, 1, 7, 131, 3, 33, 7, 3, 30, 129, 7, 131, 14, 161, 7, 35, 28, 81, 4, 35, 13, 113, 4, 35, 24, 193, 5, 35, 9, 209, 5, 35, 34, 196, 0, 35, 32, 212, 0, 3, 6, 1, 6, 131, 6, 17, 6, 131, 2, 33, 6, 35, 4, 52, 1, 163, 4, 196, 0, 35, 5, 212, 0, 163, 5, 84, 0, 35, 40, 228, 0, 35, 38, 244, 0, 3, 6, 129, 5, 131, 6, 145, 5, 3, 7, 161, 5, 131, 39, 65, 4, 35, 10, 244, 0, 163, 10, 196, 0, 35, 11, 212, 0, 163, 11, 228, 0, 35, 46, 4, 1, 35, 44, 20, 1, 3, 6, 1, 5, 131, 6, 17, 5, 3, 7, 33, 5, 131, 39, 1, 2, 35, 0, 244, 2, 163, 0, 196, 2, 35, 1, 212, 2, 163, 1, 228, 2, 35, 36, 100, 2, 35, 34, 180, 2, 131, 5, 129, 4, 3, 6, 145, 4, 131, 6, 161, 4, 35, 6, 164, 2, 163, 6, 180, 2, 35, 7, 196, 2, 163, 7, 212, 2, 111, 240, 95, 212, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 19, 4, 5, 0, 99, 142, 7, 4, 19, 137, 7, 0, 131, 164, 5, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 0, 174, 19, 117, 245, 15, 19, 10, 112, 0, 99, 26, 69, 3, 131, 41, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 147, 10, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 64, 171, 19, 117, 245, 15, 99, 22, 69, 1, 99, 28, 89, 3, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 3, 42, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 128, 165, 19, 117, 245, 15, 147, 5, 112, 0, 227, 22, 181, 250, 131, 37, 1, 0, 99, 138, 5, 0, 147, 10, 16, 0, 19, 5, 80, 0, 99, 134, 85, 1, 111, 240, 95, 249, 147, 10, 0, 0, 19, 5, 32, 0, 99, 24, 169, 0, 147, 5, 80, 0, 35, 0, 180, 0, 111, 240, 95, 248, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 64, 160, 19, 117, 245, 15, 147, 5, 112, 0, 227, 28, 181, 244, 131, 37, 1, 0, 19, 6, 0, 16, 19, 5, 80, 0, 227, 244, 197, 244, 35, 32, 52, 1, 35, 34, 68, 1, 35, 4, 84, 1, 163, 4, 180, 0, 111, 240, 31, 244, 19, 1, 1, 246, 35, 46, 17, 8, 35, 44, 129, 8, 35, 42, 145, 8, 35, 40, 33, 9, 35, 38, 49, 9, 35, 36, 65, 9, 35, 34, 81, 9, 35, 32, 97, 9, 35, 46, 113, 7, 35, 44, 129, 7, 35, 42, 145, 7, 35, 40, 161, 7, 35, 38, 177, 7, 19, 4, 5, 0, 147, 10, 80, 0, 99, 128, 7, 16, 19, 137, 7, 0, 147, 132, 5, 0, 19, 5, 129, 3, 19, 6, 64, 0, 151, 0, 0, 0, 231, 128, 0, 158, 131, 73, 1, 4, 3, 74, 129, 3, 19, 5, 32, 0, 99, 150, 169, 0, 147, 10, 10, 0, 111, 0, 0, 13, 131, 76, 161, 3, 3, 77, 145, 3, 131, 77, 193, 3, 3, 75, 177, 3, 3, 69, 225, 3, 35, 38, 161, 0, 131, 75, 209, 3, 3, 12, 241, 3, 147, 5, 17, 4, 19, 5, 17, 1, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 0, 199, 19, 5, 16, 0, 99, 10, 169, 8, 3, 165, 4, 0, 35, 44, 1, 2, 147, 5, 129, 3, 19, 6, 16, 0, 151, 32, 0, 0, 231, 128, 192, 144, 147, 122, 245, 15, 19, 5, 112, 0, 99, 152, 170, 6, 19, 149, 140, 0, 51, 101, 165, 1, 147, 149, 141, 0, 179, 229, 101, 1, 147, 149, 5, 1, 51, 229, 165, 0, 131, 37, 193, 0, 147, 149, 133, 0, 179, 229, 117, 1, 19, 22, 12, 1, 179, 229, 197, 0, 19, 86, 133, 1, 147, 149, 133, 0, 179, 228, 197, 0, 3, 41, 129, 3, 19, 21, 133, 0, 51, 106, 69, 1, 19, 5, 148, 0, 147, 5, 17, 1, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 192, 190, 35, 34, 148, 0, 35, 32, 68, 1, 35, 4, 52, 1, 35, 40, 36, 3, 111, 0, 0, 1, 35, 0, 84, 1, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 9, 3, 36, 129, 9, 131, 36, 65, 9, 3, 41, 1, 9, 131, 41, 193, 8, 3, 42, 129, 8, 131, 42, 65, 8, 3, 43, 1, 8, 131, 43, 193, 7, 3, 44, 129, 7, 131, 44, 65, 7, 3, 45, 1, 7, 131, 45, 193, 6, 19, 1, 1, 10, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 248, 35, 46, 17, 6, 35, 44, 129, 6, 35, 42, 145, 6, 35, 40, 33, 7, 151, 32, 0, 0, 231, 128, 64, 130, 35, 42, 161, 2, 19, 5, 65, 3, 35, 38, 161, 6, 55, 69, 0, 7, 19, 6, 197, 89, 55, 69, 0, 7, 19, 7, 197, 90, 19, 5, 129, 3, 147, 5, 193, 6, 147, 6, 144, 0, 147, 7, 32, 0, 19, 4, 32, 0, 151, 0, 0, 0, 231, 128, 128, 226, 3, 69, 1, 4, 99, 2, 133, 12, 19, 5, 1, 0, 147, 5, 129, 3, 19, 6, 64, 3, 151, 32, 0, 0, 231, 128, 192, 177, 19, 5, 1, 0, 151, 16, 0, 0, 231, 128, 192, 134, 99, 10, 5, 12, 19, 5, 129, 3, 147, 5, 1, 0, 151, 0, 0, 0, 231, 128, 0, 58, 3, 36, 129, 3, 99, 14, 4, 14, 3, 41, 193, 3, 131, 37, 1, 4, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 27, 147, 4, 5, 0, 151, 16, 0, 0, 231, 128, 0, 120, 35, 38, 161, 6, 19, 5, 193, 6, 35, 44, 161, 2, 55, 69, 0, 7, 147, 5, 197, 88, 19, 5, 129, 3, 19, 6, 96, 0, 147, 134, 4, 0, 151, 0, 0, 0, 231, 128, 64, 31, 19, 117, 245, 15, 147, 5, 112, 0, 99, 30, 181, 12, 99, 12, 9, 0, 147, 21, 41, 0, 19, 6, 64, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 93, 131, 32, 193, 7, 3, 36, 129, 7, 131, 36, 65, 7, 3, 41, 1, 7, 19, 1, 1, 8, 103, 128, 0, 0, 3, 5, 129, 3, 35, 0, 161, 0, 55, 69, 0, 7, 19, 5, 197, 91, 183, 69, 0, 7, 147, 134, 133, 94, 183, 69, 0, 7, 19, 135, 133, 101, 147, 5, 176, 2, 19, 6, 1, 0, 151, 64, 0, 0, 231, 128, 192, 150, 115, 16, 0, 192, 55, 69, 0, 7, 19, 5, 133, 81, 35, 44, 161, 2, 19, 5, 16, 0, 35, 46, 161, 2, 35, 32, 1, 4, 55, 69, 0, 7, 19, 5, 69, 80, 35, 36, 161, 4, 35, 38, 1, 4, 55, 69, 0, 7, 147, 5, 5, 83, 19, 5, 129, 3, 151, 48, 0, 0, 231, 128, 64, 109, 115, 16, 0, 192, 3, 5, 193, 3, 35, 6, 161, 6, 55, 69, 0, 7, 19, 5, 5, 84, 183, 69, 0, 7, 147, 134, 197, 86, 183, 69, 0, 7, 19, 135, 197, 87, 147, 5, 176, 2, 19, 6, 193, 6, 151, 64, 0, 0, 231, 128, 128, 143, 115, 16, 0, 192, 35, 10, 161, 2, 55, 69, 0, 7, 19, 5, 197, 91, 183, 69, 0, 7, 147, 134, 133, 94, 183, 69, 0, 7, 19, 135, 133, 102, 147, 5, 176, 2, 19, 6, 65, 3, 151, 64, 0, 0, 231, 128, 128, 140, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 147, 4, 5, 0, 3, 37, 5, 0, 3, 164, 5, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 192, 131, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 9, 3, 165, 68, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 129, 19, 117, 245, 15, 99, 30, 37, 5, 3, 197, 132, 0, 99, 8, 5, 0, 19, 5, 16, 0, 35, 38, 161, 0, 111, 0, 128, 0, 35, 38, 1, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 0, 126, 19, 117, 245, 15, 147, 5, 112, 0, 99, 18, 181, 2, 3, 197, 148, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 123, 19, 117, 245, 15, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 132, 6, 0, 147, 9, 0, 0, 3, 42, 5, 0, 147, 10, 65, 0, 55, 5, 8, 0, 19, 11, 69, 0, 147, 11, 75, 0, 19, 12, 176, 3, 147, 12, 0, 2, 19, 13, 0, 4, 147, 4, 203, 0, 111, 0, 128, 5, 51, 6, 173, 64, 179, 141, 202, 0, 19, 9, 69, 252, 51, 133, 164, 0, 147, 5, 65, 0, 151, 32, 0, 0, 231, 128, 192, 131, 19, 5, 129, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 33, 19, 117, 9, 252, 179, 133, 173, 0, 19, 133, 4, 0, 19, 6, 9, 0, 151, 32, 0, 0, 231, 128, 0, 129, 147, 137, 73, 0, 35, 6, 43, 5, 99, 130, 153, 7, 51, 5, 52, 1, 3, 37, 5, 0, 35, 34, 161, 0, 3, 37, 10, 0, 147, 5, 65, 0, 19, 6, 64, 0, 151, 32, 0, 0, 231, 128, 192, 201, 3, 69, 203, 4, 35, 36, 113, 1, 227, 98, 172, 248, 131, 37, 65, 0, 51, 6, 171, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 9, 69, 0, 147, 137, 73, 0, 35, 6, 43, 5, 227, 146, 153, 251, 19, 5, 112, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 147, 132, 5, 0, 19, 4, 5, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 0, 0, 0, 231, 128, 0, 40, 99, 2, 5, 16, 35, 36, 161, 0, 19, 5, 64, 3, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 129, 0, 35, 44, 161, 0, 147, 5, 129, 1, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 215, 19, 117, 245, 15, 19, 9, 112, 0, 99, 26, 37, 9, 19, 133, 196, 0, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 0, 214, 19, 117, 245, 15, 99, 30, 37, 7, 19, 133, 132, 1, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 128, 212, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 7, 19, 133, 68, 2, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 210, 19, 117, 245, 15, 99, 20, 37, 5, 131, 165, 4, 3, 3, 37, 129, 1, 35, 46, 177, 0, 147, 5, 193, 1, 19, 6, 16, 0, 151, 0, 0, 0, 231, 128, 64, 87, 19, 117, 245, 15, 147, 5, 112, 0, 99, 16, 181, 2, 3, 37, 1, 1, 131, 37, 193, 0, 3, 38, 129, 0, 35, 36, 164, 0, 35, 34, 180, 0, 35, 32, 196, 0, 111, 0, 128, 2, 131, 37, 193, 0, 35, 2, 164, 0, 35, 32, 4, 0, 99, 140, 5, 0, 3, 37, 129, 0, 147, 149, 37, 0, 19, 6, 64, 0, 151, 0, 0, 0, 231, 128, 192, 25, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 32, 0, 0, 231, 128, 0, 223, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 131, 42, 5, 0, 3, 165, 74, 0, 3, 164, 10, 0, 147, 132, 5, 0, 51, 5, 197, 0, 35, 162, 170, 0, 99, 22, 4, 0, 55, 85, 0, 7, 19, 4, 197, 165, 19, 245, 52, 0, 99, 6, 5, 2, 19, 5, 240, 255, 99, 2, 166, 2, 147, 6, 0, 0, 55, 69, 0, 7, 147, 5, 197, 91, 19, 5, 6, 0, 99, 16, 5, 2, 19, 5, 4, 0, 19, 134, 6, 0, 111, 0, 128, 9, 147, 21, 101, 0, 179, 133, 180, 0, 179, 6, 166, 64, 227, 4, 5, 254, 99, 14, 6, 6, 19, 85, 150, 1, 99, 24, 5, 10, 19, 25, 102, 0, 19, 53, 21, 0, 19, 26, 37, 0, 19, 5, 9, 0, 147, 5, 10, 0, 151, 0, 0, 0, 231, 128, 128, 11, 99, 14, 5, 8, 147, 9, 5, 0, 19, 5, 9, 252, 19, 90, 101, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 9, 0, 151, 16, 0, 0, 231, 128, 192, 84, 19, 6, 26, 0, 19, 5, 4, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 64, 203, 19, 4, 5, 0, 19, 6, 64, 0, 19, 133, 9, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 0, 7, 111, 0, 128, 1, 147, 5, 64, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 128, 200, 19, 4, 5, 0, 35, 160, 138, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 151, 32, 0, 0, 231, 128, 0, 198, 115, 16, 0, 192, 19, 5, 9, 0, 147, 5, 10, 0, 151, 32, 0, 0, 231, 128, 0, 201, 115, 16, 0, 192, 23, 19, 0, 0, 103, 0, 131, 27, 23, 19, 0, 0, 103, 0, 3, 40, 23, 19, 0, 0, 103, 0, 195, 39, 23, 35, 0, 0, 103, 0, 67, 200, 99, 14, 6, 6, 19, 8, 5, 0, 131, 167, 5, 0, 3, 199, 133, 0, 3, 165, 69, 0, 147, 5, 112, 0, 99, 10, 7, 6, 99, 238, 245, 24, 99, 130, 6, 12, 19, 7, 128, 0, 179, 5, 167, 64, 147, 6, 0, 0, 99, 100, 183, 0, 147, 134, 5, 0, 19, 7, 0, 0, 147, 21, 53, 0, 179, 133, 183, 0, 179, 7, 184, 0, 19, 8, 16, 0, 147, 5, 16, 0, 99, 130, 230, 18, 131, 200, 7, 0, 99, 156, 8, 0, 19, 7, 23, 0, 35, 128, 7, 1, 179, 53, 199, 0, 147, 135, 135, 0, 227, 18, 230, 254, 19, 197, 245, 255, 19, 117, 21, 0, 103, 128, 0, 0, 147, 5, 0, 0, 19, 197, 245, 255, 19, 117, 21, 0, 103, 128, 0, 0, 99, 232, 165, 20, 99, 142, 6, 8, 19, 7, 128, 0, 179, 5, 247, 64, 147, 6, 0, 0, 99, 100, 183, 0, 147, 134, 5, 0, 19, 7, 0, 0, 19, 21, 53, 0, 51, 5, 168, 0, 51, 5, 245, 0, 19, 8, 16, 0, 147, 5, 16, 0, 99, 140, 230, 12, 179, 8, 229, 0, 131, 194, 8, 0, 227, 146, 2, 250, 19, 7, 23, 0, 35, 128, 8, 1, 179, 53, 199, 0, 227, 18, 230, 254, 111, 240, 31, 249, 147, 5, 128, 0, 147, 6, 5, 0, 99, 228, 165, 0, 147, 6, 128, 0, 19, 7, 0, 0, 179, 136, 166, 64, 19, 21, 53, 0, 51, 133, 167, 0, 51, 5, 168, 0, 147, 5, 16, 0, 99, 136, 232, 6, 131, 71, 5, 0, 227, 158, 7, 244, 19, 7, 23, 0, 179, 53, 199, 0, 19, 5, 133, 0, 227, 20, 230, 254, 111, 240, 159, 244, 147, 5, 128, 0, 19, 135, 7, 0, 99, 228, 245, 0, 19, 7, 128, 0, 147, 6, 0, 0, 179, 8, 247, 64, 19, 21, 53, 0, 51, 133, 167, 0, 51, 5, 168, 0, 147, 5, 16, 0, 99, 132, 216, 4, 179, 7, 213, 0, 131, 199, 7, 0, 227, 152, 7, 240, 147, 134, 22, 0, 179, 181, 198, 0, 227, 20, 214, 254, 111, 240, 31, 240, 51, 5, 229, 0, 111, 0, 128, 0, 19, 133, 6, 0, 183, 69, 0, 7, 19, 134, 5, 107, 147, 5, 128, 0, 151, 48, 0, 0, 231, 128, 0, 6, 115, 16, 0, 192, 51, 135, 231, 0, 55, 69, 0, 7, 19, 6, 5, 108, 147, 5, 128, 0, 19, 5, 7, 0, 151, 48, 0, 0, 231, 128, 0, 4, 115, 16, 0, 192, 147, 5, 128, 0, 227, 114, 181, 252, 55, 69, 0, 7, 19, 6, 5, 107, 147, 5, 128, 0, 19, 133, 7, 0, 151, 48, 0, 0, 231, 128, 192, 1, 115, 16, 0, 192, 183, 69, 0, 7, 19, 134, 5, 108, 147, 5, 128, 0, 151, 48, 0, 0, 231, 128, 64, 0, 115, 16, 0, 192, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 19, 4, 5, 0, 19, 5, 129, 0, 19, 6, 0, 4, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 0, 25, 3, 69, 148, 0, 99, 16, 5, 20, 3, 37, 4, 0, 131, 37, 68, 0, 19, 54, 133, 0, 147, 182, 133, 0, 51, 118, 214, 0, 99, 4, 6, 18, 3, 70, 132, 0, 99, 4, 6, 0, 19, 133, 5, 0, 147, 5, 64, 0, 99, 234, 165, 16, 19, 5, 129, 0, 19, 6, 64, 0, 147, 6, 16, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 218, 99, 12, 5, 14, 3, 69, 84, 1, 99, 24, 5, 14, 3, 37, 196, 0, 131, 37, 4, 1, 19, 54, 133, 0, 147, 182, 133, 0, 51, 118, 214, 0, 99, 12, 6, 12, 3, 70, 68, 1, 99, 4, 6, 0, 19, 133, 5, 0, 147, 5, 80, 0, 99, 226, 165, 12, 147, 5, 196, 0, 19, 5, 129, 0, 19, 6, 48, 0, 147, 6, 16, 0, 151, 0, 0, 0, 231, 128, 64, 213, 99, 4, 5, 10, 3, 69, 20, 2, 99, 16, 5, 10, 3, 37, 132, 1, 131, 37, 196, 1, 19, 54, 133, 0, 147, 182, 133, 0, 51, 118, 214, 0, 99, 4, 6, 8, 3, 70, 4, 2, 99, 4, 6, 0, 19, 133, 5, 0, 147, 5, 80, 0, 99, 234, 165, 6, 147, 5, 132, 1, 19, 5, 129, 0, 19, 6, 48, 0, 147, 6, 16, 0, 151, 0, 0, 0, 231, 128, 64, 208, 99, 12, 5, 4, 3, 69, 212, 2, 99, 24, 5, 4, 3, 37, 68, 2, 131, 37, 132, 2, 19, 54, 133, 0, 147, 182, 133, 0, 51, 118, 214, 0, 99, 12, 6, 2, 3, 70, 196, 2, 99, 4, 6, 0, 19, 133, 5, 0, 147, 5, 96, 0, 99, 226, 165, 2, 147, 5, 68, 2, 19, 5, 129, 0, 19, 6, 32, 0, 147, 6, 16, 0, 19, 4, 16, 0, 151, 0, 0, 0, 231, 128, 0, 203, 99, 20, 5, 0, 19, 4, 0, 0, 19, 5, 4, 0, 131, 32, 193, 4, 3, 36, 129, 4, 19, 1, 1, 5, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 4, 5, 0, 3, 41, 133, 0, 3, 37, 69, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 100, 197, 4, 3, 37, 4, 0, 19, 150, 36, 0, 147, 21, 41, 0, 51, 5, 181, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 192, 13, 179, 5, 153, 0, 19, 5, 112, 0, 35, 36, 180, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 12, 3, 41, 132, 0, 111, 240, 95, 250, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 99, 0, 6, 4, 99, 192, 4, 4, 19, 9, 6, 0, 3, 165, 134, 0, 99, 0, 5, 4, 131, 165, 70, 0, 99, 140, 5, 2, 3, 165, 6, 0, 19, 6, 9, 0, 147, 134, 4, 0, 151, 0, 0, 0, 231, 128, 64, 186, 99, 28, 5, 2, 35, 34, 148, 0, 35, 36, 36, 1, 111, 0, 192, 0, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 128, 2, 99, 128, 4, 4, 19, 133, 4, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 0, 182, 227, 8, 5, 252, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 5, 9, 0, 227, 26, 5, 252, 111, 240, 223, 249, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 100, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 100, 150, 0, 147, 4, 6, 0, 147, 5, 64, 0, 99, 228, 149, 0, 147, 4, 64, 0, 147, 213, 212, 1, 19, 182, 21, 0, 147, 149, 36, 0, 19, 22, 38, 0, 99, 0, 5, 2, 131, 38, 4, 0, 19, 21, 37, 0, 35, 44, 209, 0, 35, 46, 161, 0, 19, 5, 64, 0, 35, 32, 161, 2, 111, 0, 128, 0, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 151, 0, 0, 0, 231, 128, 64, 237, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 16, 0, 0, 231, 128, 64, 108, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 16, 0, 0, 231, 128, 0, 110, 115, 16, 0, 192, 3, 37, 5, 0, 23, 35, 0, 0, 103, 0, 3, 235, 3, 69, 5, 0, 19, 21, 37, 0, 55, 70, 0, 7, 19, 6, 6, 109, 51, 5, 197, 0, 3, 38, 5, 0, 19, 133, 5, 0, 103, 0, 6, 0, 183, 69, 0, 7, 147, 133, 181, 117, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 67, 171, 183, 69, 0, 7, 147, 133, 149, 116, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 3, 170, 183, 69, 0, 7, 147, 133, 85, 115, 19, 6, 64, 1, 23, 51, 0, 0, 103, 0, 195, 168, 183, 69, 0, 7, 147, 133, 53, 114, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 131, 167, 183, 69, 0, 7, 147, 133, 181, 112, 19, 6, 128, 1, 23, 51, 0, 0, 103, 0, 67, 166, 183, 69, 0, 7, 147, 133, 245, 111, 19, 6, 192, 0, 23, 51, 0, 0, 103, 0, 3, 165, 183, 69, 0, 7, 147, 133, 197, 110, 19, 6, 48, 1, 23, 51, 0, 0, 103, 0, 195, 163, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 131, 36, 5, 0, 19, 132, 5, 0, 19, 133, 5, 0, 151, 48, 0, 0, 231, 128, 128, 162, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 195, 156, 19, 5, 4, 0, 151, 48, 0, 0, 231, 128, 128, 160, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 195, 164, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 51, 0, 0, 103, 0, 3, 244, 3, 38, 69, 0, 131, 37, 69, 1, 147, 6, 16, 0, 99, 12, 214, 0, 99, 28, 6, 0, 99, 142, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 103, 128, 0, 0, 99, 142, 5, 0, 19, 5, 0, 0, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 5, 119, 147, 5, 0, 0, 103, 128, 0, 0, 131, 37, 5, 0, 3, 165, 5, 0, 131, 165, 69, 0, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 35, 46, 49, 1, 19, 4, 5, 0, 99, 130, 5, 4, 147, 4, 6, 0, 19, 137, 5, 0, 99, 14, 6, 6, 99, 202, 4, 10, 19, 165, 4, 0, 147, 73, 21, 0, 19, 133, 4, 0, 147, 133, 9, 0, 151, 0, 0, 0, 231, 128, 0, 135, 99, 16, 5, 6, 19, 133, 4, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 78, 115, 16, 0, 192, 3, 165, 70, 1, 131, 165, 6, 1, 35, 42, 161, 0, 35, 40, 177, 0, 3, 165, 198, 0, 131, 165, 134, 0, 3, 166, 70, 0, 131, 166, 6, 0, 35, 38, 161, 0, 35, 36, 177, 0, 35, 34, 193, 0, 35, 32, 209, 0, 147, 5, 1, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 76, 111, 0, 64, 2, 19, 5, 16, 0, 35, 32, 164, 0, 35, 34, 148, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 16, 0, 0, 231, 128, 0, 203, 35, 36, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 19, 1, 1, 3, 103, 128, 0, 0, 151, 16, 0, 0, 231, 128, 0, 65, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 34, 161, 0, 19, 5, 65, 0, 35, 40, 161, 2, 55, 21, 0, 7, 19, 5, 133, 70, 35, 42, 161, 2, 55, 69, 0, 7, 19, 5, 5, 119, 35, 44, 161, 0, 19, 5, 16, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 231, 19, 6, 5, 0, 19, 135, 5, 0, 19, 5, 129, 0, 147, 6, 129, 1, 147, 5, 6, 0, 19, 6, 7, 0, 151, 0, 0, 0, 231, 128, 192, 234, 3, 37, 129, 0, 131, 37, 1, 1, 151, 16, 0, 0, 231, 128, 0, 1, 115, 16, 0, 192, 55, 69, 0, 7, 19, 5, 133, 119, 147, 5, 144, 1, 151, 16, 0, 0, 231, 128, 128, 255, 115, 16, 0, 192, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 35, 38, 113, 3, 35, 36, 129, 3, 35, 34, 145, 3, 19, 137, 7, 0, 19, 11, 7, 0, 147, 138, 6, 0, 147, 11, 6, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 6, 7, 4, 19, 133, 250, 3, 19, 85, 37, 0, 147, 124, 5, 255, 99, 136, 12, 4, 19, 213, 220, 1, 99, 22, 5, 30, 147, 153, 44, 0, 99, 194, 9, 30, 19, 53, 21, 0, 19, 28, 37, 0, 99, 128, 9, 4, 19, 133, 9, 0, 147, 5, 12, 0, 151, 240, 255, 255, 231, 128, 128, 106, 19, 10, 5, 0, 99, 24, 10, 2, 111, 0, 128, 30, 19, 133, 74, 0, 19, 85, 37, 0, 19, 5, 21, 1, 147, 124, 5, 255, 227, 156, 12, 250, 147, 9, 0, 0, 19, 10, 64, 0, 111, 0, 192, 0, 19, 10, 12, 0, 99, 0, 10, 28, 99, 238, 89, 25, 19, 5, 10, 0, 147, 133, 11, 0, 19, 134, 10, 0, 151, 16, 0, 0, 231, 128, 64, 177, 51, 5, 90, 1, 99, 8, 11, 8, 51, 134, 89, 65, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 64, 160, 99, 148, 12, 14, 99, 130, 132, 4, 3, 165, 4, 0, 131, 165, 68, 0, 3, 166, 132, 0, 131, 166, 196, 0, 3, 167, 196, 1, 131, 167, 132, 1, 3, 168, 68, 1, 131, 168, 4, 1, 35, 46, 228, 0, 35, 44, 244, 0, 35, 42, 4, 1, 35, 40, 20, 1, 35, 38, 212, 0, 35, 36, 196, 0, 35, 34, 180, 0, 35, 32, 164, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 103, 128, 0, 0, 147, 5, 0, 8, 35, 0, 181, 0, 19, 5, 21, 0, 147, 197, 250, 255, 51, 134, 185, 0, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 128, 150, 19, 133, 252, 255, 99, 132, 12, 10, 19, 21, 37, 0, 179, 5, 170, 0, 3, 165, 5, 0, 99, 24, 5, 14, 19, 85, 137, 0, 55, 6, 1, 0, 19, 6, 6, 240, 51, 117, 197, 0, 19, 86, 137, 1, 51, 101, 197, 0, 19, 22, 137, 0, 183, 6, 255, 0, 51, 118, 214, 0, 147, 22, 137, 1, 51, 230, 198, 0, 51, 101, 166, 0, 35, 160, 165, 0, 19, 215, 76, 0, 147, 6, 10, 2, 147, 2, 48, 0, 19, 5, 4, 0, 147, 133, 4, 0, 19, 6, 10, 0, 115, 0, 0, 0, 19, 6, 64, 0, 19, 5, 10, 0, 147, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 23, 243, 255, 255, 103, 0, 67, 80, 183, 85, 0, 7, 19, 134, 69, 131, 147, 5, 0, 0, 151, 32, 0, 0, 231, 128, 64, 111, 115, 16, 0, 192, 151, 16, 0, 0, 231, 128, 192, 17, 115, 16, 0, 192, 55, 69, 0, 7, 19, 5, 21, 127, 183, 85, 0, 7, 19, 134, 69, 130, 147, 5, 48, 3, 151, 32, 0, 0, 231, 128, 64, 104, 115, 16, 0, 192, 19, 133, 9, 0, 147, 5, 12, 0, 151, 16, 0, 0, 231, 128, 192, 18, 115, 16, 0, 192, 35, 36, 1, 0, 55, 69, 0, 7, 19, 6, 69, 89, 55, 85, 0, 7, 19, 7, 69, 132, 147, 6, 129, 0, 19, 5, 0, 0, 151, 0, 0, 0, 231, 128, 128, 17, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 69, 19, 4, 5, 0, 147, 5, 0, 1, 55, 85, 0, 7, 99, 246, 180, 0, 147, 5, 197, 165, 111, 0, 64, 2, 19, 215, 68, 0, 147, 6, 9, 2, 147, 5, 197, 165, 147, 2, 48, 0, 19, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 147, 5, 4, 0, 147, 151, 84, 0, 19, 245, 4, 255, 19, 21, 37, 0, 51, 6, 169, 0, 19, 149, 196, 1, 147, 86, 165, 1, 19, 5, 4, 0, 19, 7, 0, 0, 151, 0, 0, 0, 231, 128, 128, 204, 19, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 19, 4, 6, 0, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 64, 58, 147, 134, 4, 2, 147, 2, 48, 0, 147, 5, 9, 0, 19, 134, 4, 0, 19, 7, 4, 0, 115, 0, 0, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 19, 8, 7, 0, 3, 167, 70, 1, 131, 167, 6, 1, 35, 32, 177, 0, 35, 34, 193, 0, 35, 46, 225, 0, 35, 44, 241, 0, 131, 165, 198, 0, 3, 166, 134, 0, 3, 167, 70, 0, 131, 166, 6, 0, 35, 42, 177, 0, 35, 40, 193, 0, 35, 38, 225, 0, 35, 36, 209, 0, 183, 85, 0, 7, 19, 134, 69, 133, 147, 5, 1, 0, 147, 6, 65, 0, 147, 7, 129, 0, 19, 7, 6, 0, 151, 32, 0, 0, 231, 128, 0, 88, 115, 16, 0, 192, 147, 135, 5, 0, 99, 154, 213, 0, 147, 5, 6, 0, 19, 134, 7, 0, 23, 3, 0, 0, 103, 0, 195, 122, 19, 133, 7, 0, 147, 133, 6, 0, 19, 6, 7, 0, 151, 16, 0, 0, 231, 128, 64, 75, 115, 16, 0, 192, 19, 1, 1, 239, 35, 38, 17, 16, 35, 36, 129, 16, 35, 34, 145, 16, 35, 32, 33, 17, 35, 46, 49, 15, 35, 44, 65, 15, 35, 42, 81, 15, 55, 5, 8, 0, 35, 34, 5, 0, 19, 5, 69, 0, 147, 5, 69, 0, 19, 5, 1, 0, 19, 6, 192, 4, 151, 0, 0, 0, 231, 128, 64, 117, 147, 4, 129, 0, 35, 38, 1, 6, 35, 36, 1, 6, 35, 34, 1, 6, 35, 32, 1, 6, 35, 46, 1, 4, 3, 37, 65, 0, 3, 68, 129, 4, 35, 44, 1, 4, 35, 42, 1, 4, 19, 21, 149, 0, 147, 21, 52, 0, 51, 101, 181, 0, 147, 85, 133, 0, 55, 6, 1, 0, 19, 6, 6, 240, 179, 245, 197, 0, 19, 86, 133, 1, 51, 233, 197, 0, 19, 21, 133, 0, 183, 5, 255, 0, 51, 117, 181, 0, 147, 21, 180, 1, 179, 233, 165, 0, 51, 133, 132, 0, 147, 5, 0, 8, 35, 0, 181, 0, 147, 5, 240, 3, 35, 40, 1, 4, 99, 12, 180, 0, 19, 70, 244, 3, 19, 5, 21, 0, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 0, 93, 19, 117, 132, 3, 147, 5, 128, 3, 179, 234, 41, 1, 99, 24, 181, 18, 3, 37, 1, 0, 183, 85, 0, 7, 19, 132, 197, 165, 19, 9, 4, 0, 99, 4, 5, 0, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 170, 147, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 169, 19, 138, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 27, 147, 4, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 168, 147, 9, 5, 0, 19, 5, 10, 0, 151, 16, 0, 0, 231, 128, 0, 167, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 9, 0, 19, 134, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 19, 5, 1, 7, 19, 6, 128, 3, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 64, 82, 35, 38, 81, 11, 35, 36, 1, 10, 99, 132, 4, 0, 19, 132, 4, 0, 99, 24, 0, 18, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 161, 19, 9, 5, 0, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 160, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 18, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 158, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 157, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 140, 4, 8, 111, 0, 0, 20, 35, 32, 1, 4, 35, 34, 81, 5, 3, 36, 1, 0, 99, 22, 4, 0, 55, 85, 0, 7, 19, 4, 197, 165, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 152, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 151, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 192, 8, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 149, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 64, 148, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 152, 4, 10, 55, 85, 0, 7, 19, 5, 69, 141, 183, 85, 0, 7, 19, 134, 5, 144, 147, 5, 176, 2, 151, 32, 0, 0, 231, 128, 0, 31, 115, 16, 0, 192, 19, 5, 1, 11, 147, 5, 1, 7, 19, 6, 0, 4, 151, 0, 0, 0, 231, 128, 128, 76, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 141, 19, 9, 5, 0, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 140, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 254, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 138, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 137, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 227, 140, 4, 244, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 134, 19, 6, 5, 0, 147, 134, 5, 0, 55, 85, 0, 7, 19, 7, 69, 140, 19, 5, 1, 5, 147, 5, 0, 2, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 1, 5, 3, 38, 65, 5, 131, 38, 129, 5, 3, 39, 193, 5, 131, 39, 1, 6, 3, 40, 65, 6, 131, 40, 129, 6, 3, 35, 193, 6, 147, 2, 16, 0, 19, 5, 0, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 16, 0, 147, 5, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 32, 0, 147, 133, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 48, 0, 147, 5, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 64, 0, 147, 133, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 80, 0, 147, 5, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 96, 0, 147, 133, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 112, 0, 147, 5, 3, 0, 115, 0, 0, 0, 147, 2, 0, 0, 19, 5, 0, 0, 115, 0, 0, 0, 55, 85, 0, 7, 19, 5, 117, 151, 183, 85, 0, 7, 19, 134, 5, 154, 147, 5, 128, 2, 151, 32, 0, 0, 231, 128, 0, 7, 115, 16, 0, 192, 19, 5, 48, 0, 103, 128, 0, 0, 19, 5, 0, 0, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 3, 37, 5, 0, 19, 4, 6, 0, 151, 0, 0, 0, 231, 128, 192, 121, 147, 5, 5, 0, 19, 22, 36, 0, 19, 5, 112, 0, 99, 132, 197, 0, 19, 5, 64, 0, 131, 32, 193, 0, 3, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 6, 64, 0, 99, 100, 182, 0, 147, 5, 64, 0, 19, 214, 21, 0, 183, 86, 85, 85, 147, 134, 86, 85, 51, 118, 214, 0, 51, 134, 197, 64, 183, 54, 51, 51, 147, 134, 54, 51, 51, 119, 214, 0, 19, 86, 38, 0, 51, 118, 214, 0, 51, 6, 199, 0, 147, 86, 70, 0, 51, 6, 214, 0, 183, 22, 15, 15, 147, 134, 246, 240, 51, 118, 214, 0, 183, 6, 1, 1, 147, 134, 22, 16, 51, 6, 214, 2, 19, 86, 134, 1, 147, 6, 246, 255, 179, 54, 208, 0, 55, 7, 0, 128, 51, 7, 183, 64, 51, 55, 167, 0, 179, 230, 230, 0, 99, 150, 6, 2, 147, 6, 16, 0, 99, 4, 214, 0, 147, 5, 0, 0, 51, 5, 181, 0, 19, 5, 245, 255, 179, 5, 176, 64, 51, 117, 181, 0, 19, 85, 37, 0, 23, 3, 0, 0, 103, 0, 131, 116, 19, 1, 1, 255, 55, 85, 0, 7, 19, 5, 5, 155, 183, 85, 0, 7, 147, 134, 133, 157, 183, 85, 0, 7, 19, 135, 197, 164, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 128, 22, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 7, 64, 0, 19, 137, 6, 0, 19, 132, 5, 0, 147, 4, 5, 0, 99, 100, 199, 0, 19, 6, 64, 0, 19, 85, 22, 0, 183, 85, 85, 85, 147, 133, 85, 85, 51, 117, 181, 0, 51, 5, 166, 64, 183, 53, 51, 51, 147, 133, 53, 51, 179, 118, 181, 0, 19, 85, 37, 0, 51, 117, 181, 0, 51, 133, 166, 0, 147, 85, 69, 0, 51, 5, 181, 0, 183, 21, 15, 15, 147, 133, 245, 240, 51, 117, 181, 0, 183, 5, 1, 1, 147, 133, 21, 16, 51, 5, 181, 2, 19, 85, 133, 1, 147, 5, 245, 255, 179, 53, 176, 0, 183, 6, 0, 128, 179, 134, 198, 64, 179, 182, 38, 1, 179, 229, 213, 0, 99, 152, 5, 6, 147, 5, 16, 0, 99, 4, 181, 0, 19, 6, 0, 0, 51, 5, 201, 0, 19, 5, 245, 255, 179, 5, 192, 64, 51, 117, 181, 0, 19, 85, 37, 0, 151, 0, 0, 0, 231, 128, 0, 101, 147, 9, 5, 0, 99, 0, 5, 2, 99, 100, 36, 1, 19, 4, 9, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 192, 20, 19, 133, 9, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 5, 155, 183, 85, 0, 7, 147, 134, 133, 157, 183, 85, 0, 7, 19, 135, 197, 164, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 0, 3, 115, 16, 0, 192, 99, 10, 6, 14, 35, 0, 181, 0, 179, 6, 166, 0, 19, 7, 48, 0, 163, 143, 182, 254, 99, 96, 230, 14, 163, 0, 181, 0, 35, 1, 181, 0, 35, 143, 182, 254, 19, 7, 112, 0, 163, 142, 182, 254, 99, 100, 230, 12, 163, 1, 181, 0, 147, 7, 144, 0, 35, 142, 182, 254, 99, 108, 246, 10, 179, 6, 160, 64, 19, 247, 54, 0, 179, 6, 229, 0, 51, 6, 230, 64, 19, 118, 198, 255, 147, 245, 245, 15, 55, 7, 1, 1, 19, 7, 23, 16, 179, 133, 229, 2, 35, 160, 182, 0, 51, 135, 198, 0, 35, 46, 183, 254, 99, 98, 246, 8, 35, 162, 182, 0, 35, 164, 182, 0, 35, 42, 183, 254, 147, 7, 144, 1, 35, 44, 183, 254, 99, 102, 246, 6, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 34, 183, 254, 35, 36, 183, 254, 35, 38, 183, 254, 147, 247, 70, 0, 147, 231, 135, 1, 51, 6, 246, 64, 19, 8, 0, 2, 35, 40, 183, 254, 99, 108, 6, 3, 179, 134, 246, 0, 19, 7, 240, 1, 35, 160, 182, 0, 35, 162, 182, 0, 35, 164, 182, 0, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 174, 182, 0, 19, 6, 6, 254, 147, 134, 6, 2, 227, 108, 199, 252, 103, 128, 0, 0, 147, 246, 53, 0, 147, 182, 22, 0, 19, 55, 22, 0, 179, 230, 230, 0, 99, 156, 6, 14, 147, 135, 21, 0, 19, 8, 5, 0, 131, 136, 5, 0, 19, 135, 21, 0, 147, 6, 24, 0, 35, 0, 24, 1, 19, 6, 246, 255, 147, 245, 55, 0, 179, 53, 176, 0, 51, 56, 192, 0, 179, 248, 5, 1, 147, 135, 23, 0, 147, 5, 7, 0, 19, 136, 6, 0, 227, 152, 8, 252, 147, 245, 54, 0, 99, 130, 5, 12, 147, 7, 0, 2, 99, 110, 246, 34, 147, 7, 48, 0, 99, 134, 245, 18, 147, 7, 32, 0, 99, 130, 245, 26, 147, 7, 16, 0, 99, 146, 245, 34, 131, 39, 7, 0, 35, 128, 246, 0, 147, 213, 135, 0, 163, 128, 182, 0, 19, 216, 7, 1, 147, 133, 54, 0, 35, 129, 6, 1, 19, 6, 214, 255, 147, 6, 7, 1, 19, 7, 0, 1, 3, 168, 70, 255, 147, 215, 135, 1, 147, 24, 136, 0, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 1, 19, 152, 130, 0, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 1, 147, 146, 136, 0, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 1, 147, 152, 135, 0, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 54, 255, 111, 0, 0, 25, 147, 6, 5, 0, 19, 135, 5, 0, 147, 245, 54, 0, 227, 146, 5, 244, 147, 5, 0, 1, 99, 108, 182, 2, 147, 5, 240, 0, 131, 39, 7, 0, 3, 40, 71, 0, 131, 40, 135, 0, 131, 34, 199, 0, 35, 160, 246, 0, 35, 162, 6, 1, 35, 164, 22, 1, 35, 166, 86, 0, 19, 7, 7, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 234, 197, 252, 147, 117, 134, 0, 99, 142, 5, 0, 131, 37, 7, 0, 131, 39, 71, 0, 35, 160, 182, 0, 35, 162, 246, 0, 147, 134, 134, 0, 19, 7, 135, 0, 147, 117, 70, 0, 99, 130, 5, 22, 131, 37, 7, 0, 35, 160, 182, 0, 147, 134, 70, 0, 19, 7, 71, 0, 111, 0, 0, 21, 131, 39, 7, 0, 147, 133, 22, 0, 35, 128, 246, 0, 19, 6, 246, 255, 147, 6, 7, 1, 19, 7, 32, 1, 3, 168, 70, 255, 147, 215, 135, 0, 147, 24, 136, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 0, 19, 152, 130, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 0, 147, 146, 136, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 0, 147, 152, 135, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 22, 255, 111, 0, 128, 8, 131, 39, 7, 0, 35, 128, 246, 0, 19, 216, 135, 0, 147, 133, 38, 0, 163, 128, 6, 1, 19, 6, 230, 255, 147, 6, 7, 1, 19, 7, 16, 1, 3, 168, 70, 255, 147, 215, 7, 1, 147, 24, 8, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 8, 1, 19, 152, 2, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 2, 1, 147, 146, 8, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 8, 1, 147, 152, 7, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 38, 255, 147, 134, 5, 0, 147, 117, 6, 1, 99, 146, 5, 8, 147, 117, 134, 0, 99, 152, 5, 16, 147, 117, 70, 0, 99, 136, 5, 2, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 19, 7, 71, 0, 147, 135, 70, 0, 163, 129, 182, 0, 147, 134, 7, 0, 147, 117, 38, 0, 99, 152, 5, 0, 147, 117, 22, 0, 99, 150, 5, 2, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 35, 128, 182, 0, 19, 7, 39, 0, 147, 133, 38, 0, 163, 128, 246, 0, 147, 134, 5, 0, 147, 117, 22, 0, 227, 142, 5, 252, 131, 5, 7, 0, 35, 128, 182, 0, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 131, 5, 135, 0, 3, 8, 151, 0, 163, 131, 246, 0, 131, 7, 167, 0, 35, 132, 182, 0, 163, 132, 6, 1, 131, 5, 183, 0, 35, 133, 246, 0, 131, 7, 199, 0, 3, 8, 215, 0, 163, 133, 182, 0, 131, 5, 231, 0, 35, 134, 246, 0, 163, 134, 6, 1, 131, 7, 247, 0, 35, 135, 182, 0, 19, 7, 7, 1, 147, 133, 6, 1, 163, 135, 246, 0, 147, 134, 5, 0, 147, 117, 134, 0, 227, 140, 5, 238, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 19, 7, 135, 0, 147, 133, 134, 0, 163, 131, 246, 0, 147, 134, 5, 0, 147, 117, 70, 0, 227, 150, 5, 234, 111, 240, 95, 237, 151, 0, 0, 0, 231, 128, 0, 2, 115, 16, 0, 192, 147, 5, 5, 2, 103, 128, 0, 0, 103, 128, 0, 0, 147, 5, 0, 2, 103, 128, 0, 0, 55, 86, 0, 7, 19, 6, 182, 176, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 55, 85, 0, 7, 19, 5, 53, 174, 183, 85, 0, 7, 19, 134, 133, 179, 147, 5, 128, 2, 151, 32, 0, 0, 231, 128, 0, 139, 115, 16, 0, 192, 147, 7, 6, 0, 19, 23, 38, 0, 55, 86, 0, 7, 19, 6, 134, 180, 147, 2, 32, 0, 147, 6, 5, 0, 19, 133, 5, 0, 147, 133, 7, 0, 115, 0, 0, 0, 99, 100, 167, 0, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 53, 183, 183, 85, 0, 7, 19, 134, 133, 186, 147, 5, 64, 3, 151, 32, 0, 0, 231, 128, 64, 134, 115, 16, 0, 192, 147, 7, 6, 0, 55, 86, 0, 7, 19, 6, 134, 187, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 103, 128, 0, 0, 183, 5, 8, 0, 131, 166, 5, 0, 51, 134, 166, 64, 99, 236, 198, 0, 19, 149, 38, 0, 183, 6, 0, 8, 51, 133, 166, 64, 35, 160, 197, 0, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 69, 190, 183, 85, 0, 7, 19, 134, 69, 191, 147, 5, 224, 0, 151, 16, 0, 0, 231, 128, 64, 193, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 106, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 120, 150, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 4, 6, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 19, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 192, 27, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 128, 29, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 138, 5, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 248, 149, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 132, 5, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 5, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 0, 14, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 15, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 10, 6, 2, 99, 202, 4, 2, 3, 165, 134, 0, 99, 12, 5, 2, 131, 165, 70, 0, 99, 136, 5, 2, 3, 165, 6, 0, 19, 6, 16, 0, 147, 134, 4, 0, 151, 224, 255, 255, 231, 128, 0, 68, 99, 18, 5, 4, 111, 0, 192, 2, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 192, 3, 99, 132, 4, 2, 147, 5, 16, 0, 19, 133, 4, 0, 151, 224, 255, 255, 231, 128, 64, 64, 99, 28, 5, 0, 35, 34, 148, 0, 147, 5, 16, 0, 35, 36, 180, 0, 111, 0, 64, 1, 19, 5, 16, 0, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 254, 55, 85, 0, 7, 19, 5, 69, 205, 35, 36, 161, 0, 19, 5, 16, 0, 35, 38, 161, 0, 35, 40, 1, 0, 55, 85, 0, 7, 19, 5, 69, 192, 35, 44, 161, 0, 35, 46, 1, 0, 55, 85, 0, 7, 147, 5, 197, 205, 19, 5, 129, 0, 151, 16, 0, 0, 231, 128, 64, 81, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 151, 224, 255, 255, 231, 128, 128, 56, 115, 16, 0, 192, 151, 240, 255, 255, 231, 128, 64, 194, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 19, 132, 5, 0, 131, 165, 69, 0, 147, 4, 5, 0, 99, 134, 5, 4, 3, 37, 4, 0, 19, 9, 0, 0, 19, 6, 69, 0, 147, 150, 53, 0, 3, 39, 6, 0, 51, 9, 39, 1, 147, 134, 134, 255, 19, 6, 134, 0, 227, 152, 6, 254, 3, 38, 68, 1, 99, 0, 6, 4, 99, 132, 5, 2, 3, 37, 69, 0, 19, 53, 21, 0, 147, 53, 9, 1, 51, 117, 181, 0, 99, 10, 5, 0, 111, 0, 192, 1, 3, 37, 68, 1, 19, 9, 0, 0, 99, 4, 5, 4, 19, 5, 9, 0, 51, 9, 41, 1, 99, 118, 169, 0, 19, 9, 0, 0, 111, 0, 64, 3, 99, 8, 9, 2, 99, 72, 9, 12, 147, 5, 16, 0, 19, 5, 9, 0, 151, 224, 255, 255, 231, 128, 128, 43, 99, 30, 5, 0, 147, 5, 16, 0, 19, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 5, 16, 0, 35, 160, 164, 0, 35, 162, 36, 1, 3, 37, 68, 1, 131, 37, 4, 1, 35, 164, 4, 0, 35, 38, 145, 0, 35, 34, 161, 2, 35, 32, 177, 2, 3, 37, 196, 0, 131, 37, 132, 0, 3, 38, 68, 0, 131, 38, 4, 0, 35, 46, 161, 0, 35, 44, 177, 0, 35, 42, 193, 0, 35, 40, 209, 0, 55, 85, 0, 7, 147, 5, 197, 223, 19, 5, 193, 0, 19, 6, 1, 1, 151, 16, 0, 0, 231, 128, 0, 151, 99, 30, 5, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 19, 1, 1, 4, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 197, 206, 183, 85, 0, 7, 147, 134, 5, 210, 183, 85, 0, 7, 19, 135, 197, 222, 147, 5, 48, 3, 19, 6, 129, 2, 151, 16, 0, 0, 231, 128, 64, 94, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 64, 227, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 36, 5, 0, 19, 5, 0, 8, 147, 132, 5, 0, 99, 252, 165, 2, 131, 37, 132, 0, 3, 37, 68, 0, 99, 154, 165, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 132, 0, 3, 37, 4, 0, 51, 5, 181, 0, 35, 0, 149, 0, 3, 37, 132, 0, 19, 5, 21, 0, 111, 0, 64, 13, 19, 213, 180, 0, 35, 38, 1, 0, 99, 18, 5, 2, 19, 213, 100, 0, 19, 101, 5, 12, 35, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 6, 161, 0, 147, 4, 32, 0, 111, 0, 192, 7, 19, 213, 4, 1, 99, 26, 5, 2, 19, 213, 196, 0, 19, 101, 5, 14, 35, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 35, 7, 161, 0, 147, 4, 48, 0, 111, 0, 64, 4, 19, 149, 180, 0, 19, 85, 213, 1, 19, 101, 5, 15, 35, 6, 161, 0, 19, 149, 228, 0, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 35, 7, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 7, 161, 0, 147, 4, 64, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 99, 96, 149, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 5, 193, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 88, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 192, 166, 3, 41, 132, 0, 111, 240, 223, 250, 19, 1, 1, 253, 35, 38, 17, 2, 3, 37, 5, 0, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 85, 0, 7, 147, 5, 197, 223, 19, 5, 193, 0, 19, 6, 1, 1, 151, 0, 0, 0, 231, 128, 192, 117, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 3, 36, 5, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 98, 197, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 133, 9, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 73, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 151, 3, 41, 132, 0, 111, 240, 159, 250, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 5, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 85, 0, 7, 19, 6, 69, 238, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 16, 0, 0, 231, 128, 128, 137, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 85, 0, 7, 19, 134, 69, 237, 147, 5, 0, 8, 151, 0, 0, 0, 231, 128, 0, 84, 115, 16, 0, 192, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 3, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 85, 0, 7, 19, 6, 69, 238, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 0, 0, 0, 231, 128, 128, 126, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 85, 0, 7, 19, 134, 69, 237, 147, 5, 0, 8, 151, 0, 0, 0, 231, 128, 0, 73, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 69, 0, 7, 19, 5, 5, 79, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 85, 0, 7, 19, 5, 5, 255, 35, 36, 161, 0, 19, 5, 48, 0, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 1, 2, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 192, 245, 115, 16, 0, 192, 19, 6, 5, 0, 19, 5, 53, 0, 19, 119, 197, 255, 179, 6, 199, 64, 99, 238, 213, 0, 51, 133, 213, 64, 147, 55, 69, 0, 19, 184, 86, 0, 19, 72, 24, 0, 179, 103, 248, 0, 99, 134, 7, 2, 19, 5, 0, 0, 99, 128, 5, 2, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 103, 128, 0, 0, 147, 117, 53, 0, 147, 7, 0, 0, 99, 4, 199, 2, 51, 7, 230, 64, 19, 8, 6, 0, 131, 8, 8, 0, 19, 8, 24, 0, 147, 168, 8, 252, 147, 200, 24, 0, 19, 7, 23, 0, 179, 135, 23, 1, 227, 20, 7, 254, 179, 6, 214, 0, 19, 8, 0, 0, 99, 132, 5, 2, 19, 118, 197, 255, 51, 134, 198, 0, 3, 7, 6, 0, 19, 6, 22, 0, 19, 39, 7, 252, 19, 71, 23, 0, 147, 133, 245, 255, 51, 8, 232, 0, 227, 148, 5, 254, 147, 82, 37, 0, 55, 5, 1, 1, 19, 7, 21, 16, 55, 5, 255, 0, 147, 5, 245, 15, 55, 5, 1, 0, 19, 6, 21, 0, 51, 5, 248, 0, 111, 0, 64, 3, 147, 146, 40, 0, 179, 134, 86, 0, 179, 130, 23, 65, 147, 248, 56, 0, 179, 115, 179, 0, 19, 83, 131, 0, 51, 115, 179, 0, 51, 3, 115, 0, 51, 3, 195, 2, 19, 83, 3, 1, 51, 5, 163, 0, 99, 152, 8, 10, 227, 132, 2, 244, 147, 135, 2, 0, 19, 8, 0, 12, 147, 136, 2, 0, 99, 228, 2, 1, 147, 8, 0, 12, 147, 242, 200, 15, 19, 152, 34, 0, 51, 136, 6, 1, 19, 3, 0, 0, 227, 132, 2, 250, 147, 130, 6, 0, 131, 163, 2, 0, 19, 206, 243, 255, 19, 94, 126, 0, 147, 211, 99, 0, 131, 174, 66, 0, 179, 99, 126, 0, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 130, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 194, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 179, 227, 195, 1, 179, 243, 227, 0, 147, 130, 2, 1, 51, 131, 99, 0, 227, 150, 2, 249, 111, 240, 159, 242, 147, 6, 0, 12, 99, 228, 215, 0, 147, 7, 0, 12, 147, 6, 0, 0, 147, 247, 55, 0, 147, 151, 39, 0, 131, 40, 8, 0, 19, 8, 72, 0, 147, 194, 248, 255, 147, 210, 114, 0, 147, 216, 104, 0, 179, 232, 18, 1, 179, 248, 232, 0, 147, 135, 199, 255, 179, 134, 216, 0, 227, 158, 7, 252, 51, 247, 182, 0, 147, 214, 134, 0, 179, 245, 182, 0, 179, 133, 229, 0, 179, 133, 197, 2, 147, 213, 5, 1, 51, 133, 165, 0, 103, 128, 0, 0, 55, 5, 177, 232, 19, 5, 133, 221, 183, 69, 246, 136, 147, 133, 69, 41, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 3, 164, 197, 1, 131, 164, 133, 1, 131, 38, 196, 0, 19, 9, 5, 0, 55, 85, 0, 7, 147, 5, 69, 2, 19, 6, 192, 0, 19, 133, 4, 0, 231, 128, 6, 0, 147, 9, 16, 0, 99, 16, 5, 18, 3, 37, 137, 0, 99, 14, 5, 0, 35, 34, 161, 0, 19, 5, 65, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 197, 252, 111, 0, 192, 4, 3, 37, 73, 0, 131, 41, 9, 0, 131, 37, 197, 0, 19, 133, 9, 0, 231, 128, 5, 0, 55, 214, 68, 116, 19, 6, 118, 236, 179, 197, 197, 0, 55, 214, 164, 102, 19, 6, 182, 142, 51, 69, 197, 0, 51, 101, 181, 0, 99, 30, 5, 4, 35, 34, 49, 1, 19, 5, 65, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 5, 251, 35, 38, 161, 0, 55, 85, 0, 7, 19, 5, 69, 3, 35, 32, 161, 2, 19, 5, 32, 0, 35, 34, 161, 2, 35, 36, 1, 2, 19, 5, 129, 0, 35, 40, 161, 2, 147, 9, 16, 0, 35, 42, 49, 3, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 30, 99, 26, 5, 6, 3, 37, 201, 0, 147, 5, 133, 0, 19, 6, 197, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 133, 2, 35, 38, 161, 0, 35, 40, 177, 0, 55, 69, 0, 7, 19, 5, 5, 79, 35, 42, 161, 0, 35, 44, 193, 0, 35, 46, 161, 0, 55, 85, 0, 7, 19, 5, 197, 0, 35, 32, 161, 2, 19, 5, 48, 0, 35, 34, 161, 2, 35, 36, 1, 2, 147, 5, 129, 0, 35, 40, 177, 2, 35, 42, 161, 2, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 23, 147, 9, 5, 0, 19, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 19, 1, 1, 5, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 255, 35, 36, 161, 0, 35, 38, 177, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 55, 69, 0, 7, 19, 5, 133, 2, 35, 46, 161, 0, 55, 85, 0, 7, 19, 5, 69, 4, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 19, 6, 129, 1, 35, 40, 193, 0, 35, 42, 161, 0, 19, 5, 1, 0, 151, 16, 0, 0, 231, 128, 0, 180, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 1, 0, 35, 32, 161, 2, 55, 69, 0, 7, 19, 5, 5, 79, 35, 34, 161, 2, 147, 5, 65, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 85, 0, 7, 19, 5, 5, 8, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 0, 173, 115, 16, 0, 192, 3, 37, 5, 0, 111, 0, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 69, 1, 3, 39, 5, 1, 131, 39, 197, 0, 35, 42, 193, 0, 131, 166, 133, 1, 35, 40, 225, 0, 35, 38, 241, 0, 3, 38, 133, 0, 3, 39, 69, 0, 3, 37, 5, 0, 131, 165, 197, 1, 35, 36, 193, 0, 35, 34, 225, 0, 35, 32, 161, 0, 19, 6, 1, 0, 19, 133, 6, 0, 151, 0, 0, 0, 231, 128, 64, 1, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 19, 4, 6, 0, 35, 36, 1, 0, 19, 6, 0, 2, 35, 38, 193, 0, 19, 6, 48, 0, 35, 4, 193, 2, 3, 38, 132, 0, 35, 40, 1, 0, 35, 44, 1, 0, 35, 32, 161, 2, 35, 34, 177, 2, 99, 4, 6, 18, 3, 37, 196, 0, 99, 10, 5, 24, 131, 37, 4, 0, 147, 6, 245, 255, 147, 150, 86, 0, 147, 214, 86, 0, 147, 132, 22, 0, 19, 137, 69, 0, 147, 25, 85, 0, 19, 10, 6, 1, 147, 10, 16, 0, 55, 53, 0, 7, 19, 11, 5, 88, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 22, 5, 24, 3, 37, 74, 255, 35, 38, 161, 0, 3, 5, 202, 0, 35, 4, 161, 2, 131, 37, 138, 255, 3, 37, 4, 1, 35, 36, 177, 0, 131, 38, 74, 0, 131, 37, 138, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 40, 193, 0, 35, 42, 177, 0, 131, 38, 202, 255, 131, 37, 10, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 44, 193, 0, 35, 46, 177, 0, 131, 37, 10, 255, 147, 149, 53, 0, 51, 5, 181, 0, 3, 38, 69, 0, 3, 37, 5, 0, 147, 5, 129, 0, 231, 0, 6, 0, 99, 24, 5, 12, 19, 9, 137, 0, 147, 137, 9, 254, 19, 10, 10, 2, 227, 156, 9, 240, 111, 0, 192, 7, 3, 37, 68, 1, 99, 0, 5, 8, 131, 37, 4, 1, 3, 38, 4, 0, 147, 6, 245, 255, 147, 150, 54, 0, 147, 214, 54, 0, 147, 132, 22, 0, 19, 9, 70, 0, 147, 137, 69, 0, 19, 26, 53, 0, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 24, 5, 6, 3, 166, 9, 0, 3, 165, 201, 255, 147, 5, 129, 0, 231, 0, 6, 0, 99, 30, 5, 4, 19, 9, 137, 0, 19, 10, 138, 255, 147, 137, 137, 0, 227, 16, 10, 252, 111, 0, 128, 0, 147, 4, 0, 0, 3, 37, 68, 0, 99, 234, 164, 0, 111, 0, 0, 4, 147, 4, 0, 0, 3, 37, 68, 0, 99, 250, 164, 2, 3, 37, 4, 0, 147, 149, 52, 0, 51, 6, 181, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 6, 0, 3, 38, 70, 0, 131, 166, 198, 0, 231, 128, 6, 0, 99, 6, 5, 0, 19, 5, 16, 0, 111, 0, 128, 0, 19, 5, 0, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 19, 1, 1, 5, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 132, 7, 0, 147, 4, 7, 0, 147, 137, 6, 0, 19, 10, 6, 0, 19, 9, 5, 0, 99, 142, 5, 4, 3, 43, 9, 0, 19, 117, 27, 0, 183, 10, 17, 0, 99, 4, 5, 0, 147, 10, 176, 2, 179, 12, 133, 0, 19, 117, 75, 0, 99, 8, 5, 4, 19, 5, 0, 1, 99, 252, 169, 4, 19, 5, 0, 0, 99, 128, 9, 6, 147, 133, 9, 0, 19, 6, 10, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 111, 0, 128, 3, 3, 43, 9, 0, 147, 12, 20, 0, 147, 10, 208, 2, 19, 117, 75, 0, 227, 28, 5, 250, 19, 10, 0, 0, 3, 37, 137, 0, 99, 18, 5, 2, 111, 0, 0, 6, 19, 5, 10, 0, 147, 133, 9, 0, 151, 240, 255, 255, 231, 128, 128, 125, 179, 12, 149, 1, 3, 37, 137, 0, 99, 2, 5, 4, 3, 45, 201, 0, 99, 254, 172, 3, 19, 117, 139, 0, 99, 22, 5, 14, 131, 69, 9, 2, 19, 6, 48, 0, 19, 5, 16, 0, 99, 132, 197, 0, 19, 133, 5, 0, 147, 117, 53, 0, 51, 5, 157, 65, 99, 140, 5, 18, 19, 6, 16, 0, 99, 158, 197, 18, 147, 12, 0, 0, 111, 0, 0, 20, 131, 43, 137, 1, 3, 41, 201, 1, 19, 133, 11, 0, 147, 5, 9, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 128, 31, 19, 11, 16, 0, 99, 2, 5, 4, 19, 5, 11, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 3, 35, 201, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 0, 3, 0, 3, 37, 73, 0, 35, 36, 161, 0, 19, 5, 0, 3, 131, 77, 9, 2, 131, 43, 137, 1, 3, 44, 201, 1, 35, 34, 169, 0, 19, 11, 16, 0, 35, 0, 105, 3, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 18, 227, 26, 5, 242, 51, 5, 157, 65, 147, 9, 21, 0, 147, 137, 249, 255, 99, 138, 9, 12, 3, 38, 12, 1, 147, 5, 0, 3, 19, 133, 11, 0, 231, 0, 6, 0, 227, 4, 5, 254, 111, 240, 223, 240, 147, 12, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 220, 21, 0, 131, 43, 137, 1, 3, 44, 201, 1, 3, 41, 73, 0, 19, 11, 21, 0, 19, 11, 251, 255, 99, 0, 11, 2, 3, 38, 12, 1, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 11, 16, 0, 111, 240, 31, 236, 55, 5, 17, 0, 19, 11, 16, 0, 227, 10, 169, 234, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 8, 227, 26, 5, 232, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 230, 19, 4, 0, 0, 99, 136, 140, 4, 3, 38, 12, 1, 19, 4, 20, 0, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 64, 3, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 226, 19, 11, 0, 0, 3, 37, 129, 0, 35, 34, 169, 0, 35, 0, 185, 3, 111, 240, 159, 226, 19, 133, 12, 0, 51, 59, 149, 1, 111, 240, 223, 225, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 183, 7, 17, 0, 19, 4, 7, 0, 147, 132, 6, 0, 147, 137, 5, 0, 19, 9, 5, 0, 99, 0, 246, 2, 131, 166, 9, 1, 19, 5, 9, 0, 147, 5, 6, 0, 231, 128, 6, 0, 147, 5, 5, 0, 19, 5, 16, 0, 99, 156, 5, 2, 99, 136, 4, 2, 3, 163, 201, 0, 19, 5, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 0, 3, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 35, 32, 97, 1, 19, 9, 5, 0, 3, 37, 133, 0, 131, 38, 9, 1, 19, 7, 245, 255, 51, 55, 224, 0, 147, 135, 246, 255, 179, 55, 240, 0, 51, 119, 247, 0, 19, 4, 6, 0, 147, 132, 5, 0, 99, 16, 7, 30, 147, 5, 16, 0, 99, 154, 182, 20, 3, 38, 73, 1, 147, 5, 0, 0, 179, 134, 132, 0, 19, 7, 22, 0, 183, 7, 17, 0, 19, 8, 240, 13, 147, 8, 0, 15, 19, 134, 4, 0, 111, 0, 128, 1, 19, 3, 22, 0, 179, 133, 197, 64, 179, 133, 101, 0, 19, 6, 3, 0, 99, 142, 242, 16, 19, 7, 247, 255, 99, 0, 7, 8, 99, 8, 214, 16, 3, 3, 6, 0, 147, 114, 243, 15, 227, 92, 3, 252, 3, 67, 22, 0, 147, 243, 242, 1, 19, 126, 243, 3, 99, 112, 88, 4, 3, 67, 38, 0, 19, 30, 110, 0, 19, 115, 243, 3, 51, 110, 110, 0, 99, 238, 18, 3, 131, 66, 54, 0, 19, 147, 211, 1, 19, 83, 179, 0, 147, 19, 110, 0, 147, 242, 242, 3, 179, 226, 83, 0, 179, 226, 98, 0, 99, 128, 242, 12, 19, 3, 70, 0, 111, 240, 31, 249, 19, 3, 38, 0, 147, 146, 99, 0, 179, 226, 194, 1, 111, 240, 31, 248, 19, 3, 54, 0, 147, 146, 195, 0, 179, 98, 94, 0, 111, 240, 31, 247, 99, 10, 214, 8, 131, 6, 6, 0, 99, 218, 6, 4, 147, 246, 246, 15, 19, 7, 0, 14, 99, 228, 230, 4, 19, 7, 0, 15, 99, 224, 230, 4, 3, 71, 22, 0, 131, 71, 38, 0, 19, 119, 247, 3, 147, 247, 247, 3, 3, 70, 54, 0, 147, 150, 214, 1, 147, 214, 182, 0, 19, 23, 199, 0, 147, 151, 103, 0, 51, 231, 231, 0, 19, 118, 246, 3, 51, 102, 199, 0, 51, 102, 214, 0, 183, 6, 17, 0, 99, 14, 214, 2, 99, 132, 5, 2, 99, 240, 133, 2, 51, 134, 180, 0, 3, 6, 6, 0, 147, 6, 0, 252, 99, 90, 214, 0, 19, 6, 0, 0, 99, 26, 6, 0, 111, 0, 128, 1, 227, 154, 133, 254, 19, 134, 4, 0, 99, 6, 6, 0, 19, 132, 5, 0, 147, 4, 6, 0, 99, 2, 5, 8, 131, 41, 201, 0, 19, 5, 0, 1, 99, 114, 164, 6, 19, 5, 0, 0, 99, 4, 4, 2, 147, 5, 4, 0, 19, 134, 4, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 99, 116, 53, 5, 131, 69, 9, 2, 147, 6, 48, 0, 19, 6, 0, 0, 99, 132, 213, 0, 19, 134, 5, 0, 147, 117, 54, 0, 51, 133, 169, 64, 99, 130, 5, 6, 19, 6, 16, 0, 99, 148, 197, 6, 147, 10, 0, 0, 111, 0, 192, 6, 19, 133, 4, 0, 147, 5, 4, 0, 151, 240, 255, 255, 231, 128, 64, 43, 227, 96, 53, 253, 131, 37, 201, 1, 3, 37, 137, 1, 3, 163, 197, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 0, 3, 0, 147, 10, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 218, 21, 0, 131, 41, 137, 1, 3, 43, 201, 1, 3, 41, 73, 0, 19, 10, 21, 0, 19, 10, 250, 255, 99, 0, 10, 2, 3, 38, 11, 1, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 10, 16, 0, 111, 0, 128, 5, 55, 5, 17, 0, 19, 10, 16, 0, 99, 6, 169, 4, 131, 38, 203, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 99, 26, 5, 2, 19, 4, 0, 0, 99, 130, 138, 2, 3, 38, 11, 1, 19, 4, 20, 0, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 128, 0, 19, 133, 10, 0, 51, 58, 85, 1, 19, 5, 10, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 197, 1, 3, 37, 133, 1, 3, 163, 198, 0, 103, 0, 3, 0, 3, 69, 5, 0, 19, 21, 181, 1, 19, 85, 245, 1, 103, 128, 0, 0, 3, 69, 5, 0, 19, 21, 165, 1, 19, 85, 245, 1, 103, 128, 0, 0, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 85, 0, 7, 147, 133, 5, 9, 19, 6, 80, 0, 103, 0, 3, 0, 3, 38, 69, 0, 3, 37, 5, 0, 3, 35, 198, 0, 103, 0, 3, 0, 3, 37, 5, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 195, 197, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 5, 0, 3, 37, 70, 1, 35, 42, 161, 0, 3, 37, 6, 1, 35, 40, 161, 0, 3, 37, 198, 0, 35, 38, 161, 0, 3, 37, 134, 0, 35, 36, 161, 0, 131, 38, 70, 0, 3, 165, 133, 1, 35, 34, 209, 0, 3, 38, 6, 0, 131, 165, 197, 1, 35, 32, 193, 0, 19, 6, 1, 0, 151, 240, 255, 255, 231, 128, 0, 93, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 131, 190, 103, 128, 0, 0, 19, 1, 1, 254, 55, 86, 0, 7, 19, 6, 134, 9, 35, 36, 193, 0, 55, 86, 0, 7, 19, 6, 134, 11, 35, 38, 193, 0, 35, 40, 161, 0, 35, 42, 177, 0, 19, 5, 16, 0, 35, 12, 161, 0, 19, 5, 129, 0, 151, 208, 255, 255, 231, 128, 0, 104, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 35, 46, 177, 0, 19, 5, 129, 1, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 55, 85, 0, 7, 19, 5, 133, 9, 35, 40, 161, 0, 35, 42, 1, 0, 19, 5, 1, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 248, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 69, 0, 7, 19, 5, 5, 79, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 85, 0, 7, 19, 5, 197, 13, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 1, 1, 249, 35, 36, 177, 0, 35, 38, 193, 0, 35, 40, 209, 0, 19, 117, 245, 15, 35, 42, 225, 0, 99, 12, 5, 0, 147, 5, 16, 0, 99, 20, 181, 8, 55, 85, 0, 7, 19, 5, 53, 15, 111, 0, 192, 0, 55, 85, 0, 7, 19, 5, 85, 15, 35, 44, 161, 0, 19, 5, 32, 0, 131, 165, 7, 0, 35, 46, 161, 0, 99, 158, 5, 6, 19, 5, 129, 1, 35, 44, 161, 2, 55, 69, 0, 7, 19, 5, 133, 2, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 69, 0, 7, 19, 5, 5, 250, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 55, 85, 0, 7, 19, 5, 133, 21, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 19, 5, 129, 3, 35, 36, 161, 6, 19, 5, 48, 0, 111, 0, 128, 11, 55, 85, 0, 7, 19, 5, 197, 14, 35, 44, 161, 0, 19, 5, 112, 0, 131, 165, 7, 0, 35, 46, 161, 0, 227, 134, 5, 248, 3, 165, 71, 1, 131, 165, 7, 1, 35, 42, 161, 2, 35, 40, 177, 2, 3, 165, 199, 0, 131, 165, 135, 0, 3, 166, 71, 0, 131, 166, 7, 0, 35, 38, 161, 2, 35, 36, 177, 2, 35, 34, 193, 2, 35, 32, 209, 2, 19, 5, 129, 1, 35, 44, 161, 2, 55, 69, 0, 7, 19, 5, 133, 2, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 69, 0, 7, 19, 5, 5, 250, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 19, 5, 1, 2, 35, 40, 161, 4, 55, 53, 0, 7, 19, 5, 133, 88, 35, 42, 161, 4, 55, 85, 0, 7, 19, 5, 69, 19, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 147, 5, 129, 3, 35, 36, 177, 6, 35, 38, 161, 6, 19, 5, 129, 5, 147, 5, 8, 0, 151, 0, 0, 0, 231, 128, 64, 219, 115, 16, 0, 192, 19, 1, 1, 252, 35, 36, 161, 0, 35, 38, 177, 0, 35, 40, 193, 0, 35, 42, 209, 0, 19, 5, 129, 0, 35, 40, 161, 2, 55, 69, 0, 7, 19, 5, 133, 2, 35, 42, 161, 2, 19, 5, 1, 1, 35, 44, 161, 2, 55, 69, 0, 7, 19, 5, 5, 250, 35, 46, 161, 2, 55, 85, 0, 7, 19, 5, 197, 23, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 147, 5, 7, 0, 151, 0, 0, 0, 231, 128, 0, 212, 115, 16, 0, 192, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 85, 0, 7, 147, 133, 197, 24, 19, 6, 176, 0, 103, 0, 3, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 147, 6, 6, 0, 19, 87, 69, 0, 147, 7, 16, 39, 19, 6, 112, 2, 99, 118, 247, 2, 19, 7, 48, 6, 99, 106, 167, 12, 19, 7, 160, 0, 99, 118, 229, 18, 19, 6, 246, 255, 19, 7, 209, 0, 51, 7, 199, 0, 19, 5, 5, 3, 35, 0, 167, 0, 111, 0, 0, 20, 19, 6, 0, 0, 55, 23, 183, 209, 19, 7, 151, 117, 183, 39, 0, 0, 147, 135, 7, 113, 55, 24, 0, 0, 19, 8, 184, 71, 147, 8, 64, 6, 183, 82, 0, 7, 147, 130, 98, 238, 19, 3, 209, 0, 183, 227, 245, 5, 147, 131, 243, 15, 19, 14, 5, 0, 51, 53, 229, 2, 19, 85, 213, 0, 179, 14, 245, 2, 179, 14, 222, 65, 19, 159, 14, 1, 19, 95, 47, 1, 51, 15, 15, 3, 147, 95, 31, 1, 19, 95, 15, 1, 19, 127, 239, 127, 179, 143, 31, 3, 179, 142, 254, 65, 147, 158, 30, 1, 147, 222, 14, 1, 51, 143, 226, 1, 179, 15, 195, 0, 3, 68, 15, 0, 3, 15, 31, 0, 179, 142, 210, 1, 131, 132, 30, 0, 131, 206, 14, 0, 35, 130, 239, 3, 163, 129, 143, 2, 35, 131, 159, 2, 163, 130, 223, 3, 19, 6, 198, 255, 227, 234, 195, 249, 19, 6, 118, 2, 19, 7, 48, 6, 227, 122, 167, 242, 19, 23, 5, 1, 19, 87, 39, 1, 183, 23, 0, 0, 147, 135, 183, 71, 51, 7, 247, 2, 19, 87, 23, 1, 147, 7, 64, 6, 179, 7, 247, 2, 51, 5, 245, 64, 19, 21, 21, 1, 19, 85, 5, 1, 19, 6, 230, 255, 183, 87, 0, 7, 147, 135, 103, 238, 51, 133, 167, 0, 131, 7, 21, 0, 3, 69, 5, 0, 19, 8, 209, 0, 51, 8, 200, 0, 163, 0, 248, 0, 35, 0, 168, 0, 19, 5, 7, 0, 19, 7, 160, 0, 227, 110, 229, 236, 19, 21, 21, 0, 19, 6, 230, 255, 55, 87, 0, 7, 19, 7, 103, 238, 51, 5, 167, 0, 3, 7, 21, 0, 3, 69, 5, 0, 147, 7, 209, 0, 179, 135, 199, 0, 163, 128, 231, 0, 35, 128, 167, 0, 19, 5, 209, 0, 51, 7, 197, 0, 19, 5, 112, 2, 179, 7, 197, 64, 55, 85, 0, 7, 19, 6, 117, 25, 19, 133, 6, 0, 147, 6, 0, 0, 151, 240, 255, 255, 231, 128, 64, 55, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 19, 1, 1, 4, 103, 128, 0, 0, 3, 37, 5, 0, 19, 134, 5, 0, 147, 5, 16, 0, 23, 3, 0, 0, 103, 0, 3, 227, 73, 110, 118, 97, 108, 105, 100, 32, 71, 97, 109, 101, 83, 116, 97, 116, 101, 0, 0, 0, 4, 69, 0, 7, 17, 0, 0, 0, 115, 114, 99, 47, 98, 105, 110, 47, 105, 110, 105, 116, 46, 114, 115, 0, 32, 69, 0, 7, 15, 0, 0, 0, 16, 0, 0, 0, 9, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 232, 7, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 116, 20, 0, 7, 32, 69, 0, 7, 15, 0, 0, 0, 18, 0, 0, 0, 34, 0, 0, 0, 68, 105, 103, 101, 115, 116, 0, 0, 0, 0, 0, 0, 115, 97, 108, 116, 71, 97, 109, 101, 83, 116, 97, 116, 101, 115, 104, 105, 112, 115, 0, 0, 165, 69, 0, 7, 5, 0, 0, 0, 152, 69, 0, 7, 4, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 88, 13, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 116, 20, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 101, 110, 118, 46, 114, 115, 248, 69, 0, 7, 96, 0, 0, 0, 14, 1, 0, 0, 9, 0, 0, 0, 248, 69, 0, 7, 96, 0, 0, 0, 99, 1, 0, 0, 9, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 47, 103, 97, 109, 101, 47, 115, 114, 99, 47, 115, 116, 97, 116, 101, 46, 114, 115, 120, 70, 0, 7, 56, 0, 0, 0, 70, 0, 0, 0, 24, 0, 0, 0, 120, 70, 0, 7, 56, 0, 0, 0, 62, 0, 0, 0, 24, 0, 0, 0, 148, 20, 0, 7, 168, 20, 0, 7, 188, 20, 0, 7, 208, 20, 0, 7, 228, 20, 0, 7, 248, 20, 0, 7, 12, 21, 0, 7, 83, 101, 114, 105, 97, 108, 105, 122, 101, 66, 117, 102, 102, 101, 114, 70, 117, 108, 108, 78, 111, 116, 83, 117, 112, 112, 111, 114, 116, 101, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 85, 110, 101, 120, 112, 101, 99, 116, 101, 100, 69, 110, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 85, 116, 102, 56, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 79, 112, 116, 105, 111, 110, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 67, 104, 97, 114, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 66, 111, 111, 108, 0, 0, 0, 112, 71, 0, 7, 0, 0, 0, 0, 77, 101, 109, 111, 114, 121, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 102, 97, 105, 108, 117, 114, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 115, 104, 97, 46, 114, 115, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 98, 121, 116, 101, 115, 46, 108, 101, 110, 40, 41, 32, 60, 61, 32, 112, 97, 100, 108, 101, 110, 32, 42, 32, 87, 79, 82, 68, 95, 83, 73, 90, 69, 145, 71, 0, 7, 96, 0, 0, 0, 112, 0, 0, 0, 5, 0, 0, 0, 145, 71, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 20, 0, 0, 0, 145, 71, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 9, 0, 0, 0, 76, 27, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 32, 21, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 112, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 99, 111, 114, 101, 47, 115, 104, 97, 46, 114, 115, 0, 0, 100, 72, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 17, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 0, 100, 72, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 33, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 0, 16, 73, 0, 7, 103, 0, 0, 0, 222, 0, 0, 0, 9, 0, 0, 0, 85, 110, 97, 98, 108, 101, 32, 116, 111, 32, 97, 108, 105, 103, 110, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 116, 111, 32, 119, 111, 114, 100, 32, 115, 105, 122, 101, 0, 108, 32, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 16, 67, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 97, 108, 108, 111, 99, 46, 114, 115, 0, 0, 232, 73, 0, 7, 98, 0, 0, 0, 27, 0, 0, 0, 22, 0, 0, 0, 106, 9, 230, 103, 187, 103, 174, 133, 60, 110, 243, 114, 165, 79, 245, 58, 81, 14, 82, 127, 155, 5, 104, 140, 31, 131, 217, 171, 91, 224, 205, 25, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 80, 65, 78, 73, 67, 0, 0, 124, 74, 0, 7, 103, 0, 0, 0, 62, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 82, 69, 65, 68, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 110, 114, 101, 97, 100, 32, 97, 115, 32, 117, 115, 105, 122, 101, 32, 60, 61, 32, 110, 98, 121, 116, 101, 115, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 0, 124, 74, 0, 7, 103, 0, 0, 0, 189, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 87, 82, 73, 84, 69, 0, 79, 117, 116, 32, 111, 102, 32, 109, 101, 109, 111, 114, 121, 33, 0, 0, 124, 74, 0, 7, 103, 0, 0, 0, 240, 1, 0, 0, 31, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 114, 97, 119, 95, 118, 101, 99, 46, 114, 115, 99, 97, 112, 97, 99, 105, 116, 121, 32, 111, 118, 101, 114, 102, 108, 111, 119, 195, 76, 0, 7, 17, 0, 0, 0, 4, 76, 0, 7, 191, 0, 0, 0, 6, 2, 0, 0, 5, 0, 0, 0, 97, 32, 102, 111, 114, 109, 97, 116, 116, 105, 110, 103, 32, 116, 114, 97, 105, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 114, 101, 116, 117, 114, 110, 101, 100, 32, 97, 110, 32, 101, 114, 114, 111, 114, 0, 96, 43, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 132, 63, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 102, 109, 116, 46, 114, 115, 0, 48, 77, 0, 7, 187, 0, 0, 0, 100, 2, 0, 0, 9, 0, 0, 0, 208, 44, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 156, 46, 0, 7, 212, 44, 0, 7, 56, 46, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 102, 109, 116, 47, 110, 117, 109, 46, 114, 115, 0, 0, 20, 78, 0, 7, 190, 0, 0, 0, 101, 0, 0, 0, 20, 0, 0, 0, 48, 120, 48, 48, 48, 49, 48, 50, 48, 51, 48, 52, 48, 53, 48, 54, 48, 55, 48, 56, 48, 57, 49, 48, 49, 49, 49, 50, 49, 51, 49, 52, 49, 53, 49, 54, 49, 55, 49, 56, 49, 57, 50, 48, 50, 49, 50, 50, 50, 51, 50, 52, 50, 53, 50, 54, 50, 55, 50, 56, 50, 57, 51, 48, 51, 49, 51, 50, 51, 51, 51, 52, 51, 53, 51, 54, 51, 55, 51, 56, 51, 57, 52, 48, 52, 49, 52, 50, 52, 51, 52, 52, 52, 53, 52, 54, 52, 55, 52, 56, 52, 57, 53, 48, 53, 49, 53, 50, 53, 51, 53, 52, 53, 53, 53, 54, 53, 55, 53, 56, 53, 57, 54, 48, 54, 49, 54, 50, 54, 51, 54, 52, 54, 53, 54, 54, 54, 55, 54, 56, 54, 57, 55, 48, 55, 49, 55, 50, 55, 51, 55, 52, 55, 53, 55, 54, 55, 55, 55, 56, 55, 57, 56, 48, 56, 49, 56, 50, 56, 51, 56, 52, 56, 53, 56, 54, 56, 55, 56, 56, 56, 57, 57, 48, 57, 49, 57, 50, 57, 51, 57, 52, 57, 53, 57, 54, 57, 55, 57, 56, 57, 57, 115, 111, 117, 114, 99, 101, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 32, 100, 111, 101, 115, 32, 110, 111, 116, 32, 109, 97, 116, 99, 104, 32, 100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 0, 174, 79, 0, 7, 21, 0, 0, 0, 195, 79, 0, 7, 43, 0, 0, 0, 238, 79, 0, 7, 1, 0, 0, 0, 58, 0, 0, 0, 8, 80, 0, 7, 0, 0, 0, 0, 8, 80, 0, 7, 1, 0, 0, 0, 8, 80, 0, 7, 1, 0, 0, 0, 112, 97, 110, 105, 99, 107, 101, 100, 32, 97, 116, 32, 39, 39, 44, 32, 48, 80, 0, 7, 1, 0, 0, 0, 49, 80, 0, 7, 3, 0, 0, 0, 68, 80, 0, 7, 0, 0, 0, 0, 114, 97, 110, 103, 101, 32, 115, 116, 97, 114, 116, 32, 105, 110, 100, 101, 120, 32, 32, 111, 117, 116, 32, 111, 102, 32, 114, 97, 110, 103, 101, 32, 102, 111, 114, 32, 115, 108, 105, 99, 101, 32, 111, 102, 32, 108, 101, 110, 103, 116, 104, 32, 76, 80, 0, 7, 18, 0, 0, 0, 94, 80, 0, 7, 34, 0, 0, 0, 69, 114, 114, 111, 114, 0, 0, 0, 105, 110, 100, 101, 120, 32, 111, 117, 116, 32, 111, 102, 32, 98, 111, 117, 110, 100, 115, 58, 32, 116, 104, 101, 32, 108, 101, 110, 32, 105, 115, 32, 64, 64, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 8, 51, 0, 7, 32, 98, 117, 116, 32, 116, 104, 101, 32, 105, 110, 100, 101, 120, 32, 105, 115, 32, 0, 0, 152, 80, 0, 7, 32, 0, 0, 0, 200, 80, 0, 7, 18, 0, 0, 0, 109, 97, 116, 99, 104, 101, 115, 33, 61, 61, 61, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 96, 40, 108, 101, 102, 116, 32, 32, 114, 105, 103, 104, 116, 41, 96, 10, 32, 32, 108, 101, 102, 116, 58, 32, 96, 96, 44, 10, 32, 114, 105, 103, 104, 116, 58, 32, 96, 96, 58, 32, 0, 0, 0, 247, 80, 0, 7, 25, 0, 0, 0, 16, 81, 0, 7, 18, 0, 0, 0, 34, 81, 0, 7, 12, 0, 0, 0, 46, 81, 0, 7, 3, 0, 0, 0, 96, 0, 0, 0, 247, 80, 0, 7, 25, 0, 0, 0, 16, 81, 0, 7, 18, 0, 0, 0, 34, 81, 0, 7, 12, 0, 0, 0, 84, 81, 0, 7, 1, 0, 0, 0, 58, 32, 0, 0, 152, 80, 0, 7, 0, 0, 0, 0, 120, 81, 0, 7, 2, 0, 0, 0, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 47, 27, 0, 0, 148, 0, 0, 7, 120, 4, 0, 0, 2, 2, 1, 0, 203, 27, 0, 0, 12, 5, 0, 7, 84, 1, 0, 0, 2, 2, 1, 0, 104, 28, 0, 0, 96, 6, 0, 7, 136, 1, 0, 0, 2, 2, 1, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 62, 0, 0, 0, 232, 7, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 147, 0, 0, 0, 24, 69, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 189, 0, 0, 0, 4, 69, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 231, 0, 0, 0, 48, 69, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 17, 1, 0, 0, 64, 69, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 59, 1, 0, 0, 108, 69, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 101, 1, 0, 0, 124, 69, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 143, 1, 0, 0, 4, 69, 0, 7, 17, 0, 0, 0, 1, 0, 1, 0, 185, 1, 0, 0, 32, 69, 0, 7, 15, 0, 0, 0, 1, 0, 1, 0, 227, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 248, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 13, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 34, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 55, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 234, 30, 0, 0, 140, 69, 0, 7, 6, 0, 0, 0, 1, 2, 1, 0, 76, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 64, 29, 0, 0, 156, 69, 0, 7, 9, 0, 0, 0, 1, 2, 1, 0, 130, 29, 0, 0, 172, 69, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 12, 35, 0, 0, 224, 9, 0, 7, 216, 0, 0, 0, 2, 2, 1, 0, 25, 36, 0, 0, 165, 69, 0, 7, 5, 0, 0, 0, 1, 2, 1, 0, 91, 36, 0, 0, 152, 69, 0, 7, 4, 0, 0, 0, 1, 2, 1, 0, 96, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 157, 36, 0, 0, 188, 69, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 116, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 136, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 4, 30, 0, 0, 20, 12, 0, 7, 68, 1, 0, 0, 2, 2, 1, 0, 44, 31, 0, 0, 184, 10, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 156, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 176, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 196, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 202, 31, 0, 0, 188, 69, 0, 7, 43, 0, 0, 0, 1, 2, 1, 0, 11, 32, 0, 0, 232, 69, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 76, 32, 0, 0, 88, 70, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 241, 32, 0, 0, 104, 70, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 98, 34, 0, 0, 92, 13, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 142, 37, 0, 0, 88, 13, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 252, 37, 0, 0, 248, 69, 0, 7, 96, 0, 0, 0, 1, 2, 1, 0, 216, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 236, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 184, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 192, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 192, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 200, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 200, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 208, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 208, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 216, 14, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 253, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 29, 3, 0, 0, 176, 70, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 71, 3, 0, 0, 192, 70, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 113, 3, 0, 0, 120, 70, 0, 7, 56, 0, 0, 0, 1, 0, 1, 0, 155, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 187, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 219, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 251, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 27, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 53, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 163, 40, 0, 0, 224, 18, 0, 7, 184, 0, 0, 0, 2, 2, 1, 0, 239, 40, 0, 0, 152, 19, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 79, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 106, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 133, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 160, 4, 0, 0, 208, 70, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 169, 4, 0, 0, 148, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 177, 4, 0, 0, 91, 71, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 220, 4, 0, 0, 168, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 228, 4, 0, 0, 73, 71, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 15, 5, 0, 0, 188, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 23, 5, 0, 0, 53, 71, 0, 7, 20, 0, 0, 0, 1, 0, 1, 0, 66, 5, 0, 0, 208, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 74, 5, 0, 0, 35, 71, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 117, 5, 0, 0, 228, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 125, 5, 0, 0, 11, 71, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 168, 5, 0, 0, 248, 20, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 176, 5, 0, 0, 255, 70, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 219, 5, 0, 0, 12, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 227, 5, 0, 0, 236, 70, 0, 7, 19, 0, 0, 0, 1, 0, 1, 0, 165, 42, 0, 0, 104, 20, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 13, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 40, 6, 0, 0, 184, 21, 0, 7, 80, 0, 0, 0, 2, 0, 1, 0, 90, 6, 0, 0, 112, 71, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 132, 6, 0, 0, 8, 22, 0, 7, 236, 0, 0, 0, 2, 0, 1, 0, 198, 6, 0, 0, 112, 71, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 240, 6, 0, 0, 120, 71, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 93, 42, 0, 0, 32, 21, 0, 7, 152, 0, 0, 0, 2, 2, 1, 0, 26, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 53, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 79, 7, 0, 0, 144, 23, 0, 7, 180, 2, 0, 0, 2, 0, 1, 0, 142, 7, 0, 0, 52, 72, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 184, 7, 0, 0, 241, 71, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 226, 7, 0, 0, 36, 72, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 12, 8, 0, 0, 68, 72, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 54, 8, 0, 0, 145, 71, 0, 7, 96, 0, 0, 0, 1, 0, 1, 0, 96, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 142, 41, 0, 0, 76, 27, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 230, 41, 0, 0, 80, 27, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 28, 42, 0, 0, 84, 72, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 123, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 176, 44, 0, 0, 180, 27, 0, 7, 48, 0, 0, 0, 2, 2, 1, 0, 149, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 175, 8, 0, 0, 212, 72, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 217, 8, 0, 0, 0, 73, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 3, 9, 0, 0, 196, 72, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 45, 9, 0, 0, 119, 73, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 88, 9, 0, 0, 160, 73, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 131, 9, 0, 0, 100, 72, 0, 7, 94, 0, 0, 0, 1, 0, 1, 0, 173, 9, 0, 0, 16, 73, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 3, 45, 0, 0, 228, 27, 0, 7, 56, 4, 0, 0, 2, 2, 1, 0, 216, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 242, 9, 0, 0, 108, 32, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 74, 10, 0, 0, 176, 73, 0, 7, 39, 0, 0, 0, 1, 0, 1, 0, 116, 10, 0, 0, 216, 73, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 158, 10, 0, 0, 76, 74, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 200, 10, 0, 0, 232, 73, 0, 7, 98, 0, 0, 0, 1, 0, 1, 0, 61, 38, 0, 0, 112, 32, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 72, 38, 0, 0, 64, 33, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 85, 38, 0, 0, 68, 33, 0, 7, 52, 1, 0, 0, 2, 2, 1, 0, 242, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 12, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 26, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 40, 11, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 52, 11, 0, 0, 8, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 5, 29, 0, 0, 112, 35, 0, 7, 12, 4, 0, 0, 2, 2, 1, 0, 198, 38, 0, 0, 120, 34, 0, 7, 248, 0, 0, 0, 2, 2, 1, 0, 64, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 90, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 115, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 141, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 166, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 191, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 217, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 242, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 36, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 57, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 78, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 99, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 120, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 141, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 163, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 185, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 206, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 234, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 7, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 26, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 45, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 64, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 90, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 116, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 142, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 168, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 194, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 221, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 0, 14, 0, 0, 227, 74, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 42, 14, 0, 0, 11, 75, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 85, 14, 0, 0, 56, 75, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 128, 14, 0, 0, 72, 75, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 171, 14, 0, 0, 115, 75, 0, 7, 52, 0, 0, 0, 1, 0, 1, 0, 214, 14, 0, 0, 168, 75, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 1, 15, 0, 0, 184, 75, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 44, 15, 0, 0, 0, 0, 8, 0, 4, 0, 0, 0, 1, 0, 2, 0, 121, 15, 0, 0, 228, 75, 0, 7, 14, 0, 0, 0, 1, 0, 1, 0, 164, 15, 0, 0, 244, 75, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 207, 15, 0, 0, 124, 74, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 249, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 28, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 198, 49, 0, 0, 148, 40, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 32, 50, 0, 0, 76, 42, 0, 7, 172, 0, 0, 0, 2, 2, 1, 0, 108, 50, 0, 0, 112, 41, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 185, 50, 0, 0, 4, 76, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 250, 50, 0, 0, 212, 76, 0, 7, 8, 0, 0, 0, 1, 2, 1, 0, 59, 51, 0, 0, 220, 76, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 124, 51, 0, 0, 4, 76, 0, 7, 191, 0, 0, 0, 1, 2, 1, 0, 189, 51, 0, 0, 195, 76, 0, 7, 17, 0, 0, 0, 1, 2, 1, 0, 49, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 125, 38, 0, 0, 84, 43, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 93, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 115, 16, 0, 0, 96, 43, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 187, 16, 0, 0, 236, 76, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 229, 16, 0, 0, 32, 77, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 15, 17, 0, 0, 236, 77, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 57, 17, 0, 0, 48, 77, 0, 7, 187, 0, 0, 0, 1, 0, 1, 0, 99, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 121, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 110, 52, 0, 0, 252, 77, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 41, 53, 0, 0, 208, 44, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 155, 53, 0, 0, 212, 44, 0, 7, 100, 1, 0, 0, 2, 2, 1, 0, 12, 54, 0, 0, 56, 46, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 123, 54, 0, 0, 156, 46, 0, 7, 144, 0, 0, 0, 2, 2, 1, 0, 142, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 163, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 234, 54, 0, 0, 230, 78, 0, 7, 200, 0, 0, 0, 1, 2, 1, 0, 44, 55, 0, 0, 212, 78, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 110, 55, 0, 0, 228, 78, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 176, 55, 0, 0, 20, 78, 0, 7, 190, 0, 0, 0, 1, 2, 1, 0, 183, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 204, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 225, 17, 0, 0, 240, 79, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 12, 18, 0, 0, 174, 79, 0, 7, 21, 0, 0, 0, 1, 0, 1, 0, 55, 18, 0, 0, 195, 79, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 98, 18, 0, 0, 238, 79, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 141, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 162, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 15, 58, 0, 0, 8, 80, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 80, 58, 0, 0, 12, 80, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 146, 58, 0, 0, 8, 80, 0, 7, 1, 0, 0, 0, 1, 2, 1, 0, 183, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 204, 18, 0, 0, 36, 80, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 247, 18, 0, 0, 52, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 34, 19, 0, 0, 48, 80, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 77, 19, 0, 0, 49, 80, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 212, 58, 0, 0, 8, 51, 0, 7, 20, 0, 0, 0, 2, 2, 1, 0, 120, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 141, 19, 0, 0, 168, 52, 0, 7, 32, 0, 0, 0, 2, 0, 1, 0, 190, 19, 0, 0, 200, 52, 0, 7, 72, 0, 0, 0, 2, 0, 1, 0, 244, 19, 0, 0, 68, 80, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 30, 20, 0, 0, 68, 80, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 72, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 92, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 112, 20, 0, 0, 128, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 155, 20, 0, 0, 76, 80, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 197, 20, 0, 0, 94, 80, 0, 7, 34, 0, 0, 0, 1, 0, 1, 0, 240, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 4, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 24, 21, 0, 0, 132, 59, 0, 7, 156, 0, 0, 0, 2, 0, 1, 0, 95, 21, 0, 0, 144, 80, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 58, 57, 0, 0, 40, 64, 0, 7, 24, 0, 0, 0, 2, 2, 1, 0, 187, 57, 0, 0, 128, 53, 0, 7, 8, 0, 0, 0, 2, 2, 1, 0, 26, 59, 0, 0, 204, 63, 0, 7, 92, 0, 0, 0, 2, 2, 1, 0, 100, 59, 0, 0, 176, 63, 0, 7, 28, 0, 0, 0, 2, 2, 1, 0, 79, 60, 0, 0, 160, 63, 0, 7, 16, 0, 0, 0, 2, 2, 1, 0, 138, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 158, 21, 0, 0, 152, 80, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 201, 21, 0, 0, 184, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 244, 21, 0, 0, 220, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 31, 22, 0, 0, 243, 80, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 74, 22, 0, 0, 245, 80, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 117, 22, 0, 0, 88, 81, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 160, 22, 0, 0, 236, 80, 0, 7, 7, 0, 0, 0, 1, 0, 1, 0, 203, 22, 0, 0, 52, 81, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 246, 22, 0, 0, 124, 81, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 33, 23, 0, 0, 140, 81, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 77, 23, 0, 0, 200, 80, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 120, 23, 0, 0, 247, 80, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 163, 23, 0, 0, 16, 81, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 206, 23, 0, 0, 34, 81, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 249, 23, 0, 0, 46, 81, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 36, 24, 0, 0, 84, 81, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 79, 24, 0, 0, 120, 81, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 244, 60, 0, 0, 64, 64, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 76, 61, 0, 0, 64, 64, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 166, 61, 0, 0, 64, 64, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 122, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 142, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 162, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 239, 56, 0, 0, 44, 67, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 28, 62, 0, 0, 151, 81, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 182, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 216, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 249, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 27, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 61, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 94, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 127, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 160, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 193, 25, 0, 0, 20, 0, 0, 7, 128, 0, 0, 0, 18, 0, 1, 0, 201, 25, 0, 0, 236, 7, 0, 7, 244, 1, 0, 0, 18, 0, 1, 0, 208, 25, 0, 0, 80, 40, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 224, 25, 0, 0, 156, 39, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 234, 25, 0, 0, 40, 40, 0, 7, 40, 0, 0, 0, 18, 0, 1, 0, 244, 25, 0, 0, 244, 22, 0, 7, 132, 0, 0, 0, 18, 0, 1, 0, 6, 26, 0, 0, 120, 23, 0, 7, 24, 0, 0, 0, 18, 0, 1, 0, 15, 26, 0, 0, 200, 14, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 30, 26, 0, 0, 184, 14, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 43, 26, 0, 0, 248, 42, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 100, 26, 0, 0, 60, 43, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 156, 26, 0, 0, 192, 14, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 171, 26, 0, 0, 44, 32, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 12, 29, 0, 0, 36, 32, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 196, 29, 0, 0, 204, 16, 0, 7, 128, 1, 0, 0, 18, 0, 1, 0, 65, 30, 0, 0, 68, 26, 0, 7, 168, 0, 0, 0, 18, 0, 1, 0, 180, 30, 0, 0, 28, 32, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 141, 32, 0, 0, 156, 66, 0, 7, 116, 0, 0, 0, 18, 0, 1, 0, 192, 32, 0, 0, 68, 64, 0, 7, 60, 0, 0, 0, 18, 0, 1, 0, 50, 33, 0, 0, 116, 20, 0, 7, 172, 0, 0, 0, 18, 0, 1, 0, 146, 33, 0, 0, 140, 48, 0, 7, 104, 0, 0, 0, 18, 0, 1, 0, 248, 33, 0, 0, 84, 63, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 45, 34, 0, 0, 4, 0, 8, 0, 80, 0, 0, 0, 17, 0, 3, 0, 155, 35, 0, 0, 76, 18, 0, 7, 148, 0, 0, 0, 18, 0, 1, 0, 223, 36, 0, 0, 92, 74, 0, 7, 32, 0, 0, 0, 17, 0, 1, 0, 23, 37, 0, 0, 236, 26, 0, 7, 96, 0, 0, 0, 18, 0, 1, 0, 98, 38, 0, 0, 208, 14, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 134, 38, 0, 0, 216, 14, 0, 7, 244, 1, 0, 0, 18, 0, 1, 0, 205, 38, 0, 0, 196, 64, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 8, 39, 0, 0, 240, 68, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 107, 39, 0, 0, 100, 63, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 167, 39, 0, 0, 44, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 7, 40, 0, 0, 116, 63, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 67, 40, 0, 0, 220, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 73, 41, 0, 0, 16, 53, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 239, 42, 0, 0, 28, 51, 0, 7, 128, 1, 0, 0, 18, 0, 1, 0, 86, 43, 0, 0, 100, 43, 0, 7, 108, 1, 0, 0, 18, 0, 1, 0, 141, 43, 0, 0, 124, 39, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 189, 43, 0, 0, 128, 64, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 234, 43, 0, 0, 144, 39, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 116, 44, 0, 0, 40, 65, 0, 7, 116, 1, 0, 0, 18, 0, 1, 0, 58, 45, 0, 0, 136, 39, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 123, 45, 0, 0, 148, 39, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 185, 45, 0, 0, 220, 39, 0, 7, 76, 0, 0, 0, 18, 0, 1, 0, 254, 45, 0, 0, 16, 67, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 97, 46, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 1, 0, 104, 46, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 16, 0, 2, 0, 122, 46, 0, 0, 252, 255, 127, 0, 0, 0, 0, 0, 16, 0, 241, 255, 136, 46, 0, 0, 4, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 148, 46, 0, 0, 84, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 158, 46, 0, 0, 148, 39, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 27, 47, 0, 0, 144, 39, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 170, 47, 0, 0, 44, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 10, 48, 0, 0, 220, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 106, 48, 0, 0, 240, 68, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 207, 48, 0, 0, 44, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 49, 49, 0, 0, 220, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 147, 49, 0, 0, 156, 52, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 254, 51, 0, 0, 72, 43, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 63, 52, 0, 0, 32, 60, 0, 7, 52, 3, 0, 0, 18, 0, 1, 0, 175, 52, 0, 0, 228, 53, 0, 7, 100, 2, 0, 0, 18, 0, 1, 0, 214, 52, 0, 0, 132, 63, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 242, 55, 0, 0, 44, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 84, 56, 0, 0, 220, 47, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 182, 56, 0, 0, 72, 56, 0, 7, 60, 3, 0, 0, 18, 0, 1, 0, 132, 57, 0, 0, 244, 48, 0, 7, 20, 2, 0, 0, 18, 0, 1, 0, 174, 59, 0, 0, 28, 53, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 246, 59, 0, 0, 136, 53, 0, 7, 92, 0, 0, 0, 18, 0, 1, 0, 151, 60, 0, 0, 84, 63, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 94, 62, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 16, 0, 241, 255, 107, 62, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 16, 0, 241, 255, 118, 62, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 241, 255, 129, 62, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 16, 0, 241, 255, 0, 46, 116, 101, 120, 116, 0, 46, 100, 97, 116, 97, 0, 46, 98, 115, 115, 0, 46, 115, 121, 109, 116, 97, 98, 0, 46, 115, 104, 115, 116, 114, 116, 97, 98, 0, 46, 115, 116, 114, 116, 97, 98, 0, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 48, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 97, 52, 56, 55, 101, 102, 99, 54, 52, 100, 48, 51, 54, 53, 54, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 51, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 49, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 50, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 51, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 52, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 49, 53, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 50, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 51, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 52, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 53, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 54, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 55, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 56, 0, 105, 110, 105, 116, 46, 57, 98, 100, 50, 48, 56, 48, 56, 45, 99, 103, 117, 46, 57, 0, 52, 112, 113, 55, 122, 50, 118, 54, 57, 116, 121, 105, 99, 120, 97, 99, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 57, 98, 51, 100, 48, 57, 98, 101, 48, 53, 99, 55, 51, 51, 56, 52, 49, 50, 51, 97, 98, 56, 98, 56, 51, 97, 100, 50, 50, 53, 99, 100, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 57, 98, 51, 100, 48, 57, 98, 101, 48, 53, 99, 55, 51, 51, 56, 52, 49, 50, 51, 97, 98, 56, 98, 56, 51, 97, 100, 50, 50, 53, 99, 100, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 57, 98, 51, 100, 48, 57, 98, 101, 48, 53, 99, 55, 51, 51, 56, 52, 49, 50, 51, 97, 98, 56, 98, 56, 51, 97, 100, 50, 50, 53, 99, 100, 46, 48, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 51, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 55, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 51, 0, 46, 76, 74, 84, 73, 54, 95, 48, 0, 46, 76, 66, 66, 54, 95, 49, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 53, 0, 46, 76, 66, 66, 54, 95, 50, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 52, 0, 46, 76, 66, 66, 54, 95, 51, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 51, 0, 46, 76, 66, 66, 54, 95, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 50, 0, 46, 76, 66, 66, 54, 95, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 49, 0, 46, 76, 66, 66, 54, 95, 54, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 48, 0, 46, 76, 66, 66, 54, 95, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 65, 114, 103, 117, 109, 101, 110, 116, 115, 54, 97, 115, 95, 115, 116, 114, 49, 55, 104, 57, 48, 52, 56, 53, 49, 98, 51, 54, 101, 48, 101, 53, 51, 50, 102, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 53, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 84, 36, 71, 84, 36, 49, 49, 109, 97, 112, 95, 111, 114, 95, 101, 108, 115, 101, 49, 55, 104, 98, 48, 56, 50, 51, 53, 48, 48, 57, 56, 101, 100, 52, 57, 56, 52, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 50, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 115, 104, 97, 49, 53, 99, 111, 112, 121, 95, 97, 110, 100, 95, 117, 112, 100, 97, 116, 101, 49, 55, 104, 53, 56, 100, 57, 102, 57, 100, 54, 49, 52, 102, 57, 100, 102, 48, 50, 69, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 51, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 48, 48, 55, 100, 48, 102, 101, 49, 54, 97, 100, 51, 55, 97, 50, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 56, 0, 109, 117, 115, 108, 95, 109, 101, 109, 115, 101, 116, 46, 99, 0, 109, 117, 115, 108, 95, 109, 101, 109, 99, 112, 121, 46, 99, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 48, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 52, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 48, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 49, 53, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 48, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 49, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 54, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 54, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 56, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 50, 48, 72, 69, 65, 80, 95, 87, 79, 82, 68, 83, 95, 82, 69, 77, 65, 73, 78, 73, 78, 71, 49, 55, 104, 53, 97, 101, 56, 98, 54, 55, 48, 102, 98, 54, 52, 50, 100, 55, 50, 69, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 55, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 102, 53, 51, 97, 51, 51, 52, 49, 54, 100, 57, 102, 57, 49, 53, 49, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 54, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 49, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 52, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 50, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 115, 116, 114, 49, 55, 104, 56, 102, 56, 55, 54, 57, 49, 102, 48, 101, 102, 98, 98, 51, 56, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 112, 97, 110, 105, 99, 95, 100, 105, 115, 112, 108, 97, 121, 49, 55, 104, 98, 55, 101, 99, 99, 49, 53, 99, 56, 57, 48, 97, 55, 99, 98, 54, 69, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 52, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 54, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 50, 119, 114, 105, 116, 101, 95, 112, 114, 101, 102, 105, 120, 49, 55, 104, 55, 57, 52, 99, 51, 54, 55, 50, 50, 55, 97, 56, 51, 100, 102, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 50, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 50, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 56, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 53, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 49, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 50, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 56, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 57, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 53, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 51, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 52, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 53, 0, 95, 95, 115, 116, 97, 114, 116, 0, 95, 95, 109, 97, 105, 110, 0, 115, 121, 115, 95, 97, 108, 108, 111, 99, 95, 119, 111, 114, 100, 115, 0, 115, 121, 115, 95, 112, 97, 110, 105, 99, 0, 115, 121, 115, 95, 119, 114, 105, 116, 101, 0, 114, 117, 115, 116, 95, 98, 101, 103, 105, 110, 95, 117, 110, 119, 105, 110, 100, 0, 114, 117, 115, 116, 95, 111, 111, 109, 0, 95, 95, 114, 117, 115, 116, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 55, 99, 97, 112, 97, 99, 105, 116, 121, 95, 111, 118, 101, 114, 102, 108, 111, 119, 49, 55, 104, 51, 97, 54, 100, 97, 51, 54, 102, 48, 52, 56, 102, 97, 55, 49, 57, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 49, 55, 104, 97, 52, 102, 49, 48, 54, 99, 53, 53, 53, 50, 49, 57, 48, 52, 52, 69, 0, 95, 95, 114, 117, 115, 116, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 90, 78, 57, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 101, 110, 118, 46, 46, 70, 100, 82, 101, 97, 100, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 82, 101, 97, 100, 36, 71, 84, 36, 49, 48, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 100, 57, 53, 99, 57, 99, 101, 101, 102, 98, 52, 98, 102, 50, 101, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 55, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 116, 117, 112, 108, 101, 49, 55, 104, 50, 99, 102, 98, 102, 56, 56, 54, 50, 56, 52, 97, 56, 53, 50, 57, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 97, 50, 49, 48, 99, 48, 49, 51, 51, 48, 101, 50, 102, 50, 55, 51, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 100, 101, 101, 99, 100, 101, 51, 53, 48, 50, 55, 101, 48, 101, 48, 98, 69, 0, 109, 101, 109, 99, 112, 121, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 53, 115, 116, 100, 105, 110, 49, 55, 104, 55, 97, 49, 48, 101, 48, 49, 101, 48, 56, 102, 98, 102, 97, 98, 48, 69, 0, 97, 110, 111, 110, 46, 50, 52, 98, 52, 102, 55, 52, 99, 52, 55, 50, 53, 53, 55, 50, 49, 102, 55, 99, 52, 100, 100, 51, 49, 98, 56, 49, 54, 54, 98, 98, 55, 46, 53, 46, 108, 108, 118, 109, 46, 49, 50, 50, 51, 57, 48, 57, 53, 53, 54, 55, 55, 53, 50, 51, 53, 53, 50, 54, 53, 0, 97, 110, 111, 110, 46, 50, 52, 98, 52, 102, 55, 52, 99, 52, 55, 50, 53, 53, 55, 50, 49, 102, 55, 99, 52, 100, 100, 51, 49, 98, 56, 49, 54, 54, 98, 98, 55, 46, 56, 46, 108, 108, 118, 109, 46, 49, 50, 50, 51, 57, 48, 57, 53, 53, 54, 55, 55, 53, 50, 51, 53, 53, 50, 54, 53, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 57, 71, 97, 109, 101, 83, 116, 97, 116, 101, 53, 99, 104, 101, 99, 107, 49, 55, 104, 51, 98, 97, 100, 100, 53, 52, 100, 54, 101, 51, 98, 99, 57, 99, 54, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 115, 101, 114, 100, 101, 49, 48, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 54, 116, 111, 95, 118, 101, 99, 49, 55, 104, 53, 97, 55, 51, 53, 49, 54, 101, 100, 55, 50, 52, 50, 52, 50, 51, 69, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 48, 104, 97, 115, 104, 95, 119, 111, 114, 100, 115, 49, 55, 104, 102, 54, 98, 53, 57, 100, 57, 100, 53, 102, 56, 57, 51, 102, 97, 52, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 55, 106, 111, 117, 114, 110, 97, 108, 49, 55, 104, 99, 97, 56, 56, 49, 100, 99, 98, 55, 55, 97, 53, 99, 48, 57, 102, 69, 0, 97, 110, 111, 110, 46, 99, 98, 55, 101, 51, 50, 53, 101, 54, 49, 102, 50, 101, 50, 52, 102, 99, 50, 102, 102, 56, 97, 102, 53, 101, 102, 102, 102, 102, 98, 50, 101, 46, 48, 46, 108, 108, 118, 109, 46, 49, 50, 55, 49, 57, 54, 53, 57, 51, 49, 48, 50, 55, 50, 57, 55, 52, 49, 56, 52, 0, 95, 90, 78, 49, 48, 53, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 87, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 50, 52, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 110, 101, 119, 116, 121, 112, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 99, 57, 49, 48, 56, 101, 102, 53, 51, 50, 49, 52, 100, 99, 56, 56, 69, 0, 97, 110, 111, 110, 46, 51, 48, 100, 53, 98, 49, 101, 99, 51, 52, 98, 55, 57, 98, 52, 51, 48, 49, 49, 97, 53, 53, 98, 102, 100, 57, 98, 97, 100, 54, 100, 51, 46, 52, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 97, 110, 111, 110, 46, 51, 48, 100, 53, 98, 49, 101, 99, 51, 52, 98, 55, 57, 98, 52, 51, 48, 49, 49, 97, 53, 53, 98, 102, 100, 57, 98, 97, 100, 54, 100, 51, 46, 53, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 97, 110, 111, 110, 46, 51, 48, 100, 53, 98, 49, 101, 99, 51, 52, 98, 55, 57, 98, 52, 51, 48, 49, 49, 97, 53, 53, 98, 102, 100, 57, 98, 97, 100, 54, 100, 51, 46, 55, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 114, 101, 115, 117, 108, 116, 49, 51, 117, 110, 119, 114, 97, 112, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 100, 100, 48, 102, 48, 100, 50, 48, 49, 50, 102, 98, 49, 48, 50, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 102, 109, 116, 49, 55, 104, 98, 48, 52, 49, 102, 98, 52, 56, 101, 100, 102, 57, 98, 99, 57, 50, 69, 0, 97, 110, 111, 110, 46, 51, 48, 100, 53, 98, 49, 101, 99, 51, 52, 98, 55, 57, 98, 52, 51, 48, 49, 49, 97, 53, 53, 98, 102, 100, 57, 98, 97, 100, 54, 100, 51, 46, 56, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 95, 90, 78, 54, 54, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 51, 54, 56, 54, 52, 99, 100, 102, 102, 98, 48, 57, 49, 57, 100, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 108, 101, 110, 95, 109, 105, 115, 109, 97, 116, 99, 104, 95, 102, 97, 105, 108, 49, 55, 104, 56, 53, 97, 99, 100, 55, 48, 52, 54, 48, 98, 101, 49, 97, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 50, 55, 99, 57, 48, 49, 55, 52, 48, 98, 101, 97, 48, 99, 55, 54, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 54, 72, 65, 83, 72, 69, 82, 49, 55, 104, 99, 102, 102, 99, 102, 101, 49, 57, 48, 51, 102, 49, 98, 53, 50, 97, 69, 0, 95, 90, 78, 56, 50, 95, 36, 76, 84, 36, 100, 105, 103, 101, 115, 116, 46, 46, 99, 111, 114, 101, 95, 97, 112, 105, 46, 46, 119, 114, 97, 112, 112, 101, 114, 46, 46, 67, 111, 114, 101, 87, 114, 97, 112, 112, 101, 114, 36, 76, 84, 36, 84, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 100, 105, 103, 101, 115, 116, 46, 46, 85, 112, 100, 97, 116, 101, 36, 71, 84, 36, 54, 117, 112, 100, 97, 116, 101, 50, 56, 95, 36, 117, 55, 98, 36, 36, 117, 55, 98, 36, 99, 108, 111, 115, 117, 114, 101, 36, 117, 55, 100, 36, 36, 117, 55, 100, 36, 49, 55, 104, 51, 51, 54, 101, 100, 101, 98, 53, 51, 100, 49, 55, 52, 54, 49, 102, 69, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 56, 49, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 83, 104, 105, 112, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 55, 54, 56, 102, 51, 50, 54, 48, 48, 52, 102, 99, 57, 52, 102, 99, 69, 0, 95, 90, 78, 56, 55, 95, 36, 76, 84, 36, 97, 108, 108, 111, 99, 46, 46, 118, 101, 99, 46, 46, 86, 101, 99, 36, 76, 84, 36, 117, 51, 50, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 49, 119, 114, 105, 116, 101, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 56, 99, 97, 52, 54, 100, 53, 56, 99, 98, 53, 56, 97, 97, 54, 69, 0, 97, 110, 111, 110, 46, 50, 52, 98, 52, 102, 55, 52, 99, 52, 55, 50, 53, 53, 55, 50, 49, 102, 55, 99, 52, 100, 100, 51, 49, 98, 56, 49, 54, 54, 98, 98, 55, 46, 54, 46, 108, 108, 118, 109, 46, 49, 50, 50, 51, 57, 48, 57, 53, 53, 54, 55, 55, 53, 50, 51, 53, 53, 50, 54, 53, 0, 97, 110, 111, 110, 46, 50, 52, 98, 52, 102, 55, 52, 99, 52, 55, 50, 53, 53, 55, 50, 49, 102, 55, 99, 52, 100, 100, 51, 49, 98, 56, 49, 54, 54, 98, 98, 55, 46, 55, 46, 108, 108, 118, 109, 46, 49, 50, 50, 51, 57, 48, 57, 53, 53, 54, 55, 55, 53, 50, 51, 53, 53, 50, 54, 53, 0, 97, 110, 111, 110, 46, 56, 102, 55, 51, 54, 102, 55, 55, 97, 101, 102, 54, 49, 50, 48, 98, 101, 100, 97, 100, 53, 54, 100, 98, 50, 98, 100, 54, 102, 54, 51, 97, 46, 48, 46, 108, 108, 118, 109, 46, 49, 53, 57, 57, 50, 55, 57, 49, 54, 48, 56, 52, 51, 55, 49, 49, 57, 54, 53, 53, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 49, 49, 83, 72, 65, 50, 53, 54, 95, 73, 78, 73, 84, 49, 55, 104, 99, 101, 102, 102, 54, 52, 52, 49, 100, 52, 48, 52, 51, 54, 101, 55, 69, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 52, 99, 111, 109, 112, 114, 101, 115, 115, 95, 115, 108, 105, 99, 101, 49, 55, 104, 101, 97, 53, 50, 55, 55, 52, 48, 97, 54, 54, 57, 97, 56, 57, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 97, 52, 56, 55, 101, 102, 99, 54, 52, 100, 48, 51, 54, 53, 54, 55, 69, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 97, 110, 111, 110, 46, 51, 48, 100, 53, 98, 49, 101, 99, 51, 52, 98, 55, 57, 98, 52, 51, 48, 49, 49, 97, 53, 53, 98, 102, 100, 57, 98, 97, 100, 54, 100, 51, 46, 54, 46, 108, 108, 118, 109, 46, 51, 52, 57, 56, 49, 55, 48, 54, 54, 49, 56, 49, 57, 53, 56, 55, 52, 49, 51, 0, 95, 95, 114, 103, 95, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 95, 104, 97, 110, 100, 108, 101, 114, 0, 95, 95, 114, 103, 95, 111, 111, 109, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 57, 71, 97, 109, 101, 67, 104, 101, 99, 107, 53, 99, 104, 101, 99, 107, 49, 55, 104, 51, 56, 54, 98, 50, 49, 100, 51, 98, 50, 57, 99, 54, 99, 50, 52, 69, 0, 109, 101, 109, 115, 101, 116, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 56, 112, 97, 110, 105, 99, 95, 98, 111, 117, 110, 100, 115, 95, 99, 104, 101, 99, 107, 49, 55, 104, 101, 49, 55, 98, 101, 97, 53, 51, 52, 49, 101, 57, 51, 99, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 55, 55, 51, 101, 100, 54, 52, 97, 57, 99, 53, 55, 55, 55, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 108, 111, 119, 101, 114, 95, 104, 101, 120, 49, 55, 104, 53, 102, 102, 98, 50, 50, 56, 100, 53, 99, 100, 48, 57, 52, 102, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 101, 52, 101, 52, 49, 54, 52, 99, 48, 102, 97, 48, 98, 55, 99, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 117, 112, 112, 101, 114, 95, 104, 101, 120, 49, 55, 104, 99, 54, 57, 97, 98, 98, 57, 98, 53, 55, 100, 97, 52, 54, 97, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 98, 48, 53, 55, 102, 49, 52, 53, 56, 98, 102, 53, 52, 56, 49, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 48, 56, 53, 102, 50, 55, 48, 98, 56, 52, 100, 52, 48, 48, 53, 49, 69, 46, 108, 108, 118, 109, 46, 49, 55, 52, 49, 49, 55, 56, 49, 54, 49, 56, 51, 55, 48, 48, 48, 50, 54, 48, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 48, 100, 50, 99, 57, 57, 56, 98, 50, 97, 54, 53, 53, 98, 50, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 54, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 49, 55, 104, 53, 57, 51, 101, 48, 102, 54, 97, 99, 101, 100, 50, 54, 49, 57, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 51, 50, 36, 71, 84, 36, 49, 55, 104, 51, 97, 48, 53, 49, 52, 101, 51, 97, 53, 97, 57, 98, 99, 53, 57, 69, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 57, 98, 50, 98, 52, 56, 100, 54, 49, 100, 51, 53, 99, 50, 102, 54, 69, 0, 97, 110, 111, 110, 46, 53, 56, 100, 98, 52, 56, 57, 56, 51, 98, 51, 51, 57, 100, 99, 99, 49, 52, 51, 57, 57, 56, 54, 56, 56, 52, 54, 98, 48, 100, 51, 51, 46, 50, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 56, 52, 54, 55, 97, 49, 56, 50, 56, 99, 97, 52, 48, 55, 102, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 53, 102, 55, 53, 51, 56, 49, 51, 48, 49, 100, 48, 56, 57, 54, 69, 0, 95, 90, 78, 55, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 112, 97, 110, 105, 99, 46, 46, 112, 97, 110, 105, 99, 95, 105, 110, 102, 111, 46, 46, 80, 97, 110, 105, 99, 73, 110, 102, 111, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 55, 48, 101, 100, 53, 55, 49, 51, 52, 101, 101, 99, 100, 98, 54, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 51, 102, 109, 116, 54, 102, 111, 114, 109, 97, 116, 49, 50, 102, 111, 114, 109, 97, 116, 95, 105, 110, 110, 101, 114, 49, 55, 104, 101, 57, 53, 100, 99, 50, 52, 102, 97, 101, 100, 55, 54, 50, 99, 51, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 53, 97, 98, 111, 114, 116, 49, 55, 104, 102, 54, 51, 57, 57, 56, 54, 57, 51, 55, 48, 98, 50, 102, 98, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 53, 112, 97, 110, 105, 99, 49, 55, 104, 98, 53, 56, 56, 101, 101, 51, 56, 100, 101, 101, 56, 99, 101, 49, 49, 69, 0, 95, 90, 78, 49, 48, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 48, 55, 51, 54, 98, 52, 102, 53, 99, 51, 102, 49, 100, 101, 48, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 57, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 95, 105, 110, 110, 101, 114, 49, 55, 104, 102, 102, 99, 57, 99, 100, 53, 48, 54, 54, 102, 55, 97, 56, 53, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 104, 50, 52, 57, 99, 99, 102, 53, 97, 101, 101, 101, 51, 51, 53, 51, 57, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 56, 102, 105, 110, 97, 108, 105, 122, 101, 49, 55, 104, 55, 49, 56, 100, 99, 99, 53, 101, 98, 101, 54, 54, 99, 100, 54, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 53, 66, 108, 111, 99, 107, 49, 52, 97, 115, 95, 104, 97, 108, 102, 95, 98, 108, 111, 99, 107, 115, 49, 55, 104, 50, 49, 51, 97, 53, 101, 53, 98, 55, 48, 97, 55, 99, 50, 51, 57, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 98, 121, 116, 101, 115, 49, 55, 104, 52, 54, 99, 57, 99, 98, 51, 56, 99, 49, 57, 99, 101, 102, 52, 51, 69, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 49, 52, 115, 121, 115, 95, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 49, 55, 102, 102, 56, 98, 97, 100, 99, 52, 56, 102, 102, 55, 53, 102, 69, 0, 95, 90, 78, 54, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 54, 101, 57, 99, 56, 53, 49, 57, 51, 98, 52, 102, 102, 102, 97, 52, 69, 0, 95, 115, 116, 97, 114, 116, 0, 95, 95, 103, 108, 111, 98, 97, 108, 95, 112, 111, 105, 110, 116, 101, 114, 36, 0, 95, 95, 115, 116, 97, 99, 107, 95, 105, 110, 105, 116, 36, 0, 95, 95, 98, 115, 115, 95, 98, 101, 103, 105, 110, 0, 95, 95, 98, 115, 115, 95, 101, 110, 100, 0, 95, 90, 78, 57, 50, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 56, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 97, 101, 97, 52, 99, 54, 50, 97, 56, 102, 48, 101, 98, 101, 48, 51, 69, 0, 95, 90, 78, 49, 49, 49, 95, 36, 76, 84, 36, 36, 82, 70, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 70, 114, 111, 109, 36, 76, 84, 36, 36, 82, 70, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 52, 102, 114, 111, 109, 49, 55, 104, 50, 102, 54, 54, 100, 100, 54, 51, 51, 102, 57, 57, 102, 52, 53, 56, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 55, 52, 57, 99, 100, 101, 55, 55, 99, 51, 54, 98, 50, 102, 51, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 57, 55, 97, 48, 56, 102, 50, 50, 50, 56, 50, 48, 98, 98, 97, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 49, 54, 100, 101, 57, 48, 55, 50, 48, 54, 53, 49, 99, 101, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 50, 101, 51, 98, 48, 56, 102, 49, 50, 100, 51, 49, 101, 97, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 52, 100, 54, 99, 55, 100, 100, 102, 100, 101, 101, 54, 50, 49, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 51, 101, 120, 112, 101, 99, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 98, 97, 50, 55, 98, 53, 97, 97, 97, 56, 54, 54, 53, 53, 52, 101, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 52, 98, 57, 101, 97, 101, 98, 50, 97, 55, 55, 100, 56, 99, 54, 102, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 52, 57, 97, 52, 56, 100, 48, 55, 97, 55, 101, 48, 53, 48, 101, 102, 69, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 97, 99, 101, 98, 50, 53, 57, 51, 55, 48, 52, 98, 48, 101, 52, 98, 69, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 48, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 54, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 55, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 51, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 53, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 56, 114, 116, 95, 101, 114, 114, 111, 114, 49, 55, 104, 48, 48, 101, 99, 48, 48, 50, 56, 53, 48, 56, 49, 48, 97, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 51, 112, 97, 100, 49, 55, 104, 50, 53, 101, 50, 98, 101, 49, 57, 49, 53, 56, 57, 99, 99, 54, 101, 69, 0, 97, 110, 111, 110, 46, 98, 53, 102, 54, 55, 54, 57, 56, 98, 97, 57, 50, 52, 49, 102, 49, 54, 49, 53, 54, 48, 55, 56, 52, 50, 50, 57, 50, 101, 50, 56, 50, 46, 48, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 119, 114, 105, 116, 101, 49, 55, 104, 51, 49, 102, 98, 50, 57, 102, 50, 98, 100, 55, 57, 101, 102, 56, 101, 69, 0, 95, 90, 78, 53, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 100, 55, 50, 48, 55, 55, 49, 101, 53, 54, 55, 49, 102, 102, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 97, 108, 108, 111, 99, 46, 46, 115, 116, 114, 105, 110, 103, 46, 46, 83, 116, 114, 105, 110, 103, 36, 71, 84, 36, 49, 55, 104, 57, 54, 102, 100, 56, 52, 56, 51, 48, 54, 56, 48, 101, 53, 56, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 98, 97, 51, 53, 52, 100, 49, 49, 100, 98, 100, 54, 49, 50, 48, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 53, 53, 57, 55, 97, 55, 51, 48, 98, 101, 50, 97, 101, 98, 51, 55, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 48, 54, 97, 98, 51, 97, 57, 100, 102, 50, 98, 101, 50, 99, 102, 99, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 53, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 49, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 48, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 48, 97, 100, 50, 102, 99, 56, 99, 100, 100, 97, 51, 99, 55, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 98, 53, 50, 57, 53, 48, 102, 51, 52, 101, 102, 100, 99, 50, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 55, 104, 48, 49, 99, 51, 54, 52, 50, 56, 49, 97, 101, 56, 102, 50, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 55, 102, 109, 116, 95, 117, 51, 50, 49, 55, 104, 98, 102, 50, 55, 97, 100, 102, 99, 49, 51, 97, 100, 98, 102, 57, 50, 69, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 102, 55, 54, 51, 101, 49, 54, 100, 101, 102, 51, 98, 53, 56, 52, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 53, 99, 111, 117, 110, 116, 49, 52, 100, 111, 95, 99, 111, 117, 110, 116, 95, 99, 104, 97, 114, 115, 49, 55, 104, 49, 102, 52, 100, 55, 57, 55, 57, 99, 54, 52, 55, 54, 98, 52, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 111, 112, 115, 56, 102, 117, 110, 99, 116, 105, 111, 110, 54, 70, 110, 79, 110, 99, 101, 57, 99, 97, 108, 108, 95, 111, 110, 99, 101, 49, 55, 104, 98, 100, 57, 99, 102, 49, 102, 56, 97, 49, 50, 57, 49, 56, 99, 51, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 49, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 51, 54, 95, 36, 76, 84, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 97, 110, 121, 46, 46, 65, 110, 121, 36, 71, 84, 36, 55, 116, 121, 112, 101, 95, 105, 100, 49, 55, 104, 53, 51, 49, 49, 53, 56, 48, 97, 57, 51, 57, 102, 56, 99, 54, 97, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 97, 52, 100, 52, 55, 55, 48, 102, 49, 55, 53, 98, 100, 55, 57, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 49, 97, 97, 54, 57, 54, 98, 99, 102, 102, 55, 98, 57, 57, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 57, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 98, 97, 51, 101, 54, 98, 99, 48, 57, 55, 100, 97, 98, 50, 48, 97, 69, 0, 95, 90, 78, 53, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 65, 114, 103, 117, 109, 101, 110, 116, 115, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 50, 57, 53, 50, 51, 98, 100, 97, 100, 50, 54, 48, 100, 99, 98, 50, 69, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 52, 55, 102, 57, 100, 54, 52, 54, 51, 99, 57, 54, 98, 49, 51, 69, 0, 95, 90, 78, 53, 55, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 70, 111, 114, 109, 97, 116, 116, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 54, 98, 55, 99, 99, 51, 57, 100, 101, 101, 98, 51, 97, 97, 100, 100, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 54, 52, 36, 71, 84, 36, 49, 55, 104, 49, 57, 51, 53, 51, 49, 100, 53, 48, 52, 56, 101, 99, 100, 98, 55, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 49, 55, 104, 102, 49, 50, 49, 55, 48, 97, 52, 55, 101, 53, 102, 57, 49, 57, 97, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 99, 111, 114, 101, 46, 46, 111, 112, 116, 105, 111, 110, 46, 46, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 36, 71, 84, 36, 49, 55, 104, 49, 55, 56, 97, 100, 100, 56, 54, 102, 98, 52, 51, 51, 50, 57, 54, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 97, 110, 111, 110, 46, 101, 51, 101, 53, 98, 100, 99, 51, 49, 55, 99, 50, 98, 51, 102, 51, 98, 51, 102, 98, 102, 52, 51, 55, 97, 51, 52, 57, 53, 100, 53, 100, 46, 48, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 95, 104, 101, 97, 112, 95, 115, 116, 97, 114, 116, 0, 95, 95, 98, 115, 115, 95, 115, 105, 122, 101, 0, 95, 95, 104, 101, 97, 112, 95, 101, 110, 100, 0, 95, 95, 104, 101, 97, 112, 95, 115, 105, 122, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 7, 0, 16, 0, 0, 151, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 8, 0, 0, 112, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 8, 0, 0, 0, 3, 0, 0, 0, 4, 0, 8, 0, 4, 112, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 112, 0, 0, 160, 21, 0, 0, 6, 0, 0, 0, 17, 1, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 26, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 133, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 208, 133, 0, 0, 141, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
-pub const INIT_ID: [u32; 8] = [1077081152, 2570542741, 1290925023, 2463723706, 996914291, 3546844713, 705073004, 3115616517];
-pub const INIT_PATH: &'static str = r#"/home/chicken/ctf/fairgame/target/release/build/sagittarius-methods-25d5a44c9c25e36a/out/riscv-guest/riscv32im-risc0-zkvm-elf/release/init"#;
-            
-pub const SCOUT_ELF: &'static [u8] = &[127, 69, 76, 70, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 243, 0, 1, 0, 0, 0, 0, 0, 0, 7, 52, 0, 0, 0, 252, 163, 0, 0, 0, 0, 0, 0, 52, 0, 32, 0, 3, 0, 40, 0, 7, 0, 5, 0, 1, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 7, 115, 70, 0, 0, 115, 70, 0, 0, 5, 0, 0, 0, 0, 16, 0, 0, 1, 0, 0, 0, 0, 96, 0, 0, 0, 0, 8, 0, 0, 0, 8, 0, 4, 0, 0, 0, 84, 0, 0, 0, 6, 0, 0, 0, 0, 16, 0, 0, 81, 229, 116, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 17, 8, 249, 147, 129, 1, 128, 23, 1, 128, 249, 19, 1, 65, 255, 239, 0, 64, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 55, 5, 8, 0, 3, 37, 69, 0, 183, 5, 8, 0, 3, 166, 69, 5, 99, 122, 197, 0, 35, 32, 5, 0, 3, 166, 69, 5, 19, 5, 69, 0, 227, 106, 197, 254, 55, 4, 8, 0, 147, 4, 68, 0, 19, 133, 196, 0, 19, 6, 16, 4, 147, 5, 0, 0, 151, 32, 0, 0, 231, 128, 0, 235, 19, 5, 16, 0, 35, 34, 164, 0, 35, 162, 4, 0, 35, 164, 4, 0, 151, 16, 0, 0, 231, 128, 128, 145, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 19, 0, 0, 103, 0, 195, 126, 19, 1, 1, 244, 35, 46, 17, 10, 35, 44, 129, 10, 35, 42, 145, 10, 35, 40, 33, 11, 35, 38, 49, 11, 35, 36, 65, 11, 35, 34, 81, 11, 35, 32, 97, 11, 35, 46, 113, 9, 35, 44, 129, 9, 35, 42, 145, 9, 35, 40, 161, 9, 35, 38, 177, 9, 19, 4, 5, 0, 99, 6, 6, 22, 19, 9, 6, 0, 147, 132, 5, 0, 19, 5, 1, 8, 147, 7, 48, 0, 151, 0, 0, 0, 231, 128, 0, 103, 131, 73, 129, 8, 3, 75, 1, 8, 19, 5, 32, 0, 99, 150, 169, 0, 35, 0, 100, 1, 111, 0, 128, 20, 131, 76, 33, 8, 3, 77, 17, 8, 131, 77, 65, 8, 131, 74, 49, 8, 3, 76, 97, 8, 3, 5, 177, 8, 3, 74, 81, 8, 131, 5, 161, 8, 3, 70, 145, 8, 35, 5, 161, 6, 131, 11, 113, 8, 19, 149, 133, 0, 51, 101, 197, 0, 147, 5, 16, 0, 35, 20, 161, 6, 99, 14, 185, 14, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 96, 3, 71, 129, 8, 131, 71, 1, 8, 19, 5, 32, 0, 99, 22, 167, 0, 35, 0, 244, 0, 111, 0, 192, 13, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 3, 72, 97, 8, 131, 72, 81, 8, 131, 5, 177, 8, 3, 6, 161, 8, 131, 70, 145, 8, 131, 14, 113, 8, 35, 9, 177, 6, 147, 21, 134, 0, 179, 229, 213, 0, 35, 24, 177, 6, 99, 10, 169, 8, 35, 34, 209, 3, 35, 36, 193, 3, 35, 38, 113, 2, 35, 40, 97, 2, 35, 42, 81, 2, 35, 44, 17, 3, 35, 46, 1, 3, 35, 32, 241, 4, 35, 34, 225, 4, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 87, 131, 70, 129, 8, 3, 71, 1, 8, 19, 5, 32, 0, 99, 150, 166, 0, 35, 0, 228, 0, 111, 0, 0, 5, 131, 72, 33, 8, 131, 66, 17, 8, 3, 67, 65, 8, 131, 67, 49, 8, 131, 71, 97, 8, 3, 72, 81, 8, 3, 5, 177, 8, 131, 5, 161, 8, 3, 70, 145, 8, 3, 14, 113, 8, 35, 13, 161, 6, 19, 149, 133, 0, 51, 101, 197, 0, 35, 28, 161, 6, 19, 5, 48, 0, 99, 24, 169, 4, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 11, 3, 36, 129, 11, 131, 36, 65, 11, 3, 41, 1, 11, 131, 41, 193, 10, 3, 42, 129, 10, 131, 42, 65, 10, 3, 43, 1, 10, 131, 43, 193, 9, 3, 44, 129, 9, 131, 44, 65, 9, 3, 45, 1, 9, 131, 45, 193, 8, 19, 1, 1, 12, 103, 128, 0, 0, 35, 34, 193, 1, 35, 36, 113, 0, 35, 38, 97, 0, 35, 40, 81, 0, 19, 137, 8, 0, 35, 44, 1, 1, 35, 42, 241, 0, 35, 46, 225, 0, 35, 32, 209, 2, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 73, 3, 69, 129, 8, 131, 69, 1, 8, 19, 6, 32, 0, 99, 24, 197, 0, 35, 0, 180, 0, 35, 4, 196, 0, 111, 240, 95, 247, 19, 150, 140, 0, 51, 102, 166, 1, 147, 150, 141, 0, 179, 230, 86, 1, 147, 150, 6, 1, 179, 230, 198, 0, 19, 22, 140, 0, 51, 102, 70, 1, 19, 151, 11, 1, 51, 102, 230, 0, 19, 215, 134, 1, 19, 22, 134, 0, 51, 102, 230, 0, 147, 150, 134, 0, 179, 230, 102, 1, 3, 39, 65, 3, 19, 23, 135, 0, 131, 39, 1, 3, 51, 103, 247, 0, 131, 39, 193, 2, 147, 151, 135, 0, 3, 40, 129, 2, 179, 231, 7, 1, 147, 151, 7, 1, 179, 231, 231, 0, 3, 39, 193, 3, 19, 23, 135, 0, 3, 40, 129, 3, 51, 103, 7, 1, 3, 40, 65, 2, 19, 24, 8, 1, 51, 103, 7, 1, 19, 216, 135, 1, 19, 23, 135, 0, 51, 103, 7, 1, 147, 151, 135, 0, 3, 40, 1, 4, 179, 231, 7, 1, 19, 24, 137, 0, 131, 40, 1, 1, 51, 104, 24, 1, 131, 40, 193, 0, 147, 152, 136, 0, 131, 34, 129, 0, 179, 232, 88, 0, 147, 152, 8, 1, 179, 232, 8, 1, 3, 40, 65, 1, 19, 24, 136, 0, 131, 34, 129, 1, 51, 104, 88, 0, 131, 34, 65, 0, 147, 146, 2, 1, 51, 104, 88, 0, 147, 210, 136, 1, 19, 24, 136, 0, 51, 104, 88, 0, 147, 152, 136, 0, 131, 34, 193, 1, 179, 232, 88, 0, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 147, 146, 130, 0, 179, 226, 98, 0, 19, 147, 131, 0, 51, 99, 195, 1, 19, 19, 3, 1, 131, 67, 97, 8, 3, 78, 81, 8, 131, 14, 113, 8, 179, 98, 83, 0, 19, 147, 131, 0, 51, 99, 195, 1, 147, 147, 14, 1, 51, 99, 115, 0, 147, 211, 130, 1, 19, 19, 131, 0, 51, 99, 115, 0, 131, 3, 177, 8, 3, 14, 161, 8, 131, 78, 145, 8, 147, 146, 130, 0, 35, 5, 113, 4, 147, 19, 142, 0, 179, 227, 211, 1, 3, 30, 129, 6, 131, 14, 161, 6, 35, 20, 113, 4, 179, 229, 178, 0, 35, 16, 193, 7, 35, 1, 209, 7, 131, 18, 1, 7, 131, 3, 33, 7, 3, 30, 129, 7, 131, 14, 161, 7, 35, 28, 81, 4, 35, 13, 113, 4, 35, 24, 193, 5, 35, 9, 209, 5, 35, 34, 196, 0, 35, 32, 212, 0, 3, 6, 1, 6, 131, 6, 17, 6, 131, 2, 33, 6, 35, 4, 52, 1, 163, 4, 196, 0, 35, 5, 212, 0, 163, 5, 84, 0, 35, 40, 228, 0, 35, 38, 244, 0, 3, 6, 129, 5, 131, 6, 145, 5, 3, 7, 161, 5, 131, 39, 65, 4, 35, 10, 244, 0, 163, 10, 196, 0, 35, 11, 212, 0, 163, 11, 228, 0, 35, 46, 4, 1, 35, 44, 20, 1, 3, 6, 1, 5, 131, 6, 17, 5, 3, 7, 33, 5, 131, 39, 1, 2, 35, 0, 244, 2, 163, 0, 196, 2, 35, 1, 212, 2, 163, 1, 228, 2, 35, 36, 100, 2, 35, 34, 180, 2, 131, 5, 129, 4, 3, 6, 145, 4, 131, 6, 161, 4, 35, 6, 164, 2, 163, 6, 180, 2, 35, 7, 196, 2, 163, 7, 212, 2, 111, 240, 95, 212, 19, 1, 1, 242, 35, 46, 17, 12, 35, 44, 129, 12, 35, 42, 145, 12, 35, 40, 33, 13, 35, 38, 49, 13, 35, 36, 65, 13, 35, 34, 81, 13, 35, 32, 97, 13, 35, 46, 113, 11, 35, 44, 129, 11, 35, 42, 145, 11, 35, 40, 161, 11, 35, 38, 177, 11, 19, 4, 5, 0, 147, 10, 80, 0, 99, 128, 7, 28, 147, 132, 7, 0, 19, 137, 5, 0, 19, 5, 129, 7, 19, 6, 64, 0, 151, 0, 0, 0, 231, 128, 64, 179, 131, 73, 1, 8, 131, 69, 129, 7, 19, 5, 32, 0, 99, 150, 169, 0, 147, 138, 5, 0, 111, 0, 0, 25, 35, 34, 177, 2, 3, 77, 161, 7, 131, 77, 145, 7, 131, 75, 193, 7, 3, 74, 177, 7, 3, 76, 225, 7, 3, 75, 209, 7, 131, 12, 241, 7, 147, 5, 17, 8, 19, 5, 17, 5, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 128, 165, 3, 41, 9, 0, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 111, 19, 117, 245, 15, 147, 5, 112, 0, 99, 24, 181, 18, 35, 36, 65, 1, 35, 38, 113, 1, 35, 40, 177, 1, 35, 42, 161, 1, 35, 44, 145, 1, 35, 46, 97, 1, 35, 32, 129, 3, 3, 43, 129, 7, 19, 5, 161, 2, 147, 5, 17, 5, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 192, 159, 19, 5, 16, 0, 99, 140, 164, 14, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 105, 147, 122, 245, 15, 147, 11, 112, 0, 99, 154, 122, 13, 131, 36, 129, 7, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 0, 103, 147, 122, 245, 15, 99, 152, 122, 11, 3, 37, 65, 1, 19, 21, 133, 0, 131, 37, 1, 1, 51, 101, 181, 0, 131, 37, 193, 0, 147, 149, 133, 0, 3, 38, 129, 0, 179, 229, 197, 0, 147, 149, 5, 1, 51, 229, 165, 0, 131, 37, 1, 2, 147, 149, 133, 0, 3, 38, 193, 1, 179, 229, 197, 0, 3, 38, 129, 1, 19, 22, 6, 1, 179, 229, 197, 0, 19, 86, 133, 1, 147, 149, 133, 0, 51, 233, 197, 0, 131, 42, 129, 7, 19, 21, 133, 0, 131, 37, 65, 2, 51, 106, 181, 0, 19, 5, 148, 0, 147, 5, 161, 2, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 128, 147, 19, 85, 139, 1, 163, 9, 164, 2, 19, 85, 11, 1, 35, 9, 164, 2, 19, 85, 139, 0, 163, 8, 164, 2, 35, 8, 100, 3, 35, 34, 36, 1, 35, 32, 68, 1, 35, 4, 52, 1, 35, 42, 148, 2, 35, 44, 84, 3, 111, 0, 64, 1, 147, 10, 5, 0, 35, 0, 84, 1, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 13, 3, 36, 129, 13, 131, 36, 65, 13, 3, 41, 1, 13, 131, 41, 193, 12, 3, 42, 129, 12, 131, 42, 65, 12, 3, 43, 1, 12, 131, 43, 193, 11, 3, 44, 129, 11, 131, 44, 65, 11, 3, 45, 1, 11, 131, 45, 193, 10, 19, 1, 1, 14, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 19, 4, 5, 0, 99, 142, 7, 4, 19, 137, 7, 0, 131, 164, 5, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 82, 19, 117, 245, 15, 19, 10, 112, 0, 99, 26, 69, 3, 131, 41, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 147, 10, 16, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 80, 19, 117, 245, 15, 99, 22, 69, 1, 99, 28, 89, 3, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 3, 42, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 64, 74, 19, 117, 245, 15, 147, 5, 112, 0, 227, 22, 181, 250, 131, 37, 1, 0, 99, 138, 5, 0, 147, 10, 16, 0, 19, 5, 80, 0, 99, 134, 85, 1, 111, 240, 95, 249, 147, 10, 0, 0, 19, 5, 32, 0, 99, 24, 169, 0, 147, 5, 80, 0, 35, 0, 180, 0, 111, 240, 95, 248, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 69, 19, 117, 245, 15, 147, 5, 112, 0, 227, 28, 181, 244, 131, 37, 1, 0, 19, 6, 0, 16, 19, 5, 80, 0, 227, 244, 197, 244, 35, 32, 52, 1, 35, 34, 68, 1, 35, 4, 84, 1, 163, 4, 180, 0, 111, 240, 31, 244, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 19, 5, 133, 0, 151, 0, 0, 0, 231, 128, 0, 98, 19, 117, 245, 15, 19, 9, 112, 0, 99, 30, 37, 9, 19, 5, 164, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 96, 19, 117, 245, 15, 99, 18, 37, 9, 19, 5, 196, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 94, 19, 117, 245, 15, 19, 9, 112, 0, 99, 20, 37, 7, 19, 5, 228, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 93, 19, 117, 245, 15, 99, 24, 37, 5, 3, 37, 4, 0, 131, 164, 4, 0, 35, 36, 161, 0, 147, 5, 129, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 50, 19, 117, 245, 15, 147, 5, 112, 0, 99, 18, 181, 2, 3, 37, 68, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 48, 19, 117, 245, 15, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 246, 35, 46, 17, 8, 35, 44, 129, 8, 35, 42, 145, 8, 151, 16, 0, 0, 231, 128, 192, 49, 35, 36, 161, 8, 19, 4, 129, 8, 35, 38, 129, 8, 55, 69, 0, 7, 19, 6, 133, 215, 55, 69, 0, 7, 19, 7, 133, 216, 19, 5, 129, 4, 147, 5, 193, 8, 147, 6, 176, 0, 147, 7, 32, 0, 147, 4, 32, 0, 151, 0, 0, 0, 231, 128, 128, 179, 3, 69, 1, 5, 99, 4, 149, 6, 19, 5, 129, 0, 147, 5, 129, 4, 19, 6, 192, 3, 151, 16, 0, 0, 231, 128, 64, 97, 19, 5, 129, 4, 147, 5, 129, 0, 151, 0, 0, 0, 231, 128, 0, 103, 151, 16, 0, 0, 231, 128, 128, 42, 35, 36, 161, 8, 35, 38, 129, 8, 19, 5, 129, 4, 147, 5, 193, 8, 151, 0, 0, 0, 231, 128, 128, 232, 19, 117, 245, 15, 147, 5, 112, 0, 99, 22, 181, 4, 131, 32, 193, 9, 3, 36, 129, 9, 131, 36, 65, 9, 19, 1, 1, 10, 103, 128, 0, 0, 3, 5, 129, 4, 35, 4, 161, 0, 55, 69, 0, 7, 19, 5, 133, 217, 183, 69, 0, 7, 147, 134, 69, 220, 183, 69, 0, 7, 19, 135, 69, 227, 147, 5, 176, 2, 19, 6, 129, 0, 151, 48, 0, 0, 231, 128, 192, 9, 115, 16, 0, 192, 163, 9, 161, 8, 55, 69, 0, 7, 19, 5, 133, 217, 183, 69, 0, 7, 147, 134, 69, 220, 183, 69, 0, 7, 19, 135, 69, 228, 147, 5, 176, 2, 19, 6, 49, 9, 151, 48, 0, 0, 231, 128, 192, 6, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 131, 41, 5, 0, 35, 32, 209, 0, 3, 165, 9, 0, 19, 4, 8, 0, 147, 5, 1, 0, 19, 6, 64, 0, 147, 4, 1, 0, 151, 32, 0, 0, 231, 128, 128, 157, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 34, 193, 0, 99, 232, 166, 2, 3, 38, 1, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 138, 196, 0, 147, 4, 69, 252, 19, 137, 197, 0, 51, 5, 169, 0, 147, 5, 1, 0, 151, 16, 0, 0, 231, 128, 64, 75, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 34, 19, 245, 4, 252, 179, 5, 170, 0, 19, 5, 9, 0, 19, 134, 4, 0, 151, 16, 0, 0, 231, 128, 128, 72, 3, 69, 4, 0, 183, 5, 8, 0, 19, 137, 69, 0, 35, 6, 153, 4, 35, 32, 161, 0, 3, 165, 9, 0, 147, 4, 73, 0, 147, 5, 1, 0, 19, 6, 64, 0, 19, 4, 1, 0, 151, 32, 0, 0, 231, 128, 0, 145, 3, 69, 201, 4, 147, 5, 176, 3, 35, 34, 145, 0, 99, 232, 165, 2, 131, 37, 1, 0, 51, 6, 169, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 4, 69, 0, 111, 0, 64, 5, 147, 5, 0, 4, 51, 134, 165, 64, 179, 9, 196, 0, 19, 4, 69, 252, 147, 4, 201, 0, 51, 133, 164, 0, 147, 5, 1, 0, 151, 16, 0, 0, 231, 128, 128, 63, 19, 5, 65, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 22, 19, 117, 4, 252, 179, 133, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 16, 0, 0, 231, 128, 192, 60, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 133, 4, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 3, 37, 5, 0, 147, 132, 5, 0, 19, 20, 38, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 64, 130, 55, 5, 8, 0, 19, 5, 69, 0, 131, 73, 197, 4, 147, 5, 69, 0, 19, 6, 0, 4, 51, 6, 54, 65, 35, 34, 177, 0, 99, 114, 196, 2, 51, 5, 53, 1, 19, 5, 197, 0, 147, 133, 4, 0, 19, 6, 4, 0, 151, 16, 0, 0, 231, 128, 64, 51, 51, 9, 52, 1, 111, 0, 0, 8, 99, 142, 9, 2, 51, 138, 196, 0, 51, 4, 196, 64, 55, 5, 8, 0, 19, 9, 5, 1, 51, 5, 57, 1, 147, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 48, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 192, 7, 147, 4, 10, 0, 19, 117, 4, 252, 19, 121, 244, 3, 147, 5, 240, 3, 179, 137, 164, 0, 99, 252, 133, 0, 19, 86, 100, 0, 19, 5, 65, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 5, 55, 5, 8, 0, 19, 5, 5, 1, 147, 133, 9, 0, 19, 6, 9, 0, 151, 16, 0, 0, 231, 128, 0, 43, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 37, 5, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 131, 42, 5, 0, 3, 165, 74, 0, 3, 164, 10, 0, 147, 132, 5, 0, 51, 5, 197, 0, 35, 162, 170, 0, 99, 22, 4, 0, 55, 69, 0, 7, 19, 4, 197, 17, 19, 245, 52, 0, 99, 6, 5, 2, 19, 5, 240, 255, 99, 2, 166, 2, 147, 6, 0, 0, 55, 69, 0, 7, 147, 5, 133, 217, 19, 5, 6, 0, 99, 16, 5, 2, 19, 5, 4, 0, 19, 134, 6, 0, 111, 0, 128, 9, 147, 21, 101, 0, 179, 133, 180, 0, 179, 6, 166, 64, 227, 4, 5, 254, 99, 14, 6, 6, 19, 85, 150, 1, 99, 24, 5, 10, 19, 25, 102, 0, 19, 53, 21, 0, 19, 26, 37, 0, 19, 5, 9, 0, 147, 5, 10, 0, 151, 0, 0, 0, 231, 128, 0, 34, 99, 14, 5, 8, 147, 9, 5, 0, 19, 5, 9, 252, 19, 90, 101, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 9, 0, 151, 16, 0, 0, 231, 128, 0, 27, 19, 6, 26, 0, 19, 5, 4, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 152, 19, 4, 5, 0, 19, 6, 64, 0, 19, 133, 9, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 29, 111, 0, 128, 1, 147, 5, 64, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 149, 19, 4, 5, 0, 35, 160, 138, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 151, 32, 0, 0, 231, 128, 64, 140, 115, 16, 0, 192, 19, 5, 9, 0, 147, 5, 10, 0, 151, 32, 0, 0, 231, 128, 64, 143, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 70, 5, 0, 147, 6, 16, 0, 99, 202, 198, 0, 99, 18, 6, 2, 3, 165, 5, 0, 35, 36, 1, 0, 111, 0, 64, 2, 147, 6, 32, 0, 99, 24, 214, 14, 3, 165, 5, 0, 35, 36, 209, 0, 111, 0, 0, 1, 3, 165, 5, 0, 147, 5, 16, 0, 35, 36, 177, 0, 3, 37, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 16, 0, 0, 231, 128, 64, 87, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 16, 0, 0, 231, 128, 0, 5, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 220, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 16, 0, 0, 231, 128, 64, 2, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 8, 21, 0, 55, 69, 0, 7, 147, 8, 69, 229, 55, 69, 0, 7, 19, 7, 181, 229, 19, 6, 112, 0, 147, 6, 48, 0, 147, 7, 144, 0, 19, 133, 5, 0, 147, 133, 8, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 23, 3, 0, 0, 103, 0, 67, 166, 23, 19, 0, 0, 103, 0, 67, 203, 23, 19, 0, 0, 103, 0, 195, 215, 23, 19, 0, 0, 103, 0, 131, 215, 23, 19, 0, 0, 103, 0, 3, 120, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 3, 166, 69, 3, 19, 12, 246, 255, 131, 170, 69, 0, 131, 166, 133, 3, 3, 170, 5, 0, 131, 203, 133, 0, 147, 137, 74, 0, 3, 164, 5, 1, 19, 11, 74, 0, 131, 175, 197, 0, 3, 201, 69, 1, 19, 15, 52, 0, 131, 163, 197, 1, 147, 132, 63, 0, 3, 163, 133, 1, 131, 206, 5, 2, 147, 130, 51, 0, 3, 167, 133, 2, 131, 167, 69, 2, 19, 14, 51, 0, 131, 200, 197, 2, 147, 5, 39, 0, 19, 136, 39, 0, 35, 36, 1, 1, 51, 189, 86, 1, 99, 142, 11, 0, 51, 72, 76, 1, 51, 56, 0, 1, 51, 104, 168, 1, 99, 18, 8, 2, 99, 240, 54, 3, 111, 0, 0, 13, 99, 156, 86, 1, 51, 56, 76, 1, 179, 60, 108, 1, 147, 204, 28, 0, 51, 104, 152, 1, 99, 12, 8, 10, 99, 0, 9, 2, 51, 72, 252, 1, 51, 56, 0, 1, 179, 188, 134, 0, 51, 104, 152, 1, 99, 18, 8, 2, 99, 240, 230, 3, 111, 0, 128, 9, 99, 156, 134, 0, 51, 56, 252, 1, 19, 72, 24, 0, 179, 60, 156, 0, 51, 120, 152, 1, 99, 16, 8, 8, 99, 128, 14, 2, 51, 72, 108, 0, 51, 56, 0, 1, 179, 188, 118, 0, 51, 104, 152, 1, 99, 18, 8, 2, 99, 240, 86, 2, 111, 0, 0, 6, 99, 156, 118, 0, 51, 56, 108, 0, 19, 72, 24, 0, 179, 60, 204, 1, 51, 120, 152, 1, 99, 20, 8, 4, 99, 130, 8, 2, 51, 72, 252, 0, 51, 56, 0, 1, 51, 188, 230, 0, 51, 104, 136, 1, 19, 12, 0, 0, 99, 24, 8, 2, 99, 228, 182, 2, 111, 0, 192, 4, 99, 148, 230, 4, 51, 56, 252, 0, 19, 72, 24, 0, 131, 44, 129, 0, 51, 60, 156, 1, 51, 120, 136, 1, 19, 12, 0, 0, 99, 4, 8, 0, 19, 12, 32, 0, 147, 128, 246, 255, 179, 77, 70, 1, 99, 134, 11, 2, 179, 60, 176, 1, 51, 184, 80, 1, 51, 232, 12, 1, 99, 26, 8, 2, 99, 226, 48, 15, 111, 0, 192, 2, 19, 12, 0, 0, 147, 128, 246, 255, 179, 77, 70, 1, 227, 158, 11, 252, 99, 156, 80, 1, 51, 56, 70, 1, 19, 72, 24, 0, 179, 60, 102, 1, 51, 120, 152, 1, 99, 28, 8, 10, 99, 0, 9, 2, 51, 72, 246, 1, 51, 56, 0, 1, 179, 188, 128, 0, 51, 104, 152, 1, 99, 18, 8, 2, 99, 240, 224, 3, 111, 0, 128, 9, 99, 156, 128, 0, 51, 56, 246, 1, 19, 72, 24, 0, 179, 60, 150, 0, 51, 120, 152, 1, 99, 16, 8, 8, 99, 128, 14, 2, 51, 72, 102, 0, 51, 56, 0, 1, 179, 188, 112, 0, 51, 104, 152, 1, 99, 18, 8, 2, 99, 240, 80, 2, 111, 0, 0, 6, 99, 156, 112, 0, 51, 56, 102, 0, 19, 72, 24, 0, 179, 60, 198, 1, 51, 120, 152, 1, 99, 20, 8, 4, 99, 130, 8, 2, 51, 72, 246, 0, 51, 56, 0, 1, 179, 188, 224, 0, 51, 104, 152, 1, 147, 12, 0, 0, 99, 24, 8, 2, 99, 228, 176, 2, 111, 0, 128, 4, 99, 146, 224, 4, 51, 56, 246, 0, 131, 44, 129, 0, 179, 60, 150, 1, 147, 204, 28, 0, 51, 104, 152, 1, 147, 12, 0, 0, 99, 20, 8, 0, 147, 12, 32, 0, 147, 0, 22, 0, 99, 132, 11, 2, 51, 200, 64, 1, 51, 56, 0, 1, 51, 104, 168, 1, 99, 24, 8, 2, 99, 224, 54, 15, 111, 0, 128, 2, 147, 12, 0, 0, 147, 0, 22, 0, 227, 144, 11, 254, 99, 156, 86, 1, 51, 184, 64, 1, 19, 72, 24, 0, 51, 189, 96, 1, 51, 120, 168, 1, 99, 28, 8, 10, 99, 0, 9, 2, 51, 200, 240, 1, 51, 56, 0, 1, 51, 189, 134, 0, 51, 104, 168, 1, 99, 18, 8, 2, 99, 240, 230, 3, 111, 0, 128, 9, 99, 156, 134, 0, 51, 184, 240, 1, 19, 72, 24, 0, 51, 189, 144, 0, 51, 120, 168, 1, 99, 16, 8, 8, 99, 128, 14, 2, 51, 200, 96, 0, 51, 56, 0, 1, 51, 189, 118, 0, 51, 104, 168, 1, 99, 18, 8, 2, 99, 240, 86, 2, 111, 0, 0, 6, 99, 156, 118, 0, 51, 184, 96, 0, 19, 72, 24, 0, 51, 189, 192, 1, 51, 120, 168, 1, 99, 20, 8, 4, 99, 130, 8, 2, 51, 200, 240, 0, 51, 56, 0, 1, 51, 189, 230, 0, 51, 104, 168, 1, 19, 13, 0, 0, 99, 24, 8, 2, 99, 228, 182, 2, 111, 0, 128, 4, 99, 146, 230, 4, 51, 184, 240, 0, 3, 45, 129, 0, 51, 189, 160, 1, 19, 77, 29, 0, 51, 104, 168, 1, 19, 13, 0, 0, 99, 20, 8, 0, 19, 13, 32, 0, 147, 128, 22, 0, 99, 132, 11, 2, 51, 56, 176, 1, 51, 186, 80, 1, 51, 104, 72, 1, 99, 24, 8, 2, 99, 224, 48, 15, 111, 0, 128, 2, 19, 13, 0, 0, 147, 128, 22, 0, 227, 144, 11, 254, 99, 156, 80, 1, 51, 56, 70, 1, 19, 72, 24, 0, 179, 57, 102, 1, 51, 120, 56, 1, 99, 28, 8, 10, 99, 0, 9, 2, 51, 72, 246, 1, 51, 56, 0, 1, 179, 191, 128, 0, 51, 104, 248, 1, 99, 18, 8, 2, 99, 240, 224, 3, 111, 0, 128, 9, 99, 156, 128, 0, 51, 56, 246, 1, 19, 72, 24, 0, 51, 63, 150, 0, 51, 120, 232, 1, 99, 16, 8, 8, 99, 128, 14, 2, 51, 72, 102, 0, 51, 56, 0, 1, 51, 179, 112, 0, 51, 104, 104, 0, 99, 18, 8, 2, 99, 240, 80, 2, 111, 0, 0, 6, 99, 156, 112, 0, 51, 56, 102, 0, 19, 72, 24, 0, 179, 50, 198, 1, 51, 120, 88, 0, 99, 20, 8, 4, 99, 130, 8, 2, 179, 71, 246, 0, 179, 55, 240, 0, 51, 183, 224, 0, 179, 231, 231, 0, 19, 7, 0, 0, 99, 152, 7, 2, 99, 228, 176, 2, 111, 0, 64, 8, 99, 144, 224, 8, 179, 53, 246, 0, 3, 39, 129, 0, 51, 55, 230, 0, 19, 71, 23, 0, 179, 229, 229, 0, 19, 7, 0, 0, 99, 148, 5, 0, 19, 7, 32, 0, 147, 21, 7, 1, 179, 229, 165, 1, 19, 151, 12, 1, 51, 103, 135, 1, 35, 38, 181, 0, 35, 36, 229, 0, 35, 32, 197, 0, 35, 34, 213, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 19, 7, 0, 0, 111, 240, 31, 250, 3, 37, 5, 0, 23, 35, 0, 0, 103, 0, 67, 143, 3, 69, 5, 0, 19, 21, 37, 0, 55, 70, 0, 7, 19, 6, 70, 230, 51, 5, 197, 0, 3, 38, 5, 0, 19, 133, 5, 0, 103, 0, 6, 0, 183, 69, 0, 7, 147, 133, 245, 238, 19, 6, 32, 1, 23, 35, 0, 0, 103, 0, 195, 66, 183, 69, 0, 7, 147, 133, 213, 237, 19, 6, 32, 1, 23, 35, 0, 0, 103, 0, 131, 65, 183, 69, 0, 7, 147, 133, 149, 236, 19, 6, 64, 1, 23, 35, 0, 0, 103, 0, 67, 64, 183, 69, 0, 7, 147, 133, 117, 235, 19, 6, 32, 1, 23, 35, 0, 0, 103, 0, 3, 63, 183, 69, 0, 7, 147, 133, 245, 233, 19, 6, 128, 1, 23, 35, 0, 0, 103, 0, 195, 61, 183, 69, 0, 7, 147, 133, 53, 233, 19, 6, 192, 0, 23, 35, 0, 0, 103, 0, 131, 60, 183, 69, 0, 7, 147, 133, 5, 232, 19, 6, 48, 1, 23, 35, 0, 0, 103, 0, 67, 59, 3, 38, 69, 0, 131, 37, 69, 1, 147, 6, 16, 0, 99, 12, 214, 0, 99, 28, 6, 0, 99, 142, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 103, 128, 0, 0, 99, 142, 5, 0, 19, 5, 0, 0, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 69, 240, 147, 5, 0, 0, 103, 128, 0, 0, 131, 37, 5, 0, 3, 165, 5, 0, 131, 165, 69, 0, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 35, 46, 49, 1, 19, 4, 5, 0, 99, 130, 5, 4, 147, 4, 6, 0, 19, 137, 5, 0, 99, 14, 6, 6, 99, 202, 4, 10, 19, 165, 4, 0, 147, 73, 21, 0, 19, 133, 4, 0, 147, 133, 9, 0, 151, 0, 0, 0, 231, 128, 0, 155, 99, 16, 5, 6, 19, 133, 4, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 192, 17, 115, 16, 0, 192, 3, 165, 70, 1, 131, 165, 6, 1, 35, 42, 161, 0, 35, 40, 177, 0, 3, 165, 198, 0, 131, 165, 134, 0, 3, 166, 70, 0, 131, 166, 6, 0, 35, 38, 161, 0, 35, 36, 177, 0, 35, 34, 193, 0, 35, 32, 209, 0, 147, 5, 1, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 0, 16, 111, 0, 64, 2, 19, 5, 16, 0, 35, 32, 164, 0, 35, 34, 148, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 16, 0, 0, 231, 128, 192, 142, 35, 36, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 19, 1, 1, 3, 103, 128, 0, 0, 151, 16, 0, 0, 231, 128, 192, 4, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 34, 161, 0, 19, 5, 65, 0, 35, 40, 161, 2, 55, 21, 0, 7, 19, 5, 133, 85, 35, 42, 161, 2, 55, 69, 0, 7, 19, 5, 69, 240, 35, 44, 161, 0, 19, 5, 16, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 231, 19, 6, 5, 0, 19, 135, 5, 0, 19, 5, 129, 0, 147, 6, 129, 1, 147, 5, 6, 0, 19, 6, 7, 0, 151, 0, 0, 0, 231, 128, 192, 234, 3, 37, 129, 0, 131, 37, 1, 1, 151, 16, 0, 0, 231, 128, 192, 196, 115, 16, 0, 192, 55, 69, 0, 7, 19, 5, 197, 240, 147, 5, 144, 1, 151, 16, 0, 0, 231, 128, 64, 195, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 19, 4, 6, 0, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 0, 0, 0, 231, 128, 0, 132, 147, 134, 4, 2, 147, 2, 48, 0, 147, 5, 9, 0, 19, 134, 4, 0, 19, 7, 4, 0, 115, 0, 0, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 147, 135, 5, 0, 99, 154, 213, 0, 147, 5, 6, 0, 19, 134, 7, 0, 23, 3, 0, 0, 103, 0, 195, 122, 19, 133, 7, 0, 147, 133, 6, 0, 19, 6, 7, 0, 151, 16, 0, 0, 231, 128, 64, 53, 115, 16, 0, 192, 19, 1, 1, 239, 35, 38, 17, 16, 35, 36, 129, 16, 35, 34, 145, 16, 35, 32, 33, 17, 35, 46, 49, 15, 35, 44, 65, 15, 35, 42, 81, 15, 55, 5, 8, 0, 35, 34, 5, 0, 19, 5, 69, 0, 147, 5, 69, 0, 19, 5, 1, 0, 19, 6, 192, 4, 151, 0, 0, 0, 231, 128, 64, 117, 147, 4, 129, 0, 35, 38, 1, 6, 35, 36, 1, 6, 35, 34, 1, 6, 35, 32, 1, 6, 35, 46, 1, 4, 3, 37, 65, 0, 3, 68, 129, 4, 35, 44, 1, 4, 35, 42, 1, 4, 19, 21, 149, 0, 147, 21, 52, 0, 51, 101, 181, 0, 147, 85, 133, 0, 55, 6, 1, 0, 19, 6, 6, 240, 179, 245, 197, 0, 19, 86, 133, 1, 51, 233, 197, 0, 19, 21, 133, 0, 183, 5, 255, 0, 51, 117, 181, 0, 147, 21, 180, 1, 179, 233, 165, 0, 51, 133, 132, 0, 147, 5, 0, 8, 35, 0, 181, 0, 147, 5, 240, 3, 35, 40, 1, 4, 99, 12, 180, 0, 19, 70, 244, 3, 19, 5, 21, 0, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 0, 93, 19, 117, 132, 3, 147, 5, 128, 3, 179, 234, 41, 1, 99, 24, 181, 18, 3, 37, 1, 0, 183, 69, 0, 7, 19, 132, 197, 17, 19, 9, 4, 0, 99, 4, 5, 0, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 170, 147, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 169, 19, 138, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 192, 107, 147, 4, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 168, 147, 9, 5, 0, 19, 5, 10, 0, 151, 16, 0, 0, 231, 128, 0, 167, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 9, 0, 19, 134, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 19, 5, 1, 7, 19, 6, 128, 3, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 64, 82, 35, 38, 81, 11, 35, 36, 1, 10, 99, 132, 4, 0, 19, 132, 4, 0, 99, 24, 0, 18, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 161, 19, 9, 5, 0, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 160, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 64, 98, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 158, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 157, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 140, 4, 8, 111, 0, 0, 20, 35, 32, 1, 4, 35, 34, 81, 5, 3, 36, 1, 0, 99, 22, 4, 0, 55, 69, 0, 7, 19, 4, 197, 17, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 152, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 151, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 89, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 149, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 64, 148, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 152, 4, 10, 55, 69, 0, 7, 19, 5, 69, 249, 183, 69, 0, 7, 19, 134, 5, 252, 147, 5, 176, 2, 151, 32, 0, 0, 231, 128, 64, 250, 115, 16, 0, 192, 19, 5, 1, 11, 147, 5, 1, 7, 19, 6, 0, 4, 151, 0, 0, 0, 231, 128, 128, 76, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 141, 19, 9, 5, 0, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 140, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 64, 78, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 138, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 137, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 227, 140, 4, 244, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 134, 19, 6, 5, 0, 147, 134, 5, 0, 55, 69, 0, 7, 19, 7, 69, 248, 19, 5, 1, 5, 147, 5, 0, 2, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 1, 5, 3, 38, 65, 5, 131, 38, 129, 5, 3, 39, 193, 5, 131, 39, 1, 6, 3, 40, 65, 6, 131, 40, 129, 6, 3, 35, 193, 6, 147, 2, 16, 0, 19, 5, 0, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 16, 0, 147, 5, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 32, 0, 147, 133, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 48, 0, 147, 5, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 64, 0, 147, 133, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 80, 0, 147, 5, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 96, 0, 147, 133, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 112, 0, 147, 5, 3, 0, 115, 0, 0, 0, 147, 2, 0, 0, 19, 5, 0, 0, 115, 0, 0, 0, 55, 69, 0, 7, 19, 5, 117, 3, 183, 69, 0, 7, 19, 134, 5, 6, 147, 5, 128, 2, 151, 32, 0, 0, 231, 128, 64, 226, 115, 16, 0, 192, 19, 5, 48, 0, 103, 128, 0, 0, 19, 5, 0, 0, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 3, 37, 5, 0, 19, 4, 6, 0, 151, 0, 0, 0, 231, 128, 192, 121, 147, 5, 5, 0, 19, 22, 36, 0, 19, 5, 112, 0, 99, 132, 197, 0, 19, 5, 64, 0, 131, 32, 193, 0, 3, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 6, 64, 0, 99, 100, 182, 0, 147, 5, 64, 0, 19, 214, 21, 0, 183, 86, 85, 85, 147, 134, 86, 85, 51, 118, 214, 0, 51, 134, 197, 64, 183, 54, 51, 51, 147, 134, 54, 51, 51, 119, 214, 0, 19, 86, 38, 0, 51, 118, 214, 0, 51, 6, 199, 0, 147, 86, 70, 0, 51, 6, 214, 0, 183, 22, 15, 15, 147, 134, 246, 240, 51, 118, 214, 0, 183, 6, 1, 1, 147, 134, 22, 16, 51, 6, 214, 2, 19, 86, 134, 1, 147, 6, 246, 255, 179, 54, 208, 0, 55, 7, 0, 128, 51, 7, 183, 64, 51, 55, 167, 0, 179, 230, 230, 0, 99, 150, 6, 2, 147, 6, 16, 0, 99, 4, 214, 0, 147, 5, 0, 0, 51, 5, 181, 0, 19, 5, 245, 255, 179, 5, 176, 64, 51, 117, 181, 0, 19, 85, 37, 0, 23, 3, 0, 0, 103, 0, 131, 116, 19, 1, 1, 255, 55, 69, 0, 7, 19, 5, 5, 7, 183, 69, 0, 7, 147, 134, 133, 9, 183, 69, 0, 7, 19, 135, 197, 16, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 64, 212, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 7, 64, 0, 19, 137, 6, 0, 19, 132, 5, 0, 147, 4, 5, 0, 99, 100, 199, 0, 19, 6, 64, 0, 19, 85, 22, 0, 183, 85, 85, 85, 147, 133, 85, 85, 51, 117, 181, 0, 51, 5, 166, 64, 183, 53, 51, 51, 147, 133, 53, 51, 179, 118, 181, 0, 19, 85, 37, 0, 51, 117, 181, 0, 51, 133, 166, 0, 147, 85, 69, 0, 51, 5, 181, 0, 183, 21, 15, 15, 147, 133, 245, 240, 51, 117, 181, 0, 183, 5, 1, 1, 147, 133, 21, 16, 51, 5, 181, 2, 19, 85, 133, 1, 147, 5, 245, 255, 179, 53, 176, 0, 183, 6, 0, 128, 179, 134, 198, 64, 179, 182, 38, 1, 179, 229, 213, 0, 99, 152, 5, 6, 147, 5, 16, 0, 99, 4, 181, 0, 19, 6, 0, 0, 51, 5, 201, 0, 19, 5, 245, 255, 179, 5, 192, 64, 51, 117, 181, 0, 19, 85, 37, 0, 151, 0, 0, 0, 231, 128, 0, 101, 147, 9, 5, 0, 99, 0, 5, 2, 99, 100, 36, 1, 19, 4, 9, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 192, 20, 19, 133, 9, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 5, 7, 183, 69, 0, 7, 147, 134, 133, 9, 183, 69, 0, 7, 19, 135, 197, 16, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 192, 192, 115, 16, 0, 192, 99, 10, 6, 14, 35, 0, 181, 0, 179, 6, 166, 0, 19, 7, 48, 0, 163, 143, 182, 254, 99, 96, 230, 14, 163, 0, 181, 0, 35, 1, 181, 0, 35, 143, 182, 254, 19, 7, 112, 0, 163, 142, 182, 254, 99, 100, 230, 12, 163, 1, 181, 0, 147, 7, 144, 0, 35, 142, 182, 254, 99, 108, 246, 10, 179, 6, 160, 64, 19, 247, 54, 0, 179, 6, 229, 0, 51, 6, 230, 64, 19, 118, 198, 255, 147, 245, 245, 15, 55, 7, 1, 1, 19, 7, 23, 16, 179, 133, 229, 2, 35, 160, 182, 0, 51, 135, 198, 0, 35, 46, 183, 254, 99, 98, 246, 8, 35, 162, 182, 0, 35, 164, 182, 0, 35, 42, 183, 254, 147, 7, 144, 1, 35, 44, 183, 254, 99, 102, 246, 6, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 34, 183, 254, 35, 36, 183, 254, 35, 38, 183, 254, 147, 247, 70, 0, 147, 231, 135, 1, 51, 6, 246, 64, 19, 8, 0, 2, 35, 40, 183, 254, 99, 108, 6, 3, 179, 134, 246, 0, 19, 7, 240, 1, 35, 160, 182, 0, 35, 162, 182, 0, 35, 164, 182, 0, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 174, 182, 0, 19, 6, 6, 254, 147, 134, 6, 2, 227, 108, 199, 252, 103, 128, 0, 0, 147, 246, 53, 0, 147, 182, 22, 0, 19, 55, 22, 0, 179, 230, 230, 0, 99, 156, 6, 14, 147, 135, 21, 0, 19, 8, 5, 0, 131, 136, 5, 0, 19, 135, 21, 0, 147, 6, 24, 0, 35, 0, 24, 1, 19, 6, 246, 255, 147, 245, 55, 0, 179, 53, 176, 0, 51, 56, 192, 0, 179, 248, 5, 1, 147, 135, 23, 0, 147, 5, 7, 0, 19, 136, 6, 0, 227, 152, 8, 252, 147, 245, 54, 0, 99, 130, 5, 12, 147, 7, 0, 2, 99, 110, 246, 34, 147, 7, 48, 0, 99, 134, 245, 18, 147, 7, 32, 0, 99, 130, 245, 26, 147, 7, 16, 0, 99, 146, 245, 34, 131, 39, 7, 0, 35, 128, 246, 0, 147, 213, 135, 0, 163, 128, 182, 0, 19, 216, 7, 1, 147, 133, 54, 0, 35, 129, 6, 1, 19, 6, 214, 255, 147, 6, 7, 1, 19, 7, 0, 1, 3, 168, 70, 255, 147, 215, 135, 1, 147, 24, 136, 0, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 1, 19, 152, 130, 0, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 1, 147, 146, 136, 0, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 1, 147, 152, 135, 0, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 54, 255, 111, 0, 0, 25, 147, 6, 5, 0, 19, 135, 5, 0, 147, 245, 54, 0, 227, 146, 5, 244, 147, 5, 0, 1, 99, 108, 182, 2, 147, 5, 240, 0, 131, 39, 7, 0, 3, 40, 71, 0, 131, 40, 135, 0, 131, 34, 199, 0, 35, 160, 246, 0, 35, 162, 6, 1, 35, 164, 22, 1, 35, 166, 86, 0, 19, 7, 7, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 234, 197, 252, 147, 117, 134, 0, 99, 142, 5, 0, 131, 37, 7, 0, 131, 39, 71, 0, 35, 160, 182, 0, 35, 162, 246, 0, 147, 134, 134, 0, 19, 7, 135, 0, 147, 117, 70, 0, 99, 130, 5, 22, 131, 37, 7, 0, 35, 160, 182, 0, 147, 134, 70, 0, 19, 7, 71, 0, 111, 0, 0, 21, 131, 39, 7, 0, 147, 133, 22, 0, 35, 128, 246, 0, 19, 6, 246, 255, 147, 6, 7, 1, 19, 7, 32, 1, 3, 168, 70, 255, 147, 215, 135, 0, 147, 24, 136, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 0, 19, 152, 130, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 0, 147, 146, 136, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 0, 147, 152, 135, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 22, 255, 111, 0, 128, 8, 131, 39, 7, 0, 35, 128, 246, 0, 19, 216, 135, 0, 147, 133, 38, 0, 163, 128, 6, 1, 19, 6, 230, 255, 147, 6, 7, 1, 19, 7, 16, 1, 3, 168, 70, 255, 147, 215, 7, 1, 147, 24, 8, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 8, 1, 19, 152, 2, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 2, 1, 147, 146, 8, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 8, 1, 147, 152, 7, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 38, 255, 147, 134, 5, 0, 147, 117, 6, 1, 99, 146, 5, 8, 147, 117, 134, 0, 99, 152, 5, 16, 147, 117, 70, 0, 99, 136, 5, 2, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 19, 7, 71, 0, 147, 135, 70, 0, 163, 129, 182, 0, 147, 134, 7, 0, 147, 117, 38, 0, 99, 152, 5, 0, 147, 117, 22, 0, 99, 150, 5, 2, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 35, 128, 182, 0, 19, 7, 39, 0, 147, 133, 38, 0, 163, 128, 246, 0, 147, 134, 5, 0, 147, 117, 22, 0, 227, 142, 5, 252, 131, 5, 7, 0, 35, 128, 182, 0, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 131, 5, 135, 0, 3, 8, 151, 0, 163, 131, 246, 0, 131, 7, 167, 0, 35, 132, 182, 0, 163, 132, 6, 1, 131, 5, 183, 0, 35, 133, 246, 0, 131, 7, 199, 0, 3, 8, 215, 0, 163, 133, 182, 0, 131, 5, 231, 0, 35, 134, 246, 0, 163, 134, 6, 1, 131, 7, 247, 0, 35, 135, 182, 0, 19, 7, 7, 1, 147, 133, 6, 1, 163, 135, 246, 0, 147, 134, 5, 0, 147, 117, 134, 0, 227, 140, 5, 238, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 19, 7, 135, 0, 147, 133, 134, 0, 163, 131, 246, 0, 147, 134, 5, 0, 147, 117, 70, 0, 227, 150, 5, 234, 111, 240, 95, 237, 151, 0, 0, 0, 231, 128, 0, 2, 115, 16, 0, 192, 147, 5, 5, 2, 103, 128, 0, 0, 103, 128, 0, 0, 147, 5, 0, 2, 103, 128, 0, 0, 55, 70, 0, 7, 19, 6, 182, 28, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 55, 69, 0, 7, 19, 5, 53, 26, 183, 69, 0, 7, 19, 134, 133, 31, 147, 5, 128, 2, 151, 16, 0, 0, 231, 128, 64, 102, 115, 16, 0, 192, 147, 7, 6, 0, 19, 23, 38, 0, 55, 70, 0, 7, 19, 6, 134, 32, 147, 2, 32, 0, 147, 6, 5, 0, 19, 133, 5, 0, 147, 133, 7, 0, 115, 0, 0, 0, 99, 100, 167, 0, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 53, 35, 183, 69, 0, 7, 19, 134, 133, 38, 147, 5, 64, 3, 151, 16, 0, 0, 231, 128, 128, 97, 115, 16, 0, 192, 147, 7, 6, 0, 55, 70, 0, 7, 19, 6, 134, 39, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 103, 128, 0, 0, 183, 5, 8, 0, 131, 166, 5, 0, 51, 134, 166, 64, 99, 236, 198, 0, 19, 149, 38, 0, 183, 6, 0, 8, 51, 133, 166, 64, 35, 160, 197, 0, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 69, 42, 183, 69, 0, 7, 19, 134, 69, 43, 147, 5, 224, 0, 151, 16, 0, 0, 231, 128, 64, 171, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 106, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 120, 150, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 4, 6, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 19, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 192, 27, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 128, 29, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 138, 5, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 248, 149, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 132, 5, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 5, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 0, 14, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 15, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 10, 6, 2, 99, 202, 4, 2, 3, 165, 134, 0, 99, 12, 5, 2, 131, 165, 70, 0, 99, 136, 5, 2, 3, 165, 6, 0, 19, 6, 16, 0, 147, 134, 4, 0, 151, 240, 255, 255, 231, 128, 64, 148, 99, 18, 5, 4, 111, 0, 192, 2, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 192, 3, 99, 132, 4, 2, 147, 5, 16, 0, 19, 133, 4, 0, 151, 240, 255, 255, 231, 128, 128, 144, 99, 28, 5, 0, 35, 34, 148, 0, 147, 5, 16, 0, 35, 36, 180, 0, 111, 0, 64, 1, 19, 5, 16, 0, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 254, 55, 69, 0, 7, 19, 5, 69, 57, 35, 36, 161, 0, 19, 5, 16, 0, 35, 38, 161, 0, 35, 40, 1, 0, 55, 69, 0, 7, 19, 5, 69, 44, 35, 44, 161, 0, 35, 46, 1, 0, 55, 69, 0, 7, 147, 5, 197, 57, 19, 5, 129, 0, 151, 16, 0, 0, 231, 128, 128, 44, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 151, 240, 255, 255, 231, 128, 192, 136, 115, 16, 0, 192, 151, 240, 255, 255, 231, 128, 128, 254, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 19, 132, 5, 0, 131, 165, 69, 0, 147, 4, 5, 0, 99, 134, 5, 4, 3, 37, 4, 0, 19, 9, 0, 0, 19, 6, 69, 0, 147, 150, 53, 0, 3, 39, 6, 0, 51, 9, 39, 1, 147, 134, 134, 255, 19, 6, 134, 0, 227, 152, 6, 254, 3, 38, 68, 1, 99, 0, 6, 4, 99, 132, 5, 2, 3, 37, 69, 0, 19, 53, 21, 0, 147, 53, 9, 1, 51, 117, 181, 0, 99, 10, 5, 0, 111, 0, 192, 1, 3, 37, 68, 1, 19, 9, 0, 0, 99, 4, 5, 4, 19, 5, 9, 0, 51, 9, 41, 1, 99, 118, 169, 0, 19, 9, 0, 0, 111, 0, 64, 3, 99, 8, 9, 2, 99, 72, 9, 12, 147, 5, 16, 0, 19, 5, 9, 0, 151, 224, 255, 255, 231, 128, 192, 123, 99, 30, 5, 0, 147, 5, 16, 0, 19, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 5, 16, 0, 35, 160, 164, 0, 35, 162, 36, 1, 3, 37, 68, 1, 131, 37, 4, 1, 35, 164, 4, 0, 35, 38, 145, 0, 35, 34, 161, 2, 35, 32, 177, 2, 3, 37, 196, 0, 131, 37, 132, 0, 3, 38, 68, 0, 131, 38, 4, 0, 35, 46, 161, 0, 35, 44, 177, 0, 35, 42, 193, 0, 35, 40, 209, 0, 55, 69, 0, 7, 147, 5, 197, 75, 19, 5, 193, 0, 19, 6, 1, 1, 151, 0, 0, 0, 231, 128, 64, 116, 99, 30, 5, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 19, 1, 1, 4, 103, 128, 0, 0, 55, 69, 0, 7, 19, 5, 197, 58, 183, 69, 0, 7, 147, 134, 5, 62, 183, 69, 0, 7, 19, 135, 197, 74, 147, 5, 48, 3, 19, 6, 129, 2, 151, 16, 0, 0, 231, 128, 0, 28, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 64, 227, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 36, 5, 0, 19, 5, 0, 8, 147, 132, 5, 0, 99, 252, 165, 2, 131, 37, 132, 0, 3, 37, 68, 0, 99, 154, 165, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 132, 0, 3, 37, 4, 0, 51, 5, 181, 0, 35, 0, 149, 0, 3, 37, 132, 0, 19, 5, 21, 0, 111, 0, 64, 13, 19, 213, 180, 0, 35, 38, 1, 0, 99, 18, 5, 2, 19, 213, 100, 0, 19, 101, 5, 12, 35, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 6, 161, 0, 147, 4, 32, 0, 111, 0, 192, 7, 19, 213, 4, 1, 99, 26, 5, 2, 19, 213, 196, 0, 19, 101, 5, 14, 35, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 35, 7, 161, 0, 147, 4, 48, 0, 111, 0, 64, 4, 19, 149, 180, 0, 19, 85, 213, 1, 19, 101, 5, 15, 35, 6, 161, 0, 19, 149, 228, 0, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 35, 7, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 7, 161, 0, 147, 4, 64, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 99, 96, 149, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 5, 193, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 88, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 192, 166, 3, 41, 132, 0, 111, 240, 223, 250, 19, 1, 1, 253, 35, 38, 17, 2, 3, 37, 5, 0, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 69, 0, 7, 147, 5, 197, 75, 19, 5, 193, 0, 19, 6, 1, 1, 151, 0, 0, 0, 231, 128, 0, 83, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 3, 36, 5, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 98, 197, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 133, 9, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 73, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 151, 3, 41, 132, 0, 111, 240, 159, 250, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 69, 0, 7, 19, 5, 5, 214, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 69, 0, 7, 19, 5, 5, 94, 35, 36, 161, 0, 19, 5, 48, 0, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 1, 2, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 0, 231, 115, 16, 0, 192, 19, 6, 5, 0, 19, 5, 53, 0, 19, 119, 197, 255, 179, 6, 199, 64, 99, 238, 213, 0, 51, 133, 213, 64, 147, 55, 69, 0, 19, 184, 86, 0, 19, 72, 24, 0, 179, 103, 248, 0, 99, 134, 7, 2, 19, 5, 0, 0, 99, 128, 5, 2, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 103, 128, 0, 0, 147, 117, 53, 0, 147, 7, 0, 0, 99, 4, 199, 2, 51, 7, 230, 64, 19, 8, 6, 0, 131, 8, 8, 0, 19, 8, 24, 0, 147, 168, 8, 252, 147, 200, 24, 0, 19, 7, 23, 0, 179, 135, 23, 1, 227, 20, 7, 254, 179, 6, 214, 0, 19, 8, 0, 0, 99, 132, 5, 2, 19, 118, 197, 255, 51, 134, 198, 0, 3, 7, 6, 0, 19, 6, 22, 0, 19, 39, 7, 252, 19, 71, 23, 0, 147, 133, 245, 255, 51, 8, 232, 0, 227, 148, 5, 254, 147, 82, 37, 0, 55, 5, 1, 1, 19, 7, 21, 16, 55, 5, 255, 0, 147, 5, 245, 15, 55, 5, 1, 0, 19, 6, 21, 0, 51, 5, 248, 0, 111, 0, 64, 3, 147, 146, 40, 0, 179, 134, 86, 0, 179, 130, 23, 65, 147, 248, 56, 0, 179, 115, 179, 0, 19, 83, 131, 0, 51, 115, 179, 0, 51, 3, 115, 0, 51, 3, 195, 2, 19, 83, 3, 1, 51, 5, 163, 0, 99, 152, 8, 10, 227, 132, 2, 244, 147, 135, 2, 0, 19, 8, 0, 12, 147, 136, 2, 0, 99, 228, 2, 1, 147, 8, 0, 12, 147, 242, 200, 15, 19, 152, 34, 0, 51, 136, 6, 1, 19, 3, 0, 0, 227, 132, 2, 250, 147, 130, 6, 0, 131, 163, 2, 0, 19, 206, 243, 255, 19, 94, 126, 0, 147, 211, 99, 0, 131, 174, 66, 0, 179, 99, 126, 0, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 130, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 194, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 179, 227, 195, 1, 179, 243, 227, 0, 147, 130, 2, 1, 51, 131, 99, 0, 227, 150, 2, 249, 111, 240, 159, 242, 147, 6, 0, 12, 99, 228, 215, 0, 147, 7, 0, 12, 147, 6, 0, 0, 147, 247, 55, 0, 147, 151, 39, 0, 131, 40, 8, 0, 19, 8, 72, 0, 147, 194, 248, 255, 147, 210, 114, 0, 147, 216, 104, 0, 179, 232, 18, 1, 179, 248, 232, 0, 147, 135, 199, 255, 179, 134, 216, 0, 227, 158, 7, 252, 51, 247, 182, 0, 147, 214, 134, 0, 179, 245, 182, 0, 179, 133, 229, 0, 179, 133, 197, 2, 147, 213, 5, 1, 51, 133, 165, 0, 103, 128, 0, 0, 55, 5, 177, 232, 19, 5, 133, 221, 183, 69, 246, 136, 147, 133, 69, 41, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 3, 164, 197, 1, 131, 164, 133, 1, 131, 38, 196, 0, 19, 9, 5, 0, 55, 69, 0, 7, 147, 5, 69, 97, 19, 6, 192, 0, 19, 133, 4, 0, 231, 128, 6, 0, 147, 9, 16, 0, 99, 16, 5, 18, 3, 37, 137, 0, 99, 14, 5, 0, 35, 34, 161, 0, 19, 5, 65, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 69, 161, 111, 0, 192, 4, 3, 37, 73, 0, 131, 41, 9, 0, 131, 37, 197, 0, 19, 133, 9, 0, 231, 128, 5, 0, 55, 214, 68, 116, 19, 6, 118, 236, 179, 197, 197, 0, 55, 214, 164, 102, 19, 6, 182, 142, 51, 69, 197, 0, 51, 101, 181, 0, 99, 30, 5, 4, 35, 34, 49, 1, 19, 5, 65, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 133, 159, 35, 38, 161, 0, 55, 69, 0, 7, 19, 5, 69, 98, 35, 32, 161, 2, 19, 5, 32, 0, 35, 34, 161, 2, 35, 36, 1, 2, 19, 5, 129, 0, 35, 40, 161, 2, 147, 9, 16, 0, 35, 42, 49, 3, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 17, 99, 26, 5, 6, 3, 37, 201, 0, 147, 5, 133, 0, 19, 6, 197, 0, 35, 36, 161, 0, 55, 69, 0, 7, 19, 5, 5, 167, 35, 38, 161, 0, 35, 40, 177, 0, 55, 69, 0, 7, 19, 5, 5, 214, 35, 42, 161, 0, 35, 44, 193, 0, 35, 46, 161, 0, 55, 69, 0, 7, 19, 5, 197, 95, 35, 32, 161, 2, 19, 5, 48, 0, 35, 34, 161, 2, 35, 36, 1, 2, 147, 5, 129, 0, 35, 40, 177, 2, 35, 42, 161, 2, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 10, 147, 9, 5, 0, 19, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 19, 1, 1, 5, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 255, 35, 36, 161, 0, 35, 38, 177, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 55, 69, 0, 7, 19, 5, 5, 167, 35, 46, 161, 0, 55, 69, 0, 7, 19, 5, 69, 99, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 19, 6, 129, 1, 35, 40, 193, 0, 35, 42, 161, 0, 19, 5, 1, 0, 151, 16, 0, 0, 231, 128, 64, 165, 115, 16, 0, 192, 3, 37, 5, 0, 111, 0, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 19, 4, 6, 0, 35, 36, 1, 0, 19, 6, 0, 2, 35, 38, 193, 0, 19, 6, 48, 0, 35, 4, 193, 2, 3, 38, 132, 0, 35, 40, 1, 0, 35, 44, 1, 0, 35, 32, 161, 2, 35, 34, 177, 2, 99, 4, 6, 18, 3, 37, 196, 0, 99, 10, 5, 24, 131, 37, 4, 0, 147, 6, 245, 255, 147, 150, 86, 0, 147, 214, 86, 0, 147, 132, 22, 0, 19, 137, 69, 0, 147, 25, 85, 0, 19, 10, 6, 1, 147, 10, 16, 0, 55, 53, 0, 7, 19, 11, 69, 4, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 22, 5, 24, 3, 37, 74, 255, 35, 38, 161, 0, 3, 5, 202, 0, 35, 4, 161, 2, 131, 37, 138, 255, 3, 37, 4, 1, 35, 36, 177, 0, 131, 38, 74, 0, 131, 37, 138, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 40, 193, 0, 35, 42, 177, 0, 131, 38, 202, 255, 131, 37, 10, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 44, 193, 0, 35, 46, 177, 0, 131, 37, 10, 255, 147, 149, 53, 0, 51, 5, 181, 0, 3, 38, 69, 0, 3, 37, 5, 0, 147, 5, 129, 0, 231, 0, 6, 0, 99, 24, 5, 12, 19, 9, 137, 0, 147, 137, 9, 254, 19, 10, 10, 2, 227, 156, 9, 240, 111, 0, 192, 7, 3, 37, 68, 1, 99, 0, 5, 8, 131, 37, 4, 1, 3, 38, 4, 0, 147, 6, 245, 255, 147, 150, 54, 0, 147, 214, 54, 0, 147, 132, 22, 0, 19, 9, 70, 0, 147, 137, 69, 0, 19, 26, 53, 0, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 24, 5, 6, 3, 166, 9, 0, 3, 165, 201, 255, 147, 5, 129, 0, 231, 0, 6, 0, 99, 30, 5, 4, 19, 9, 137, 0, 19, 10, 138, 255, 147, 137, 137, 0, 227, 16, 10, 252, 111, 0, 128, 0, 147, 4, 0, 0, 3, 37, 68, 0, 99, 234, 164, 0, 111, 0, 0, 4, 147, 4, 0, 0, 3, 37, 68, 0, 99, 250, 164, 2, 3, 37, 4, 0, 147, 149, 52, 0, 51, 6, 181, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 6, 0, 3, 38, 70, 0, 131, 166, 198, 0, 231, 128, 6, 0, 99, 6, 5, 0, 19, 5, 16, 0, 111, 0, 128, 0, 19, 5, 0, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 19, 1, 1, 5, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 132, 7, 0, 147, 4, 7, 0, 147, 137, 6, 0, 19, 10, 6, 0, 19, 9, 5, 0, 99, 142, 5, 4, 3, 43, 9, 0, 19, 117, 27, 0, 183, 10, 17, 0, 99, 4, 5, 0, 147, 10, 176, 2, 179, 12, 133, 0, 19, 117, 75, 0, 99, 8, 5, 4, 19, 5, 0, 1, 99, 252, 169, 4, 19, 5, 0, 0, 99, 128, 9, 6, 147, 133, 9, 0, 19, 6, 10, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 111, 0, 128, 3, 3, 43, 9, 0, 147, 12, 20, 0, 147, 10, 208, 2, 19, 117, 75, 0, 227, 28, 5, 250, 19, 10, 0, 0, 3, 37, 137, 0, 99, 18, 5, 2, 111, 0, 0, 6, 19, 5, 10, 0, 147, 133, 9, 0, 151, 0, 0, 0, 231, 128, 64, 138, 179, 12, 149, 1, 3, 37, 137, 0, 99, 2, 5, 4, 3, 45, 201, 0, 99, 254, 172, 3, 19, 117, 139, 0, 99, 22, 5, 14, 131, 69, 9, 2, 19, 6, 48, 0, 19, 5, 16, 0, 99, 132, 197, 0, 19, 133, 5, 0, 147, 117, 53, 0, 51, 5, 157, 65, 99, 140, 5, 18, 19, 6, 16, 0, 99, 158, 197, 18, 147, 12, 0, 0, 111, 0, 0, 20, 131, 43, 137, 1, 3, 41, 201, 1, 19, 133, 11, 0, 147, 5, 9, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 128, 31, 19, 11, 16, 0, 99, 2, 5, 4, 19, 5, 11, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 3, 35, 201, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 0, 3, 0, 3, 37, 73, 0, 35, 36, 161, 0, 19, 5, 0, 3, 131, 77, 9, 2, 131, 43, 137, 1, 3, 44, 201, 1, 35, 34, 169, 0, 19, 11, 16, 0, 35, 0, 105, 3, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 18, 227, 26, 5, 242, 51, 5, 157, 65, 147, 9, 21, 0, 147, 137, 249, 255, 99, 138, 9, 12, 3, 38, 12, 1, 147, 5, 0, 3, 19, 133, 11, 0, 231, 0, 6, 0, 227, 4, 5, 254, 111, 240, 223, 240, 147, 12, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 220, 21, 0, 131, 43, 137, 1, 3, 44, 201, 1, 3, 41, 73, 0, 19, 11, 21, 0, 19, 11, 251, 255, 99, 0, 11, 2, 3, 38, 12, 1, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 11, 16, 0, 111, 240, 31, 236, 55, 5, 17, 0, 19, 11, 16, 0, 227, 10, 169, 234, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 8, 227, 26, 5, 232, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 230, 19, 4, 0, 0, 99, 136, 140, 4, 3, 38, 12, 1, 19, 4, 20, 0, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 64, 3, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 226, 19, 11, 0, 0, 3, 37, 129, 0, 35, 34, 169, 0, 35, 0, 185, 3, 111, 240, 159, 226, 19, 133, 12, 0, 51, 59, 149, 1, 111, 240, 223, 225, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 183, 7, 17, 0, 19, 4, 7, 0, 147, 132, 6, 0, 147, 137, 5, 0, 19, 9, 5, 0, 99, 0, 246, 2, 131, 166, 9, 1, 19, 5, 9, 0, 147, 5, 6, 0, 231, 128, 6, 0, 147, 5, 5, 0, 19, 5, 16, 0, 99, 156, 5, 2, 99, 136, 4, 2, 3, 163, 201, 0, 19, 5, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 0, 3, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 35, 32, 97, 1, 19, 9, 5, 0, 3, 37, 133, 0, 131, 38, 9, 1, 19, 7, 245, 255, 51, 55, 224, 0, 147, 135, 246, 255, 179, 55, 240, 0, 51, 119, 247, 0, 19, 4, 6, 0, 147, 132, 5, 0, 99, 16, 7, 30, 147, 5, 16, 0, 99, 154, 182, 20, 3, 38, 73, 1, 147, 5, 0, 0, 179, 134, 132, 0, 19, 7, 22, 0, 183, 7, 17, 0, 19, 8, 240, 13, 147, 8, 0, 15, 19, 134, 4, 0, 111, 0, 128, 1, 19, 3, 22, 0, 179, 133, 197, 64, 179, 133, 101, 0, 19, 6, 3, 0, 99, 142, 242, 16, 19, 7, 247, 255, 99, 0, 7, 8, 99, 8, 214, 16, 3, 3, 6, 0, 147, 114, 243, 15, 227, 92, 3, 252, 3, 67, 22, 0, 147, 243, 242, 1, 19, 126, 243, 3, 99, 112, 88, 4, 3, 67, 38, 0, 19, 30, 110, 0, 19, 115, 243, 3, 51, 110, 110, 0, 99, 238, 18, 3, 131, 66, 54, 0, 19, 147, 211, 1, 19, 83, 179, 0, 147, 19, 110, 0, 147, 242, 242, 3, 179, 226, 83, 0, 179, 226, 98, 0, 99, 128, 242, 12, 19, 3, 70, 0, 111, 240, 31, 249, 19, 3, 38, 0, 147, 146, 99, 0, 179, 226, 194, 1, 111, 240, 31, 248, 19, 3, 54, 0, 147, 146, 195, 0, 179, 98, 94, 0, 111, 240, 31, 247, 99, 10, 214, 8, 131, 6, 6, 0, 99, 218, 6, 4, 147, 246, 246, 15, 19, 7, 0, 14, 99, 228, 230, 4, 19, 7, 0, 15, 99, 224, 230, 4, 3, 71, 22, 0, 131, 71, 38, 0, 19, 119, 247, 3, 147, 247, 247, 3, 3, 70, 54, 0, 147, 150, 214, 1, 147, 214, 182, 0, 19, 23, 199, 0, 147, 151, 103, 0, 51, 231, 231, 0, 19, 118, 246, 3, 51, 102, 199, 0, 51, 102, 214, 0, 183, 6, 17, 0, 99, 14, 214, 2, 99, 132, 5, 2, 99, 240, 133, 2, 51, 134, 180, 0, 3, 6, 6, 0, 147, 6, 0, 252, 99, 90, 214, 0, 19, 6, 0, 0, 99, 26, 6, 0, 111, 0, 128, 1, 227, 154, 133, 254, 19, 134, 4, 0, 99, 6, 6, 0, 19, 132, 5, 0, 147, 4, 6, 0, 99, 2, 5, 8, 131, 41, 201, 0, 19, 5, 0, 1, 99, 114, 164, 6, 19, 5, 0, 0, 99, 4, 4, 2, 147, 5, 4, 0, 19, 134, 4, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 99, 116, 53, 5, 131, 69, 9, 2, 147, 6, 48, 0, 19, 6, 0, 0, 99, 132, 213, 0, 19, 134, 5, 0, 147, 117, 54, 0, 51, 133, 169, 64, 99, 130, 5, 6, 19, 6, 16, 0, 99, 148, 197, 6, 147, 10, 0, 0, 111, 0, 192, 6, 19, 133, 4, 0, 147, 5, 4, 0, 151, 240, 255, 255, 231, 128, 0, 56, 227, 96, 53, 253, 131, 37, 201, 1, 3, 37, 137, 1, 3, 163, 197, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 0, 3, 0, 147, 10, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 218, 21, 0, 131, 41, 137, 1, 3, 43, 201, 1, 3, 41, 73, 0, 19, 10, 21, 0, 19, 10, 250, 255, 99, 0, 10, 2, 3, 38, 11, 1, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 10, 16, 0, 111, 0, 128, 5, 55, 5, 17, 0, 19, 10, 16, 0, 99, 6, 169, 4, 131, 38, 203, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 99, 26, 5, 2, 19, 4, 0, 0, 99, 130, 138, 2, 3, 38, 11, 1, 19, 4, 20, 0, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 128, 0, 19, 133, 10, 0, 51, 58, 85, 1, 19, 5, 10, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 197, 1, 3, 37, 133, 1, 3, 163, 198, 0, 103, 0, 3, 0, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 69, 0, 7, 147, 133, 197, 99, 19, 6, 80, 0, 103, 0, 3, 0, 3, 38, 69, 0, 3, 37, 5, 0, 3, 35, 198, 0, 103, 0, 3, 0, 3, 37, 5, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 195, 199, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 5, 0, 3, 37, 70, 1, 35, 42, 161, 0, 3, 37, 6, 1, 35, 40, 161, 0, 3, 37, 198, 0, 35, 38, 161, 0, 3, 37, 134, 0, 35, 36, 161, 0, 131, 38, 70, 0, 3, 165, 133, 1, 35, 34, 209, 0, 3, 38, 6, 0, 131, 165, 197, 1, 35, 32, 193, 0, 19, 6, 1, 0, 151, 240, 255, 255, 231, 128, 0, 95, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 131, 192, 103, 128, 0, 0, 19, 1, 1, 254, 55, 70, 0, 7, 19, 6, 70, 100, 35, 36, 193, 0, 55, 70, 0, 7, 19, 6, 70, 100, 35, 38, 193, 0, 35, 40, 161, 0, 35, 42, 177, 0, 19, 5, 16, 0, 35, 12, 161, 0, 19, 5, 129, 0, 151, 224, 255, 255, 231, 128, 0, 201, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 35, 46, 177, 0, 19, 5, 129, 1, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 55, 69, 0, 7, 19, 5, 69, 100, 35, 40, 161, 0, 35, 42, 1, 0, 19, 5, 1, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 248, 115, 16, 0, 192, 19, 1, 1, 252, 35, 36, 161, 0, 35, 38, 177, 0, 35, 40, 193, 0, 35, 42, 209, 0, 19, 5, 129, 0, 35, 40, 161, 2, 55, 69, 0, 7, 19, 5, 5, 167, 35, 42, 161, 2, 19, 5, 1, 1, 35, 44, 161, 2, 55, 69, 0, 7, 19, 5, 133, 158, 35, 46, 161, 2, 55, 69, 0, 7, 19, 5, 133, 101, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 147, 5, 7, 0, 151, 0, 0, 0, 231, 128, 128, 241, 115, 16, 0, 192, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 69, 0, 7, 147, 133, 133, 102, 19, 6, 176, 0, 103, 0, 3, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 147, 6, 6, 0, 19, 87, 69, 0, 147, 7, 16, 39, 19, 6, 112, 2, 99, 118, 247, 2, 19, 7, 48, 6, 99, 106, 167, 12, 19, 7, 160, 0, 99, 118, 229, 18, 19, 6, 246, 255, 19, 7, 209, 0, 51, 7, 199, 0, 19, 5, 5, 3, 35, 0, 167, 0, 111, 0, 0, 20, 19, 6, 0, 0, 55, 23, 183, 209, 19, 7, 151, 117, 183, 39, 0, 0, 147, 135, 7, 113, 55, 24, 0, 0, 19, 8, 184, 71, 147, 8, 64, 6, 183, 66, 0, 7, 147, 130, 66, 77, 19, 3, 209, 0, 183, 227, 245, 5, 147, 131, 243, 15, 19, 14, 5, 0, 51, 53, 229, 2, 19, 85, 213, 0, 179, 14, 245, 2, 179, 14, 222, 65, 19, 159, 14, 1, 19, 95, 47, 1, 51, 15, 15, 3, 147, 95, 31, 1, 19, 95, 15, 1, 19, 127, 239, 127, 179, 143, 31, 3, 179, 142, 254, 65, 147, 158, 30, 1, 147, 222, 14, 1, 51, 143, 226, 1, 179, 15, 195, 0, 3, 68, 15, 0, 3, 15, 31, 0, 179, 142, 210, 1, 131, 132, 30, 0, 131, 206, 14, 0, 35, 130, 239, 3, 163, 129, 143, 2, 35, 131, 159, 2, 163, 130, 223, 3, 19, 6, 198, 255, 227, 234, 195, 249, 19, 6, 118, 2, 19, 7, 48, 6, 227, 122, 167, 242, 19, 23, 5, 1, 19, 87, 39, 1, 183, 23, 0, 0, 147, 135, 183, 71, 51, 7, 247, 2, 19, 87, 23, 1, 147, 7, 64, 6, 179, 7, 247, 2, 51, 5, 245, 64, 19, 21, 21, 1, 19, 85, 5, 1, 19, 6, 230, 255, 183, 71, 0, 7, 147, 135, 71, 77, 51, 133, 167, 0, 131, 7, 21, 0, 3, 69, 5, 0, 19, 8, 209, 0, 51, 8, 200, 0, 163, 0, 248, 0, 35, 0, 168, 0, 19, 5, 7, 0, 19, 7, 160, 0, 227, 110, 229, 236, 19, 21, 21, 0, 19, 6, 230, 255, 55, 71, 0, 7, 19, 7, 71, 77, 51, 5, 167, 0, 3, 7, 21, 0, 3, 69, 5, 0, 147, 7, 209, 0, 179, 135, 199, 0, 163, 128, 231, 0, 35, 128, 167, 0, 19, 5, 209, 0, 51, 7, 197, 0, 19, 5, 112, 2, 179, 7, 197, 64, 55, 69, 0, 7, 19, 6, 53, 103, 19, 133, 6, 0, 147, 6, 0, 0, 151, 240, 255, 255, 231, 128, 192, 86, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 19, 1, 1, 4, 103, 128, 0, 0, 3, 37, 5, 0, 19, 134, 5, 0, 147, 5, 16, 0, 23, 3, 0, 0, 103, 0, 3, 227, 115, 104, 111, 116, 83, 99, 111, 117, 116, 80, 97, 114, 97, 109, 115, 115, 116, 97, 116, 101, 131, 61, 0, 7, 5, 0, 0, 0, 116, 61, 0, 7, 4, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 136, 13, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 100, 21, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 101, 110, 118, 46, 114, 115, 212, 61, 0, 7, 96, 0, 0, 0, 14, 1, 0, 0, 9, 0, 0, 0, 212, 61, 0, 7, 96, 0, 0, 0, 99, 1, 0, 0, 9, 0, 0, 0, 72, 105, 116, 84, 121, 112, 101, 68, 101, 115, 116, 114, 111, 121, 101, 100, 132, 21, 0, 7, 152, 21, 0, 7, 172, 21, 0, 7, 192, 21, 0, 7, 212, 21, 0, 7, 232, 21, 0, 7, 252, 21, 0, 7, 83, 101, 114, 105, 97, 108, 105, 122, 101, 66, 117, 102, 102, 101, 114, 70, 117, 108, 108, 78, 111, 116, 83, 117, 112, 112, 111, 114, 116, 101, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 85, 110, 101, 120, 112, 101, 99, 116, 101, 100, 69, 110, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 85, 116, 102, 56, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 79, 112, 116, 105, 111, 110, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 67, 104, 97, 114, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 66, 111, 111, 108, 0, 0, 0, 4, 63, 0, 7, 0, 0, 0, 0, 77, 101, 109, 111, 114, 121, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 102, 97, 105, 108, 117, 114, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 112, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 99, 111, 114, 101, 47, 115, 104, 97, 46, 114, 115, 0, 37, 63, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 17, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 0, 37, 63, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 33, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 0, 208, 63, 0, 7, 103, 0, 0, 0, 222, 0, 0, 0, 9, 0, 0, 0, 85, 110, 97, 98, 108, 101, 32, 116, 111, 32, 97, 108, 105, 103, 110, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 116, 111, 32, 119, 111, 114, 100, 32, 115, 105, 122, 101, 0, 0, 29, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 128, 59, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 97, 108, 108, 111, 99, 46, 114, 115, 0, 0, 168, 64, 0, 7, 98, 0, 0, 0, 27, 0, 0, 0, 22, 0, 0, 0, 106, 9, 230, 103, 187, 103, 174, 133, 60, 110, 243, 114, 165, 79, 245, 58, 81, 14, 82, 127, 155, 5, 104, 140, 31, 131, 217, 171, 91, 224, 205, 25, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 80, 65, 78, 73, 67, 0, 0, 60, 65, 0, 7, 103, 0, 0, 0, 62, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 82, 69, 65, 68, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 110, 114, 101, 97, 100, 32, 97, 115, 32, 117, 115, 105, 122, 101, 32, 60, 61, 32, 110, 98, 121, 116, 101, 115, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 0, 60, 65, 0, 7, 103, 0, 0, 0, 189, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 87, 82, 73, 84, 69, 0, 79, 117, 116, 32, 111, 102, 32, 109, 101, 109, 111, 114, 121, 33, 0, 0, 60, 65, 0, 7, 103, 0, 0, 0, 240, 1, 0, 0, 31, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 114, 97, 119, 95, 118, 101, 99, 46, 114, 115, 99, 97, 112, 97, 99, 105, 116, 121, 32, 111, 118, 101, 114, 102, 108, 111, 119, 131, 67, 0, 7, 17, 0, 0, 0, 196, 66, 0, 7, 191, 0, 0, 0, 6, 2, 0, 0, 5, 0, 0, 0, 97, 32, 102, 111, 114, 109, 97, 116, 116, 105, 110, 103, 32, 116, 114, 97, 105, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 114, 101, 116, 117, 114, 110, 101, 100, 32, 97, 110, 32, 101, 114, 114, 111, 114, 0, 244, 39, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 204, 57, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 102, 109, 116, 46, 114, 115, 0, 240, 67, 0, 7, 187, 0, 0, 0, 100, 2, 0, 0, 9, 0, 0, 0, 100, 41, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 48, 43, 0, 7, 104, 41, 0, 7, 204, 42, 0, 7, 48, 48, 48, 49, 48, 50, 48, 51, 48, 52, 48, 53, 48, 54, 48, 55, 48, 56, 48, 57, 49, 48, 49, 49, 49, 50, 49, 51, 49, 52, 49, 53, 49, 54, 49, 55, 49, 56, 49, 57, 50, 48, 50, 49, 50, 50, 50, 51, 50, 52, 50, 53, 50, 54, 50, 55, 50, 56, 50, 57, 51, 48, 51, 49, 51, 50, 51, 51, 51, 52, 51, 53, 51, 54, 51, 55, 51, 56, 51, 57, 52, 48, 52, 49, 52, 50, 52, 51, 52, 52, 52, 53, 52, 54, 52, 55, 52, 56, 52, 57, 53, 48, 53, 49, 53, 50, 53, 51, 53, 52, 53, 53, 53, 54, 53, 55, 53, 56, 53, 57, 54, 48, 54, 49, 54, 50, 54, 51, 54, 52, 54, 53, 54, 54, 54, 55, 54, 56, 54, 57, 55, 48, 55, 49, 55, 50, 55, 51, 55, 52, 55, 53, 55, 54, 55, 55, 55, 56, 55, 57, 56, 48, 56, 49, 56, 50, 56, 51, 56, 52, 56, 53, 56, 54, 56, 55, 56, 56, 56, 57, 57, 48, 57, 49, 57, 50, 57, 51, 57, 52, 57, 53, 57, 54, 57, 55, 57, 56, 57, 57, 115, 111, 117, 114, 99, 101, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 32, 100, 111, 101, 115, 32, 110, 111, 116, 32, 109, 97, 116, 99, 104, 32, 100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 0, 0, 0, 156, 69, 0, 7, 21, 0, 0, 0, 177, 69, 0, 7, 43, 0, 0, 0, 220, 69, 0, 7, 1, 0, 0, 0, 58, 0, 0, 0, 248, 69, 0, 7, 0, 0, 0, 0, 248, 69, 0, 7, 1, 0, 0, 0, 248, 69, 0, 7, 1, 0, 0, 0, 112, 97, 110, 105, 99, 107, 101, 100, 32, 97, 116, 32, 39, 39, 44, 32, 32, 70, 0, 7, 1, 0, 0, 0, 33, 70, 0, 7, 3, 0, 0, 0, 52, 70, 0, 7, 0, 0, 0, 0, 69, 114, 114, 111, 114, 0, 0, 0, 136, 58, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 60, 46, 0, 7, 58, 32, 0, 0, 68, 70, 0, 7, 0, 0, 0, 0, 84, 70, 0, 7, 2, 0, 0, 0, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 21, 21, 0, 0, 148, 0, 0, 7, 120, 4, 0, 0, 2, 2, 1, 0, 177, 21, 0, 0, 84, 7, 0, 7, 84, 1, 0, 0, 2, 2, 1, 0, 78, 22, 0, 0, 12, 5, 0, 7, 72, 2, 0, 0, 2, 2, 1, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 142, 23, 0, 0, 168, 8, 0, 7, 228, 0, 0, 0, 2, 2, 1, 0, 76, 25, 0, 0, 116, 61, 0, 7, 4, 0, 0, 0, 1, 2, 1, 0, 142, 25, 0, 0, 120, 61, 0, 7, 11, 0, 0, 0, 1, 2, 1, 0, 208, 25, 0, 0, 131, 61, 0, 7, 5, 0, 0, 0, 1, 2, 1, 0, 18, 26, 0, 0, 136, 61, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 217, 28, 0, 0, 152, 61, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 249, 29, 0, 0, 172, 10, 0, 7, 192, 1, 0, 0, 2, 2, 1, 0, 45, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 190, 24, 0, 0, 108, 12, 0, 7, 28, 1, 0, 0, 2, 2, 1, 0, 162, 27, 0, 0, 152, 61, 0, 7, 43, 0, 0, 0, 1, 2, 1, 0, 227, 27, 0, 0, 196, 61, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 36, 28, 0, 0, 52, 62, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 152, 28, 0, 0, 68, 62, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 79, 29, 0, 0, 140, 13, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 152, 30, 0, 0, 136, 13, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 21, 32, 0, 0, 212, 61, 0, 7, 96, 0, 0, 0, 1, 2, 1, 0, 66, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 44, 24, 0, 0, 232, 14, 0, 7, 104, 1, 0, 0, 2, 2, 1, 0, 86, 32, 0, 0, 84, 62, 0, 7, 7, 0, 0, 0, 1, 2, 1, 0, 152, 32, 0, 0, 91, 62, 0, 7, 9, 0, 0, 0, 1, 2, 1, 0, 87, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 80, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 88, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 88, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 96, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 96, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 104, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 104, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 112, 16, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 103, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 135, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 167, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 199, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 7, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 39, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 103, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 130, 2, 0, 0, 100, 62, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 139, 2, 0, 0, 132, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 147, 2, 0, 0, 239, 62, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 190, 2, 0, 0, 152, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 198, 2, 0, 0, 221, 62, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 241, 2, 0, 0, 172, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 249, 2, 0, 0, 201, 62, 0, 7, 20, 0, 0, 0, 1, 0, 1, 0, 36, 3, 0, 0, 192, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 44, 3, 0, 0, 183, 62, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 87, 3, 0, 0, 212, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 95, 3, 0, 0, 159, 62, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 138, 3, 0, 0, 232, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 146, 3, 0, 0, 147, 62, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 189, 3, 0, 0, 252, 21, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 197, 3, 0, 0, 128, 62, 0, 7, 19, 0, 0, 0, 1, 0, 1, 0, 235, 33, 0, 0, 88, 21, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 239, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 10, 4, 0, 0, 16, 22, 0, 7, 80, 0, 0, 0, 2, 0, 1, 0, 60, 4, 0, 0, 4, 63, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 102, 4, 0, 0, 96, 22, 0, 7, 236, 0, 0, 0, 2, 0, 1, 0, 168, 4, 0, 0, 4, 63, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 210, 4, 0, 0, 12, 63, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 252, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 23, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 49, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 76, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 102, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 129, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 141, 35, 0, 0, 72, 24, 0, 7, 48, 0, 0, 0, 2, 2, 1, 0, 155, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 181, 5, 0, 0, 148, 63, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 223, 5, 0, 0, 192, 63, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 9, 6, 0, 0, 132, 63, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 51, 6, 0, 0, 55, 64, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 94, 6, 0, 0, 96, 64, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 137, 6, 0, 0, 37, 63, 0, 7, 94, 0, 0, 0, 1, 0, 1, 0, 179, 6, 0, 0, 208, 63, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 224, 35, 0, 0, 120, 24, 0, 7, 56, 4, 0, 0, 2, 2, 1, 0, 222, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 248, 6, 0, 0, 0, 29, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 80, 7, 0, 0, 112, 64, 0, 7, 39, 0, 0, 0, 1, 0, 1, 0, 122, 7, 0, 0, 152, 64, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 164, 7, 0, 0, 12, 65, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 206, 7, 0, 0, 168, 64, 0, 7, 98, 0, 0, 0, 1, 0, 1, 0, 218, 32, 0, 0, 4, 29, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 229, 32, 0, 0, 212, 29, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 242, 32, 0, 0, 216, 29, 0, 7, 52, 1, 0, 0, 2, 2, 1, 0, 248, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 18, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 32, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 46, 8, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 58, 8, 0, 0, 8, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 235, 22, 0, 0, 4, 32, 0, 7, 12, 4, 0, 0, 2, 2, 1, 0, 129, 33, 0, 0, 12, 31, 0, 7, 248, 0, 0, 0, 2, 2, 1, 0, 70, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 96, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 121, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 147, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 172, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 197, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 223, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 248, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 17, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 42, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 63, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 84, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 105, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 126, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 147, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 169, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 191, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 212, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 240, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 13, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 32, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 51, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 70, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 96, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 122, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 148, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 174, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 200, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 227, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 6, 11, 0, 0, 163, 65, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 48, 11, 0, 0, 203, 65, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 91, 11, 0, 0, 248, 65, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 134, 11, 0, 0, 8, 66, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 177, 11, 0, 0, 51, 66, 0, 7, 52, 0, 0, 0, 1, 0, 1, 0, 220, 11, 0, 0, 104, 66, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 7, 12, 0, 0, 120, 66, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 50, 12, 0, 0, 0, 0, 8, 0, 4, 0, 0, 0, 1, 0, 2, 0, 127, 12, 0, 0, 164, 66, 0, 7, 14, 0, 0, 0, 1, 0, 1, 0, 170, 12, 0, 0, 180, 66, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 213, 12, 0, 0, 60, 65, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 255, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 34, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 31, 39, 0, 0, 40, 37, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 121, 39, 0, 0, 224, 38, 0, 7, 172, 0, 0, 0, 2, 2, 1, 0, 197, 39, 0, 0, 4, 38, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 18, 40, 0, 0, 196, 66, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 83, 40, 0, 0, 148, 67, 0, 7, 8, 0, 0, 0, 1, 2, 1, 0, 148, 40, 0, 0, 156, 67, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 213, 40, 0, 0, 196, 66, 0, 7, 191, 0, 0, 0, 1, 2, 1, 0, 22, 41, 0, 0, 131, 67, 0, 7, 17, 0, 0, 0, 1, 2, 1, 0, 55, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 77, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 26, 33, 0, 0, 232, 39, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 99, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 121, 13, 0, 0, 244, 39, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 193, 13, 0, 0, 172, 67, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 235, 13, 0, 0, 224, 67, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 21, 14, 0, 0, 172, 68, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 63, 14, 0, 0, 240, 67, 0, 7, 187, 0, 0, 0, 1, 0, 1, 0, 105, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 127, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 199, 41, 0, 0, 188, 68, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 130, 42, 0, 0, 100, 41, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 244, 42, 0, 0, 104, 41, 0, 7, 100, 1, 0, 0, 2, 2, 1, 0, 101, 43, 0, 0, 204, 42, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 212, 43, 0, 0, 48, 43, 0, 7, 144, 0, 0, 0, 2, 2, 1, 0, 148, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 169, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 67, 44, 0, 0, 212, 68, 0, 7, 200, 0, 0, 0, 1, 2, 1, 0, 189, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 210, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 14, 0, 0, 224, 69, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 18, 15, 0, 0, 156, 69, 0, 7, 21, 0, 0, 0, 1, 0, 1, 0, 61, 15, 0, 0, 177, 69, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 104, 15, 0, 0, 220, 69, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 147, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 168, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 222, 45, 0, 0, 248, 69, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 31, 46, 0, 0, 252, 69, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 97, 46, 0, 0, 248, 69, 0, 7, 1, 0, 0, 0, 1, 2, 1, 0, 189, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 210, 15, 0, 0, 20, 70, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 253, 15, 0, 0, 36, 70, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 40, 16, 0, 0, 32, 70, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 83, 16, 0, 0, 33, 70, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 163, 46, 0, 0, 60, 46, 0, 7, 20, 0, 0, 0, 2, 2, 1, 0, 126, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 147, 16, 0, 0, 220, 47, 0, 7, 32, 0, 0, 0, 2, 0, 1, 0, 196, 16, 0, 0, 252, 47, 0, 7, 72, 0, 0, 0, 2, 0, 1, 0, 250, 16, 0, 0, 52, 70, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 36, 17, 0, 0, 52, 70, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 78, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 98, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 118, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 138, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 158, 17, 0, 0, 236, 53, 0, 7, 156, 0, 0, 0, 2, 0, 1, 0, 229, 17, 0, 0, 60, 70, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 9, 45, 0, 0, 112, 58, 0, 7, 24, 0, 0, 0, 2, 2, 1, 0, 138, 45, 0, 0, 68, 48, 0, 7, 8, 0, 0, 0, 2, 2, 1, 0, 233, 46, 0, 0, 20, 58, 0, 7, 92, 0, 0, 0, 2, 2, 1, 0, 51, 47, 0, 0, 248, 57, 0, 7, 28, 0, 0, 0, 2, 2, 1, 0, 125, 47, 0, 0, 232, 57, 0, 7, 16, 0, 0, 0, 2, 2, 1, 0, 16, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 36, 18, 0, 0, 68, 70, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 79, 18, 0, 0, 68, 70, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 122, 18, 0, 0, 88, 70, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 165, 18, 0, 0, 104, 70, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 209, 18, 0, 0, 84, 70, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 34, 48, 0, 0, 136, 58, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 122, 48, 0, 0, 136, 58, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 212, 48, 0, 0, 136, 58, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 252, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 16, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 36, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 190, 44, 0, 0, 156, 59, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 74, 49, 0, 0, 115, 70, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 56, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 90, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 123, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 157, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 191, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 224, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 1, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 34, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 67, 20, 0, 0, 20, 0, 0, 7, 128, 0, 0, 0, 18, 0, 1, 0, 75, 20, 0, 0, 140, 9, 0, 7, 32, 1, 0, 0, 18, 0, 1, 0, 82, 20, 0, 0, 228, 36, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 98, 20, 0, 0, 48, 36, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 108, 20, 0, 0, 188, 36, 0, 7, 40, 0, 0, 0, 18, 0, 1, 0, 118, 20, 0, 0, 76, 23, 0, 7, 132, 0, 0, 0, 18, 0, 1, 0, 136, 20, 0, 0, 208, 23, 0, 7, 24, 0, 0, 0, 18, 0, 1, 0, 145, 20, 0, 0, 192, 28, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 242, 22, 0, 0, 96, 16, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 1, 23, 0, 0, 80, 16, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 14, 23, 0, 0, 140, 39, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 71, 23, 0, 0, 208, 39, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 127, 23, 0, 0, 88, 16, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 84, 26, 0, 0, 192, 43, 0, 7, 104, 0, 0, 0, 18, 0, 1, 0, 186, 26, 0, 0, 188, 57, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 239, 26, 0, 0, 184, 28, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 35, 27, 0, 0, 112, 16, 0, 7, 232, 4, 0, 0, 18, 0, 1, 0, 108, 27, 0, 0, 176, 28, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 101, 28, 0, 0, 12, 59, 0, 7, 116, 0, 0, 0, 18, 0, 1, 0, 26, 29, 0, 0, 4, 0, 8, 0, 80, 0, 0, 0, 17, 0, 3, 0, 6, 31, 0, 0, 28, 65, 0, 7, 32, 0, 0, 0, 17, 0, 1, 0, 62, 31, 0, 0, 232, 23, 0, 7, 96, 0, 0, 0, 18, 0, 1, 0, 181, 31, 0, 0, 100, 21, 0, 7, 172, 0, 0, 0, 18, 0, 1, 0, 255, 32, 0, 0, 104, 16, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 35, 33, 0, 0, 200, 58, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 80, 33, 0, 0, 140, 58, 0, 7, 60, 0, 0, 0, 18, 0, 1, 0, 136, 33, 0, 0, 96, 61, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 53, 34, 0, 0, 80, 46, 0, 7, 128, 1, 0, 0, 18, 0, 1, 0, 156, 34, 0, 0, 248, 39, 0, 7, 108, 1, 0, 0, 18, 0, 1, 0, 211, 34, 0, 0, 16, 36, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 3, 35, 0, 0, 36, 36, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 23, 36, 0, 0, 28, 36, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 88, 36, 0, 0, 40, 36, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 150, 36, 0, 0, 112, 36, 0, 7, 76, 0, 0, 0, 18, 0, 1, 0, 219, 36, 0, 0, 128, 59, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 62, 37, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 1, 0, 69, 37, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 16, 0, 2, 0, 87, 37, 0, 0, 252, 255, 127, 0, 0, 0, 0, 0, 16, 0, 241, 255, 101, 37, 0, 0, 4, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 113, 37, 0, 0, 84, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 123, 37, 0, 0, 40, 36, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 248, 37, 0, 0, 36, 36, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 135, 38, 0, 0, 96, 61, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 236, 38, 0, 0, 208, 47, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 87, 41, 0, 0, 220, 39, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 152, 41, 0, 0, 136, 54, 0, 7, 52, 3, 0, 0, 18, 0, 1, 0, 8, 42, 0, 0, 76, 48, 0, 7, 100, 2, 0, 0, 18, 0, 1, 0, 47, 42, 0, 0, 204, 57, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 133, 44, 0, 0, 176, 50, 0, 7, 60, 3, 0, 0, 18, 0, 1, 0, 83, 45, 0, 0, 40, 44, 0, 7, 20, 2, 0, 0, 18, 0, 1, 0, 197, 47, 0, 0, 188, 57, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 140, 49, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 16, 0, 241, 255, 153, 49, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 16, 0, 241, 255, 164, 49, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 241, 255, 175, 49, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 16, 0, 241, 255, 0, 46, 116, 101, 120, 116, 0, 46, 100, 97, 116, 97, 0, 46, 98, 115, 115, 0, 46, 115, 121, 109, 116, 97, 98, 0, 46, 115, 104, 115, 116, 114, 116, 97, 98, 0, 46, 115, 116, 114, 116, 97, 98, 0, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 48, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 48, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 49, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 50, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 51, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 52, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 49, 53, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 50, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 51, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 52, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 53, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 54, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 55, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 56, 0, 115, 99, 111, 117, 116, 46, 97, 54, 102, 100, 48, 51, 53, 52, 45, 99, 103, 117, 46, 57, 0, 112, 102, 99, 109, 57, 110, 109, 118, 112, 114, 50, 103, 104, 102, 54, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 48, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 49, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 50, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 51, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 53, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 55, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 51, 0, 46, 76, 74, 84, 73, 54, 95, 48, 0, 46, 76, 66, 66, 54, 95, 49, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 53, 0, 46, 76, 66, 66, 54, 95, 50, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 52, 0, 46, 76, 66, 66, 54, 95, 51, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 51, 0, 46, 76, 66, 66, 54, 95, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 50, 0, 46, 76, 66, 66, 54, 95, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 49, 0, 46, 76, 66, 66, 54, 95, 54, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 48, 0, 46, 76, 66, 66, 54, 95, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 65, 114, 103, 117, 109, 101, 110, 116, 115, 54, 97, 115, 95, 115, 116, 114, 49, 55, 104, 57, 48, 52, 56, 53, 49, 98, 51, 54, 101, 48, 101, 53, 51, 50, 102, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 53, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 84, 36, 71, 84, 36, 49, 49, 109, 97, 112, 95, 111, 114, 95, 101, 108, 115, 101, 49, 55, 104, 98, 48, 56, 50, 51, 53, 48, 48, 57, 56, 101, 100, 52, 57, 56, 52, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 51, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 48, 48, 55, 100, 48, 102, 101, 49, 54, 97, 100, 51, 55, 97, 50, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 56, 0, 109, 117, 115, 108, 95, 109, 101, 109, 115, 101, 116, 46, 99, 0, 109, 117, 115, 108, 95, 109, 101, 109, 99, 112, 121, 46, 99, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 48, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 52, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 48, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 49, 53, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 48, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 49, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 54, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 54, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 56, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 50, 48, 72, 69, 65, 80, 95, 87, 79, 82, 68, 83, 95, 82, 69, 77, 65, 73, 78, 73, 78, 71, 49, 55, 104, 53, 97, 101, 56, 98, 54, 55, 48, 102, 98, 54, 52, 50, 100, 55, 50, 69, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 55, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 102, 53, 51, 97, 51, 51, 52, 49, 54, 100, 57, 102, 57, 49, 53, 49, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 54, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 49, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 52, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 50, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 115, 116, 114, 49, 55, 104, 56, 102, 56, 55, 54, 57, 49, 102, 48, 101, 102, 98, 98, 51, 56, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 112, 97, 110, 105, 99, 95, 100, 105, 115, 112, 108, 97, 121, 49, 55, 104, 98, 55, 101, 99, 99, 49, 53, 99, 56, 57, 48, 97, 55, 99, 98, 54, 69, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 52, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 53, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 54, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 50, 119, 114, 105, 116, 101, 95, 112, 114, 101, 102, 105, 120, 49, 55, 104, 55, 57, 52, 99, 51, 54, 55, 50, 50, 55, 97, 56, 51, 100, 102, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 50, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 50, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 49, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 56, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 57, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 53, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 51, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 52, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 53, 0, 95, 95, 115, 116, 97, 114, 116, 0, 95, 95, 109, 97, 105, 110, 0, 115, 121, 115, 95, 97, 108, 108, 111, 99, 95, 119, 111, 114, 100, 115, 0, 115, 121, 115, 95, 112, 97, 110, 105, 99, 0, 115, 121, 115, 95, 119, 114, 105, 116, 101, 0, 114, 117, 115, 116, 95, 98, 101, 103, 105, 110, 95, 117, 110, 119, 105, 110, 100, 0, 114, 117, 115, 116, 95, 111, 111, 109, 0, 95, 90, 78, 57, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 101, 110, 118, 46, 46, 70, 100, 82, 101, 97, 100, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 82, 101, 97, 100, 36, 71, 84, 36, 49, 48, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 100, 57, 53, 99, 57, 99, 101, 101, 102, 98, 52, 98, 102, 50, 101, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 55, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 116, 117, 112, 108, 101, 49, 55, 104, 99, 102, 48, 55, 57, 100, 51, 52, 101, 54, 100, 52, 102, 56, 49, 54, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 102, 100, 56, 97, 49, 48, 56, 57, 53, 48, 56, 53, 100, 97, 50, 49, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 53, 56, 51, 102, 57, 56, 100, 49, 52, 51, 55, 50, 102, 100, 97, 98, 69, 0, 109, 101, 109, 99, 112, 121, 0, 95, 95, 114, 117, 115, 116, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 55, 99, 97, 112, 97, 99, 105, 116, 121, 95, 111, 118, 101, 114, 102, 108, 111, 119, 49, 55, 104, 51, 97, 54, 100, 97, 51, 54, 102, 48, 52, 56, 102, 97, 55, 49, 57, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 49, 55, 104, 97, 52, 102, 49, 48, 54, 99, 53, 53, 53, 50, 49, 57, 48, 52, 52, 69, 0, 95, 95, 114, 117, 115, 116, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 95, 57, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 46, 83, 99, 111, 117, 116, 82, 101, 115, 117, 108, 116, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 54, 52, 100, 100, 48, 57, 101, 97, 97, 49, 53, 54, 97, 101, 53, 101, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 49, 95, 56, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 116, 121, 112, 101, 115, 46, 46, 72, 105, 116, 84, 121, 112, 101, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 52, 57, 49, 53, 57, 54, 97, 57, 49, 49, 48, 51, 50, 56, 53, 102, 69, 0, 95, 90, 78, 49, 48, 50, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 101, 110, 118, 46, 46, 70, 100, 87, 114, 105, 116, 101, 114, 36, 76, 84, 36, 70, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 49, 119, 114, 105, 116, 101, 95, 119, 111, 114, 100, 115, 49, 55, 104, 102, 97, 51, 101, 52, 54, 99, 97, 52, 53, 51, 102, 100, 100, 56, 97, 69, 0, 97, 110, 111, 110, 46, 100, 53, 102, 97, 102, 49, 52, 57, 100, 98, 55, 48, 102, 50, 101, 99, 57, 101, 100, 57, 55, 55, 53, 97, 100, 50, 52, 53, 99, 101, 49, 98, 46, 50, 46, 108, 108, 118, 109, 46, 49, 50, 51, 52, 54, 50, 48, 52, 48, 57, 54, 54, 57, 49, 56, 51, 50, 56, 52, 55, 0, 97, 110, 111, 110, 46, 100, 53, 102, 97, 102, 49, 52, 57, 100, 98, 55, 48, 102, 50, 101, 99, 57, 101, 100, 57, 55, 55, 53, 97, 100, 50, 52, 53, 99, 101, 49, 98, 46, 51, 46, 108, 108, 118, 109, 46, 49, 50, 51, 52, 54, 50, 48, 52, 48, 57, 54, 54, 57, 49, 56, 51, 50, 56, 52, 55, 0, 97, 110, 111, 110, 46, 100, 53, 102, 97, 102, 49, 52, 57, 100, 98, 55, 48, 102, 50, 101, 99, 57, 101, 100, 57, 55, 55, 53, 97, 100, 50, 52, 53, 99, 101, 49, 98, 46, 52, 46, 108, 108, 118, 109, 46, 49, 50, 51, 52, 54, 50, 48, 52, 48, 57, 54, 54, 57, 49, 56, 51, 50, 56, 52, 55, 0, 97, 110, 111, 110, 46, 100, 53, 102, 97, 102, 49, 52, 57, 100, 98, 55, 48, 102, 50, 101, 99, 57, 101, 100, 57, 55, 55, 53, 97, 100, 50, 52, 53, 99, 101, 49, 98, 46, 53, 46, 108, 108, 118, 109, 46, 49, 50, 51, 52, 54, 50, 48, 52, 48, 57, 54, 54, 57, 49, 56, 51, 50, 56, 52, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 108, 101, 110, 95, 109, 105, 115, 109, 97, 116, 99, 104, 95, 102, 97, 105, 108, 49, 55, 104, 56, 53, 97, 99, 100, 55, 48, 52, 54, 48, 98, 101, 49, 97, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 50, 55, 99, 57, 48, 49, 55, 52, 48, 98, 101, 97, 48, 99, 55, 54, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 53, 115, 116, 100, 105, 110, 49, 55, 104, 55, 97, 49, 48, 101, 48, 49, 101, 48, 56, 102, 98, 102, 97, 98, 48, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 49, 83, 99, 111, 117, 116, 80, 97, 114, 97, 109, 115, 55, 112, 114, 111, 99, 101, 115, 115, 49, 55, 104, 56, 99, 57, 57, 54, 55, 99, 55, 51, 55, 98, 57, 53, 51, 50, 99, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 55, 106, 111, 117, 114, 110, 97, 108, 49, 55, 104, 99, 97, 56, 56, 49, 100, 99, 98, 55, 55, 97, 53, 99, 48, 57, 102, 69, 0, 97, 110, 111, 110, 46, 51, 53, 52, 49, 54, 52, 102, 98, 48, 51, 54, 57, 53, 56, 51, 57, 97, 48, 57, 98, 56, 100, 102, 102, 101, 54, 52, 50, 97, 49, 51, 52, 46, 52, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 97, 110, 111, 110, 46, 51, 53, 52, 49, 54, 52, 102, 98, 48, 51, 54, 57, 53, 56, 51, 57, 97, 48, 57, 98, 56, 100, 102, 102, 101, 54, 52, 50, 97, 49, 51, 52, 46, 53, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 97, 110, 111, 110, 46, 51, 53, 52, 49, 54, 52, 102, 98, 48, 51, 54, 57, 53, 56, 51, 57, 97, 48, 57, 98, 56, 100, 102, 102, 101, 54, 52, 50, 97, 49, 51, 52, 46, 55, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 114, 101, 115, 117, 108, 116, 49, 51, 117, 110, 119, 114, 97, 112, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 100, 100, 48, 102, 48, 100, 50, 48, 49, 50, 102, 98, 49, 48, 50, 50, 69, 0, 97, 110, 111, 110, 46, 51, 53, 52, 49, 54, 52, 102, 98, 48, 51, 54, 57, 53, 56, 51, 57, 97, 48, 57, 98, 56, 100, 102, 102, 101, 54, 52, 50, 97, 49, 51, 52, 46, 56, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 97, 110, 111, 110, 46, 102, 101, 102, 99, 55, 48, 51, 55, 55, 49, 49, 56, 102, 100, 52, 98, 52, 98, 100, 101, 50, 100, 55, 51, 57, 56, 49, 49, 49, 57, 54, 100, 46, 48, 46, 108, 108, 118, 109, 46, 55, 50, 55, 55, 55, 48, 49, 56, 51, 50, 51, 49, 48, 56, 49, 52, 56, 53, 54, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 54, 72, 65, 83, 72, 69, 82, 49, 55, 104, 99, 102, 102, 99, 102, 101, 49, 57, 48, 51, 102, 49, 98, 53, 50, 97, 69, 0, 95, 90, 78, 56, 50, 95, 36, 76, 84, 36, 100, 105, 103, 101, 115, 116, 46, 46, 99, 111, 114, 101, 95, 97, 112, 105, 46, 46, 119, 114, 97, 112, 112, 101, 114, 46, 46, 67, 111, 114, 101, 87, 114, 97, 112, 112, 101, 114, 36, 76, 84, 36, 84, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 100, 105, 103, 101, 115, 116, 46, 46, 85, 112, 100, 97, 116, 101, 36, 71, 84, 36, 54, 117, 112, 100, 97, 116, 101, 50, 56, 95, 36, 117, 55, 98, 36, 36, 117, 55, 98, 36, 99, 108, 111, 115, 117, 114, 101, 36, 117, 55, 100, 36, 36, 117, 55, 100, 36, 49, 55, 104, 50, 97, 57, 97, 54, 51, 53, 97, 51, 56, 52, 52, 99, 100, 57, 97, 69, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 95, 90, 78, 49, 48, 53, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 87, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 50, 53, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 110, 101, 119, 116, 121, 112, 101, 95, 118, 97, 114, 105, 97, 110, 116, 49, 55, 104, 102, 53, 52, 97, 101, 55, 52, 52, 102, 50, 98, 49, 98, 48, 101, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 102, 50, 52, 100, 53, 50, 99, 55, 101, 53, 49, 55, 102, 56, 50, 51, 69, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 49, 49, 83, 72, 65, 50, 53, 54, 95, 73, 78, 73, 84, 49, 55, 104, 99, 101, 102, 102, 54, 52, 52, 49, 100, 52, 48, 52, 51, 54, 101, 55, 69, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 52, 99, 111, 109, 112, 114, 101, 115, 115, 95, 115, 108, 105, 99, 101, 49, 55, 104, 101, 97, 53, 50, 55, 55, 52, 48, 97, 54, 54, 57, 97, 56, 57, 99, 69, 0, 95, 90, 78, 54, 54, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 51, 54, 56, 54, 52, 99, 100, 102, 102, 98, 48, 57, 49, 57, 100, 102, 69, 0, 97, 110, 111, 110, 46, 51, 53, 52, 49, 54, 52, 102, 98, 48, 51, 54, 57, 53, 56, 51, 57, 97, 48, 57, 98, 56, 100, 102, 102, 101, 54, 52, 50, 97, 49, 51, 52, 46, 54, 46, 108, 108, 118, 109, 46, 51, 57, 50, 57, 48, 49, 49, 49, 48, 51, 55, 55, 48, 55, 57, 54, 48, 57, 57, 0, 97, 110, 111, 110, 46, 98, 97, 100, 56, 51, 52, 98, 48, 49, 57, 50, 52, 98, 98, 51, 101, 51, 53, 49, 53, 100, 100, 97, 53, 57, 101, 52, 54, 49, 57, 102, 57, 46, 48, 46, 108, 108, 118, 109, 46, 49, 48, 56, 57, 50, 51, 48, 57, 53, 54, 56, 50, 53, 50, 48, 53, 54, 56, 56, 48, 0, 97, 110, 111, 110, 46, 98, 97, 100, 56, 51, 52, 98, 48, 49, 57, 50, 52, 98, 98, 51, 101, 51, 53, 49, 53, 100, 100, 97, 53, 57, 101, 52, 54, 49, 57, 102, 57, 46, 49, 46, 108, 108, 118, 109, 46, 49, 48, 56, 57, 50, 51, 48, 57, 53, 54, 56, 50, 53, 50, 48, 53, 54, 56, 56, 48, 0, 95, 95, 114, 103, 95, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 95, 104, 97, 110, 100, 108, 101, 114, 0, 95, 95, 114, 103, 95, 111, 111, 109, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 53, 112, 97, 110, 105, 99, 49, 55, 104, 98, 53, 56, 56, 101, 101, 51, 56, 100, 101, 101, 56, 99, 101, 49, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 102, 109, 116, 49, 55, 104, 98, 48, 52, 49, 102, 98, 52, 56, 101, 100, 102, 57, 98, 99, 57, 50, 69, 0, 109, 101, 109, 115, 101, 116, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 55, 55, 51, 101, 100, 54, 52, 97, 57, 99, 53, 55, 55, 55, 100, 101, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 53, 102, 55, 53, 51, 56, 49, 51, 48, 49, 100, 48, 56, 57, 54, 69, 0, 95, 90, 78, 55, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 112, 97, 110, 105, 99, 46, 46, 112, 97, 110, 105, 99, 95, 105, 110, 102, 111, 46, 46, 80, 97, 110, 105, 99, 73, 110, 102, 111, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 55, 48, 101, 100, 53, 55, 49, 51, 52, 101, 101, 99, 100, 98, 54, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 51, 102, 109, 116, 54, 102, 111, 114, 109, 97, 116, 49, 50, 102, 111, 114, 109, 97, 116, 95, 105, 110, 110, 101, 114, 49, 55, 104, 101, 57, 53, 100, 99, 50, 52, 102, 97, 101, 100, 55, 54, 50, 99, 51, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 53, 97, 98, 111, 114, 116, 49, 55, 104, 102, 54, 51, 57, 57, 56, 54, 57, 51, 55, 48, 98, 50, 102, 98, 49, 69, 0, 95, 90, 78, 49, 48, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 48, 55, 51, 54, 98, 52, 102, 53, 99, 51, 102, 49, 100, 101, 48, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 104, 50, 52, 57, 99, 99, 102, 53, 97, 101, 101, 101, 51, 51, 53, 51, 57, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 56, 102, 105, 110, 97, 108, 105, 122, 101, 49, 55, 104, 55, 49, 56, 100, 99, 99, 53, 101, 98, 101, 54, 54, 99, 100, 54, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 53, 66, 108, 111, 99, 107, 49, 52, 97, 115, 95, 104, 97, 108, 102, 95, 98, 108, 111, 99, 107, 115, 49, 55, 104, 50, 49, 51, 97, 53, 101, 53, 98, 55, 48, 97, 55, 99, 50, 51, 57, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 98, 121, 116, 101, 115, 49, 55, 104, 52, 54, 99, 57, 99, 98, 51, 56, 99, 49, 57, 99, 101, 102, 52, 51, 69, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 49, 52, 115, 121, 115, 95, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 49, 55, 102, 102, 56, 98, 97, 100, 99, 52, 56, 102, 102, 55, 53, 102, 69, 0, 95, 90, 78, 54, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 54, 101, 57, 99, 56, 53, 49, 57, 51, 98, 52, 102, 102, 102, 97, 52, 69, 0, 95, 115, 116, 97, 114, 116, 0, 95, 95, 103, 108, 111, 98, 97, 108, 95, 112, 111, 105, 110, 116, 101, 114, 36, 0, 95, 95, 115, 116, 97, 99, 107, 95, 105, 110, 105, 116, 36, 0, 95, 95, 98, 115, 115, 95, 98, 101, 103, 105, 110, 0, 95, 95, 98, 115, 115, 95, 101, 110, 100, 0, 95, 90, 78, 57, 50, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 56, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 97, 101, 97, 52, 99, 54, 50, 97, 56, 102, 48, 101, 98, 101, 48, 51, 69, 0, 95, 90, 78, 49, 49, 49, 95, 36, 76, 84, 36, 36, 82, 70, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 70, 114, 111, 109, 36, 76, 84, 36, 36, 82, 70, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 52, 102, 114, 111, 109, 49, 55, 104, 50, 102, 54, 54, 100, 100, 54, 51, 51, 102, 57, 57, 102, 52, 53, 56, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 49, 54, 100, 101, 57, 48, 55, 50, 48, 54, 53, 49, 99, 101, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 51, 101, 120, 112, 101, 99, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 98, 97, 50, 55, 98, 53, 97, 97, 97, 56, 54, 54, 53, 53, 52, 101, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 52, 98, 57, 101, 97, 101, 98, 50, 97, 55, 55, 100, 56, 99, 54, 102, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 52, 57, 97, 52, 56, 100, 48, 55, 97, 55, 101, 48, 53, 48, 101, 102, 69, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 97, 99, 101, 98, 50, 53, 57, 51, 55, 48, 52, 98, 48, 101, 52, 98, 69, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 48, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 54, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 55, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 51, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 53, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 56, 114, 116, 95, 101, 114, 114, 111, 114, 49, 55, 104, 48, 48, 101, 99, 48, 48, 50, 56, 53, 48, 56, 49, 48, 97, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 51, 112, 97, 100, 49, 55, 104, 50, 53, 101, 50, 98, 101, 49, 57, 49, 53, 56, 57, 99, 99, 54, 101, 69, 0, 97, 110, 111, 110, 46, 98, 53, 102, 54, 55, 54, 57, 56, 98, 97, 57, 50, 52, 49, 102, 49, 54, 49, 53, 54, 48, 55, 56, 52, 50, 50, 57, 50, 101, 50, 56, 50, 46, 48, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 119, 114, 105, 116, 101, 49, 55, 104, 51, 49, 102, 98, 50, 57, 102, 50, 98, 100, 55, 57, 101, 102, 56, 101, 69, 0, 95, 90, 78, 53, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 100, 55, 50, 48, 55, 55, 49, 101, 53, 54, 55, 49, 102, 102, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 97, 108, 108, 111, 99, 46, 46, 115, 116, 114, 105, 110, 103, 46, 46, 83, 116, 114, 105, 110, 103, 36, 71, 84, 36, 49, 55, 104, 57, 54, 102, 100, 56, 52, 56, 51, 48, 54, 56, 48, 101, 53, 56, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 98, 97, 51, 53, 52, 100, 49, 49, 100, 98, 100, 54, 49, 50, 48, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 53, 53, 57, 55, 97, 55, 51, 48, 98, 101, 50, 97, 101, 98, 51, 55, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 48, 54, 97, 98, 51, 97, 57, 100, 102, 50, 98, 101, 50, 99, 102, 99, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 53, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 55, 104, 48, 49, 99, 51, 54, 52, 50, 56, 49, 97, 101, 56, 102, 50, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 55, 102, 109, 116, 95, 117, 51, 50, 49, 55, 104, 98, 102, 50, 55, 97, 100, 102, 99, 49, 51, 97, 100, 98, 102, 57, 50, 69, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 102, 55, 54, 51, 101, 49, 54, 100, 101, 102, 51, 98, 53, 56, 52, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 53, 99, 111, 117, 110, 116, 49, 52, 100, 111, 95, 99, 111, 117, 110, 116, 95, 99, 104, 97, 114, 115, 49, 55, 104, 49, 102, 52, 100, 55, 57, 55, 57, 99, 54, 52, 55, 54, 98, 52, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 111, 112, 115, 56, 102, 117, 110, 99, 116, 105, 111, 110, 54, 70, 110, 79, 110, 99, 101, 57, 99, 97, 108, 108, 95, 111, 110, 99, 101, 49, 55, 104, 98, 100, 57, 99, 102, 49, 102, 56, 97, 49, 50, 57, 49, 56, 99, 51, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 49, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 51, 54, 95, 36, 76, 84, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 97, 110, 121, 46, 46, 65, 110, 121, 36, 71, 84, 36, 55, 116, 121, 112, 101, 95, 105, 100, 49, 55, 104, 53, 51, 49, 49, 53, 56, 48, 97, 57, 51, 57, 102, 56, 99, 54, 97, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 97, 52, 100, 52, 55, 55, 48, 102, 49, 55, 53, 98, 100, 55, 57, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 49, 97, 97, 54, 57, 54, 98, 99, 102, 102, 55, 98, 57, 57, 55, 69, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 52, 55, 102, 57, 100, 54, 52, 54, 51, 99, 57, 54, 98, 49, 51, 69, 0, 95, 90, 78, 53, 55, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 70, 111, 114, 109, 97, 116, 116, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 54, 98, 55, 99, 99, 51, 57, 100, 101, 101, 98, 51, 97, 97, 100, 100, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 54, 52, 36, 71, 84, 36, 49, 55, 104, 49, 57, 51, 53, 51, 49, 100, 53, 48, 52, 56, 101, 99, 100, 98, 55, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 49, 55, 104, 102, 49, 50, 49, 55, 48, 97, 52, 55, 101, 53, 102, 57, 49, 57, 97, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 99, 111, 114, 101, 46, 46, 111, 112, 116, 105, 111, 110, 46, 46, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 36, 71, 84, 36, 49, 55, 104, 49, 55, 56, 97, 100, 100, 56, 54, 102, 98, 52, 51, 51, 50, 57, 54, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 97, 110, 111, 110, 46, 101, 51, 101, 53, 98, 100, 99, 51, 49, 55, 99, 50, 98, 51, 102, 51, 98, 51, 102, 98, 102, 52, 51, 55, 97, 51, 52, 57, 53, 100, 53, 100, 46, 48, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 95, 104, 101, 97, 112, 95, 115, 116, 97, 114, 116, 0, 95, 95, 98, 115, 115, 95, 115, 105, 122, 101, 0, 95, 95, 104, 101, 97, 112, 95, 101, 110, 100, 0, 95, 95, 104, 101, 97, 112, 95, 115, 105, 122, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 7, 0, 16, 0, 0, 115, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 8, 0, 0, 96, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 8, 0, 0, 0, 3, 0, 0, 0, 4, 0, 8, 0, 4, 96, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 96, 0, 0, 16, 18, 0, 0, 6, 0, 0, 0, 234, 0, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 26, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 114, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 114, 0, 0, 187, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
-pub const SCOUT_ID: [u32; 8] = [2514848793, 3872234834, 3003035733, 2470932253, 733260, 1404485211, 3713457453, 2659228676];
-pub const SCOUT_PATH: &'static str = r#"/home/chicken/ctf/fairgame/target/release/build/sagittarius-methods-25d5a44c9c25e36a/out/riscv-guest/riscv32im-risc0-zkvm-elf/release/scout"#;
-            
-pub const CLUSTER_ELF: &'static [u8] = &[127, 69, 76, 70, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 243, 0, 1, 0, 0, 0, 0, 0, 0, 7, 52, 0, 0, 0, 56, 69, 1, 0, 0, 0, 0, 0, 52, 0, 32, 0, 3, 0, 40, 0, 7, 0, 5, 0, 1, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 7, 96, 164, 0, 0, 96, 164, 0, 0, 5, 0, 0, 0, 0, 16, 0, 0, 1, 0, 0, 0, 0, 192, 0, 0, 0, 0, 8, 0, 0, 0, 8, 0, 4, 0, 0, 0, 84, 0, 0, 0, 6, 0, 0, 0, 0, 16, 0, 0, 81, 229, 116, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 17, 8, 249, 147, 129, 1, 128, 23, 1, 128, 249, 19, 1, 65, 255, 239, 0, 64, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 55, 5, 8, 0, 3, 37, 69, 0, 183, 5, 8, 0, 3, 166, 69, 5, 99, 122, 197, 0, 35, 32, 5, 0, 3, 166, 69, 5, 19, 5, 69, 0, 227, 106, 197, 254, 55, 4, 8, 0, 147, 4, 68, 0, 19, 133, 196, 0, 19, 6, 16, 4, 147, 5, 0, 0, 151, 64, 0, 0, 231, 128, 192, 199, 19, 5, 16, 0, 35, 34, 164, 0, 35, 162, 4, 0, 35, 164, 4, 0, 151, 16, 0, 0, 231, 128, 0, 49, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 51, 0, 0, 103, 0, 67, 74, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 99, 0, 6, 4, 99, 192, 4, 4, 19, 9, 6, 0, 3, 165, 134, 0, 99, 0, 5, 4, 131, 165, 70, 0, 99, 140, 5, 2, 3, 165, 6, 0, 19, 6, 9, 0, 147, 134, 4, 0, 151, 32, 0, 0, 231, 128, 64, 42, 99, 28, 5, 2, 35, 34, 148, 0, 35, 36, 36, 1, 111, 0, 192, 0, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 128, 2, 99, 128, 4, 4, 19, 133, 4, 0, 147, 5, 9, 0, 151, 32, 0, 0, 231, 128, 0, 38, 227, 8, 5, 252, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 5, 9, 0, 227, 26, 5, 252, 111, 240, 223, 249, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 132, 5, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 228, 149, 0, 147, 132, 5, 0, 147, 5, 64, 0, 99, 228, 149, 0, 147, 4, 64, 0, 147, 213, 196, 1, 19, 182, 21, 0, 147, 149, 52, 0, 19, 22, 38, 0, 99, 0, 5, 2, 131, 38, 4, 0, 19, 21, 53, 0, 35, 44, 209, 0, 35, 46, 161, 0, 19, 5, 64, 0, 35, 32, 161, 2, 111, 0, 128, 0, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 151, 0, 0, 0, 231, 128, 64, 237, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 64, 0, 0, 231, 128, 0, 114, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 64, 0, 0, 231, 128, 192, 115, 115, 16, 0, 192, 19, 1, 1, 244, 35, 46, 17, 10, 35, 44, 129, 10, 35, 42, 145, 10, 35, 40, 33, 11, 35, 38, 49, 11, 35, 36, 65, 11, 35, 34, 81, 11, 35, 32, 97, 11, 35, 46, 113, 9, 35, 44, 129, 9, 35, 42, 145, 9, 35, 40, 161, 9, 35, 38, 177, 9, 19, 4, 5, 0, 99, 6, 6, 22, 19, 9, 6, 0, 147, 132, 5, 0, 19, 5, 1, 8, 147, 7, 48, 0, 151, 0, 0, 0, 231, 128, 128, 111, 131, 73, 129, 8, 3, 75, 1, 8, 19, 5, 32, 0, 99, 150, 169, 0, 35, 0, 100, 1, 111, 0, 128, 20, 131, 76, 33, 8, 3, 77, 17, 8, 131, 77, 65, 8, 131, 74, 49, 8, 3, 76, 97, 8, 3, 5, 177, 8, 3, 74, 81, 8, 131, 5, 161, 8, 3, 70, 145, 8, 35, 5, 161, 6, 131, 11, 113, 8, 19, 149, 133, 0, 51, 101, 197, 0, 147, 5, 16, 0, 35, 20, 161, 6, 99, 14, 185, 14, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 104, 3, 71, 129, 8, 131, 71, 1, 8, 19, 5, 32, 0, 99, 22, 167, 0, 35, 0, 244, 0, 111, 0, 192, 13, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 3, 72, 97, 8, 131, 72, 81, 8, 131, 5, 177, 8, 3, 6, 161, 8, 131, 70, 145, 8, 131, 14, 113, 8, 35, 9, 177, 6, 147, 21, 134, 0, 179, 229, 213, 0, 35, 24, 177, 6, 99, 10, 169, 8, 35, 34, 209, 3, 35, 36, 193, 3, 35, 38, 113, 2, 35, 40, 97, 2, 35, 42, 81, 2, 35, 44, 17, 3, 35, 46, 1, 3, 35, 32, 241, 4, 35, 34, 225, 4, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 96, 131, 70, 129, 8, 3, 71, 1, 8, 19, 5, 32, 0, 99, 150, 166, 0, 35, 0, 228, 0, 111, 0, 0, 5, 131, 72, 33, 8, 131, 66, 17, 8, 3, 67, 65, 8, 131, 67, 49, 8, 131, 71, 97, 8, 3, 72, 81, 8, 3, 5, 177, 8, 131, 5, 161, 8, 3, 70, 145, 8, 3, 14, 113, 8, 35, 13, 161, 6, 19, 149, 133, 0, 51, 101, 197, 0, 35, 28, 161, 6, 19, 5, 48, 0, 99, 24, 169, 4, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 11, 3, 36, 129, 11, 131, 36, 65, 11, 3, 41, 1, 11, 131, 41, 193, 10, 3, 42, 129, 10, 131, 42, 65, 10, 3, 43, 1, 10, 131, 43, 193, 9, 3, 44, 129, 9, 131, 44, 65, 9, 3, 45, 1, 9, 131, 45, 193, 8, 19, 1, 1, 12, 103, 128, 0, 0, 35, 34, 193, 1, 35, 36, 113, 0, 35, 38, 97, 0, 35, 40, 81, 0, 19, 137, 8, 0, 35, 44, 1, 1, 35, 42, 241, 0, 35, 46, 225, 0, 35, 32, 209, 2, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 82, 3, 69, 129, 8, 131, 69, 1, 8, 19, 6, 32, 0, 99, 24, 197, 0, 35, 0, 180, 0, 35, 4, 196, 0, 111, 240, 95, 247, 19, 150, 140, 0, 51, 102, 166, 1, 147, 150, 141, 0, 179, 230, 86, 1, 147, 150, 6, 1, 179, 230, 198, 0, 19, 22, 140, 0, 51, 102, 70, 1, 19, 151, 11, 1, 51, 102, 230, 0, 19, 215, 134, 1, 19, 22, 134, 0, 51, 102, 230, 0, 147, 150, 134, 0, 179, 230, 102, 1, 3, 39, 65, 3, 19, 23, 135, 0, 131, 39, 1, 3, 51, 103, 247, 0, 131, 39, 193, 2, 147, 151, 135, 0, 3, 40, 129, 2, 179, 231, 7, 1, 147, 151, 7, 1, 179, 231, 231, 0, 3, 39, 193, 3, 19, 23, 135, 0, 3, 40, 129, 3, 51, 103, 7, 1, 3, 40, 65, 2, 19, 24, 8, 1, 51, 103, 7, 1, 19, 216, 135, 1, 19, 23, 135, 0, 51, 103, 7, 1, 147, 151, 135, 0, 3, 40, 1, 4, 179, 231, 7, 1, 19, 24, 137, 0, 131, 40, 1, 1, 51, 104, 24, 1, 131, 40, 193, 0, 147, 152, 136, 0, 131, 34, 129, 0, 179, 232, 88, 0, 147, 152, 8, 1, 179, 232, 8, 1, 3, 40, 65, 1, 19, 24, 136, 0, 131, 34, 129, 1, 51, 104, 88, 0, 131, 34, 65, 0, 147, 146, 2, 1, 51, 104, 88, 0, 147, 210, 136, 1, 19, 24, 136, 0, 51, 104, 88, 0, 147, 152, 136, 0, 131, 34, 193, 1, 179, 232, 88, 0, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 147, 146, 130, 0, 179, 226, 98, 0, 19, 147, 131, 0, 51, 99, 195, 1, 19, 19, 3, 1, 131, 67, 97, 8, 3, 78, 81, 8, 131, 14, 113, 8, 179, 98, 83, 0, 19, 147, 131, 0, 51, 99, 195, 1, 147, 147, 14, 1, 51, 99, 115, 0, 147, 211, 130, 1, 19, 19, 131, 0, 51, 99, 115, 0, 131, 3, 177, 8, 3, 14, 161, 8, 131, 78, 145, 8, 147, 146, 130, 0, 35, 5, 113, 4, 147, 19, 142, 0, 179, 227, 211, 1, 3, 30, 129, 6, 131, 14, 161, 6, 35, 20, 113, 4, 179, 229, 178, 0, 35, 16, 193, 7, 35, 1, 209, 7, 131, 18, 1, 7, 131, 3, 33, 7, 3, 30, 129, 7, 131, 14, 161, 7, 35, 28, 81, 4, 35, 13, 113, 4, 35, 24, 193, 5, 35, 9, 209, 5, 35, 34, 196, 0, 35, 32, 212, 0, 3, 6, 1, 6, 131, 6, 17, 6, 131, 2, 33, 6, 35, 4, 52, 1, 163, 4, 196, 0, 35, 5, 212, 0, 163, 5, 84, 0, 35, 40, 228, 0, 35, 38, 244, 0, 3, 6, 129, 5, 131, 6, 145, 5, 3, 7, 161, 5, 131, 39, 65, 4, 35, 10, 244, 0, 163, 10, 196, 0, 35, 11, 212, 0, 163, 11, 228, 0, 35, 46, 4, 1, 35, 44, 20, 1, 3, 6, 1, 5, 131, 6, 17, 5, 3, 7, 33, 5, 131, 39, 1, 2, 35, 0, 244, 2, 163, 0, 196, 2, 35, 1, 212, 2, 163, 1, 228, 2, 35, 36, 100, 2, 35, 34, 180, 2, 131, 5, 129, 4, 3, 6, 145, 4, 131, 6, 161, 4, 35, 6, 164, 2, 163, 6, 180, 2, 35, 7, 196, 2, 163, 7, 212, 2, 111, 240, 95, 212, 19, 1, 1, 243, 35, 38, 17, 12, 35, 36, 129, 12, 35, 34, 145, 12, 35, 32, 33, 13, 35, 46, 49, 11, 35, 44, 65, 11, 35, 42, 81, 11, 35, 40, 97, 11, 35, 38, 113, 11, 35, 36, 129, 11, 35, 34, 145, 11, 35, 32, 161, 11, 35, 46, 177, 9, 19, 4, 5, 0, 147, 9, 80, 0, 99, 132, 7, 36, 147, 132, 7, 0, 19, 137, 5, 0, 19, 5, 129, 6, 19, 6, 64, 0, 151, 0, 0, 0, 231, 128, 64, 179, 3, 74, 1, 7, 131, 74, 129, 6, 19, 5, 32, 0, 99, 22, 170, 0, 147, 137, 10, 0, 111, 0, 128, 33, 131, 76, 161, 6, 3, 77, 145, 6, 131, 77, 193, 6, 3, 75, 177, 6, 3, 69, 225, 6, 35, 42, 161, 0, 3, 69, 209, 6, 35, 40, 161, 0, 3, 12, 241, 6, 147, 5, 17, 7, 19, 5, 17, 4, 19, 6, 112, 2, 151, 48, 0, 0, 231, 128, 128, 105, 3, 41, 9, 0, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 147, 11, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 0, 34, 19, 117, 245, 15, 147, 5, 112, 0, 99, 24, 181, 26, 3, 37, 129, 6, 35, 38, 161, 0, 19, 5, 161, 1, 147, 5, 17, 4, 19, 6, 112, 2, 151, 48, 0, 0, 231, 128, 0, 101, 99, 138, 116, 25, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 192, 29, 19, 117, 245, 15, 147, 11, 112, 0, 99, 22, 117, 23, 3, 37, 129, 6, 35, 36, 161, 0, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 0, 27, 19, 117, 245, 15, 99, 18, 117, 21, 19, 5, 32, 0, 99, 128, 164, 20, 3, 37, 129, 6, 35, 34, 161, 0, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 0, 24, 19, 117, 245, 15, 147, 11, 112, 0, 99, 24, 117, 17, 3, 37, 129, 6, 35, 32, 161, 0, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 64, 21, 19, 117, 245, 15, 99, 20, 117, 15, 19, 5, 48, 0, 99, 130, 164, 14, 131, 36, 129, 6, 35, 36, 1, 6, 147, 5, 129, 6, 19, 6, 16, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 128, 18, 19, 117, 245, 15, 147, 5, 112, 0, 99, 28, 181, 10, 3, 41, 129, 6, 19, 5, 240, 15, 99, 104, 37, 11, 19, 149, 140, 0, 51, 101, 165, 1, 147, 149, 141, 0, 179, 229, 101, 1, 147, 149, 5, 1, 51, 229, 165, 0, 131, 37, 65, 1, 147, 149, 133, 0, 3, 38, 1, 1, 179, 229, 197, 0, 19, 22, 12, 1, 179, 229, 197, 0, 19, 86, 133, 1, 147, 149, 133, 0, 179, 233, 197, 0, 19, 21, 133, 0, 179, 106, 85, 1, 19, 5, 148, 0, 147, 5, 161, 1, 19, 6, 112, 2, 151, 48, 0, 0, 231, 128, 0, 81, 131, 37, 193, 0, 19, 213, 133, 1, 163, 9, 164, 2, 19, 213, 5, 1, 35, 9, 164, 2, 19, 213, 133, 0, 163, 8, 164, 2, 35, 8, 180, 2, 35, 34, 52, 1, 35, 32, 84, 1, 35, 4, 68, 1, 3, 37, 129, 0, 35, 42, 164, 2, 3, 37, 65, 0, 35, 44, 164, 2, 3, 37, 1, 0, 35, 46, 164, 2, 35, 32, 148, 4, 35, 2, 36, 5, 111, 0, 64, 1, 147, 9, 5, 0, 35, 0, 52, 1, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 12, 3, 36, 129, 12, 131, 36, 65, 12, 3, 41, 1, 12, 131, 41, 193, 11, 3, 42, 129, 11, 131, 42, 65, 11, 3, 43, 1, 11, 131, 43, 193, 10, 3, 44, 129, 10, 131, 44, 65, 10, 3, 45, 1, 10, 131, 45, 193, 9, 19, 1, 1, 13, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 19, 4, 5, 0, 99, 142, 7, 4, 19, 137, 7, 0, 131, 164, 5, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 48, 0, 0, 231, 128, 64, 253, 19, 117, 245, 15, 19, 10, 112, 0, 99, 26, 69, 3, 131, 41, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 147, 10, 16, 0, 19, 133, 4, 0, 151, 48, 0, 0, 231, 128, 128, 250, 19, 117, 245, 15, 99, 22, 69, 1, 99, 28, 89, 3, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 3, 42, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 48, 0, 0, 231, 128, 192, 244, 19, 117, 245, 15, 147, 5, 112, 0, 227, 22, 181, 250, 131, 37, 1, 0, 99, 138, 5, 0, 147, 10, 16, 0, 19, 5, 80, 0, 99, 134, 85, 1, 111, 240, 95, 249, 147, 10, 0, 0, 19, 5, 32, 0, 99, 24, 169, 0, 147, 5, 80, 0, 35, 0, 180, 0, 111, 240, 95, 248, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 48, 0, 0, 231, 128, 128, 239, 19, 117, 245, 15, 147, 5, 112, 0, 227, 28, 181, 244, 131, 37, 1, 0, 19, 6, 0, 16, 19, 5, 80, 0, 227, 244, 197, 244, 35, 32, 52, 1, 35, 34, 68, 1, 35, 4, 84, 1, 163, 4, 180, 0, 111, 240, 31, 244, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 131, 42, 5, 0, 3, 165, 74, 0, 3, 164, 10, 0, 147, 132, 5, 0, 51, 5, 197, 0, 35, 162, 170, 0, 99, 22, 4, 0, 55, 149, 0, 7, 19, 4, 197, 201, 19, 245, 52, 0, 99, 6, 5, 2, 19, 5, 240, 255, 99, 2, 166, 2, 147, 6, 0, 0, 55, 133, 0, 7, 147, 5, 69, 57, 19, 5, 6, 0, 99, 16, 5, 2, 19, 5, 4, 0, 19, 134, 6, 0, 111, 0, 128, 9, 147, 21, 101, 0, 179, 133, 180, 0, 179, 6, 166, 64, 227, 4, 5, 254, 99, 14, 6, 6, 19, 85, 150, 1, 99, 24, 5, 10, 19, 25, 102, 0, 19, 53, 21, 0, 19, 26, 37, 0, 19, 5, 9, 0, 147, 5, 10, 0, 151, 32, 0, 0, 231, 128, 192, 128, 99, 14, 5, 8, 147, 9, 5, 0, 19, 5, 9, 252, 19, 90, 101, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 9, 0, 151, 48, 0, 0, 231, 128, 192, 36, 19, 6, 26, 0, 19, 5, 4, 0, 147, 133, 9, 0, 151, 48, 0, 0, 231, 128, 0, 138, 19, 4, 5, 0, 19, 6, 64, 0, 19, 133, 9, 0, 147, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 124, 111, 0, 128, 1, 147, 5, 64, 0, 19, 5, 4, 0, 151, 48, 0, 0, 231, 128, 64, 135, 19, 4, 5, 0, 35, 160, 138, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 151, 64, 0, 0, 231, 128, 0, 209, 115, 16, 0, 192, 19, 5, 9, 0, 147, 5, 10, 0, 151, 64, 0, 0, 231, 128, 0, 212, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 131, 41, 5, 0, 35, 32, 209, 0, 3, 165, 9, 0, 19, 4, 8, 0, 147, 5, 1, 0, 19, 6, 64, 0, 147, 4, 1, 0, 151, 64, 0, 0, 231, 128, 128, 158, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 34, 193, 0, 99, 232, 166, 2, 3, 38, 1, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 138, 196, 0, 147, 4, 69, 252, 19, 137, 197, 0, 51, 5, 169, 0, 147, 5, 1, 0, 151, 48, 0, 0, 231, 128, 64, 17, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 192, 222, 19, 245, 4, 252, 179, 5, 170, 0, 19, 5, 9, 0, 19, 134, 4, 0, 151, 48, 0, 0, 231, 128, 128, 14, 3, 69, 4, 0, 183, 5, 8, 0, 19, 137, 69, 0, 35, 6, 153, 4, 35, 32, 161, 0, 3, 165, 9, 0, 147, 4, 73, 0, 147, 5, 1, 0, 19, 6, 64, 0, 19, 4, 1, 0, 151, 64, 0, 0, 231, 128, 0, 146, 3, 69, 201, 4, 147, 5, 176, 3, 35, 34, 145, 0, 99, 232, 165, 2, 131, 37, 1, 0, 51, 6, 169, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 4, 69, 0, 111, 0, 64, 5, 147, 5, 0, 4, 51, 134, 165, 64, 179, 9, 196, 0, 19, 4, 69, 252, 147, 4, 201, 0, 51, 133, 164, 0, 147, 5, 1, 0, 151, 48, 0, 0, 231, 128, 128, 5, 19, 5, 65, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 211, 19, 117, 4, 252, 179, 133, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 48, 0, 0, 231, 128, 192, 2, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 133, 4, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 147, 132, 5, 0, 19, 4, 5, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 16, 0, 0, 231, 128, 0, 87, 99, 2, 5, 16, 35, 36, 161, 0, 19, 5, 64, 3, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 129, 0, 35, 44, 161, 0, 147, 5, 129, 1, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 15, 19, 117, 245, 15, 19, 9, 112, 0, 99, 26, 37, 9, 19, 133, 196, 0, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 128, 13, 19, 117, 245, 15, 99, 30, 37, 7, 19, 133, 132, 1, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 0, 12, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 7, 19, 133, 68, 2, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 64, 10, 19, 117, 245, 15, 99, 20, 37, 5, 131, 165, 4, 3, 3, 37, 129, 1, 35, 46, 177, 0, 147, 5, 193, 1, 19, 6, 16, 0, 151, 32, 0, 0, 231, 128, 192, 207, 19, 117, 245, 15, 147, 5, 112, 0, 99, 16, 181, 2, 3, 37, 1, 1, 131, 37, 193, 0, 3, 38, 129, 0, 35, 36, 164, 0, 35, 34, 180, 0, 35, 32, 196, 0, 111, 0, 128, 2, 131, 37, 193, 0, 35, 2, 164, 0, 35, 32, 4, 0, 99, 140, 5, 0, 3, 37, 129, 0, 147, 149, 37, 0, 19, 6, 64, 0, 151, 16, 0, 0, 231, 128, 192, 72, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 64, 0, 0, 231, 128, 192, 163, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 147, 4, 5, 0, 3, 37, 5, 0, 3, 164, 5, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 192, 196, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 9, 3, 165, 68, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 64, 194, 19, 117, 245, 15, 99, 30, 37, 5, 3, 197, 132, 0, 99, 8, 5, 0, 19, 5, 16, 0, 35, 38, 161, 0, 111, 0, 128, 0, 35, 38, 1, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 0, 191, 19, 117, 245, 15, 147, 5, 112, 0, 99, 18, 181, 2, 3, 197, 148, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 128, 188, 19, 117, 245, 15, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 19, 132, 5, 0, 147, 4, 5, 0, 151, 16, 0, 0, 231, 128, 128, 25, 19, 117, 245, 15, 19, 9, 112, 0, 99, 18, 37, 15, 19, 133, 132, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 36, 19, 117, 245, 15, 99, 22, 37, 13, 3, 197, 148, 0, 131, 37, 4, 0, 35, 36, 161, 0, 3, 165, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 48, 0, 0, 231, 128, 0, 94, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 48, 0, 0, 231, 128, 192, 208, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 158, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 48, 0, 0, 231, 128, 0, 206, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 19, 132, 5, 0, 147, 4, 5, 0, 151, 0, 0, 0, 231, 128, 192, 235, 19, 117, 245, 15, 19, 9, 112, 0, 99, 28, 37, 17, 19, 133, 196, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 0, 234, 19, 117, 245, 15, 99, 16, 37, 17, 19, 133, 132, 1, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 232, 19, 117, 245, 15, 19, 9, 112, 0, 99, 18, 37, 15, 19, 133, 68, 2, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 230, 19, 117, 245, 15, 99, 22, 37, 13, 3, 165, 4, 3, 131, 37, 4, 0, 35, 36, 161, 0, 3, 165, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 48, 0, 0, 231, 128, 64, 72, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 48, 0, 0, 231, 128, 0, 187, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 136, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 48, 0, 0, 231, 128, 64, 184, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 69, 5, 0, 99, 10, 5, 0, 3, 165, 5, 0, 147, 5, 16, 0, 35, 36, 177, 0, 111, 0, 192, 0, 3, 165, 5, 0, 35, 36, 1, 0, 3, 37, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 48, 0, 0, 231, 128, 192, 55, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 48, 0, 0, 231, 128, 128, 170, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 240, 255, 255, 231, 128, 0, 120, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 48, 0, 0, 231, 128, 192, 167, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 228, 35, 46, 17, 26, 35, 44, 129, 26, 35, 42, 145, 26, 35, 40, 33, 27, 35, 38, 49, 27, 35, 36, 65, 27, 35, 34, 81, 27, 35, 32, 97, 27, 35, 46, 113, 25, 151, 32, 0, 0, 231, 128, 64, 92, 35, 40, 161, 12, 19, 5, 1, 13, 35, 40, 161, 6, 55, 133, 0, 7, 19, 6, 5, 51, 55, 133, 0, 7, 19, 7, 69, 55, 19, 5, 1, 15, 147, 5, 1, 7, 147, 6, 16, 1, 147, 7, 64, 0, 151, 240, 255, 255, 231, 128, 64, 43, 3, 69, 129, 15, 147, 5, 32, 0, 99, 10, 181, 90, 19, 5, 129, 0, 147, 5, 1, 15, 19, 6, 128, 4, 151, 48, 0, 0, 231, 128, 0, 157, 131, 41, 65, 4, 3, 42, 193, 3, 131, 42, 129, 4, 3, 43, 1, 4, 19, 5, 129, 0, 151, 16, 0, 0, 231, 128, 128, 247, 19, 4, 5, 0, 99, 0, 5, 8, 99, 76, 4, 100, 19, 37, 4, 0, 19, 73, 21, 0, 19, 5, 4, 0, 147, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 244, 147, 4, 5, 0, 99, 2, 5, 100, 19, 133, 4, 0, 147, 5, 4, 0, 151, 48, 0, 0, 231, 128, 64, 216, 99, 16, 5, 6, 51, 133, 106, 65, 179, 133, 73, 65, 147, 245, 245, 15, 19, 117, 245, 15, 179, 9, 181, 2, 99, 140, 9, 6, 19, 153, 57, 0, 147, 5, 64, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 238, 99, 18, 5, 6, 147, 5, 64, 0, 19, 5, 9, 0, 151, 48, 0, 0, 231, 128, 0, 75, 115, 16, 0, 192, 147, 4, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 151, 48, 0, 0, 231, 128, 128, 210, 227, 4, 5, 250, 35, 40, 161, 14, 55, 133, 0, 7, 19, 5, 5, 34, 183, 133, 0, 7, 147, 134, 197, 36, 183, 133, 0, 7, 19, 135, 5, 39, 147, 5, 176, 2, 19, 6, 1, 15, 151, 96, 0, 0, 231, 128, 0, 85, 115, 16, 0, 192, 19, 5, 64, 0, 3, 41, 193, 3, 3, 43, 65, 4, 35, 40, 161, 4, 35, 42, 49, 5, 35, 44, 1, 4, 99, 100, 43, 3, 3, 37, 1, 4, 131, 37, 129, 4, 99, 242, 165, 14, 51, 53, 105, 1, 147, 69, 21, 0, 51, 9, 169, 0, 51, 53, 43, 1, 51, 229, 165, 0, 227, 6, 5, 254, 19, 5, 64, 0, 35, 32, 161, 6, 35, 34, 1, 6, 35, 36, 1, 6, 99, 14, 4, 18, 19, 9, 4, 0, 147, 137, 4, 0, 111, 0, 0, 3, 3, 37, 1, 6, 147, 149, 53, 0, 51, 5, 181, 0, 35, 32, 69, 1, 35, 34, 85, 1, 3, 37, 129, 6, 19, 5, 21, 0, 35, 36, 161, 6, 19, 9, 249, 255, 147, 137, 25, 0, 99, 2, 9, 16, 3, 43, 129, 5, 99, 2, 11, 64, 3, 197, 9, 0, 131, 37, 1, 5, 51, 118, 101, 3, 19, 21, 54, 0, 51, 133, 165, 0, 3, 42, 5, 0, 131, 42, 69, 0, 147, 5, 133, 0, 19, 70, 246, 255, 51, 6, 203, 0, 19, 22, 54, 0, 151, 112, 0, 0, 231, 128, 192, 186, 131, 37, 129, 6, 3, 37, 65, 6, 19, 6, 251, 255, 35, 44, 193, 4, 227, 148, 165, 248, 19, 5, 1, 6, 151, 240, 255, 255, 231, 128, 192, 183, 131, 37, 129, 6, 111, 240, 95, 247, 51, 53, 105, 1, 147, 69, 21, 0, 51, 9, 169, 0, 51, 53, 43, 1, 51, 229, 165, 0, 227, 30, 5, 242, 131, 41, 1, 4, 131, 43, 129, 4, 99, 240, 59, 5, 111, 240, 223, 253, 3, 37, 1, 5, 147, 21, 54, 0, 51, 5, 181, 0, 35, 32, 85, 1, 35, 34, 69, 1, 3, 37, 129, 5, 179, 181, 121, 1, 19, 198, 21, 0, 179, 137, 185, 0, 19, 5, 21, 0, 179, 181, 59, 1, 179, 101, 182, 0, 35, 44, 161, 4, 227, 146, 5, 250, 19, 5, 9, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 70, 3, 38, 129, 5, 131, 38, 65, 5, 147, 10, 5, 0, 19, 138, 5, 0, 227, 20, 214, 250, 19, 5, 1, 5, 147, 5, 6, 0, 151, 240, 255, 255, 231, 128, 0, 174, 3, 38, 129, 5, 111, 240, 31, 249, 19, 5, 1, 7, 147, 5, 129, 0, 19, 6, 1, 6, 151, 16, 0, 0, 231, 128, 128, 221, 35, 38, 1, 12, 35, 36, 1, 12, 35, 34, 1, 12, 35, 32, 1, 12, 35, 46, 1, 10, 35, 44, 1, 10, 35, 42, 1, 10, 35, 40, 1, 10, 35, 38, 1, 14, 35, 36, 1, 14, 35, 34, 1, 14, 35, 32, 1, 14, 35, 46, 1, 12, 35, 44, 1, 12, 35, 42, 1, 12, 35, 40, 1, 12, 19, 5, 1, 15, 147, 5, 129, 0, 151, 240, 255, 255, 231, 128, 0, 112, 3, 41, 1, 15, 99, 10, 9, 46, 131, 41, 65, 15, 131, 37, 129, 15, 19, 5, 9, 0, 151, 32, 0, 0, 231, 128, 192, 201, 151, 48, 0, 0, 231, 128, 64, 233, 19, 6, 128, 0, 99, 154, 197, 46, 131, 37, 197, 1, 35, 38, 177, 12, 131, 37, 133, 1, 35, 36, 177, 12, 131, 37, 69, 1, 35, 34, 177, 12, 131, 37, 5, 1, 35, 32, 177, 12, 131, 37, 197, 0, 35, 46, 177, 10, 131, 37, 133, 0, 35, 44, 177, 10, 131, 37, 69, 0, 35, 42, 177, 10, 3, 37, 5, 0, 35, 40, 161, 10, 99, 140, 9, 0, 147, 149, 41, 0, 19, 6, 64, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 193, 19, 5, 1, 15, 147, 5, 1, 7, 151, 240, 255, 255, 231, 128, 192, 102, 3, 41, 1, 15, 99, 8, 9, 40, 131, 41, 65, 15, 131, 37, 129, 15, 19, 5, 9, 0, 151, 32, 0, 0, 231, 128, 128, 192, 151, 48, 0, 0, 231, 128, 0, 224, 19, 6, 128, 0, 99, 144, 197, 42, 131, 37, 197, 1, 35, 38, 177, 14, 131, 37, 133, 1, 35, 36, 177, 14, 131, 37, 69, 1, 35, 34, 177, 14, 131, 37, 5, 1, 35, 32, 177, 14, 131, 37, 197, 0, 35, 46, 177, 12, 131, 37, 133, 0, 35, 44, 177, 12, 131, 37, 69, 0, 35, 42, 177, 12, 3, 37, 5, 0, 35, 40, 161, 12, 99, 140, 9, 0, 147, 149, 41, 0, 19, 6, 64, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 184, 19, 5, 1, 19, 147, 5, 129, 0, 19, 6, 128, 4, 151, 32, 0, 0, 231, 128, 192, 92, 3, 37, 1, 11, 131, 37, 65, 11, 35, 40, 161, 14, 35, 42, 177, 14, 3, 37, 129, 11, 131, 37, 193, 11, 3, 38, 1, 12, 131, 38, 65, 12, 35, 44, 161, 14, 35, 46, 177, 14, 35, 32, 193, 16, 35, 34, 209, 16, 3, 37, 129, 12, 131, 37, 193, 12, 3, 38, 1, 13, 131, 38, 65, 13, 35, 36, 161, 16, 35, 38, 177, 16, 35, 40, 193, 16, 35, 42, 209, 16, 3, 37, 129, 13, 131, 37, 193, 13, 3, 38, 1, 14, 131, 38, 65, 14, 35, 44, 161, 16, 35, 46, 177, 16, 35, 32, 193, 18, 35, 34, 209, 18, 3, 37, 129, 14, 131, 37, 193, 14, 3, 38, 129, 6, 131, 38, 65, 6, 35, 36, 161, 18, 35, 38, 177, 18, 35, 32, 193, 24, 35, 46, 209, 22, 3, 37, 1, 6, 131, 37, 65, 10, 3, 38, 129, 10, 131, 38, 193, 10, 35, 44, 161, 22, 35, 34, 177, 24, 35, 36, 193, 24, 35, 38, 209, 24, 151, 32, 0, 0, 231, 128, 192, 10, 35, 40, 161, 24, 19, 5, 1, 25, 35, 42, 161, 24, 19, 5, 1, 15, 147, 5, 65, 25, 151, 0, 0, 0, 231, 128, 192, 28, 19, 117, 245, 15, 147, 5, 112, 0, 99, 22, 181, 22, 131, 37, 193, 23, 99, 140, 5, 0, 3, 37, 129, 23, 147, 149, 53, 0, 19, 6, 64, 0, 151, 16, 0, 0, 231, 128, 64, 167, 131, 37, 129, 24, 99, 140, 5, 0, 3, 37, 65, 24, 147, 149, 21, 0, 19, 6, 16, 0, 151, 16, 0, 0, 231, 128, 128, 165, 131, 37, 65, 5, 99, 140, 5, 0, 3, 37, 1, 5, 147, 149, 53, 0, 19, 6, 64, 0, 151, 16, 0, 0, 231, 128, 192, 163, 99, 12, 4, 0, 19, 6, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 162, 131, 32, 193, 27, 3, 36, 129, 27, 131, 36, 65, 27, 3, 41, 1, 27, 131, 41, 193, 26, 3, 42, 129, 26, 131, 42, 65, 26, 3, 43, 1, 26, 131, 43, 193, 25, 19, 1, 1, 28, 103, 128, 0, 0, 55, 133, 0, 7, 19, 5, 5, 41, 183, 133, 0, 7, 19, 134, 5, 40, 147, 5, 144, 3, 151, 96, 0, 0, 231, 128, 0, 232, 115, 16, 0, 192, 3, 5, 1, 15, 35, 4, 161, 0, 55, 133, 0, 7, 19, 5, 69, 22, 183, 133, 0, 7, 147, 134, 5, 25, 183, 133, 0, 7, 19, 135, 5, 32, 147, 5, 176, 2, 19, 6, 129, 0, 151, 96, 0, 0, 231, 128, 128, 6, 115, 16, 0, 192, 3, 5, 65, 15, 35, 10, 161, 24, 55, 133, 0, 7, 19, 5, 5, 34, 183, 133, 0, 7, 147, 134, 197, 44, 183, 133, 0, 7, 19, 135, 197, 45, 111, 0, 0, 3, 55, 133, 0, 7, 19, 6, 197, 46, 111, 0, 0, 4, 3, 5, 65, 15, 35, 10, 161, 24, 55, 133, 0, 7, 19, 5, 5, 34, 183, 133, 0, 7, 147, 134, 197, 44, 183, 133, 0, 7, 19, 135, 197, 47, 147, 5, 176, 2, 19, 6, 65, 25, 151, 96, 0, 0, 231, 128, 64, 0, 115, 16, 0, 192, 55, 133, 0, 7, 19, 6, 197, 48, 19, 5, 128, 0, 151, 48, 0, 0, 231, 128, 0, 94, 115, 16, 0, 192, 163, 13, 161, 24, 55, 133, 0, 7, 19, 5, 69, 22, 183, 133, 0, 7, 147, 134, 5, 25, 183, 133, 0, 7, 19, 135, 5, 33, 147, 5, 176, 2, 19, 6, 177, 25, 151, 96, 0, 0, 231, 128, 192, 251, 115, 16, 0, 192, 151, 48, 0, 0, 231, 128, 64, 232, 115, 16, 0, 192, 19, 5, 4, 0, 147, 5, 9, 0, 151, 48, 0, 0, 231, 128, 64, 235, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 147, 4, 5, 0, 147, 10, 0, 0, 35, 32, 177, 0, 3, 170, 5, 0, 19, 11, 65, 0, 55, 5, 8, 0, 147, 11, 69, 0, 19, 140, 75, 0, 147, 12, 176, 3, 19, 13, 0, 2, 147, 13, 0, 4, 19, 137, 203, 0, 111, 0, 128, 5, 51, 134, 173, 64, 51, 4, 203, 0, 147, 9, 69, 252, 51, 5, 169, 0, 147, 5, 65, 0, 151, 32, 0, 0, 231, 128, 192, 42, 19, 5, 129, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 64, 248, 19, 245, 9, 252, 179, 5, 164, 0, 19, 5, 9, 0, 19, 134, 9, 0, 151, 32, 0, 0, 231, 128, 0, 40, 147, 138, 74, 0, 35, 134, 59, 5, 99, 130, 170, 7, 51, 133, 84, 1, 3, 37, 5, 0, 35, 34, 161, 0, 3, 37, 10, 0, 147, 5, 65, 0, 19, 6, 64, 0, 151, 48, 0, 0, 231, 128, 192, 171, 3, 197, 203, 4, 35, 36, 129, 1, 227, 226, 172, 248, 131, 37, 65, 0, 51, 134, 171, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 147, 9, 69, 0, 147, 138, 74, 0, 35, 134, 59, 5, 227, 146, 170, 251, 147, 10, 0, 0, 19, 139, 4, 2, 55, 5, 8, 0, 19, 12, 69, 0, 147, 12, 76, 0, 19, 13, 176, 3, 147, 13, 0, 2, 19, 4, 0, 4, 19, 9, 204, 0, 111, 0, 192, 5, 51, 6, 164, 64, 147, 5, 65, 0, 179, 139, 197, 0, 147, 9, 69, 252, 51, 5, 169, 0, 147, 5, 65, 0, 151, 32, 0, 0, 231, 128, 192, 28, 19, 5, 129, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 64, 234, 19, 245, 9, 252, 179, 133, 171, 0, 19, 5, 9, 0, 19, 134, 9, 0, 151, 32, 0, 0, 231, 128, 0, 26, 147, 138, 74, 0, 35, 6, 60, 5, 99, 130, 186, 7, 51, 5, 91, 1, 3, 37, 5, 0, 35, 34, 161, 0, 3, 37, 10, 0, 147, 5, 65, 0, 19, 6, 64, 0, 151, 48, 0, 0, 231, 128, 192, 157, 3, 69, 204, 4, 35, 36, 145, 1, 227, 96, 173, 248, 131, 37, 65, 0, 51, 6, 172, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 147, 9, 69, 0, 147, 138, 74, 0, 35, 6, 60, 5, 227, 146, 186, 251, 19, 133, 4, 4, 3, 43, 1, 0, 147, 5, 11, 0, 151, 0, 0, 0, 231, 128, 192, 36, 19, 117, 245, 15, 147, 5, 112, 0, 99, 16, 181, 32, 131, 170, 4, 9, 3, 37, 11, 0, 3, 169, 132, 8, 35, 34, 81, 1, 3, 37, 5, 0, 147, 5, 65, 0, 19, 6, 64, 0, 19, 4, 65, 0, 151, 48, 0, 0, 231, 128, 64, 149, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 36, 193, 0, 99, 232, 166, 2, 3, 38, 65, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 9, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 4, 196, 0, 147, 9, 69, 252, 19, 138, 197, 0, 51, 5, 170, 0, 147, 5, 65, 0, 151, 32, 0, 0, 231, 128, 0, 8, 19, 5, 129, 0, 19, 6, 16, 0, 147, 5, 10, 0, 151, 240, 255, 255, 231, 128, 128, 213, 19, 245, 9, 252, 179, 5, 164, 0, 19, 5, 10, 0, 19, 134, 9, 0, 151, 32, 0, 0, 231, 128, 64, 5, 55, 5, 8, 0, 35, 8, 53, 5, 19, 148, 58, 0, 147, 9, 112, 0, 99, 6, 4, 2, 19, 10, 137, 0, 19, 5, 9, 0, 147, 5, 11, 0, 151, 0, 0, 0, 231, 128, 64, 64, 19, 117, 245, 15, 19, 4, 132, 255, 19, 9, 10, 0, 227, 14, 53, 253, 111, 0, 64, 16, 3, 170, 196, 9, 3, 37, 11, 0, 131, 164, 68, 9, 35, 34, 65, 1, 3, 37, 5, 0, 147, 5, 65, 0, 19, 6, 64, 0, 19, 4, 65, 0, 151, 48, 0, 0, 231, 128, 128, 133, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 36, 193, 0, 99, 232, 166, 2, 3, 38, 65, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 9, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 4, 196, 0, 19, 9, 69, 252, 147, 137, 197, 0, 51, 133, 169, 0, 147, 5, 65, 0, 151, 32, 0, 0, 231, 128, 64, 248, 19, 5, 129, 0, 19, 6, 16, 0, 147, 133, 9, 0, 151, 240, 255, 255, 231, 128, 192, 197, 19, 117, 9, 252, 179, 5, 164, 0, 19, 133, 9, 0, 19, 6, 9, 0, 151, 32, 0, 0, 231, 128, 128, 245, 55, 5, 8, 0, 35, 8, 37, 5, 19, 20, 26, 0, 19, 9, 112, 0, 99, 6, 4, 2, 147, 137, 36, 0, 19, 133, 4, 0, 147, 5, 11, 0, 151, 0, 0, 0, 231, 128, 0, 26, 19, 117, 245, 15, 19, 4, 228, 255, 147, 132, 9, 0, 227, 14, 37, 253, 111, 0, 128, 0, 19, 5, 112, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 19, 132, 5, 0, 147, 4, 5, 0, 151, 240, 255, 255, 231, 128, 64, 32, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 17, 19, 133, 68, 3, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 39, 19, 117, 245, 15, 99, 20, 37, 15, 19, 133, 196, 3, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 37, 19, 117, 245, 15, 147, 5, 112, 0, 99, 22, 181, 12, 3, 197, 68, 4, 131, 37, 4, 0, 35, 36, 161, 0, 3, 165, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 32, 0, 0, 231, 128, 192, 107, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 32, 0, 0, 231, 128, 128, 222, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 240, 255, 255, 231, 128, 0, 172, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 192, 219, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 70, 5, 0, 147, 6, 16, 0, 99, 202, 198, 0, 99, 18, 6, 2, 3, 165, 5, 0, 35, 36, 1, 0, 111, 0, 64, 2, 147, 6, 32, 0, 99, 24, 214, 14, 3, 165, 5, 0, 35, 36, 209, 0, 111, 0, 0, 1, 3, 165, 5, 0, 147, 5, 16, 0, 35, 36, 177, 0, 3, 37, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 32, 0, 0, 231, 128, 128, 89, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 32, 0, 0, 231, 128, 64, 204, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 240, 255, 255, 231, 128, 192, 153, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 201, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 8, 21, 0, 55, 133, 0, 7, 147, 8, 69, 57, 55, 133, 0, 7, 19, 7, 181, 57, 19, 6, 112, 0, 147, 6, 48, 0, 147, 7, 144, 0, 19, 133, 5, 0, 147, 133, 8, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 23, 243, 255, 255, 103, 0, 131, 167, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 19, 4, 5, 0, 3, 37, 5, 0, 131, 169, 5, 0, 35, 32, 161, 0, 3, 165, 9, 0, 147, 5, 1, 0, 19, 6, 64, 0, 147, 4, 1, 0, 151, 32, 0, 0, 231, 128, 64, 69, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 34, 193, 0, 99, 232, 166, 2, 3, 38, 1, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 138, 196, 0, 147, 4, 69, 252, 19, 137, 197, 0, 51, 5, 169, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 0, 184, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 128, 133, 19, 245, 4, 252, 179, 5, 170, 0, 19, 5, 9, 0, 19, 134, 4, 0, 151, 32, 0, 0, 231, 128, 64, 181, 3, 37, 68, 0, 183, 5, 8, 0, 19, 137, 69, 0, 35, 6, 153, 4, 35, 32, 161, 0, 3, 165, 9, 0, 147, 4, 73, 0, 147, 5, 1, 0, 19, 6, 64, 0, 19, 4, 1, 0, 151, 32, 0, 0, 231, 128, 192, 56, 3, 69, 201, 4, 147, 5, 176, 3, 35, 34, 145, 0, 99, 232, 165, 2, 131, 37, 1, 0, 51, 6, 169, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 4, 69, 0, 111, 0, 64, 5, 147, 5, 0, 4, 51, 134, 165, 64, 179, 9, 196, 0, 19, 4, 69, 252, 147, 4, 201, 0, 51, 133, 164, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 64, 172, 19, 5, 65, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 224, 255, 255, 231, 128, 192, 121, 19, 117, 4, 252, 179, 133, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 169, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 133, 4, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 23, 19, 0, 0, 103, 0, 3, 101, 23, 19, 0, 0, 103, 0, 131, 113, 23, 19, 0, 0, 103, 0, 67, 113, 23, 35, 0, 0, 103, 0, 3, 132, 23, 35, 0, 0, 103, 0, 131, 93, 19, 1, 1, 254, 131, 37, 197, 3, 3, 38, 69, 3, 131, 38, 5, 4, 3, 39, 133, 3, 51, 133, 197, 64, 19, 5, 21, 0, 19, 54, 21, 0, 179, 133, 230, 64, 147, 133, 21, 0, 147, 182, 21, 0, 51, 102, 214, 0, 99, 30, 6, 2, 179, 133, 165, 2, 19, 6, 64, 0, 19, 5, 16, 0, 99, 130, 197, 2, 19, 134, 165, 255, 147, 6, 48, 0, 19, 5, 32, 0, 99, 106, 214, 0, 147, 133, 117, 255, 19, 6, 80, 0, 19, 5, 48, 0, 99, 102, 182, 2, 19, 1, 1, 2, 103, 128, 0, 0, 55, 133, 0, 7, 19, 5, 69, 58, 183, 133, 0, 7, 19, 134, 197, 64, 147, 5, 144, 2, 151, 80, 0, 0, 231, 128, 64, 64, 115, 16, 0, 192, 55, 133, 0, 7, 19, 5, 5, 67, 35, 36, 161, 0, 19, 5, 16, 0, 35, 38, 161, 0, 35, 40, 1, 0, 55, 133, 0, 7, 19, 5, 69, 58, 35, 44, 161, 0, 35, 46, 1, 0, 55, 133, 0, 7, 147, 5, 133, 67, 19, 5, 129, 0, 151, 80, 0, 0, 231, 128, 128, 56, 115, 16, 0, 192, 19, 1, 1, 241, 35, 38, 17, 14, 35, 36, 129, 14, 35, 34, 145, 14, 35, 32, 33, 15, 35, 46, 49, 13, 35, 44, 65, 13, 35, 42, 81, 13, 35, 40, 97, 13, 35, 38, 113, 13, 35, 36, 129, 13, 19, 9, 6, 0, 147, 132, 5, 0, 19, 4, 5, 0, 19, 133, 5, 3, 35, 40, 177, 4, 35, 42, 161, 4, 19, 5, 129, 0, 147, 5, 1, 5, 147, 9, 129, 0, 151, 0, 0, 0, 231, 128, 64, 96, 3, 165, 4, 3, 35, 44, 161, 2, 3, 37, 137, 0, 147, 5, 16, 0, 35, 32, 177, 4, 35, 34, 1, 4, 35, 36, 1, 4, 99, 4, 5, 16, 3, 41, 9, 0, 19, 10, 129, 3, 19, 21, 53, 0, 179, 10, 169, 0, 111, 0, 192, 2, 3, 37, 1, 4, 147, 149, 21, 0, 51, 5, 181, 0, 35, 0, 101, 1, 163, 0, 117, 1, 3, 37, 129, 4, 19, 5, 21, 0, 19, 9, 137, 0, 35, 36, 161, 4, 99, 6, 89, 13, 3, 37, 9, 0, 131, 165, 68, 3, 3, 166, 196, 3, 179, 53, 181, 0, 51, 53, 166, 0, 51, 229, 165, 0, 99, 22, 5, 16, 3, 37, 73, 0, 131, 165, 132, 3, 3, 166, 4, 4, 179, 53, 181, 0, 51, 53, 166, 0, 51, 229, 165, 0, 99, 18, 5, 16, 35, 40, 49, 5, 35, 42, 65, 5, 19, 5, 1, 9, 147, 5, 1, 5, 151, 0, 0, 0, 231, 128, 128, 85, 3, 43, 129, 3, 131, 43, 9, 0, 3, 44, 73, 0, 19, 5, 1, 5, 147, 5, 1, 9, 19, 6, 0, 3, 151, 32, 0, 0, 231, 128, 64, 133, 35, 32, 97, 9, 35, 34, 113, 9, 35, 36, 129, 9, 19, 5, 1, 9, 147, 5, 1, 5, 151, 0, 0, 0, 231, 128, 64, 13, 19, 5, 129, 0, 147, 5, 1, 9, 19, 6, 64, 3, 151, 32, 0, 0, 231, 128, 64, 130, 3, 75, 65, 12, 131, 37, 129, 4, 3, 37, 65, 4, 131, 75, 81, 12, 227, 148, 165, 242, 19, 5, 1, 4, 151, 0, 0, 0, 231, 128, 0, 64, 131, 37, 129, 4, 111, 240, 95, 241, 147, 5, 129, 0, 19, 6, 64, 3, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 128, 126, 3, 37, 1, 4, 131, 37, 65, 4, 3, 38, 129, 4, 35, 42, 164, 2, 35, 44, 180, 2, 35, 46, 196, 2, 131, 32, 193, 14, 3, 36, 129, 14, 131, 36, 65, 14, 3, 41, 1, 14, 131, 41, 193, 13, 3, 42, 129, 13, 131, 42, 65, 13, 3, 43, 1, 13, 131, 43, 193, 12, 3, 44, 129, 12, 19, 1, 1, 15, 103, 128, 0, 0, 55, 133, 0, 7, 19, 5, 133, 68, 183, 133, 0, 7, 19, 134, 197, 75, 111, 0, 64, 1, 55, 133, 0, 7, 19, 5, 197, 76, 183, 133, 0, 7, 19, 134, 5, 84, 147, 5, 16, 7, 151, 80, 0, 0, 231, 128, 128, 27, 115, 16, 0, 192, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 147, 132, 5, 0, 19, 4, 5, 0, 19, 133, 5, 3, 35, 44, 177, 2, 35, 46, 161, 2, 19, 5, 1, 0, 147, 5, 129, 3, 151, 0, 0, 0, 231, 128, 128, 65, 3, 165, 68, 3, 131, 165, 132, 3, 3, 166, 4, 3, 147, 54, 133, 0, 19, 183, 133, 0, 179, 246, 230, 0, 35, 40, 193, 2, 99, 138, 6, 34, 3, 38, 1, 0, 3, 71, 129, 0, 131, 38, 65, 0, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 70, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 0, 0, 99, 8, 6, 2, 111, 0, 192, 18, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 70, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 0, 0, 99, 154, 6, 18, 3, 38, 193, 0, 3, 71, 65, 1, 131, 38, 1, 1, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 54, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 16, 0, 99, 8, 6, 2, 111, 0, 128, 12, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 54, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 16, 0, 99, 152, 6, 12, 3, 38, 129, 1, 3, 71, 1, 2, 131, 38, 193, 1, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 54, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 32, 0, 99, 8, 6, 2, 111, 0, 64, 6, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 54, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 32, 0, 99, 150, 6, 6, 3, 38, 65, 2, 3, 71, 193, 2, 131, 38, 129, 2, 99, 10, 7, 2, 51, 69, 197, 0, 19, 53, 21, 0, 51, 182, 213, 0, 19, 70, 22, 0, 51, 117, 197, 0, 19, 134, 38, 0, 51, 182, 197, 0, 51, 117, 197, 0, 147, 4, 48, 0, 99, 10, 5, 10, 51, 133, 213, 64, 111, 0, 0, 3, 179, 197, 213, 0, 147, 181, 21, 0, 179, 54, 197, 0, 147, 198, 22, 0, 179, 245, 213, 0, 147, 6, 38, 0, 179, 54, 213, 0, 179, 245, 213, 0, 147, 4, 48, 0, 99, 130, 5, 8, 51, 5, 197, 64, 19, 117, 117, 0, 147, 5, 16, 0, 51, 149, 165, 0, 147, 5, 192, 0, 179, 133, 180, 2, 19, 6, 1, 0, 179, 5, 182, 0, 3, 134, 149, 0, 183, 134, 0, 7, 147, 134, 198, 50, 179, 134, 150, 0, 131, 198, 6, 0, 51, 101, 197, 0, 163, 132, 165, 0, 19, 117, 245, 15, 19, 9, 48, 0, 99, 4, 213, 0, 19, 9, 16, 0, 147, 5, 1, 0, 19, 6, 64, 3, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 0, 82, 35, 10, 36, 3, 163, 10, 148, 2, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 19, 1, 1, 5, 103, 128, 0, 0, 147, 5, 1, 0, 19, 6, 64, 3, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 192, 78, 35, 10, 4, 2, 111, 240, 31, 253, 55, 133, 0, 7, 19, 5, 5, 85, 183, 133, 0, 7, 19, 134, 5, 87, 147, 5, 224, 1, 151, 80, 0, 0, 231, 128, 0, 241, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 99, 0, 6, 4, 99, 192, 4, 4, 19, 9, 6, 0, 3, 165, 134, 0, 99, 0, 5, 4, 131, 165, 70, 0, 99, 140, 5, 2, 3, 165, 6, 0, 19, 6, 9, 0, 147, 134, 4, 0, 151, 0, 0, 0, 231, 128, 64, 162, 99, 28, 5, 2, 35, 34, 148, 0, 35, 36, 36, 1, 111, 0, 192, 0, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 128, 2, 99, 128, 4, 4, 19, 133, 4, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 0, 158, 227, 8, 5, 252, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 5, 9, 0, 227, 26, 5, 252, 111, 240, 223, 249, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 142, 5, 8, 19, 4, 5, 0, 131, 38, 69, 0, 19, 149, 22, 0, 147, 4, 5, 0, 99, 250, 165, 2, 147, 5, 64, 0, 99, 252, 149, 2, 147, 213, 228, 1, 19, 182, 21, 0, 147, 149, 20, 0, 99, 142, 6, 2, 131, 38, 4, 0, 35, 44, 209, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 128, 2, 147, 132, 5, 0, 147, 5, 64, 0, 227, 232, 149, 252, 147, 4, 64, 0, 147, 213, 228, 1, 19, 182, 21, 0, 147, 149, 20, 0, 227, 150, 6, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 151, 0, 0, 0, 231, 128, 0, 236, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 32, 0, 0, 231, 128, 192, 232, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 32, 0, 0, 231, 128, 128, 234, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 129, 0, 35, 36, 145, 0, 3, 175, 69, 0, 131, 175, 5, 0, 99, 138, 239, 7, 19, 136, 207, 0, 35, 160, 5, 1, 131, 166, 15, 0, 3, 167, 79, 0, 3, 198, 143, 0, 131, 199, 159, 0, 99, 14, 232, 5, 147, 136, 143, 1, 35, 160, 21, 1, 131, 162, 207, 0, 3, 163, 15, 1, 131, 195, 79, 1, 3, 200, 95, 1, 99, 136, 232, 9, 147, 132, 79, 2, 35, 160, 149, 0, 3, 174, 143, 1, 131, 174, 207, 1, 3, 196, 15, 2, 131, 200, 31, 2, 99, 140, 228, 7, 19, 143, 15, 3, 35, 160, 229, 1, 131, 165, 79, 2, 3, 175, 143, 2, 131, 196, 207, 2, 131, 207, 223, 2, 111, 0, 128, 0, 19, 6, 32, 0, 35, 32, 213, 0, 35, 34, 229, 0, 35, 4, 197, 0, 163, 4, 245, 0, 35, 38, 85, 0, 35, 40, 101, 0, 35, 10, 117, 0, 163, 10, 5, 1, 35, 44, 197, 1, 35, 46, 213, 1, 35, 0, 133, 2, 163, 0, 21, 3, 35, 34, 181, 2, 35, 36, 229, 3, 35, 6, 149, 2, 163, 6, 245, 3, 3, 36, 193, 0, 131, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 111, 240, 31, 251, 111, 240, 223, 250, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 4, 5, 0, 3, 41, 133, 0, 3, 37, 69, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 100, 197, 4, 3, 37, 4, 0, 19, 150, 36, 0, 147, 21, 41, 0, 51, 5, 181, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 31, 179, 5, 153, 0, 19, 5, 112, 0, 35, 36, 180, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 12, 3, 41, 132, 0, 111, 240, 95, 250, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 99, 0, 6, 4, 99, 192, 4, 4, 19, 9, 6, 0, 3, 165, 134, 0, 99, 0, 5, 4, 131, 165, 70, 0, 99, 140, 5, 2, 3, 165, 6, 0, 19, 6, 9, 0, 147, 134, 4, 0, 151, 240, 255, 255, 231, 128, 192, 112, 99, 28, 5, 2, 35, 34, 148, 0, 35, 36, 36, 1, 111, 0, 192, 0, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 128, 2, 99, 128, 4, 4, 19, 133, 4, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 128, 108, 227, 8, 5, 252, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 5, 9, 0, 227, 26, 5, 252, 111, 240, 223, 249, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 100, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 100, 150, 0, 147, 4, 6, 0, 147, 5, 64, 0, 99, 228, 149, 0, 147, 4, 64, 0, 147, 213, 212, 1, 19, 182, 21, 0, 147, 149, 36, 0, 19, 22, 38, 0, 99, 0, 5, 2, 131, 38, 4, 0, 19, 21, 37, 0, 35, 44, 209, 0, 35, 46, 161, 0, 19, 5, 64, 0, 35, 32, 161, 2, 111, 0, 128, 0, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 151, 0, 0, 0, 231, 128, 64, 237, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 32, 0, 0, 231, 128, 128, 184, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 32, 0, 0, 231, 128, 64, 186, 115, 16, 0, 192, 3, 37, 5, 0, 23, 51, 0, 0, 103, 0, 3, 252, 3, 69, 5, 0, 19, 21, 37, 0, 55, 134, 0, 7, 19, 6, 6, 88, 51, 5, 197, 0, 3, 38, 5, 0, 19, 133, 5, 0, 103, 0, 6, 0, 183, 133, 0, 7, 147, 133, 181, 96, 19, 6, 32, 1, 23, 67, 0, 0, 103, 0, 3, 6, 183, 133, 0, 7, 147, 133, 149, 95, 19, 6, 32, 1, 23, 67, 0, 0, 103, 0, 195, 4, 183, 133, 0, 7, 147, 133, 85, 94, 19, 6, 64, 1, 23, 67, 0, 0, 103, 0, 131, 3, 183, 133, 0, 7, 147, 133, 53, 93, 19, 6, 32, 1, 23, 67, 0, 0, 103, 0, 67, 2, 183, 133, 0, 7, 147, 133, 181, 91, 19, 6, 128, 1, 23, 67, 0, 0, 103, 0, 3, 1, 183, 133, 0, 7, 147, 133, 245, 90, 19, 6, 192, 0, 23, 67, 0, 0, 103, 0, 195, 255, 183, 133, 0, 7, 147, 133, 197, 89, 19, 6, 48, 1, 23, 67, 0, 0, 103, 0, 131, 254, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 131, 36, 5, 0, 19, 132, 5, 0, 19, 133, 5, 0, 151, 64, 0, 0, 231, 128, 64, 253, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 3, 233, 19, 5, 4, 0, 151, 64, 0, 0, 231, 128, 64, 251, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 3, 241, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 83, 0, 0, 103, 0, 67, 6, 3, 38, 69, 0, 131, 37, 69, 1, 147, 6, 16, 0, 99, 12, 214, 0, 99, 28, 6, 0, 99, 142, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 103, 128, 0, 0, 99, 142, 5, 0, 19, 5, 0, 0, 103, 128, 0, 0, 55, 133, 0, 7, 19, 5, 5, 98, 147, 5, 0, 0, 103, 128, 0, 0, 131, 37, 5, 0, 3, 165, 5, 0, 131, 165, 69, 0, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 35, 46, 49, 1, 19, 4, 5, 0, 99, 130, 5, 4, 147, 4, 6, 0, 19, 137, 5, 0, 99, 14, 6, 6, 99, 202, 4, 10, 19, 165, 4, 0, 147, 73, 21, 0, 19, 133, 4, 0, 147, 133, 9, 0, 151, 240, 255, 255, 231, 128, 128, 61, 99, 16, 5, 6, 19, 133, 4, 0, 147, 133, 9, 0, 151, 32, 0, 0, 231, 128, 64, 154, 115, 16, 0, 192, 3, 165, 70, 1, 131, 165, 6, 1, 35, 42, 161, 0, 35, 40, 177, 0, 3, 165, 198, 0, 131, 165, 134, 0, 3, 166, 70, 0, 131, 166, 6, 0, 35, 38, 161, 0, 35, 36, 177, 0, 35, 34, 193, 0, 35, 32, 209, 0, 147, 5, 1, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 128, 152, 111, 0, 64, 2, 19, 5, 16, 0, 35, 32, 164, 0, 35, 34, 148, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 16, 0, 0, 231, 128, 64, 220, 35, 36, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 19, 1, 1, 3, 103, 128, 0, 0, 151, 32, 0, 0, 231, 128, 64, 141, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 34, 161, 0, 19, 5, 65, 0, 35, 40, 161, 2, 55, 53, 0, 7, 19, 5, 69, 219, 35, 42, 161, 2, 55, 133, 0, 7, 19, 5, 5, 98, 35, 44, 161, 0, 19, 5, 16, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 231, 19, 6, 5, 0, 19, 135, 5, 0, 19, 5, 129, 0, 147, 6, 129, 1, 147, 5, 6, 0, 19, 6, 7, 0, 151, 0, 0, 0, 231, 128, 192, 234, 3, 37, 129, 0, 131, 37, 1, 1, 151, 16, 0, 0, 231, 128, 192, 35, 115, 16, 0, 192, 55, 133, 0, 7, 19, 5, 133, 98, 147, 5, 144, 1, 151, 16, 0, 0, 231, 128, 64, 34, 115, 16, 0, 192, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 35, 38, 113, 3, 35, 36, 129, 3, 35, 34, 145, 3, 19, 137, 7, 0, 19, 11, 7, 0, 147, 138, 6, 0, 147, 11, 6, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 6, 7, 4, 19, 133, 250, 3, 19, 85, 37, 0, 147, 124, 5, 255, 99, 136, 12, 4, 19, 213, 220, 1, 99, 22, 5, 30, 147, 153, 44, 0, 99, 194, 9, 30, 19, 53, 21, 0, 19, 28, 37, 0, 99, 128, 9, 4, 19, 133, 9, 0, 147, 5, 12, 0, 151, 240, 255, 255, 231, 128, 0, 33, 19, 10, 5, 0, 99, 24, 10, 2, 111, 0, 128, 30, 19, 133, 74, 0, 19, 85, 37, 0, 19, 5, 21, 1, 147, 124, 5, 255, 227, 156, 12, 250, 147, 9, 0, 0, 19, 10, 64, 0, 111, 0, 192, 0, 19, 10, 12, 0, 99, 0, 10, 28, 99, 238, 89, 25, 19, 5, 10, 0, 147, 133, 11, 0, 19, 134, 10, 0, 151, 16, 0, 0, 231, 128, 128, 194, 51, 5, 90, 1, 99, 8, 11, 8, 51, 134, 89, 65, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 128, 177, 99, 148, 12, 14, 99, 130, 132, 4, 3, 165, 4, 0, 131, 165, 68, 0, 3, 166, 132, 0, 131, 166, 196, 0, 3, 167, 196, 1, 131, 167, 132, 1, 3, 168, 68, 1, 131, 168, 4, 1, 35, 46, 228, 0, 35, 44, 244, 0, 35, 42, 4, 1, 35, 40, 20, 1, 35, 38, 212, 0, 35, 36, 196, 0, 35, 34, 180, 0, 35, 32, 164, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 103, 128, 0, 0, 147, 5, 0, 8, 35, 0, 181, 0, 19, 5, 21, 0, 147, 197, 250, 255, 51, 134, 185, 0, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 192, 167, 19, 133, 252, 255, 99, 132, 12, 10, 19, 21, 37, 0, 179, 5, 170, 0, 3, 165, 5, 0, 99, 24, 5, 14, 19, 85, 137, 0, 55, 6, 1, 0, 19, 6, 6, 240, 51, 117, 197, 0, 19, 86, 137, 1, 51, 101, 197, 0, 19, 22, 137, 0, 183, 6, 255, 0, 51, 118, 214, 0, 147, 22, 137, 1, 51, 230, 198, 0, 51, 101, 166, 0, 35, 160, 165, 0, 19, 215, 76, 0, 147, 6, 10, 2, 147, 2, 48, 0, 19, 5, 4, 0, 147, 133, 4, 0, 19, 6, 10, 0, 115, 0, 0, 0, 19, 6, 64, 0, 19, 5, 10, 0, 147, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 23, 243, 255, 255, 103, 0, 195, 6, 183, 133, 0, 7, 19, 134, 69, 110, 147, 5, 0, 0, 151, 64, 0, 0, 231, 128, 0, 84, 115, 16, 0, 192, 151, 16, 0, 0, 231, 128, 0, 94, 115, 16, 0, 192, 55, 133, 0, 7, 19, 5, 21, 106, 183, 133, 0, 7, 19, 134, 69, 109, 147, 5, 48, 3, 151, 64, 0, 0, 231, 128, 0, 77, 115, 16, 0, 192, 19, 133, 9, 0, 147, 5, 12, 0, 151, 16, 0, 0, 231, 128, 0, 95, 115, 16, 0, 192, 35, 36, 1, 0, 55, 133, 0, 7, 19, 6, 69, 50, 55, 133, 0, 7, 19, 7, 69, 111, 147, 6, 129, 0, 19, 5, 0, 0, 151, 0, 0, 0, 231, 128, 128, 17, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 251, 19, 4, 5, 0, 147, 5, 0, 1, 55, 149, 0, 7, 99, 246, 180, 0, 147, 5, 197, 201, 111, 0, 64, 2, 19, 215, 68, 0, 147, 6, 9, 2, 147, 5, 197, 201, 147, 2, 48, 0, 19, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 147, 5, 4, 0, 147, 151, 84, 0, 19, 245, 4, 255, 19, 21, 37, 0, 51, 6, 169, 0, 19, 149, 196, 1, 147, 86, 165, 1, 19, 5, 4, 0, 19, 7, 0, 0, 151, 0, 0, 0, 231, 128, 128, 204, 19, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 19, 4, 6, 0, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 192, 240, 147, 134, 4, 2, 147, 2, 48, 0, 147, 5, 9, 0, 19, 134, 4, 0, 19, 7, 4, 0, 115, 0, 0, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 19, 8, 7, 0, 3, 167, 70, 1, 131, 167, 6, 1, 35, 32, 177, 0, 35, 34, 193, 0, 35, 46, 225, 0, 35, 44, 241, 0, 131, 165, 198, 0, 3, 166, 134, 0, 3, 167, 70, 0, 131, 166, 6, 0, 35, 42, 177, 0, 35, 40, 193, 0, 35, 38, 225, 0, 35, 36, 209, 0, 183, 133, 0, 7, 19, 134, 69, 112, 147, 5, 1, 0, 147, 6, 65, 0, 147, 7, 129, 0, 19, 7, 6, 0, 151, 64, 0, 0, 231, 128, 192, 60, 115, 16, 0, 192, 147, 135, 5, 0, 99, 154, 213, 0, 147, 5, 6, 0, 19, 134, 7, 0, 23, 19, 0, 0, 103, 0, 3, 140, 19, 133, 7, 0, 147, 133, 6, 0, 19, 6, 7, 0, 151, 32, 0, 0, 231, 128, 64, 176, 115, 16, 0, 192, 19, 1, 1, 239, 35, 38, 17, 16, 35, 36, 129, 16, 35, 34, 145, 16, 35, 32, 33, 17, 35, 46, 49, 15, 35, 44, 65, 15, 35, 42, 81, 15, 55, 5, 8, 0, 35, 34, 5, 0, 19, 5, 69, 0, 147, 5, 69, 0, 19, 5, 1, 0, 19, 6, 192, 4, 151, 16, 0, 0, 231, 128, 128, 134, 147, 4, 129, 0, 35, 38, 1, 6, 35, 36, 1, 6, 35, 34, 1, 6, 35, 32, 1, 6, 35, 46, 1, 4, 3, 37, 65, 0, 3, 68, 129, 4, 35, 44, 1, 4, 35, 42, 1, 4, 19, 21, 149, 0, 147, 21, 52, 0, 51, 101, 181, 0, 147, 85, 133, 0, 55, 6, 1, 0, 19, 6, 6, 240, 179, 245, 197, 0, 19, 86, 133, 1, 51, 233, 197, 0, 19, 21, 133, 0, 183, 5, 255, 0, 51, 117, 181, 0, 147, 21, 180, 1, 179, 233, 165, 0, 51, 133, 132, 0, 147, 5, 0, 8, 35, 0, 181, 0, 147, 5, 240, 3, 35, 40, 1, 4, 99, 12, 180, 0, 19, 70, 244, 3, 19, 5, 21, 0, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 64, 110, 19, 117, 132, 3, 147, 5, 128, 3, 179, 234, 41, 1, 99, 24, 181, 18, 3, 37, 1, 0, 183, 149, 0, 7, 19, 132, 197, 201, 19, 9, 4, 0, 99, 4, 5, 0, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 64, 245, 147, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 64, 244, 19, 138, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 210, 147, 4, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 242, 147, 9, 5, 0, 19, 5, 10, 0, 151, 16, 0, 0, 231, 128, 128, 241, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 9, 0, 19, 134, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 19, 5, 1, 7, 19, 6, 128, 3, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 128, 99, 35, 38, 81, 11, 35, 36, 1, 10, 99, 132, 4, 0, 19, 132, 4, 0, 99, 24, 0, 18, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 192, 235, 19, 9, 5, 0, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 192, 234, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 200, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 0, 233, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 232, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 140, 4, 8, 111, 0, 0, 20, 35, 32, 1, 4, 35, 34, 81, 5, 3, 36, 1, 0, 99, 22, 4, 0, 55, 149, 0, 7, 19, 4, 197, 201, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 226, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 225, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 64, 191, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 192, 223, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 192, 222, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 152, 4, 10, 55, 133, 0, 7, 19, 5, 69, 120, 183, 133, 0, 7, 19, 134, 5, 123, 147, 5, 176, 2, 151, 64, 0, 0, 231, 128, 192, 3, 115, 16, 0, 192, 19, 5, 1, 11, 147, 5, 1, 7, 19, 6, 0, 4, 151, 0, 0, 0, 231, 128, 192, 93, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 192, 215, 19, 9, 5, 0, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 192, 214, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 180, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 0, 213, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 212, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 227, 140, 4, 244, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 209, 19, 6, 5, 0, 147, 134, 5, 0, 55, 133, 0, 7, 19, 7, 69, 119, 19, 5, 1, 5, 147, 5, 0, 2, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 1, 5, 3, 38, 65, 5, 131, 38, 129, 5, 3, 39, 193, 5, 131, 39, 1, 6, 3, 40, 65, 6, 131, 40, 129, 6, 3, 35, 193, 6, 147, 2, 16, 0, 19, 5, 0, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 16, 0, 147, 5, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 32, 0, 147, 133, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 48, 0, 147, 5, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 64, 0, 147, 133, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 80, 0, 147, 5, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 96, 0, 147, 133, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 112, 0, 147, 5, 3, 0, 115, 0, 0, 0, 147, 2, 0, 0, 19, 5, 0, 0, 115, 0, 0, 0, 55, 149, 0, 7, 19, 5, 117, 130, 183, 149, 0, 7, 19, 134, 5, 133, 147, 5, 128, 2, 151, 64, 0, 0, 231, 128, 192, 235, 115, 16, 0, 192, 19, 5, 48, 0, 103, 128, 0, 0, 19, 5, 0, 0, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 3, 37, 5, 0, 19, 4, 6, 0, 151, 16, 0, 0, 231, 128, 0, 198, 147, 5, 5, 0, 19, 22, 36, 0, 19, 5, 112, 0, 99, 132, 197, 0, 19, 5, 64, 0, 131, 32, 193, 0, 3, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 6, 64, 0, 99, 100, 182, 0, 147, 5, 64, 0, 19, 214, 21, 0, 183, 86, 85, 85, 147, 134, 86, 85, 51, 118, 214, 0, 51, 134, 197, 64, 183, 54, 51, 51, 147, 134, 54, 51, 51, 119, 214, 0, 19, 86, 38, 0, 51, 118, 214, 0, 51, 6, 199, 0, 147, 86, 70, 0, 51, 6, 214, 0, 183, 22, 15, 15, 147, 134, 246, 240, 51, 118, 214, 0, 183, 6, 1, 1, 147, 134, 22, 16, 51, 6, 214, 2, 19, 86, 134, 1, 147, 6, 246, 255, 179, 54, 208, 0, 55, 7, 0, 128, 51, 7, 183, 64, 51, 55, 167, 0, 179, 230, 230, 0, 99, 150, 6, 2, 147, 6, 16, 0, 99, 4, 214, 0, 147, 5, 0, 0, 51, 5, 181, 0, 19, 5, 245, 255, 179, 5, 176, 64, 51, 117, 181, 0, 19, 85, 37, 0, 23, 19, 0, 0, 103, 0, 195, 192, 19, 1, 1, 255, 55, 149, 0, 7, 19, 5, 5, 134, 183, 149, 0, 7, 147, 134, 133, 136, 183, 149, 0, 7, 19, 135, 197, 143, 147, 5, 112, 2, 19, 6, 129, 0, 151, 64, 0, 0, 231, 128, 64, 251, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 7, 64, 0, 19, 137, 6, 0, 19, 132, 5, 0, 147, 4, 5, 0, 99, 100, 199, 0, 19, 6, 64, 0, 19, 85, 22, 0, 183, 85, 85, 85, 147, 133, 85, 85, 51, 117, 181, 0, 51, 5, 166, 64, 183, 53, 51, 51, 147, 133, 53, 51, 179, 118, 181, 0, 19, 85, 37, 0, 51, 117, 181, 0, 51, 133, 166, 0, 147, 85, 69, 0, 51, 5, 181, 0, 183, 21, 15, 15, 147, 133, 245, 240, 51, 117, 181, 0, 183, 5, 1, 1, 147, 133, 21, 16, 51, 5, 181, 2, 19, 85, 133, 1, 147, 5, 245, 255, 179, 53, 176, 0, 183, 6, 0, 128, 179, 134, 198, 64, 179, 182, 38, 1, 179, 229, 213, 0, 99, 152, 5, 6, 147, 5, 16, 0, 99, 4, 181, 0, 19, 6, 0, 0, 51, 5, 201, 0, 19, 5, 245, 255, 179, 5, 192, 64, 51, 117, 181, 0, 19, 85, 37, 0, 151, 16, 0, 0, 231, 128, 64, 177, 147, 9, 5, 0, 99, 0, 5, 2, 99, 100, 36, 1, 19, 4, 9, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 0, 38, 19, 133, 9, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 55, 149, 0, 7, 19, 5, 5, 134, 183, 149, 0, 7, 147, 134, 133, 136, 183, 149, 0, 7, 19, 135, 197, 143, 147, 5, 112, 2, 19, 6, 129, 0, 151, 64, 0, 0, 231, 128, 192, 231, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 19, 6, 64, 0, 19, 4, 5, 0, 99, 100, 182, 0, 147, 5, 64, 0, 19, 213, 21, 0, 55, 86, 85, 85, 19, 6, 86, 85, 51, 117, 197, 0, 51, 133, 165, 64, 55, 54, 51, 51, 19, 6, 54, 51, 179, 118, 197, 0, 19, 85, 37, 0, 51, 117, 197, 0, 51, 133, 166, 0, 19, 86, 69, 0, 51, 5, 197, 0, 55, 22, 15, 15, 19, 6, 246, 240, 51, 117, 197, 0, 55, 6, 1, 1, 19, 6, 22, 16, 51, 5, 197, 2, 19, 85, 133, 1, 19, 6, 245, 255, 51, 54, 192, 0, 183, 6, 0, 128, 179, 134, 182, 64, 179, 182, 134, 0, 51, 102, 214, 0, 99, 16, 6, 6, 19, 6, 16, 0, 99, 4, 197, 0, 147, 5, 0, 0, 51, 5, 180, 0, 19, 5, 245, 255, 179, 5, 176, 64, 51, 117, 181, 0, 19, 85, 37, 0, 151, 16, 0, 0, 231, 128, 0, 159, 147, 4, 5, 0, 99, 12, 5, 0, 19, 133, 4, 0, 147, 5, 0, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 192, 4, 19, 133, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 55, 149, 0, 7, 19, 5, 5, 134, 183, 149, 0, 7, 147, 134, 133, 136, 183, 149, 0, 7, 19, 135, 197, 143, 147, 5, 112, 2, 19, 6, 1, 0, 151, 64, 0, 0, 231, 128, 128, 214, 115, 16, 0, 192, 99, 10, 6, 14, 35, 0, 181, 0, 179, 6, 166, 0, 19, 7, 48, 0, 163, 143, 182, 254, 99, 96, 230, 14, 163, 0, 181, 0, 35, 1, 181, 0, 35, 143, 182, 254, 19, 7, 112, 0, 163, 142, 182, 254, 99, 100, 230, 12, 163, 1, 181, 0, 147, 7, 144, 0, 35, 142, 182, 254, 99, 108, 246, 10, 179, 6, 160, 64, 19, 247, 54, 0, 179, 6, 229, 0, 51, 6, 230, 64, 19, 118, 198, 255, 147, 245, 245, 15, 55, 7, 1, 1, 19, 7, 23, 16, 179, 133, 229, 2, 35, 160, 182, 0, 51, 135, 198, 0, 35, 46, 183, 254, 99, 98, 246, 8, 35, 162, 182, 0, 35, 164, 182, 0, 35, 42, 183, 254, 147, 7, 144, 1, 35, 44, 183, 254, 99, 102, 246, 6, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 34, 183, 254, 35, 36, 183, 254, 35, 38, 183, 254, 147, 247, 70, 0, 147, 231, 135, 1, 51, 6, 246, 64, 19, 8, 0, 2, 35, 40, 183, 254, 99, 108, 6, 3, 179, 134, 246, 0, 19, 7, 240, 1, 35, 160, 182, 0, 35, 162, 182, 0, 35, 164, 182, 0, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 174, 182, 0, 19, 6, 6, 254, 147, 134, 6, 2, 227, 108, 199, 252, 103, 128, 0, 0, 147, 246, 53, 0, 147, 182, 22, 0, 19, 55, 22, 0, 179, 230, 230, 0, 99, 156, 6, 14, 147, 135, 21, 0, 19, 8, 5, 0, 131, 136, 5, 0, 19, 135, 21, 0, 147, 6, 24, 0, 35, 0, 24, 1, 19, 6, 246, 255, 147, 245, 55, 0, 179, 53, 176, 0, 51, 56, 192, 0, 179, 248, 5, 1, 147, 135, 23, 0, 147, 5, 7, 0, 19, 136, 6, 0, 227, 152, 8, 252, 147, 245, 54, 0, 99, 130, 5, 12, 147, 7, 0, 2, 99, 110, 246, 34, 147, 7, 48, 0, 99, 134, 245, 18, 147, 7, 32, 0, 99, 130, 245, 26, 147, 7, 16, 0, 99, 146, 245, 34, 131, 39, 7, 0, 35, 128, 246, 0, 147, 213, 135, 0, 163, 128, 182, 0, 19, 216, 7, 1, 147, 133, 54, 0, 35, 129, 6, 1, 19, 6, 214, 255, 147, 6, 7, 1, 19, 7, 0, 1, 3, 168, 70, 255, 147, 215, 135, 1, 147, 24, 136, 0, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 1, 19, 152, 130, 0, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 1, 147, 146, 136, 0, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 1, 147, 152, 135, 0, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 54, 255, 111, 0, 0, 25, 147, 6, 5, 0, 19, 135, 5, 0, 147, 245, 54, 0, 227, 146, 5, 244, 147, 5, 0, 1, 99, 108, 182, 2, 147, 5, 240, 0, 131, 39, 7, 0, 3, 40, 71, 0, 131, 40, 135, 0, 131, 34, 199, 0, 35, 160, 246, 0, 35, 162, 6, 1, 35, 164, 22, 1, 35, 166, 86, 0, 19, 7, 7, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 234, 197, 252, 147, 117, 134, 0, 99, 142, 5, 0, 131, 37, 7, 0, 131, 39, 71, 0, 35, 160, 182, 0, 35, 162, 246, 0, 147, 134, 134, 0, 19, 7, 135, 0, 147, 117, 70, 0, 99, 130, 5, 22, 131, 37, 7, 0, 35, 160, 182, 0, 147, 134, 70, 0, 19, 7, 71, 0, 111, 0, 0, 21, 131, 39, 7, 0, 147, 133, 22, 0, 35, 128, 246, 0, 19, 6, 246, 255, 147, 6, 7, 1, 19, 7, 32, 1, 3, 168, 70, 255, 147, 215, 135, 0, 147, 24, 136, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 0, 19, 152, 130, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 0, 147, 146, 136, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 0, 147, 152, 135, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 22, 255, 111, 0, 128, 8, 131, 39, 7, 0, 35, 128, 246, 0, 19, 216, 135, 0, 147, 133, 38, 0, 163, 128, 6, 1, 19, 6, 230, 255, 147, 6, 7, 1, 19, 7, 16, 1, 3, 168, 70, 255, 147, 215, 7, 1, 147, 24, 8, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 8, 1, 19, 152, 2, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 2, 1, 147, 146, 8, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 8, 1, 147, 152, 7, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 38, 255, 147, 134, 5, 0, 147, 117, 6, 1, 99, 146, 5, 8, 147, 117, 134, 0, 99, 152, 5, 16, 147, 117, 70, 0, 99, 136, 5, 2, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 19, 7, 71, 0, 147, 135, 70, 0, 163, 129, 182, 0, 147, 134, 7, 0, 147, 117, 38, 0, 99, 152, 5, 0, 147, 117, 22, 0, 99, 150, 5, 2, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 35, 128, 182, 0, 19, 7, 39, 0, 147, 133, 38, 0, 163, 128, 246, 0, 147, 134, 5, 0, 147, 117, 22, 0, 227, 142, 5, 252, 131, 5, 7, 0, 35, 128, 182, 0, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 131, 5, 135, 0, 3, 8, 151, 0, 163, 131, 246, 0, 131, 7, 167, 0, 35, 132, 182, 0, 163, 132, 6, 1, 131, 5, 183, 0, 35, 133, 246, 0, 131, 7, 199, 0, 3, 8, 215, 0, 163, 133, 182, 0, 131, 5, 231, 0, 35, 134, 246, 0, 163, 134, 6, 1, 131, 7, 247, 0, 35, 135, 182, 0, 19, 7, 7, 1, 147, 133, 6, 1, 163, 135, 246, 0, 147, 134, 5, 0, 147, 117, 134, 0, 227, 140, 5, 238, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 19, 7, 135, 0, 147, 133, 134, 0, 163, 131, 246, 0, 147, 134, 5, 0, 147, 117, 70, 0, 227, 150, 5, 234, 111, 240, 95, 237, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 99, 138, 5, 8, 19, 132, 5, 0, 19, 9, 5, 0, 147, 138, 53, 0, 147, 9, 64, 0, 99, 246, 58, 1, 147, 4, 0, 0, 111, 0, 64, 3, 99, 206, 10, 8, 147, 244, 202, 255, 99, 202, 4, 8, 19, 197, 250, 255, 19, 85, 213, 1, 19, 122, 69, 0, 19, 133, 4, 0, 147, 5, 10, 0, 151, 224, 255, 255, 231, 128, 128, 20, 99, 14, 5, 8, 147, 9, 5, 0, 147, 213, 42, 0, 19, 133, 9, 0, 151, 0, 0, 0, 231, 128, 64, 52, 99, 228, 132, 6, 19, 5, 9, 0, 147, 133, 9, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 64, 182, 19, 5, 64, 0, 99, 236, 170, 0, 19, 6, 64, 0, 19, 133, 9, 0, 147, 133, 4, 0, 151, 224, 255, 255, 231, 128, 192, 14, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 0, 101, 115, 16, 0, 192, 55, 149, 0, 7, 19, 6, 197, 150, 19, 5, 4, 0, 147, 133, 4, 0, 151, 32, 0, 0, 231, 128, 0, 12, 115, 16, 0, 192, 19, 133, 4, 0, 147, 5, 10, 0, 151, 0, 0, 0, 231, 128, 64, 102, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 128, 43, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 19, 132, 5, 0, 147, 4, 5, 0, 19, 133, 5, 0, 151, 48, 0, 0, 231, 128, 0, 180, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 19, 0, 0, 103, 0, 195, 159, 19, 5, 4, 0, 151, 48, 0, 0, 231, 128, 0, 178, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 19, 0, 0, 103, 0, 195, 167, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 67, 0, 0, 103, 0, 67, 186, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 19, 132, 5, 0, 147, 4, 5, 0, 19, 133, 5, 0, 151, 48, 0, 0, 231, 128, 128, 170, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 19, 0, 0, 103, 0, 67, 150, 19, 5, 4, 0, 151, 48, 0, 0, 231, 128, 128, 168, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 19, 0, 0, 103, 0, 67, 158, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 67, 0, 0, 103, 0, 131, 179, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 19, 4, 5, 0, 55, 149, 0, 7, 19, 6, 197, 151, 19, 5, 1, 0, 147, 6, 80, 0, 151, 48, 0, 0, 231, 128, 128, 162, 3, 37, 4, 0, 99, 66, 5, 2, 35, 36, 161, 0, 55, 149, 0, 7, 147, 5, 5, 157, 55, 149, 0, 7, 19, 7, 133, 157, 19, 5, 1, 0, 19, 6, 128, 0, 111, 0, 64, 11, 183, 5, 0, 128, 179, 69, 181, 0, 19, 6, 224, 0, 99, 108, 182, 0, 55, 134, 0, 0, 19, 6, 182, 159, 51, 86, 166, 0, 19, 118, 22, 0, 99, 18, 6, 2, 35, 36, 161, 0, 55, 149, 0, 7, 147, 5, 21, 152, 55, 149, 0, 7, 19, 7, 5, 153, 19, 5, 1, 0, 19, 6, 192, 0, 111, 0, 0, 7, 147, 149, 37, 0, 55, 150, 0, 7, 19, 6, 70, 194, 51, 6, 182, 0, 183, 150, 0, 7, 147, 134, 6, 198, 179, 133, 182, 0, 131, 165, 5, 0, 3, 38, 6, 0, 35, 36, 177, 0, 35, 38, 193, 0, 35, 42, 161, 0, 55, 149, 0, 7, 147, 5, 5, 154, 55, 149, 0, 7, 19, 7, 5, 153, 19, 5, 1, 0, 19, 6, 208, 0, 147, 6, 65, 1, 151, 16, 0, 0, 231, 128, 128, 16, 55, 149, 0, 7, 147, 5, 213, 154, 55, 149, 0, 7, 19, 7, 133, 155, 19, 5, 1, 0, 19, 6, 176, 0, 147, 6, 129, 0, 151, 16, 0, 0, 231, 128, 64, 14, 19, 5, 1, 0, 151, 16, 0, 0, 231, 128, 0, 48, 131, 32, 193, 1, 3, 36, 129, 1, 19, 1, 1, 2, 103, 128, 0, 0, 3, 38, 5, 0, 131, 38, 69, 0, 19, 135, 5, 0, 19, 5, 6, 0, 147, 133, 6, 0, 19, 6, 7, 0, 23, 51, 0, 0, 103, 0, 195, 149, 147, 5, 5, 2, 103, 128, 0, 0, 103, 128, 0, 0, 147, 5, 0, 2, 103, 128, 0, 0, 147, 5, 128, 0, 103, 128, 0, 0, 55, 150, 0, 7, 19, 6, 182, 212, 147, 2, 32, 0, 115, 0, 0, 0, 103, 128, 0, 0, 55, 150, 0, 7, 19, 6, 134, 215, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 55, 149, 0, 7, 19, 5, 53, 210, 183, 149, 0, 7, 19, 134, 69, 218, 147, 5, 128, 2, 151, 48, 0, 0, 231, 128, 128, 35, 115, 16, 0, 192, 147, 7, 6, 0, 19, 23, 38, 0, 55, 150, 0, 7, 19, 6, 70, 219, 147, 2, 32, 0, 147, 6, 5, 0, 19, 133, 5, 0, 147, 133, 7, 0, 115, 0, 0, 0, 99, 100, 167, 0, 103, 128, 0, 0, 55, 149, 0, 7, 19, 5, 245, 221, 183, 149, 0, 7, 19, 134, 69, 225, 147, 5, 64, 3, 151, 48, 0, 0, 231, 128, 192, 30, 115, 16, 0, 192, 147, 7, 6, 0, 55, 150, 0, 7, 19, 6, 70, 226, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 103, 128, 0, 0, 183, 5, 8, 0, 131, 166, 5, 0, 51, 134, 166, 64, 99, 236, 198, 0, 19, 149, 38, 0, 183, 6, 0, 8, 51, 133, 166, 64, 35, 160, 197, 0, 103, 128, 0, 0, 55, 149, 0, 7, 19, 5, 5, 229, 183, 149, 0, 7, 19, 134, 5, 230, 147, 5, 224, 0, 151, 32, 0, 0, 231, 128, 0, 134, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 106, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 120, 150, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 4, 6, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 19, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 192, 27, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 128, 29, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 138, 5, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 248, 149, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 132, 5, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 5, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 0, 14, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 15, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 10, 6, 2, 99, 202, 4, 2, 3, 165, 134, 0, 99, 12, 5, 2, 131, 165, 70, 0, 99, 136, 5, 2, 3, 165, 6, 0, 19, 6, 16, 0, 147, 134, 4, 0, 151, 224, 255, 255, 231, 128, 64, 174, 99, 18, 5, 4, 111, 0, 192, 2, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 192, 3, 99, 132, 4, 2, 147, 5, 16, 0, 19, 133, 4, 0, 151, 224, 255, 255, 231, 128, 128, 170, 99, 28, 5, 0, 35, 34, 148, 0, 147, 5, 16, 0, 35, 36, 180, 0, 111, 0, 64, 1, 19, 5, 16, 0, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 254, 55, 149, 0, 7, 19, 5, 5, 244, 35, 36, 161, 0, 19, 5, 16, 0, 35, 38, 161, 0, 35, 40, 1, 0, 55, 149, 0, 7, 19, 5, 5, 231, 35, 44, 161, 0, 35, 46, 1, 0, 55, 149, 0, 7, 147, 5, 133, 244, 19, 5, 129, 0, 151, 48, 0, 0, 231, 128, 192, 233, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 151, 224, 255, 255, 231, 128, 64, 163, 115, 16, 0, 192, 151, 224, 255, 255, 231, 128, 0, 118, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 19, 132, 5, 0, 131, 165, 69, 0, 147, 4, 5, 0, 99, 134, 5, 4, 3, 37, 4, 0, 19, 9, 0, 0, 19, 6, 69, 0, 147, 150, 53, 0, 3, 39, 6, 0, 51, 9, 39, 1, 147, 134, 134, 255, 19, 6, 134, 0, 227, 152, 6, 254, 3, 38, 68, 1, 99, 0, 6, 4, 99, 132, 5, 2, 3, 37, 69, 0, 19, 53, 21, 0, 147, 53, 9, 1, 51, 117, 181, 0, 99, 10, 5, 0, 111, 0, 192, 1, 3, 37, 68, 1, 19, 9, 0, 0, 99, 4, 5, 4, 19, 5, 9, 0, 51, 9, 41, 1, 99, 118, 169, 0, 19, 9, 0, 0, 111, 0, 64, 3, 99, 8, 9, 2, 99, 72, 9, 12, 147, 5, 16, 0, 19, 5, 9, 0, 151, 224, 255, 255, 231, 128, 192, 149, 99, 30, 5, 0, 147, 5, 16, 0, 19, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 5, 16, 0, 35, 160, 164, 0, 35, 162, 36, 1, 3, 37, 68, 1, 131, 37, 4, 1, 35, 164, 4, 0, 35, 38, 145, 0, 35, 34, 161, 2, 35, 32, 177, 2, 3, 37, 196, 0, 131, 37, 132, 0, 3, 38, 68, 0, 131, 38, 4, 0, 35, 46, 161, 0, 35, 44, 177, 0, 35, 42, 193, 0, 35, 40, 209, 0, 55, 149, 0, 7, 147, 5, 133, 6, 19, 5, 193, 0, 19, 6, 1, 1, 151, 32, 0, 0, 231, 128, 128, 165, 99, 30, 5, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 19, 1, 1, 4, 103, 128, 0, 0, 55, 149, 0, 7, 19, 5, 133, 245, 183, 149, 0, 7, 147, 134, 197, 248, 183, 149, 0, 7, 19, 135, 133, 5, 147, 5, 48, 3, 19, 6, 129, 2, 151, 48, 0, 0, 231, 128, 192, 246, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 64, 227, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 36, 5, 0, 19, 5, 0, 8, 147, 132, 5, 0, 99, 252, 165, 2, 131, 37, 132, 0, 3, 37, 68, 0, 99, 154, 165, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 132, 0, 3, 37, 4, 0, 51, 5, 181, 0, 35, 0, 149, 0, 3, 37, 132, 0, 19, 5, 21, 0, 111, 0, 64, 13, 19, 213, 180, 0, 35, 38, 1, 0, 99, 18, 5, 2, 19, 213, 100, 0, 19, 101, 5, 12, 35, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 6, 161, 0, 147, 4, 32, 0, 111, 0, 192, 7, 19, 213, 4, 1, 99, 26, 5, 2, 19, 213, 196, 0, 19, 101, 5, 14, 35, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 35, 7, 161, 0, 147, 4, 48, 0, 111, 0, 64, 4, 19, 149, 180, 0, 19, 85, 213, 1, 19, 101, 5, 15, 35, 6, 161, 0, 19, 149, 228, 0, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 35, 7, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 7, 161, 0, 147, 4, 64, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 99, 96, 149, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 5, 193, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 29, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 192, 166, 3, 41, 132, 0, 111, 240, 223, 250, 19, 1, 1, 253, 35, 38, 17, 2, 3, 37, 5, 0, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 149, 0, 7, 147, 5, 133, 6, 19, 5, 193, 0, 19, 6, 1, 1, 151, 32, 0, 0, 231, 128, 64, 132, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 3, 36, 5, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 98, 197, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 133, 9, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 0, 14, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 151, 3, 41, 132, 0, 111, 240, 159, 250, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 5, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 149, 0, 7, 19, 6, 5, 21, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 32, 0, 0, 231, 128, 0, 152, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 149, 0, 7, 19, 134, 5, 20, 147, 5, 0, 8, 151, 16, 0, 0, 231, 128, 64, 84, 115, 16, 0, 192, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 3, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 149, 0, 7, 19, 6, 5, 21, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 32, 0, 0, 231, 128, 0, 141, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 149, 0, 7, 19, 134, 5, 20, 147, 5, 0, 8, 151, 16, 0, 0, 231, 128, 64, 73, 115, 16, 0, 192, 147, 5, 0, 0, 19, 22, 181, 0, 147, 8, 16, 2, 183, 150, 0, 7, 147, 134, 134, 47, 19, 7, 16, 0, 147, 7, 240, 15, 19, 8, 16, 2, 111, 0, 0, 1, 19, 136, 8, 0, 179, 8, 184, 64, 99, 246, 5, 5, 147, 216, 24, 0, 179, 136, 184, 0, 147, 146, 40, 0, 179, 130, 86, 0, 131, 162, 2, 0, 19, 147, 178, 0, 147, 2, 240, 255, 99, 102, 195, 0, 179, 66, 195, 0, 179, 50, 80, 0, 227, 134, 226, 252, 147, 245, 242, 15, 99, 154, 245, 0, 147, 133, 24, 0, 179, 8, 184, 64, 227, 226, 5, 253, 111, 0, 128, 0, 147, 133, 24, 0, 147, 6, 0, 2, 99, 236, 182, 12, 19, 150, 37, 0, 55, 151, 0, 7, 19, 7, 135, 47, 51, 7, 199, 0, 19, 6, 112, 45, 99, 134, 213, 0, 3, 38, 71, 0, 19, 86, 86, 1, 147, 134, 245, 255, 99, 246, 213, 0, 147, 7, 0, 0, 111, 0, 128, 2, 147, 5, 16, 2, 99, 254, 182, 10, 147, 149, 38, 0, 183, 150, 0, 7, 147, 134, 134, 47, 179, 133, 182, 0, 131, 165, 5, 0, 147, 149, 181, 0, 147, 215, 181, 0, 131, 37, 7, 0, 147, 213, 85, 1, 147, 198, 245, 255, 179, 6, 214, 0, 99, 134, 6, 4, 19, 7, 112, 45, 147, 134, 5, 0, 99, 100, 183, 0, 147, 6, 112, 45, 19, 7, 0, 0, 179, 7, 245, 64, 19, 5, 246, 255, 55, 150, 0, 7, 19, 6, 198, 55, 99, 134, 182, 2, 51, 8, 182, 0, 3, 72, 8, 0, 51, 7, 7, 1, 99, 234, 231, 0, 147, 133, 21, 0, 227, 20, 181, 254, 19, 117, 21, 0, 103, 128, 0, 0, 19, 245, 21, 0, 103, 128, 0, 0, 55, 149, 0, 7, 19, 6, 69, 101, 147, 5, 112, 45, 19, 133, 6, 0, 151, 48, 0, 0, 231, 128, 128, 135, 115, 16, 0, 192, 55, 149, 0, 7, 19, 6, 69, 101, 19, 5, 16, 2, 147, 5, 16, 2, 151, 48, 0, 0, 231, 128, 192, 133, 115, 16, 0, 192, 55, 149, 0, 7, 19, 6, 133, 46, 147, 5, 16, 2, 19, 133, 6, 0, 151, 48, 0, 0, 231, 128, 0, 132, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 133, 0, 7, 19, 5, 5, 246, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 149, 0, 7, 19, 5, 133, 106, 35, 36, 161, 0, 19, 5, 48, 0, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 1, 2, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 32, 0, 0, 231, 128, 128, 117, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 249, 35, 36, 193, 0, 147, 7, 16, 16, 35, 38, 209, 0, 99, 226, 245, 4, 19, 8, 240, 251, 179, 8, 245, 0, 131, 136, 248, 255, 147, 135, 247, 255, 227, 90, 24, 255, 99, 254, 183, 2, 51, 8, 245, 0, 3, 8, 8, 0, 147, 8, 240, 251, 99, 212, 8, 17, 35, 40, 161, 0, 35, 42, 241, 0, 183, 151, 0, 7, 147, 135, 7, 110, 19, 8, 80, 0, 111, 0, 192, 2, 19, 8, 0, 0, 35, 40, 161, 0, 35, 42, 177, 0, 111, 0, 64, 1, 99, 158, 245, 12, 19, 8, 0, 0, 35, 40, 161, 0, 35, 42, 241, 0, 183, 151, 0, 7, 147, 135, 7, 108, 35, 44, 241, 0, 179, 183, 197, 0, 179, 184, 213, 0, 179, 231, 23, 1, 35, 46, 1, 1, 99, 140, 7, 4, 99, 228, 197, 0, 19, 134, 6, 0, 35, 36, 193, 2, 19, 5, 129, 2, 35, 36, 161, 4, 55, 133, 0, 7, 19, 5, 5, 246, 35, 38, 161, 4, 19, 5, 1, 1, 35, 40, 161, 4, 55, 117, 0, 7, 19, 5, 5, 124, 35, 42, 161, 4, 147, 5, 129, 1, 35, 44, 177, 4, 35, 46, 161, 4, 55, 149, 0, 7, 19, 5, 133, 112, 35, 40, 161, 2, 19, 5, 48, 0, 111, 0, 128, 39, 99, 246, 198, 6, 19, 5, 129, 0, 35, 36, 161, 4, 55, 133, 0, 7, 19, 5, 5, 246, 35, 38, 161, 4, 147, 5, 193, 0, 35, 40, 177, 4, 35, 42, 161, 4, 19, 5, 1, 1, 35, 44, 161, 4, 55, 117, 0, 7, 19, 5, 5, 124, 35, 46, 161, 4, 147, 5, 129, 1, 35, 32, 177, 6, 35, 34, 161, 6, 55, 149, 0, 7, 19, 5, 5, 115, 35, 40, 161, 2, 19, 5, 64, 0, 111, 0, 0, 34, 19, 6, 0, 0, 147, 134, 7, 0, 151, 0, 0, 0, 231, 128, 192, 234, 115, 16, 0, 192, 99, 20, 6, 4, 19, 134, 6, 0, 35, 32, 193, 2, 147, 134, 5, 0, 99, 124, 182, 6, 147, 7, 214, 255, 147, 6, 0, 0, 99, 100, 246, 0, 147, 134, 7, 0, 147, 7, 22, 0, 99, 252, 215, 2, 55, 165, 0, 7, 19, 6, 5, 137, 19, 133, 6, 0, 147, 133, 7, 0, 151, 16, 0, 0, 231, 128, 128, 29, 115, 16, 0, 192, 99, 118, 182, 6, 179, 7, 197, 0, 131, 135, 7, 0, 19, 8, 0, 252, 227, 214, 7, 251, 111, 240, 223, 250, 179, 6, 213, 0, 179, 7, 245, 0, 179, 134, 215, 64, 147, 7, 0, 252, 51, 8, 197, 0, 3, 8, 8, 0, 147, 134, 246, 255, 19, 6, 246, 255, 227, 72, 248, 254, 147, 6, 22, 0, 99, 140, 6, 2, 99, 248, 182, 2, 51, 6, 213, 0, 3, 6, 6, 0, 147, 7, 240, 251, 99, 194, 199, 2, 19, 134, 6, 0, 147, 134, 5, 0, 151, 0, 0, 0, 231, 128, 128, 223, 115, 16, 0, 192, 227, 8, 182, 244, 111, 240, 31, 245, 227, 146, 182, 254, 99, 144, 182, 2, 55, 149, 0, 7, 19, 5, 69, 122, 147, 5, 176, 2, 19, 6, 7, 0, 151, 32, 0, 0, 231, 128, 64, 85, 115, 16, 0, 192, 51, 5, 213, 0, 3, 6, 5, 0, 147, 117, 246, 15, 99, 72, 6, 0, 35, 34, 177, 2, 147, 5, 16, 0, 111, 0, 128, 9, 131, 71, 21, 0, 19, 246, 245, 1, 19, 8, 240, 13, 147, 247, 247, 3, 99, 114, 184, 4, 3, 72, 37, 0, 147, 151, 103, 0, 19, 120, 248, 3, 147, 8, 0, 15, 179, 231, 7, 1, 99, 236, 21, 3, 3, 69, 53, 0, 147, 21, 214, 1, 147, 213, 181, 0, 19, 150, 103, 0, 19, 117, 245, 3, 51, 101, 166, 0, 51, 101, 181, 0, 183, 5, 17, 0, 227, 14, 181, 246, 111, 0, 128, 1, 19, 21, 102, 0, 51, 101, 245, 0, 111, 0, 192, 0, 19, 21, 198, 0, 51, 229, 167, 0, 35, 34, 161, 2, 19, 6, 0, 8, 147, 5, 16, 0, 99, 96, 197, 2, 19, 86, 181, 0, 147, 5, 32, 0, 99, 10, 6, 0, 19, 85, 5, 1, 19, 53, 21, 0, 147, 5, 64, 0, 179, 133, 165, 64, 51, 133, 213, 0, 35, 36, 209, 2, 35, 38, 161, 2, 19, 5, 1, 2, 35, 36, 161, 4, 55, 133, 0, 7, 19, 5, 5, 246, 35, 38, 161, 4, 19, 5, 65, 2, 35, 40, 161, 4, 55, 117, 0, 7, 19, 5, 69, 68, 35, 42, 161, 4, 19, 5, 129, 2, 35, 44, 161, 4, 55, 101, 0, 7, 19, 5, 133, 8, 35, 46, 161, 4, 19, 5, 1, 1, 35, 32, 161, 6, 55, 117, 0, 7, 19, 5, 5, 124, 35, 34, 161, 6, 147, 5, 129, 1, 35, 36, 177, 6, 35, 38, 161, 6, 55, 149, 0, 7, 19, 5, 197, 119, 35, 40, 161, 2, 19, 5, 80, 0, 35, 42, 161, 2, 35, 44, 1, 2, 147, 5, 129, 4, 35, 32, 177, 4, 35, 34, 161, 4, 19, 5, 1, 3, 147, 5, 7, 0, 151, 32, 0, 0, 231, 128, 128, 60, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 4, 6, 0, 147, 132, 5, 0, 147, 9, 0, 0, 19, 11, 0, 0, 147, 13, 0, 0, 3, 42, 133, 0, 3, 41, 5, 0, 3, 37, 69, 0, 35, 36, 161, 0, 55, 133, 0, 7, 19, 5, 197, 49, 35, 34, 161, 0, 147, 11, 128, 0, 19, 12, 160, 0, 147, 12, 16, 0, 111, 0, 0, 3, 51, 5, 182, 0, 3, 69, 245, 255, 19, 5, 101, 255, 19, 53, 21, 0, 35, 0, 170, 0, 3, 37, 129, 0, 131, 38, 197, 0, 19, 5, 9, 0, 231, 128, 6, 0, 147, 9, 13, 0, 99, 28, 5, 16, 19, 245, 253, 15, 99, 20, 5, 16, 99, 120, 100, 5, 147, 13, 16, 0, 19, 141, 9, 0, 147, 10, 4, 0, 99, 138, 137, 14, 3, 69, 10, 0, 99, 0, 5, 2, 3, 37, 129, 0, 131, 38, 197, 0, 19, 6, 64, 0, 19, 5, 9, 0, 131, 37, 65, 0, 231, 128, 6, 0, 99, 28, 5, 12, 51, 134, 58, 65, 179, 133, 52, 1, 227, 150, 58, 249, 19, 5, 0, 0, 111, 240, 95, 249, 227, 108, 100, 251, 51, 6, 100, 65, 179, 134, 100, 1, 99, 120, 118, 3, 147, 5, 0, 0, 99, 0, 6, 4, 51, 133, 182, 0, 3, 69, 5, 0, 99, 0, 133, 5, 147, 133, 21, 0, 227, 24, 182, 254, 19, 5, 0, 0, 147, 5, 6, 0, 99, 10, 149, 3, 111, 0, 192, 6, 19, 5, 160, 0, 147, 133, 6, 0, 151, 16, 0, 0, 231, 128, 192, 160, 99, 14, 149, 1, 111, 0, 64, 5, 19, 5, 0, 0, 99, 8, 149, 1, 111, 0, 128, 4, 19, 5, 16, 0, 99, 16, 149, 5, 51, 5, 187, 0, 19, 11, 21, 0, 147, 53, 27, 0, 51, 54, 100, 1, 179, 229, 197, 0, 227, 146, 5, 248, 51, 133, 164, 0, 3, 69, 5, 0, 227, 28, 133, 247, 147, 13, 0, 0, 19, 13, 11, 0, 147, 10, 11, 0, 3, 69, 10, 0, 227, 26, 5, 242, 111, 240, 223, 244, 19, 11, 4, 0, 147, 13, 16, 0, 19, 141, 9, 0, 147, 10, 4, 0, 227, 154, 137, 240, 19, 5, 0, 0, 111, 0, 128, 0, 19, 5, 16, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 19, 1, 1, 250, 35, 46, 17, 4, 35, 44, 129, 4, 35, 42, 145, 4, 35, 40, 33, 5, 35, 38, 49, 5, 35, 36, 65, 5, 35, 34, 81, 5, 35, 32, 97, 5, 35, 46, 113, 3, 19, 4, 5, 0, 3, 69, 69, 0, 147, 11, 16, 0, 19, 11, 16, 0, 99, 14, 5, 2, 35, 2, 100, 1, 163, 2, 116, 1, 19, 5, 4, 0, 131, 32, 193, 5, 3, 36, 129, 5, 131, 36, 65, 5, 3, 41, 1, 5, 131, 41, 193, 4, 3, 42, 129, 4, 131, 42, 65, 4, 3, 43, 1, 4, 131, 43, 193, 3, 19, 1, 1, 6, 103, 128, 0, 0, 19, 9, 7, 0, 147, 132, 6, 0, 19, 10, 6, 0, 147, 138, 5, 0, 131, 41, 4, 0, 131, 197, 9, 0, 3, 69, 84, 0, 147, 245, 69, 0, 99, 156, 5, 0, 19, 54, 21, 0, 99, 6, 5, 16, 55, 165, 0, 7, 147, 5, 53, 140, 111, 0, 128, 16, 99, 20, 5, 2, 131, 165, 201, 1, 3, 165, 137, 1, 131, 166, 197, 0, 183, 165, 0, 7, 147, 133, 197, 139, 19, 6, 48, 0, 231, 128, 6, 0, 19, 11, 16, 0, 227, 22, 5, 246, 19, 11, 16, 0, 163, 11, 97, 1, 3, 165, 137, 1, 131, 165, 201, 1, 35, 36, 161, 0, 35, 38, 177, 0, 19, 5, 113, 1, 35, 40, 161, 0, 3, 165, 9, 0, 131, 165, 73, 0, 3, 134, 9, 2, 131, 166, 137, 0, 3, 167, 201, 0, 131, 167, 9, 1, 3, 168, 73, 1, 35, 44, 161, 0, 35, 46, 177, 0, 35, 12, 193, 2, 35, 32, 209, 2, 35, 34, 225, 2, 35, 36, 241, 2, 35, 38, 1, 3, 19, 5, 129, 0, 35, 40, 161, 2, 55, 165, 0, 7, 19, 5, 5, 189, 35, 42, 161, 2, 19, 5, 129, 0, 147, 133, 10, 0, 19, 6, 10, 0, 151, 0, 0, 0, 231, 128, 192, 203, 227, 20, 5, 238, 55, 165, 0, 7, 147, 5, 245, 139, 19, 5, 129, 0, 19, 6, 32, 0, 151, 0, 0, 0, 231, 128, 0, 202, 227, 22, 5, 236, 3, 38, 201, 0, 147, 5, 129, 1, 19, 133, 4, 0, 231, 0, 6, 0, 227, 28, 5, 234, 131, 37, 65, 3, 3, 37, 1, 3, 131, 166, 197, 0, 183, 165, 0, 7, 147, 133, 21, 140, 19, 6, 32, 0, 231, 128, 6, 0, 111, 0, 192, 7, 55, 165, 0, 7, 147, 5, 85, 140, 131, 166, 201, 1, 3, 165, 137, 1, 131, 166, 198, 0, 19, 102, 38, 0, 231, 128, 6, 0, 19, 11, 16, 0, 227, 26, 5, 230, 131, 165, 201, 1, 3, 165, 137, 1, 131, 166, 197, 0, 147, 133, 10, 0, 19, 6, 10, 0, 231, 128, 6, 0, 19, 11, 16, 0, 227, 26, 5, 228, 131, 165, 201, 1, 3, 165, 137, 1, 131, 166, 197, 0, 183, 165, 0, 7, 147, 133, 245, 139, 19, 6, 32, 0, 231, 128, 6, 0, 19, 11, 16, 0, 227, 24, 5, 226, 3, 38, 201, 0, 19, 133, 4, 0, 147, 133, 9, 0, 231, 0, 6, 0, 19, 11, 5, 0, 111, 240, 159, 225, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 19, 4, 5, 0, 3, 69, 85, 0, 131, 69, 68, 0, 99, 14, 5, 4, 19, 5, 16, 0, 99, 150, 5, 4, 3, 37, 4, 0, 131, 69, 5, 0, 147, 245, 69, 0, 99, 144, 5, 2, 131, 37, 197, 1, 3, 37, 133, 1, 131, 166, 197, 0, 183, 165, 0, 7, 147, 133, 149, 140, 19, 6, 32, 0, 111, 0, 192, 1, 131, 37, 197, 1, 3, 37, 133, 1, 131, 166, 197, 0, 183, 165, 0, 7, 147, 133, 133, 140, 19, 6, 16, 0, 231, 128, 6, 0, 35, 2, 164, 0, 147, 5, 5, 0, 51, 53, 176, 0, 131, 32, 193, 0, 3, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 19, 6, 0, 8, 35, 36, 1, 0, 99, 248, 197, 0, 35, 4, 177, 0, 19, 6, 16, 0, 111, 0, 64, 10, 19, 214, 181, 0, 99, 18, 6, 2, 19, 214, 101, 0, 19, 102, 6, 12, 35, 4, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 163, 4, 177, 0, 19, 6, 32, 0, 111, 0, 192, 7, 19, 214, 5, 1, 99, 26, 6, 2, 19, 214, 197, 0, 19, 102, 6, 14, 35, 4, 193, 0, 19, 150, 69, 1, 19, 86, 166, 1, 19, 102, 6, 8, 163, 4, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 35, 5, 177, 0, 19, 6, 48, 0, 111, 0, 64, 4, 19, 150, 181, 0, 19, 86, 214, 1, 19, 102, 6, 15, 35, 4, 193, 0, 19, 150, 229, 0, 19, 86, 166, 1, 19, 102, 6, 8, 163, 4, 193, 0, 19, 150, 69, 1, 19, 86, 166, 1, 19, 102, 6, 8, 35, 5, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 163, 5, 177, 0, 19, 6, 64, 0, 147, 5, 129, 0, 151, 0, 0, 0, 231, 128, 64, 169, 131, 32, 193, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 165, 0, 7, 147, 5, 197, 140, 19, 5, 193, 0, 19, 6, 1, 1, 151, 16, 0, 0, 231, 128, 192, 174, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 3, 37, 5, 0, 23, 3, 0, 0, 103, 0, 195, 161, 19, 1, 1, 255, 35, 38, 17, 0, 3, 37, 5, 0, 19, 6, 0, 8, 35, 36, 1, 0, 99, 248, 197, 0, 35, 4, 177, 0, 19, 6, 16, 0, 111, 0, 64, 10, 19, 214, 181, 0, 99, 18, 6, 2, 19, 214, 101, 0, 19, 102, 6, 12, 35, 4, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 163, 4, 177, 0, 19, 6, 32, 0, 111, 0, 192, 7, 19, 214, 5, 1, 99, 26, 6, 2, 19, 214, 197, 0, 19, 102, 6, 14, 35, 4, 193, 0, 19, 150, 69, 1, 19, 86, 166, 1, 19, 102, 6, 8, 163, 4, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 35, 5, 177, 0, 19, 6, 48, 0, 111, 0, 64, 4, 19, 150, 181, 0, 19, 86, 214, 1, 19, 102, 6, 15, 35, 4, 193, 0, 19, 150, 229, 0, 19, 86, 166, 1, 19, 102, 6, 8, 163, 4, 193, 0, 19, 150, 69, 1, 19, 86, 166, 1, 19, 102, 6, 8, 35, 5, 193, 0, 147, 245, 245, 3, 147, 229, 5, 8, 163, 5, 177, 0, 19, 6, 64, 0, 147, 5, 129, 0, 151, 0, 0, 0, 231, 128, 192, 148, 131, 32, 193, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 3, 37, 5, 0, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 165, 0, 7, 147, 5, 197, 140, 19, 5, 193, 0, 19, 6, 1, 1, 151, 16, 0, 0, 231, 128, 0, 154, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 6, 5, 0, 19, 5, 53, 0, 19, 119, 197, 255, 179, 6, 199, 64, 99, 238, 213, 0, 51, 133, 213, 64, 147, 55, 69, 0, 19, 184, 86, 0, 19, 72, 24, 0, 179, 103, 248, 0, 99, 134, 7, 2, 19, 5, 0, 0, 99, 128, 5, 2, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 103, 128, 0, 0, 147, 117, 53, 0, 147, 7, 0, 0, 99, 4, 199, 2, 51, 7, 230, 64, 19, 8, 6, 0, 131, 8, 8, 0, 19, 8, 24, 0, 147, 168, 8, 252, 147, 200, 24, 0, 19, 7, 23, 0, 179, 135, 23, 1, 227, 20, 7, 254, 179, 6, 214, 0, 19, 8, 0, 0, 99, 132, 5, 2, 19, 118, 197, 255, 51, 134, 198, 0, 3, 7, 6, 0, 19, 6, 22, 0, 19, 39, 7, 252, 19, 71, 23, 0, 147, 133, 245, 255, 51, 8, 232, 0, 227, 148, 5, 254, 147, 82, 37, 0, 55, 5, 1, 1, 19, 7, 21, 16, 55, 5, 255, 0, 147, 5, 245, 15, 55, 5, 1, 0, 19, 6, 21, 0, 51, 5, 248, 0, 111, 0, 64, 3, 147, 146, 40, 0, 179, 134, 86, 0, 179, 130, 23, 65, 147, 248, 56, 0, 179, 115, 179, 0, 19, 83, 131, 0, 51, 115, 179, 0, 51, 3, 115, 0, 51, 3, 195, 2, 19, 83, 3, 1, 51, 5, 163, 0, 99, 152, 8, 10, 227, 132, 2, 244, 147, 135, 2, 0, 19, 8, 0, 12, 147, 136, 2, 0, 99, 228, 2, 1, 147, 8, 0, 12, 147, 242, 200, 15, 19, 152, 34, 0, 51, 136, 6, 1, 19, 3, 0, 0, 227, 132, 2, 250, 147, 130, 6, 0, 131, 163, 2, 0, 19, 206, 243, 255, 19, 94, 126, 0, 147, 211, 99, 0, 131, 174, 66, 0, 179, 99, 126, 0, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 130, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 194, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 179, 227, 195, 1, 179, 243, 227, 0, 147, 130, 2, 1, 51, 131, 99, 0, 227, 150, 2, 249, 111, 240, 159, 242, 147, 6, 0, 12, 99, 228, 215, 0, 147, 7, 0, 12, 147, 6, 0, 0, 147, 247, 55, 0, 147, 151, 39, 0, 131, 40, 8, 0, 19, 8, 72, 0, 147, 194, 248, 255, 147, 210, 114, 0, 147, 216, 104, 0, 179, 232, 18, 1, 179, 248, 232, 0, 147, 135, 199, 255, 179, 134, 216, 0, 227, 158, 7, 252, 51, 247, 182, 0, 147, 214, 134, 0, 179, 245, 182, 0, 179, 133, 229, 0, 179, 133, 197, 2, 147, 213, 5, 1, 51, 133, 165, 0, 103, 128, 0, 0, 55, 5, 177, 232, 19, 5, 133, 221, 183, 69, 246, 136, 147, 133, 69, 41, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 3, 164, 197, 1, 131, 164, 133, 1, 131, 38, 196, 0, 19, 9, 5, 0, 55, 165, 0, 7, 147, 5, 69, 142, 19, 6, 192, 0, 19, 133, 4, 0, 231, 128, 6, 0, 147, 9, 16, 0, 99, 16, 5, 18, 3, 37, 137, 0, 99, 14, 5, 0, 35, 34, 161, 0, 19, 5, 65, 0, 35, 36, 161, 0, 55, 117, 0, 7, 19, 5, 69, 118, 111, 0, 192, 4, 3, 37, 73, 0, 131, 41, 9, 0, 131, 37, 197, 0, 19, 133, 9, 0, 231, 128, 5, 0, 55, 214, 68, 116, 19, 6, 118, 236, 179, 197, 197, 0, 55, 214, 164, 102, 19, 6, 182, 142, 51, 69, 197, 0, 51, 101, 181, 0, 99, 30, 5, 4, 35, 34, 49, 1, 19, 5, 65, 0, 35, 36, 161, 0, 55, 117, 0, 7, 19, 5, 133, 116, 35, 38, 161, 0, 55, 165, 0, 7, 19, 5, 69, 143, 35, 32, 161, 2, 19, 5, 32, 0, 35, 34, 161, 2, 35, 36, 1, 2, 19, 5, 129, 0, 35, 40, 161, 2, 147, 9, 16, 0, 35, 42, 49, 3, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 0, 104, 99, 26, 5, 6, 3, 37, 201, 0, 147, 5, 133, 0, 19, 6, 197, 0, 35, 36, 161, 0, 55, 117, 0, 7, 19, 5, 5, 124, 35, 38, 161, 0, 35, 40, 177, 0, 55, 133, 0, 7, 19, 5, 5, 246, 35, 42, 161, 0, 35, 44, 193, 0, 35, 46, 161, 0, 55, 165, 0, 7, 19, 5, 69, 138, 35, 32, 161, 2, 19, 5, 48, 0, 35, 34, 161, 2, 35, 36, 1, 2, 147, 5, 129, 0, 35, 40, 177, 2, 35, 42, 161, 2, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 0, 97, 147, 9, 5, 0, 19, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 19, 1, 1, 5, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 255, 35, 36, 161, 0, 35, 38, 177, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 55, 117, 0, 7, 19, 5, 5, 124, 35, 46, 161, 0, 55, 165, 0, 7, 19, 5, 69, 144, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 19, 6, 129, 1, 35, 40, 193, 0, 35, 42, 161, 0, 19, 5, 1, 0, 151, 32, 0, 0, 231, 128, 192, 135, 115, 16, 0, 192, 147, 134, 53, 0, 147, 246, 198, 255, 19, 119, 245, 15, 99, 140, 182, 2, 51, 133, 182, 64, 99, 100, 197, 0, 19, 5, 6, 0, 99, 4, 5, 2, 147, 6, 0, 0, 179, 135, 213, 0, 131, 199, 7, 0, 99, 130, 231, 12, 147, 134, 22, 0, 227, 24, 213, 254, 147, 6, 134, 255, 99, 248, 166, 0, 111, 0, 0, 7, 19, 5, 0, 0, 147, 6, 134, 255, 183, 7, 255, 254, 147, 135, 247, 239, 55, 136, 128, 128, 19, 8, 8, 8, 183, 8, 1, 1, 147, 136, 24, 16, 179, 8, 23, 3, 179, 130, 165, 0, 3, 163, 2, 0, 51, 67, 19, 1, 147, 67, 243, 255, 51, 3, 243, 0, 179, 243, 3, 1, 51, 243, 99, 0, 99, 20, 3, 2, 131, 162, 66, 0, 179, 194, 18, 1, 19, 195, 242, 255, 179, 130, 242, 0, 51, 115, 3, 1, 179, 114, 83, 0, 99, 150, 2, 0, 19, 5, 133, 0, 227, 240, 166, 252, 99, 106, 166, 4, 99, 12, 197, 0, 179, 134, 165, 0, 131, 198, 6, 0, 99, 128, 230, 2, 19, 5, 21, 0, 227, 24, 166, 254, 147, 5, 0, 0, 147, 6, 6, 0, 19, 133, 5, 0, 147, 133, 6, 0, 103, 128, 0, 0, 147, 5, 16, 0, 147, 6, 5, 0, 19, 133, 5, 0, 147, 133, 6, 0, 103, 128, 0, 0, 147, 5, 16, 0, 19, 133, 5, 0, 147, 133, 6, 0, 103, 128, 0, 0, 183, 165, 0, 7, 147, 134, 5, 157, 147, 5, 6, 0, 19, 134, 6, 0, 151, 0, 0, 0, 231, 128, 128, 42, 115, 16, 0, 192, 19, 1, 1, 247, 35, 38, 17, 8, 35, 36, 129, 8, 35, 34, 145, 8, 35, 32, 33, 9, 19, 132, 5, 0, 131, 165, 5, 0, 19, 246, 5, 1, 147, 4, 5, 0, 99, 22, 6, 2, 19, 245, 5, 2, 99, 22, 5, 6, 3, 165, 4, 0, 147, 5, 16, 0, 19, 9, 16, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 202, 99, 12, 5, 12, 111, 0, 192, 33, 147, 5, 0, 0, 3, 168, 4, 0, 19, 5, 1, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 179, 2, 181, 0, 19, 88, 71, 0, 179, 135, 248, 0, 163, 143, 242, 6, 147, 133, 245, 255, 99, 242, 230, 6, 19, 7, 8, 0, 147, 119, 248, 0, 147, 8, 0, 3, 227, 238, 199, 252, 147, 8, 112, 5, 111, 240, 95, 253, 147, 5, 0, 0, 3, 168, 4, 0, 19, 5, 1, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 179, 2, 181, 0, 19, 88, 71, 0, 179, 135, 248, 0, 163, 143, 242, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 7, 8, 0, 147, 119, 248, 0, 147, 8, 0, 3, 227, 238, 199, 252, 147, 8, 112, 3, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 24, 19, 5, 1, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 149, 0, 7, 19, 6, 5, 21, 147, 5, 16, 0, 147, 6, 32, 0, 19, 9, 16, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 90, 99, 22, 5, 20, 3, 37, 132, 1, 131, 37, 196, 1, 55, 166, 0, 7, 19, 6, 70, 158, 35, 32, 193, 0, 19, 9, 16, 0, 35, 34, 33, 1, 35, 36, 1, 0, 55, 166, 0, 7, 19, 6, 198, 158, 35, 40, 193, 0, 35, 42, 1, 0, 19, 6, 1, 0, 151, 0, 0, 0, 231, 128, 0, 48, 99, 22, 5, 16, 3, 37, 4, 0, 147, 117, 5, 1, 99, 154, 5, 2, 19, 117, 5, 2, 99, 26, 5, 6, 3, 165, 68, 0, 147, 5, 16, 0, 19, 6, 4, 0, 131, 32, 193, 8, 3, 36, 129, 8, 131, 36, 65, 8, 3, 41, 1, 8, 19, 1, 1, 9, 23, 35, 0, 0, 103, 0, 67, 181, 147, 5, 0, 0, 3, 168, 68, 0, 19, 5, 1, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 179, 2, 181, 0, 19, 88, 71, 0, 179, 135, 248, 0, 163, 143, 242, 6, 147, 133, 245, 255, 99, 242, 230, 6, 19, 7, 8, 0, 147, 119, 248, 0, 147, 8, 0, 3, 227, 238, 199, 252, 147, 8, 112, 5, 111, 240, 95, 253, 147, 5, 0, 0, 3, 168, 68, 0, 19, 5, 1, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 179, 2, 181, 0, 19, 88, 71, 0, 179, 135, 248, 0, 163, 143, 242, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 7, 8, 0, 147, 119, 248, 0, 147, 8, 0, 3, 227, 238, 199, 252, 147, 8, 112, 3, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 120, 197, 4, 19, 5, 1, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 149, 0, 7, 19, 6, 5, 21, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 69, 19, 9, 5, 0, 19, 5, 9, 0, 131, 32, 193, 8, 3, 36, 129, 8, 131, 36, 65, 8, 3, 41, 1, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 149, 0, 7, 19, 134, 5, 20, 147, 5, 0, 8, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 1, 0, 35, 32, 161, 2, 55, 133, 0, 7, 19, 5, 5, 246, 35, 34, 161, 2, 147, 5, 65, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 165, 0, 7, 19, 5, 5, 162, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 64, 69, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 1, 0, 35, 32, 161, 2, 55, 133, 0, 7, 19, 5, 5, 246, 35, 34, 161, 2, 147, 5, 65, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 165, 0, 7, 19, 5, 5, 163, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 64, 62, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 1, 0, 35, 32, 161, 2, 55, 133, 0, 7, 19, 5, 5, 246, 35, 34, 161, 2, 147, 5, 65, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 165, 0, 7, 19, 5, 69, 166, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 64, 55, 115, 16, 0, 192, 3, 37, 5, 0, 111, 0, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 69, 1, 3, 39, 5, 1, 131, 39, 197, 0, 35, 42, 193, 0, 131, 166, 133, 1, 35, 40, 225, 0, 35, 38, 241, 0, 3, 38, 133, 0, 3, 39, 69, 0, 3, 37, 5, 0, 131, 165, 197, 1, 35, 36, 193, 0, 35, 34, 225, 0, 35, 32, 161, 0, 19, 6, 1, 0, 19, 133, 6, 0, 151, 0, 0, 0, 231, 128, 64, 1, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 19, 4, 6, 0, 35, 36, 1, 0, 19, 6, 0, 2, 35, 38, 193, 0, 19, 6, 48, 0, 35, 4, 193, 2, 3, 38, 132, 0, 35, 40, 1, 0, 35, 44, 1, 0, 35, 32, 161, 2, 35, 34, 177, 2, 99, 4, 6, 18, 3, 37, 196, 0, 99, 10, 5, 24, 131, 37, 4, 0, 147, 6, 245, 255, 147, 150, 86, 0, 147, 214, 86, 0, 147, 132, 22, 0, 19, 137, 69, 0, 147, 25, 85, 0, 19, 10, 6, 1, 147, 10, 16, 0, 55, 101, 0, 7, 19, 11, 69, 71, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 22, 5, 24, 3, 37, 74, 255, 35, 38, 161, 0, 3, 5, 202, 0, 35, 4, 161, 2, 131, 37, 138, 255, 3, 37, 4, 1, 35, 36, 177, 0, 131, 38, 74, 0, 131, 37, 138, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 40, 193, 0, 35, 42, 177, 0, 131, 38, 202, 255, 131, 37, 10, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 44, 193, 0, 35, 46, 177, 0, 131, 37, 10, 255, 147, 149, 53, 0, 51, 5, 181, 0, 3, 38, 69, 0, 3, 37, 5, 0, 147, 5, 129, 0, 231, 0, 6, 0, 99, 24, 5, 12, 19, 9, 137, 0, 147, 137, 9, 254, 19, 10, 10, 2, 227, 156, 9, 240, 111, 0, 192, 7, 3, 37, 68, 1, 99, 0, 5, 8, 131, 37, 4, 1, 3, 38, 4, 0, 147, 6, 245, 255, 147, 150, 54, 0, 147, 214, 54, 0, 147, 132, 22, 0, 19, 9, 70, 0, 147, 137, 69, 0, 19, 26, 53, 0, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 24, 5, 6, 3, 166, 9, 0, 3, 165, 201, 255, 147, 5, 129, 0, 231, 0, 6, 0, 99, 30, 5, 4, 19, 9, 137, 0, 19, 10, 138, 255, 147, 137, 137, 0, 227, 16, 10, 252, 111, 0, 128, 0, 147, 4, 0, 0, 3, 37, 68, 0, 99, 234, 164, 0, 111, 0, 0, 4, 147, 4, 0, 0, 3, 37, 68, 0, 99, 250, 164, 2, 3, 37, 4, 0, 147, 149, 52, 0, 51, 6, 181, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 6, 0, 3, 38, 70, 0, 131, 166, 198, 0, 231, 128, 6, 0, 99, 6, 5, 0, 19, 5, 16, 0, 111, 0, 128, 0, 19, 5, 0, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 19, 1, 1, 5, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 132, 7, 0, 147, 4, 7, 0, 147, 137, 6, 0, 19, 10, 6, 0, 19, 9, 5, 0, 99, 142, 5, 4, 3, 43, 9, 0, 19, 117, 27, 0, 183, 10, 17, 0, 99, 4, 5, 0, 147, 10, 176, 2, 179, 12, 133, 0, 19, 117, 75, 0, 99, 8, 5, 4, 19, 5, 0, 1, 99, 252, 169, 4, 19, 5, 0, 0, 99, 128, 9, 6, 147, 133, 9, 0, 19, 6, 10, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 111, 0, 128, 3, 3, 43, 9, 0, 147, 12, 20, 0, 147, 10, 208, 2, 19, 117, 75, 0, 227, 28, 5, 250, 19, 10, 0, 0, 3, 37, 137, 0, 99, 18, 5, 2, 111, 0, 0, 6, 19, 5, 10, 0, 147, 133, 9, 0, 151, 240, 255, 255, 231, 128, 192, 51, 179, 12, 149, 1, 3, 37, 137, 0, 99, 2, 5, 4, 3, 45, 201, 0, 99, 254, 172, 3, 19, 117, 139, 0, 99, 22, 5, 14, 131, 69, 9, 2, 19, 6, 48, 0, 19, 5, 16, 0, 99, 132, 197, 0, 19, 133, 5, 0, 147, 117, 53, 0, 51, 5, 157, 65, 99, 140, 5, 18, 19, 6, 16, 0, 99, 158, 197, 18, 147, 12, 0, 0, 111, 0, 0, 20, 131, 43, 137, 1, 3, 41, 201, 1, 19, 133, 11, 0, 147, 5, 9, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 128, 31, 19, 11, 16, 0, 99, 2, 5, 4, 19, 5, 11, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 3, 35, 201, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 0, 3, 0, 3, 37, 73, 0, 35, 36, 161, 0, 19, 5, 0, 3, 131, 77, 9, 2, 131, 43, 137, 1, 3, 44, 201, 1, 35, 34, 169, 0, 19, 11, 16, 0, 35, 0, 105, 3, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 18, 227, 26, 5, 242, 51, 5, 157, 65, 147, 9, 21, 0, 147, 137, 249, 255, 99, 138, 9, 12, 3, 38, 12, 1, 147, 5, 0, 3, 19, 133, 11, 0, 231, 0, 6, 0, 227, 4, 5, 254, 111, 240, 223, 240, 147, 12, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 220, 21, 0, 131, 43, 137, 1, 3, 44, 201, 1, 3, 41, 73, 0, 19, 11, 21, 0, 19, 11, 251, 255, 99, 0, 11, 2, 3, 38, 12, 1, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 11, 16, 0, 111, 240, 31, 236, 55, 5, 17, 0, 19, 11, 16, 0, 227, 10, 169, 234, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 8, 227, 26, 5, 232, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 230, 19, 4, 0, 0, 99, 136, 140, 4, 3, 38, 12, 1, 19, 4, 20, 0, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 64, 3, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 226, 19, 11, 0, 0, 3, 37, 129, 0, 35, 34, 169, 0, 35, 0, 185, 3, 111, 240, 159, 226, 19, 133, 12, 0, 51, 59, 149, 1, 111, 240, 223, 225, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 183, 7, 17, 0, 19, 4, 7, 0, 147, 132, 6, 0, 147, 137, 5, 0, 19, 9, 5, 0, 99, 0, 246, 2, 131, 166, 9, 1, 19, 5, 9, 0, 147, 5, 6, 0, 231, 128, 6, 0, 147, 5, 5, 0, 19, 5, 16, 0, 99, 156, 5, 2, 99, 136, 4, 2, 3, 163, 201, 0, 19, 5, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 0, 3, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 35, 32, 97, 1, 19, 9, 5, 0, 3, 37, 133, 0, 131, 38, 9, 1, 19, 7, 245, 255, 51, 55, 224, 0, 147, 135, 246, 255, 179, 55, 240, 0, 51, 119, 247, 0, 19, 4, 6, 0, 147, 132, 5, 0, 99, 16, 7, 30, 147, 5, 16, 0, 99, 154, 182, 20, 3, 38, 73, 1, 147, 5, 0, 0, 179, 134, 132, 0, 19, 7, 22, 0, 183, 7, 17, 0, 19, 8, 240, 13, 147, 8, 0, 15, 19, 134, 4, 0, 111, 0, 128, 1, 19, 3, 22, 0, 179, 133, 197, 64, 179, 133, 101, 0, 19, 6, 3, 0, 99, 142, 242, 16, 19, 7, 247, 255, 99, 0, 7, 8, 99, 8, 214, 16, 3, 3, 6, 0, 147, 114, 243, 15, 227, 92, 3, 252, 3, 67, 22, 0, 147, 243, 242, 1, 19, 126, 243, 3, 99, 112, 88, 4, 3, 67, 38, 0, 19, 30, 110, 0, 19, 115, 243, 3, 51, 110, 110, 0, 99, 238, 18, 3, 131, 66, 54, 0, 19, 147, 211, 1, 19, 83, 179, 0, 147, 19, 110, 0, 147, 242, 242, 3, 179, 226, 83, 0, 179, 226, 98, 0, 99, 128, 242, 12, 19, 3, 70, 0, 111, 240, 31, 249, 19, 3, 38, 0, 147, 146, 99, 0, 179, 226, 194, 1, 111, 240, 31, 248, 19, 3, 54, 0, 147, 146, 195, 0, 179, 98, 94, 0, 111, 240, 31, 247, 99, 10, 214, 8, 131, 6, 6, 0, 99, 218, 6, 4, 147, 246, 246, 15, 19, 7, 0, 14, 99, 228, 230, 4, 19, 7, 0, 15, 99, 224, 230, 4, 3, 71, 22, 0, 131, 71, 38, 0, 19, 119, 247, 3, 147, 247, 247, 3, 3, 70, 54, 0, 147, 150, 214, 1, 147, 214, 182, 0, 19, 23, 199, 0, 147, 151, 103, 0, 51, 231, 231, 0, 19, 118, 246, 3, 51, 102, 199, 0, 51, 102, 214, 0, 183, 6, 17, 0, 99, 14, 214, 2, 99, 132, 5, 2, 99, 240, 133, 2, 51, 134, 180, 0, 3, 6, 6, 0, 147, 6, 0, 252, 99, 90, 214, 0, 19, 6, 0, 0, 99, 26, 6, 0, 111, 0, 128, 1, 227, 154, 133, 254, 19, 134, 4, 0, 99, 6, 6, 0, 19, 132, 5, 0, 147, 4, 6, 0, 99, 2, 5, 8, 131, 41, 201, 0, 19, 5, 0, 1, 99, 114, 164, 6, 19, 5, 0, 0, 99, 4, 4, 2, 147, 5, 4, 0, 19, 134, 4, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 99, 116, 53, 5, 131, 69, 9, 2, 147, 6, 48, 0, 19, 6, 0, 0, 99, 132, 213, 0, 19, 134, 5, 0, 147, 117, 54, 0, 51, 133, 169, 64, 99, 130, 5, 6, 19, 6, 16, 0, 99, 148, 197, 6, 147, 10, 0, 0, 111, 0, 192, 6, 19, 133, 4, 0, 147, 5, 4, 0, 151, 240, 255, 255, 231, 128, 128, 225, 227, 96, 53, 253, 131, 37, 201, 1, 3, 37, 137, 1, 3, 163, 197, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 0, 3, 0, 147, 10, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 218, 21, 0, 131, 41, 137, 1, 3, 43, 201, 1, 3, 41, 73, 0, 19, 10, 21, 0, 19, 10, 250, 255, 99, 0, 10, 2, 3, 38, 11, 1, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 10, 16, 0, 111, 0, 128, 5, 55, 5, 17, 0, 19, 10, 16, 0, 99, 6, 169, 4, 131, 38, 203, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 99, 26, 5, 2, 19, 4, 0, 0, 99, 130, 138, 2, 3, 38, 11, 1, 19, 4, 20, 0, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 128, 0, 19, 133, 10, 0, 51, 58, 85, 1, 19, 5, 10, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 197, 1, 3, 37, 133, 1, 3, 163, 198, 0, 103, 0, 3, 0, 3, 69, 5, 0, 19, 21, 181, 1, 19, 85, 245, 1, 103, 128, 0, 0, 3, 69, 5, 0, 19, 21, 165, 1, 19, 85, 245, 1, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 19, 132, 5, 0, 3, 167, 197, 1, 131, 165, 133, 1, 3, 39, 199, 0, 147, 4, 5, 0, 19, 133, 5, 0, 147, 5, 6, 0, 19, 134, 6, 0, 231, 0, 7, 0, 35, 160, 132, 0, 35, 130, 164, 0, 163, 130, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 250, 35, 46, 17, 4, 35, 44, 129, 4, 35, 42, 145, 4, 35, 40, 33, 5, 35, 38, 49, 5, 35, 36, 65, 5, 35, 34, 81, 5, 35, 32, 97, 5, 35, 46, 113, 3, 35, 44, 129, 3, 35, 42, 145, 3, 35, 40, 161, 3, 35, 38, 177, 3, 131, 38, 198, 1, 3, 36, 134, 1, 35, 46, 209, 0, 3, 163, 6, 1, 35, 32, 177, 2, 35, 34, 161, 2, 147, 5, 32, 2, 19, 5, 4, 0, 35, 36, 97, 2, 231, 0, 3, 0, 99, 2, 5, 4, 19, 5, 16, 0, 131, 32, 193, 5, 3, 36, 129, 5, 131, 36, 65, 5, 3, 41, 1, 5, 131, 41, 193, 4, 3, 42, 129, 4, 131, 42, 65, 4, 3, 43, 1, 4, 131, 43, 193, 3, 3, 44, 129, 3, 131, 44, 65, 3, 3, 45, 1, 3, 131, 45, 193, 2, 19, 1, 1, 6, 103, 128, 0, 0, 131, 39, 1, 2, 99, 136, 7, 64, 147, 10, 0, 0, 147, 9, 0, 0, 3, 41, 65, 2, 51, 5, 249, 0, 35, 44, 161, 0, 55, 5, 17, 0, 147, 6, 37, 0, 19, 7, 32, 2, 183, 13, 239, 255, 19, 12, 16, 0, 147, 11, 21, 0, 55, 165, 0, 7, 147, 12, 5, 176, 55, 85, 85, 85, 19, 5, 85, 85, 35, 38, 161, 0, 55, 53, 51, 51, 19, 5, 53, 51, 35, 36, 161, 0, 55, 21, 15, 15, 19, 5, 245, 240, 35, 34, 161, 0, 55, 5, 1, 1, 19, 5, 21, 16, 35, 32, 161, 0, 55, 165, 0, 7, 19, 8, 69, 167, 35, 42, 209, 0, 35, 40, 1, 1, 111, 0, 192, 2, 179, 10, 53, 1, 131, 39, 1, 2, 131, 38, 65, 1, 19, 7, 32, 2, 3, 40, 1, 1, 51, 133, 41, 65, 179, 9, 101, 1, 19, 9, 11, 0, 3, 37, 129, 1, 99, 6, 171, 52, 3, 5, 9, 0, 147, 116, 245, 15, 99, 78, 5, 2, 19, 11, 25, 0, 99, 98, 151, 10, 19, 149, 36, 0, 51, 5, 5, 1, 3, 37, 5, 0, 19, 10, 0, 3, 19, 141, 6, 0, 103, 0, 5, 0, 19, 10, 64, 7, 19, 141, 6, 0, 51, 5, 189, 1, 147, 5, 48, 0, 99, 122, 181, 8, 111, 0, 64, 9, 131, 69, 25, 0, 19, 245, 244, 1, 147, 245, 245, 3, 19, 6, 240, 13, 99, 120, 150, 4, 3, 70, 41, 0, 19, 11, 57, 0, 147, 149, 101, 0, 19, 118, 246, 3, 179, 229, 197, 0, 19, 6, 0, 15, 99, 228, 196, 44, 3, 70, 11, 0, 19, 21, 213, 1, 19, 85, 181, 0, 147, 149, 101, 0, 19, 118, 246, 3, 179, 229, 197, 0, 179, 228, 165, 0, 55, 5, 17, 0, 99, 138, 164, 42, 19, 11, 73, 0, 227, 124, 151, 246, 111, 0, 64, 1, 19, 11, 41, 0, 19, 21, 101, 0, 179, 100, 181, 0, 227, 114, 151, 246, 19, 5, 192, 5, 99, 146, 164, 4, 19, 141, 6, 0, 19, 138, 4, 0, 51, 5, 189, 1, 147, 5, 48, 0, 99, 100, 181, 0, 19, 5, 48, 0, 227, 12, 133, 241, 99, 230, 89, 51, 3, 37, 65, 2, 179, 5, 85, 1, 99, 134, 10, 4, 99, 242, 250, 4, 3, 133, 5, 0, 19, 6, 0, 252, 99, 94, 197, 2, 111, 0, 192, 48, 19, 133, 4, 0, 151, 224, 255, 255, 231, 128, 0, 216, 99, 28, 5, 24, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 64, 174, 99, 4, 5, 24, 55, 5, 17, 0, 19, 13, 21, 0, 19, 138, 4, 0, 111, 0, 0, 31, 99, 156, 250, 44, 99, 130, 9, 2, 99, 254, 249, 0, 3, 37, 65, 2, 51, 5, 53, 1, 3, 5, 5, 0, 19, 6, 240, 251, 99, 70, 166, 0, 111, 0, 128, 43, 99, 154, 249, 42, 3, 37, 193, 1, 131, 38, 197, 0, 51, 134, 89, 65, 19, 5, 4, 0, 231, 128, 6, 0, 227, 24, 5, 218, 19, 6, 80, 0, 111, 0, 0, 2, 147, 10, 6, 0, 19, 141, 11, 0, 19, 5, 4, 0, 3, 35, 129, 2, 231, 0, 3, 0, 19, 134, 10, 0, 227, 22, 5, 216, 51, 5, 189, 1, 147, 5, 48, 0, 99, 100, 181, 0, 19, 5, 48, 0, 99, 84, 172, 6, 147, 5, 192, 5, 147, 6, 32, 0, 227, 4, 213, 252, 19, 117, 246, 15, 19, 21, 37, 0, 51, 5, 149, 1, 3, 37, 5, 0, 147, 10, 0, 0, 147, 5, 208, 7, 103, 0, 5, 0, 19, 21, 42, 0, 51, 85, 173, 0, 19, 117, 245, 0, 147, 5, 0, 3, 147, 6, 160, 0, 99, 100, 213, 0, 147, 5, 112, 5, 147, 6, 0, 0, 147, 10, 16, 0, 99, 6, 10, 0, 147, 6, 250, 255, 147, 10, 6, 0, 179, 133, 165, 0, 19, 138, 6, 0, 111, 240, 159, 247, 99, 30, 133, 3, 55, 13, 17, 0, 147, 5, 10, 0, 147, 10, 6, 0, 227, 18, 170, 247, 111, 0, 128, 2, 147, 10, 32, 0, 147, 5, 176, 7, 111, 240, 95, 245, 147, 10, 48, 0, 147, 5, 80, 7, 111, 240, 159, 244, 147, 10, 64, 0, 147, 5, 192, 5, 111, 240, 223, 243, 19, 5, 16, 0, 147, 5, 0, 8, 227, 230, 180, 216, 147, 213, 180, 0, 19, 5, 32, 0, 227, 128, 5, 216, 19, 213, 4, 1, 19, 53, 21, 0, 147, 5, 64, 0, 51, 133, 165, 64, 111, 240, 223, 214, 19, 10, 224, 6, 19, 141, 6, 0, 51, 5, 189, 1, 147, 5, 48, 0, 227, 120, 181, 228, 111, 240, 31, 229, 19, 10, 32, 7, 19, 141, 6, 0, 51, 5, 189, 1, 147, 5, 48, 0, 227, 124, 181, 226, 111, 240, 159, 227, 19, 229, 20, 0, 147, 85, 21, 0, 51, 101, 181, 0, 147, 85, 37, 0, 51, 101, 181, 0, 147, 85, 69, 0, 51, 101, 181, 0, 147, 85, 133, 0, 51, 101, 181, 0, 147, 85, 5, 1, 51, 101, 181, 0, 19, 69, 245, 255, 147, 85, 21, 0, 3, 38, 193, 0, 179, 245, 197, 0, 51, 5, 181, 64, 3, 38, 129, 0, 179, 117, 197, 0, 19, 85, 37, 0, 51, 117, 197, 0, 51, 133, 165, 0, 147, 85, 69, 0, 51, 5, 181, 0, 131, 37, 65, 0, 51, 117, 181, 0, 131, 37, 1, 0, 51, 5, 181, 2, 19, 85, 165, 1, 19, 74, 117, 0, 19, 141, 4, 0, 131, 39, 1, 2, 131, 38, 65, 1, 19, 7, 32, 2, 3, 40, 1, 1, 51, 5, 189, 1, 147, 5, 48, 0, 227, 112, 181, 218, 111, 240, 31, 218, 19, 21, 197, 0, 179, 228, 165, 0, 227, 122, 151, 204, 111, 240, 31, 215, 99, 130, 10, 2, 99, 246, 250, 8, 3, 37, 65, 2, 51, 5, 85, 1, 3, 5, 5, 0, 147, 5, 240, 251, 99, 208, 165, 8, 19, 133, 10, 0, 111, 0, 128, 0, 19, 5, 0, 0, 131, 37, 193, 1, 131, 166, 197, 0, 131, 37, 65, 2, 179, 133, 165, 0, 51, 134, 167, 64, 19, 5, 4, 0, 231, 128, 6, 0, 227, 22, 5, 184, 147, 5, 32, 2, 19, 5, 4, 0, 3, 35, 129, 2, 131, 32, 193, 5, 3, 36, 129, 5, 131, 36, 65, 5, 3, 41, 1, 5, 131, 41, 193, 4, 3, 42, 129, 4, 131, 42, 65, 4, 3, 43, 1, 4, 131, 43, 193, 3, 3, 44, 129, 3, 131, 44, 65, 3, 3, 45, 1, 3, 131, 45, 193, 2, 19, 1, 1, 6, 103, 0, 3, 0, 19, 133, 7, 0, 227, 138, 250, 248, 55, 165, 0, 7, 19, 7, 133, 203, 3, 37, 65, 2, 147, 133, 7, 0, 19, 134, 10, 0, 147, 134, 7, 0, 151, 224, 255, 255, 231, 128, 192, 199, 115, 16, 0, 192, 55, 165, 0, 7, 19, 7, 133, 202, 3, 37, 65, 2, 147, 133, 7, 0, 19, 134, 10, 0, 147, 134, 9, 0, 151, 224, 255, 255, 231, 128, 128, 197, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 35, 46, 49, 1, 35, 44, 65, 1, 35, 42, 81, 1, 35, 40, 97, 1, 35, 38, 113, 1, 35, 36, 129, 1, 35, 34, 145, 1, 3, 166, 197, 1, 3, 164, 133, 1, 3, 35, 6, 1, 147, 4, 5, 0, 147, 5, 112, 2, 19, 9, 112, 2, 19, 5, 4, 0, 35, 32, 97, 0, 231, 0, 3, 0, 99, 14, 5, 2, 19, 5, 16, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 3, 42, 129, 1, 131, 42, 65, 1, 3, 43, 1, 1, 131, 43, 193, 0, 3, 44, 129, 0, 131, 44, 65, 0, 19, 1, 1, 3, 103, 128, 0, 0, 131, 164, 4, 0, 99, 104, 153, 2, 19, 149, 36, 0, 183, 165, 0, 7, 147, 133, 133, 177, 51, 5, 181, 0, 3, 37, 5, 0, 19, 9, 0, 3, 183, 5, 17, 0, 147, 137, 37, 0, 103, 0, 5, 0, 19, 9, 64, 7, 111, 0, 64, 5, 19, 5, 192, 5, 99, 154, 164, 0, 55, 5, 17, 0, 147, 9, 37, 0, 19, 137, 4, 0, 111, 0, 0, 13, 19, 133, 4, 0, 151, 224, 255, 255, 231, 128, 128, 151, 99, 28, 5, 2, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 109, 99, 4, 5, 2, 55, 5, 17, 0, 147, 9, 21, 0, 111, 240, 31, 253, 19, 9, 224, 6, 111, 0, 128, 0, 19, 9, 32, 7, 55, 5, 17, 0, 147, 9, 37, 0, 111, 0, 192, 8, 19, 229, 20, 0, 147, 85, 21, 0, 51, 101, 181, 0, 147, 85, 37, 0, 51, 101, 181, 0, 147, 85, 69, 0, 51, 101, 181, 0, 147, 85, 133, 0, 51, 101, 181, 0, 147, 85, 5, 1, 51, 101, 181, 0, 19, 69, 245, 255, 147, 85, 21, 0, 55, 86, 85, 85, 19, 6, 86, 85, 179, 245, 197, 0, 51, 5, 181, 64, 183, 53, 51, 51, 147, 133, 53, 51, 51, 118, 181, 0, 19, 85, 37, 0, 51, 117, 181, 0, 51, 5, 166, 0, 147, 85, 69, 0, 51, 5, 181, 0, 183, 21, 15, 15, 147, 133, 245, 240, 51, 117, 181, 0, 183, 5, 1, 1, 147, 133, 21, 16, 51, 5, 181, 2, 19, 85, 165, 1, 19, 73, 117, 0, 147, 137, 4, 0, 19, 6, 80, 0, 183, 4, 239, 255, 55, 5, 17, 0, 19, 10, 21, 0, 147, 10, 16, 0, 19, 11, 32, 0, 55, 165, 0, 7, 147, 11, 133, 187, 19, 12, 160, 0, 111, 0, 0, 2, 147, 9, 10, 0, 147, 12, 6, 0, 19, 5, 4, 0, 3, 35, 1, 0, 231, 0, 3, 0, 19, 134, 12, 0, 227, 24, 5, 230, 51, 133, 153, 0, 147, 5, 48, 0, 99, 100, 181, 0, 19, 5, 48, 0, 99, 208, 170, 6, 147, 5, 192, 5, 227, 6, 101, 253, 19, 117, 246, 15, 19, 21, 37, 0, 51, 5, 117, 1, 3, 37, 5, 0, 147, 12, 0, 0, 147, 5, 208, 7, 103, 0, 5, 0, 19, 21, 41, 0, 51, 213, 169, 0, 19, 117, 245, 0, 147, 5, 0, 3, 99, 100, 133, 1, 147, 5, 112, 5, 147, 6, 0, 0, 147, 12, 16, 0, 99, 6, 9, 0, 147, 6, 249, 255, 147, 12, 6, 0, 179, 133, 165, 0, 19, 137, 6, 0, 111, 240, 31, 248, 99, 30, 85, 3, 183, 9, 17, 0, 147, 5, 9, 0, 147, 12, 6, 0, 227, 22, 57, 247, 111, 0, 128, 2, 147, 12, 32, 0, 147, 5, 176, 7, 111, 240, 223, 245, 147, 12, 48, 0, 147, 5, 80, 7, 111, 240, 31, 245, 147, 12, 64, 0, 147, 5, 192, 5, 111, 240, 95, 244, 147, 5, 112, 2, 19, 5, 4, 0, 3, 35, 1, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 3, 42, 129, 1, 131, 42, 65, 1, 3, 43, 1, 1, 131, 43, 193, 0, 3, 44, 129, 0, 131, 44, 65, 0, 19, 1, 1, 3, 103, 0, 3, 0, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 165, 0, 7, 147, 133, 133, 204, 19, 6, 80, 0, 103, 0, 3, 0, 3, 38, 69, 0, 3, 37, 5, 0, 3, 35, 198, 0, 103, 0, 3, 0, 3, 37, 5, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 243, 255, 255, 103, 0, 195, 59, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 5, 0, 3, 37, 70, 1, 35, 42, 161, 0, 3, 37, 6, 1, 35, 40, 161, 0, 3, 37, 198, 0, 35, 38, 161, 0, 3, 37, 134, 0, 35, 36, 161, 0, 131, 38, 70, 0, 3, 165, 133, 1, 35, 34, 209, 0, 3, 38, 6, 0, 131, 165, 197, 1, 35, 32, 193, 0, 19, 6, 1, 0, 151, 240, 255, 255, 231, 128, 0, 211, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 243, 255, 255, 103, 0, 131, 52, 103, 128, 0, 0, 19, 1, 1, 254, 55, 166, 0, 7, 19, 6, 6, 205, 35, 36, 193, 0, 55, 166, 0, 7, 19, 6, 6, 207, 35, 38, 193, 0, 35, 40, 161, 0, 35, 42, 177, 0, 19, 5, 16, 0, 35, 12, 161, 0, 19, 5, 129, 0, 151, 192, 255, 255, 231, 128, 64, 131, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 35, 46, 177, 0, 19, 5, 129, 1, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 55, 165, 0, 7, 19, 5, 5, 205, 35, 40, 161, 0, 35, 42, 1, 0, 19, 5, 1, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 248, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 133, 0, 7, 19, 5, 5, 246, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 165, 0, 7, 19, 5, 69, 209, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 1, 1, 249, 35, 36, 177, 0, 35, 38, 193, 0, 35, 40, 209, 0, 19, 117, 245, 15, 35, 42, 225, 0, 99, 12, 5, 0, 147, 5, 16, 0, 99, 20, 181, 8, 55, 165, 0, 7, 19, 5, 181, 210, 111, 0, 192, 0, 55, 165, 0, 7, 19, 5, 213, 210, 35, 44, 161, 0, 19, 5, 32, 0, 131, 165, 7, 0, 35, 46, 161, 0, 99, 158, 5, 6, 19, 5, 129, 1, 35, 44, 161, 2, 55, 117, 0, 7, 19, 5, 5, 124, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 117, 0, 7, 19, 5, 133, 115, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 55, 165, 0, 7, 19, 5, 5, 217, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 19, 5, 129, 3, 35, 36, 161, 6, 19, 5, 48, 0, 111, 0, 128, 11, 55, 165, 0, 7, 19, 5, 69, 210, 35, 44, 161, 0, 19, 5, 112, 0, 131, 165, 7, 0, 35, 46, 161, 0, 227, 134, 5, 248, 3, 165, 71, 1, 131, 165, 7, 1, 35, 42, 161, 2, 35, 40, 177, 2, 3, 165, 199, 0, 131, 165, 135, 0, 3, 166, 71, 0, 131, 166, 7, 0, 35, 38, 161, 2, 35, 36, 177, 2, 35, 34, 193, 2, 35, 32, 209, 2, 19, 5, 129, 1, 35, 44, 161, 2, 55, 117, 0, 7, 19, 5, 5, 124, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 117, 0, 7, 19, 5, 133, 115, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 19, 5, 1, 2, 35, 40, 161, 4, 55, 101, 0, 7, 19, 5, 5, 72, 35, 42, 161, 4, 55, 165, 0, 7, 19, 5, 197, 214, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 147, 5, 129, 3, 35, 36, 177, 6, 35, 38, 161, 6, 19, 5, 129, 5, 147, 5, 8, 0, 151, 0, 0, 0, 231, 128, 64, 219, 115, 16, 0, 192, 19, 1, 1, 252, 35, 36, 161, 0, 35, 38, 177, 0, 35, 40, 193, 0, 35, 42, 209, 0, 19, 5, 129, 0, 35, 40, 161, 2, 55, 117, 0, 7, 19, 5, 5, 124, 35, 42, 161, 2, 19, 5, 1, 1, 35, 44, 161, 2, 55, 117, 0, 7, 19, 5, 133, 115, 35, 46, 161, 2, 55, 165, 0, 7, 19, 5, 69, 219, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 147, 5, 7, 0, 151, 0, 0, 0, 231, 128, 0, 212, 115, 16, 0, 192, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 165, 0, 7, 147, 133, 69, 220, 19, 6, 176, 0, 103, 0, 3, 0, 147, 18, 5, 1, 99, 12, 6, 6, 147, 8, 5, 0, 19, 5, 0, 0, 19, 211, 130, 1, 19, 22, 22, 0, 51, 134, 197, 0, 147, 243, 248, 15, 111, 0, 192, 1, 51, 53, 211, 1, 51, 206, 197, 0, 19, 62, 30, 0, 51, 110, 197, 1, 19, 133, 8, 0, 99, 18, 14, 4, 131, 206, 5, 0, 3, 206, 21, 0, 147, 133, 37, 0, 179, 8, 197, 1, 227, 156, 110, 252, 99, 228, 168, 12, 99, 110, 23, 13, 51, 133, 166, 0, 99, 12, 14, 0, 131, 78, 5, 0, 19, 5, 21, 0, 19, 14, 254, 255, 227, 152, 126, 254, 111, 0, 0, 7, 19, 133, 8, 0, 227, 146, 197, 252, 99, 8, 8, 6, 179, 133, 7, 1, 19, 214, 2, 1, 19, 5, 16, 0, 131, 198, 7, 0, 19, 151, 134, 1, 19, 87, 135, 65, 19, 136, 23, 0, 99, 74, 7, 0, 147, 7, 8, 0, 51, 6, 214, 64, 99, 84, 6, 2, 111, 0, 192, 2, 99, 4, 184, 4, 131, 198, 23, 0, 147, 135, 39, 0, 19, 119, 247, 7, 19, 23, 135, 0, 179, 102, 215, 0, 51, 6, 214, 64, 99, 70, 6, 0, 19, 69, 21, 0, 227, 156, 183, 250, 19, 117, 21, 0, 103, 128, 0, 0, 19, 5, 0, 0, 19, 117, 21, 0, 103, 128, 0, 0, 19, 5, 16, 0, 19, 117, 21, 0, 103, 128, 0, 0, 55, 165, 0, 7, 19, 5, 245, 220, 183, 165, 0, 7, 19, 134, 69, 237, 147, 5, 176, 2, 151, 0, 0, 0, 231, 128, 128, 196, 115, 16, 0, 192, 183, 165, 0, 7, 19, 134, 69, 236, 147, 133, 8, 0, 151, 240, 255, 255, 231, 128, 192, 129, 115, 16, 0, 192, 55, 165, 0, 7, 19, 6, 69, 236, 19, 133, 8, 0, 147, 5, 7, 0, 151, 224, 255, 255, 231, 128, 0, 121, 115, 16, 0, 192, 147, 5, 0, 2, 99, 118, 181, 0, 19, 5, 0, 0, 103, 128, 0, 0, 19, 6, 240, 7, 147, 5, 16, 0, 99, 118, 197, 0, 19, 133, 5, 0, 103, 128, 0, 0, 147, 85, 5, 1, 99, 152, 5, 2, 183, 165, 0, 7, 147, 133, 69, 238, 55, 166, 0, 7, 147, 6, 70, 243, 55, 166, 0, 7, 147, 7, 54, 5, 19, 6, 128, 2, 19, 7, 240, 17, 19, 8, 240, 18, 23, 3, 0, 0, 103, 0, 67, 230, 147, 85, 21, 1, 99, 152, 5, 2, 183, 165, 0, 7, 147, 133, 37, 24, 55, 166, 0, 7, 147, 6, 166, 29, 55, 166, 0, 7, 147, 7, 230, 41, 19, 6, 192, 2, 19, 7, 64, 12, 19, 8, 32, 28, 23, 3, 0, 0, 103, 0, 3, 227, 147, 85, 85, 0, 55, 22, 0, 0, 147, 6, 118, 83, 179, 197, 213, 0, 147, 181, 21, 0, 183, 86, 253, 255, 147, 134, 102, 140, 179, 6, 213, 0, 147, 182, 102, 0, 179, 229, 213, 0, 147, 86, 21, 0, 55, 103, 1, 0, 19, 7, 247, 192, 179, 198, 230, 0, 147, 182, 22, 0, 179, 229, 182, 0, 183, 54, 253, 255, 147, 134, 230, 21, 179, 6, 213, 0, 147, 182, 230, 0, 179, 229, 182, 0, 183, 22, 253, 255, 147, 134, 246, 65, 179, 6, 213, 0, 19, 6, 246, 193, 51, 182, 198, 0, 179, 101, 182, 0, 55, 6, 253, 255, 147, 6, 38, 94, 179, 6, 213, 0, 147, 182, 38, 94, 179, 229, 182, 0, 183, 246, 252, 255, 147, 134, 86, 203, 179, 6, 213, 0, 147, 182, 86, 0, 179, 229, 182, 0, 183, 230, 252, 255, 147, 134, 6, 197, 179, 6, 213, 0, 55, 231, 10, 0, 19, 7, 7, 213, 179, 182, 230, 0, 179, 229, 182, 0, 147, 246, 21, 0, 147, 5, 0, 0, 227, 156, 6, 236, 183, 5, 239, 255, 51, 5, 181, 0, 147, 5, 6, 31, 179, 53, 181, 0, 19, 133, 5, 0, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 147, 6, 6, 0, 19, 87, 69, 0, 147, 7, 16, 39, 19, 6, 112, 2, 99, 118, 247, 2, 19, 7, 48, 6, 99, 106, 167, 12, 19, 7, 160, 0, 99, 118, 229, 18, 19, 6, 246, 255, 19, 7, 209, 0, 51, 7, 199, 0, 19, 5, 5, 3, 35, 0, 167, 0, 111, 0, 0, 20, 19, 6, 0, 0, 55, 23, 183, 209, 19, 7, 151, 117, 183, 39, 0, 0, 147, 135, 7, 113, 55, 24, 0, 0, 19, 8, 184, 71, 147, 8, 64, 6, 183, 146, 0, 7, 147, 130, 34, 21, 19, 3, 209, 0, 183, 227, 245, 5, 147, 131, 243, 15, 19, 14, 5, 0, 51, 53, 229, 2, 19, 85, 213, 0, 179, 14, 245, 2, 179, 14, 222, 65, 19, 159, 14, 1, 19, 95, 47, 1, 51, 15, 15, 3, 147, 95, 31, 1, 19, 95, 15, 1, 19, 127, 239, 127, 179, 143, 31, 3, 179, 142, 254, 65, 147, 158, 30, 1, 147, 222, 14, 1, 51, 143, 226, 1, 179, 15, 195, 0, 3, 68, 15, 0, 3, 15, 31, 0, 179, 142, 210, 1, 131, 132, 30, 0, 131, 206, 14, 0, 35, 130, 239, 3, 163, 129, 143, 2, 35, 131, 159, 2, 163, 130, 223, 3, 19, 6, 198, 255, 227, 234, 195, 249, 19, 6, 118, 2, 19, 7, 48, 6, 227, 122, 167, 242, 19, 23, 5, 1, 19, 87, 39, 1, 183, 23, 0, 0, 147, 135, 183, 71, 51, 7, 247, 2, 19, 87, 23, 1, 147, 7, 64, 6, 179, 7, 247, 2, 51, 5, 245, 64, 19, 21, 21, 1, 19, 85, 5, 1, 19, 6, 230, 255, 183, 151, 0, 7, 147, 135, 39, 21, 51, 133, 167, 0, 131, 7, 21, 0, 3, 69, 5, 0, 19, 8, 209, 0, 51, 8, 200, 0, 163, 0, 248, 0, 35, 0, 168, 0, 19, 5, 7, 0, 19, 7, 160, 0, 227, 110, 229, 236, 19, 21, 21, 0, 19, 6, 230, 255, 55, 151, 0, 7, 19, 7, 39, 21, 51, 5, 167, 0, 3, 7, 21, 0, 3, 69, 5, 0, 147, 7, 209, 0, 179, 135, 199, 0, 163, 128, 231, 0, 35, 128, 167, 0, 19, 5, 209, 0, 51, 7, 197, 0, 19, 5, 112, 2, 179, 7, 197, 64, 55, 165, 0, 7, 19, 6, 5, 70, 19, 133, 6, 0, 147, 6, 0, 0, 151, 240, 255, 255, 231, 128, 128, 130, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 19, 1, 1, 4, 103, 128, 0, 0, 3, 38, 5, 0, 19, 85, 246, 65, 179, 70, 166, 0, 51, 133, 166, 64, 19, 70, 246, 255, 19, 86, 246, 1, 147, 134, 5, 0, 147, 5, 6, 0, 19, 134, 6, 0, 23, 3, 0, 0, 103, 0, 131, 225, 3, 37, 5, 0, 19, 134, 5, 0, 147, 5, 16, 0, 23, 3, 0, 0, 103, 0, 67, 224, 179, 6, 181, 64, 99, 244, 198, 10, 179, 6, 197, 0, 19, 7, 240, 0, 179, 135, 197, 0, 99, 116, 199, 18, 19, 248, 54, 0, 179, 8, 0, 65, 19, 247, 198, 255, 99, 2, 8, 2, 179, 130, 197, 0, 147, 130, 242, 255, 3, 131, 2, 0, 147, 131, 246, 255, 163, 143, 102, 254, 147, 130, 242, 255, 147, 134, 3, 0, 227, 102, 119, 254, 179, 135, 23, 1, 51, 6, 6, 65, 147, 118, 198, 255, 147, 248, 55, 0, 51, 8, 208, 64, 179, 6, 215, 64, 99, 136, 8, 14, 99, 88, 8, 16, 147, 149, 56, 0, 147, 242, 199, 255, 3, 163, 2, 0, 179, 8, 176, 64, 147, 248, 136, 1, 147, 130, 194, 255, 131, 163, 2, 0, 51, 19, 19, 1, 51, 222, 179, 0, 51, 99, 110, 0, 19, 14, 199, 255, 35, 46, 103, 254, 147, 130, 194, 255, 19, 7, 14, 0, 19, 131, 3, 0, 227, 238, 198, 253, 111, 0, 192, 12, 147, 6, 240, 0, 99, 250, 198, 8, 179, 6, 160, 64, 147, 246, 54, 0, 51, 7, 213, 0, 99, 128, 6, 2, 147, 7, 5, 0, 19, 136, 5, 0, 131, 8, 8, 0, 35, 128, 23, 1, 147, 135, 23, 0, 19, 8, 24, 0, 227, 232, 231, 254, 179, 133, 213, 0, 51, 6, 214, 64, 147, 119, 198, 255, 19, 248, 53, 0, 179, 6, 247, 0, 99, 8, 8, 10, 99, 84, 240, 12, 19, 24, 56, 0, 147, 242, 197, 255, 3, 163, 2, 0, 179, 8, 0, 65, 147, 248, 136, 1, 147, 130, 66, 0, 131, 163, 2, 0, 51, 83, 3, 1, 51, 158, 19, 1, 51, 99, 110, 0, 35, 32, 103, 0, 19, 7, 71, 0, 147, 130, 66, 0, 19, 131, 3, 0, 227, 96, 215, 254, 111, 0, 128, 8, 147, 5, 5, 0, 111, 0, 64, 4, 147, 6, 5, 0, 99, 18, 6, 8, 111, 0, 128, 9, 99, 82, 8, 2, 179, 5, 182, 0, 147, 133, 197, 255, 131, 168, 5, 0, 147, 2, 199, 255, 35, 46, 23, 255, 147, 133, 197, 255, 19, 135, 2, 0, 227, 230, 86, 254, 147, 117, 54, 0, 99, 134, 5, 6, 179, 135, 7, 1, 179, 133, 182, 64, 19, 134, 247, 255, 3, 7, 6, 0, 147, 135, 246, 255, 163, 143, 230, 254, 19, 6, 246, 255, 147, 134, 7, 0, 227, 230, 245, 254, 111, 0, 64, 4, 99, 94, 240, 0, 19, 136, 5, 0, 131, 40, 8, 0, 35, 32, 23, 1, 19, 7, 71, 0, 19, 8, 72, 0, 227, 104, 215, 254, 179, 133, 245, 0, 19, 118, 54, 0, 99, 14, 6, 0, 51, 134, 198, 0, 3, 135, 5, 0, 35, 128, 230, 0, 147, 134, 22, 0, 147, 133, 21, 0, 227, 232, 198, 254, 103, 128, 0, 0, 23, 3, 0, 0, 103, 0, 131, 225, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 184, 10, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 192, 45, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 101, 110, 118, 46, 114, 115, 160, 129, 0, 7, 96, 0, 0, 0, 14, 1, 0, 0, 9, 0, 0, 0, 160, 129, 0, 7, 96, 0, 0, 0, 99, 1, 0, 0, 9, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 128, 19, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 52, 68, 0, 7, 115, 114, 99, 47, 98, 105, 110, 47, 99, 108, 117, 115, 116, 101, 114, 46, 114, 115, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 22, 0, 0, 0, 5, 0, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 33, 0, 0, 0, 21, 0, 0, 0, 97, 116, 116, 101, 109, 112, 116, 32, 116, 111, 32, 99, 97, 108, 99, 117, 108, 97, 116, 101, 32, 116, 104, 101, 32, 114, 101, 109, 97, 105, 110, 100, 101, 114, 32, 119, 105, 116, 104, 32, 97, 32, 100, 105, 118, 105, 115, 111, 114, 32, 111, 102, 32, 122, 101, 114, 111, 0, 0, 0, 128, 19, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 192, 45, 0, 7, 92, 130, 0, 7, 18, 0, 0, 0, 41, 0, 0, 0, 70, 0, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 41, 0, 0, 0, 37, 0, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 42, 0, 0, 0, 70, 0, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 42, 0, 0, 0, 37, 0, 0, 0, 32, 32, 32, 32, 32, 60, 61, 32, 0, 0, 0, 0, 115, 101, 101, 100, 15, 7, 7, 3, 67, 108, 117, 115, 116, 101, 114, 66, 111, 109, 98, 80, 97, 114, 97, 109, 115, 115, 116, 97, 116, 101, 117, 112, 112, 101, 114, 95, 108, 101, 102, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 100, 111, 119, 110, 95, 114, 105, 103, 104, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 0, 0, 65, 131, 0, 7, 5, 0, 0, 0, 70, 131, 0, 7, 22, 0, 0, 0, 92, 131, 0, 7, 22, 0, 0, 0, 40, 131, 0, 7, 4, 0, 0, 0, 72, 105, 116, 84, 121, 112, 101, 68, 101, 115, 116, 114, 111, 121, 101, 100, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 119, 105, 100, 116, 104, 32, 62, 32, 48, 32, 38, 38, 32, 104, 101, 105, 103, 104, 116, 32, 62, 32, 48, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 47, 103, 97, 109, 101, 47, 115, 114, 99, 47, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 114, 115, 0, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 28, 0, 0, 0, 9, 0, 0, 0, 84, 104, 101, 32, 97, 114, 101, 97, 32, 105, 115, 32, 116, 111, 111, 32, 98, 105, 103, 33, 28, 132, 0, 7, 20, 0, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 34, 0, 0, 0, 18, 0, 0, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 112, 111, 115, 105, 116, 105, 111, 110, 46, 120, 32, 62, 61, 32, 115, 101, 108, 102, 46, 117, 112, 112, 101, 114, 95, 108, 101, 102, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 46, 120, 32, 38, 38, 92, 110, 32, 32, 32, 32, 112, 111, 115, 105, 116, 105, 111, 110, 46, 120, 32, 60, 61, 32, 115, 101, 108, 102, 46, 100, 111, 119, 110, 95, 114, 105, 103, 104, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 46, 120, 0, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 39, 0, 0, 0, 9, 0, 0, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 112, 111, 115, 105, 116, 105, 111, 110, 46, 121, 32, 62, 61, 32, 115, 101, 108, 102, 46, 117, 112, 112, 101, 114, 95, 108, 101, 102, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 46, 121, 32, 38, 38, 92, 110, 32, 32, 32, 32, 112, 111, 115, 105, 116, 105, 111, 110, 46, 121, 32, 60, 61, 32, 115, 101, 108, 102, 46, 100, 111, 119, 110, 95, 114, 105, 103, 104, 116, 95, 99, 111, 111, 114, 100, 105, 110, 97, 116, 101, 115, 46, 121, 0, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 40, 0, 0, 0, 9, 0, 0, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 115, 104, 111, 116, 46, 99, 104, 101, 99, 107, 40, 41, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 152, 0, 0, 0, 9, 0, 0, 0, 224, 45, 0, 7, 244, 45, 0, 7, 8, 46, 0, 7, 28, 46, 0, 7, 48, 46, 0, 7, 68, 46, 0, 7, 88, 46, 0, 7, 83, 101, 114, 105, 97, 108, 105, 122, 101, 66, 117, 102, 102, 101, 114, 70, 117, 108, 108, 78, 111, 116, 83, 117, 112, 112, 111, 114, 116, 101, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 85, 110, 101, 120, 112, 101, 99, 116, 101, 100, 69, 110, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 85, 116, 102, 56, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 79, 112, 116, 105, 111, 110, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 67, 104, 97, 114, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 66, 111, 111, 108, 0, 0, 0, 32, 134, 0, 7, 0, 0, 0, 0, 77, 101, 109, 111, 114, 121, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 102, 97, 105, 108, 117, 114, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 115, 104, 97, 46, 114, 115, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 98, 121, 116, 101, 115, 46, 108, 101, 110, 40, 41, 32, 60, 61, 32, 112, 97, 100, 108, 101, 110, 32, 42, 32, 87, 79, 82, 68, 95, 83, 73, 90, 69, 65, 134, 0, 7, 96, 0, 0, 0, 112, 0, 0, 0, 5, 0, 0, 0, 65, 134, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 20, 0, 0, 0, 65, 134, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 9, 0, 0, 0, 152, 52, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 108, 46, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 112, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 99, 111, 114, 101, 47, 115, 104, 97, 46, 114, 115, 0, 0, 20, 135, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 17, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 0, 20, 135, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 33, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 0, 192, 135, 0, 7, 103, 0, 0, 0, 222, 0, 0, 0, 9, 0, 0, 0, 85, 110, 97, 98, 108, 101, 32, 116, 111, 32, 97, 108, 105, 103, 110, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 116, 111, 32, 119, 111, 114, 100, 32, 115, 105, 122, 101, 0, 184, 57, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 168, 122, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 97, 108, 108, 111, 99, 46, 114, 115, 0, 0, 152, 136, 0, 7, 98, 0, 0, 0, 27, 0, 0, 0, 22, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 109, 111, 100, 46, 114, 115, 12, 137, 0, 7, 96, 0, 0, 0, 88, 0, 0, 0, 28, 0, 0, 0, 69, 114, 114, 111, 114, 117, 110, 107, 110, 111, 119, 110, 95, 99, 111, 100, 101, 0, 0, 0, 48, 68, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 152, 67, 0, 7, 105, 110, 116, 101, 114, 110, 97, 108, 95, 99, 111, 100, 101, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 48, 68, 0, 7, 8, 0, 0, 0, 4, 0, 0, 0, 92, 69, 0, 7, 32, 40, 98, 121, 116, 101, 115, 32, 111, 115, 95, 101, 114, 114, 111, 114, 48, 68, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 0, 67, 0, 7, 78, 111, 100, 101, 46, 106, 115, 32, 69, 83, 32, 109, 111, 100, 117, 108, 101, 115, 32, 97, 114, 101, 32, 110, 111, 116, 32, 100, 105, 114, 101, 99, 116, 108, 121, 32, 115, 117, 112, 112, 111, 114, 116, 101, 100, 44, 32, 115, 101, 101, 32, 104, 116, 116, 112, 115, 58, 47, 47, 100, 111, 99, 115, 46, 114, 115, 47, 103, 101, 116, 114, 97, 110, 100, 111, 109, 35, 110, 111, 100, 101, 106, 115, 45, 101, 115, 45, 109, 111, 100, 117, 108, 101, 45, 115, 117, 112, 112, 111, 114, 116, 67, 97, 108, 108, 105, 110, 103, 32, 78, 111, 100, 101, 46, 106, 115, 32, 65, 80, 73, 32, 99, 114, 121, 112, 116, 111, 46, 114, 97, 110, 100, 111, 109, 70, 105, 108, 108, 83, 121, 110, 99, 32, 102, 97, 105, 108, 101, 100, 78, 111, 100, 101, 46, 106, 115, 32, 99, 114, 121, 112, 116, 111, 32, 67, 111, 109, 109, 111, 110, 74, 83, 32, 109, 111, 100, 117, 108, 101, 32, 105, 115, 32, 117, 110, 97, 118, 97, 105, 108, 97, 98, 108, 101, 114, 97, 110, 100, 83, 101, 99, 117, 114, 101, 58, 32, 86, 120, 87, 111, 114, 107, 115, 32, 82, 78, 71, 32, 109, 111, 100, 117, 108, 101, 32, 105, 115, 32, 110, 111, 116, 32, 105, 110, 105, 116, 105, 97, 108, 105, 122, 101, 100, 67, 97, 108, 108, 105, 110, 103, 32, 87, 101, 98, 32, 65, 80, 73, 32, 99, 114, 121, 112, 116, 111, 46, 103, 101, 116, 82, 97, 110, 100, 111, 109, 86, 97, 108, 117, 101, 115, 32, 102, 97, 105, 108, 101, 100, 87, 101, 98, 32, 67, 114, 121, 112, 116, 111, 32, 65, 80, 73, 32, 105, 115, 32, 117, 110, 97, 118, 97, 105, 108, 97, 98, 108, 101, 82, 68, 82, 65, 78, 68, 58, 32, 105, 110, 115, 116, 114, 117, 99, 116, 105, 111, 110, 32, 110, 111, 116, 32, 115, 117, 112, 112, 111, 114, 116, 101, 100, 82, 68, 82, 65, 78, 68, 58, 32, 102, 97, 105, 108, 101, 100, 32, 109, 117, 108, 116, 105, 112, 108, 101, 32, 116, 105, 109, 101, 115, 58, 32, 67, 80, 85, 32, 105, 115, 115, 117, 101, 32, 108, 105, 107, 101, 108, 121, 82, 116, 108, 71, 101, 110, 82, 97, 110, 100, 111, 109, 58, 32, 87, 105, 110, 100, 111, 119, 115, 32, 115, 121, 115, 116, 101, 109, 32, 102, 117, 110, 99, 116, 105, 111, 110, 32, 102, 97, 105, 108, 117, 114, 101, 83, 101, 99, 82, 97, 110, 100, 111, 109, 67, 111, 112, 121, 66, 121, 116, 101, 115, 58, 32, 105, 79, 83, 32, 83, 101, 99, 117, 114, 105, 116, 121, 32, 102, 114, 97, 109, 101, 119, 111, 114, 107, 32, 102, 97, 105, 108, 117, 114, 101, 101, 114, 114, 110, 111, 58, 32, 100, 105, 100, 32, 110, 111, 116, 32, 114, 101, 116, 117, 114, 110, 32, 97, 32, 112, 111, 115, 105, 116, 105, 118, 101, 32, 118, 97, 108, 117, 101, 103, 101, 116, 114, 97, 110, 100, 111, 109, 58, 32, 116, 104, 105, 115, 32, 116, 97, 114, 103, 101, 116, 32, 105, 115, 32, 110, 111, 116, 32, 115, 117, 112, 112, 111, 114, 116, 101, 100, 0, 0, 0, 39, 0, 0, 0, 38, 0, 0, 0, 39, 0, 0, 0, 50, 0, 0, 0, 45, 0, 0, 0, 47, 0, 0, 0, 33, 0, 0, 0, 29, 0, 0, 0, 45, 0, 0, 0, 39, 0, 0, 0, 39, 0, 0, 0, 49, 0, 0, 0, 45, 0, 0, 0, 48, 0, 0, 0, 101, 0, 0, 0, 250, 139, 0, 7, 212, 139, 0, 7, 250, 139, 0, 7, 162, 139, 0, 7, 117, 139, 0, 7, 70, 139, 0, 7, 37, 139, 0, 7, 8, 139, 0, 7, 219, 138, 0, 7, 250, 139, 0, 7, 250, 139, 0, 7, 170, 138, 0, 7, 125, 138, 0, 7, 77, 138, 0, 7, 232, 137, 0, 7, 106, 9, 230, 103, 187, 103, 174, 133, 60, 110, 243, 114, 165, 79, 245, 58, 81, 14, 82, 127, 155, 5, 104, 140, 31, 131, 217, 171, 91, 224, 205, 25, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 82, 65, 78, 68, 79, 77, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 80, 65, 78, 73, 67, 0, 188, 140, 0, 7, 103, 0, 0, 0, 62, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 82, 69, 65, 68, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 110, 114, 101, 97, 100, 32, 97, 115, 32, 117, 115, 105, 122, 101, 32, 60, 61, 32, 110, 98, 121, 116, 101, 115, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 0, 188, 140, 0, 7, 103, 0, 0, 0, 189, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 87, 82, 73, 84, 69, 0, 79, 117, 116, 32, 111, 102, 32, 109, 101, 109, 111, 114, 121, 33, 0, 0, 188, 140, 0, 7, 103, 0, 0, 0, 240, 1, 0, 0, 31, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 114, 97, 119, 95, 118, 101, 99, 46, 114, 115, 99, 97, 112, 97, 99, 105, 116, 121, 32, 111, 118, 101, 114, 102, 108, 111, 119, 47, 143, 0, 7, 17, 0, 0, 0, 112, 142, 0, 7, 191, 0, 0, 0, 6, 2, 0, 0, 5, 0, 0, 0, 97, 32, 102, 111, 114, 109, 97, 116, 116, 105, 110, 103, 32, 116, 114, 97, 105, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 114, 101, 116, 117, 114, 110, 101, 100, 32, 97, 110, 32, 101, 114, 114, 111, 114, 0, 112, 73, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 28, 119, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 102, 109, 116, 46, 114, 115, 0, 156, 143, 0, 7, 187, 0, 0, 0, 100, 2, 0, 0, 9, 0, 0, 0, 224, 74, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 172, 76, 0, 7, 228, 74, 0, 7, 72, 76, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 102, 109, 116, 47, 110, 117, 109, 46, 114, 115, 0, 0, 128, 144, 0, 7, 190, 0, 0, 0, 101, 0, 0, 0, 20, 0, 0, 0, 48, 120, 48, 48, 48, 49, 48, 50, 48, 51, 48, 52, 48, 53, 48, 54, 48, 55, 48, 56, 48, 57, 49, 48, 49, 49, 49, 50, 49, 51, 49, 52, 49, 53, 49, 54, 49, 55, 49, 56, 49, 57, 50, 48, 50, 49, 50, 50, 50, 51, 50, 52, 50, 53, 50, 54, 50, 55, 50, 56, 50, 57, 51, 48, 51, 49, 51, 50, 51, 51, 51, 52, 51, 53, 51, 54, 51, 55, 51, 56, 51, 57, 52, 48, 52, 49, 52, 50, 52, 51, 52, 52, 52, 53, 52, 54, 52, 55, 52, 56, 52, 57, 53, 48, 53, 49, 53, 50, 53, 51, 53, 52, 53, 53, 53, 54, 53, 55, 53, 56, 53, 57, 54, 48, 54, 49, 54, 50, 54, 51, 54, 52, 54, 53, 54, 54, 54, 55, 54, 56, 54, 57, 55, 48, 55, 49, 55, 50, 55, 51, 55, 52, 55, 53, 55, 54, 55, 55, 55, 56, 55, 57, 56, 48, 56, 49, 56, 50, 56, 51, 56, 52, 56, 53, 56, 54, 56, 55, 56, 56, 56, 57, 57, 48, 57, 49, 57, 50, 57, 51, 57, 52, 57, 53, 57, 54, 57, 55, 57, 56, 57, 57, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 117, 110, 105, 99, 111, 100, 101, 47, 117, 110, 105, 99, 111, 100, 101, 95, 100, 97, 116, 97, 46, 114, 115, 0, 0, 0, 26, 146, 0, 7, 203, 0, 0, 0, 87, 0, 0, 0, 62, 0, 0, 0, 0, 3, 0, 0, 131, 4, 32, 0, 145, 5, 96, 0, 93, 19, 160, 0, 18, 23, 32, 31, 12, 32, 96, 31, 239, 44, 160, 43, 42, 48, 32, 44, 111, 166, 224, 44, 2, 168, 96, 45, 30, 251, 96, 46, 0, 254, 32, 54, 158, 255, 96, 54, 253, 1, 225, 54, 1, 10, 33, 55, 36, 13, 225, 55, 171, 14, 97, 57, 47, 24, 161, 57, 48, 28, 97, 72, 243, 30, 161, 76, 64, 52, 97, 80, 240, 106, 161, 81, 79, 111, 33, 82, 157, 188, 161, 82, 0, 207, 97, 83, 101, 209, 161, 83, 0, 218, 33, 84, 0, 224, 225, 85, 174, 226, 97, 87, 236, 228, 33, 89, 208, 232, 161, 89, 32, 0, 238, 89, 240, 1, 127, 90, 0, 112, 0, 7, 0, 45, 1, 1, 1, 2, 1, 2, 1, 1, 72, 11, 48, 21, 16, 1, 101, 7, 2, 6, 2, 2, 1, 4, 35, 1, 30, 27, 91, 11, 58, 9, 9, 1, 24, 4, 1, 9, 1, 3, 1, 5, 43, 3, 60, 8, 42, 24, 1, 32, 55, 1, 1, 1, 4, 8, 4, 1, 3, 7, 10, 2, 29, 1, 58, 1, 1, 1, 2, 4, 8, 1, 9, 1, 10, 2, 26, 1, 2, 2, 57, 1, 4, 2, 4, 2, 2, 3, 3, 1, 30, 2, 3, 1, 11, 2, 57, 1, 4, 5, 1, 2, 4, 1, 20, 2, 22, 6, 1, 1, 58, 1, 1, 2, 1, 4, 8, 1, 7, 3, 10, 2, 30, 1, 59, 1, 1, 1, 12, 1, 9, 1, 40, 1, 3, 1, 55, 1, 1, 3, 5, 3, 1, 4, 7, 2, 11, 2, 29, 1, 58, 1, 2, 1, 2, 1, 3, 1, 5, 2, 7, 2, 11, 2, 28, 2, 57, 2, 1, 1, 2, 4, 8, 1, 9, 1, 10, 2, 29, 1, 72, 1, 4, 1, 2, 3, 1, 1, 8, 1, 81, 1, 2, 7, 12, 8, 98, 1, 2, 9, 11, 7, 73, 2, 27, 1, 1, 1, 1, 1, 55, 14, 1, 5, 1, 2, 5, 11, 1, 36, 9, 1, 102, 4, 1, 6, 1, 2, 2, 2, 25, 2, 4, 3, 16, 4, 13, 1, 2, 2, 6, 1, 15, 1, 0, 3, 0, 3, 29, 2, 30, 2, 30, 2, 64, 2, 1, 7, 8, 1, 2, 11, 9, 1, 45, 3, 1, 1, 117, 2, 34, 1, 118, 3, 4, 2, 9, 1, 6, 3, 219, 2, 2, 1, 58, 1, 1, 7, 1, 1, 1, 1, 2, 8, 6, 10, 2, 1, 48, 31, 49, 4, 48, 7, 1, 1, 5, 1, 40, 9, 12, 2, 32, 4, 2, 2, 1, 3, 56, 1, 1, 2, 3, 1, 1, 3, 58, 8, 2, 2, 152, 3, 1, 13, 1, 7, 4, 1, 6, 1, 3, 2, 198, 64, 0, 1, 195, 33, 0, 3, 141, 1, 96, 32, 0, 6, 105, 2, 0, 4, 1, 10, 32, 2, 80, 2, 0, 1, 3, 1, 4, 1, 25, 2, 5, 1, 151, 2, 26, 18, 13, 1, 38, 8, 25, 11, 46, 3, 48, 1, 2, 4, 2, 2, 39, 1, 67, 6, 2, 2, 2, 2, 12, 1, 8, 1, 47, 1, 51, 1, 1, 3, 2, 2, 5, 2, 1, 1, 42, 2, 8, 1, 238, 1, 2, 1, 4, 1, 0, 1, 0, 16, 16, 16, 0, 2, 0, 1, 226, 1, 149, 5, 0, 3, 1, 2, 5, 4, 40, 3, 4, 1, 165, 2, 0, 4, 0, 2, 80, 3, 70, 11, 49, 4, 123, 1, 54, 15, 41, 1, 2, 2, 10, 3, 49, 4, 2, 2, 7, 1, 61, 3, 36, 5, 1, 8, 62, 1, 12, 2, 52, 9, 10, 4, 2, 1, 95, 3, 2, 1, 1, 2, 6, 1, 2, 1, 157, 1, 3, 8, 21, 2, 57, 2, 1, 1, 1, 1, 22, 1, 14, 7, 3, 5, 195, 8, 2, 3, 1, 1, 23, 1, 81, 1, 2, 6, 1, 1, 2, 1, 1, 2, 1, 2, 235, 1, 2, 4, 6, 2, 1, 2, 27, 2, 85, 8, 2, 1, 1, 2, 106, 1, 1, 1, 2, 6, 1, 1, 101, 3, 2, 4, 1, 5, 0, 9, 1, 2, 245, 1, 10, 2, 1, 1, 4, 1, 144, 4, 2, 2, 4, 1, 32, 10, 40, 6, 2, 4, 8, 1, 9, 6, 2, 3, 46, 13, 1, 2, 0, 7, 1, 6, 1, 1, 82, 22, 2, 7, 1, 2, 1, 2, 122, 6, 3, 1, 1, 2, 1, 7, 1, 1, 72, 2, 3, 1, 1, 1, 0, 2, 11, 2, 52, 5, 5, 1, 1, 1, 0, 1, 6, 15, 0, 5, 59, 7, 0, 1, 63, 4, 81, 1, 0, 2, 0, 46, 2, 23, 0, 1, 1, 3, 4, 5, 8, 8, 2, 7, 30, 4, 148, 3, 0, 55, 4, 50, 8, 1, 14, 1, 22, 5, 1, 15, 0, 7, 1, 17, 2, 7, 1, 2, 1, 5, 100, 1, 160, 7, 0, 1, 61, 4, 0, 4, 0, 7, 109, 7, 0, 96, 128, 240, 0, 0, 26, 146, 0, 7, 203, 0, 0, 0, 63, 1, 0, 0, 9, 0, 0, 0, 115, 111, 117, 114, 99, 101, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 32, 100, 111, 101, 115, 32, 110, 111, 116, 32, 109, 97, 116, 99, 104, 32, 100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 0, 0, 0, 100, 150, 0, 7, 21, 0, 0, 0, 121, 150, 0, 7, 43, 0, 0, 0, 164, 150, 0, 7, 1, 0, 0, 0, 41, 32, 119, 104, 101, 110, 32, 115, 108, 105, 99, 105, 110, 103, 32, 96, 114, 97, 110, 103, 101, 32, 101, 110, 100, 32, 105, 110, 100, 101, 120, 32, 91, 46, 46, 46, 93, 98, 121, 116, 101, 32, 105, 110, 100, 101, 120, 32, 32, 105, 115, 32, 111, 117, 116, 32, 111, 102, 32, 98, 111, 117, 110, 100, 115, 32, 111, 102, 32, 96, 96, 0, 229, 150, 0, 7, 11, 0, 0, 0, 240, 150, 0, 7, 22, 0, 0, 0, 6, 151, 0, 7, 1, 0, 0, 0, 98, 101, 103, 105, 110, 32, 60, 61, 32, 101, 110, 100, 32, 40, 0, 0, 32, 151, 0, 7, 14, 0, 0, 0, 32, 131, 0, 7, 4, 0, 0, 0, 192, 150, 0, 7, 16, 0, 0, 0, 6, 151, 0, 7, 1, 0, 0, 0, 32, 105, 115, 32, 110, 111, 116, 32, 97, 32, 99, 104, 97, 114, 32, 98, 111, 117, 110, 100, 97, 114, 121, 59, 32, 105, 116, 32, 105, 115, 32, 105, 110, 115, 105, 100, 101, 32, 41, 32, 111, 102, 32, 96, 229, 150, 0, 7, 11, 0, 0, 0, 80, 151, 0, 7, 38, 0, 0, 0, 200, 137, 0, 7, 8, 0, 0, 0, 118, 151, 0, 7, 6, 0, 0, 0, 6, 151, 0, 7, 1, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 115, 116, 114, 47, 109, 111, 100, 46, 114, 115, 0, 0, 0, 207, 151, 0, 7, 190, 0, 0, 0, 7, 1, 0, 0, 29, 0, 0, 0, 58, 0, 0, 0, 160, 152, 0, 7, 0, 0, 0, 0, 160, 152, 0, 7, 1, 0, 0, 0, 160, 152, 0, 7, 1, 0, 0, 0, 32, 123, 10, 58, 32, 44, 10, 44, 32, 32, 123, 32, 125, 32, 125, 0, 32, 84, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 4, 90, 0, 7, 16, 90, 0, 7, 236, 90, 0, 7, 112, 97, 110, 105, 99, 107, 101, 100, 32, 97, 116, 32, 39, 39, 44, 32, 240, 152, 0, 7, 1, 0, 0, 0, 241, 152, 0, 7, 3, 0, 0, 0, 4, 153, 0, 7, 0, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 115, 108, 105, 99, 101, 47, 109, 101, 109, 99, 104, 114, 46, 114, 115, 0, 12, 153, 0, 7, 195, 0, 0, 0, 104, 0, 0, 0, 39, 0, 0, 0, 46, 46, 0, 0, 224, 153, 0, 7, 2, 0, 0, 0, 114, 97, 110, 103, 101, 32, 115, 116, 97, 114, 116, 32, 105, 110, 100, 101, 120, 32, 32, 111, 117, 116, 32, 111, 102, 32, 114, 97, 110, 103, 101, 32, 102, 111, 114, 32, 115, 108, 105, 99, 101, 32, 111, 102, 32, 108, 101, 110, 103, 116, 104, 32, 236, 153, 0, 7, 18, 0, 0, 0, 254, 153, 0, 7, 34, 0, 0, 0, 208, 150, 0, 7, 16, 0, 0, 0, 254, 153, 0, 7, 34, 0, 0, 0, 115, 108, 105, 99, 101, 32, 105, 110, 100, 101, 120, 32, 115, 116, 97, 114, 116, 115, 32, 97, 116, 32, 32, 98, 117, 116, 32, 101, 110, 100, 115, 32, 97, 116, 32, 0, 64, 154, 0, 7, 22, 0, 0, 0, 86, 154, 0, 7, 13, 0, 0, 0, 224, 112, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 72, 112, 0, 7, 140, 114, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 164, 114, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 24, 113, 0, 7, 216, 112, 0, 7, 96, 114, 0, 7, 152, 113, 0, 7, 232, 113, 0, 7, 60, 114, 0, 7, 72, 114, 0, 7, 84, 114, 0, 7, 236, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 0, 117, 0, 7, 76, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 84, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 32, 117, 0, 7, 16, 117, 0, 7, 220, 118, 0, 7, 28, 118, 0, 7, 104, 118, 0, 7, 184, 118, 0, 7, 196, 118, 0, 7, 208, 118, 0, 7, 124, 100, 0, 7, 12, 0, 0, 0, 4, 0, 0, 0, 36, 84, 0, 7, 204, 88, 0, 7, 164, 89, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 102, 109, 116, 47, 109, 111, 100, 46, 114, 115, 0, 0, 232, 155, 0, 7, 190, 0, 0, 0, 123, 9, 0, 0, 30, 0, 0, 0, 232, 155, 0, 7, 190, 0, 0, 0, 130, 9, 0, 0, 22, 0, 0, 0, 69, 114, 114, 111, 114, 0, 0, 0, 105, 110, 100, 101, 120, 32, 111, 117, 116, 32, 111, 102, 32, 98, 111, 117, 110, 100, 115, 58, 32, 116, 104, 101, 32, 108, 101, 110, 32, 105, 115, 32, 216, 119, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 100, 93, 0, 7, 32, 98, 117, 116, 32, 116, 104, 101, 32, 105, 110, 100, 101, 120, 32, 105, 115, 32, 0, 0, 208, 156, 0, 7, 32, 0, 0, 0, 0, 157, 0, 7, 18, 0, 0, 0, 109, 97, 116, 99, 104, 101, 115, 33, 61, 61, 61, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 96, 40, 108, 101, 102, 116, 32, 32, 114, 105, 103, 104, 116, 41, 96, 10, 32, 32, 108, 101, 102, 116, 58, 32, 96, 96, 44, 10, 32, 114, 105, 103, 104, 116, 58, 32, 96, 96, 58, 32, 0, 0, 0, 47, 157, 0, 7, 25, 0, 0, 0, 72, 157, 0, 7, 18, 0, 0, 0, 90, 157, 0, 7, 12, 0, 0, 0, 102, 157, 0, 7, 3, 0, 0, 0, 96, 0, 0, 0, 47, 157, 0, 7, 25, 0, 0, 0, 72, 157, 0, 7, 18, 0, 0, 0, 90, 157, 0, 7, 12, 0, 0, 0, 140, 157, 0, 7, 1, 0, 0, 0, 58, 32, 0, 0, 208, 156, 0, 7, 0, 0, 0, 0, 176, 157, 0, 7, 2, 0, 0, 0, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 117, 110, 105, 99, 111, 100, 101, 47, 112, 114, 105, 110, 116, 97, 98, 108, 101, 46, 114, 115, 0, 0, 250, 157, 0, 7, 200, 0, 0, 0, 10, 0, 0, 0, 28, 0, 0, 0, 250, 157, 0, 7, 200, 0, 0, 0, 26, 0, 0, 0, 40, 0, 0, 0, 0, 1, 3, 5, 5, 6, 6, 2, 7, 6, 8, 7, 9, 17, 10, 28, 11, 25, 12, 26, 13, 16, 14, 12, 15, 4, 16, 3, 18, 18, 19, 9, 22, 1, 23, 4, 24, 1, 25, 3, 26, 7, 27, 1, 28, 2, 31, 22, 32, 3, 43, 3, 45, 11, 46, 1, 48, 3, 49, 2, 50, 1, 167, 2, 169, 2, 170, 4, 171, 8, 250, 2, 251, 5, 253, 2, 254, 3, 255, 9, 173, 120, 121, 139, 141, 162, 48, 87, 88, 139, 140, 144, 28, 221, 14, 15, 75, 76, 251, 252, 46, 47, 63, 92, 93, 95, 226, 132, 141, 142, 145, 146, 169, 177, 186, 187, 197, 198, 201, 202, 222, 228, 229, 255, 0, 4, 17, 18, 41, 49, 52, 55, 58, 59, 61, 73, 74, 93, 132, 142, 146, 169, 177, 180, 186, 187, 198, 202, 206, 207, 228, 229, 0, 4, 13, 14, 17, 18, 41, 49, 52, 58, 59, 69, 70, 73, 74, 94, 100, 101, 132, 145, 155, 157, 201, 206, 207, 13, 17, 41, 58, 59, 69, 73, 87, 91, 92, 94, 95, 100, 101, 141, 145, 169, 180, 186, 187, 197, 201, 223, 228, 229, 240, 13, 17, 69, 73, 100, 101, 128, 132, 178, 188, 190, 191, 213, 215, 240, 241, 131, 133, 139, 164, 166, 190, 191, 197, 199, 207, 218, 219, 72, 152, 189, 205, 198, 206, 207, 73, 78, 79, 87, 89, 94, 95, 137, 142, 143, 177, 182, 183, 191, 193, 198, 199, 215, 17, 22, 23, 91, 92, 246, 247, 254, 255, 128, 109, 113, 222, 223, 14, 31, 110, 111, 28, 29, 95, 125, 126, 174, 175, 127, 187, 188, 22, 23, 30, 31, 70, 71, 78, 79, 88, 90, 92, 94, 126, 127, 181, 197, 212, 213, 220, 240, 241, 245, 114, 115, 143, 116, 117, 150, 38, 46, 47, 167, 175, 183, 191, 199, 207, 215, 223, 154, 64, 151, 152, 48, 143, 31, 210, 212, 206, 255, 78, 79, 90, 91, 7, 8, 15, 16, 39, 47, 238, 239, 110, 111, 55, 61, 63, 66, 69, 144, 145, 83, 103, 117, 200, 201, 208, 209, 216, 217, 231, 254, 255, 0, 32, 95, 34, 130, 223, 4, 130, 68, 8, 27, 4, 6, 17, 129, 172, 14, 128, 171, 5, 31, 9, 129, 27, 3, 25, 8, 1, 4, 47, 4, 52, 4, 7, 3, 1, 7, 6, 7, 17, 10, 80, 15, 18, 7, 85, 7, 3, 4, 28, 10, 9, 3, 8, 3, 7, 3, 2, 3, 3, 3, 12, 4, 5, 3, 11, 6, 1, 14, 21, 5, 78, 7, 27, 7, 87, 7, 2, 6, 23, 12, 80, 4, 67, 3, 45, 3, 1, 4, 17, 6, 15, 12, 58, 4, 29, 37, 95, 32, 109, 4, 106, 37, 128, 200, 5, 130, 176, 3, 26, 6, 130, 253, 3, 89, 7, 22, 9, 24, 9, 20, 12, 20, 12, 106, 6, 10, 6, 26, 6, 89, 7, 43, 5, 70, 10, 44, 4, 12, 4, 1, 3, 49, 11, 44, 4, 26, 6, 11, 3, 128, 172, 6, 10, 6, 47, 49, 77, 3, 128, 164, 8, 60, 3, 15, 3, 60, 7, 56, 8, 43, 5, 130, 255, 17, 24, 8, 47, 17, 45, 3, 33, 15, 33, 15, 128, 140, 4, 130, 151, 25, 11, 21, 136, 148, 5, 47, 5, 59, 7, 2, 14, 24, 9, 128, 190, 34, 116, 12, 128, 214, 26, 12, 5, 128, 255, 5, 128, 223, 12, 242, 157, 3, 55, 9, 129, 92, 20, 128, 184, 8, 128, 203, 5, 10, 24, 59, 3, 10, 6, 56, 8, 70, 8, 12, 6, 116, 11, 30, 3, 90, 4, 89, 9, 128, 131, 24, 28, 10, 22, 9, 76, 4, 128, 138, 6, 171, 164, 12, 23, 4, 49, 161, 4, 129, 218, 38, 7, 12, 5, 5, 128, 166, 16, 129, 245, 7, 1, 32, 42, 6, 76, 4, 128, 141, 4, 128, 190, 3, 27, 3, 15, 13, 0, 6, 1, 1, 3, 1, 4, 2, 5, 7, 7, 2, 8, 8, 9, 2, 10, 5, 11, 2, 14, 4, 16, 1, 17, 2, 18, 5, 19, 17, 20, 1, 21, 2, 23, 2, 25, 13, 28, 5, 29, 8, 31, 1, 36, 1, 106, 4, 107, 2, 175, 3, 177, 2, 188, 2, 207, 2, 209, 2, 212, 12, 213, 9, 214, 2, 215, 2, 218, 1, 224, 5, 225, 2, 231, 4, 232, 2, 238, 32, 240, 4, 248, 2, 250, 3, 251, 1, 12, 39, 59, 62, 78, 79, 143, 158, 158, 159, 123, 139, 147, 150, 162, 178, 186, 134, 177, 6, 7, 9, 54, 61, 62, 86, 243, 208, 209, 4, 20, 24, 54, 55, 86, 87, 127, 170, 174, 175, 189, 53, 224, 18, 135, 137, 142, 158, 4, 13, 14, 17, 18, 41, 49, 52, 58, 69, 70, 73, 74, 78, 79, 100, 101, 92, 182, 183, 27, 28, 7, 8, 10, 11, 20, 23, 54, 57, 58, 168, 169, 216, 217, 9, 55, 144, 145, 168, 7, 10, 59, 62, 102, 105, 143, 146, 17, 111, 95, 191, 238, 239, 90, 98, 244, 252, 255, 83, 84, 154, 155, 46, 47, 39, 40, 85, 157, 160, 161, 163, 164, 167, 168, 173, 186, 188, 196, 6, 11, 12, 21, 29, 58, 63, 69, 81, 166, 167, 204, 205, 160, 7, 25, 26, 34, 37, 62, 63, 231, 236, 239, 255, 197, 198, 4, 32, 35, 37, 38, 40, 51, 56, 58, 72, 74, 76, 80, 83, 85, 86, 88, 90, 92, 94, 96, 99, 101, 102, 107, 115, 120, 125, 127, 138, 164, 170, 175, 176, 192, 208, 174, 175, 110, 111, 190, 147, 94, 34, 123, 5, 3, 4, 45, 3, 102, 3, 1, 47, 46, 128, 130, 29, 3, 49, 15, 28, 4, 36, 9, 30, 5, 43, 5, 68, 4, 14, 42, 128, 170, 6, 36, 4, 36, 4, 40, 8, 52, 11, 78, 67, 129, 55, 9, 22, 10, 8, 24, 59, 69, 57, 3, 99, 8, 9, 48, 22, 5, 33, 3, 27, 5, 1, 64, 56, 4, 75, 5, 47, 4, 10, 7, 9, 7, 64, 32, 39, 4, 12, 9, 54, 3, 58, 5, 26, 7, 4, 12, 7, 80, 73, 55, 51, 13, 51, 7, 46, 8, 10, 129, 38, 82, 75, 43, 8, 42, 22, 26, 38, 28, 20, 23, 9, 78, 4, 36, 9, 68, 13, 25, 7, 10, 6, 72, 8, 39, 9, 117, 11, 66, 62, 42, 6, 59, 5, 10, 6, 81, 6, 1, 5, 16, 3, 5, 128, 139, 98, 30, 72, 8, 10, 128, 166, 94, 34, 69, 11, 10, 6, 13, 19, 58, 6, 10, 54, 44, 4, 23, 128, 185, 60, 100, 83, 12, 72, 9, 10, 70, 69, 27, 72, 8, 83, 13, 73, 7, 10, 128, 246, 70, 10, 29, 3, 71, 73, 55, 3, 14, 8, 10, 6, 57, 7, 10, 129, 54, 25, 7, 59, 3, 28, 86, 1, 15, 50, 13, 131, 155, 102, 117, 11, 128, 196, 138, 76, 99, 13, 132, 48, 16, 22, 143, 170, 130, 71, 161, 185, 130, 57, 7, 42, 4, 92, 6, 38, 10, 70, 10, 40, 5, 19, 130, 176, 91, 101, 75, 4, 57, 7, 17, 64, 5, 11, 2, 14, 151, 248, 8, 132, 214, 42, 9, 162, 231, 129, 51, 15, 1, 29, 6, 14, 4, 8, 129, 140, 137, 4, 107, 5, 13, 3, 9, 7, 16, 146, 96, 71, 9, 116, 60, 128, 246, 10, 115, 8, 112, 21, 70, 122, 20, 12, 20, 12, 87, 9, 25, 128, 135, 129, 71, 3, 133, 66, 15, 21, 132, 80, 31, 6, 6, 128, 213, 43, 5, 62, 33, 1, 112, 45, 3, 26, 4, 2, 129, 64, 31, 17, 58, 5, 1, 129, 208, 42, 130, 230, 128, 247, 41, 76, 4, 10, 4, 2, 131, 17, 68, 76, 61, 128, 194, 60, 6, 1, 4, 85, 5, 27, 52, 2, 129, 14, 44, 4, 100, 12, 86, 10, 128, 174, 56, 29, 13, 44, 4, 9, 7, 2, 14, 6, 128, 154, 131, 216, 4, 17, 3, 13, 3, 119, 4, 95, 6, 12, 4, 1, 15, 12, 4, 56, 8, 10, 6, 40, 8, 34, 78, 129, 84, 12, 29, 3, 9, 7, 54, 8, 14, 4, 9, 7, 9, 7, 128, 203, 37, 10, 132, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 70, 41, 0, 0, 148, 0, 0, 7, 184, 0, 0, 0, 2, 2, 1, 0, 51, 42, 0, 0, 76, 1, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 19, 43, 0, 0, 28, 2, 0, 7, 120, 4, 0, 0, 2, 2, 1, 0, 175, 43, 0, 0, 100, 9, 0, 7, 84, 1, 0, 0, 2, 2, 1, 0, 76, 44, 0, 0, 148, 6, 0, 7, 208, 2, 0, 0, 2, 2, 1, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 37, 45, 0, 0, 188, 10, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 135, 46, 0, 0, 100, 129, 0, 7, 43, 0, 0, 0, 1, 2, 1, 0, 201, 46, 0, 0, 144, 129, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 11, 47, 0, 0, 0, 130, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 86, 48, 0, 0, 16, 130, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 152, 48, 0, 0, 184, 10, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 88, 50, 0, 0, 160, 129, 0, 7, 96, 0, 0, 0, 1, 2, 1, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 194, 51, 0, 0, 24, 12, 0, 7, 192, 1, 0, 0, 2, 2, 1, 0, 97, 52, 0, 0, 216, 13, 0, 7, 68, 1, 0, 0, 2, 2, 1, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 158, 52, 0, 0, 28, 15, 0, 7, 216, 0, 0, 0, 2, 2, 1, 0, 45, 53, 0, 0, 28, 17, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 101, 54, 0, 0, 244, 15, 0, 7, 40, 1, 0, 0, 2, 2, 1, 0, 134, 55, 0, 0, 120, 18, 0, 7, 8, 1, 0, 0, 2, 2, 1, 0, 4, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 27, 1, 0, 0, 128, 19, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 106, 1, 0, 0, 32, 130, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 148, 1, 0, 0, 76, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 190, 1, 0, 0, 112, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 232, 1, 0, 0, 144, 130, 0, 7, 57, 0, 0, 0, 1, 0, 1, 0, 238, 1, 0, 0, 128, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 24, 2, 0, 0, 204, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 66, 2, 0, 0, 220, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 108, 2, 0, 0, 236, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 150, 2, 0, 0, 252, 130, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 192, 2, 0, 0, 12, 131, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 235, 2, 0, 0, 92, 130, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 21, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 4, 46, 0, 0, 48, 131, 0, 7, 17, 0, 0, 0, 1, 2, 1, 0, 69, 46, 0, 0, 116, 131, 0, 7, 32, 0, 0, 0, 1, 2, 1, 0, 182, 47, 0, 0, 164, 26, 0, 7, 88, 4, 0, 0, 2, 2, 1, 0, 193, 53, 0, 0, 252, 30, 0, 7, 68, 1, 0, 0, 2, 2, 1, 0, 252, 58, 0, 0, 65, 131, 0, 7, 5, 0, 0, 0, 1, 2, 1, 0, 61, 59, 0, 0, 70, 131, 0, 7, 22, 0, 0, 0, 1, 2, 1, 0, 126, 59, 0, 0, 92, 131, 0, 7, 22, 0, 0, 0, 1, 2, 1, 0, 191, 59, 0, 0, 40, 131, 0, 7, 4, 0, 0, 0, 1, 2, 1, 0, 44, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 63, 49, 0, 0, 148, 131, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 67, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 244, 54, 0, 0, 64, 32, 0, 7, 104, 1, 0, 0, 2, 2, 1, 0, 30, 56, 0, 0, 168, 33, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 1, 60, 0, 0, 148, 131, 0, 7, 7, 0, 0, 0, 1, 2, 1, 0, 66, 60, 0, 0, 155, 131, 0, 7, 9, 0, 0, 0, 1, 2, 1, 0, 90, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 113, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 108, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 116, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 116, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 124, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 124, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 132, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 132, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 140, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 140, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 148, 35, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 130, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 162, 3, 0, 0, 164, 131, 0, 7, 41, 0, 0, 0, 1, 0, 1, 0, 204, 3, 0, 0, 12, 132, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 246, 3, 0, 0, 48, 132, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 32, 4, 0, 0, 164, 131, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 74, 4, 0, 0, 56, 132, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 116, 4, 0, 0, 72, 132, 0, 7, 113, 0, 0, 0, 1, 0, 1, 0, 158, 4, 0, 0, 188, 132, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 200, 4, 0, 0, 204, 132, 0, 7, 113, 0, 0, 0, 1, 0, 1, 0, 242, 4, 0, 0, 64, 133, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 29, 5, 0, 0, 80, 133, 0, 7, 30, 0, 0, 0, 1, 0, 1, 0, 72, 5, 0, 0, 112, 133, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 115, 5, 0, 0, 205, 131, 0, 7, 60, 0, 0, 0, 1, 0, 1, 0, 157, 5, 0, 0, 28, 132, 0, 7, 20, 0, 0, 0, 1, 0, 1, 0, 199, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 7, 6, 0, 0, 20, 41, 0, 7, 184, 0, 0, 0, 2, 0, 1, 0, 152, 61, 0, 0, 204, 41, 0, 7, 228, 0, 0, 0, 2, 2, 1, 0, 58, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 90, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 122, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 87, 61, 0, 0, 180, 42, 0, 7, 228, 0, 0, 0, 2, 2, 1, 0, 154, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 186, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 218, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 244, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 187, 63, 0, 0, 44, 44, 0, 7, 184, 0, 0, 0, 2, 2, 1, 0, 7, 64, 0, 0, 228, 44, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 14, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 41, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 68, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 95, 7, 0, 0, 128, 133, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 104, 7, 0, 0, 224, 45, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 112, 7, 0, 0, 11, 134, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 155, 7, 0, 0, 244, 45, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 163, 7, 0, 0, 249, 133, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 206, 7, 0, 0, 8, 46, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 214, 7, 0, 0, 229, 133, 0, 7, 20, 0, 0, 0, 1, 0, 1, 0, 1, 8, 0, 0, 28, 46, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 9, 8, 0, 0, 211, 133, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 52, 8, 0, 0, 48, 46, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 60, 8, 0, 0, 187, 133, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 103, 8, 0, 0, 68, 46, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 111, 8, 0, 0, 175, 133, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 154, 8, 0, 0, 88, 46, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 162, 8, 0, 0, 156, 133, 0, 7, 19, 0, 0, 0, 1, 0, 1, 0, 189, 65, 0, 0, 180, 45, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 204, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 8, 0, 0, 4, 47, 0, 7, 80, 0, 0, 0, 2, 0, 1, 0, 25, 9, 0, 0, 32, 134, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 67, 9, 0, 0, 84, 47, 0, 7, 236, 0, 0, 0, 2, 0, 1, 0, 133, 9, 0, 0, 32, 134, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 175, 9, 0, 0, 40, 134, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 117, 65, 0, 0, 108, 46, 0, 7, 152, 0, 0, 0, 2, 2, 1, 0, 217, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 244, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 14, 10, 0, 0, 220, 48, 0, 7, 180, 2, 0, 0, 2, 0, 1, 0, 77, 10, 0, 0, 228, 134, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 119, 10, 0, 0, 161, 134, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 161, 10, 0, 0, 212, 134, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 203, 10, 0, 0, 244, 134, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 245, 10, 0, 0, 65, 134, 0, 7, 96, 0, 0, 0, 1, 0, 1, 0, 31, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 166, 64, 0, 0, 152, 52, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 254, 64, 0, 0, 156, 52, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 52, 65, 0, 0, 4, 135, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 58, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 155, 67, 0, 0, 0, 53, 0, 7, 48, 0, 0, 0, 2, 2, 1, 0, 84, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 110, 11, 0, 0, 132, 135, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 152, 11, 0, 0, 176, 135, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 194, 11, 0, 0, 116, 135, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 236, 11, 0, 0, 39, 136, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 23, 12, 0, 0, 80, 136, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 66, 12, 0, 0, 20, 135, 0, 7, 94, 0, 0, 0, 1, 0, 1, 0, 108, 12, 0, 0, 192, 135, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 238, 67, 0, 0, 48, 53, 0, 7, 56, 4, 0, 0, 2, 2, 1, 0, 151, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 177, 12, 0, 0, 184, 57, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 9, 13, 0, 0, 96, 136, 0, 7, 39, 0, 0, 0, 1, 0, 1, 0, 51, 13, 0, 0, 136, 136, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 93, 13, 0, 0, 252, 136, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 135, 13, 0, 0, 152, 136, 0, 7, 98, 0, 0, 0, 1, 0, 1, 0, 131, 60, 0, 0, 188, 57, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 142, 60, 0, 0, 140, 58, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 155, 60, 0, 0, 144, 58, 0, 7, 52, 1, 0, 0, 2, 2, 1, 0, 168, 60, 0, 0, 196, 59, 0, 7, 20, 1, 0, 0, 2, 2, 1, 0, 177, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 203, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 217, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 13, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 243, 13, 0, 0, 8, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 255, 13, 0, 0, 108, 137, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 41, 14, 0, 0, 12, 137, 0, 7, 96, 0, 0, 0, 1, 0, 1, 0, 233, 44, 0, 0, 208, 61, 0, 7, 12, 4, 0, 0, 2, 2, 1, 0, 61, 51, 0, 0, 216, 60, 0, 7, 248, 0, 0, 0, 2, 2, 1, 0, 83, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 109, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 134, 14, 0, 0, 0, 67, 0, 7, 152, 0, 0, 0, 2, 0, 1, 0, 227, 14, 0, 0, 152, 67, 0, 7, 152, 0, 0, 0, 2, 0, 1, 0, 64, 15, 0, 0, 48, 68, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 123, 15, 0, 0, 124, 137, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 165, 15, 0, 0, 216, 137, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 207, 15, 0, 0, 129, 137, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 249, 15, 0, 0, 144, 137, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 35, 16, 0, 0, 36, 140, 0, 7, 60, 0, 0, 0, 1, 0, 1, 0, 142, 16, 0, 0, 96, 140, 0, 7, 60, 0, 0, 0, 1, 0, 1, 0, 251, 16, 0, 0, 160, 137, 0, 7, 13, 0, 0, 0, 1, 0, 1, 0, 37, 17, 0, 0, 173, 137, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 79, 17, 0, 0, 184, 137, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 121, 17, 0, 0, 232, 137, 0, 7, 101, 0, 0, 0, 1, 0, 1, 0, 164, 17, 0, 0, 77, 138, 0, 7, 48, 0, 0, 0, 1, 0, 1, 0, 207, 17, 0, 0, 125, 138, 0, 7, 45, 0, 0, 0, 1, 0, 1, 0, 250, 17, 0, 0, 170, 138, 0, 7, 49, 0, 0, 0, 1, 0, 1, 0, 37, 18, 0, 0, 219, 138, 0, 7, 45, 0, 0, 0, 1, 0, 1, 0, 80, 18, 0, 0, 8, 139, 0, 7, 29, 0, 0, 0, 1, 0, 1, 0, 123, 18, 0, 0, 37, 139, 0, 7, 33, 0, 0, 0, 1, 0, 1, 0, 166, 18, 0, 0, 70, 139, 0, 7, 47, 0, 0, 0, 1, 0, 1, 0, 209, 18, 0, 0, 117, 139, 0, 7, 45, 0, 0, 0, 1, 0, 1, 0, 252, 18, 0, 0, 162, 139, 0, 7, 50, 0, 0, 0, 1, 0, 1, 0, 39, 19, 0, 0, 212, 139, 0, 7, 38, 0, 0, 0, 1, 0, 1, 0, 82, 19, 0, 0, 250, 139, 0, 7, 39, 0, 0, 0, 1, 0, 1, 0, 125, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 163, 71, 0, 0, 92, 69, 0, 7, 32, 0, 0, 0, 2, 2, 1, 0, 150, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 175, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 201, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 226, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 251, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 21, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 46, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 96, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 117, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 138, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 159, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 180, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 201, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 223, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 245, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 10, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 38, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 67, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 86, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 105, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 124, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 150, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 176, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 202, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 228, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 254, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 25, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 60, 22, 0, 0, 35, 141, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 102, 22, 0, 0, 75, 141, 0, 7, 45, 0, 0, 0, 1, 0, 1, 0, 144, 22, 0, 0, 120, 141, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 187, 22, 0, 0, 164, 141, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 230, 22, 0, 0, 180, 141, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 17, 23, 0, 0, 223, 141, 0, 7, 52, 0, 0, 0, 1, 0, 1, 0, 60, 23, 0, 0, 20, 142, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 103, 23, 0, 0, 36, 142, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 146, 23, 0, 0, 0, 0, 8, 0, 4, 0, 0, 0, 1, 0, 2, 0, 223, 23, 0, 0, 80, 142, 0, 7, 14, 0, 0, 0, 1, 0, 1, 0, 10, 24, 0, 0, 96, 142, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 53, 24, 0, 0, 188, 140, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 95, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 130, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 159, 75, 0, 0, 164, 70, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 249, 75, 0, 0, 92, 72, 0, 7, 172, 0, 0, 0, 2, 2, 1, 0, 69, 76, 0, 0, 128, 71, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 146, 76, 0, 0, 112, 142, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 211, 76, 0, 0, 64, 143, 0, 7, 8, 0, 0, 0, 1, 2, 1, 0, 20, 77, 0, 0, 72, 143, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 85, 77, 0, 0, 112, 142, 0, 7, 191, 0, 0, 0, 1, 2, 1, 0, 150, 77, 0, 0, 47, 143, 0, 7, 17, 0, 0, 0, 1, 2, 1, 0, 151, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 173, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 213, 60, 0, 0, 100, 73, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 195, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 217, 24, 0, 0, 112, 73, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 33, 25, 0, 0, 88, 143, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 75, 25, 0, 0, 140, 143, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 117, 25, 0, 0, 88, 144, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 159, 25, 0, 0, 156, 143, 0, 7, 187, 0, 0, 0, 1, 0, 1, 0, 201, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 223, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 244, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 78, 0, 0, 104, 144, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 2, 79, 0, 0, 224, 74, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 116, 79, 0, 0, 228, 74, 0, 7, 100, 1, 0, 0, 2, 2, 1, 0, 229, 79, 0, 0, 72, 76, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 84, 80, 0, 0, 172, 76, 0, 7, 144, 0, 0, 0, 2, 2, 1, 0, 9, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 195, 80, 0, 0, 82, 145, 0, 7, 200, 0, 0, 0, 1, 2, 1, 0, 5, 81, 0, 0, 64, 145, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 71, 81, 0, 0, 80, 145, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 137, 81, 0, 0, 128, 144, 0, 7, 190, 0, 0, 0, 1, 2, 1, 0, 29, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 19, 83, 0, 0, 232, 146, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 86, 83, 0, 0, 248, 146, 0, 7, 132, 0, 0, 0, 1, 2, 1, 0, 199, 83, 0, 0, 124, 147, 0, 7, 215, 2, 0, 0, 1, 2, 1, 0, 45, 84, 0, 0, 84, 150, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 112, 84, 0, 0, 26, 146, 0, 7, 203, 0, 0, 0, 1, 2, 1, 0, 50, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 71, 26, 0, 0, 168, 150, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 114, 26, 0, 0, 100, 150, 0, 7, 21, 0, 0, 0, 1, 0, 1, 0, 157, 26, 0, 0, 121, 150, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 200, 26, 0, 0, 164, 150, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 243, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 8, 27, 0, 0, 192, 150, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 50, 27, 0, 0, 224, 150, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 93, 27, 0, 0, 8, 151, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 136, 27, 0, 0, 48, 151, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 179, 27, 0, 0, 144, 152, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 222, 27, 0, 0, 164, 151, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 9, 28, 0, 0, 124, 151, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 52, 28, 0, 0, 229, 150, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 95, 28, 0, 0, 240, 150, 0, 7, 22, 0, 0, 0, 1, 0, 1, 0, 138, 28, 0, 0, 6, 151, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 181, 28, 0, 0, 32, 151, 0, 7, 14, 0, 0, 0, 1, 0, 1, 0, 224, 28, 0, 0, 80, 151, 0, 7, 38, 0, 0, 0, 1, 0, 1, 0, 11, 29, 0, 0, 118, 151, 0, 7, 6, 0, 0, 0, 1, 0, 1, 0, 54, 29, 0, 0, 207, 151, 0, 7, 190, 0, 0, 0, 1, 0, 1, 0, 97, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 118, 29, 0, 0, 204, 152, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 161, 29, 0, 0, 4, 90, 0, 7, 12, 0, 0, 0, 2, 0, 1, 0, 247, 29, 0, 0, 16, 90, 0, 7, 220, 0, 0, 0, 2, 0, 1, 0, 79, 30, 0, 0, 236, 90, 0, 7, 100, 0, 0, 0, 2, 0, 1, 0, 179, 84, 0, 0, 201, 152, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 245, 84, 0, 0, 200, 152, 0, 7, 1, 0, 0, 0, 1, 2, 1, 0, 156, 87, 0, 0, 32, 84, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 241, 87, 0, 0, 160, 152, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 50, 88, 0, 0, 164, 152, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 220, 88, 0, 0, 195, 152, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 30, 89, 0, 0, 188, 152, 0, 7, 3, 0, 0, 0, 1, 2, 1, 0, 96, 89, 0, 0, 191, 152, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 162, 89, 0, 0, 193, 152, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 228, 89, 0, 0, 197, 152, 0, 7, 3, 0, 0, 0, 1, 2, 1, 0, 38, 90, 0, 0, 204, 88, 0, 7, 216, 0, 0, 0, 2, 2, 1, 0, 89, 90, 0, 0, 164, 89, 0, 7, 96, 0, 0, 0, 2, 2, 1, 0, 138, 90, 0, 0, 160, 152, 0, 7, 1, 0, 0, 0, 1, 2, 1, 0, 204, 90, 0, 0, 32, 84, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 35, 91, 0, 0, 32, 84, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 165, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 186, 30, 0, 0, 228, 152, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 229, 30, 0, 0, 244, 152, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 16, 31, 0, 0, 240, 152, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 59, 31, 0, 0, 241, 152, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 189, 91, 0, 0, 100, 93, 0, 7, 20, 0, 0, 0, 2, 2, 1, 0, 102, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 123, 31, 0, 0, 4, 95, 0, 7, 32, 0, 0, 0, 2, 0, 1, 0, 172, 31, 0, 0, 36, 95, 0, 7, 72, 0, 0, 0, 2, 0, 1, 0, 226, 31, 0, 0, 4, 153, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 12, 32, 0, 0, 4, 153, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 151, 92, 0, 0, 208, 153, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 216, 92, 0, 0, 12, 153, 0, 7, 195, 0, 0, 0, 1, 2, 1, 0, 54, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 74, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 94, 32, 0, 0, 228, 153, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 136, 32, 0, 0, 236, 153, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 178, 32, 0, 0, 32, 154, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 221, 32, 0, 0, 48, 154, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 8, 33, 0, 0, 100, 154, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 51, 33, 0, 0, 224, 153, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 93, 33, 0, 0, 236, 153, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 135, 33, 0, 0, 254, 153, 0, 7, 34, 0, 0, 0, 1, 0, 1, 0, 178, 33, 0, 0, 64, 154, 0, 7, 22, 0, 0, 0, 1, 0, 1, 0, 221, 33, 0, 0, 86, 154, 0, 7, 13, 0, 0, 0, 1, 0, 1, 0, 172, 86, 0, 0, 136, 96, 0, 7, 156, 2, 0, 0, 2, 2, 1, 0, 8, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 28, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 48, 34, 0, 0, 124, 106, 0, 7, 156, 0, 0, 0, 2, 0, 1, 0, 119, 34, 0, 0, 0, 155, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 129, 34, 0, 0, 116, 154, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 139, 34, 0, 0, 72, 112, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 148, 34, 0, 0, 24, 113, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 158, 34, 0, 0, 216, 112, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 168, 34, 0, 0, 224, 112, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 178, 34, 0, 0, 152, 113, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 188, 34, 0, 0, 232, 113, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 198, 34, 0, 0, 96, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 208, 34, 0, 0, 60, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 218, 34, 0, 0, 72, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 228, 34, 0, 0, 84, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 238, 34, 0, 0, 140, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 248, 34, 0, 0, 164, 114, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 2, 35, 0, 0, 184, 156, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 45, 35, 0, 0, 168, 156, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 88, 35, 0, 0, 24, 155, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 98, 35, 0, 0, 0, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 107, 35, 0, 0, 32, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 116, 35, 0, 0, 16, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 125, 35, 0, 0, 236, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 135, 35, 0, 0, 76, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 145, 35, 0, 0, 84, 117, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 155, 35, 0, 0, 184, 155, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 165, 35, 0, 0, 28, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 175, 35, 0, 0, 104, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 185, 35, 0, 0, 220, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 195, 35, 0, 0, 184, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 205, 35, 0, 0, 196, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 215, 35, 0, 0, 208, 118, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 225, 35, 0, 0, 200, 156, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 44, 86, 0, 0, 192, 119, 0, 7, 24, 0, 0, 0, 2, 2, 1, 0, 72, 87, 0, 0, 116, 100, 0, 7, 8, 0, 0, 0, 2, 2, 1, 0, 124, 91, 0, 0, 208, 155, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 3, 92, 0, 0, 100, 119, 0, 7, 92, 0, 0, 0, 2, 2, 1, 0, 77, 92, 0, 0, 72, 119, 0, 7, 28, 0, 0, 0, 2, 2, 1, 0, 235, 93, 0, 0, 124, 100, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 177, 94, 0, 0, 56, 119, 0, 7, 16, 0, 0, 0, 2, 2, 1, 0, 249, 94, 0, 0, 232, 155, 0, 7, 190, 0, 0, 0, 1, 2, 1, 0, 58, 95, 0, 0, 124, 100, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 12, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 32, 36, 0, 0, 208, 156, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 75, 36, 0, 0, 240, 156, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 118, 36, 0, 0, 20, 157, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 161, 36, 0, 0, 43, 157, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 204, 36, 0, 0, 45, 157, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 247, 36, 0, 0, 144, 157, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 34, 37, 0, 0, 36, 157, 0, 7, 7, 0, 0, 0, 1, 0, 1, 0, 77, 37, 0, 0, 108, 157, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 120, 37, 0, 0, 180, 157, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 163, 37, 0, 0, 196, 157, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 207, 37, 0, 0, 0, 157, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 250, 37, 0, 0, 47, 157, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 37, 38, 0, 0, 72, 157, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 80, 38, 0, 0, 90, 157, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 123, 38, 0, 0, 102, 157, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 166, 38, 0, 0, 140, 157, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 209, 38, 0, 0, 176, 157, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 7, 96, 0, 0, 216, 119, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 95, 96, 0, 0, 216, 119, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 185, 96, 0, 0, 216, 119, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 252, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 16, 39, 0, 0, 207, 157, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 59, 39, 0, 0, 212, 158, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 102, 39, 0, 0, 196, 158, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 145, 39, 0, 0, 250, 157, 0, 7, 200, 0, 0, 0, 1, 0, 1, 0, 47, 97, 0, 0, 196, 122, 0, 7, 76, 1, 0, 0, 2, 2, 1, 0, 125, 97, 0, 0, 228, 158, 0, 7, 80, 0, 0, 0, 1, 2, 1, 0, 191, 97, 0, 0, 52, 159, 0, 7, 31, 1, 0, 0, 1, 2, 1, 0, 1, 98, 0, 0, 83, 160, 0, 7, 47, 1, 0, 0, 1, 2, 1, 0, 67, 98, 0, 0, 130, 161, 0, 7, 88, 0, 0, 0, 1, 2, 1, 0, 133, 98, 0, 0, 218, 161, 0, 7, 196, 0, 0, 0, 1, 2, 1, 0, 199, 98, 0, 0, 158, 162, 0, 7, 194, 1, 0, 0, 1, 2, 1, 0, 188, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 208, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 167, 85, 0, 0, 112, 125, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 9, 99, 0, 0, 96, 164, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 228, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 6, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 176, 99, 0, 0, 116, 127, 0, 7, 232, 1, 0, 0, 2, 2, 1, 0, 39, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 53, 51, 0, 0, 92, 129, 0, 7, 8, 0, 0, 0, 2, 2, 1, 0, 73, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 107, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 140, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 173, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 206, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 239, 40, 0, 0, 20, 0, 0, 7, 128, 0, 0, 0, 18, 0, 1, 0, 247, 40, 0, 0, 132, 19, 0, 7, 32, 7, 0, 0, 18, 0, 1, 0, 254, 40, 0, 0, 96, 70, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 14, 41, 0, 0, 172, 69, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 24, 41, 0, 0, 152, 69, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 33, 41, 0, 0, 56, 70, 0, 7, 40, 0, 0, 0, 18, 0, 1, 0, 43, 41, 0, 0, 64, 48, 0, 7, 132, 0, 0, 0, 18, 0, 1, 0, 61, 41, 0, 0, 196, 48, 0, 7, 24, 0, 0, 0, 18, 0, 1, 0, 146, 41, 0, 0, 124, 35, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 161, 41, 0, 0, 108, 35, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 174, 41, 0, 0, 132, 35, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 194, 41, 0, 0, 8, 73, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 251, 41, 0, 0, 76, 73, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 128, 42, 0, 0, 116, 35, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 143, 42, 0, 0, 120, 57, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 240, 44, 0, 0, 4, 0, 8, 0, 80, 0, 0, 0, 17, 0, 3, 0, 208, 45, 0, 0, 112, 57, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 77, 47, 0, 0, 52, 122, 0, 7, 116, 0, 0, 0, 18, 0, 1, 0, 128, 47, 0, 0, 104, 57, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 7, 49, 0, 0, 156, 140, 0, 7, 32, 0, 0, 0, 17, 0, 1, 0, 129, 49, 0, 0, 56, 52, 0, 7, 96, 0, 0, 0, 18, 0, 1, 0, 248, 49, 0, 0, 192, 45, 0, 7, 172, 0, 0, 0, 18, 0, 1, 0, 154, 50, 0, 0, 40, 80, 0, 7, 104, 0, 0, 0, 18, 0, 1, 0, 0, 51, 0, 0, 76, 110, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 68, 51, 0, 0, 152, 43, 0, 7, 148, 0, 0, 0, 18, 0, 1, 0, 177, 56, 0, 0, 148, 35, 0, 7, 204, 0, 0, 0, 18, 0, 1, 0, 254, 56, 0, 0, 220, 65, 0, 7, 24, 1, 0, 0, 18, 0, 1, 0, 56, 57, 0, 0, 176, 42, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 117, 57, 0, 0, 96, 36, 0, 7, 12, 2, 0, 0, 18, 0, 1, 0, 196, 57, 0, 0, 144, 51, 0, 7, 168, 0, 0, 0, 18, 0, 1, 0, 55, 58, 0, 0, 144, 69, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 117, 58, 0, 0, 24, 120, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 162, 58, 0, 0, 52, 68, 0, 7, 40, 1, 0, 0, 18, 0, 1, 0, 186, 60, 0, 0, 140, 35, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 222, 60, 0, 0, 108, 38, 0, 7, 168, 2, 0, 0, 18, 0, 1, 0, 38, 61, 0, 0, 220, 119, 0, 7, 60, 0, 0, 0, 18, 0, 1, 0, 229, 61, 0, 0, 92, 120, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 32, 62, 0, 0, 96, 127, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 131, 62, 0, 0, 92, 110, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 191, 62, 0, 0, 60, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 31, 63, 0, 0, 108, 110, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 91, 63, 0, 0, 236, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 97, 64, 0, 0, 36, 99, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 7, 66, 0, 0, 120, 93, 0, 7, 128, 1, 0, 0, 18, 0, 1, 0, 110, 66, 0, 0, 116, 73, 0, 7, 108, 1, 0, 0, 18, 0, 1, 0, 165, 66, 0, 0, 244, 66, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 213, 66, 0, 0, 132, 69, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 95, 67, 0, 0, 192, 120, 0, 7, 116, 1, 0, 0, 18, 0, 1, 0, 37, 68, 0, 0, 124, 69, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 102, 68, 0, 0, 136, 69, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 164, 68, 0, 0, 236, 69, 0, 7, 76, 0, 0, 0, 18, 0, 1, 0, 233, 68, 0, 0, 168, 122, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 76, 69, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 1, 0, 83, 69, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 16, 0, 2, 0, 101, 69, 0, 0, 252, 255, 127, 0, 0, 0, 0, 0, 16, 0, 241, 255, 115, 69, 0, 0, 148, 99, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 182, 69, 0, 0, 4, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 194, 69, 0, 0, 84, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 204, 69, 0, 0, 60, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 44, 70, 0, 0, 236, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 140, 70, 0, 0, 52, 127, 0, 7, 44, 0, 0, 0, 18, 0, 1, 0, 239, 70, 0, 0, 124, 110, 0, 7, 84, 0, 0, 0, 18, 0, 1, 0, 40, 71, 0, 0, 28, 86, 0, 7, 40, 2, 0, 0, 18, 0, 1, 0, 101, 71, 0, 0, 68, 88, 0, 7, 136, 0, 0, 0, 18, 0, 1, 0, 235, 71, 0, 0, 208, 110, 0, 7, 116, 5, 0, 0, 18, 0, 1, 0, 49, 72, 0, 0, 136, 69, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 174, 72, 0, 0, 144, 69, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 44, 73, 0, 0, 132, 69, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 187, 73, 0, 0, 68, 116, 0, 7, 216, 2, 0, 0, 18, 0, 1, 0, 2, 74, 0, 0, 4, 100, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 67, 74, 0, 0, 96, 127, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 168, 74, 0, 0, 60, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 10, 75, 0, 0, 236, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 108, 75, 0, 0, 248, 94, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 215, 77, 0, 0, 88, 73, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 24, 78, 0, 0, 24, 107, 0, 7, 52, 3, 0, 0, 18, 0, 1, 0, 136, 78, 0, 0, 220, 100, 0, 7, 100, 2, 0, 0, 18, 0, 1, 0, 175, 78, 0, 0, 28, 119, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 203, 81, 0, 0, 60, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 45, 82, 0, 0, 236, 77, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 143, 82, 0, 0, 64, 103, 0, 7, 60, 3, 0, 0, 18, 0, 1, 0, 200, 82, 0, 0, 156, 78, 0, 7, 140, 1, 0, 0, 18, 0, 1, 0, 55, 85, 0, 0, 16, 124, 0, 7, 96, 1, 0, 0, 18, 0, 1, 0, 116, 85, 0, 0, 144, 80, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 242, 85, 0, 0, 108, 95, 0, 7, 28, 1, 0, 0, 18, 0, 1, 0, 118, 86, 0, 0, 156, 80, 0, 7, 132, 3, 0, 0, 18, 0, 1, 0, 17, 87, 0, 0, 80, 91, 0, 7, 20, 2, 0, 0, 18, 0, 1, 0, 116, 88, 0, 0, 36, 84, 0, 7, 248, 1, 0, 0, 18, 0, 1, 0, 25, 93, 0, 0, 48, 99, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 97, 93, 0, 0, 160, 99, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 167, 93, 0, 0, 16, 100, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 88, 94, 0, 0, 128, 100, 0, 7, 92, 0, 0, 0, 18, 0, 1, 0, 170, 95, 0, 0, 76, 110, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 75, 99, 0, 0, 52, 127, 0, 7, 44, 0, 0, 0, 18, 0, 1, 0, 231, 99, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 16, 0, 241, 255, 244, 99, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 16, 0, 241, 255, 255, 99, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 241, 255, 10, 100, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 16, 0, 241, 255, 0, 46, 116, 101, 120, 116, 0, 46, 100, 97, 116, 97, 0, 46, 98, 115, 115, 0, 46, 115, 121, 109, 116, 97, 98, 0, 46, 115, 104, 115, 116, 114, 116, 97, 98, 0, 46, 115, 116, 114, 116, 97, 98, 0, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 48, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 48, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 49, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 50, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 51, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 52, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 49, 53, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 50, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 51, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 52, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 52, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 46, 101, 114, 114, 111, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 99, 53, 99, 53, 57, 52, 98, 49, 57, 52, 53, 57, 53, 57, 51, 102, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 51, 0, 115, 116, 114, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 56, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 50, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 54, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 55, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 56, 0, 99, 108, 117, 115, 116, 101, 114, 46, 56, 55, 99, 56, 97, 57, 54, 54, 45, 99, 103, 117, 46, 57, 0, 49, 108, 102, 113, 57, 48, 48, 106, 102, 101, 103, 52, 110, 110, 56, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 56, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 49, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 50, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 98, 52, 52, 98, 52, 50, 50, 101, 102, 101, 97, 53, 54, 49, 56, 99, 69, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 51, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 53, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 55, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 51, 0, 46, 76, 74, 84, 73, 54, 95, 48, 0, 46, 76, 66, 66, 54, 95, 49, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 53, 0, 46, 76, 66, 66, 54, 95, 50, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 52, 0, 46, 76, 66, 66, 54, 95, 51, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 51, 0, 46, 76, 66, 66, 54, 95, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 50, 0, 46, 76, 66, 66, 54, 95, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 49, 0, 46, 76, 66, 66, 54, 95, 54, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 48, 0, 46, 76, 66, 66, 54, 95, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 65, 114, 103, 117, 109, 101, 110, 116, 115, 54, 97, 115, 95, 115, 116, 114, 49, 55, 104, 57, 48, 52, 56, 53, 49, 98, 51, 54, 101, 48, 101, 53, 51, 50, 102, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 53, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 84, 36, 71, 84, 36, 49, 49, 109, 97, 112, 95, 111, 114, 95, 101, 108, 115, 101, 49, 55, 104, 98, 48, 56, 50, 51, 53, 48, 48, 57, 56, 101, 100, 52, 57, 56, 52, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 50, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 115, 104, 97, 49, 53, 99, 111, 112, 121, 95, 97, 110, 100, 95, 117, 112, 100, 97, 116, 101, 49, 55, 104, 53, 56, 100, 57, 102, 57, 100, 54, 49, 52, 102, 57, 100, 102, 48, 50, 69, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 51, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 48, 48, 55, 100, 48, 102, 101, 49, 54, 97, 100, 51, 55, 97, 50, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 56, 0, 109, 117, 115, 108, 95, 109, 101, 109, 115, 101, 116, 46, 99, 0, 109, 117, 115, 108, 95, 109, 101, 109, 99, 112, 121, 46, 99, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 48, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 57, 100, 97, 100, 57, 98, 99, 99, 48, 57, 54, 98, 56, 52, 99, 52, 100, 97, 98, 97, 50, 51, 50, 50, 98, 51, 102, 98, 57, 51, 51, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 57, 100, 97, 100, 57, 98, 99, 99, 48, 57, 54, 98, 56, 52, 99, 52, 100, 97, 98, 97, 50, 51, 50, 50, 98, 51, 102, 98, 57, 51, 51, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 57, 0, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 53, 54, 98, 102, 56, 51, 101, 51, 45, 99, 103, 117, 46, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 48, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 54, 49, 102, 100, 97, 97, 54, 52, 101, 57, 54, 50, 100, 52, 52, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 48, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 97, 54, 99, 101, 56, 54, 56, 102, 51, 55, 55, 53, 53, 50, 54, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 105, 51, 50, 36, 71, 84, 36, 49, 55, 104, 98, 56, 57, 97, 99, 48, 102, 99, 100, 56, 97, 97, 97, 48, 98, 54, 69, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 50, 0, 46, 76, 115, 119, 105, 116, 99, 104, 46, 116, 97, 98, 108, 101, 46, 95, 90, 78, 54, 50, 95, 36, 76, 84, 36, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 46, 101, 114, 114, 111, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 99, 54, 48, 99, 97, 55, 53, 97, 57, 100, 49, 57, 57, 49, 49, 56, 69, 0, 46, 76, 115, 119, 105, 116, 99, 104, 46, 116, 97, 98, 108, 101, 46, 95, 90, 78, 54, 50, 95, 36, 76, 84, 36, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 46, 101, 114, 114, 111, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 99, 54, 48, 99, 97, 55, 53, 97, 57, 100, 49, 57, 57, 49, 49, 56, 69, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 54, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 56, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 49, 57, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 50, 49, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 50, 50, 0, 46, 76, 97, 110, 111, 110, 46, 100, 99, 53, 97, 49, 99, 57, 99, 97, 48, 56, 51, 98, 56, 51, 56, 97, 49, 48, 51, 55, 53, 102, 99, 48, 99, 99, 98, 98, 53, 51, 97, 46, 50, 51, 0, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 53, 54, 98, 102, 56, 51, 101, 51, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 52, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 48, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 49, 53, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 48, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 49, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 54, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 54, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 56, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 50, 48, 72, 69, 65, 80, 95, 87, 79, 82, 68, 83, 95, 82, 69, 77, 65, 73, 78, 73, 78, 71, 49, 55, 104, 53, 97, 101, 56, 98, 54, 55, 48, 102, 98, 54, 52, 50, 100, 55, 50, 69, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 55, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 102, 53, 51, 97, 51, 51, 52, 49, 54, 100, 57, 102, 57, 49, 53, 49, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 54, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 49, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 50, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 52, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 50, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 51, 55, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 53, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 53, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 53, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 57, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 51, 56, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 51, 57, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 54, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 52, 56, 0, 46, 76, 97, 110, 111, 110, 46, 51, 101, 102, 99, 51, 102, 97, 51, 50, 100, 52, 56, 50, 51, 97, 51, 55, 48, 101, 55, 54, 102, 52, 51, 48, 48, 48, 55, 102, 55, 98, 52, 46, 53, 49, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 56, 102, 99, 52, 56, 49, 52, 97, 51, 54, 56, 49, 102, 52, 54, 56, 69, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 102, 55, 98, 100, 56, 55, 54, 57, 56, 53, 97, 102, 52, 55, 51, 52, 69, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 56, 48, 53, 97, 102, 56, 54, 101, 101, 98, 55, 56, 101, 55, 51, 102, 69, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 115, 116, 114, 49, 55, 104, 56, 102, 56, 55, 54, 57, 49, 102, 48, 101, 102, 98, 98, 51, 56, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 112, 97, 110, 105, 99, 95, 100, 105, 115, 112, 108, 97, 121, 49, 55, 104, 98, 55, 101, 99, 99, 49, 53, 99, 56, 57, 48, 97, 55, 99, 98, 54, 69, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 52, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 56, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 57, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 54, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 50, 119, 114, 105, 116, 101, 95, 112, 114, 101, 102, 105, 120, 49, 55, 104, 55, 57, 52, 99, 51, 54, 55, 50, 50, 55, 97, 56, 51, 100, 102, 55, 69, 0, 46, 76, 74, 84, 73, 53, 48, 95, 49, 0, 46, 76, 74, 84, 73, 53, 48, 95, 48, 0, 46, 76, 66, 66, 53, 48, 95, 57, 0, 46, 76, 66, 66, 53, 48, 95, 50, 52, 0, 46, 76, 66, 66, 53, 48, 95, 49, 54, 0, 46, 76, 66, 66, 53, 48, 95, 49, 55, 0, 46, 76, 66, 66, 53, 48, 95, 51, 53, 0, 46, 76, 66, 66, 53, 48, 95, 52, 49, 0, 46, 76, 66, 66, 53, 48, 95, 53, 49, 0, 46, 76, 66, 66, 53, 48, 95, 52, 56, 0, 46, 76, 66, 66, 53, 48, 95, 52, 57, 0, 46, 76, 66, 66, 53, 48, 95, 53, 48, 0, 46, 76, 66, 66, 53, 48, 95, 53, 52, 0, 46, 76, 66, 66, 53, 48, 95, 53, 53, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 49, 51, 0, 46, 76, 74, 84, 73, 53, 50, 95, 48, 0, 46, 76, 66, 66, 53, 50, 95, 52, 0, 46, 76, 66, 66, 53, 50, 95, 56, 0, 46, 76, 66, 66, 53, 50, 95, 54, 0, 46, 76, 66, 66, 53, 50, 95, 49, 53, 0, 46, 76, 66, 66, 53, 50, 95, 49, 49, 0, 46, 76, 66, 66, 53, 50, 95, 49, 50, 0, 46, 76, 74, 84, 73, 53, 50, 95, 49, 0, 46, 76, 66, 66, 53, 50, 95, 49, 55, 0, 46, 76, 66, 66, 53, 50, 95, 50, 51, 0, 46, 76, 66, 66, 53, 50, 95, 51, 51, 0, 46, 76, 66, 66, 53, 50, 95, 51, 48, 0, 46, 76, 66, 66, 53, 50, 95, 51, 49, 0, 46, 76, 66, 66, 53, 50, 95, 51, 50, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 50, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 50, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 56, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 53, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 49, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 50, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 56, 0, 46, 76, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 50, 49, 0, 46, 76, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 52, 0, 46, 76, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 51, 0, 46, 76, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 57, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 53, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 51, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 52, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 53, 0, 95, 95, 115, 116, 97, 114, 116, 0, 95, 95, 109, 97, 105, 110, 0, 115, 121, 115, 95, 97, 108, 108, 111, 99, 95, 119, 111, 114, 100, 115, 0, 115, 121, 115, 95, 112, 97, 110, 105, 99, 0, 115, 121, 115, 95, 114, 97, 110, 100, 0, 115, 121, 115, 95, 119, 114, 105, 116, 101, 0, 114, 117, 115, 116, 95, 98, 101, 103, 105, 110, 95, 117, 110, 119, 105, 110, 100, 0, 114, 117, 115, 116, 95, 111, 111, 109, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 100, 102, 55, 100, 49, 50, 100, 50, 54, 54, 102, 100, 50, 48, 101, 56, 69, 46, 108, 108, 118, 109, 46, 51, 48, 49, 56, 55, 56, 52, 57, 51, 53, 51, 50, 54, 52, 51, 49, 51, 56, 48, 0, 95, 95, 114, 117, 115, 116, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 95, 122, 101, 114, 111, 101, 100, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 55, 99, 97, 112, 97, 99, 105, 116, 121, 95, 111, 118, 101, 114, 102, 108, 111, 119, 49, 55, 104, 51, 97, 54, 100, 97, 51, 54, 102, 48, 52, 56, 102, 97, 55, 49, 57, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 49, 55, 104, 97, 52, 102, 49, 48, 54, 99, 53, 53, 53, 50, 49, 57, 48, 52, 52, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 50, 48, 52, 51, 102, 48, 55, 56, 53, 97, 57, 97, 52, 52, 56, 97, 69, 0, 95, 95, 114, 117, 115, 116, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 90, 78, 57, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 101, 110, 118, 46, 46, 70, 100, 82, 101, 97, 100, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 82, 101, 97, 100, 36, 71, 84, 36, 49, 48, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 100, 57, 53, 99, 57, 99, 101, 101, 102, 98, 52, 98, 102, 50, 101, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 55, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 116, 117, 112, 108, 101, 49, 55, 104, 55, 53, 50, 56, 97, 101, 99, 49, 102, 56, 52, 97, 101, 100, 48, 51, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 98, 49, 101, 49, 50, 51, 48, 102, 57, 97, 54, 99, 100, 48, 56, 53, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 49, 102, 57, 53, 102, 54, 55, 55, 100, 102, 97, 49, 53, 56, 49, 98, 69, 0, 109, 101, 109, 99, 112, 121, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 54, 72, 65, 83, 72, 69, 82, 49, 55, 104, 99, 102, 102, 99, 102, 101, 49, 57, 48, 51, 102, 49, 98, 53, 50, 97, 69, 0, 95, 90, 78, 56, 50, 95, 36, 76, 84, 36, 100, 105, 103, 101, 115, 116, 46, 46, 99, 111, 114, 101, 95, 97, 112, 105, 46, 46, 119, 114, 97, 112, 112, 101, 114, 46, 46, 67, 111, 114, 101, 87, 114, 97, 112, 112, 101, 114, 36, 76, 84, 36, 84, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 100, 105, 103, 101, 115, 116, 46, 46, 85, 112, 100, 97, 116, 101, 36, 71, 84, 36, 54, 117, 112, 100, 97, 116, 101, 50, 56, 95, 36, 117, 55, 98, 36, 36, 117, 55, 98, 36, 99, 108, 111, 115, 117, 114, 101, 36, 117, 55, 100, 36, 36, 117, 55, 100, 36, 49, 55, 104, 57, 52, 101, 52, 53, 101, 52, 48, 100, 57, 100, 55, 100, 101, 100, 53, 69, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 53, 115, 116, 100, 105, 110, 49, 55, 104, 55, 97, 49, 48, 101, 48, 49, 101, 48, 56, 102, 98, 102, 97, 98, 48, 69, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 54, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 49, 49, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 97, 48, 50, 50, 51, 51, 102, 97, 99, 50, 102, 50, 48, 52, 102, 54, 97, 48, 97, 48, 55, 53, 51, 56, 101, 48, 50, 98, 48, 97, 48, 98, 46, 52, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 97, 110, 111, 110, 46, 97, 48, 50, 50, 51, 51, 102, 97, 99, 50, 102, 50, 48, 52, 102, 54, 97, 48, 97, 48, 55, 53, 51, 56, 101, 48, 50, 98, 48, 97, 48, 98, 46, 53, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 97, 110, 111, 110, 46, 97, 48, 50, 50, 51, 51, 102, 97, 99, 50, 102, 50, 48, 52, 102, 54, 97, 48, 97, 48, 55, 53, 51, 56, 101, 48, 50, 98, 48, 97, 48, 98, 46, 55, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 114, 101, 115, 117, 108, 116, 49, 51, 117, 110, 119, 114, 97, 112, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 100, 100, 48, 102, 48, 100, 50, 48, 49, 50, 102, 98, 49, 48, 50, 50, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 55, 106, 111, 117, 114, 110, 97, 108, 49, 55, 104, 99, 97, 56, 56, 49, 100, 99, 98, 55, 55, 97, 53, 99, 48, 57, 102, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 95, 57, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 46, 67, 108, 117, 115, 116, 101, 114, 67, 111, 109, 109, 105, 116, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 102, 52, 100, 50, 53, 51, 56, 56, 50, 54, 98, 53, 54, 54, 97, 55, 69, 0, 97, 110, 111, 110, 46, 97, 48, 50, 50, 51, 51, 102, 97, 99, 50, 102, 50, 48, 52, 102, 54, 97, 48, 97, 48, 55, 53, 51, 56, 101, 48, 50, 98, 48, 97, 48, 98, 46, 56, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 100, 57, 51, 56, 55, 52, 48, 57, 50, 48, 97, 101, 51, 100, 53, 51, 69, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 49, 49, 83, 72, 65, 50, 53, 54, 95, 73, 78, 73, 84, 49, 55, 104, 99, 101, 102, 102, 54, 52, 52, 49, 100, 52, 48, 52, 51, 54, 101, 55, 69, 0, 97, 110, 111, 110, 46, 52, 101, 102, 99, 56, 98, 56, 101, 98, 48, 53, 53, 53, 98, 49, 48, 99, 50, 52, 57, 101, 52, 100, 99, 52, 51, 56, 100, 100, 101, 99, 56, 46, 48, 46, 108, 108, 118, 109, 46, 49, 52, 49, 57, 49, 52, 54, 48, 53, 48, 57, 48, 57, 53, 52, 49, 51, 56, 52, 48, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 52, 99, 111, 109, 112, 114, 101, 115, 115, 95, 115, 108, 105, 99, 101, 49, 55, 104, 101, 97, 53, 50, 55, 55, 52, 48, 97, 54, 54, 57, 97, 56, 57, 99, 69, 0, 95, 90, 78, 54, 54, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 51, 54, 56, 54, 52, 99, 100, 102, 102, 98, 48, 57, 49, 57, 100, 102, 69, 0, 97, 110, 111, 110, 46, 97, 48, 50, 50, 51, 51, 102, 97, 99, 50, 102, 50, 48, 52, 102, 54, 97, 48, 97, 48, 55, 53, 51, 56, 101, 48, 50, 98, 48, 97, 48, 98, 46, 54, 46, 108, 108, 118, 109, 46, 49, 54, 50, 52, 49, 55, 50, 55, 53, 49, 52, 52, 50, 51, 48, 57, 56, 55, 54, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 108, 101, 110, 95, 109, 105, 115, 109, 97, 116, 99, 104, 95, 102, 97, 105, 108, 49, 55, 104, 56, 53, 97, 99, 100, 55, 48, 52, 54, 48, 98, 101, 49, 97, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 50, 55, 99, 57, 48, 49, 55, 52, 48, 98, 101, 97, 48, 99, 55, 54, 69, 0, 109, 101, 109, 109, 111, 118, 101, 0, 109, 101, 109, 115, 101, 116, 0, 95, 90, 78, 56, 55, 95, 36, 76, 84, 36, 97, 108, 108, 111, 99, 46, 46, 118, 101, 99, 46, 46, 86, 101, 99, 36, 76, 84, 36, 117, 51, 50, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 49, 119, 114, 105, 116, 101, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 56, 99, 97, 52, 54, 100, 53, 56, 99, 98, 53, 56, 97, 97, 54, 69, 0, 95, 90, 78, 49, 48, 53, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 87, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 50, 53, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 110, 101, 119, 116, 121, 112, 101, 95, 118, 97, 114, 105, 97, 110, 116, 49, 55, 104, 50, 98, 102, 102, 50, 99, 56, 99, 57, 50, 54, 55, 52, 56, 57, 54, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 115, 101, 114, 100, 101, 49, 48, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 54, 116, 111, 95, 118, 101, 99, 49, 55, 104, 50, 97, 57, 100, 57, 97, 56, 51, 98, 54, 56, 101, 50, 102, 50, 49, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 56, 49, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 83, 104, 105, 112, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 51, 50, 54, 98, 50, 49, 50, 101, 49, 49, 100, 57, 51, 57, 48, 54, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 56, 54, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 71, 97, 109, 101, 83, 116, 97, 116, 101, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 49, 53, 51, 99, 55, 51, 53, 53, 52, 50, 56, 102, 97, 54, 57, 101, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 95, 57, 56, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 46, 67, 108, 117, 115, 116, 101, 114, 66, 111, 109, 98, 80, 97, 114, 97, 109, 115, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 49, 53, 99, 48, 98, 51, 98, 52, 54, 101, 49, 48, 51, 54, 53, 55, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 56, 49, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 83, 104, 105, 112, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 97, 55, 51, 53, 102, 98, 101, 48, 50, 48, 55, 53, 49, 50, 51, 55, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 49, 95, 56, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 116, 121, 112, 101, 115, 46, 46, 72, 105, 116, 84, 121, 112, 101, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 52, 48, 102, 51, 98, 100, 49, 55, 55, 53, 50, 48, 100, 54, 51, 50, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 57, 48, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 83, 104, 105, 112, 68, 105, 114, 101, 99, 116, 105, 111, 110, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 102, 100, 99, 57, 51, 55, 54, 99, 98, 51, 98, 102, 48, 98, 100, 51, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 49, 95, 56, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 116, 121, 112, 101, 115, 46, 46, 80, 111, 115, 105, 116, 105, 111, 110, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 49, 54, 49, 49, 50, 49, 99, 57, 98, 100, 50, 99, 51, 51, 100, 101, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 55, 67, 108, 117, 115, 116, 101, 114, 66, 111, 109, 98, 80, 97, 114, 97, 109, 115, 53, 99, 104, 101, 99, 107, 49, 55, 104, 54, 48, 99, 57, 101, 102, 53, 52, 97, 53, 101, 52, 55, 51, 49, 53, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 49, 52, 122, 107, 118, 109, 95, 103, 101, 116, 114, 97, 110, 100, 111, 109, 49, 55, 104, 54, 48, 98, 99, 55, 52, 102, 48, 56, 102, 48, 97, 50, 101, 97, 51, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 56, 80, 111, 115, 105, 116, 105, 111, 110, 51, 110, 101, 119, 49, 55, 104, 49, 54, 50, 49, 54, 99, 52, 49, 101, 55, 49, 51, 97, 49, 54, 100, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 55, 67, 108, 117, 115, 116, 101, 114, 66, 111, 109, 98, 80, 97, 114, 97, 109, 115, 55, 112, 114, 111, 99, 101, 115, 115, 49, 55, 104, 49, 49, 100, 101, 54, 54, 56, 102, 53, 54, 51, 52, 100, 52, 52, 55, 69, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 48, 104, 97, 115, 104, 95, 119, 111, 114, 100, 115, 49, 55, 104, 102, 54, 98, 53, 57, 100, 57, 100, 53, 102, 56, 57, 51, 102, 97, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 119, 111, 114, 100, 115, 49, 55, 104, 50, 99, 101, 48, 48, 49, 101, 102, 48, 99, 56, 50, 98, 55, 102, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 53, 112, 97, 110, 105, 99, 49, 55, 104, 98, 53, 56, 56, 101, 101, 51, 56, 100, 101, 101, 56, 99, 101, 49, 49, 69, 0, 95, 90, 78, 54, 48, 95, 36, 76, 84, 36, 103, 101, 116, 114, 97, 110, 100, 111, 109, 46, 46, 101, 114, 114, 111, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 55, 53, 53, 55, 49, 51, 99, 56, 101, 99, 102, 99, 101, 102, 97, 51, 69, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 55, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 56, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 57, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 55, 97, 102, 97, 54, 97, 56, 100, 101, 57, 51, 98, 57, 97, 97, 51, 57, 100, 49, 55, 97, 53, 53, 52, 51, 49, 52, 53, 98, 55, 97, 55, 46, 49, 48, 46, 108, 108, 118, 109, 46, 49, 50, 51, 57, 50, 56, 48, 57, 54, 57, 56, 55, 48, 52, 53, 51, 49, 52, 49, 0, 97, 110, 111, 110, 46, 97, 54, 100, 99, 100, 53, 48, 98, 56, 52, 52, 98, 99, 54, 52, 49, 51, 102, 57, 57, 48, 57, 99, 100, 98, 52, 49, 56, 97, 48, 102, 48, 46, 48, 46, 108, 108, 118, 109, 46, 56, 49, 53, 49, 57, 49, 48, 52, 53, 48, 49, 53, 53, 51, 54, 50, 50, 57, 50, 0, 97, 110, 111, 110, 46, 97, 54, 100, 99, 100, 53, 48, 98, 56, 52, 52, 98, 99, 54, 52, 49, 51, 102, 57, 57, 48, 57, 99, 100, 98, 52, 49, 56, 97, 48, 102, 48, 46, 49, 46, 108, 108, 118, 109, 46, 56, 49, 53, 49, 57, 49, 48, 52, 53, 48, 49, 53, 53, 51, 54, 50, 50, 57, 50, 0, 95, 95, 114, 103, 95, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 97, 108, 108, 111, 99, 95, 122, 101, 114, 111, 101, 100, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 95, 104, 97, 110, 100, 108, 101, 114, 0, 95, 95, 114, 103, 95, 111, 111, 109, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 48, 83, 104, 111, 116, 80, 97, 114, 97, 109, 115, 55, 112, 114, 111, 99, 101, 115, 115, 49, 55, 104, 54, 57, 98, 56, 55, 99, 100, 52, 52, 97, 51, 50, 55, 99, 99, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 102, 109, 116, 49, 55, 104, 98, 48, 52, 49, 102, 98, 52, 56, 101, 100, 102, 57, 98, 99, 57, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 97, 114, 114, 97, 121, 50, 56, 99, 111, 108, 108, 101, 99, 116, 95, 105, 110, 116, 111, 95, 97, 114, 114, 97, 121, 95, 117, 110, 99, 104, 101, 99, 107, 101, 100, 49, 55, 104, 54, 99, 101, 99, 49, 53, 102, 98, 98, 56, 50, 97, 56, 56, 57, 56, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 55, 101, 57, 98, 50, 57, 52, 54, 57, 56, 52, 51, 99, 51, 97, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 56, 112, 97, 110, 105, 99, 95, 98, 111, 117, 110, 100, 115, 95, 99, 104, 101, 99, 107, 49, 55, 104, 101, 49, 55, 98, 101, 97, 53, 51, 52, 49, 101, 57, 51, 99, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 55, 55, 51, 101, 100, 54, 52, 97, 57, 99, 53, 55, 55, 55, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 108, 111, 119, 101, 114, 95, 104, 101, 120, 49, 55, 104, 53, 102, 102, 98, 50, 50, 56, 100, 53, 99, 100, 48, 57, 52, 102, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 101, 52, 101, 52, 49, 54, 52, 99, 48, 102, 97, 48, 98, 55, 99, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 117, 112, 112, 101, 114, 95, 104, 101, 120, 49, 55, 104, 99, 54, 57, 97, 98, 98, 57, 98, 53, 55, 100, 97, 52, 54, 97, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 98, 48, 53, 55, 102, 49, 52, 53, 56, 98, 102, 53, 52, 56, 49, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 48, 56, 53, 102, 50, 55, 48, 98, 56, 52, 100, 52, 48, 48, 53, 49, 69, 46, 108, 108, 118, 109, 46, 49, 55, 52, 49, 49, 55, 56, 49, 54, 49, 56, 51, 55, 48, 48, 48, 50, 54, 48, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 48, 100, 50, 99, 57, 57, 56, 98, 50, 97, 54, 53, 53, 98, 50, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 54, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 49, 55, 104, 53, 57, 51, 101, 48, 102, 54, 97, 99, 101, 100, 50, 54, 49, 57, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 51, 50, 36, 71, 84, 36, 49, 55, 104, 51, 97, 48, 53, 49, 52, 101, 51, 97, 53, 97, 57, 98, 99, 53, 57, 69, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 57, 98, 50, 98, 52, 56, 100, 54, 49, 100, 51, 53, 99, 50, 102, 54, 69, 0, 97, 110, 111, 110, 46, 53, 56, 100, 98, 52, 56, 57, 56, 51, 98, 51, 51, 57, 100, 99, 99, 49, 52, 51, 57, 57, 56, 54, 56, 56, 52, 54, 98, 48, 100, 51, 51, 46, 50, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 56, 52, 54, 55, 97, 49, 56, 50, 56, 99, 97, 52, 48, 55, 102, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 53, 102, 55, 53, 51, 56, 49, 51, 48, 49, 100, 48, 56, 57, 54, 69, 0, 95, 90, 78, 55, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 112, 97, 110, 105, 99, 46, 46, 112, 97, 110, 105, 99, 95, 105, 110, 102, 111, 46, 46, 80, 97, 110, 105, 99, 73, 110, 102, 111, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 55, 48, 101, 100, 53, 55, 49, 51, 52, 101, 101, 99, 100, 98, 54, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 51, 102, 109, 116, 54, 102, 111, 114, 109, 97, 116, 49, 50, 102, 111, 114, 109, 97, 116, 95, 105, 110, 110, 101, 114, 49, 55, 104, 101, 57, 53, 100, 99, 50, 52, 102, 97, 101, 100, 55, 54, 50, 99, 51, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 53, 97, 98, 111, 114, 116, 49, 55, 104, 102, 54, 51, 57, 57, 56, 54, 57, 51, 55, 48, 98, 50, 102, 98, 49, 69, 0, 95, 90, 78, 49, 48, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 48, 55, 51, 54, 98, 52, 102, 53, 99, 51, 102, 49, 100, 101, 48, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 57, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 95, 105, 110, 110, 101, 114, 49, 55, 104, 102, 102, 99, 57, 99, 100, 53, 48, 54, 54, 102, 55, 97, 56, 53, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 104, 50, 52, 57, 99, 99, 102, 53, 97, 101, 101, 101, 51, 51, 53, 51, 57, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 56, 102, 105, 110, 97, 108, 105, 122, 101, 49, 55, 104, 55, 49, 56, 100, 99, 99, 53, 101, 98, 101, 54, 54, 99, 100, 54, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 53, 66, 108, 111, 99, 107, 49, 52, 97, 115, 95, 104, 97, 108, 102, 95, 98, 108, 111, 99, 107, 115, 49, 55, 104, 50, 49, 51, 97, 53, 101, 53, 98, 55, 48, 97, 55, 99, 50, 51, 57, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 98, 121, 116, 101, 115, 49, 55, 104, 52, 54, 99, 57, 99, 98, 51, 56, 99, 49, 57, 99, 101, 102, 52, 51, 69, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 49, 52, 115, 121, 115, 95, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 49, 55, 102, 102, 56, 98, 97, 100, 99, 52, 56, 102, 102, 55, 53, 102, 69, 0, 95, 90, 78, 54, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 54, 101, 57, 99, 56, 53, 49, 57, 51, 98, 52, 102, 102, 102, 97, 52, 69, 0, 95, 115, 116, 97, 114, 116, 0, 95, 95, 103, 108, 111, 98, 97, 108, 95, 112, 111, 105, 110, 116, 101, 114, 36, 0, 95, 95, 115, 116, 97, 99, 107, 95, 105, 110, 105, 116, 36, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 52, 115, 108, 105, 99, 101, 95, 101, 110, 100, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 49, 55, 104, 57, 99, 53, 97, 48, 98, 49, 57, 97, 100, 50, 54, 54, 102, 99, 53, 69, 0, 95, 95, 98, 115, 115, 95, 98, 101, 103, 105, 110, 0, 95, 95, 98, 115, 115, 95, 101, 110, 100, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 55, 52, 57, 99, 100, 101, 55, 55, 99, 51, 54, 98, 50, 102, 51, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 57, 55, 97, 48, 56, 102, 50, 50, 50, 56, 50, 48, 98, 98, 97, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 57, 55, 51, 53, 50, 99, 98, 97, 54, 57, 57, 53, 52, 51, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 100, 101, 98, 117, 103, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 101, 53, 49, 56, 98, 56, 51, 52, 57, 48, 54, 57, 101, 102, 98, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 56, 98, 117, 105, 108, 100, 101, 114, 115, 49, 49, 68, 101, 98, 117, 103, 83, 116, 114, 117, 99, 116, 53, 102, 105, 101, 108, 100, 49, 55, 104, 50, 53, 57, 50, 52, 55, 100, 98, 51, 56, 51, 50, 101, 57, 97, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 56, 98, 117, 105, 108, 100, 101, 114, 115, 49, 49, 68, 101, 98, 117, 103, 83, 116, 114, 117, 99, 116, 54, 102, 105, 110, 105, 115, 104, 49, 55, 104, 57, 99, 55, 102, 55, 56, 51, 99, 100, 100, 53, 102, 55, 52, 97, 102, 69, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 102, 97, 97, 56, 49, 101, 56, 54, 48, 55, 57, 55, 100, 49, 54, 100, 69, 0, 95, 90, 78, 52, 48, 95, 36, 76, 84, 36, 115, 116, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 102, 57, 52, 57, 51, 97, 99, 53, 99, 48, 57, 48, 55, 99, 50, 69, 0, 95, 90, 78, 57, 50, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 56, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 97, 101, 97, 52, 99, 54, 50, 97, 56, 102, 48, 101, 98, 101, 48, 51, 69, 0, 95, 90, 78, 57, 51, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 102, 97, 49, 53, 57, 56, 57, 49, 55, 57, 55, 57, 99, 51, 102, 51, 69, 0, 95, 90, 78, 49, 49, 49, 95, 36, 76, 84, 36, 36, 82, 70, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 70, 114, 111, 109, 36, 76, 84, 36, 36, 82, 70, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 52, 102, 114, 111, 109, 49, 55, 104, 50, 102, 54, 54, 100, 100, 54, 51, 51, 102, 57, 57, 102, 52, 53, 56, 69, 0, 95, 90, 78, 52, 49, 95, 36, 76, 84, 36, 99, 104, 97, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 55, 102, 100, 49, 99, 51, 97, 102, 57, 53, 97, 57, 49, 101, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 50, 115, 108, 105, 99, 101, 95, 105, 110, 100, 101, 120, 95, 111, 114, 100, 101, 114, 95, 102, 97, 105, 108, 49, 55, 104, 56, 53, 98, 101, 50, 102, 100, 102, 99, 100, 97, 102, 49, 54, 102, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 49, 54, 100, 101, 57, 48, 55, 50, 48, 54, 53, 49, 99, 101, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 50, 101, 51, 98, 48, 56, 102, 49, 50, 100, 51, 49, 101, 97, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 52, 100, 54, 99, 55, 100, 100, 102, 100, 101, 101, 54, 50, 49, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 51, 101, 120, 112, 101, 99, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 98, 97, 50, 55, 98, 53, 97, 97, 97, 56, 54, 54, 53, 53, 52, 101, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 52, 98, 57, 101, 97, 101, 98, 50, 97, 55, 55, 100, 56, 99, 54, 102, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 52, 57, 97, 52, 56, 100, 48, 55, 97, 55, 101, 48, 53, 48, 101, 102, 69, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 97, 99, 101, 98, 50, 53, 57, 51, 55, 48, 52, 98, 48, 101, 52, 98, 69, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 48, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 54, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 55, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 51, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 53, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 56, 114, 116, 95, 101, 114, 114, 111, 114, 49, 55, 104, 48, 48, 101, 99, 48, 48, 50, 56, 53, 48, 56, 49, 48, 97, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 51, 112, 97, 100, 49, 55, 104, 50, 53, 101, 50, 98, 101, 49, 57, 49, 53, 56, 57, 99, 99, 54, 101, 69, 0, 97, 110, 111, 110, 46, 98, 53, 102, 54, 55, 54, 57, 56, 98, 97, 57, 50, 52, 49, 102, 49, 54, 49, 53, 54, 48, 55, 56, 52, 50, 50, 57, 50, 101, 50, 56, 50, 46, 48, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 119, 114, 105, 116, 101, 49, 55, 104, 51, 49, 102, 98, 50, 57, 102, 50, 98, 100, 55, 57, 101, 102, 56, 101, 69, 0, 95, 90, 78, 53, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 100, 55, 50, 48, 55, 55, 49, 101, 53, 54, 55, 49, 102, 102, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 97, 108, 108, 111, 99, 46, 46, 115, 116, 114, 105, 110, 103, 46, 46, 83, 116, 114, 105, 110, 103, 36, 71, 84, 36, 49, 55, 104, 57, 54, 102, 100, 56, 52, 56, 51, 48, 54, 56, 48, 101, 53, 56, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 98, 97, 51, 53, 52, 100, 49, 49, 100, 98, 100, 54, 49, 50, 48, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 53, 53, 57, 55, 97, 55, 51, 48, 98, 101, 50, 97, 101, 98, 51, 55, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 48, 54, 97, 98, 51, 97, 57, 100, 102, 50, 98, 101, 50, 99, 102, 99, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 53, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 49, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 48, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 48, 97, 100, 50, 102, 99, 56, 99, 100, 100, 97, 51, 99, 55, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 98, 53, 50, 57, 53, 48, 102, 51, 52, 101, 102, 100, 99, 50, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 55, 104, 48, 49, 99, 51, 54, 52, 50, 56, 49, 97, 101, 56, 102, 50, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 55, 117, 110, 105, 99, 111, 100, 101, 49, 50, 117, 110, 105, 99, 111, 100, 101, 95, 100, 97, 116, 97, 49, 53, 103, 114, 97, 112, 104, 101, 109, 101, 95, 101, 120, 116, 101, 110, 100, 54, 108, 111, 111, 107, 117, 112, 49, 55, 104, 52, 52, 49, 53, 97, 53, 51, 53, 99, 50, 54, 100, 100, 56, 97, 50, 69, 0, 97, 110, 111, 110, 46, 99, 49, 52, 54, 52, 51, 51, 98, 49, 99, 97, 51, 55, 57, 56, 52, 97, 48, 57, 51, 49, 57, 54, 97, 100, 101, 56, 56, 53, 101, 49, 99, 46, 51, 57, 46, 108, 108, 118, 109, 46, 49, 52, 57, 52, 57, 56, 48, 54, 51, 57, 48, 53, 52, 54, 53, 55, 53, 50, 49, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 55, 117, 110, 105, 99, 111, 100, 101, 49, 50, 117, 110, 105, 99, 111, 100, 101, 95, 100, 97, 116, 97, 49, 53, 103, 114, 97, 112, 104, 101, 109, 101, 95, 101, 120, 116, 101, 110, 100, 49, 55, 83, 72, 79, 82, 84, 95, 79, 70, 70, 83, 69, 84, 95, 82, 85, 78, 83, 49, 55, 104, 97, 56, 48, 98, 53, 57, 100, 51, 100, 100, 97, 49, 50, 55, 97, 54, 69, 46, 108, 108, 118, 109, 46, 49, 52, 57, 52, 57, 56, 48, 54, 51, 57, 48, 53, 52, 54, 53, 55, 53, 50, 49, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 55, 117, 110, 105, 99, 111, 100, 101, 49, 50, 117, 110, 105, 99, 111, 100, 101, 95, 100, 97, 116, 97, 49, 53, 103, 114, 97, 112, 104, 101, 109, 101, 95, 101, 120, 116, 101, 110, 100, 55, 79, 70, 70, 83, 69, 84, 83, 49, 55, 104, 99, 48, 54, 55, 53, 55, 56, 52, 100, 48, 97, 56, 98, 100, 52, 48, 69, 46, 108, 108, 118, 109, 46, 49, 52, 57, 52, 57, 56, 48, 54, 51, 57, 48, 53, 52, 54, 53, 55, 53, 50, 49, 57, 0, 97, 110, 111, 110, 46, 99, 49, 52, 54, 52, 51, 51, 98, 49, 99, 97, 51, 55, 57, 56, 52, 97, 48, 57, 51, 49, 57, 54, 97, 100, 101, 56, 56, 53, 101, 49, 99, 46, 56, 50, 46, 108, 108, 118, 109, 46, 49, 52, 57, 52, 57, 56, 48, 54, 51, 57, 48, 53, 52, 54, 53, 55, 53, 50, 49, 57, 0, 97, 110, 111, 110, 46, 99, 49, 52, 54, 52, 51, 51, 98, 49, 99, 97, 51, 55, 57, 56, 52, 97, 48, 57, 51, 49, 57, 54, 97, 100, 101, 56, 56, 53, 101, 49, 99, 46, 51, 56, 46, 108, 108, 118, 109, 46, 49, 52, 57, 52, 57, 56, 48, 54, 51, 57, 48, 53, 52, 54, 53, 55, 53, 50, 49, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 51, 51, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 51, 48, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 55, 117, 110, 105, 99, 111, 100, 101, 57, 112, 114, 105, 110, 116, 97, 98, 108, 101, 49, 50, 105, 115, 95, 112, 114, 105, 110, 116, 97, 98, 108, 101, 49, 55, 104, 101, 52, 53, 98, 53, 56, 97, 97, 51, 50, 57, 52, 52, 55, 53, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 49, 54, 115, 108, 105, 99, 101, 95, 101, 114, 114, 111, 114, 95, 102, 97, 105, 108, 49, 55, 104, 99, 53, 102, 97, 55, 101, 49, 98, 55, 52, 48, 51, 49, 49, 50, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 55, 102, 109, 116, 95, 117, 51, 50, 49, 55, 104, 98, 102, 50, 55, 97, 100, 102, 99, 49, 51, 97, 100, 98, 102, 57, 50, 69, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 54, 109, 101, 109, 99, 104, 114, 49, 52, 109, 101, 109, 99, 104, 114, 95, 97, 108, 105, 103, 110, 101, 100, 49, 55, 104, 51, 54, 99, 56, 52, 101, 50, 57, 52, 102, 54, 48, 55, 50, 100, 97, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 102, 55, 54, 51, 101, 49, 54, 100, 101, 102, 51, 98, 53, 56, 52, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 49, 57, 115, 108, 105, 99, 101, 95, 101, 114, 114, 111, 114, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 48, 97, 57, 101, 99, 101, 56, 53, 100, 102, 57, 98, 50, 99, 102, 48, 69, 0, 95, 90, 78, 55, 49, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 111, 112, 115, 46, 46, 114, 97, 110, 103, 101, 46, 46, 82, 97, 110, 103, 101, 36, 76, 84, 36, 73, 100, 120, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 50, 101, 53, 49, 98, 57, 48, 101, 99, 100, 51, 98, 55, 50, 100, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 53, 99, 111, 117, 110, 116, 49, 52, 100, 111, 95, 99, 111, 117, 110, 116, 95, 99, 104, 97, 114, 115, 49, 55, 104, 49, 102, 52, 100, 55, 57, 55, 57, 99, 54, 52, 55, 54, 98, 52, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 111, 112, 115, 56, 102, 117, 110, 99, 116, 105, 111, 110, 54, 70, 110, 79, 110, 99, 101, 57, 99, 97, 108, 108, 95, 111, 110, 99, 101, 49, 55, 104, 98, 100, 57, 99, 102, 49, 102, 56, 97, 49, 50, 57, 49, 56, 99, 51, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 53, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 104, 97, 114, 36, 71, 84, 36, 49, 55, 104, 99, 48, 51, 51, 57, 53, 102, 50, 54, 56, 48, 56, 55, 53, 48, 53, 69, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 54, 56, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 98, 117, 105, 108, 100, 101, 114, 115, 46, 46, 80, 97, 100, 65, 100, 97, 112, 116, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 50, 101, 57, 97, 54, 50, 51, 98, 97, 102, 50, 53, 54, 99, 51, 57, 69, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 55, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 52, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 53, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 54, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 56, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 87, 114, 105, 116, 101, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 57, 57, 97, 48, 50, 100, 99, 53, 53, 49, 99, 98, 48, 57, 52, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 87, 114, 105, 116, 101, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 54, 54, 53, 102, 52, 98, 102, 52, 54, 51, 52, 54, 102, 54, 50, 53, 69, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 49, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 55, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 56, 36, 71, 84, 36, 49, 55, 104, 98, 53, 97, 101, 57, 51, 48, 102, 55, 51, 53, 56, 52, 52, 48, 99, 69, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 57, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 99, 104, 97, 114, 36, 71, 84, 36, 49, 55, 104, 98, 56, 98, 52, 99, 52, 100, 52, 50, 101, 53, 97, 101, 50, 99, 53, 69, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 51, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 95, 90, 78, 51, 54, 95, 36, 76, 84, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 97, 110, 121, 46, 46, 65, 110, 121, 36, 71, 84, 36, 55, 116, 121, 112, 101, 95, 105, 100, 49, 55, 104, 53, 51, 49, 49, 53, 56, 48, 97, 57, 51, 57, 102, 56, 99, 54, 97, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 97, 52, 100, 52, 55, 55, 48, 102, 49, 55, 53, 98, 100, 55, 57, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 49, 97, 97, 54, 57, 54, 98, 99, 102, 102, 55, 98, 57, 57, 55, 69, 0, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 52, 46, 108, 108, 118, 109, 46, 53, 51, 51, 54, 50, 49, 55, 51, 55, 54, 54, 56, 57, 48, 57, 50, 51, 55, 49, 0, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 51, 46, 108, 108, 118, 109, 46, 53, 51, 51, 54, 50, 49, 55, 51, 55, 54, 54, 56, 57, 48, 57, 50, 51, 55, 49, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 57, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 98, 97, 51, 101, 54, 98, 99, 48, 57, 55, 100, 97, 98, 50, 48, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 55, 115, 108, 105, 99, 101, 95, 101, 110, 100, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 100, 53, 51, 51, 97, 49, 99, 97, 50, 101, 99, 101, 54, 56, 48, 56, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 53, 115, 108, 105, 99, 101, 95, 105, 110, 100, 101, 120, 95, 111, 114, 100, 101, 114, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 55, 53, 52, 54, 99, 101, 51, 54, 102, 102, 102, 57, 55, 102, 99, 54, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 52, 57, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 100, 121, 110, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 49, 55, 104, 98, 101, 102, 98, 49, 98, 100, 56, 52, 52, 48, 100, 101, 102, 52, 56, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 95, 90, 78, 53, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 65, 114, 103, 117, 109, 101, 110, 116, 115, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 50, 57, 53, 50, 51, 98, 100, 97, 100, 50, 54, 48, 100, 99, 98, 50, 69, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 52, 55, 102, 57, 100, 54, 52, 54, 51, 99, 57, 54, 98, 49, 51, 69, 0, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 53, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 50, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 98, 117, 105, 108, 100, 101, 114, 115, 46, 46, 80, 97, 100, 65, 100, 97, 112, 116, 101, 114, 36, 71, 84, 36, 49, 55, 104, 52, 55, 101, 51, 50, 101, 100, 50, 51, 97, 56, 97, 54, 56, 54, 52, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 95, 90, 78, 53, 55, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 70, 111, 114, 109, 97, 116, 116, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 54, 98, 55, 99, 99, 51, 57, 100, 101, 101, 98, 51, 97, 97, 100, 100, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 54, 52, 36, 71, 84, 36, 49, 55, 104, 49, 57, 51, 53, 51, 49, 100, 53, 48, 52, 56, 101, 99, 100, 98, 55, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 49, 55, 104, 102, 49, 50, 49, 55, 48, 97, 52, 55, 101, 53, 102, 57, 49, 57, 97, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 99, 111, 114, 101, 46, 46, 111, 112, 116, 105, 111, 110, 46, 46, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 36, 71, 84, 36, 49, 55, 104, 49, 55, 56, 97, 100, 100, 56, 54, 102, 98, 52, 51, 51, 50, 57, 54, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 55, 117, 110, 105, 99, 111, 100, 101, 57, 112, 114, 105, 110, 116, 97, 98, 108, 101, 53, 99, 104, 101, 99, 107, 49, 55, 104, 102, 100, 57, 57, 53, 51, 57, 101, 102, 52, 101, 49, 97, 99, 100, 57, 69, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 53, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 54, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 55, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 56, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 51, 57, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 56, 53, 102, 57, 50, 97, 53, 52, 100, 100, 51, 54, 48, 48, 50, 101, 57, 56, 54, 51, 99, 102, 56, 55, 101, 51, 98, 48, 50, 102, 102, 50, 46, 52, 48, 46, 108, 108, 118, 109, 46, 55, 56, 57, 51, 48, 56, 53, 56, 53, 54, 52, 57, 48, 54, 57, 52, 56, 57, 49, 0, 97, 110, 111, 110, 46, 101, 51, 101, 53, 98, 100, 99, 51, 49, 55, 99, 50, 98, 51, 102, 51, 98, 51, 102, 98, 102, 52, 51, 55, 97, 51, 52, 57, 53, 100, 53, 100, 46, 48, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 101, 55, 97, 55, 98, 99, 97, 53, 102, 48, 97, 56, 100, 49, 99, 99, 69, 0, 95, 90, 78, 49, 55, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 51, 109, 101, 109, 55, 109, 101, 109, 109, 111, 118, 101, 49, 55, 104, 55, 102, 101, 50, 52, 97, 51, 55, 52, 53, 56, 51, 55, 53, 52, 53, 69, 0, 95, 95, 104, 101, 97, 112, 95, 115, 116, 97, 114, 116, 0, 95, 95, 98, 115, 115, 95, 115, 105, 122, 101, 0, 95, 95, 104, 101, 97, 112, 95, 101, 110, 100, 0, 95, 95, 104, 101, 97, 112, 95, 115, 105, 122, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 7, 0, 16, 0, 0, 96, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 8, 0, 0, 192, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 8, 0, 0, 0, 3, 0, 0, 0, 4, 0, 8, 0, 4, 192, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 192, 0, 0, 240, 32, 0, 0, 6, 0, 0, 0, 173, 1, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 26, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 244, 224, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 225, 0, 0, 22, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
-pub const CLUSTER_ID: [u32; 8] = [283208537, 2607632695, 3035338955, 3282970173, 3860997799, 3854580583, 371591324, 3068503762];
-pub const CLUSTER_PATH: &'static str = r#"/home/chicken/ctf/fairgame/target/release/build/sagittarius-methods-25d5a44c9c25e36a/out/riscv-guest/riscv32im-risc0-zkvm-elf/release/cluster"#;
-            
-pub const TURN_ELF: &'static [u8] = &[127, 69, 76, 70, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 243, 0, 1, 0, 0, 0, 0, 0, 0, 7, 52, 0, 0, 0, 220, 199, 0, 0, 0, 0, 0, 0, 52, 0, 32, 0, 3, 0, 40, 0, 7, 0, 5, 0, 1, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 7, 0, 0, 0, 7, 71, 90, 0, 0, 71, 90, 0, 0, 5, 0, 0, 0, 0, 16, 0, 0, 1, 0, 0, 0, 0, 112, 0, 0, 0, 0, 8, 0, 0, 0, 8, 0, 4, 0, 0, 0, 84, 0, 0, 0, 6, 0, 0, 0, 0, 16, 0, 0, 81, 229, 116, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 17, 8, 249, 147, 129, 1, 128, 23, 1, 128, 249, 19, 1, 65, 255, 239, 0, 64, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 55, 5, 8, 0, 3, 37, 69, 0, 183, 5, 8, 0, 3, 166, 69, 5, 99, 122, 197, 0, 35, 32, 5, 0, 3, 166, 69, 5, 19, 5, 69, 0, 227, 106, 197, 254, 55, 4, 8, 0, 147, 4, 68, 0, 19, 133, 196, 0, 19, 6, 16, 4, 147, 5, 0, 0, 151, 48, 0, 0, 231, 128, 0, 170, 19, 5, 16, 0, 35, 34, 164, 0, 35, 162, 4, 0, 35, 164, 4, 0, 151, 16, 0, 0, 231, 128, 0, 10, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 195, 61, 19, 1, 1, 244, 35, 46, 17, 10, 35, 44, 129, 10, 35, 42, 145, 10, 35, 40, 33, 11, 35, 38, 49, 11, 35, 36, 65, 11, 35, 34, 81, 11, 35, 32, 97, 11, 35, 46, 113, 9, 35, 44, 129, 9, 35, 42, 145, 9, 35, 40, 161, 9, 35, 38, 177, 9, 19, 4, 5, 0, 99, 6, 6, 22, 19, 9, 6, 0, 147, 132, 5, 0, 19, 5, 1, 8, 147, 7, 48, 0, 151, 0, 0, 0, 231, 128, 128, 66, 131, 73, 129, 8, 3, 75, 1, 8, 19, 5, 32, 0, 99, 150, 169, 0, 35, 0, 100, 1, 111, 0, 128, 20, 131, 76, 33, 8, 3, 77, 17, 8, 131, 77, 65, 8, 131, 74, 49, 8, 3, 76, 97, 8, 3, 5, 177, 8, 3, 74, 81, 8, 131, 5, 161, 8, 3, 70, 145, 8, 35, 5, 161, 6, 131, 11, 113, 8, 19, 149, 133, 0, 51, 101, 197, 0, 147, 5, 16, 0, 35, 20, 161, 6, 99, 14, 185, 14, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 59, 3, 71, 129, 8, 131, 71, 1, 8, 19, 5, 32, 0, 99, 22, 167, 0, 35, 0, 244, 0, 111, 0, 192, 13, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 3, 72, 97, 8, 131, 72, 81, 8, 131, 5, 177, 8, 3, 6, 161, 8, 131, 70, 145, 8, 131, 14, 113, 8, 35, 9, 177, 6, 147, 21, 134, 0, 179, 229, 213, 0, 35, 24, 177, 6, 99, 10, 169, 8, 35, 34, 209, 3, 35, 36, 193, 3, 35, 38, 113, 2, 35, 40, 97, 2, 35, 42, 81, 2, 35, 44, 17, 3, 35, 46, 1, 3, 35, 32, 241, 4, 35, 34, 225, 4, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 0, 51, 131, 70, 129, 8, 3, 71, 1, 8, 19, 5, 32, 0, 99, 150, 166, 0, 35, 0, 228, 0, 111, 0, 0, 5, 131, 72, 33, 8, 131, 66, 17, 8, 3, 67, 65, 8, 131, 67, 49, 8, 131, 71, 97, 8, 3, 72, 81, 8, 3, 5, 177, 8, 131, 5, 161, 8, 3, 70, 145, 8, 3, 14, 113, 8, 35, 13, 161, 6, 19, 149, 133, 0, 51, 101, 197, 0, 35, 28, 161, 6, 19, 5, 48, 0, 99, 24, 169, 4, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 11, 3, 36, 129, 11, 131, 36, 65, 11, 3, 41, 1, 11, 131, 41, 193, 10, 3, 42, 129, 10, 131, 42, 65, 10, 3, 43, 1, 10, 131, 43, 193, 9, 3, 44, 129, 9, 131, 44, 65, 9, 3, 45, 1, 9, 131, 45, 193, 8, 19, 1, 1, 12, 103, 128, 0, 0, 35, 34, 193, 1, 35, 36, 113, 0, 35, 38, 97, 0, 35, 40, 81, 0, 19, 137, 8, 0, 35, 44, 1, 1, 35, 42, 241, 0, 35, 46, 225, 0, 35, 32, 209, 2, 19, 5, 1, 8, 147, 7, 48, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 64, 37, 3, 69, 129, 8, 131, 69, 1, 8, 19, 6, 32, 0, 99, 24, 197, 0, 35, 0, 180, 0, 35, 4, 196, 0, 111, 240, 95, 247, 19, 150, 140, 0, 51, 102, 166, 1, 147, 150, 141, 0, 179, 230, 86, 1, 147, 150, 6, 1, 179, 230, 198, 0, 19, 22, 140, 0, 51, 102, 70, 1, 19, 151, 11, 1, 51, 102, 230, 0, 19, 215, 134, 1, 19, 22, 134, 0, 51, 102, 230, 0, 147, 150, 134, 0, 179, 230, 102, 1, 3, 39, 65, 3, 19, 23, 135, 0, 131, 39, 1, 3, 51, 103, 247, 0, 131, 39, 193, 2, 147, 151, 135, 0, 3, 40, 129, 2, 179, 231, 7, 1, 147, 151, 7, 1, 179, 231, 231, 0, 3, 39, 193, 3, 19, 23, 135, 0, 3, 40, 129, 3, 51, 103, 7, 1, 3, 40, 65, 2, 19, 24, 8, 1, 51, 103, 7, 1, 19, 216, 135, 1, 19, 23, 135, 0, 51, 103, 7, 1, 147, 151, 135, 0, 3, 40, 1, 4, 179, 231, 7, 1, 19, 24, 137, 0, 131, 40, 1, 1, 51, 104, 24, 1, 131, 40, 193, 0, 147, 152, 136, 0, 131, 34, 129, 0, 179, 232, 88, 0, 147, 152, 8, 1, 179, 232, 8, 1, 3, 40, 65, 1, 19, 24, 136, 0, 131, 34, 129, 1, 51, 104, 88, 0, 131, 34, 65, 0, 147, 146, 2, 1, 51, 104, 88, 0, 147, 210, 136, 1, 19, 24, 136, 0, 51, 104, 88, 0, 147, 152, 136, 0, 131, 34, 193, 1, 179, 232, 88, 0, 131, 66, 33, 8, 3, 67, 17, 8, 131, 67, 65, 8, 3, 78, 49, 8, 147, 146, 130, 0, 179, 226, 98, 0, 19, 147, 131, 0, 51, 99, 195, 1, 19, 19, 3, 1, 131, 67, 97, 8, 3, 78, 81, 8, 131, 14, 113, 8, 179, 98, 83, 0, 19, 147, 131, 0, 51, 99, 195, 1, 147, 147, 14, 1, 51, 99, 115, 0, 147, 211, 130, 1, 19, 19, 131, 0, 51, 99, 115, 0, 131, 3, 177, 8, 3, 14, 161, 8, 131, 78, 145, 8, 147, 146, 130, 0, 35, 5, 113, 4, 147, 19, 142, 0, 179, 227, 211, 1, 3, 30, 129, 6, 131, 14, 161, 6, 35, 20, 113, 4, 179, 229, 178, 0, 35, 16, 193, 7, 35, 1, 209, 7, 131, 18, 1, 7, 131, 3, 33, 7, 3, 30, 129, 7, 131, 14, 161, 7, 35, 28, 81, 4, 35, 13, 113, 4, 35, 24, 193, 5, 35, 9, 209, 5, 35, 34, 196, 0, 35, 32, 212, 0, 3, 6, 1, 6, 131, 6, 17, 6, 131, 2, 33, 6, 35, 4, 52, 1, 163, 4, 196, 0, 35, 5, 212, 0, 163, 5, 84, 0, 35, 40, 228, 0, 35, 38, 244, 0, 3, 6, 129, 5, 131, 6, 145, 5, 3, 7, 161, 5, 131, 39, 65, 4, 35, 10, 244, 0, 163, 10, 196, 0, 35, 11, 212, 0, 163, 11, 228, 0, 35, 46, 4, 1, 35, 44, 20, 1, 3, 6, 1, 5, 131, 6, 17, 5, 3, 7, 33, 5, 131, 39, 1, 2, 35, 0, 244, 2, 163, 0, 196, 2, 35, 1, 212, 2, 163, 1, 228, 2, 35, 36, 100, 2, 35, 34, 180, 2, 131, 5, 129, 4, 3, 6, 145, 4, 131, 6, 161, 4, 35, 6, 164, 2, 163, 6, 180, 2, 35, 7, 196, 2, 163, 7, 212, 2, 111, 240, 95, 212, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 19, 4, 5, 0, 99, 142, 7, 4, 19, 137, 7, 0, 131, 164, 5, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 64, 54, 19, 117, 245, 15, 19, 10, 112, 0, 99, 26, 69, 3, 131, 41, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 147, 10, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 128, 51, 19, 117, 245, 15, 99, 22, 69, 1, 99, 28, 89, 3, 19, 5, 80, 0, 35, 0, 164, 0, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 3, 42, 1, 0, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 192, 45, 19, 117, 245, 15, 147, 5, 112, 0, 227, 22, 181, 250, 131, 37, 1, 0, 99, 138, 5, 0, 147, 10, 16, 0, 19, 5, 80, 0, 99, 134, 85, 1, 111, 240, 95, 249, 147, 10, 0, 0, 19, 5, 32, 0, 99, 24, 169, 0, 147, 5, 80, 0, 35, 0, 180, 0, 111, 240, 95, 248, 35, 32, 1, 0, 147, 5, 1, 0, 19, 6, 16, 0, 19, 133, 4, 0, 151, 32, 0, 0, 231, 128, 128, 40, 19, 117, 245, 15, 147, 5, 112, 0, 227, 28, 181, 244, 131, 37, 1, 0, 19, 6, 0, 16, 19, 5, 80, 0, 227, 244, 197, 244, 35, 32, 52, 1, 35, 34, 68, 1, 35, 4, 84, 1, 163, 4, 180, 0, 111, 240, 31, 244, 19, 1, 1, 242, 35, 46, 17, 12, 35, 44, 129, 12, 35, 42, 145, 12, 35, 40, 33, 13, 35, 38, 49, 13, 35, 36, 65, 13, 35, 34, 81, 13, 35, 32, 97, 13, 35, 46, 113, 11, 35, 44, 129, 11, 35, 42, 145, 11, 35, 40, 161, 11, 35, 38, 177, 11, 19, 4, 5, 0, 147, 10, 80, 0, 99, 128, 7, 28, 147, 132, 7, 0, 19, 137, 5, 0, 19, 5, 129, 7, 19, 6, 64, 0, 151, 0, 0, 0, 231, 128, 0, 158, 131, 73, 1, 8, 131, 69, 129, 7, 19, 5, 32, 0, 99, 150, 169, 0, 147, 138, 5, 0, 111, 0, 0, 25, 35, 34, 177, 2, 3, 77, 161, 7, 131, 77, 145, 7, 131, 75, 193, 7, 3, 74, 177, 7, 3, 76, 225, 7, 3, 75, 209, 7, 131, 12, 241, 7, 147, 5, 17, 8, 19, 5, 17, 5, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 64, 79, 3, 41, 9, 0, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 32, 0, 0, 231, 128, 64, 25, 19, 117, 245, 15, 147, 5, 112, 0, 99, 24, 181, 18, 35, 36, 65, 1, 35, 38, 113, 1, 35, 40, 177, 1, 35, 42, 161, 1, 35, 44, 145, 1, 35, 46, 97, 1, 35, 32, 129, 3, 3, 43, 129, 7, 19, 5, 161, 2, 147, 5, 17, 5, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 128, 73, 19, 5, 16, 0, 99, 140, 164, 14, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 32, 0, 0, 231, 128, 64, 19, 147, 122, 245, 15, 147, 11, 112, 0, 99, 154, 122, 13, 131, 36, 129, 7, 35, 44, 1, 6, 147, 5, 129, 7, 19, 6, 16, 0, 19, 5, 9, 0, 151, 32, 0, 0, 231, 128, 192, 16, 147, 122, 245, 15, 99, 152, 122, 11, 3, 37, 65, 1, 19, 21, 133, 0, 131, 37, 1, 1, 51, 101, 181, 0, 131, 37, 193, 0, 147, 149, 133, 0, 3, 38, 129, 0, 179, 229, 197, 0, 147, 149, 5, 1, 51, 229, 165, 0, 131, 37, 1, 2, 147, 149, 133, 0, 3, 38, 193, 1, 179, 229, 197, 0, 3, 38, 129, 1, 19, 22, 6, 1, 179, 229, 197, 0, 19, 86, 133, 1, 147, 149, 133, 0, 51, 233, 197, 0, 131, 42, 129, 7, 19, 21, 133, 0, 131, 37, 65, 2, 51, 106, 181, 0, 19, 5, 148, 0, 147, 5, 161, 2, 19, 6, 112, 2, 151, 32, 0, 0, 231, 128, 64, 61, 19, 85, 139, 1, 163, 9, 164, 2, 19, 85, 11, 1, 35, 9, 164, 2, 19, 85, 139, 0, 163, 8, 164, 2, 35, 8, 100, 3, 35, 34, 36, 1, 35, 32, 68, 1, 35, 4, 52, 1, 35, 42, 148, 2, 35, 44, 84, 3, 111, 0, 64, 1, 147, 10, 5, 0, 35, 0, 84, 1, 19, 5, 32, 0, 35, 4, 164, 0, 131, 32, 193, 13, 3, 36, 129, 13, 131, 36, 65, 13, 3, 41, 1, 13, 131, 41, 193, 12, 3, 42, 129, 12, 131, 42, 65, 12, 3, 43, 1, 12, 131, 43, 193, 11, 3, 44, 129, 11, 131, 44, 65, 11, 3, 45, 1, 11, 131, 45, 193, 10, 19, 1, 1, 14, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 131, 42, 5, 0, 3, 165, 74, 0, 3, 164, 10, 0, 147, 132, 5, 0, 51, 5, 197, 0, 35, 162, 170, 0, 99, 22, 4, 0, 55, 85, 0, 7, 19, 4, 197, 48, 19, 245, 52, 0, 99, 6, 5, 2, 19, 5, 240, 255, 99, 2, 166, 2, 147, 6, 0, 0, 55, 85, 0, 7, 147, 5, 197, 228, 19, 5, 6, 0, 99, 16, 5, 2, 19, 5, 4, 0, 19, 134, 6, 0, 111, 0, 128, 9, 147, 21, 101, 0, 179, 133, 180, 0, 179, 6, 166, 64, 227, 4, 5, 254, 99, 14, 6, 6, 19, 85, 150, 1, 99, 24, 5, 10, 19, 25, 102, 0, 19, 53, 21, 0, 19, 26, 37, 0, 19, 5, 9, 0, 147, 5, 10, 0, 151, 16, 0, 0, 231, 128, 64, 221, 99, 14, 5, 8, 147, 9, 5, 0, 19, 5, 9, 252, 19, 90, 101, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 9, 0, 151, 32, 0, 0, 231, 128, 0, 40, 19, 6, 26, 0, 19, 5, 4, 0, 147, 133, 9, 0, 151, 32, 0, 0, 231, 128, 128, 158, 19, 4, 5, 0, 19, 6, 64, 0, 19, 133, 9, 0, 147, 5, 9, 0, 151, 16, 0, 0, 231, 128, 192, 216, 111, 0, 128, 1, 147, 5, 64, 0, 19, 5, 4, 0, 151, 32, 0, 0, 231, 128, 192, 155, 19, 4, 5, 0, 35, 160, 138, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 19, 1, 1, 2, 103, 128, 0, 0, 151, 48, 0, 0, 231, 128, 192, 153, 115, 16, 0, 192, 19, 5, 9, 0, 147, 5, 10, 0, 151, 48, 0, 0, 231, 128, 192, 156, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 147, 4, 5, 0, 3, 37, 5, 0, 3, 164, 5, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 128, 9, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 9, 3, 165, 68, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 0, 7, 19, 117, 245, 15, 99, 30, 37, 5, 3, 197, 132, 0, 99, 8, 5, 0, 19, 5, 16, 0, 35, 38, 161, 0, 111, 0, 128, 0, 35, 38, 1, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 192, 3, 19, 117, 245, 15, 147, 5, 112, 0, 99, 18, 181, 2, 3, 197, 148, 0, 35, 38, 161, 0, 147, 5, 193, 0, 19, 6, 16, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 1, 19, 117, 245, 15, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 131, 41, 5, 0, 35, 32, 209, 0, 3, 165, 9, 0, 19, 4, 8, 0, 147, 5, 1, 0, 19, 6, 64, 0, 147, 4, 1, 0, 151, 32, 0, 0, 231, 128, 192, 89, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 34, 193, 0, 99, 232, 166, 2, 3, 38, 1, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 138, 196, 0, 147, 4, 69, 252, 19, 137, 197, 0, 51, 5, 169, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 0, 7, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 64, 209, 19, 245, 4, 252, 179, 5, 170, 0, 19, 5, 9, 0, 19, 134, 4, 0, 151, 32, 0, 0, 231, 128, 64, 4, 3, 69, 4, 0, 183, 5, 8, 0, 19, 137, 69, 0, 35, 6, 153, 4, 35, 32, 161, 0, 3, 165, 9, 0, 147, 4, 73, 0, 147, 5, 1, 0, 19, 6, 64, 0, 19, 4, 1, 0, 151, 32, 0, 0, 231, 128, 64, 77, 3, 69, 201, 4, 147, 5, 176, 3, 35, 34, 145, 0, 99, 232, 165, 2, 131, 37, 1, 0, 51, 6, 169, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 4, 69, 0, 111, 0, 64, 5, 147, 5, 0, 4, 51, 134, 165, 64, 179, 9, 196, 0, 19, 4, 69, 252, 147, 4, 201, 0, 51, 133, 164, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 64, 251, 19, 5, 65, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 197, 19, 117, 4, 252, 179, 133, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 248, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 133, 4, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 147, 132, 5, 0, 19, 4, 5, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 16, 0, 0, 231, 128, 0, 166, 99, 2, 5, 16, 35, 36, 161, 0, 19, 5, 64, 3, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 129, 0, 35, 44, 161, 0, 147, 5, 129, 1, 19, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 209, 19, 117, 245, 15, 19, 9, 112, 0, 99, 26, 37, 9, 19, 133, 196, 0, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 207, 19, 117, 245, 15, 99, 30, 37, 7, 19, 133, 132, 1, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 64, 206, 19, 117, 245, 15, 19, 9, 112, 0, 99, 16, 37, 7, 19, 133, 68, 2, 147, 5, 129, 1, 151, 0, 0, 0, 231, 128, 128, 204, 19, 117, 245, 15, 99, 20, 37, 5, 131, 165, 4, 3, 3, 37, 129, 1, 35, 46, 177, 0, 147, 5, 193, 1, 19, 6, 16, 0, 151, 16, 0, 0, 231, 128, 192, 214, 19, 117, 245, 15, 147, 5, 112, 0, 99, 16, 181, 2, 3, 37, 1, 1, 131, 37, 193, 0, 3, 38, 129, 0, 35, 36, 164, 0, 35, 34, 180, 0, 35, 32, 196, 0, 111, 0, 128, 2, 131, 37, 193, 0, 35, 2, 164, 0, 35, 32, 4, 0, 99, 140, 5, 0, 3, 37, 129, 0, 147, 149, 37, 0, 19, 6, 64, 0, 151, 16, 0, 0, 231, 128, 192, 151, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 5, 0, 13, 147, 5, 64, 0, 151, 32, 0, 0, 231, 128, 0, 95, 115, 16, 0, 192, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 70, 5, 0, 147, 6, 16, 0, 99, 202, 198, 0, 99, 18, 6, 2, 3, 165, 5, 0, 35, 36, 1, 0, 111, 0, 64, 2, 147, 6, 32, 0, 99, 24, 214, 14, 3, 165, 5, 0, 35, 36, 209, 0, 111, 0, 0, 1, 3, 165, 5, 0, 147, 5, 16, 0, 35, 36, 177, 0, 3, 37, 5, 0, 147, 5, 129, 0, 19, 6, 64, 0, 19, 4, 129, 0, 151, 32, 0, 0, 231, 128, 0, 39, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 38, 193, 0, 99, 232, 166, 2, 3, 38, 129, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 19, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 9, 196, 0, 19, 4, 69, 252, 147, 132, 197, 0, 51, 133, 164, 0, 147, 5, 129, 0, 151, 32, 0, 0, 231, 128, 64, 212, 19, 5, 193, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 158, 19, 117, 4, 252, 179, 5, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 209, 55, 5, 8, 0, 35, 8, 133, 4, 19, 5, 112, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 8, 21, 0, 55, 85, 0, 7, 147, 8, 197, 228, 55, 85, 0, 7, 19, 7, 53, 229, 19, 6, 112, 0, 147, 6, 48, 0, 147, 7, 144, 0, 19, 133, 5, 0, 147, 133, 8, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 23, 3, 0, 0, 103, 0, 195, 185, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 19, 4, 5, 0, 3, 37, 5, 0, 131, 169, 5, 0, 35, 32, 161, 0, 3, 165, 9, 0, 147, 5, 1, 0, 19, 6, 64, 0, 147, 4, 1, 0, 151, 32, 0, 0, 231, 128, 192, 18, 55, 5, 8, 0, 147, 5, 69, 0, 3, 197, 197, 4, 19, 134, 69, 0, 147, 6, 176, 3, 35, 34, 193, 0, 99, 232, 166, 2, 3, 38, 1, 0, 179, 133, 165, 0, 147, 86, 134, 1, 163, 135, 213, 0, 147, 86, 6, 1, 35, 135, 213, 0, 147, 86, 134, 0, 163, 134, 213, 0, 35, 134, 197, 0, 147, 4, 69, 0, 111, 0, 64, 5, 19, 6, 0, 4, 51, 6, 166, 64, 51, 138, 196, 0, 147, 4, 69, 252, 19, 137, 197, 0, 51, 5, 169, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 0, 192, 19, 5, 65, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 64, 138, 19, 245, 4, 252, 179, 5, 170, 0, 19, 5, 9, 0, 19, 134, 4, 0, 151, 32, 0, 0, 231, 128, 64, 189, 3, 37, 68, 0, 183, 5, 8, 0, 19, 137, 69, 0, 35, 6, 153, 4, 35, 32, 161, 0, 3, 165, 9, 0, 147, 4, 73, 0, 147, 5, 1, 0, 19, 6, 64, 0, 19, 4, 1, 0, 151, 32, 0, 0, 231, 128, 64, 6, 3, 69, 201, 4, 147, 5, 176, 3, 35, 34, 145, 0, 99, 232, 165, 2, 131, 37, 1, 0, 51, 6, 169, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 19, 4, 69, 0, 111, 0, 64, 5, 147, 5, 0, 4, 51, 134, 165, 64, 179, 9, 196, 0, 19, 4, 69, 252, 147, 4, 201, 0, 51, 133, 164, 0, 147, 5, 1, 0, 151, 32, 0, 0, 231, 128, 64, 180, 19, 5, 65, 0, 19, 6, 16, 0, 147, 133, 4, 0, 151, 240, 255, 255, 231, 128, 128, 126, 19, 117, 4, 252, 179, 133, 169, 0, 19, 133, 4, 0, 19, 6, 4, 0, 151, 32, 0, 0, 231, 128, 128, 177, 183, 5, 8, 0, 19, 5, 112, 0, 35, 136, 133, 4, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 19, 1, 1, 2, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 238, 35, 46, 17, 16, 35, 44, 129, 16, 35, 42, 145, 16, 151, 16, 0, 0, 231, 128, 64, 120, 35, 44, 161, 8, 19, 5, 129, 9, 35, 32, 161, 4, 55, 85, 0, 7, 19, 6, 69, 239, 55, 85, 0, 7, 19, 7, 69, 240, 19, 5, 129, 11, 147, 5, 1, 4, 147, 6, 160, 0, 147, 7, 32, 0, 19, 4, 32, 0, 151, 240, 255, 255, 231, 128, 64, 80, 3, 69, 1, 12, 99, 6, 133, 38, 19, 5, 1, 0, 147, 5, 129, 11, 19, 6, 192, 3, 151, 32, 0, 0, 231, 128, 192, 167, 19, 5, 1, 4, 147, 5, 1, 0, 151, 0, 0, 0, 231, 128, 192, 91, 35, 42, 1, 8, 35, 40, 1, 8, 35, 38, 1, 8, 35, 36, 1, 8, 35, 34, 1, 8, 35, 32, 1, 8, 35, 46, 1, 6, 35, 44, 1, 6, 35, 42, 1, 10, 35, 40, 1, 10, 35, 38, 1, 10, 35, 36, 1, 10, 35, 34, 1, 10, 35, 32, 1, 10, 35, 46, 1, 8, 35, 44, 1, 8, 19, 5, 129, 11, 147, 5, 1, 0, 151, 0, 0, 0, 231, 128, 128, 172, 3, 36, 129, 11, 99, 2, 4, 34, 131, 36, 193, 11, 131, 37, 1, 12, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 64, 13, 151, 32, 0, 0, 231, 128, 64, 226, 19, 6, 128, 0, 99, 146, 197, 34, 131, 37, 197, 1, 35, 42, 177, 8, 131, 37, 133, 1, 35, 40, 177, 8, 131, 37, 69, 1, 35, 38, 177, 8, 131, 37, 5, 1, 35, 36, 177, 8, 131, 37, 197, 0, 35, 34, 177, 8, 131, 37, 133, 0, 35, 32, 177, 8, 131, 37, 69, 0, 35, 46, 177, 6, 3, 37, 5, 0, 35, 44, 161, 6, 99, 140, 4, 0, 147, 149, 36, 0, 19, 6, 64, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 0, 77, 19, 5, 129, 11, 147, 5, 1, 4, 151, 0, 0, 0, 231, 128, 64, 163, 3, 36, 129, 11, 99, 0, 4, 28, 131, 36, 193, 11, 131, 37, 1, 12, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 0, 4, 151, 32, 0, 0, 231, 128, 0, 217, 19, 6, 128, 0, 99, 152, 197, 28, 131, 37, 197, 1, 35, 42, 177, 10, 131, 37, 133, 1, 35, 40, 177, 10, 131, 37, 69, 1, 35, 38, 177, 10, 131, 37, 5, 1, 35, 36, 177, 10, 131, 37, 197, 0, 35, 34, 177, 10, 131, 37, 133, 0, 35, 32, 177, 10, 131, 37, 69, 0, 35, 46, 177, 8, 3, 37, 5, 0, 35, 44, 161, 8, 99, 140, 4, 0, 147, 149, 36, 0, 19, 6, 64, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 67, 3, 37, 65, 9, 131, 37, 1, 9, 3, 38, 193, 8, 35, 42, 161, 12, 35, 40, 177, 12, 35, 38, 193, 12, 3, 37, 129, 8, 131, 37, 65, 8, 3, 38, 1, 8, 131, 38, 193, 7, 35, 36, 161, 12, 35, 34, 177, 12, 35, 32, 193, 12, 35, 46, 209, 10, 3, 37, 129, 7, 131, 37, 129, 9, 3, 38, 193, 9, 131, 38, 1, 10, 35, 44, 161, 10, 35, 44, 177, 12, 35, 46, 193, 12, 35, 32, 209, 14, 3, 37, 65, 10, 131, 37, 129, 10, 3, 38, 193, 10, 131, 38, 1, 11, 35, 34, 161, 14, 35, 36, 177, 14, 35, 38, 193, 14, 35, 40, 209, 14, 3, 37, 65, 11, 131, 37, 65, 3, 3, 38, 129, 3, 131, 22, 65, 7, 35, 42, 161, 14, 35, 44, 177, 14, 35, 46, 193, 14, 35, 16, 209, 16, 151, 16, 0, 0, 231, 128, 0, 81, 35, 36, 161, 16, 19, 5, 129, 16, 35, 38, 161, 16, 19, 5, 129, 11, 147, 5, 193, 16, 151, 0, 0, 0, 231, 128, 128, 16, 19, 117, 245, 15, 147, 5, 112, 0, 99, 20, 181, 12, 131, 32, 193, 17, 3, 36, 129, 17, 131, 36, 65, 17, 19, 1, 1, 18, 103, 128, 0, 0, 3, 5, 129, 11, 35, 0, 161, 0, 55, 85, 0, 7, 19, 5, 5, 217, 183, 85, 0, 7, 147, 134, 197, 219, 183, 85, 0, 7, 19, 135, 197, 226, 147, 5, 176, 2, 19, 6, 1, 0, 151, 48, 0, 0, 231, 128, 192, 114, 115, 16, 0, 192, 3, 5, 193, 11, 35, 6, 161, 16, 55, 85, 0, 7, 19, 5, 197, 229, 183, 85, 0, 7, 147, 134, 133, 232, 183, 85, 0, 7, 19, 135, 133, 234, 111, 0, 0, 3, 55, 85, 0, 7, 19, 6, 133, 235, 111, 0, 0, 4, 3, 5, 193, 11, 35, 6, 161, 16, 55, 85, 0, 7, 19, 5, 197, 229, 183, 85, 0, 7, 147, 134, 133, 232, 183, 85, 0, 7, 19, 135, 133, 236, 147, 5, 176, 2, 19, 6, 193, 16, 151, 48, 0, 0, 231, 128, 128, 108, 115, 16, 0, 192, 55, 85, 0, 7, 19, 6, 133, 237, 19, 5, 128, 0, 151, 32, 0, 0, 231, 128, 0, 74, 115, 16, 0, 192, 163, 9, 161, 16, 55, 85, 0, 7, 19, 5, 5, 217, 183, 85, 0, 7, 147, 134, 197, 219, 183, 85, 0, 7, 19, 135, 197, 227, 147, 5, 176, 2, 19, 6, 49, 17, 151, 48, 0, 0, 231, 128, 0, 104, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 147, 4, 5, 0, 147, 10, 0, 0, 35, 32, 177, 0, 3, 170, 5, 0, 19, 11, 65, 0, 55, 5, 8, 0, 147, 11, 69, 0, 19, 140, 75, 0, 147, 12, 176, 3, 19, 13, 0, 2, 147, 13, 0, 4, 19, 137, 203, 0, 111, 0, 128, 5, 51, 134, 173, 64, 51, 4, 203, 0, 147, 9, 69, 252, 51, 5, 169, 0, 147, 5, 65, 0, 151, 16, 0, 0, 231, 128, 0, 108, 19, 5, 129, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 64, 54, 19, 245, 9, 252, 179, 5, 164, 0, 19, 5, 9, 0, 19, 134, 9, 0, 151, 16, 0, 0, 231, 128, 64, 105, 147, 138, 74, 0, 35, 134, 59, 5, 99, 130, 170, 7, 51, 133, 84, 1, 3, 37, 5, 0, 35, 34, 161, 0, 3, 37, 10, 0, 147, 5, 65, 0, 19, 6, 64, 0, 151, 32, 0, 0, 231, 128, 128, 178, 3, 197, 203, 4, 35, 36, 129, 1, 227, 226, 172, 248, 131, 37, 65, 0, 51, 134, 171, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 147, 9, 69, 0, 147, 138, 74, 0, 35, 134, 59, 5, 227, 146, 170, 251, 147, 10, 0, 0, 19, 139, 4, 2, 55, 5, 8, 0, 19, 12, 69, 0, 147, 12, 76, 0, 19, 13, 176, 3, 147, 13, 0, 2, 19, 4, 0, 4, 19, 9, 204, 0, 111, 0, 192, 5, 51, 6, 164, 64, 147, 5, 65, 0, 179, 139, 197, 0, 147, 9, 69, 252, 51, 5, 169, 0, 147, 5, 65, 0, 151, 16, 0, 0, 231, 128, 0, 94, 19, 5, 129, 0, 19, 6, 16, 0, 147, 5, 9, 0, 151, 240, 255, 255, 231, 128, 64, 40, 19, 245, 9, 252, 179, 133, 171, 0, 19, 5, 9, 0, 19, 134, 9, 0, 151, 16, 0, 0, 231, 128, 64, 91, 147, 138, 74, 0, 35, 6, 60, 5, 99, 130, 186, 7, 51, 5, 91, 1, 3, 37, 5, 0, 35, 34, 161, 0, 3, 37, 10, 0, 147, 5, 65, 0, 19, 6, 64, 0, 151, 32, 0, 0, 231, 128, 128, 164, 3, 69, 204, 4, 35, 36, 145, 1, 227, 96, 173, 248, 131, 37, 65, 0, 51, 6, 172, 0, 147, 214, 133, 1, 163, 7, 214, 0, 147, 214, 5, 1, 35, 7, 214, 0, 147, 214, 133, 0, 163, 6, 214, 0, 35, 6, 182, 0, 147, 9, 69, 0, 147, 138, 74, 0, 35, 6, 60, 5, 227, 146, 186, 251, 19, 133, 4, 4, 3, 36, 1, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 192, 136, 19, 117, 245, 15, 147, 5, 112, 0, 99, 28, 181, 0, 19, 133, 132, 4, 147, 5, 4, 0, 151, 240, 255, 255, 231, 128, 128, 112, 19, 117, 245, 15, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 23, 19, 0, 0, 103, 0, 3, 29, 23, 19, 0, 0, 103, 0, 131, 41, 23, 19, 0, 0, 103, 0, 67, 41, 23, 35, 0, 0, 103, 0, 67, 202, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 147, 132, 5, 0, 19, 4, 5, 0, 19, 133, 5, 3, 35, 44, 177, 2, 35, 46, 161, 2, 19, 5, 1, 0, 147, 5, 129, 3, 151, 0, 0, 0, 231, 128, 128, 39, 3, 165, 68, 3, 131, 165, 132, 3, 3, 166, 4, 3, 147, 54, 133, 0, 19, 183, 133, 0, 179, 246, 230, 0, 35, 40, 193, 2, 99, 138, 6, 34, 3, 38, 1, 0, 3, 71, 129, 0, 131, 38, 65, 0, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 70, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 0, 0, 99, 8, 6, 2, 111, 0, 192, 18, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 70, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 0, 0, 99, 154, 6, 18, 3, 38, 193, 0, 3, 71, 65, 1, 131, 38, 1, 1, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 54, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 16, 0, 99, 8, 6, 2, 111, 0, 128, 12, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 54, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 16, 0, 99, 152, 6, 12, 3, 38, 129, 1, 3, 71, 1, 2, 131, 38, 193, 1, 99, 8, 7, 2, 51, 70, 197, 0, 19, 54, 22, 0, 51, 183, 213, 0, 19, 71, 23, 0, 51, 118, 230, 0, 19, 135, 54, 0, 51, 183, 229, 0, 51, 118, 230, 0, 147, 4, 32, 0, 99, 8, 6, 2, 111, 0, 64, 6, 179, 198, 213, 0, 147, 182, 22, 0, 51, 55, 197, 0, 19, 71, 23, 0, 179, 246, 230, 0, 19, 7, 54, 0, 51, 55, 229, 0, 179, 246, 230, 0, 147, 4, 32, 0, 99, 150, 6, 6, 3, 38, 65, 2, 3, 71, 193, 2, 131, 38, 129, 2, 99, 10, 7, 2, 51, 69, 197, 0, 19, 53, 21, 0, 51, 182, 213, 0, 19, 70, 22, 0, 51, 117, 197, 0, 19, 134, 38, 0, 51, 182, 197, 0, 51, 117, 197, 0, 147, 4, 48, 0, 99, 10, 5, 10, 51, 133, 213, 64, 111, 0, 0, 3, 179, 197, 213, 0, 147, 181, 21, 0, 179, 54, 197, 0, 147, 198, 22, 0, 179, 245, 213, 0, 147, 6, 38, 0, 179, 54, 213, 0, 179, 245, 213, 0, 147, 4, 48, 0, 99, 130, 5, 8, 51, 5, 197, 64, 19, 117, 117, 0, 147, 5, 16, 0, 51, 149, 165, 0, 147, 5, 192, 0, 179, 133, 180, 2, 19, 6, 1, 0, 179, 5, 182, 0, 3, 134, 149, 0, 183, 86, 0, 7, 147, 134, 6, 239, 179, 134, 150, 0, 131, 198, 6, 0, 51, 101, 197, 0, 163, 132, 165, 0, 19, 117, 245, 15, 19, 9, 48, 0, 99, 4, 213, 0, 19, 9, 16, 0, 147, 5, 1, 0, 19, 6, 64, 3, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 192, 38, 35, 10, 36, 3, 163, 10, 148, 2, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 19, 1, 1, 5, 103, 128, 0, 0, 147, 5, 1, 0, 19, 6, 64, 3, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 128, 35, 35, 10, 4, 2, 111, 240, 31, 253, 55, 85, 0, 7, 19, 5, 5, 245, 183, 85, 0, 7, 19, 134, 5, 247, 147, 5, 224, 1, 151, 48, 0, 0, 231, 128, 192, 242, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 129, 0, 35, 36, 145, 0, 3, 175, 69, 0, 131, 175, 5, 0, 99, 138, 239, 7, 19, 136, 207, 0, 35, 160, 5, 1, 131, 166, 15, 0, 3, 167, 79, 0, 3, 198, 143, 0, 131, 199, 159, 0, 99, 14, 232, 5, 147, 136, 143, 1, 35, 160, 21, 1, 131, 162, 207, 0, 3, 163, 15, 1, 131, 195, 79, 1, 3, 200, 95, 1, 99, 136, 232, 9, 147, 132, 79, 2, 35, 160, 149, 0, 3, 174, 143, 1, 131, 174, 207, 1, 3, 196, 15, 2, 131, 200, 31, 2, 99, 140, 228, 7, 19, 143, 15, 3, 35, 160, 229, 1, 131, 165, 79, 2, 3, 175, 143, 2, 131, 196, 207, 2, 131, 207, 223, 2, 111, 0, 128, 0, 19, 6, 32, 0, 35, 32, 213, 0, 35, 34, 229, 0, 35, 4, 197, 0, 163, 4, 245, 0, 35, 38, 85, 0, 35, 40, 101, 0, 35, 10, 117, 0, 163, 10, 5, 1, 35, 44, 197, 1, 35, 46, 213, 1, 35, 0, 133, 2, 163, 0, 21, 3, 35, 34, 181, 2, 35, 36, 229, 3, 35, 6, 149, 2, 163, 6, 245, 3, 3, 36, 193, 0, 131, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 111, 240, 31, 251, 111, 240, 223, 250, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 4, 5, 0, 3, 41, 133, 0, 3, 37, 69, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 100, 197, 4, 3, 37, 4, 0, 19, 150, 36, 0, 147, 21, 41, 0, 51, 5, 181, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 192, 13, 179, 5, 153, 0, 19, 5, 112, 0, 35, 36, 180, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 12, 3, 41, 132, 0, 111, 240, 95, 250, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 4, 5, 0, 99, 0, 6, 4, 99, 192, 4, 4, 19, 9, 6, 0, 3, 165, 134, 0, 99, 0, 5, 4, 131, 165, 70, 0, 99, 140, 5, 2, 3, 165, 6, 0, 19, 6, 9, 0, 147, 134, 4, 0, 151, 0, 0, 0, 231, 128, 192, 184, 99, 28, 5, 2, 35, 34, 148, 0, 35, 36, 36, 1, 111, 0, 192, 0, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 128, 2, 99, 128, 4, 4, 19, 133, 4, 0, 147, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 180, 227, 8, 5, 252, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 5, 9, 0, 227, 26, 5, 252, 111, 240, 223, 249, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 100, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 100, 150, 0, 147, 4, 6, 0, 147, 5, 64, 0, 99, 228, 149, 0, 147, 4, 64, 0, 147, 213, 212, 1, 19, 182, 21, 0, 147, 149, 36, 0, 19, 22, 38, 0, 99, 0, 5, 2, 131, 38, 4, 0, 19, 21, 37, 0, 35, 44, 209, 0, 35, 46, 161, 0, 19, 5, 64, 0, 35, 32, 161, 2, 111, 0, 128, 0, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 151, 0, 0, 0, 231, 128, 64, 237, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 16, 0, 0, 231, 128, 192, 108, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 16, 0, 0, 231, 128, 128, 110, 115, 16, 0, 192, 3, 37, 5, 0, 23, 35, 0, 0, 103, 0, 131, 235, 3, 69, 5, 0, 19, 21, 37, 0, 55, 86, 0, 7, 19, 6, 6, 248, 51, 5, 197, 0, 3, 38, 5, 0, 19, 133, 5, 0, 103, 0, 6, 0, 183, 85, 0, 7, 147, 133, 181, 0, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 195, 171, 183, 85, 0, 7, 147, 133, 149, 255, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 131, 170, 183, 85, 0, 7, 147, 133, 85, 254, 19, 6, 64, 1, 23, 51, 0, 0, 103, 0, 67, 169, 183, 85, 0, 7, 147, 133, 53, 253, 19, 6, 32, 1, 23, 51, 0, 0, 103, 0, 3, 168, 183, 85, 0, 7, 147, 133, 181, 251, 19, 6, 128, 1, 23, 51, 0, 0, 103, 0, 195, 166, 183, 85, 0, 7, 147, 133, 245, 250, 19, 6, 192, 0, 23, 51, 0, 0, 103, 0, 131, 165, 183, 85, 0, 7, 147, 133, 197, 249, 19, 6, 48, 1, 23, 51, 0, 0, 103, 0, 67, 164, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 131, 36, 5, 0, 19, 132, 5, 0, 19, 133, 5, 0, 151, 48, 0, 0, 231, 128, 0, 163, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 67, 157, 19, 5, 4, 0, 151, 48, 0, 0, 231, 128, 0, 161, 99, 2, 5, 2, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 35, 0, 0, 103, 0, 67, 165, 19, 133, 4, 0, 147, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 23, 51, 0, 0, 103, 0, 131, 244, 3, 38, 69, 0, 131, 37, 69, 1, 147, 6, 16, 0, 99, 12, 214, 0, 99, 28, 6, 0, 99, 142, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 103, 128, 0, 0, 99, 142, 5, 0, 19, 5, 0, 0, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 5, 2, 147, 5, 0, 0, 103, 128, 0, 0, 131, 37, 5, 0, 3, 165, 5, 0, 131, 165, 69, 0, 103, 128, 0, 0, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 35, 32, 33, 3, 35, 46, 49, 1, 19, 4, 5, 0, 99, 130, 5, 4, 147, 4, 6, 0, 19, 137, 5, 0, 99, 14, 6, 6, 99, 202, 4, 10, 19, 165, 4, 0, 147, 73, 21, 0, 19, 133, 4, 0, 147, 133, 9, 0, 151, 0, 0, 0, 231, 128, 128, 133, 99, 16, 5, 6, 19, 133, 4, 0, 147, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 78, 115, 16, 0, 192, 3, 165, 70, 1, 131, 165, 6, 1, 35, 42, 161, 0, 35, 40, 177, 0, 3, 165, 198, 0, 131, 165, 134, 0, 3, 166, 70, 0, 131, 166, 6, 0, 35, 38, 161, 0, 35, 36, 177, 0, 35, 34, 193, 0, 35, 32, 209, 0, 147, 5, 1, 0, 19, 5, 4, 0, 151, 16, 0, 0, 231, 128, 192, 76, 111, 0, 64, 2, 19, 5, 16, 0, 35, 32, 164, 0, 35, 34, 148, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 16, 0, 0, 231, 128, 0, 203, 35, 36, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 3, 41, 1, 2, 131, 41, 193, 1, 19, 1, 1, 3, 103, 128, 0, 0, 151, 16, 0, 0, 231, 128, 128, 65, 115, 16, 0, 192, 19, 1, 1, 252, 35, 46, 17, 2, 35, 34, 161, 0, 19, 5, 65, 0, 35, 40, 161, 2, 55, 37, 0, 7, 19, 5, 197, 206, 35, 42, 161, 2, 55, 85, 0, 7, 19, 5, 5, 2, 35, 44, 161, 0, 19, 5, 16, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 151, 0, 0, 0, 231, 128, 192, 231, 19, 6, 5, 0, 19, 135, 5, 0, 19, 5, 129, 0, 147, 6, 129, 1, 147, 5, 6, 0, 19, 6, 7, 0, 151, 0, 0, 0, 231, 128, 192, 234, 3, 37, 129, 0, 131, 37, 1, 1, 151, 16, 0, 0, 231, 128, 0, 1, 115, 16, 0, 192, 55, 85, 0, 7, 19, 5, 133, 2, 147, 5, 144, 1, 151, 16, 0, 0, 231, 128, 128, 255, 115, 16, 0, 192, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 35, 38, 113, 3, 35, 36, 129, 3, 35, 34, 145, 3, 19, 137, 7, 0, 19, 11, 7, 0, 147, 138, 6, 0, 147, 11, 6, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 6, 7, 4, 19, 133, 250, 3, 19, 85, 37, 0, 147, 124, 5, 255, 99, 136, 12, 4, 19, 213, 220, 1, 99, 22, 5, 30, 147, 153, 44, 0, 99, 194, 9, 30, 19, 53, 21, 0, 19, 28, 37, 0, 99, 128, 9, 4, 19, 133, 9, 0, 147, 5, 12, 0, 151, 240, 255, 255, 231, 128, 0, 105, 19, 10, 5, 0, 99, 24, 10, 2, 111, 0, 128, 30, 19, 133, 74, 0, 19, 85, 37, 0, 19, 5, 21, 1, 147, 124, 5, 255, 227, 156, 12, 250, 147, 9, 0, 0, 19, 10, 64, 0, 111, 0, 192, 0, 19, 10, 12, 0, 99, 0, 10, 28, 99, 238, 89, 25, 19, 5, 10, 0, 147, 133, 11, 0, 19, 134, 10, 0, 151, 16, 0, 0, 231, 128, 64, 177, 51, 5, 90, 1, 99, 8, 11, 8, 51, 134, 89, 65, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 64, 160, 99, 148, 12, 14, 99, 130, 132, 4, 3, 165, 4, 0, 131, 165, 68, 0, 3, 166, 132, 0, 131, 166, 196, 0, 3, 167, 196, 1, 131, 167, 132, 1, 3, 168, 68, 1, 131, 168, 4, 1, 35, 46, 228, 0, 35, 44, 244, 0, 35, 42, 4, 1, 35, 40, 20, 1, 35, 38, 212, 0, 35, 36, 196, 0, 35, 34, 180, 0, 35, 32, 164, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 103, 128, 0, 0, 147, 5, 0, 8, 35, 0, 181, 0, 19, 5, 21, 0, 147, 197, 250, 255, 51, 134, 185, 0, 147, 5, 0, 0, 151, 16, 0, 0, 231, 128, 128, 150, 19, 133, 252, 255, 99, 132, 12, 10, 19, 21, 37, 0, 179, 5, 170, 0, 3, 165, 5, 0, 99, 24, 5, 14, 19, 85, 137, 0, 55, 6, 1, 0, 19, 6, 6, 240, 51, 117, 197, 0, 19, 86, 137, 1, 51, 101, 197, 0, 19, 22, 137, 0, 183, 6, 255, 0, 51, 118, 214, 0, 147, 22, 137, 1, 51, 230, 198, 0, 51, 101, 166, 0, 35, 160, 165, 0, 19, 215, 76, 0, 147, 6, 10, 2, 147, 2, 48, 0, 19, 5, 4, 0, 147, 133, 4, 0, 19, 6, 10, 0, 115, 0, 0, 0, 19, 6, 64, 0, 19, 5, 10, 0, 147, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 131, 43, 193, 2, 3, 44, 129, 2, 131, 44, 65, 2, 19, 1, 1, 5, 23, 243, 255, 255, 103, 0, 195, 78, 183, 85, 0, 7, 19, 134, 69, 14, 147, 5, 0, 0, 151, 32, 0, 0, 231, 128, 192, 111, 115, 16, 0, 192, 151, 16, 0, 0, 231, 128, 64, 18, 115, 16, 0, 192, 55, 85, 0, 7, 19, 5, 21, 10, 183, 85, 0, 7, 19, 134, 69, 13, 147, 5, 48, 3, 151, 32, 0, 0, 231, 128, 192, 104, 115, 16, 0, 192, 19, 133, 9, 0, 147, 5, 12, 0, 151, 16, 0, 0, 231, 128, 64, 19, 115, 16, 0, 192, 35, 36, 1, 0, 55, 85, 0, 7, 19, 6, 133, 238, 55, 85, 0, 7, 19, 7, 69, 15, 147, 6, 129, 0, 19, 5, 0, 0, 151, 0, 0, 0, 231, 128, 128, 17, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 67, 19, 4, 5, 0, 147, 5, 0, 1, 55, 85, 0, 7, 99, 246, 180, 0, 147, 5, 197, 48, 111, 0, 64, 2, 19, 215, 68, 0, 147, 6, 9, 2, 147, 5, 197, 48, 147, 2, 48, 0, 19, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 147, 5, 4, 0, 147, 151, 84, 0, 19, 245, 4, 255, 19, 21, 37, 0, 51, 6, 169, 0, 19, 149, 196, 1, 147, 86, 165, 1, 19, 5, 4, 0, 19, 7, 0, 0, 151, 0, 0, 0, 231, 128, 128, 204, 19, 5, 4, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 35, 32, 33, 1, 19, 4, 6, 0, 147, 132, 5, 0, 19, 9, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 192, 56, 147, 134, 4, 2, 147, 2, 48, 0, 147, 5, 9, 0, 19, 134, 4, 0, 19, 7, 4, 0, 115, 0, 0, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 3, 41, 1, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 1, 1, 254, 19, 8, 7, 0, 3, 167, 70, 1, 131, 167, 6, 1, 35, 32, 177, 0, 35, 34, 193, 0, 35, 46, 225, 0, 35, 44, 241, 0, 131, 165, 198, 0, 3, 166, 134, 0, 3, 167, 70, 0, 131, 166, 6, 0, 35, 42, 177, 0, 35, 40, 193, 0, 35, 38, 225, 0, 35, 36, 209, 0, 183, 85, 0, 7, 19, 134, 69, 16, 147, 5, 1, 0, 147, 6, 65, 0, 147, 7, 129, 0, 19, 7, 6, 0, 151, 32, 0, 0, 231, 128, 128, 88, 115, 16, 0, 192, 147, 135, 5, 0, 99, 154, 213, 0, 147, 5, 6, 0, 19, 134, 7, 0, 23, 3, 0, 0, 103, 0, 195, 122, 19, 133, 7, 0, 147, 133, 6, 0, 19, 6, 7, 0, 151, 16, 0, 0, 231, 128, 192, 75, 115, 16, 0, 192, 19, 1, 1, 239, 35, 38, 17, 16, 35, 36, 129, 16, 35, 34, 145, 16, 35, 32, 33, 17, 35, 46, 49, 15, 35, 44, 65, 15, 35, 42, 81, 15, 55, 5, 8, 0, 35, 34, 5, 0, 19, 5, 69, 0, 147, 5, 69, 0, 19, 5, 1, 0, 19, 6, 192, 4, 151, 0, 0, 0, 231, 128, 64, 117, 147, 4, 129, 0, 35, 38, 1, 6, 35, 36, 1, 6, 35, 34, 1, 6, 35, 32, 1, 6, 35, 46, 1, 4, 3, 37, 65, 0, 3, 68, 129, 4, 35, 44, 1, 4, 35, 42, 1, 4, 19, 21, 149, 0, 147, 21, 52, 0, 51, 101, 181, 0, 147, 85, 133, 0, 55, 6, 1, 0, 19, 6, 6, 240, 179, 245, 197, 0, 19, 86, 133, 1, 51, 233, 197, 0, 19, 21, 133, 0, 183, 5, 255, 0, 51, 117, 181, 0, 147, 21, 180, 1, 179, 233, 165, 0, 51, 133, 132, 0, 147, 5, 0, 8, 35, 0, 181, 0, 147, 5, 240, 3, 35, 40, 1, 4, 99, 12, 180, 0, 19, 70, 244, 3, 19, 5, 21, 0, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 0, 93, 19, 117, 132, 3, 147, 5, 128, 3, 179, 234, 41, 1, 99, 24, 181, 18, 3, 37, 1, 0, 183, 85, 0, 7, 19, 132, 197, 48, 19, 9, 4, 0, 99, 4, 5, 0, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 170, 147, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 192, 169, 19, 138, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 0, 26, 147, 4, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 0, 168, 147, 9, 5, 0, 19, 5, 10, 0, 151, 16, 0, 0, 231, 128, 0, 167, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 9, 0, 19, 134, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 19, 5, 1, 7, 19, 6, 128, 3, 147, 5, 0, 0, 151, 0, 0, 0, 231, 128, 64, 82, 35, 38, 81, 11, 35, 36, 1, 10, 99, 132, 4, 0, 19, 132, 4, 0, 99, 24, 0, 18, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 161, 19, 9, 5, 0, 19, 5, 1, 7, 151, 16, 0, 0, 231, 128, 64, 160, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 16, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 158, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 157, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 140, 4, 8, 111, 0, 0, 20, 35, 32, 1, 4, 35, 34, 81, 5, 3, 36, 1, 0, 99, 22, 4, 0, 55, 85, 0, 7, 19, 4, 197, 48, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 152, 19, 9, 5, 0, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 0, 151, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 64, 7, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 64, 149, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 64, 148, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 99, 152, 4, 10, 55, 85, 0, 7, 19, 5, 69, 24, 183, 85, 0, 7, 19, 134, 5, 27, 147, 5, 176, 2, 151, 32, 0, 0, 231, 128, 128, 31, 115, 16, 0, 192, 19, 5, 1, 11, 147, 5, 1, 7, 19, 6, 0, 4, 151, 0, 0, 0, 231, 128, 128, 76, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 141, 19, 9, 5, 0, 19, 5, 1, 11, 151, 16, 0, 0, 231, 128, 64, 140, 147, 137, 5, 0, 19, 5, 0, 4, 147, 5, 64, 0, 151, 240, 255, 255, 231, 128, 128, 252, 147, 4, 5, 0, 19, 5, 9, 0, 151, 16, 0, 0, 231, 128, 128, 138, 19, 9, 5, 0, 19, 133, 9, 0, 151, 16, 0, 0, 231, 128, 128, 137, 147, 6, 5, 0, 147, 2, 48, 0, 19, 7, 16, 0, 19, 133, 4, 0, 147, 5, 4, 0, 19, 6, 9, 0, 115, 0, 0, 0, 35, 32, 145, 0, 35, 4, 1, 4, 227, 140, 4, 244, 19, 133, 4, 0, 151, 16, 0, 0, 231, 128, 128, 134, 19, 6, 5, 0, 147, 134, 5, 0, 55, 85, 0, 7, 19, 7, 69, 23, 19, 5, 1, 5, 147, 5, 0, 2, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 1, 5, 3, 38, 65, 5, 131, 38, 129, 5, 3, 39, 193, 5, 131, 39, 1, 6, 3, 40, 65, 6, 131, 40, 129, 6, 3, 35, 193, 6, 147, 2, 16, 0, 19, 5, 0, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 16, 0, 147, 5, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 32, 0, 147, 133, 6, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 48, 0, 147, 5, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 64, 0, 147, 133, 7, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 80, 0, 147, 5, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 96, 0, 147, 133, 8, 0, 115, 0, 0, 0, 147, 2, 16, 0, 19, 5, 112, 0, 147, 5, 3, 0, 115, 0, 0, 0, 147, 2, 0, 0, 19, 5, 0, 0, 115, 0, 0, 0, 55, 85, 0, 7, 19, 5, 117, 34, 183, 85, 0, 7, 19, 134, 5, 37, 147, 5, 128, 2, 151, 32, 0, 0, 231, 128, 128, 7, 115, 16, 0, 192, 19, 5, 48, 0, 103, 128, 0, 0, 19, 5, 0, 0, 103, 128, 0, 0, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 3, 37, 5, 0, 19, 4, 6, 0, 151, 0, 0, 0, 231, 128, 64, 122, 147, 5, 5, 0, 19, 22, 36, 0, 19, 5, 112, 0, 99, 132, 197, 0, 19, 5, 64, 0, 131, 32, 193, 0, 3, 36, 129, 0, 19, 1, 1, 1, 103, 128, 0, 0, 103, 128, 0, 0, 19, 6, 64, 0, 99, 100, 182, 0, 147, 5, 64, 0, 19, 214, 21, 0, 183, 86, 85, 85, 147, 134, 86, 85, 51, 118, 214, 0, 51, 134, 197, 64, 183, 54, 51, 51, 147, 134, 54, 51, 51, 119, 214, 0, 19, 86, 38, 0, 51, 118, 214, 0, 51, 6, 199, 0, 147, 86, 70, 0, 51, 6, 214, 0, 183, 22, 15, 15, 147, 134, 246, 240, 51, 118, 214, 0, 183, 6, 1, 1, 147, 134, 22, 16, 51, 6, 214, 2, 19, 86, 134, 1, 147, 6, 246, 255, 179, 54, 208, 0, 55, 7, 0, 128, 51, 7, 183, 64, 51, 55, 167, 0, 179, 230, 230, 0, 99, 150, 6, 2, 147, 6, 16, 0, 99, 4, 214, 0, 147, 5, 0, 0, 51, 5, 181, 0, 19, 5, 245, 255, 179, 5, 176, 64, 51, 117, 181, 0, 19, 85, 37, 0, 23, 3, 0, 0, 103, 0, 3, 117, 19, 1, 1, 255, 55, 85, 0, 7, 19, 5, 5, 38, 183, 85, 0, 7, 147, 134, 133, 40, 183, 85, 0, 7, 19, 135, 197, 47, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 0, 23, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 19, 7, 64, 0, 19, 137, 6, 0, 19, 132, 5, 0, 147, 4, 5, 0, 99, 100, 199, 0, 19, 6, 64, 0, 19, 85, 22, 0, 183, 85, 85, 85, 147, 133, 85, 85, 51, 117, 181, 0, 51, 5, 166, 64, 183, 53, 51, 51, 147, 133, 53, 51, 179, 118, 181, 0, 19, 85, 37, 0, 51, 117, 181, 0, 51, 133, 166, 0, 147, 85, 69, 0, 51, 5, 181, 0, 183, 21, 15, 15, 147, 133, 245, 240, 51, 117, 181, 0, 183, 5, 1, 1, 147, 133, 21, 16, 51, 5, 181, 2, 19, 85, 133, 1, 147, 5, 245, 255, 179, 53, 176, 0, 183, 6, 0, 128, 179, 134, 198, 64, 179, 182, 38, 1, 179, 229, 213, 0, 99, 152, 5, 6, 147, 5, 16, 0, 99, 4, 181, 0, 19, 6, 0, 0, 51, 5, 201, 0, 19, 5, 245, 255, 179, 5, 192, 64, 51, 117, 181, 0, 19, 85, 37, 0, 151, 0, 0, 0, 231, 128, 128, 101, 147, 9, 5, 0, 99, 0, 5, 2, 99, 100, 36, 1, 19, 4, 9, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 151, 0, 0, 0, 231, 128, 192, 20, 19, 133, 9, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 5, 38, 183, 85, 0, 7, 147, 134, 133, 40, 183, 85, 0, 7, 19, 135, 197, 47, 147, 5, 112, 2, 19, 6, 129, 0, 151, 32, 0, 0, 231, 128, 128, 3, 115, 16, 0, 192, 99, 10, 6, 14, 35, 0, 181, 0, 179, 6, 166, 0, 19, 7, 48, 0, 163, 143, 182, 254, 99, 96, 230, 14, 163, 0, 181, 0, 35, 1, 181, 0, 35, 143, 182, 254, 19, 7, 112, 0, 163, 142, 182, 254, 99, 100, 230, 12, 163, 1, 181, 0, 147, 7, 144, 0, 35, 142, 182, 254, 99, 108, 246, 10, 179, 6, 160, 64, 19, 247, 54, 0, 179, 6, 229, 0, 51, 6, 230, 64, 19, 118, 198, 255, 147, 245, 245, 15, 55, 7, 1, 1, 19, 7, 23, 16, 179, 133, 229, 2, 35, 160, 182, 0, 51, 135, 198, 0, 35, 46, 183, 254, 99, 98, 246, 8, 35, 162, 182, 0, 35, 164, 182, 0, 35, 42, 183, 254, 147, 7, 144, 1, 35, 44, 183, 254, 99, 102, 246, 6, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 34, 183, 254, 35, 36, 183, 254, 35, 38, 183, 254, 147, 247, 70, 0, 147, 231, 135, 1, 51, 6, 246, 64, 19, 8, 0, 2, 35, 40, 183, 254, 99, 108, 6, 3, 179, 134, 246, 0, 19, 7, 240, 1, 35, 160, 182, 0, 35, 162, 182, 0, 35, 164, 182, 0, 35, 166, 182, 0, 35, 168, 182, 0, 35, 170, 182, 0, 35, 172, 182, 0, 35, 174, 182, 0, 19, 6, 6, 254, 147, 134, 6, 2, 227, 108, 199, 252, 103, 128, 0, 0, 147, 246, 53, 0, 147, 182, 22, 0, 19, 55, 22, 0, 179, 230, 230, 0, 99, 156, 6, 14, 147, 135, 21, 0, 19, 8, 5, 0, 131, 136, 5, 0, 19, 135, 21, 0, 147, 6, 24, 0, 35, 0, 24, 1, 19, 6, 246, 255, 147, 245, 55, 0, 179, 53, 176, 0, 51, 56, 192, 0, 179, 248, 5, 1, 147, 135, 23, 0, 147, 5, 7, 0, 19, 136, 6, 0, 227, 152, 8, 252, 147, 245, 54, 0, 99, 130, 5, 12, 147, 7, 0, 2, 99, 110, 246, 34, 147, 7, 48, 0, 99, 134, 245, 18, 147, 7, 32, 0, 99, 130, 245, 26, 147, 7, 16, 0, 99, 146, 245, 34, 131, 39, 7, 0, 35, 128, 246, 0, 147, 213, 135, 0, 163, 128, 182, 0, 19, 216, 7, 1, 147, 133, 54, 0, 35, 129, 6, 1, 19, 6, 214, 255, 147, 6, 7, 1, 19, 7, 0, 1, 3, 168, 70, 255, 147, 215, 135, 1, 147, 24, 136, 0, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 1, 19, 152, 130, 0, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 1, 147, 146, 136, 0, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 1, 147, 152, 135, 0, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 54, 255, 111, 0, 0, 25, 147, 6, 5, 0, 19, 135, 5, 0, 147, 245, 54, 0, 227, 146, 5, 244, 147, 5, 0, 1, 99, 108, 182, 2, 147, 5, 240, 0, 131, 39, 7, 0, 3, 40, 71, 0, 131, 40, 135, 0, 131, 34, 199, 0, 35, 160, 246, 0, 35, 162, 6, 1, 35, 164, 22, 1, 35, 166, 86, 0, 19, 7, 7, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 234, 197, 252, 147, 117, 134, 0, 99, 142, 5, 0, 131, 37, 7, 0, 131, 39, 71, 0, 35, 160, 182, 0, 35, 162, 246, 0, 147, 134, 134, 0, 19, 7, 135, 0, 147, 117, 70, 0, 99, 130, 5, 22, 131, 37, 7, 0, 35, 160, 182, 0, 147, 134, 70, 0, 19, 7, 71, 0, 111, 0, 0, 21, 131, 39, 7, 0, 147, 133, 22, 0, 35, 128, 246, 0, 19, 6, 246, 255, 147, 6, 7, 1, 19, 7, 32, 1, 3, 168, 70, 255, 147, 215, 135, 0, 147, 24, 136, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 136, 0, 19, 152, 130, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 130, 0, 147, 146, 136, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 136, 0, 147, 152, 135, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 22, 255, 111, 0, 128, 8, 131, 39, 7, 0, 35, 128, 246, 0, 19, 216, 135, 0, 147, 133, 38, 0, 163, 128, 6, 1, 19, 6, 230, 255, 147, 6, 7, 1, 19, 7, 16, 1, 3, 168, 70, 255, 147, 215, 7, 1, 147, 24, 8, 1, 131, 162, 134, 255, 179, 231, 248, 0, 35, 160, 245, 0, 147, 87, 8, 1, 19, 152, 2, 1, 131, 168, 198, 255, 179, 103, 248, 0, 35, 162, 245, 0, 19, 216, 2, 1, 147, 146, 8, 1, 131, 167, 6, 0, 51, 232, 2, 1, 35, 164, 5, 1, 19, 216, 8, 1, 147, 152, 7, 1, 51, 232, 8, 1, 35, 166, 5, 1, 147, 133, 5, 1, 19, 6, 6, 255, 147, 134, 6, 1, 227, 98, 199, 250, 19, 135, 38, 255, 147, 134, 5, 0, 147, 117, 6, 1, 99, 146, 5, 8, 147, 117, 134, 0, 99, 152, 5, 16, 147, 117, 70, 0, 99, 136, 5, 2, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 19, 7, 71, 0, 147, 135, 70, 0, 163, 129, 182, 0, 147, 134, 7, 0, 147, 117, 38, 0, 99, 152, 5, 0, 147, 117, 22, 0, 99, 150, 5, 2, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 35, 128, 182, 0, 19, 7, 39, 0, 147, 133, 38, 0, 163, 128, 246, 0, 147, 134, 5, 0, 147, 117, 22, 0, 227, 142, 5, 252, 131, 5, 7, 0, 35, 128, 182, 0, 103, 128, 0, 0, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 131, 5, 135, 0, 3, 8, 151, 0, 163, 131, 246, 0, 131, 7, 167, 0, 35, 132, 182, 0, 163, 132, 6, 1, 131, 5, 183, 0, 35, 133, 246, 0, 131, 7, 199, 0, 3, 8, 215, 0, 163, 133, 182, 0, 131, 5, 231, 0, 35, 134, 246, 0, 163, 134, 6, 1, 131, 7, 247, 0, 35, 135, 182, 0, 19, 7, 7, 1, 147, 133, 6, 1, 163, 135, 246, 0, 147, 134, 5, 0, 147, 117, 134, 0, 227, 140, 5, 238, 131, 5, 7, 0, 131, 7, 23, 0, 3, 8, 39, 0, 35, 128, 182, 0, 163, 128, 246, 0, 131, 5, 55, 0, 35, 129, 6, 1, 131, 7, 71, 0, 3, 8, 87, 0, 163, 129, 182, 0, 131, 5, 103, 0, 35, 130, 246, 0, 163, 130, 6, 1, 131, 7, 119, 0, 35, 131, 182, 0, 19, 7, 135, 0, 147, 133, 134, 0, 163, 131, 246, 0, 147, 134, 5, 0, 147, 117, 70, 0, 227, 150, 5, 234, 111, 240, 95, 237, 151, 0, 0, 0, 231, 128, 128, 2, 115, 16, 0, 192, 147, 5, 5, 2, 103, 128, 0, 0, 103, 128, 0, 0, 147, 5, 0, 2, 103, 128, 0, 0, 147, 5, 128, 0, 103, 128, 0, 0, 55, 86, 0, 7, 19, 6, 182, 59, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 55, 85, 0, 7, 19, 5, 53, 57, 183, 85, 0, 7, 19, 134, 133, 62, 147, 5, 128, 2, 151, 32, 0, 0, 231, 128, 0, 139, 115, 16, 0, 192, 147, 7, 6, 0, 19, 23, 38, 0, 55, 86, 0, 7, 19, 6, 134, 63, 147, 2, 32, 0, 147, 6, 5, 0, 19, 133, 5, 0, 147, 133, 7, 0, 115, 0, 0, 0, 99, 100, 167, 0, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 53, 66, 183, 85, 0, 7, 19, 134, 133, 69, 147, 5, 64, 3, 151, 32, 0, 0, 231, 128, 64, 134, 115, 16, 0, 192, 147, 7, 6, 0, 55, 86, 0, 7, 19, 6, 134, 70, 147, 2, 32, 0, 147, 6, 5, 0, 19, 135, 5, 0, 19, 5, 0, 0, 147, 5, 0, 0, 115, 0, 0, 0, 103, 128, 0, 0, 183, 5, 8, 0, 131, 166, 5, 0, 51, 134, 166, 64, 99, 236, 198, 0, 19, 149, 38, 0, 183, 6, 0, 8, 51, 133, 166, 64, 35, 160, 197, 0, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 69, 73, 183, 85, 0, 7, 19, 134, 69, 74, 147, 5, 224, 0, 151, 16, 0, 0, 231, 128, 64, 193, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 51, 134, 197, 0, 99, 106, 182, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 120, 150, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 4, 6, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 128, 19, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 192, 27, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 128, 29, 115, 16, 0, 192, 19, 1, 1, 253, 35, 38, 17, 2, 35, 36, 129, 2, 35, 34, 145, 2, 147, 133, 21, 0, 99, 138, 5, 8, 19, 4, 5, 0, 3, 37, 69, 0, 147, 20, 21, 0, 99, 248, 149, 2, 147, 5, 128, 0, 99, 250, 149, 2, 147, 197, 244, 255, 19, 214, 245, 1, 99, 12, 5, 2, 131, 37, 4, 0, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 16, 0, 35, 32, 161, 2, 111, 0, 64, 2, 147, 132, 5, 0, 147, 5, 128, 0, 227, 234, 149, 252, 147, 4, 128, 0, 147, 197, 244, 255, 19, 214, 245, 1, 227, 24, 5, 252, 35, 32, 1, 2, 19, 5, 129, 0, 147, 6, 129, 1, 147, 133, 4, 0, 151, 0, 0, 0, 231, 128, 192, 5, 131, 37, 129, 0, 3, 37, 193, 0, 99, 130, 5, 2, 131, 37, 1, 1, 55, 6, 0, 128, 19, 6, 22, 0, 99, 142, 197, 0, 99, 150, 5, 2, 151, 0, 0, 0, 231, 128, 0, 14, 115, 16, 0, 192, 35, 32, 164, 0, 35, 34, 148, 0, 131, 32, 193, 2, 3, 36, 129, 2, 131, 36, 65, 2, 19, 1, 1, 3, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 15, 115, 16, 0, 192, 19, 1, 1, 255, 35, 38, 17, 0, 35, 36, 129, 0, 35, 34, 145, 0, 147, 132, 5, 0, 19, 4, 5, 0, 99, 10, 6, 2, 99, 202, 4, 2, 3, 165, 134, 0, 99, 12, 5, 2, 131, 165, 70, 0, 99, 136, 5, 2, 3, 165, 6, 0, 19, 6, 16, 0, 147, 134, 4, 0, 151, 224, 255, 255, 231, 128, 0, 66, 99, 18, 5, 4, 111, 0, 192, 2, 35, 34, 148, 0, 35, 36, 4, 0, 147, 5, 16, 0, 111, 0, 192, 3, 99, 132, 4, 2, 147, 5, 16, 0, 19, 133, 4, 0, 151, 224, 255, 255, 231, 128, 64, 62, 99, 28, 5, 0, 35, 34, 148, 0, 147, 5, 16, 0, 35, 36, 180, 0, 111, 0, 64, 1, 19, 5, 16, 0, 147, 5, 0, 0, 35, 34, 164, 0, 35, 36, 148, 0, 35, 32, 180, 0, 131, 32, 193, 0, 3, 36, 129, 0, 131, 36, 65, 0, 19, 1, 1, 1, 103, 128, 0, 0, 19, 1, 1, 254, 55, 85, 0, 7, 19, 5, 69, 88, 35, 36, 161, 0, 19, 5, 16, 0, 35, 38, 161, 0, 35, 40, 1, 0, 55, 85, 0, 7, 19, 5, 69, 75, 35, 44, 161, 0, 35, 46, 1, 0, 55, 85, 0, 7, 147, 5, 197, 88, 19, 5, 129, 0, 151, 16, 0, 0, 231, 128, 64, 81, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 151, 224, 255, 255, 231, 128, 128, 54, 115, 16, 0, 192, 151, 240, 255, 255, 231, 128, 192, 193, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 19, 132, 5, 0, 131, 165, 69, 0, 147, 4, 5, 0, 99, 134, 5, 4, 3, 37, 4, 0, 19, 9, 0, 0, 19, 6, 69, 0, 147, 150, 53, 0, 3, 39, 6, 0, 51, 9, 39, 1, 147, 134, 134, 255, 19, 6, 134, 0, 227, 152, 6, 254, 3, 38, 68, 1, 99, 0, 6, 4, 99, 132, 5, 2, 3, 37, 69, 0, 19, 53, 21, 0, 147, 53, 9, 1, 51, 117, 181, 0, 99, 10, 5, 0, 111, 0, 192, 1, 3, 37, 68, 1, 19, 9, 0, 0, 99, 4, 5, 4, 19, 5, 9, 0, 51, 9, 41, 1, 99, 118, 169, 0, 19, 9, 0, 0, 111, 0, 64, 3, 99, 8, 9, 2, 99, 72, 9, 12, 147, 5, 16, 0, 19, 5, 9, 0, 151, 224, 255, 255, 231, 128, 128, 41, 99, 30, 5, 0, 147, 5, 16, 0, 19, 5, 9, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 5, 16, 0, 35, 160, 164, 0, 35, 162, 36, 1, 3, 37, 68, 1, 131, 37, 4, 1, 35, 164, 4, 0, 35, 38, 145, 0, 35, 34, 161, 2, 35, 32, 177, 2, 3, 37, 196, 0, 131, 37, 132, 0, 3, 38, 68, 0, 131, 38, 4, 0, 35, 46, 161, 0, 35, 44, 177, 0, 35, 42, 193, 0, 35, 40, 209, 0, 55, 85, 0, 7, 147, 5, 197, 106, 19, 5, 193, 0, 19, 6, 1, 1, 151, 16, 0, 0, 231, 128, 0, 151, 99, 30, 5, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 19, 1, 1, 4, 103, 128, 0, 0, 55, 85, 0, 7, 19, 5, 197, 89, 183, 85, 0, 7, 147, 134, 5, 93, 183, 85, 0, 7, 19, 135, 197, 105, 147, 5, 48, 3, 19, 6, 129, 2, 151, 16, 0, 0, 231, 128, 64, 94, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 64, 227, 115, 16, 0, 192, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 3, 36, 5, 0, 19, 5, 0, 8, 147, 132, 5, 0, 99, 252, 165, 2, 131, 37, 132, 0, 3, 37, 68, 0, 99, 154, 165, 0, 19, 5, 4, 0, 151, 0, 0, 0, 231, 128, 128, 198, 131, 37, 132, 0, 3, 37, 4, 0, 51, 5, 181, 0, 35, 0, 149, 0, 3, 37, 132, 0, 19, 5, 21, 0, 111, 0, 64, 13, 19, 213, 180, 0, 35, 38, 1, 0, 99, 18, 5, 2, 19, 213, 100, 0, 19, 101, 5, 12, 35, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 6, 161, 0, 147, 4, 32, 0, 111, 0, 192, 7, 19, 213, 4, 1, 99, 26, 5, 2, 19, 213, 196, 0, 19, 101, 5, 14, 35, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 35, 7, 161, 0, 147, 4, 48, 0, 111, 0, 64, 4, 19, 149, 180, 0, 19, 85, 213, 1, 19, 101, 5, 15, 35, 6, 161, 0, 19, 149, 228, 0, 19, 85, 165, 1, 19, 101, 5, 8, 163, 6, 161, 0, 19, 149, 68, 1, 19, 85, 165, 1, 19, 101, 5, 8, 35, 7, 161, 0, 19, 245, 244, 3, 19, 101, 5, 8, 163, 7, 161, 0, 147, 4, 64, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 99, 96, 149, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 5, 193, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 128, 87, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 192, 166, 3, 41, 132, 0, 111, 240, 223, 250, 19, 1, 1, 253, 35, 38, 17, 2, 3, 37, 5, 0, 3, 166, 69, 1, 131, 166, 5, 1, 35, 38, 161, 0, 35, 34, 193, 2, 35, 32, 209, 2, 3, 165, 197, 0, 3, 166, 133, 0, 131, 166, 69, 0, 131, 165, 5, 0, 35, 46, 161, 0, 35, 44, 193, 0, 35, 42, 209, 0, 35, 40, 177, 0, 55, 85, 0, 7, 147, 5, 197, 106, 19, 5, 193, 0, 19, 6, 1, 1, 151, 0, 0, 0, 231, 128, 192, 117, 131, 32, 193, 2, 19, 1, 1, 3, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 3, 36, 5, 0, 3, 41, 132, 0, 3, 37, 68, 0, 51, 5, 37, 65, 147, 4, 6, 0, 147, 137, 5, 0, 99, 98, 197, 4, 3, 37, 4, 0, 51, 5, 37, 1, 147, 133, 9, 0, 19, 134, 4, 0, 151, 240, 255, 255, 231, 128, 128, 72, 51, 5, 153, 0, 35, 36, 164, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 5, 4, 0, 147, 5, 9, 0, 19, 134, 4, 0, 151, 0, 0, 0, 231, 128, 128, 151, 3, 41, 132, 0, 111, 240, 159, 250, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 5, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 85, 0, 7, 19, 6, 69, 121, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 16, 0, 0, 231, 128, 128, 137, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 85, 0, 7, 19, 134, 69, 120, 147, 5, 0, 8, 151, 0, 0, 0, 231, 128, 0, 84, 115, 16, 0, 192, 19, 1, 1, 247, 35, 38, 17, 8, 19, 136, 5, 0, 147, 5, 0, 0, 131, 40, 5, 0, 19, 5, 193, 0, 19, 6, 160, 0, 147, 6, 240, 0, 111, 0, 192, 1, 51, 3, 181, 0, 147, 88, 71, 0, 179, 135, 242, 0, 163, 15, 243, 6, 147, 133, 245, 255, 99, 254, 230, 0, 19, 135, 8, 0, 147, 247, 248, 0, 147, 2, 0, 3, 227, 238, 199, 252, 147, 2, 112, 3, 111, 240, 95, 253, 19, 133, 5, 8, 19, 6, 16, 8, 99, 126, 197, 2, 19, 5, 193, 0, 51, 5, 181, 0, 19, 7, 5, 8, 179, 7, 176, 64, 55, 85, 0, 7, 19, 6, 69, 121, 147, 5, 16, 0, 147, 6, 32, 0, 19, 5, 8, 0, 151, 0, 0, 0, 231, 128, 128, 126, 131, 32, 193, 8, 19, 1, 1, 9, 103, 128, 0, 0, 183, 85, 0, 7, 19, 134, 69, 120, 147, 5, 0, 8, 151, 0, 0, 0, 231, 128, 0, 73, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 85, 0, 7, 19, 5, 197, 215, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 101, 0, 7, 19, 5, 5, 138, 35, 36, 161, 0, 19, 5, 48, 0, 35, 38, 161, 0, 35, 40, 1, 0, 19, 5, 1, 2, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 192, 245, 115, 16, 0, 192, 19, 6, 5, 0, 19, 5, 53, 0, 19, 119, 197, 255, 179, 6, 199, 64, 99, 238, 213, 0, 51, 133, 213, 64, 147, 55, 69, 0, 19, 184, 86, 0, 19, 72, 24, 0, 179, 103, 248, 0, 99, 134, 7, 2, 19, 5, 0, 0, 99, 128, 5, 2, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 103, 128, 0, 0, 147, 117, 53, 0, 147, 7, 0, 0, 99, 4, 199, 2, 51, 7, 230, 64, 19, 8, 6, 0, 131, 8, 8, 0, 19, 8, 24, 0, 147, 168, 8, 252, 147, 200, 24, 0, 19, 7, 23, 0, 179, 135, 23, 1, 227, 20, 7, 254, 179, 6, 214, 0, 19, 8, 0, 0, 99, 132, 5, 2, 19, 118, 197, 255, 51, 134, 198, 0, 3, 7, 6, 0, 19, 6, 22, 0, 19, 39, 7, 252, 19, 71, 23, 0, 147, 133, 245, 255, 51, 8, 232, 0, 227, 148, 5, 254, 147, 82, 37, 0, 55, 5, 1, 1, 19, 7, 21, 16, 55, 5, 255, 0, 147, 5, 245, 15, 55, 5, 1, 0, 19, 6, 21, 0, 51, 5, 248, 0, 111, 0, 64, 3, 147, 146, 40, 0, 179, 134, 86, 0, 179, 130, 23, 65, 147, 248, 56, 0, 179, 115, 179, 0, 19, 83, 131, 0, 51, 115, 179, 0, 51, 3, 115, 0, 51, 3, 195, 2, 19, 83, 3, 1, 51, 5, 163, 0, 99, 152, 8, 10, 227, 132, 2, 244, 147, 135, 2, 0, 19, 8, 0, 12, 147, 136, 2, 0, 99, 228, 2, 1, 147, 8, 0, 12, 147, 242, 200, 15, 19, 152, 34, 0, 51, 136, 6, 1, 19, 3, 0, 0, 227, 132, 2, 250, 147, 130, 6, 0, 131, 163, 2, 0, 19, 206, 243, 255, 19, 94, 126, 0, 147, 211, 99, 0, 131, 174, 66, 0, 179, 99, 126, 0, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 130, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 131, 174, 194, 0, 179, 227, 195, 1, 179, 243, 227, 0, 51, 131, 99, 0, 147, 195, 254, 255, 147, 211, 115, 0, 19, 222, 110, 0, 179, 227, 195, 1, 179, 243, 227, 0, 147, 130, 2, 1, 51, 131, 99, 0, 227, 150, 2, 249, 111, 240, 159, 242, 147, 6, 0, 12, 99, 228, 215, 0, 147, 7, 0, 12, 147, 6, 0, 0, 147, 247, 55, 0, 147, 151, 39, 0, 131, 40, 8, 0, 19, 8, 72, 0, 147, 194, 248, 255, 147, 210, 114, 0, 147, 216, 104, 0, 179, 232, 18, 1, 179, 248, 232, 0, 147, 135, 199, 255, 179, 134, 216, 0, 227, 158, 7, 252, 51, 247, 182, 0, 147, 214, 134, 0, 179, 245, 182, 0, 179, 133, 229, 0, 179, 133, 197, 2, 147, 213, 5, 1, 51, 133, 165, 0, 103, 128, 0, 0, 55, 5, 177, 232, 19, 5, 133, 221, 183, 69, 246, 136, 147, 133, 69, 41, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 3, 164, 197, 1, 131, 164, 133, 1, 131, 38, 196, 0, 19, 9, 5, 0, 55, 101, 0, 7, 147, 5, 69, 141, 19, 6, 192, 0, 19, 133, 4, 0, 231, 128, 6, 0, 147, 9, 16, 0, 99, 16, 5, 18, 3, 37, 137, 0, 99, 14, 5, 0, 35, 34, 161, 0, 19, 5, 65, 0, 35, 36, 161, 0, 55, 85, 0, 7, 19, 5, 133, 133, 111, 0, 192, 4, 3, 37, 73, 0, 131, 41, 9, 0, 131, 37, 197, 0, 19, 133, 9, 0, 231, 128, 5, 0, 55, 214, 68, 116, 19, 6, 118, 236, 179, 197, 197, 0, 55, 214, 164, 102, 19, 6, 182, 142, 51, 69, 197, 0, 51, 101, 181, 0, 99, 30, 5, 4, 35, 34, 49, 1, 19, 5, 65, 0, 35, 36, 161, 0, 55, 85, 0, 7, 19, 5, 197, 131, 35, 38, 161, 0, 55, 101, 0, 7, 19, 5, 69, 142, 35, 32, 161, 2, 19, 5, 32, 0, 35, 34, 161, 2, 35, 36, 1, 2, 19, 5, 129, 0, 35, 40, 161, 2, 147, 9, 16, 0, 35, 42, 49, 3, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 30, 99, 26, 5, 6, 3, 37, 201, 0, 147, 5, 133, 0, 19, 6, 197, 0, 35, 36, 161, 0, 55, 85, 0, 7, 19, 5, 69, 139, 35, 38, 161, 0, 35, 40, 177, 0, 55, 85, 0, 7, 19, 5, 197, 215, 35, 42, 161, 0, 35, 44, 193, 0, 35, 46, 161, 0, 55, 101, 0, 7, 19, 5, 197, 139, 35, 32, 161, 2, 19, 5, 48, 0, 35, 34, 161, 2, 35, 36, 1, 2, 147, 5, 129, 0, 35, 40, 177, 2, 35, 42, 161, 2, 19, 6, 1, 2, 19, 133, 4, 0, 147, 5, 4, 0, 151, 0, 0, 0, 231, 128, 64, 23, 147, 9, 5, 0, 19, 133, 9, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 19, 1, 1, 5, 103, 128, 0, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 255, 35, 36, 161, 0, 35, 38, 177, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 55, 85, 0, 7, 19, 5, 69, 139, 35, 46, 161, 0, 55, 101, 0, 7, 19, 5, 69, 143, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 19, 6, 129, 1, 35, 40, 193, 0, 35, 42, 161, 0, 19, 5, 1, 0, 151, 16, 0, 0, 231, 128, 0, 180, 115, 16, 0, 192, 151, 0, 0, 0, 231, 128, 192, 0, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 1, 0, 35, 32, 161, 2, 55, 85, 0, 7, 19, 5, 197, 215, 35, 34, 161, 2, 147, 5, 65, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 101, 0, 7, 19, 5, 5, 147, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 16, 0, 0, 231, 128, 0, 173, 115, 16, 0, 192, 3, 37, 5, 0, 111, 0, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 69, 1, 3, 39, 5, 1, 131, 39, 197, 0, 35, 42, 193, 0, 131, 166, 133, 1, 35, 40, 225, 0, 35, 38, 241, 0, 3, 38, 133, 0, 3, 39, 69, 0, 3, 37, 5, 0, 131, 165, 197, 1, 35, 36, 193, 0, 35, 34, 225, 0, 35, 32, 161, 0, 19, 6, 1, 0, 19, 133, 6, 0, 151, 0, 0, 0, 231, 128, 64, 1, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 251, 35, 38, 17, 4, 35, 36, 129, 4, 35, 34, 145, 4, 35, 32, 33, 5, 35, 46, 49, 3, 35, 44, 65, 3, 35, 42, 81, 3, 35, 40, 97, 3, 19, 4, 6, 0, 35, 36, 1, 0, 19, 6, 0, 2, 35, 38, 193, 0, 19, 6, 48, 0, 35, 4, 193, 2, 3, 38, 132, 0, 35, 40, 1, 0, 35, 44, 1, 0, 35, 32, 161, 2, 35, 34, 177, 2, 99, 4, 6, 18, 3, 37, 196, 0, 99, 10, 5, 24, 131, 37, 4, 0, 147, 6, 245, 255, 147, 150, 86, 0, 147, 214, 86, 0, 147, 132, 22, 0, 19, 137, 69, 0, 147, 25, 85, 0, 19, 10, 6, 1, 147, 10, 16, 0, 55, 69, 0, 7, 19, 11, 197, 224, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 22, 5, 24, 3, 37, 74, 255, 35, 38, 161, 0, 3, 5, 202, 0, 35, 4, 161, 2, 131, 37, 138, 255, 3, 37, 4, 1, 35, 36, 177, 0, 131, 38, 74, 0, 131, 37, 138, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 40, 193, 0, 35, 42, 177, 0, 131, 38, 202, 255, 131, 37, 10, 0, 99, 134, 6, 2, 19, 6, 0, 0, 99, 148, 86, 3, 147, 149, 53, 0, 179, 5, 181, 0, 3, 166, 69, 0, 99, 6, 102, 1, 19, 6, 0, 0, 111, 0, 0, 1, 131, 165, 5, 0, 131, 165, 5, 0, 19, 6, 16, 0, 35, 44, 193, 0, 35, 46, 177, 0, 131, 37, 10, 255, 147, 149, 53, 0, 51, 5, 181, 0, 3, 38, 69, 0, 3, 37, 5, 0, 147, 5, 129, 0, 231, 0, 6, 0, 99, 24, 5, 12, 19, 9, 137, 0, 147, 137, 9, 254, 19, 10, 10, 2, 227, 156, 9, 240, 111, 0, 192, 7, 3, 37, 68, 1, 99, 0, 5, 8, 131, 37, 4, 1, 3, 38, 4, 0, 147, 6, 245, 255, 147, 150, 54, 0, 147, 214, 54, 0, 147, 132, 22, 0, 19, 9, 70, 0, 147, 137, 69, 0, 19, 26, 53, 0, 3, 38, 9, 0, 99, 14, 6, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 201, 255, 131, 166, 198, 0, 231, 128, 6, 0, 99, 24, 5, 6, 3, 166, 9, 0, 3, 165, 201, 255, 147, 5, 129, 0, 231, 0, 6, 0, 99, 30, 5, 4, 19, 9, 137, 0, 19, 10, 138, 255, 147, 137, 137, 0, 227, 16, 10, 252, 111, 0, 128, 0, 147, 4, 0, 0, 3, 37, 68, 0, 99, 234, 164, 0, 111, 0, 0, 4, 147, 4, 0, 0, 3, 37, 68, 0, 99, 250, 164, 2, 3, 37, 4, 0, 147, 149, 52, 0, 51, 6, 181, 0, 131, 38, 65, 2, 3, 37, 1, 2, 131, 37, 6, 0, 3, 38, 70, 0, 131, 166, 198, 0, 231, 128, 6, 0, 99, 6, 5, 0, 19, 5, 16, 0, 111, 0, 128, 0, 19, 5, 0, 0, 131, 32, 193, 4, 3, 36, 129, 4, 131, 36, 65, 4, 3, 41, 1, 4, 131, 41, 193, 3, 3, 42, 129, 3, 131, 42, 65, 3, 3, 43, 1, 3, 19, 1, 1, 5, 103, 128, 0, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 35, 40, 33, 3, 35, 38, 49, 3, 35, 36, 65, 3, 35, 34, 81, 3, 35, 32, 97, 3, 35, 46, 113, 1, 35, 44, 129, 1, 35, 42, 145, 1, 35, 40, 161, 1, 35, 38, 177, 1, 19, 132, 7, 0, 147, 4, 7, 0, 147, 137, 6, 0, 19, 10, 6, 0, 19, 9, 5, 0, 99, 142, 5, 4, 3, 43, 9, 0, 19, 117, 27, 0, 183, 10, 17, 0, 99, 4, 5, 0, 147, 10, 176, 2, 179, 12, 133, 0, 19, 117, 75, 0, 99, 8, 5, 4, 19, 5, 0, 1, 99, 252, 169, 4, 19, 5, 0, 0, 99, 128, 9, 6, 147, 133, 9, 0, 19, 6, 10, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 111, 0, 128, 3, 3, 43, 9, 0, 147, 12, 20, 0, 147, 10, 208, 2, 19, 117, 75, 0, 227, 28, 5, 250, 19, 10, 0, 0, 3, 37, 137, 0, 99, 18, 5, 2, 111, 0, 0, 6, 19, 5, 10, 0, 147, 133, 9, 0, 151, 240, 255, 255, 231, 128, 128, 125, 179, 12, 149, 1, 3, 37, 137, 0, 99, 2, 5, 4, 3, 45, 201, 0, 99, 254, 172, 3, 19, 117, 139, 0, 99, 22, 5, 14, 131, 69, 9, 2, 19, 6, 48, 0, 19, 5, 16, 0, 99, 132, 197, 0, 19, 133, 5, 0, 147, 117, 53, 0, 51, 5, 157, 65, 99, 140, 5, 18, 19, 6, 16, 0, 99, 158, 197, 18, 147, 12, 0, 0, 111, 0, 0, 20, 131, 43, 137, 1, 3, 41, 201, 1, 19, 133, 11, 0, 147, 5, 9, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 128, 31, 19, 11, 16, 0, 99, 2, 5, 4, 19, 5, 11, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 128, 0, 0, 3, 35, 201, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 3, 41, 1, 3, 131, 41, 193, 2, 3, 42, 129, 2, 131, 42, 65, 2, 3, 43, 1, 2, 131, 43, 193, 1, 3, 44, 129, 1, 131, 44, 65, 1, 3, 45, 1, 1, 131, 45, 193, 0, 19, 1, 1, 4, 103, 0, 3, 0, 3, 37, 73, 0, 35, 36, 161, 0, 19, 5, 0, 3, 131, 77, 9, 2, 131, 43, 137, 1, 3, 44, 201, 1, 35, 34, 169, 0, 19, 11, 16, 0, 35, 0, 105, 3, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 18, 227, 26, 5, 242, 51, 5, 157, 65, 147, 9, 21, 0, 147, 137, 249, 255, 99, 138, 9, 12, 3, 38, 12, 1, 147, 5, 0, 3, 19, 133, 11, 0, 231, 0, 6, 0, 227, 4, 5, 254, 111, 240, 223, 240, 147, 12, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 220, 21, 0, 131, 43, 137, 1, 3, 44, 201, 1, 3, 41, 73, 0, 19, 11, 21, 0, 19, 11, 251, 255, 99, 0, 11, 2, 3, 38, 12, 1, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 11, 16, 0, 111, 240, 31, 236, 55, 5, 17, 0, 19, 11, 16, 0, 227, 10, 169, 234, 19, 133, 11, 0, 147, 5, 12, 0, 19, 134, 10, 0, 147, 6, 10, 0, 19, 135, 9, 0, 151, 0, 0, 0, 231, 128, 64, 8, 227, 26, 5, 232, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 230, 19, 4, 0, 0, 99, 136, 140, 4, 3, 38, 12, 1, 19, 4, 20, 0, 19, 133, 11, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 64, 3, 131, 38, 204, 0, 19, 133, 11, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 227, 30, 5, 226, 19, 11, 0, 0, 3, 37, 129, 0, 35, 34, 169, 0, 35, 0, 185, 3, 111, 240, 159, 226, 19, 133, 12, 0, 51, 59, 149, 1, 111, 240, 223, 225, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 183, 7, 17, 0, 19, 4, 7, 0, 147, 132, 6, 0, 147, 137, 5, 0, 19, 9, 5, 0, 99, 0, 246, 2, 131, 166, 9, 1, 19, 5, 9, 0, 147, 5, 6, 0, 231, 128, 6, 0, 147, 5, 5, 0, 19, 5, 16, 0, 99, 156, 5, 2, 99, 136, 4, 2, 3, 163, 201, 0, 19, 5, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 0, 3, 0, 19, 5, 0, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 19, 1, 1, 2, 103, 128, 0, 0, 19, 1, 1, 254, 35, 46, 17, 0, 35, 44, 129, 0, 35, 42, 145, 0, 35, 40, 33, 1, 35, 38, 49, 1, 35, 36, 65, 1, 35, 34, 81, 1, 35, 32, 97, 1, 19, 9, 5, 0, 3, 37, 133, 0, 131, 38, 9, 1, 19, 7, 245, 255, 51, 55, 224, 0, 147, 135, 246, 255, 179, 55, 240, 0, 51, 119, 247, 0, 19, 4, 6, 0, 147, 132, 5, 0, 99, 16, 7, 30, 147, 5, 16, 0, 99, 154, 182, 20, 3, 38, 73, 1, 147, 5, 0, 0, 179, 134, 132, 0, 19, 7, 22, 0, 183, 7, 17, 0, 19, 8, 240, 13, 147, 8, 0, 15, 19, 134, 4, 0, 111, 0, 128, 1, 19, 3, 22, 0, 179, 133, 197, 64, 179, 133, 101, 0, 19, 6, 3, 0, 99, 142, 242, 16, 19, 7, 247, 255, 99, 0, 7, 8, 99, 8, 214, 16, 3, 3, 6, 0, 147, 114, 243, 15, 227, 92, 3, 252, 3, 67, 22, 0, 147, 243, 242, 1, 19, 126, 243, 3, 99, 112, 88, 4, 3, 67, 38, 0, 19, 30, 110, 0, 19, 115, 243, 3, 51, 110, 110, 0, 99, 238, 18, 3, 131, 66, 54, 0, 19, 147, 211, 1, 19, 83, 179, 0, 147, 19, 110, 0, 147, 242, 242, 3, 179, 226, 83, 0, 179, 226, 98, 0, 99, 128, 242, 12, 19, 3, 70, 0, 111, 240, 31, 249, 19, 3, 38, 0, 147, 146, 99, 0, 179, 226, 194, 1, 111, 240, 31, 248, 19, 3, 54, 0, 147, 146, 195, 0, 179, 98, 94, 0, 111, 240, 31, 247, 99, 10, 214, 8, 131, 6, 6, 0, 99, 218, 6, 4, 147, 246, 246, 15, 19, 7, 0, 14, 99, 228, 230, 4, 19, 7, 0, 15, 99, 224, 230, 4, 3, 71, 22, 0, 131, 71, 38, 0, 19, 119, 247, 3, 147, 247, 247, 3, 3, 70, 54, 0, 147, 150, 214, 1, 147, 214, 182, 0, 19, 23, 199, 0, 147, 151, 103, 0, 51, 231, 231, 0, 19, 118, 246, 3, 51, 102, 199, 0, 51, 102, 214, 0, 183, 6, 17, 0, 99, 14, 214, 2, 99, 132, 5, 2, 99, 240, 133, 2, 51, 134, 180, 0, 3, 6, 6, 0, 147, 6, 0, 252, 99, 90, 214, 0, 19, 6, 0, 0, 99, 26, 6, 0, 111, 0, 128, 1, 227, 154, 133, 254, 19, 134, 4, 0, 99, 6, 6, 0, 19, 132, 5, 0, 147, 4, 6, 0, 99, 2, 5, 8, 131, 41, 201, 0, 19, 5, 0, 1, 99, 114, 164, 6, 19, 5, 0, 0, 99, 4, 4, 2, 147, 5, 4, 0, 19, 134, 4, 0, 131, 6, 6, 0, 19, 6, 22, 0, 147, 166, 6, 252, 147, 198, 22, 0, 147, 133, 245, 255, 51, 5, 213, 0, 227, 148, 5, 254, 99, 116, 53, 5, 131, 69, 9, 2, 147, 6, 48, 0, 19, 6, 0, 0, 99, 132, 213, 0, 19, 134, 5, 0, 147, 117, 54, 0, 51, 133, 169, 64, 99, 130, 5, 6, 19, 6, 16, 0, 99, 148, 197, 6, 147, 10, 0, 0, 111, 0, 192, 6, 19, 133, 4, 0, 147, 5, 4, 0, 151, 240, 255, 255, 231, 128, 64, 43, 227, 96, 53, 253, 131, 37, 201, 1, 3, 37, 137, 1, 3, 163, 197, 0, 147, 133, 4, 0, 19, 6, 4, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 0, 3, 0, 147, 10, 5, 0, 19, 133, 5, 0, 111, 0, 0, 1, 147, 5, 21, 0, 19, 85, 21, 0, 147, 218, 21, 0, 131, 41, 137, 1, 3, 43, 201, 1, 3, 41, 73, 0, 19, 10, 21, 0, 19, 10, 250, 255, 99, 0, 10, 2, 3, 38, 11, 1, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 10, 16, 0, 111, 0, 128, 5, 55, 5, 17, 0, 19, 10, 16, 0, 99, 6, 169, 4, 131, 38, 203, 0, 19, 133, 9, 0, 147, 133, 4, 0, 19, 6, 4, 0, 231, 128, 6, 0, 99, 26, 5, 2, 19, 4, 0, 0, 99, 130, 138, 2, 3, 38, 11, 1, 19, 4, 20, 0, 19, 133, 9, 0, 147, 5, 9, 0, 231, 0, 6, 0, 227, 4, 5, 254, 19, 5, 244, 255, 111, 0, 128, 0, 19, 133, 10, 0, 51, 58, 85, 1, 19, 5, 10, 0, 131, 32, 193, 1, 3, 36, 129, 1, 131, 36, 65, 1, 3, 41, 1, 1, 131, 41, 193, 0, 3, 42, 129, 0, 131, 42, 65, 0, 3, 43, 1, 0, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 197, 1, 3, 37, 133, 1, 3, 163, 198, 0, 103, 0, 3, 0, 3, 69, 5, 0, 19, 21, 181, 1, 19, 85, 245, 1, 103, 128, 0, 0, 3, 69, 5, 0, 19, 21, 165, 1, 19, 85, 245, 1, 103, 128, 0, 0, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 101, 0, 7, 147, 133, 5, 148, 19, 6, 80, 0, 103, 0, 3, 0, 3, 38, 69, 0, 3, 37, 5, 0, 3, 35, 198, 0, 103, 0, 3, 0, 3, 37, 5, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 195, 197, 19, 1, 1, 254, 35, 46, 17, 0, 3, 38, 5, 0, 3, 37, 70, 1, 35, 42, 161, 0, 3, 37, 6, 1, 35, 40, 161, 0, 3, 37, 198, 0, 35, 38, 161, 0, 3, 37, 134, 0, 35, 36, 161, 0, 131, 38, 70, 0, 3, 165, 133, 1, 35, 34, 209, 0, 3, 38, 6, 0, 131, 165, 197, 1, 35, 32, 193, 0, 19, 6, 1, 0, 151, 240, 255, 255, 231, 128, 0, 93, 131, 32, 193, 1, 19, 1, 1, 2, 103, 128, 0, 0, 131, 38, 5, 0, 3, 38, 69, 0, 19, 133, 5, 0, 147, 133, 6, 0, 23, 3, 0, 0, 103, 0, 131, 190, 103, 128, 0, 0, 19, 1, 1, 254, 55, 102, 0, 7, 19, 6, 134, 148, 35, 36, 193, 0, 55, 102, 0, 7, 19, 6, 134, 150, 35, 38, 193, 0, 35, 40, 161, 0, 35, 42, 177, 0, 19, 5, 16, 0, 35, 12, 161, 0, 19, 5, 129, 0, 151, 208, 255, 255, 231, 128, 128, 103, 115, 16, 0, 192, 19, 1, 1, 254, 35, 44, 161, 0, 35, 46, 177, 0, 19, 5, 129, 1, 35, 32, 161, 0, 19, 5, 16, 0, 35, 34, 161, 0, 35, 36, 1, 0, 55, 101, 0, 7, 19, 5, 133, 148, 35, 40, 161, 0, 35, 42, 1, 0, 19, 5, 1, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 192, 248, 115, 16, 0, 192, 19, 1, 1, 253, 35, 32, 161, 0, 35, 34, 177, 0, 19, 5, 65, 0, 35, 32, 161, 2, 55, 85, 0, 7, 19, 5, 197, 215, 35, 34, 161, 2, 147, 5, 1, 0, 35, 36, 177, 2, 35, 38, 161, 2, 55, 101, 0, 7, 19, 5, 197, 152, 35, 36, 161, 0, 19, 5, 32, 0, 35, 38, 161, 0, 35, 40, 1, 0, 147, 5, 1, 2, 35, 44, 177, 0, 35, 46, 161, 0, 19, 5, 129, 0, 147, 5, 6, 0, 151, 0, 0, 0, 231, 128, 128, 242, 115, 16, 0, 192, 19, 1, 1, 249, 35, 36, 177, 0, 35, 38, 193, 0, 35, 40, 209, 0, 19, 117, 245, 15, 35, 42, 225, 0, 99, 12, 5, 0, 147, 5, 16, 0, 99, 20, 181, 8, 55, 101, 0, 7, 19, 5, 53, 154, 111, 0, 192, 0, 55, 101, 0, 7, 19, 5, 85, 154, 35, 44, 161, 0, 19, 5, 32, 0, 131, 165, 7, 0, 35, 46, 161, 0, 99, 158, 5, 6, 19, 5, 129, 1, 35, 44, 161, 2, 55, 85, 0, 7, 19, 5, 69, 139, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 85, 0, 7, 19, 5, 197, 130, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 55, 101, 0, 7, 19, 5, 133, 160, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 19, 5, 129, 3, 35, 36, 161, 6, 19, 5, 48, 0, 111, 0, 128, 11, 55, 101, 0, 7, 19, 5, 197, 153, 35, 44, 161, 0, 19, 5, 112, 0, 131, 165, 7, 0, 35, 46, 161, 0, 227, 134, 5, 248, 3, 165, 71, 1, 131, 165, 7, 1, 35, 42, 161, 2, 35, 40, 177, 2, 3, 165, 199, 0, 131, 165, 135, 0, 3, 166, 71, 0, 131, 166, 7, 0, 35, 38, 161, 2, 35, 36, 177, 2, 35, 34, 193, 2, 35, 32, 209, 2, 19, 5, 129, 1, 35, 44, 161, 2, 55, 85, 0, 7, 19, 5, 69, 139, 35, 46, 161, 2, 19, 5, 129, 0, 35, 32, 161, 4, 55, 85, 0, 7, 19, 5, 197, 130, 35, 34, 161, 4, 147, 5, 1, 1, 35, 36, 177, 4, 35, 38, 161, 4, 19, 5, 1, 2, 35, 40, 161, 4, 55, 69, 0, 7, 19, 5, 69, 225, 35, 42, 161, 4, 55, 101, 0, 7, 19, 5, 69, 158, 35, 44, 161, 4, 19, 5, 64, 0, 35, 46, 161, 4, 35, 32, 1, 6, 147, 5, 129, 3, 35, 36, 177, 6, 35, 38, 161, 6, 19, 5, 129, 5, 147, 5, 8, 0, 151, 0, 0, 0, 231, 128, 64, 219, 115, 16, 0, 192, 19, 1, 1, 252, 35, 36, 161, 0, 35, 38, 177, 0, 35, 40, 193, 0, 35, 42, 209, 0, 19, 5, 129, 0, 35, 40, 161, 2, 55, 85, 0, 7, 19, 5, 69, 139, 35, 42, 161, 2, 19, 5, 1, 1, 35, 44, 161, 2, 55, 85, 0, 7, 19, 5, 197, 130, 35, 46, 161, 2, 55, 101, 0, 7, 19, 5, 197, 162, 35, 44, 161, 0, 19, 5, 32, 0, 35, 46, 161, 0, 35, 32, 1, 2, 147, 5, 1, 3, 35, 36, 177, 2, 35, 38, 161, 2, 19, 5, 129, 1, 147, 5, 7, 0, 151, 0, 0, 0, 231, 128, 0, 212, 115, 16, 0, 192, 3, 166, 197, 1, 3, 165, 133, 1, 3, 35, 198, 0, 183, 101, 0, 7, 147, 133, 197, 163, 19, 6, 176, 0, 103, 0, 3, 0, 19, 1, 1, 252, 35, 46, 17, 2, 35, 44, 129, 2, 35, 42, 145, 2, 147, 6, 6, 0, 19, 87, 69, 0, 147, 7, 16, 39, 19, 6, 112, 2, 99, 118, 247, 2, 19, 7, 48, 6, 99, 106, 167, 12, 19, 7, 160, 0, 99, 118, 229, 18, 19, 6, 246, 255, 19, 7, 209, 0, 51, 7, 199, 0, 19, 5, 5, 3, 35, 0, 167, 0, 111, 0, 0, 20, 19, 6, 0, 0, 55, 23, 183, 209, 19, 7, 151, 117, 183, 39, 0, 0, 147, 135, 7, 113, 55, 24, 0, 0, 19, 8, 184, 71, 147, 8, 64, 6, 183, 82, 0, 7, 147, 130, 98, 121, 19, 3, 209, 0, 183, 227, 245, 5, 147, 131, 243, 15, 19, 14, 5, 0, 51, 53, 229, 2, 19, 85, 213, 0, 179, 14, 245, 2, 179, 14, 222, 65, 19, 159, 14, 1, 19, 95, 47, 1, 51, 15, 15, 3, 147, 95, 31, 1, 19, 95, 15, 1, 19, 127, 239, 127, 179, 143, 31, 3, 179, 142, 254, 65, 147, 158, 30, 1, 147, 222, 14, 1, 51, 143, 226, 1, 179, 15, 195, 0, 3, 68, 15, 0, 3, 15, 31, 0, 179, 142, 210, 1, 131, 132, 30, 0, 131, 206, 14, 0, 35, 130, 239, 3, 163, 129, 143, 2, 35, 131, 159, 2, 163, 130, 223, 3, 19, 6, 198, 255, 227, 234, 195, 249, 19, 6, 118, 2, 19, 7, 48, 6, 227, 122, 167, 242, 19, 23, 5, 1, 19, 87, 39, 1, 183, 23, 0, 0, 147, 135, 183, 71, 51, 7, 247, 2, 19, 87, 23, 1, 147, 7, 64, 6, 179, 7, 247, 2, 51, 5, 245, 64, 19, 21, 21, 1, 19, 85, 5, 1, 19, 6, 230, 255, 183, 87, 0, 7, 147, 135, 103, 121, 51, 133, 167, 0, 131, 7, 21, 0, 3, 69, 5, 0, 19, 8, 209, 0, 51, 8, 200, 0, 163, 0, 248, 0, 35, 0, 168, 0, 19, 5, 7, 0, 19, 7, 160, 0, 227, 110, 229, 236, 19, 21, 21, 0, 19, 6, 230, 255, 55, 87, 0, 7, 19, 7, 103, 121, 51, 5, 167, 0, 3, 7, 21, 0, 3, 69, 5, 0, 147, 7, 209, 0, 179, 135, 199, 0, 163, 128, 231, 0, 35, 128, 167, 0, 19, 5, 209, 0, 51, 7, 197, 0, 19, 5, 112, 2, 179, 7, 197, 64, 55, 101, 0, 7, 19, 6, 117, 164, 19, 133, 6, 0, 147, 6, 0, 0, 151, 240, 255, 255, 231, 128, 64, 55, 131, 32, 193, 3, 3, 36, 129, 3, 131, 36, 65, 3, 19, 1, 1, 4, 103, 128, 0, 0, 3, 37, 5, 0, 19, 134, 5, 0, 147, 5, 16, 0, 23, 3, 0, 0, 103, 0, 3, 227, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 168, 8, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 248, 28, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 101, 110, 118, 46, 114, 115, 204, 77, 0, 7, 96, 0, 0, 0, 14, 1, 0, 0, 9, 0, 0, 0, 204, 77, 0, 7, 96, 0, 0, 0, 99, 1, 0, 0, 9, 0, 0, 0, 72, 105, 116, 84, 121, 112, 101, 68, 101, 115, 116, 114, 111, 121, 101, 100, 99, 97, 108, 108, 101, 100, 32, 96, 82, 101, 115, 117, 108, 116, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 110, 32, 96, 69, 114, 114, 96, 32, 118, 97, 108, 117, 101, 0, 16, 17, 0, 7, 1, 0, 0, 0, 1, 0, 0, 0, 248, 28, 0, 7, 115, 114, 99, 47, 98, 105, 110, 47, 116, 117, 114, 110, 46, 114, 115, 0, 152, 78, 0, 7, 15, 0, 0, 0, 19, 0, 0, 0, 70, 0, 0, 0, 152, 78, 0, 7, 15, 0, 0, 0, 19, 0, 0, 0, 37, 0, 0, 0, 152, 78, 0, 7, 15, 0, 0, 0, 20, 0, 0, 0, 70, 0, 0, 0, 152, 78, 0, 7, 15, 0, 0, 0, 20, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 115, 104, 111, 116, 15, 7, 7, 3, 83, 104, 111, 116, 80, 97, 114, 97, 109, 115, 115, 116, 97, 116, 101, 0, 254, 78, 0, 7, 5, 0, 0, 0, 236, 78, 0, 7, 4, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 47, 103, 97, 109, 101, 47, 115, 114, 99, 47, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 114, 115, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 115, 104, 111, 116, 46, 99, 104, 101, 99, 107, 40, 41, 0, 0, 20, 79, 0, 7, 60, 0, 0, 0, 152, 0, 0, 0, 9, 0, 0, 0, 24, 29, 0, 7, 44, 29, 0, 7, 64, 29, 0, 7, 84, 29, 0, 7, 104, 29, 0, 7, 124, 29, 0, 7, 144, 29, 0, 7, 83, 101, 114, 105, 97, 108, 105, 122, 101, 66, 117, 102, 102, 101, 114, 70, 117, 108, 108, 78, 111, 116, 83, 117, 112, 112, 111, 114, 116, 101, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 85, 110, 101, 120, 112, 101, 99, 116, 101, 100, 69, 110, 100, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 85, 116, 102, 56, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 79, 112, 116, 105, 111, 110, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 67, 104, 97, 114, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 66, 97, 100, 66, 111, 111, 108, 0, 0, 0, 32, 80, 0, 7, 0, 0, 0, 0, 77, 101, 109, 111, 114, 121, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 102, 97, 105, 108, 117, 114, 101, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 115, 104, 97, 46, 114, 115, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 98, 121, 116, 101, 115, 46, 108, 101, 110, 40, 41, 32, 60, 61, 32, 112, 97, 100, 108, 101, 110, 32, 42, 32, 87, 79, 82, 68, 95, 83, 73, 90, 69, 65, 80, 0, 7, 96, 0, 0, 0, 112, 0, 0, 0, 5, 0, 0, 0, 65, 80, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 20, 0, 0, 0, 65, 80, 0, 7, 96, 0, 0, 0, 136, 0, 0, 0, 9, 0, 0, 0, 208, 35, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 164, 29, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 112, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 99, 111, 114, 101, 47, 115, 104, 97, 46, 114, 115, 0, 0, 20, 81, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 17, 0, 0, 0, 99, 97, 108, 108, 101, 100, 32, 96, 79, 112, 116, 105, 111, 110, 58, 58, 117, 110, 119, 114, 97, 112, 40, 41, 96, 32, 111, 110, 32, 97, 32, 96, 78, 111, 110, 101, 96, 32, 118, 97, 108, 117, 101, 0, 20, 81, 0, 7, 94, 0, 0, 0, 192, 1, 0, 0, 33, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 0, 192, 81, 0, 7, 103, 0, 0, 0, 222, 0, 0, 0, 9, 0, 0, 0, 85, 110, 97, 98, 108, 101, 32, 116, 111, 32, 97, 108, 105, 103, 110, 32, 97, 108, 108, 111, 99, 97, 116, 105, 111, 110, 32, 116, 111, 32, 119, 111, 114, 100, 32, 115, 105, 122, 101, 0, 240, 40, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 156, 75, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 103, 117, 101, 115, 116, 47, 97, 108, 108, 111, 99, 46, 114, 115, 0, 0, 152, 82, 0, 7, 98, 0, 0, 0, 27, 0, 0, 0, 22, 0, 0, 0, 106, 9, 230, 103, 187, 103, 174, 133, 60, 110, 243, 114, 165, 79, 245, 58, 81, 14, 82, 127, 155, 5, 104, 140, 31, 131, 217, 171, 91, 224, 205, 25, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 46, 99, 97, 114, 103, 111, 47, 114, 101, 103, 105, 115, 116, 114, 121, 47, 115, 114, 99, 47, 103, 105, 116, 104, 117, 98, 46, 99, 111, 109, 45, 49, 101, 99, 99, 54, 50, 57, 57, 100, 98, 57, 101, 99, 56, 50, 51, 47, 114, 105, 115, 99, 48, 45, 122, 107, 118, 109, 45, 112, 108, 97, 116, 102, 111, 114, 109, 45, 48, 46, 49, 52, 46, 48, 47, 115, 114, 99, 47, 115, 121, 115, 99, 97, 108, 108, 46, 114, 115, 105, 110, 116, 101, 114, 110, 97, 108, 32, 101, 114, 114, 111, 114, 58, 32, 101, 110, 116, 101, 114, 101, 100, 32, 117, 110, 114, 101, 97, 99, 104, 97, 98, 108, 101, 32, 99, 111, 100, 101, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 80, 65, 78, 73, 67, 0, 0, 44, 83, 0, 7, 103, 0, 0, 0, 62, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 82, 69, 65, 68, 0, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 110, 114, 101, 97, 100, 32, 97, 115, 32, 117, 115, 105, 122, 101, 32, 60, 61, 32, 110, 98, 121, 116, 101, 115, 95, 114, 101, 113, 117, 101, 115, 116, 101, 100, 0, 44, 83, 0, 7, 103, 0, 0, 0, 189, 1, 0, 0, 5, 0, 0, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 58, 58, 115, 121, 115, 99, 97, 108, 108, 58, 58, 110, 114, 58, 58, 83, 89, 83, 95, 87, 82, 73, 84, 69, 0, 79, 117, 116, 32, 111, 102, 32, 109, 101, 109, 111, 114, 121, 33, 0, 0, 44, 83, 0, 7, 103, 0, 0, 0, 240, 1, 0, 0, 31, 0, 0, 0, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 114, 97, 119, 95, 118, 101, 99, 46, 114, 115, 99, 97, 112, 97, 99, 105, 116, 121, 32, 111, 118, 101, 114, 102, 108, 111, 119, 115, 85, 0, 7, 17, 0, 0, 0, 180, 84, 0, 7, 191, 0, 0, 0, 6, 2, 0, 0, 5, 0, 0, 0, 97, 32, 102, 111, 114, 109, 97, 116, 116, 105, 110, 103, 32, 116, 114, 97, 105, 116, 32, 105, 109, 112, 108, 101, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 114, 101, 116, 117, 114, 110, 101, 100, 32, 97, 110, 32, 101, 114, 114, 111, 114, 0, 236, 51, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 16, 72, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 97, 108, 108, 111, 99, 47, 115, 114, 99, 47, 102, 109, 116, 46, 114, 115, 0, 224, 85, 0, 7, 187, 0, 0, 0, 100, 2, 0, 0, 9, 0, 0, 0, 92, 53, 0, 7, 4, 0, 0, 0, 4, 0, 0, 0, 40, 55, 0, 7, 96, 53, 0, 7, 196, 54, 0, 7, 47, 104, 111, 109, 101, 47, 99, 104, 105, 99, 107, 101, 110, 47, 99, 116, 102, 47, 102, 97, 105, 114, 103, 97, 109, 101, 47, 116, 97, 114, 103, 101, 116, 47, 114, 101, 108, 101, 97, 115, 101, 47, 98, 117, 105, 108, 100, 47, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 45, 109, 101, 116, 104, 111, 100, 115, 45, 50, 53, 100, 53, 97, 52, 52, 99, 57, 99, 50, 53, 101, 51, 54, 97, 47, 111, 117, 116, 47, 114, 117, 115, 116, 45, 115, 116, 100, 95, 52, 102, 50, 57, 100, 57, 53, 48, 101, 49, 48, 49, 57, 51, 54, 101, 57, 51, 52, 101, 97, 102, 101, 48, 52, 51, 98, 102, 97, 97, 48, 50, 102, 57, 57, 49, 99, 55, 52, 53, 55, 53, 97, 54, 100, 102, 99, 56, 54, 52, 55, 99, 51, 99, 55, 102, 98, 98, 100, 101, 100, 101, 53, 56, 47, 108, 105, 98, 114, 97, 114, 121, 47, 99, 111, 114, 101, 47, 115, 114, 99, 47, 102, 109, 116, 47, 110, 117, 109, 46, 114, 115, 0, 0, 196, 86, 0, 7, 190, 0, 0, 0, 101, 0, 0, 0, 20, 0, 0, 0, 48, 120, 48, 48, 48, 49, 48, 50, 48, 51, 48, 52, 48, 53, 48, 54, 48, 55, 48, 56, 48, 57, 49, 48, 49, 49, 49, 50, 49, 51, 49, 52, 49, 53, 49, 54, 49, 55, 49, 56, 49, 57, 50, 48, 50, 49, 50, 50, 50, 51, 50, 52, 50, 53, 50, 54, 50, 55, 50, 56, 50, 57, 51, 48, 51, 49, 51, 50, 51, 51, 51, 52, 51, 53, 51, 54, 51, 55, 51, 56, 51, 57, 52, 48, 52, 49, 52, 50, 52, 51, 52, 52, 52, 53, 52, 54, 52, 55, 52, 56, 52, 57, 53, 48, 53, 49, 53, 50, 53, 51, 53, 52, 53, 53, 53, 54, 53, 55, 53, 56, 53, 57, 54, 48, 54, 49, 54, 50, 54, 51, 54, 52, 54, 53, 54, 54, 54, 55, 54, 56, 54, 57, 55, 48, 55, 49, 55, 50, 55, 51, 55, 52, 55, 53, 55, 54, 55, 55, 55, 56, 55, 57, 56, 48, 56, 49, 56, 50, 56, 51, 56, 52, 56, 53, 56, 54, 56, 55, 56, 56, 56, 57, 57, 48, 57, 49, 57, 50, 57, 51, 57, 52, 57, 53, 57, 54, 57, 55, 57, 56, 57, 57, 115, 111, 117, 114, 99, 101, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 32, 100, 111, 101, 115, 32, 110, 111, 116, 32, 109, 97, 116, 99, 104, 32, 100, 101, 115, 116, 105, 110, 97, 116, 105, 111, 110, 32, 115, 108, 105, 99, 101, 32, 108, 101, 110, 103, 116, 104, 32, 40, 41, 0, 94, 88, 0, 7, 21, 0, 0, 0, 115, 88, 0, 7, 43, 0, 0, 0, 158, 88, 0, 7, 1, 0, 0, 0, 58, 0, 0, 0, 184, 88, 0, 7, 0, 0, 0, 0, 184, 88, 0, 7, 1, 0, 0, 0, 184, 88, 0, 7, 1, 0, 0, 0, 112, 97, 110, 105, 99, 107, 101, 100, 32, 97, 116, 32, 39, 39, 44, 32, 224, 88, 0, 7, 1, 0, 0, 0, 225, 88, 0, 7, 3, 0, 0, 0, 244, 88, 0, 7, 0, 0, 0, 0, 114, 97, 110, 103, 101, 32, 115, 116, 97, 114, 116, 32, 105, 110, 100, 101, 120, 32, 32, 111, 117, 116, 32, 111, 102, 32, 114, 97, 110, 103, 101, 32, 102, 111, 114, 32, 115, 108, 105, 99, 101, 32, 111, 102, 32, 108, 101, 110, 103, 116, 104, 32, 252, 88, 0, 7, 18, 0, 0, 0, 14, 89, 0, 7, 34, 0, 0, 0, 69, 114, 114, 111, 114, 0, 0, 0, 105, 110, 100, 101, 120, 32, 111, 117, 116, 32, 111, 102, 32, 98, 111, 117, 110, 100, 115, 58, 32, 116, 104, 101, 32, 108, 101, 110, 32, 105, 115, 32, 204, 72, 0, 7, 0, 0, 0, 0, 1, 0, 0, 0, 148, 59, 0, 7, 32, 98, 117, 116, 32, 116, 104, 101, 32, 105, 110, 100, 101, 120, 32, 105, 115, 32, 0, 0, 72, 89, 0, 7, 32, 0, 0, 0, 120, 89, 0, 7, 18, 0, 0, 0, 109, 97, 116, 99, 104, 101, 115, 33, 61, 61, 61, 97, 115, 115, 101, 114, 116, 105, 111, 110, 32, 102, 97, 105, 108, 101, 100, 58, 32, 96, 40, 108, 101, 102, 116, 32, 32, 114, 105, 103, 104, 116, 41, 96, 10, 32, 32, 108, 101, 102, 116, 58, 32, 96, 96, 44, 10, 32, 114, 105, 103, 104, 116, 58, 32, 96, 96, 58, 32, 0, 0, 0, 167, 89, 0, 7, 25, 0, 0, 0, 192, 89, 0, 7, 18, 0, 0, 0, 210, 89, 0, 7, 12, 0, 0, 0, 222, 89, 0, 7, 3, 0, 0, 0, 96, 0, 0, 0, 167, 89, 0, 7, 25, 0, 0, 0, 192, 89, 0, 7, 18, 0, 0, 0, 210, 89, 0, 7, 12, 0, 0, 0, 4, 90, 0, 7, 1, 0, 0, 0, 58, 32, 0, 0, 72, 89, 0, 7, 0, 0, 0, 0, 40, 90, 0, 7, 2, 0, 0, 0, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 103, 27, 0, 0, 148, 0, 0, 7, 120, 4, 0, 0, 2, 2, 1, 0, 3, 28, 0, 0, 12, 5, 0, 7, 84, 1, 0, 0, 2, 2, 1, 0, 160, 28, 0, 0, 96, 6, 0, 7, 72, 2, 0, 0, 2, 2, 1, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 121, 29, 0, 0, 172, 8, 0, 7, 92, 1, 0, 0, 2, 2, 1, 0, 87, 30, 0, 0, 144, 77, 0, 7, 43, 0, 0, 0, 1, 2, 1, 0, 152, 30, 0, 0, 188, 77, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 217, 30, 0, 0, 44, 78, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 32, 32, 0, 0, 60, 78, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 97, 32, 0, 0, 168, 8, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 32, 34, 0, 0, 204, 77, 0, 7, 96, 0, 0, 0, 1, 2, 1, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 252, 34, 0, 0, 8, 10, 0, 7, 216, 0, 0, 0, 2, 2, 1, 0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 9, 36, 0, 0, 224, 10, 0, 7, 192, 1, 0, 0, 2, 2, 1, 0, 168, 36, 0, 0, 160, 12, 0, 7, 68, 1, 0, 0, 2, 2, 1, 0, 207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 7, 33, 0, 0, 76, 78, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 229, 36, 0, 0, 76, 15, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 120, 37, 0, 0, 228, 13, 0, 7, 104, 1, 0, 0, 2, 2, 1, 0, 10, 38, 0, 0, 76, 78, 0, 7, 7, 0, 0, 0, 1, 2, 1, 0, 75, 38, 0, 0, 83, 78, 0, 7, 9, 0, 0, 0, 1, 2, 1, 0, 11, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 31, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 51, 1, 0, 0, 16, 17, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 136, 1, 0, 0, 92, 78, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 178, 1, 0, 0, 136, 78, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 220, 1, 0, 0, 168, 78, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 6, 2, 0, 0, 184, 78, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 48, 2, 0, 0, 200, 78, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 90, 2, 0, 0, 216, 78, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 132, 2, 0, 0, 152, 78, 0, 7, 15, 0, 0, 0, 1, 0, 1, 0, 174, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 131, 31, 0, 0, 180, 20, 0, 7, 112, 2, 0, 0, 2, 2, 1, 0, 140, 38, 0, 0, 244, 78, 0, 7, 10, 0, 0, 0, 1, 2, 1, 0, 205, 38, 0, 0, 4, 79, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 7, 40, 0, 0, 236, 78, 0, 7, 4, 0, 0, 0, 1, 2, 1, 0, 72, 40, 0, 0, 254, 78, 0, 7, 5, 0, 0, 0, 1, 2, 1, 0, 194, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 0, 0, 0, 0, 36, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 44, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 44, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 52, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 52, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 60, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 60, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 68, 23, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 211, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 243, 2, 0, 0, 80, 79, 0, 7, 30, 0, 0, 0, 1, 0, 1, 0, 30, 3, 0, 0, 112, 79, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 73, 3, 0, 0, 20, 79, 0, 7, 60, 0, 0, 0, 1, 0, 1, 0, 115, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 147, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 179, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 211, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 243, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 48, 41, 0, 0, 236, 25, 0, 7, 228, 0, 0, 0, 2, 2, 1, 0, 19, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 51, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 83, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 109, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 78, 43, 0, 0, 100, 27, 0, 7, 184, 0, 0, 0, 2, 2, 1, 0, 154, 43, 0, 0, 28, 28, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 135, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 162, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 189, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 216, 4, 0, 0, 128, 79, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 225, 4, 0, 0, 24, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 233, 4, 0, 0, 11, 80, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 20, 5, 0, 0, 44, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 28, 5, 0, 0, 249, 79, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 71, 5, 0, 0, 64, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 79, 5, 0, 0, 229, 79, 0, 7, 20, 0, 0, 0, 1, 0, 1, 0, 122, 5, 0, 0, 84, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 130, 5, 0, 0, 211, 79, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 173, 5, 0, 0, 104, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 181, 5, 0, 0, 187, 79, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 224, 5, 0, 0, 124, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 232, 5, 0, 0, 175, 79, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 19, 6, 0, 0, 144, 29, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 27, 6, 0, 0, 156, 79, 0, 7, 19, 0, 0, 0, 1, 0, 1, 0, 80, 45, 0, 0, 236, 28, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 69, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 96, 6, 0, 0, 60, 30, 0, 7, 80, 0, 0, 0, 2, 0, 1, 0, 146, 6, 0, 0, 32, 80, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 188, 6, 0, 0, 140, 30, 0, 7, 236, 0, 0, 0, 2, 0, 1, 0, 254, 6, 0, 0, 32, 80, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 40, 7, 0, 0, 40, 80, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 8, 45, 0, 0, 164, 29, 0, 7, 152, 0, 0, 0, 2, 2, 1, 0, 82, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 109, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 135, 7, 0, 0, 20, 32, 0, 7, 180, 2, 0, 0, 2, 0, 1, 0, 198, 7, 0, 0, 228, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 240, 7, 0, 0, 161, 80, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 26, 8, 0, 0, 212, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 68, 8, 0, 0, 244, 80, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 110, 8, 0, 0, 65, 80, 0, 7, 96, 0, 0, 0, 1, 0, 1, 0, 152, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 57, 44, 0, 0, 208, 35, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 145, 44, 0, 0, 212, 35, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 199, 44, 0, 0, 4, 81, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 179, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 46, 47, 0, 0, 56, 36, 0, 7, 48, 0, 0, 0, 2, 2, 1, 0, 205, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 231, 8, 0, 0, 132, 81, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 17, 9, 0, 0, 176, 81, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 59, 9, 0, 0, 116, 81, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 101, 9, 0, 0, 39, 82, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 144, 9, 0, 0, 80, 82, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 187, 9, 0, 0, 20, 81, 0, 7, 94, 0, 0, 0, 1, 0, 1, 0, 229, 9, 0, 0, 192, 81, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 129, 47, 0, 0, 104, 36, 0, 7, 56, 4, 0, 0, 2, 2, 1, 0, 16, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 42, 10, 0, 0, 240, 40, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 130, 10, 0, 0, 96, 82, 0, 7, 39, 0, 0, 0, 1, 0, 1, 0, 172, 10, 0, 0, 136, 82, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 214, 10, 0, 0, 252, 82, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 0, 11, 0, 0, 152, 82, 0, 7, 98, 0, 0, 0, 1, 0, 1, 0, 137, 40, 0, 0, 244, 40, 0, 7, 208, 0, 0, 0, 2, 2, 1, 0, 148, 40, 0, 0, 196, 41, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 161, 40, 0, 0, 200, 41, 0, 7, 52, 1, 0, 0, 2, 2, 1, 0, 42, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 68, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 82, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 96, 11, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 108, 11, 0, 0, 8, 0, 0, 7, 0, 0, 0, 0, 0, 0, 1, 0, 61, 29, 0, 0, 244, 43, 0, 7, 12, 4, 0, 0, 2, 2, 1, 0, 113, 41, 0, 0, 252, 42, 0, 7, 248, 0, 0, 0, 2, 2, 1, 0, 120, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 146, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 171, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 197, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 222, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 247, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 17, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 42, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 67, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 92, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 113, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 134, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 155, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 176, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 197, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 219, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 241, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 6, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 34, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 63, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 82, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 101, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 120, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 146, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 172, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 198, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 224, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 250, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 21, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 56, 14, 0, 0, 147, 83, 0, 7, 40, 0, 0, 0, 1, 0, 1, 0, 98, 14, 0, 0, 187, 83, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 141, 14, 0, 0, 232, 83, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 184, 14, 0, 0, 248, 83, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 227, 14, 0, 0, 35, 84, 0, 7, 52, 0, 0, 0, 1, 0, 1, 0, 14, 15, 0, 0, 88, 84, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 57, 15, 0, 0, 104, 84, 0, 7, 44, 0, 0, 0, 1, 0, 1, 0, 100, 15, 0, 0, 0, 0, 8, 0, 4, 0, 0, 0, 1, 0, 2, 0, 177, 15, 0, 0, 148, 84, 0, 7, 14, 0, 0, 0, 1, 0, 1, 0, 220, 15, 0, 0, 164, 84, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 7, 16, 0, 0, 44, 83, 0, 7, 103, 0, 0, 0, 1, 0, 1, 0, 49, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 84, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 194, 52, 0, 0, 32, 49, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 28, 53, 0, 0, 216, 50, 0, 7, 172, 0, 0, 0, 2, 2, 1, 0, 104, 53, 0, 0, 252, 49, 0, 7, 220, 0, 0, 0, 2, 2, 1, 0, 181, 53, 0, 0, 180, 84, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 246, 53, 0, 0, 132, 85, 0, 7, 8, 0, 0, 0, 1, 2, 1, 0, 55, 54, 0, 0, 140, 85, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 120, 54, 0, 0, 180, 84, 0, 7, 191, 0, 0, 0, 1, 2, 1, 0, 185, 54, 0, 0, 115, 85, 0, 7, 17, 0, 0, 0, 1, 2, 1, 0, 105, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 127, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 201, 40, 0, 0, 224, 51, 0, 7, 12, 0, 0, 0, 2, 2, 1, 0, 149, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 171, 16, 0, 0, 236, 51, 0, 7, 4, 0, 0, 0, 2, 0, 1, 0, 243, 16, 0, 0, 156, 85, 0, 7, 51, 0, 0, 0, 1, 0, 1, 0, 29, 17, 0, 0, 208, 85, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 71, 17, 0, 0, 156, 86, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 113, 17, 0, 0, 224, 85, 0, 7, 187, 0, 0, 0, 1, 0, 1, 0, 155, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 177, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 106, 55, 0, 0, 172, 86, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 37, 56, 0, 0, 92, 53, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 151, 56, 0, 0, 96, 53, 0, 7, 100, 1, 0, 0, 2, 2, 1, 0, 8, 57, 0, 0, 196, 54, 0, 7, 100, 0, 0, 0, 2, 2, 1, 0, 119, 57, 0, 0, 40, 55, 0, 7, 144, 0, 0, 0, 2, 2, 1, 0, 198, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 219, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 230, 57, 0, 0, 150, 87, 0, 7, 200, 0, 0, 0, 1, 2, 1, 0, 40, 58, 0, 0, 132, 87, 0, 7, 16, 0, 0, 0, 1, 2, 1, 0, 106, 58, 0, 0, 148, 87, 0, 7, 2, 0, 0, 0, 1, 2, 1, 0, 172, 58, 0, 0, 196, 86, 0, 7, 190, 0, 0, 0, 1, 2, 1, 0, 239, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 4, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 25, 18, 0, 0, 160, 88, 0, 7, 24, 0, 0, 0, 1, 0, 1, 0, 68, 18, 0, 0, 94, 88, 0, 7, 21, 0, 0, 0, 1, 0, 1, 0, 111, 18, 0, 0, 115, 88, 0, 7, 43, 0, 0, 0, 1, 0, 1, 0, 154, 18, 0, 0, 158, 88, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 197, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 218, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 11, 61, 0, 0, 184, 88, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 76, 61, 0, 0, 188, 88, 0, 7, 24, 0, 0, 0, 1, 2, 1, 0, 142, 61, 0, 0, 184, 88, 0, 7, 1, 0, 0, 0, 1, 2, 1, 0, 239, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 4, 19, 0, 0, 212, 88, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 47, 19, 0, 0, 228, 88, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 90, 19, 0, 0, 224, 88, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 133, 19, 0, 0, 225, 88, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 208, 61, 0, 0, 148, 59, 0, 7, 20, 0, 0, 0, 2, 2, 1, 0, 176, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 197, 19, 0, 0, 52, 61, 0, 7, 32, 0, 0, 0, 2, 0, 1, 0, 246, 19, 0, 0, 84, 61, 0, 7, 72, 0, 0, 0, 2, 0, 1, 0, 44, 20, 0, 0, 244, 88, 0, 7, 8, 0, 0, 0, 1, 0, 1, 0, 86, 20, 0, 0, 244, 88, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 128, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 148, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 168, 20, 0, 0, 48, 89, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 211, 20, 0, 0, 252, 88, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 253, 20, 0, 0, 14, 89, 0, 7, 34, 0, 0, 0, 1, 0, 1, 0, 40, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 60, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 80, 21, 0, 0, 16, 68, 0, 7, 156, 0, 0, 0, 2, 0, 1, 0, 151, 21, 0, 0, 64, 89, 0, 7, 5, 0, 0, 0, 1, 0, 1, 0, 54, 60, 0, 0, 180, 72, 0, 7, 24, 0, 0, 0, 2, 2, 1, 0, 183, 60, 0, 0, 12, 62, 0, 7, 8, 0, 0, 0, 2, 2, 1, 0, 22, 62, 0, 0, 88, 72, 0, 7, 92, 0, 0, 0, 2, 2, 1, 0, 96, 62, 0, 0, 60, 72, 0, 7, 28, 0, 0, 0, 2, 2, 1, 0, 75, 63, 0, 0, 44, 72, 0, 7, 16, 0, 0, 0, 2, 2, 1, 0, 194, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 214, 21, 0, 0, 72, 89, 0, 7, 0, 0, 0, 0, 1, 0, 1, 0, 1, 22, 0, 0, 104, 89, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 44, 22, 0, 0, 140, 89, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 87, 22, 0, 0, 163, 89, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 130, 22, 0, 0, 165, 89, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 173, 22, 0, 0, 8, 90, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 216, 22, 0, 0, 156, 89, 0, 7, 7, 0, 0, 0, 1, 0, 1, 0, 3, 23, 0, 0, 228, 89, 0, 7, 32, 0, 0, 0, 1, 0, 1, 0, 46, 23, 0, 0, 44, 90, 0, 7, 16, 0, 0, 0, 1, 0, 1, 0, 89, 23, 0, 0, 60, 90, 0, 7, 11, 0, 0, 0, 1, 0, 1, 0, 133, 23, 0, 0, 120, 89, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 176, 23, 0, 0, 167, 89, 0, 7, 25, 0, 0, 0, 1, 0, 1, 0, 219, 23, 0, 0, 192, 89, 0, 7, 18, 0, 0, 0, 1, 0, 1, 0, 6, 24, 0, 0, 210, 89, 0, 7, 12, 0, 0, 0, 1, 0, 1, 0, 49, 24, 0, 0, 222, 89, 0, 7, 3, 0, 0, 0, 1, 0, 1, 0, 92, 24, 0, 0, 4, 90, 0, 7, 1, 0, 0, 0, 1, 0, 1, 0, 135, 24, 0, 0, 40, 90, 0, 7, 2, 0, 0, 0, 1, 0, 1, 0, 240, 63, 0, 0, 204, 72, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 72, 64, 0, 0, 204, 72, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 162, 64, 0, 0, 204, 72, 0, 7, 4, 0, 0, 0, 2, 2, 1, 0, 178, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 198, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 218, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 235, 59, 0, 0, 184, 75, 0, 7, 196, 1, 0, 0, 2, 2, 1, 0, 24, 65, 0, 0, 71, 90, 0, 7, 0, 0, 0, 0, 1, 2, 1, 0, 238, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 16, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 49, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 83, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 117, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 150, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 183, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 216, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 241, 255, 249, 25, 0, 0, 20, 0, 0, 7, 128, 0, 0, 0, 18, 0, 1, 0, 1, 26, 0, 0, 20, 17, 0, 7, 160, 3, 0, 0, 18, 0, 1, 0, 8, 26, 0, 0, 220, 48, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 24, 26, 0, 0, 40, 48, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 34, 26, 0, 0, 180, 48, 0, 7, 40, 0, 0, 0, 18, 0, 1, 0, 44, 26, 0, 0, 120, 31, 0, 7, 132, 0, 0, 0, 18, 0, 1, 0, 62, 26, 0, 0, 252, 31, 0, 7, 24, 0, 0, 0, 18, 0, 1, 0, 71, 26, 0, 0, 52, 23, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 86, 26, 0, 0, 36, 23, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 99, 26, 0, 0, 132, 51, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 156, 26, 0, 0, 200, 51, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 212, 26, 0, 0, 44, 23, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 227, 26, 0, 0, 176, 40, 0, 7, 64, 0, 0, 0, 18, 0, 1, 0, 68, 29, 0, 0, 4, 0, 8, 0, 80, 0, 0, 0, 17, 0, 3, 0, 35, 30, 0, 0, 168, 40, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 26, 31, 0, 0, 40, 75, 0, 7, 116, 0, 0, 0, 18, 0, 1, 0, 77, 31, 0, 0, 160, 40, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 207, 32, 0, 0, 12, 83, 0, 7, 32, 0, 0, 0, 17, 0, 1, 0, 73, 33, 0, 0, 112, 35, 0, 7, 96, 0, 0, 0, 18, 0, 1, 0, 192, 33, 0, 0, 248, 28, 0, 7, 172, 0, 0, 0, 18, 0, 1, 0, 97, 34, 0, 0, 24, 57, 0, 7, 104, 0, 0, 0, 18, 0, 1, 0, 199, 34, 0, 0, 224, 71, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 139, 35, 0, 0, 208, 26, 0, 7, 148, 0, 0, 0, 18, 0, 1, 0, 14, 39, 0, 0, 68, 23, 0, 7, 168, 2, 0, 0, 18, 0, 1, 0, 86, 39, 0, 0, 200, 34, 0, 7, 168, 0, 0, 0, 18, 0, 1, 0, 201, 39, 0, 0, 32, 48, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 174, 40, 0, 0, 60, 23, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 210, 40, 0, 0, 12, 73, 0, 7, 68, 0, 0, 0, 18, 0, 1, 0, 255, 40, 0, 0, 208, 72, 0, 7, 60, 0, 0, 0, 18, 0, 1, 0, 120, 41, 0, 0, 80, 73, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 179, 41, 0, 0, 124, 77, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 22, 42, 0, 0, 240, 71, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 82, 42, 0, 0, 184, 55, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 178, 42, 0, 0, 0, 72, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 238, 42, 0, 0, 104, 56, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 244, 43, 0, 0, 156, 61, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 154, 45, 0, 0, 168, 59, 0, 7, 128, 1, 0, 0, 18, 0, 1, 0, 1, 46, 0, 0, 240, 51, 0, 7, 108, 1, 0, 0, 18, 0, 1, 0, 56, 46, 0, 0, 0, 48, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 104, 46, 0, 0, 20, 48, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 242, 46, 0, 0, 180, 73, 0, 7, 116, 1, 0, 0, 18, 0, 1, 0, 184, 47, 0, 0, 12, 48, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 249, 47, 0, 0, 24, 48, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 55, 48, 0, 0, 104, 48, 0, 7, 76, 0, 0, 0, 18, 0, 1, 0, 124, 48, 0, 0, 156, 75, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 223, 48, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 1, 0, 230, 48, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 16, 0, 2, 0, 248, 48, 0, 0, 252, 255, 127, 0, 0, 0, 0, 0, 16, 0, 241, 255, 6, 49, 0, 0, 4, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 18, 49, 0, 0, 84, 0, 8, 0, 0, 0, 0, 0, 16, 0, 3, 0, 28, 49, 0, 0, 24, 48, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 153, 49, 0, 0, 32, 48, 0, 7, 8, 0, 0, 0, 18, 0, 1, 0, 23, 50, 0, 0, 20, 48, 0, 7, 4, 0, 0, 0, 18, 0, 1, 0, 166, 50, 0, 0, 184, 55, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 6, 51, 0, 0, 104, 56, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 102, 51, 0, 0, 124, 77, 0, 7, 20, 0, 0, 0, 18, 0, 1, 0, 203, 51, 0, 0, 184, 55, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 45, 52, 0, 0, 104, 56, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 143, 52, 0, 0, 40, 61, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 250, 54, 0, 0, 212, 51, 0, 7, 12, 0, 0, 0, 18, 0, 1, 0, 59, 55, 0, 0, 172, 68, 0, 7, 52, 3, 0, 0, 18, 0, 1, 0, 171, 55, 0, 0, 112, 62, 0, 7, 100, 2, 0, 0, 18, 0, 1, 0, 210, 55, 0, 0, 16, 72, 0, 7, 28, 0, 0, 0, 18, 0, 1, 0, 238, 58, 0, 0, 184, 55, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 80, 59, 0, 0, 104, 56, 0, 7, 176, 0, 0, 0, 18, 0, 1, 0, 178, 59, 0, 0, 212, 64, 0, 7, 60, 3, 0, 0, 18, 0, 1, 0, 128, 60, 0, 0, 128, 57, 0, 7, 20, 2, 0, 0, 18, 0, 1, 0, 170, 62, 0, 0, 168, 61, 0, 7, 100, 0, 0, 0, 18, 0, 1, 0, 242, 62, 0, 0, 20, 62, 0, 7, 92, 0, 0, 0, 18, 0, 1, 0, 147, 63, 0, 0, 224, 71, 0, 7, 16, 0, 0, 0, 18, 0, 1, 0, 90, 65, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 16, 0, 241, 255, 103, 65, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 16, 0, 241, 255, 114, 65, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 16, 0, 241, 255, 125, 65, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 16, 0, 241, 255, 0, 46, 116, 101, 120, 116, 0, 46, 100, 97, 116, 97, 0, 46, 98, 115, 115, 0, 46, 115, 121, 109, 116, 97, 98, 0, 46, 115, 104, 115, 116, 114, 116, 97, 98, 0, 46, 115, 116, 114, 116, 97, 98, 0, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 48, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 48, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 49, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 50, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 51, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 52, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 49, 53, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 50, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 51, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 52, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 53, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 54, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 55, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 56, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 51, 51, 49, 97, 53, 48, 101, 97, 57, 56, 55, 49, 99, 101, 56, 97, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 48, 99, 102, 51, 54, 99, 99, 48, 98, 57, 56, 56, 54, 49, 49, 57, 55, 99, 99, 55, 57, 101, 98, 57, 101, 55, 53, 56, 51, 48, 53, 46, 50, 0, 116, 117, 114, 110, 46, 50, 48, 48, 99, 99, 57, 102, 53, 45, 99, 103, 117, 46, 57, 0, 49, 118, 56, 57, 102, 120, 121, 97, 98, 117, 100, 56, 52, 111, 105, 98, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 99, 49, 98, 57, 50, 57, 57, 51, 51, 102, 101, 55, 51, 54, 50, 56, 102, 53, 55, 97, 97, 50, 100, 50, 101, 50, 57, 54, 49, 55, 50, 51, 46, 50, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 49, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 50, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 51, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 52, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 53, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 55, 0, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 99, 102, 56, 49, 57, 102, 54, 50, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 51, 0, 46, 76, 74, 84, 73, 54, 95, 48, 0, 46, 76, 66, 66, 54, 95, 49, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 53, 0, 46, 76, 66, 66, 54, 95, 50, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 52, 0, 46, 76, 66, 66, 54, 95, 51, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 51, 0, 46, 76, 66, 66, 54, 95, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 50, 0, 46, 76, 66, 66, 54, 95, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 49, 0, 46, 76, 66, 66, 54, 95, 54, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 49, 48, 0, 46, 76, 66, 66, 54, 95, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 99, 100, 48, 50, 55, 99, 54, 100, 55, 53, 55, 53, 101, 54, 49, 49, 56, 97, 52, 56, 49, 97, 97, 49, 54, 99, 97, 102, 49, 54, 102, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 52, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 65, 114, 103, 117, 109, 101, 110, 116, 115, 54, 97, 115, 95, 115, 116, 114, 49, 55, 104, 57, 48, 52, 56, 53, 49, 98, 51, 54, 101, 48, 101, 53, 51, 50, 102, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 53, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 84, 36, 71, 84, 36, 49, 49, 109, 97, 112, 95, 111, 114, 95, 101, 108, 115, 101, 49, 55, 104, 98, 48, 56, 50, 51, 53, 48, 48, 57, 56, 101, 100, 52, 57, 56, 52, 69, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 54, 52, 57, 51, 57, 55, 55, 48, 52, 102, 50, 98, 55, 49, 100, 49, 101, 100, 55, 99, 57, 54, 101, 57, 51, 55, 100, 54, 98, 97, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 50, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 115, 104, 97, 49, 53, 99, 111, 112, 121, 95, 97, 110, 100, 95, 117, 112, 100, 97, 116, 101, 49, 55, 104, 53, 56, 100, 57, 102, 57, 100, 54, 49, 52, 102, 57, 100, 102, 48, 50, 69, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 54, 0, 46, 76, 97, 110, 111, 110, 46, 54, 51, 97, 55, 50, 53, 98, 56, 52, 97, 56, 102, 101, 49, 51, 99, 101, 50, 100, 54, 53, 54, 49, 99, 99, 52, 51, 99, 52, 98, 97, 97, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 49, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 50, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 54, 100, 54, 48, 56, 54, 98, 101, 102, 99, 57, 102, 57, 100, 48, 50, 51, 99, 52, 101, 102, 101, 49, 49, 97, 102, 55, 57, 49, 99, 100, 52, 46, 49, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 55, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 51, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 48, 48, 55, 100, 48, 102, 101, 49, 54, 97, 100, 51, 55, 97, 50, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 51, 0, 46, 76, 97, 110, 111, 110, 46, 50, 51, 98, 48, 53, 100, 50, 100, 49, 97, 97, 98, 53, 100, 101, 100, 97, 51, 54, 101, 102, 48, 48, 49, 102, 101, 100, 48, 50, 53, 102, 48, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 56, 0, 109, 117, 115, 108, 95, 109, 101, 109, 115, 101, 116, 46, 99, 0, 109, 117, 115, 108, 95, 109, 101, 109, 99, 112, 121, 46, 99, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 48, 0, 46, 76, 112, 99, 114, 101, 108, 95, 104, 105, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 53, 52, 53, 49, 49, 48, 100, 53, 45, 99, 103, 117, 46, 57, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 52, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 49, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 53, 0, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 102, 99, 55, 55, 100, 99, 51, 56, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 48, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 54, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 50, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 49, 51, 0, 115, 101, 114, 100, 101, 46, 55, 50, 49, 57, 102, 54, 52, 53, 45, 99, 103, 117, 46, 56, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 122, 101, 114, 111, 105, 111, 46, 53, 57, 55, 97, 55, 49, 55, 100, 45, 99, 103, 117, 46, 49, 53, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 48, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 49, 0, 104, 101, 120, 46, 57, 52, 50, 101, 101, 51, 100, 50, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 48, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 51, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 50, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 54, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 0, 114, 105, 115, 99, 48, 95, 99, 111, 114, 101, 46, 101, 100, 52, 48, 53, 48, 57, 102, 45, 99, 103, 117, 46, 49, 51, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 54, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 49, 56, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 50, 48, 72, 69, 65, 80, 95, 87, 79, 82, 68, 83, 95, 82, 69, 77, 65, 73, 78, 73, 78, 71, 49, 55, 104, 53, 97, 101, 56, 98, 54, 55, 48, 102, 98, 54, 52, 50, 100, 55, 50, 69, 46, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 48, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 50, 0, 46, 76, 97, 110, 111, 110, 46, 51, 97, 48, 52, 48, 100, 99, 97, 102, 97, 100, 52, 98, 54, 101, 99, 54, 53, 54, 51, 49, 55, 57, 98, 48, 50, 57, 50, 101, 49, 97, 54, 46, 50, 0, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 46, 51, 55, 100, 57, 54, 48, 56, 49, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 48, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 55, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 102, 53, 51, 97, 51, 51, 52, 49, 54, 100, 57, 102, 57, 49, 53, 49, 69, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 52, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 50, 50, 51, 48, 48, 53, 50, 55, 50, 102, 48, 55, 51, 56, 99, 50, 56, 50, 55, 48, 57, 50, 57, 50, 54, 55, 57, 54, 98, 100, 99, 100, 46, 54, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 49, 49, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 51, 0, 97, 108, 108, 111, 99, 46, 53, 51, 48, 50, 52, 97, 99, 53, 45, 99, 103, 117, 46, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 52, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 49, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 50, 0, 46, 76, 97, 110, 111, 110, 46, 102, 48, 99, 100, 98, 57, 48, 55, 54, 55, 102, 102, 99, 56, 101, 49, 49, 48, 101, 102, 52, 56, 56, 53, 54, 52, 50, 54, 51, 51, 52, 97, 46, 51, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 52, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 55, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 53, 0, 46, 76, 97, 110, 111, 110, 46, 55, 54, 98, 54, 49, 57, 48, 97, 54, 56, 51, 55, 99, 99, 98, 50, 53, 99, 100, 57, 97, 97, 56, 102, 48, 98, 51, 51, 48, 101, 101, 102, 46, 49, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 115, 116, 114, 49, 55, 104, 56, 102, 56, 55, 54, 57, 49, 102, 48, 101, 102, 98, 98, 51, 56, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 112, 97, 110, 105, 99, 95, 100, 105, 115, 112, 108, 97, 121, 49, 55, 104, 98, 55, 101, 99, 99, 49, 53, 99, 56, 57, 48, 97, 55, 99, 98, 54, 69, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 50, 0, 46, 76, 97, 110, 111, 110, 46, 97, 51, 48, 98, 51, 51, 48, 57, 98, 48, 51, 50, 102, 50, 57, 97, 97, 55, 54, 56, 52, 101, 101, 98, 99, 56, 48, 51, 99, 53, 101, 55, 46, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 52, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 53, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 49, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 57, 0, 46, 76, 97, 110, 111, 110, 46, 54, 54, 52, 98, 57, 99, 54, 48, 98, 53, 101, 52, 49, 52, 97, 48, 49, 48, 101, 57, 49, 48, 102, 53, 50, 98, 51, 99, 57, 52, 101, 50, 46, 49, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 49, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 54, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 50, 119, 114, 105, 116, 101, 95, 112, 114, 101, 102, 105, 120, 49, 55, 104, 55, 57, 52, 99, 51, 54, 55, 50, 50, 55, 97, 56, 51, 100, 102, 55, 69, 0, 46, 76, 97, 110, 111, 110, 46, 100, 53, 52, 100, 48, 97, 55, 55, 56, 48, 102, 48, 54, 49, 52, 101, 53, 50, 52, 56, 98, 50, 50, 99, 51, 100, 100, 101, 50, 98, 55, 98, 46, 50, 48, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 50, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 56, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 53, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 54, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 55, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 49, 49, 51, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 52, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 53, 57, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 48, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 49, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 50, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 52, 0, 46, 76, 97, 110, 111, 110, 46, 98, 97, 57, 102, 48, 101, 101, 53, 53, 98, 101, 100, 55, 57, 54, 53, 98, 49, 100, 102, 102, 57, 99, 49, 97, 53, 101, 52, 101, 97, 57, 101, 46, 54, 54, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 56, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 51, 0, 99, 111, 114, 101, 46, 57, 51, 97, 54, 97, 101, 57, 49, 45, 99, 103, 117, 46, 57, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 48, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 49, 53, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 50, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 51, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 52, 0, 99, 111, 109, 112, 105, 108, 101, 114, 95, 98, 117, 105, 108, 116, 105, 110, 115, 46, 57, 102, 98, 57, 51, 101, 102, 56, 45, 99, 103, 117, 46, 53, 0, 95, 95, 115, 116, 97, 114, 116, 0, 95, 95, 109, 97, 105, 110, 0, 115, 121, 115, 95, 97, 108, 108, 111, 99, 95, 119, 111, 114, 100, 115, 0, 115, 121, 115, 95, 112, 97, 110, 105, 99, 0, 115, 121, 115, 95, 119, 114, 105, 116, 101, 0, 114, 117, 115, 116, 95, 98, 101, 103, 105, 110, 95, 117, 110, 119, 105, 110, 100, 0, 114, 117, 115, 116, 95, 111, 111, 109, 0, 95, 95, 114, 117, 115, 116, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 55, 99, 97, 112, 97, 99, 105, 116, 121, 95, 111, 118, 101, 114, 102, 108, 111, 119, 49, 55, 104, 51, 97, 54, 100, 97, 51, 54, 102, 48, 52, 56, 102, 97, 55, 49, 57, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 49, 55, 104, 97, 52, 102, 49, 48, 54, 99, 53, 53, 53, 50, 49, 57, 48, 52, 52, 69, 0, 95, 95, 114, 117, 115, 116, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 90, 78, 57, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 101, 110, 118, 46, 46, 70, 100, 82, 101, 97, 100, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 82, 101, 97, 100, 36, 71, 84, 36, 49, 48, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 100, 57, 53, 99, 57, 99, 101, 101, 102, 98, 52, 98, 102, 50, 101, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 55, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 116, 117, 112, 108, 101, 49, 55, 104, 53, 102, 51, 50, 48, 48, 48, 102, 101, 100, 49, 97, 54, 55, 55, 102, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 51, 99, 56, 51, 56, 54, 52, 57, 99, 97, 100, 56, 53, 48, 99, 54, 69, 0, 95, 90, 78, 49, 49, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 82, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 100, 101, 46, 46, 68, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 49, 56, 100, 101, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 115, 116, 114, 117, 99, 116, 49, 55, 104, 52, 52, 57, 50, 57, 102, 56, 50, 100, 97, 56, 48, 99, 54, 100, 97, 69, 0, 109, 101, 109, 99, 112, 121, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 54, 72, 65, 83, 72, 69, 82, 49, 55, 104, 99, 102, 102, 99, 102, 101, 49, 57, 48, 51, 102, 49, 98, 53, 50, 97, 69, 0, 95, 90, 78, 56, 50, 95, 36, 76, 84, 36, 100, 105, 103, 101, 115, 116, 46, 46, 99, 111, 114, 101, 95, 97, 112, 105, 46, 46, 119, 114, 97, 112, 112, 101, 114, 46, 46, 67, 111, 114, 101, 87, 114, 97, 112, 112, 101, 114, 36, 76, 84, 36, 84, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 100, 105, 103, 101, 115, 116, 46, 46, 85, 112, 100, 97, 116, 101, 36, 71, 84, 36, 54, 117, 112, 100, 97, 116, 101, 50, 56, 95, 36, 117, 55, 98, 36, 36, 117, 55, 98, 36, 99, 108, 111, 115, 117, 114, 101, 36, 117, 55, 100, 36, 36, 117, 55, 100, 36, 49, 55, 104, 99, 48, 98, 98, 56, 51, 54, 99, 55, 102, 98, 55, 101, 48, 100, 97, 69, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 53, 115, 116, 100, 105, 110, 49, 55, 104, 55, 97, 49, 48, 101, 48, 49, 101, 48, 56, 102, 98, 102, 97, 98, 48, 69, 0, 97, 110, 111, 110, 46, 53, 54, 52, 99, 56, 51, 55, 55, 102, 102, 56, 51, 57, 55, 98, 98, 52, 101, 56, 57, 101, 54, 50, 54, 51, 57, 52, 53, 102, 100, 101, 101, 46, 52, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 97, 110, 111, 110, 46, 53, 54, 52, 99, 56, 51, 55, 55, 102, 102, 56, 51, 57, 55, 98, 98, 52, 101, 56, 57, 101, 54, 50, 54, 51, 57, 52, 53, 102, 100, 101, 101, 46, 53, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 97, 110, 111, 110, 46, 53, 54, 52, 99, 56, 51, 55, 55, 102, 102, 56, 51, 57, 55, 98, 98, 52, 101, 56, 57, 101, 54, 50, 54, 51, 57, 52, 53, 102, 100, 101, 101, 46, 55, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 114, 101, 115, 117, 108, 116, 49, 51, 117, 110, 119, 114, 97, 112, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 100, 100, 48, 102, 48, 100, 50, 48, 49, 50, 102, 98, 49, 48, 50, 50, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 55, 106, 111, 117, 114, 110, 97, 108, 49, 55, 104, 99, 97, 56, 56, 49, 100, 99, 98, 55, 55, 97, 53, 99, 48, 57, 102, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 95, 57, 49, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 97, 98, 105, 108, 105, 116, 105, 101, 115, 46, 46, 83, 104, 111, 116, 67, 111, 109, 109, 105, 116, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 102, 99, 53, 101, 97, 55, 48, 98, 102, 101, 55, 49, 98, 98, 98, 56, 69, 0, 97, 110, 111, 110, 46, 53, 54, 52, 99, 56, 51, 55, 55, 102, 102, 56, 51, 57, 55, 98, 98, 52, 101, 56, 57, 101, 54, 50, 54, 51, 57, 52, 53, 102, 100, 101, 101, 46, 56, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 71, 84, 36, 49, 55, 104, 51, 51, 49, 97, 53, 48, 101, 97, 57, 56, 55, 49, 99, 101, 56, 97, 69, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 49, 49, 83, 72, 65, 50, 53, 54, 95, 73, 78, 73, 84, 49, 55, 104, 99, 101, 102, 102, 54, 52, 52, 49, 100, 52, 48, 52, 51, 54, 101, 55, 69, 0, 97, 110, 111, 110, 46, 56, 51, 99, 52, 102, 102, 56, 56, 51, 48, 99, 100, 98, 55, 54, 56, 57, 57, 52, 55, 97, 52, 54, 56, 52, 102, 52, 51, 57, 53, 56, 51, 46, 48, 46, 108, 108, 118, 109, 46, 49, 50, 50, 51, 49, 54, 56, 57, 56, 56, 52, 50, 56, 52, 53, 50, 55, 55, 48, 56, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 52, 99, 111, 109, 112, 114, 101, 115, 115, 95, 115, 108, 105, 99, 101, 49, 55, 104, 101, 97, 53, 50, 55, 55, 52, 48, 97, 54, 54, 57, 97, 56, 57, 99, 69, 0, 95, 90, 78, 54, 54, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 101, 114, 114, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 51, 54, 56, 54, 52, 99, 100, 102, 102, 98, 48, 57, 49, 57, 100, 102, 69, 0, 97, 110, 111, 110, 46, 53, 54, 52, 99, 56, 51, 55, 55, 102, 102, 56, 51, 57, 55, 98, 98, 52, 101, 56, 57, 101, 54, 50, 54, 51, 57, 52, 53, 102, 100, 101, 101, 46, 54, 46, 108, 108, 118, 109, 46, 53, 53, 57, 54, 51, 48, 51, 55, 57, 57, 56, 55, 50, 50, 52, 49, 57, 53, 57, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 108, 101, 110, 95, 109, 105, 115, 109, 97, 116, 99, 104, 95, 102, 97, 105, 108, 49, 55, 104, 56, 53, 97, 99, 100, 55, 48, 52, 54, 48, 98, 101, 49, 97, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 50, 55, 99, 57, 48, 49, 55, 52, 48, 98, 101, 97, 48, 99, 55, 54, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 115, 116, 97, 116, 101, 49, 95, 56, 49, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 115, 116, 97, 116, 101, 46, 46, 83, 104, 105, 112, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 101, 53, 57, 53, 52, 52, 57, 51, 51, 100, 98, 100, 51, 100, 98, 101, 69, 0, 95, 90, 78, 56, 55, 95, 36, 76, 84, 36, 97, 108, 108, 111, 99, 46, 46, 118, 101, 99, 46, 46, 86, 101, 99, 36, 76, 84, 36, 117, 51, 50, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 87, 111, 114, 100, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 49, 119, 114, 105, 116, 101, 95, 119, 111, 114, 100, 115, 49, 55, 104, 52, 56, 99, 97, 52, 54, 100, 53, 56, 99, 98, 53, 56, 97, 97, 54, 69, 0, 95, 90, 78, 49, 48, 53, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 76, 84, 36, 87, 36, 71, 84, 36, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 114, 36, 71, 84, 36, 50, 53, 115, 101, 114, 105, 97, 108, 105, 122, 101, 95, 110, 101, 119, 116, 121, 112, 101, 95, 118, 97, 114, 105, 97, 110, 116, 49, 55, 104, 100, 51, 54, 99, 101, 48, 55, 54, 55, 50, 48, 52, 56, 55, 48, 98, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 115, 101, 114, 100, 101, 49, 48, 115, 101, 114, 105, 97, 108, 105, 122, 101, 114, 54, 116, 111, 95, 118, 101, 99, 49, 55, 104, 98, 100, 99, 98, 100, 53, 51, 57, 97, 51, 49, 57, 49, 101, 52, 55, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 49, 95, 56, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 116, 121, 112, 101, 115, 46, 46, 80, 111, 115, 105, 116, 105, 111, 110, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 101, 55, 101, 53, 56, 99, 102, 57, 54, 102, 56, 48, 48, 102, 57, 100, 69, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 53, 116, 121, 112, 101, 115, 49, 95, 56, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 115, 101, 114, 100, 101, 46, 46, 115, 101, 114, 46, 46, 83, 101, 114, 105, 97, 108, 105, 122, 101, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 46, 46, 116, 121, 112, 101, 115, 46, 46, 72, 105, 116, 84, 121, 112, 101, 36, 71, 84, 36, 57, 115, 101, 114, 105, 97, 108, 105, 122, 101, 49, 55, 104, 99, 97, 102, 56, 50, 98, 52, 48, 51, 53, 98, 50, 50, 48, 102, 101, 69, 0, 97, 110, 111, 110, 46, 102, 55, 51, 53, 55, 52, 52, 56, 98, 53, 51, 56, 53, 56, 49, 100, 56, 50, 55, 51, 49, 99, 98, 56, 102, 55, 98, 54, 55, 53, 54, 55, 46, 48, 46, 108, 108, 118, 109, 46, 51, 56, 52, 54, 57, 48, 52, 50, 49, 57, 54, 56, 51, 56, 57, 55, 49, 54, 48, 0, 97, 110, 111, 110, 46, 102, 55, 51, 53, 55, 52, 52, 56, 98, 53, 51, 56, 53, 56, 49, 100, 56, 50, 55, 51, 49, 99, 98, 56, 102, 55, 98, 54, 55, 53, 54, 55, 46, 49, 46, 108, 108, 118, 109, 46, 51, 56, 52, 54, 57, 48, 52, 50, 49, 57, 54, 56, 51, 56, 57, 55, 49, 54, 48, 0, 97, 110, 111, 110, 46, 51, 98, 99, 56, 49, 98, 55, 99, 97, 98, 98, 57, 49, 50, 97, 55, 101, 99, 101, 102, 102, 101, 100, 49, 52, 98, 48, 97, 52, 98, 50, 56, 46, 53, 46, 108, 108, 118, 109, 46, 53, 55, 48, 56, 55, 57, 52, 49, 57, 48, 53, 55, 49, 55, 57, 49, 53, 48, 57, 0, 97, 110, 111, 110, 46, 51, 98, 99, 56, 49, 98, 55, 99, 97, 98, 98, 57, 49, 50, 97, 55, 101, 99, 101, 102, 102, 101, 100, 49, 52, 98, 48, 97, 52, 98, 50, 56, 46, 55, 46, 108, 108, 118, 109, 46, 53, 55, 48, 56, 55, 57, 52, 49, 57, 48, 53, 55, 49, 55, 57, 49, 53, 48, 57, 0, 95, 90, 78, 49, 54, 115, 97, 103, 105, 116, 116, 97, 114, 105, 117, 115, 95, 103, 97, 109, 101, 57, 97, 98, 105, 108, 105, 116, 105, 101, 115, 49, 48, 83, 104, 111, 116, 80, 97, 114, 97, 109, 115, 55, 112, 114, 111, 99, 101, 115, 115, 49, 55, 104, 54, 57, 98, 56, 55, 99, 100, 52, 52, 97, 51, 50, 55, 99, 99, 50, 69, 0, 95, 90, 78, 55, 55, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 46, 46, 103, 117, 101, 115, 116, 46, 46, 115, 104, 97, 46, 46, 73, 109, 112, 108, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 115, 104, 97, 46, 46, 83, 104, 97, 50, 53, 54, 36, 71, 84, 36, 49, 48, 104, 97, 115, 104, 95, 119, 111, 114, 100, 115, 49, 55, 104, 102, 54, 98, 53, 57, 100, 57, 100, 53, 102, 56, 57, 51, 102, 97, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 119, 111, 114, 100, 115, 49, 55, 104, 50, 99, 101, 48, 48, 49, 101, 102, 48, 99, 56, 50, 98, 55, 102, 97, 69, 0, 97, 110, 111, 110, 46, 51, 98, 99, 56, 49, 98, 55, 99, 97, 98, 98, 57, 49, 50, 97, 55, 101, 99, 101, 102, 102, 101, 100, 49, 52, 98, 48, 97, 52, 98, 50, 56, 46, 51, 46, 108, 108, 118, 109, 46, 53, 55, 48, 56, 55, 57, 52, 49, 57, 48, 53, 55, 49, 55, 57, 49, 53, 48, 57, 0, 97, 110, 111, 110, 46, 51, 98, 99, 56, 49, 98, 55, 99, 97, 98, 98, 57, 49, 50, 97, 55, 101, 99, 101, 102, 102, 101, 100, 49, 52, 98, 48, 97, 52, 98, 50, 56, 46, 54, 46, 108, 108, 118, 109, 46, 53, 55, 48, 56, 55, 57, 52, 49, 57, 48, 53, 55, 49, 55, 57, 49, 53, 48, 57, 0, 95, 95, 114, 103, 95, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 100, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 103, 95, 114, 101, 97, 108, 108, 111, 99, 0, 95, 95, 114, 117, 115, 116, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 95, 104, 97, 110, 100, 108, 101, 114, 0, 95, 95, 114, 103, 95, 111, 111, 109, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 53, 112, 97, 110, 105, 99, 49, 55, 104, 98, 53, 56, 56, 101, 101, 51, 56, 100, 101, 101, 56, 99, 101, 49, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 57, 112, 97, 110, 105, 99, 95, 102, 109, 116, 49, 55, 104, 98, 48, 52, 49, 102, 98, 52, 56, 101, 100, 102, 57, 98, 99, 57, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 97, 114, 114, 97, 121, 50, 56, 99, 111, 108, 108, 101, 99, 116, 95, 105, 110, 116, 111, 95, 97, 114, 114, 97, 121, 95, 117, 110, 99, 104, 101, 99, 107, 101, 100, 49, 55, 104, 54, 99, 101, 99, 49, 53, 102, 98, 98, 56, 50, 97, 56, 56, 57, 56, 69, 0, 109, 101, 109, 115, 101, 116, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 56, 112, 97, 110, 105, 99, 95, 98, 111, 117, 110, 100, 115, 95, 99, 104, 101, 99, 107, 49, 55, 104, 101, 49, 55, 98, 101, 97, 53, 51, 52, 49, 101, 57, 51, 99, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 50, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 55, 55, 51, 101, 100, 54, 52, 97, 57, 99, 53, 55, 55, 55, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 108, 111, 119, 101, 114, 95, 104, 101, 120, 49, 55, 104, 53, 102, 102, 98, 50, 50, 56, 100, 53, 99, 100, 48, 57, 52, 102, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 101, 52, 101, 52, 49, 54, 52, 99, 48, 102, 97, 48, 98, 55, 99, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 53, 100, 101, 98, 117, 103, 95, 117, 112, 112, 101, 114, 95, 104, 101, 120, 49, 55, 104, 99, 54, 57, 97, 98, 98, 57, 98, 53, 55, 100, 97, 52, 54, 97, 102, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 98, 48, 53, 55, 102, 49, 52, 53, 56, 98, 102, 53, 52, 56, 49, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 48, 56, 53, 102, 50, 55, 48, 98, 56, 52, 100, 52, 48, 48, 53, 49, 69, 46, 108, 108, 118, 109, 46, 49, 55, 52, 49, 49, 55, 56, 49, 54, 49, 56, 51, 55, 48, 48, 48, 50, 54, 48, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 48, 100, 50, 99, 57, 57, 56, 98, 50, 97, 54, 53, 53, 98, 50, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 54, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 49, 55, 104, 53, 57, 51, 101, 48, 102, 54, 97, 99, 101, 100, 50, 54, 49, 57, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 51, 50, 36, 71, 84, 36, 49, 55, 104, 51, 97, 48, 53, 49, 52, 101, 51, 97, 53, 97, 57, 98, 99, 53, 57, 69, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 51, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 57, 98, 50, 98, 52, 56, 100, 54, 49, 100, 51, 53, 99, 50, 102, 54, 69, 0, 97, 110, 111, 110, 46, 53, 56, 100, 98, 52, 56, 57, 56, 51, 98, 51, 51, 57, 100, 99, 99, 49, 52, 51, 57, 57, 56, 54, 56, 56, 52, 54, 98, 48, 100, 51, 51, 46, 50, 46, 108, 108, 118, 109, 46, 49, 50, 53, 54, 55, 50, 53, 53, 55, 50, 54, 50, 50, 51, 50, 53, 51, 48, 51, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 56, 52, 54, 55, 97, 49, 56, 50, 56, 99, 97, 52, 48, 55, 102, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 53, 102, 55, 53, 51, 56, 49, 51, 48, 49, 100, 48, 56, 57, 54, 69, 0, 95, 90, 78, 55, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 112, 97, 110, 105, 99, 46, 46, 112, 97, 110, 105, 99, 95, 105, 110, 102, 111, 46, 46, 80, 97, 110, 105, 99, 73, 110, 102, 111, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 55, 48, 101, 100, 53, 55, 49, 51, 52, 101, 101, 99, 100, 98, 54, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 51, 102, 109, 116, 54, 102, 111, 114, 109, 97, 116, 49, 50, 102, 111, 114, 109, 97, 116, 95, 105, 110, 110, 101, 114, 49, 55, 104, 101, 57, 53, 100, 99, 50, 52, 102, 97, 101, 100, 55, 54, 50, 99, 51, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 53, 97, 98, 111, 114, 116, 49, 55, 104, 102, 54, 51, 57, 57, 56, 54, 57, 51, 55, 48, 98, 50, 102, 98, 49, 69, 0, 95, 90, 78, 49, 48, 52, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 48, 55, 51, 54, 98, 52, 102, 53, 99, 51, 102, 49, 100, 101, 48, 97, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 57, 112, 97, 110, 105, 99, 107, 105, 110, 103, 49, 57, 97, 115, 115, 101, 114, 116, 95, 102, 97, 105, 108, 101, 100, 95, 105, 110, 110, 101, 114, 49, 55, 104, 102, 102, 99, 57, 99, 100, 53, 48, 54, 54, 102, 55, 97, 56, 53, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 50, 57, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 36, 117, 53, 98, 36, 84, 36, 117, 53, 100, 36, 36, 71, 84, 36, 49, 53, 99, 111, 112, 121, 95, 102, 114, 111, 109, 95, 115, 108, 105, 99, 101, 49, 55, 104, 50, 52, 57, 99, 99, 102, 53, 97, 101, 101, 101, 51, 51, 53, 51, 57, 69, 0, 95, 90, 78, 49, 48, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 53, 103, 117, 101, 115, 116, 51, 101, 110, 118, 56, 102, 105, 110, 97, 108, 105, 122, 101, 49, 55, 104, 55, 49, 56, 100, 99, 99, 53, 101, 98, 101, 54, 54, 99, 100, 54, 52, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 51, 115, 104, 97, 53, 66, 108, 111, 99, 107, 49, 52, 97, 115, 95, 104, 97, 108, 102, 95, 98, 108, 111, 99, 107, 115, 49, 55, 104, 50, 49, 51, 97, 53, 101, 53, 98, 55, 48, 97, 55, 99, 50, 51, 57, 69, 0, 95, 90, 78, 57, 114, 105, 115, 99, 48, 95, 122, 107, 112, 52, 99, 111, 114, 101, 54, 100, 105, 103, 101, 115, 116, 54, 68, 105, 103, 101, 115, 116, 56, 97, 115, 95, 98, 121, 116, 101, 115, 49, 55, 104, 52, 54, 99, 57, 99, 98, 51, 56, 99, 49, 57, 99, 101, 102, 52, 51, 69, 0, 95, 90, 78, 49, 57, 114, 105, 115, 99, 48, 95, 122, 107, 118, 109, 95, 112, 108, 97, 116, 102, 111, 114, 109, 55, 115, 121, 115, 99, 97, 108, 108, 49, 52, 115, 121, 115, 95, 114, 101, 97, 100, 95, 119, 111, 114, 100, 115, 49, 55, 104, 49, 55, 102, 102, 56, 98, 97, 100, 99, 52, 56, 102, 102, 55, 53, 102, 69, 0, 95, 90, 78, 54, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 97, 108, 108, 111, 99, 46, 46, 108, 97, 121, 111, 117, 116, 46, 46, 76, 97, 121, 111, 117, 116, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 54, 101, 57, 99, 56, 53, 49, 57, 51, 98, 52, 102, 102, 102, 97, 52, 69, 0, 95, 115, 116, 97, 114, 116, 0, 95, 95, 103, 108, 111, 98, 97, 108, 95, 112, 111, 105, 110, 116, 101, 114, 36, 0, 95, 95, 115, 116, 97, 99, 107, 95, 105, 110, 105, 116, 36, 0, 95, 95, 98, 115, 115, 95, 98, 101, 103, 105, 110, 0, 95, 95, 98, 115, 115, 95, 101, 110, 100, 0, 95, 90, 78, 57, 50, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 56, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 97, 101, 97, 52, 99, 54, 50, 97, 56, 102, 48, 101, 98, 101, 48, 51, 69, 0, 95, 90, 78, 57, 51, 95, 36, 76, 84, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 65, 115, 82, 101, 102, 36, 76, 84, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 54, 97, 115, 95, 114, 101, 102, 49, 55, 104, 102, 97, 49, 53, 57, 56, 57, 49, 55, 57, 55, 57, 99, 51, 102, 51, 69, 0, 95, 90, 78, 49, 49, 49, 95, 36, 76, 84, 36, 36, 82, 70, 36, 114, 105, 115, 99, 48, 95, 122, 107, 112, 46, 46, 99, 111, 114, 101, 46, 46, 100, 105, 103, 101, 115, 116, 46, 46, 68, 105, 103, 101, 115, 116, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 99, 111, 110, 118, 101, 114, 116, 46, 46, 70, 114, 111, 109, 36, 76, 84, 36, 36, 82, 70, 36, 36, 117, 53, 98, 36, 117, 51, 50, 36, 117, 51, 98, 36, 36, 117, 50, 48, 36, 95, 36, 117, 53, 100, 36, 36, 71, 84, 36, 36, 71, 84, 36, 52, 102, 114, 111, 109, 49, 55, 104, 50, 102, 54, 54, 100, 100, 54, 51, 51, 102, 57, 57, 102, 52, 53, 56, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 55, 52, 57, 99, 100, 101, 55, 55, 99, 51, 54, 98, 50, 102, 51, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 51, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 51, 50, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 57, 55, 97, 48, 56, 102, 50, 50, 50, 56, 50, 48, 98, 98, 97, 50, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 53, 52, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 49, 54, 100, 101, 57, 48, 55, 50, 48, 54, 53, 49, 99, 101, 49, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 50, 101, 51, 98, 48, 56, 102, 49, 50, 100, 51, 49, 101, 97, 99, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 52, 100, 54, 99, 55, 100, 100, 102, 100, 101, 101, 54, 50, 49, 53, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 54, 111, 112, 116, 105, 111, 110, 49, 51, 101, 120, 112, 101, 99, 116, 95, 102, 97, 105, 108, 101, 100, 49, 55, 104, 98, 97, 50, 55, 98, 53, 97, 97, 97, 56, 54, 54, 53, 53, 52, 101, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 55, 114, 101, 115, 101, 114, 118, 101, 50, 49, 100, 111, 95, 114, 101, 115, 101, 114, 118, 101, 95, 97, 110, 100, 95, 104, 97, 110, 100, 108, 101, 49, 55, 104, 52, 98, 57, 101, 97, 101, 98, 50, 97, 55, 55, 100, 56, 99, 54, 102, 69, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 49, 102, 105, 110, 105, 115, 104, 95, 103, 114, 111, 119, 49, 55, 104, 52, 57, 97, 52, 56, 100, 48, 55, 97, 55, 101, 48, 53, 48, 101, 102, 69, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 55, 114, 97, 119, 95, 118, 101, 99, 49, 57, 82, 97, 119, 86, 101, 99, 36, 76, 84, 36, 84, 36, 67, 36, 65, 36, 71, 84, 36, 49, 54, 114, 101, 115, 101, 114, 118, 101, 95, 102, 111, 114, 95, 112, 117, 115, 104, 49, 55, 104, 97, 99, 101, 98, 50, 53, 57, 51, 55, 48, 52, 98, 48, 101, 52, 98, 69, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 48, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 54, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 55, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 51, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 97, 110, 111, 110, 46, 57, 50, 48, 50, 51, 53, 55, 101, 48, 48, 56, 49, 100, 98, 54, 57, 97, 55, 49, 99, 55, 99, 99, 49, 54, 102, 53, 102, 57, 56, 56, 48, 46, 53, 46, 108, 108, 118, 109, 46, 52, 57, 56, 54, 49, 55, 53, 57, 56, 54, 55, 49, 48, 57, 57, 51, 49, 53, 57, 0, 95, 90, 78, 53, 97, 108, 108, 111, 99, 53, 97, 108, 108, 111, 99, 49, 56, 104, 97, 110, 100, 108, 101, 95, 97, 108, 108, 111, 99, 95, 101, 114, 114, 111, 114, 56, 114, 116, 95, 101, 114, 114, 111, 114, 49, 55, 104, 48, 48, 101, 99, 48, 48, 50, 56, 53, 48, 56, 49, 48, 97, 101, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 51, 112, 97, 100, 49, 55, 104, 50, 53, 101, 50, 98, 101, 49, 57, 49, 53, 56, 57, 99, 99, 54, 101, 69, 0, 97, 110, 111, 110, 46, 98, 53, 102, 54, 55, 54, 57, 56, 98, 97, 57, 50, 52, 49, 102, 49, 54, 49, 53, 54, 48, 55, 56, 52, 50, 50, 57, 50, 101, 50, 56, 50, 46, 48, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 53, 119, 114, 105, 116, 101, 49, 55, 104, 51, 49, 102, 98, 50, 57, 102, 50, 98, 100, 55, 57, 101, 102, 56, 101, 69, 0, 95, 90, 78, 53, 51, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 69, 114, 114, 111, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 53, 100, 55, 50, 48, 55, 55, 49, 101, 53, 54, 55, 49, 102, 102, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 52, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 97, 108, 108, 111, 99, 46, 46, 115, 116, 114, 105, 110, 103, 46, 46, 83, 116, 114, 105, 110, 103, 36, 71, 84, 36, 49, 55, 104, 57, 54, 102, 100, 56, 52, 56, 51, 48, 54, 56, 48, 101, 53, 56, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 49, 48, 119, 114, 105, 116, 101, 95, 99, 104, 97, 114, 49, 55, 104, 98, 97, 51, 53, 52, 100, 49, 49, 100, 98, 100, 54, 49, 50, 48, 57, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 102, 109, 116, 49, 55, 104, 53, 53, 57, 55, 97, 55, 51, 48, 98, 101, 50, 97, 101, 98, 51, 55, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 95, 90, 78, 53, 48, 95, 36, 76, 84, 36, 36, 82, 70, 36, 109, 117, 116, 36, 117, 50, 48, 36, 87, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 48, 54, 97, 98, 51, 97, 57, 100, 102, 50, 98, 101, 50, 99, 102, 99, 69, 46, 108, 108, 118, 109, 46, 50, 48, 53, 56, 53, 48, 53, 53, 48, 50, 57, 53, 53, 50, 53, 57, 56, 53, 53, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 53, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 49, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 97, 110, 111, 110, 46, 53, 99, 51, 51, 48, 100, 49, 56, 101, 99, 102, 55, 52, 55, 53, 57, 101, 98, 52, 57, 48, 100, 100, 51, 57, 100, 57, 98, 97, 51, 102, 54, 46, 48, 46, 108, 108, 118, 109, 46, 49, 51, 49, 52, 52, 52, 49, 50, 55, 56, 52, 48, 52, 57, 54, 56, 56, 49, 49, 48, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 76, 111, 119, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 48, 97, 100, 50, 102, 99, 56, 99, 100, 100, 97, 51, 99, 55, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 53, 53, 95, 36, 76, 84, 36, 105, 109, 112, 108, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 85, 112, 112, 101, 114, 72, 101, 120, 36, 117, 50, 48, 36, 102, 111, 114, 36, 117, 50, 48, 36, 105, 115, 105, 122, 101, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 98, 53, 50, 57, 53, 48, 102, 51, 52, 101, 102, 100, 99, 50, 54, 52, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 57, 70, 111, 114, 109, 97, 116, 116, 101, 114, 49, 50, 112, 97, 100, 95, 105, 110, 116, 101, 103, 114, 97, 108, 49, 55, 104, 48, 49, 99, 51, 54, 52, 50, 56, 49, 97, 101, 56, 102, 50, 100, 101, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 102, 109, 116, 51, 110, 117, 109, 51, 105, 109, 112, 55, 102, 109, 116, 95, 117, 51, 50, 49, 55, 104, 98, 102, 50, 55, 97, 100, 102, 99, 49, 51, 97, 100, 98, 102, 57, 50, 69, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 102, 55, 54, 51, 101, 49, 54, 100, 101, 102, 51, 98, 53, 56, 52, 57, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 115, 116, 114, 53, 99, 111, 117, 110, 116, 49, 52, 100, 111, 95, 99, 111, 117, 110, 116, 95, 99, 104, 97, 114, 115, 49, 55, 104, 49, 102, 52, 100, 55, 57, 55, 57, 99, 54, 52, 55, 54, 98, 52, 48, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 111, 112, 115, 56, 102, 117, 110, 99, 116, 105, 111, 110, 54, 70, 110, 79, 110, 99, 101, 57, 99, 97, 108, 108, 95, 111, 110, 99, 101, 49, 55, 104, 98, 100, 57, 99, 102, 49, 102, 56, 97, 49, 50, 57, 49, 56, 99, 51, 69, 46, 108, 108, 118, 109, 46, 54, 50, 48, 51, 48, 57, 53, 55, 57, 54, 48, 48, 49, 49, 48, 55, 49, 56, 52, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 50, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 97, 110, 111, 110, 46, 100, 49, 99, 98, 99, 101, 101, 57, 101, 48, 53, 51, 52, 100, 52, 102, 53, 99, 48, 51, 101, 54, 50, 49, 54, 53, 55, 99, 53, 100, 102, 99, 46, 50, 49, 46, 108, 108, 118, 109, 46, 49, 51, 56, 52, 53, 56, 48, 55, 51, 57, 56, 52, 49, 49, 56, 48, 50, 55, 57, 0, 95, 90, 78, 51, 54, 95, 36, 76, 84, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 97, 110, 121, 46, 46, 65, 110, 121, 36, 71, 84, 36, 55, 116, 121, 112, 101, 95, 105, 100, 49, 55, 104, 53, 51, 49, 49, 53, 56, 48, 97, 57, 51, 57, 102, 56, 99, 54, 97, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 52, 97, 52, 100, 52, 55, 55, 48, 102, 49, 55, 53, 98, 100, 55, 57, 69, 0, 95, 90, 78, 52, 52, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 48, 49, 97, 97, 54, 57, 54, 98, 99, 102, 102, 55, 98, 57, 57, 55, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 53, 115, 108, 105, 99, 101, 53, 105, 110, 100, 101, 120, 50, 57, 115, 108, 105, 99, 101, 95, 115, 116, 97, 114, 116, 95, 105, 110, 100, 101, 120, 95, 108, 101, 110, 95, 102, 97, 105, 108, 95, 114, 116, 49, 55, 104, 98, 97, 51, 101, 54, 98, 99, 48, 57, 55, 100, 97, 98, 50, 48, 97, 69, 0, 95, 90, 78, 53, 57, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 65, 114, 103, 117, 109, 101, 110, 116, 115, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 105, 115, 112, 108, 97, 121, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 50, 57, 53, 50, 51, 98, 100, 97, 100, 50, 54, 48, 100, 99, 98, 50, 69, 0, 95, 90, 78, 52, 50, 95, 36, 76, 84, 36, 36, 82, 70, 36, 84, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 68, 101, 98, 117, 103, 36, 71, 84, 36, 51, 102, 109, 116, 49, 55, 104, 97, 52, 55, 102, 57, 100, 54, 52, 54, 51, 99, 57, 54, 98, 49, 51, 69, 0, 95, 90, 78, 53, 55, 95, 36, 76, 84, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 70, 111, 114, 109, 97, 116, 116, 101, 114, 36, 117, 50, 48, 36, 97, 115, 36, 117, 50, 48, 36, 99, 111, 114, 101, 46, 46, 102, 109, 116, 46, 46, 87, 114, 105, 116, 101, 36, 71, 84, 36, 57, 119, 114, 105, 116, 101, 95, 115, 116, 114, 49, 55, 104, 54, 98, 55, 99, 99, 51, 57, 100, 101, 101, 98, 51, 97, 97, 100, 100, 69, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 50, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 54, 52, 36, 71, 84, 36, 49, 55, 104, 49, 57, 51, 53, 51, 49, 100, 53, 48, 52, 56, 101, 99, 100, 98, 55, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 51, 48, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 49, 55, 104, 102, 49, 50, 49, 55, 48, 97, 52, 55, 101, 53, 102, 57, 49, 57, 97, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 95, 90, 78, 52, 99, 111, 114, 101, 51, 112, 116, 114, 53, 56, 100, 114, 111, 112, 95, 105, 110, 95, 112, 108, 97, 99, 101, 36, 76, 84, 36, 36, 82, 70, 36, 99, 111, 114, 101, 46, 46, 111, 112, 116, 105, 111, 110, 46, 46, 79, 112, 116, 105, 111, 110, 36, 76, 84, 36, 117, 115, 105, 122, 101, 36, 71, 84, 36, 36, 71, 84, 36, 49, 55, 104, 49, 55, 56, 97, 100, 100, 56, 54, 102, 98, 52, 51, 51, 50, 57, 54, 69, 46, 108, 108, 118, 109, 46, 52, 48, 54, 53, 54, 49, 52, 52, 52, 51, 52, 51, 48, 49, 51, 52, 52, 56, 51, 0, 97, 110, 111, 110, 46, 101, 51, 101, 53, 98, 100, 99, 51, 49, 55, 99, 50, 98, 51, 102, 51, 98, 51, 102, 98, 102, 52, 51, 55, 97, 51, 52, 57, 53, 100, 53, 100, 46, 48, 46, 108, 108, 118, 109, 46, 49, 54, 56, 49, 50, 54, 54, 53, 54, 48, 50, 55, 57, 51, 57, 51, 54, 50, 51, 55, 0, 95, 95, 104, 101, 97, 112, 95, 115, 116, 97, 114, 116, 0, 95, 95, 98, 115, 115, 95, 115, 105, 122, 101, 0, 95, 95, 104, 101, 97, 112, 95, 101, 110, 100, 0, 95, 95, 104, 101, 97, 112, 95, 115, 105, 122, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 7, 0, 16, 0, 0, 71, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 0, 8, 0, 0, 112, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 8, 0, 0, 0, 3, 0, 0, 0, 4, 0, 8, 0, 4, 112, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 112, 0, 0, 32, 22, 0, 0, 6, 0, 0, 0, 24, 1, 0, 0, 4, 0, 0, 0, 16, 0, 0, 0, 26, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 134, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 134, 0, 0, 137, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
-pub const TURN_ID: [u32; 8] = [817141686, 2548328244, 3788869858, 1426088251, 2070323489, 3470214631, 1625637753, 2987703608];
-pub const TURN_PATH: &'static str = r#"/home/chicken/ctf/fairgame/target/release/build/sagittarius-methods-25d5a44c9c25e36a/out/riscv-guest/riscv32im-risc0-zkvm-elf/release/turn"#;
-            
\ No newline at end of file
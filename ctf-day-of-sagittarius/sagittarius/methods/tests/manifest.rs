@@ -0,0 +1,43 @@
+use sagittarius_methods::{
+    CLUSTER_ELF, CLUSTER_ID, INIT_ELF, INIT_ID, MANIFEST, SALVO_ELF, SALVO_ID, SCOUT_ELF, SCOUT_ID, TURN_ELF, TURN_ID,
+};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+#[path = "../sources.rs"]
+mod sources;
+
+const GUESTS: [(&str, &[u8], [u32; 8]); 5] = [
+    ("cluster", CLUSTER_ELF, CLUSTER_ID),
+    ("init", INIT_ELF, INIT_ID),
+    ("salvo", SALVO_ELF, SALVO_ID),
+    ("scout", SCOUT_ELF, SCOUT_ID),
+    ("turn", TURN_ELF, TURN_ID),
+];
+
+fn manifest() -> Value {
+    serde_json::from_str(MANIFEST).unwrap()
+}
+
+#[test]
+fn manifest_lists_every_embedded_guest() {
+    let manifest = manifest();
+    let mut names = manifest["guests"].as_object().unwrap().keys().collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, GUESTS.map(|(name, ..)| name));
+}
+
+#[test]
+fn manifest_matches_the_embedded_images() {
+    let manifest = manifest();
+    for (name, elf, id) in GUESTS {
+        let guest = &manifest["guests"][name];
+        assert_eq!(guest["image_id"], json!(id), "image id of {}", name);
+        assert_eq!(guest["elf_sha256"], sources::hex(&Sha256::digest(elf)), "elf hash of {}", name);
+    }
+}
+
+#[test]
+fn images_were_built_from_the_current_sources() {
+    assert_eq!(manifest()["source_hash"], sources::source_hash());
+}
@@ -0,0 +1,24 @@
+use crossterm::style::{Color, Stylize};
+
+use crate::{grid::{Cell, Grid}, text};
+
+fn cell_color(cell: &Cell) -> Option<Color> {
+    match cell {
+        Cell::Empty => None,
+        Cell::Ship(_) => Some(Color::Cyan),
+        Cell::Damaged | Cell::Hit => Some(Color::Red),
+        Cell::Miss => Some(Color::DarkGrey),
+        Cell::Revealed => Some(Color::Yellow),
+        Cell::Sunk => Some(Color::DarkRed),
+    }
+}
+
+pub fn render(title: &str, grid: &Grid) -> Vec<String> {
+    text::render_with(title, grid, |cell| {
+        let symbol = format!(" {} ", cell.symbol());
+        match cell_color(cell) {
+            Some(color) => symbol.with(color).bold().to_string(),
+            None => symbol,
+        }
+    })
+}
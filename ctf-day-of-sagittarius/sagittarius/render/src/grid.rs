@@ -0,0 +1,77 @@
+use sagittarius_game::{state::GameState, types::HitType, BOARD_SIZE, SHIP_SPANS};
+
+use crate::HitMap;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    /// Intact segment of the ship with the given index
+    Ship(u8),
+    /// Segment of one of our ships that has been hit
+    Damaged,
+    Miss,
+    Hit,
+    Revealed,
+    Sunk,
+}
+
+impl Cell {
+    pub fn symbol(&self) -> char {
+        match self {
+            Cell::Empty => ' ',
+            Cell::Ship(i) => (b'A' + i) as char,
+            Cell::Damaged => 'X',
+            Cell::Miss => 'M',
+            Cell::Hit => 'H',
+            Cell::Revealed => 'R',
+            Cell::Sunk => 'S',
+        }
+    }
+}
+
+impl From<HitType> for Cell {
+    fn from(hit: HitType) -> Self {
+        match hit {
+            HitType::Miss => Cell::Miss,
+            HitType::Hit | HitType::AlreadyHit => Cell::Hit,
+            HitType::Revealed => Cell::Revealed,
+            HitType::Destroyed(_) => Cell::Sunk,
+        }
+    }
+}
+
+pub type Grid = [[Cell; BOARD_SIZE]; BOARD_SIZE];
+
+pub fn empty_grid() -> Grid {
+    [[Cell::Empty; BOARD_SIZE]; BOARD_SIZE]
+}
+
+pub fn player_grid(state: &GameState) -> Grid {
+    let mut grid = empty_grid();
+
+    for (i, ship) in state.ships.iter().enumerate() {
+        for j in 0..SHIP_SPANS[i] as u32 {
+            if let Some(pos) = ship.cell(j).filter(|pos| pos.check()) {
+                grid[pos.y as usize][pos.x as usize] = if ship.hit_mask & (1 << j) != 0 {
+                    Cell::Damaged
+                } else {
+                    Cell::Ship(i as u8)
+                };
+            }
+        }
+    }
+
+    grid
+}
+
+pub fn enemy_grid(shots: &HitMap) -> Grid {
+    let mut grid = empty_grid();
+
+    for (pos, hit) in shots {
+        if pos.check() {
+            grid[pos.y as usize][pos.x as usize] = Cell::from(*hit);
+        }
+    }
+
+    grid
+}
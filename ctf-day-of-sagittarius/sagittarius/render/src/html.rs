@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use sagittarius_game::BOARD_SIZE;
+
+use crate::grid::{Cell, Grid};
+
+pub(crate) fn cell_class(cell: &Cell) -> &'static str {
+    match cell {
+        Cell::Empty => "empty",
+        Cell::Ship(_) => "ship",
+        Cell::Damaged => "damaged",
+        Cell::Miss => "miss",
+        Cell::Hit => "hit",
+        Cell::Revealed => "revealed",
+        Cell::Sunk => "sunk",
+    }
+}
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders the board as a `<table>`. Cells carry a class named after their
+/// state (`ship`, `hit`, `miss`, ...) so the page decides on the colours.
+pub fn render(title: &str, grid: &Grid) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "<table class=\"sagittarius-board\">").unwrap();
+    writeln!(out, "  <caption>{}</caption>", escape(title)).unwrap();
+
+    write!(out, "  <tr><th></th>").unwrap();
+    for c in (b'a'..b'a' + BOARD_SIZE as u8).map(char::from) {
+        write!(out, "<th>{}</th>", c).unwrap();
+    }
+    writeln!(out, "</tr>").unwrap();
+
+    for (i, row) in grid.iter().enumerate() {
+        write!(out, "  <tr><th>{}</th>", i + 1).unwrap();
+        for cell in row {
+            write!(out, "<td class=\"{}\">{}</td>", cell_class(cell), cell.symbol()).unwrap();
+        }
+        writeln!(out, "</tr>").unwrap();
+    }
+
+    writeln!(out, "</table>").unwrap();
+    out
+}
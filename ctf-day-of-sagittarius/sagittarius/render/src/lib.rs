@@ -0,0 +1,14 @@
+pub mod grid;
+pub mod text;
+pub mod ansi;
+pub mod html;
+pub mod svg;
+
+use std::collections::HashMap;
+
+use sagittarius_game::types::{Position, HitType};
+
+pub use grid::{Cell, Grid, player_grid, enemy_grid};
+
+/// Everything we know about the enemy board, keyed by cell.
+pub type HitMap = HashMap<Position, HitType>;
@@ -0,0 +1,52 @@
+use std::fmt::Write;
+
+use sagittarius_game::BOARD_SIZE;
+
+use crate::{grid::{Cell, Grid}, html::escape};
+
+const CELL_SIZE: usize = 32;
+const MARGIN: usize = 24;
+const TITLE_HEIGHT: usize = 28;
+
+fn cell_fill(cell: &Cell) -> &'static str {
+    match cell {
+        Cell::Empty => "#0b1026",
+        Cell::Ship(_) => "#3aa7c9",
+        Cell::Damaged | Cell::Hit => "#d9412b",
+        Cell::Miss => "#4a4f63",
+        Cell::Revealed => "#e0b341",
+        Cell::Sunk => "#7a1c10",
+    }
+}
+
+/// Renders the board as a standalone SVG image.
+pub fn render(title: &str, grid: &Grid) -> String {
+    let width = MARGIN + BOARD_SIZE * CELL_SIZE;
+    let height = TITLE_HEIGHT + MARGIN + BOARD_SIZE * CELL_SIZE;
+    let mut out = String::new();
+
+    writeln!(out, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"monospace\" font-size=\"14\">", width, height).unwrap();
+    writeln!(out, "  <text x=\"{}\" y=\"18\" text-anchor=\"middle\">{}</text>", width / 2, escape(title)).unwrap();
+
+    // Column letters and row numbers
+    for i in 0..BOARD_SIZE {
+        let c = (b'a' + i as u8) as char;
+        let offset = MARGIN + i * CELL_SIZE + CELL_SIZE / 2;
+        writeln!(out, "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>", offset, TITLE_HEIGHT + 16, c).unwrap();
+        writeln!(out, "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>", MARGIN / 2, TITLE_HEIGHT + offset + 5, i + 1).unwrap();
+    }
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let left = MARGIN + x * CELL_SIZE;
+            let top = TITLE_HEIGHT + MARGIN + y * CELL_SIZE;
+            writeln!(out, "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\" stroke=\"#c0c4d6\"/>", left, top, CELL_SIZE, CELL_SIZE, cell_fill(cell)).unwrap();
+            if *cell != Cell::Empty {
+                writeln!(out, "  <text x=\"{}\" y=\"{}\" text-anchor=\"middle\" fill=\"#ffffff\">{}</text>", left + CELL_SIZE / 2, top + CELL_SIZE / 2 + 5, cell.symbol()).unwrap();
+            }
+        }
+    }
+
+    writeln!(out, "</svg>").unwrap();
+    out
+}
@@ -0,0 +1,42 @@
+use sagittarius_game::BOARD_SIZE;
+
+use crate::grid::{Cell, Grid};
+
+/// Draws the framed board, formatting every cell with `cell_fn`.
+/// `cell_fn` must return something that is 3 columns wide on screen.
+pub fn render_with<F: Fn(&Cell) -> String>(title: &str, grid: &Grid, cell_fn: F) -> Vec<String> {
+    let mut result = Vec::new();
+
+    // Calculate total width of the board
+    let total_width = BOARD_SIZE * 4 + 4;  // 2 characters per cell + 1 for the vertical divider
+
+    // Print title
+    let padded_title = format!("{0: ^1$}", title, total_width - 2);  // Center the title with padding
+    let title_frame = format!("┏{}┓", "━".repeat(total_width - 2));
+    let title_frame_bot = format!("┣━━┳{}━━━┫", "━━━┳".repeat(BOARD_SIZE - 1));
+    let title_line = format!("┃{}┃", padded_title);
+    result.push(title_frame);
+    result.push(title_line);
+    result.push(title_frame_bot);
+
+    // Print column letters
+    let letters_line = (b'a'..=b'a' + (BOARD_SIZE - 1) as u8).map(char::from).map(|c| format!(" {} ┃", c)).collect::<String>();
+    result.push(format!("┃  ┃{}", letters_line));
+
+    // Print board
+    for (i, row) in grid.iter().enumerate() {
+        let cells = row.iter().map(&cell_fn).collect::<Vec<_>>();
+        let row_line = format!("┃ {}┃{}┃", i + 1, cells.join("│"));
+        result.push("┣━━╋".to_string() + &"───┼".repeat(BOARD_SIZE - 1) + "───┨");
+        result.push(row_line);
+    }
+
+    // Add bottom border
+    result.push("┗━━┷".to_string() + &"━━━┷".repeat(BOARD_SIZE - 1) + "━━━┛");
+
+    result
+}
+
+pub fn render(title: &str, grid: &Grid) -> Vec<String> {
+    render_with(title, grid, |cell| format!(" {} ", cell.symbol()))
+}
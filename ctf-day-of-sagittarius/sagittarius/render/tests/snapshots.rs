@@ -0,0 +1,68 @@
+use std::{env, fs, path::PathBuf};
+
+use sagittarius_game::{
+    state::{GameState, Ship, ShipDirection},
+    types::{HitType, Position},
+};
+use sagittarius_render::{ansi, enemy_grid, html, player_grid, svg, text, HitMap};
+
+/// Compares against `tests/snapshots/<name>`. Run with `UPDATE_SNAPSHOTS=1` to accept new output.
+fn check(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots").join(name);
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing snapshot {}", path.display()));
+    assert_eq!(actual, expected, "snapshot {} changed", name);
+}
+
+/// A fleet along the edges and in the middle, with the mothership hit twice
+fn state() -> GameState {
+    let mut state = GameState::new(
+        [
+            Ship::new(0, 0, ShipDirection::Horizontal),
+            Ship::new(0, 2, ShipDirection::Vertical),
+            Ship::new(2, 2, ShipDirection::Horizontal),
+            Ship::new(7, 6, ShipDirection::Vertical),
+        ],
+        0,
+    );
+    state.ships[0].hit_mask = 0b0101;
+    state
+}
+
+fn shots() -> HitMap {
+    HitMap::from([
+        (Position::new(1, 1), HitType::Miss),
+        (Position::new(4, 4), HitType::Hit),
+        (Position::new(6, 0), HitType::Revealed),
+        (Position::new(0, 7), HitType::Destroyed(3)),
+        (Position::new(1, 7), HitType::Destroyed(3)),
+    ])
+}
+
+#[test]
+fn text_boards() {
+    check("player.txt", &(text::render("Your fleet", &player_grid(&state())).join("\n") + "\n"));
+    check("enemy.txt", &(text::render("Enemy fleet", &enemy_grid(&shots())).join("\n") + "\n"));
+}
+
+#[test]
+fn ansi_boards() {
+    check("player.ansi", &(ansi::render("Your fleet", &player_grid(&state())).join("\n") + "\n"));
+    check("enemy.ansi", &(ansi::render("Enemy fleet", &enemy_grid(&shots())).join("\n") + "\n"));
+}
+
+#[test]
+fn html_boards() {
+    check("player.html", &html::render("Your <fleet>", &player_grid(&state())));
+    check("enemy.html", &html::render("Enemy fleet", &enemy_grid(&shots())));
+}
+
+#[test]
+fn svg_boards() {
+    check("player.svg", &svg::render("Your fleet", &player_grid(&state())));
+    check("enemy.svg", &svg::render("Enemy & fleet", &enemy_grid(&shots())));
+}
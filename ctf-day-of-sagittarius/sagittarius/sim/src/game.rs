@@ -0,0 +1,287 @@
+use rand::{seq::SliceRandom, Rng};
+use sagittarius_game::{
+    abilities::{ClusterArea, ScoutParams, ShotParams},
+    state::GameState,
+    sunk_mask,
+    topology::Topology,
+    types::{HitType, Position},
+    BOARD_SIZE,
+};
+use sagittarius_render::HitMap;
+
+use crate::rules::{Mode, Rules};
+use crate::strategy::{wounded, Strategy};
+
+/// Cluster charges are fired at a 3x4 rectangle, the largest one that still gets 3 shots
+const CLUSTER_WIDTH: u32 = 3;
+const CLUSTER_HEIGHT: u32 = 4;
+
+/// How often an action was taken and how many enemy ship cells it hit or revealed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Usage {
+    pub uses: u32,
+    pub found: u32,
+}
+
+impl Usage {
+    fn record(&mut self, found: usize) {
+        self.uses += 1;
+        self.found += found as u32;
+    }
+
+    fn add(&mut self, other: &Usage) {
+        self.uses += other.uses;
+        self.found += other.found;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub shots: Usage,
+    pub scouts: Usage,
+    pub clusters: Usage,
+}
+
+impl Stats {
+    pub fn add(&mut self, other: &Stats) {
+        self.shots.add(&other.shots);
+        self.scouts.add(&other.scouts);
+        self.clusters.add(&other.clusters);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Player {
+    pub strategy: Strategy,
+    /// Spend scouts and cluster charges while no wounded enemy ship is known
+    pub abilities: bool,
+}
+
+pub struct Outcome {
+    /// 0 for player A, 1 for player B
+    pub winner: usize,
+    pub first: usize,
+    /// Turns taken by both players together
+    pub turns: u32,
+    pub stats: [Stats; 2],
+}
+
+struct Side {
+    player: Player,
+    /// Our own board. Its charges are the ones the enemy can still use against it
+    state: GameState,
+    /// Everything we know about the enemy board
+    hits: HitMap,
+    stats: Stats,
+}
+
+impl Side {
+    fn record(&mut self, pos: &Position, hit: HitType) -> usize {
+        match hit {
+            HitType::AlreadyHit => 0,
+            _ => {
+                self.hits.insert(pos.clone(), hit);
+                matches!(hit, HitType::Hit | HitType::Destroyed(_)) as usize
+            },
+        }
+    }
+}
+
+fn defeated(state: &GameState, spans: &[usize]) -> bool {
+    state.ships.iter().zip(spans.iter()).all(|(ship, span)| ship.hit_mask == sunk_mask(*span))
+}
+
+fn surviving(state: &GameState, spans: &[usize]) -> usize {
+    state.ships.iter().zip(spans.iter()).filter(|(ship, span)| ship.hit_mask != sunk_mask(**span)).count()
+}
+
+fn shoot(rules: &Rules, topology: &impl Topology, attacker: &mut Side, defender: &mut Side, pos: &Position) -> usize {
+    let result = ShotParams { state: defender.state.clone(), shot: pos.clone() }.process_with(topology, &rules.spans);
+    defender.state = result.state;
+    attacker.record(pos, result.hit)
+}
+
+/// Scouts the cells around `pos`, keeping earlier hits like the client does.
+fn scout(rules: &Rules, attacker: &mut Side, defender: &mut Side, pos: &Position) {
+    let result = ScoutParams { state: defender.state.clone(), shot: pos.clone() }.process_with(&rules.spans);
+    defender.state = result.state;
+
+    let positions = [Position::new(pos.x.wrapping_sub(1), pos.y),
+                     Position::new(pos.x, pos.y.wrapping_sub(1)),
+                     Position::new(pos.x + 1, pos.y),
+                     Position::new(pos.x, pos.y + 1)];
+    let mut found = 0;
+    for (cell, hit) in positions.iter().zip(result.cells.iter()) {
+        if !attacker.hits.contains_key(cell) {
+            found += (*hit == HitType::Revealed) as usize;
+            attacker.hits.insert(cell.clone(), *hit);
+        }
+    }
+    attacker.stats.scouts.record(found);
+}
+
+/// Fires a cluster charge around `pos`. Follows `ClusterBombParams::process` and the guest picking
+/// random cells of the area, but with the simulated spans and skipping asteroids.
+fn cluster(rules: &Rules, topology: &impl Topology, attacker: &mut Side, defender: &mut Side, pos: &Position, rng: &mut impl Rng) {
+    let last = BOARD_SIZE as u32 - 1;
+    let ul = Position::new(pos.x.saturating_sub(1).min(last + 1 - CLUSTER_WIDTH), pos.y.saturating_sub(1).min(last + 1 - CLUSTER_HEIGHT));
+    let dr = Position::new(ul.x + CLUSTER_WIDTH - 1, ul.y + CLUSTER_HEIGHT - 1);
+    let area = ClusterArea::new(ul, dr);
+    let count = area.validate().expect("Simulated cluster area is invalid");
+
+    defender.state.cluster_bombs -= 1;
+    let cells = area.positions().into_iter().filter(|cell| topology.contains(cell)).collect::<Vec<_>>();
+    let mut found = 0;
+    for cell in cells.choose_multiple(rng, count) {
+        found += shoot(rules, topology, attacker, defender, cell);
+    }
+    attacker.stats.clusters.record(found);
+}
+
+fn take_turn(rules: &Rules, topology: &impl Topology, attacker: &mut Side, defender: &mut Side, rng: &mut impl Rng) {
+    let targets = attacker.player.strategy.rank(topology, &attacker.hits, &rules.spans, rng);
+    let best = targets[0].clone();
+
+    // Abilities are for finding ships, so only spend them while hunting
+    if attacker.player.abilities && !wounded(&attacker.hits, &rules.spans) {
+        if defender.state.scouts > 0 && best.check() {
+            scout(rules, attacker, defender, &best);
+            return;
+        }
+        if defender.state.cluster_bombs > 0 {
+            cluster(rules, topology, attacker, defender, &best, rng);
+            return;
+        }
+    }
+
+    let count = match rules.mode {
+        Mode::Classic => 1,
+        Mode::Salvo => surviving(&attacker.state, &rules.spans),
+    };
+    for pos in targets.iter().take(count) {
+        let found = shoot(rules, topology, attacker, defender, pos);
+        attacker.stats.shots.record(found);
+    }
+}
+
+/// Plays one game to the end. Player `first` moves first.
+pub fn play(rules: &Rules, players: [Player; 2], first: usize, rng: &mut impl Rng) -> Outcome {
+    let topology = rules.topology(rng);
+
+    // Both players know where the asteroids are
+    let mut blocked = HitMap::new();
+    for y in 0..BOARD_SIZE as u32 {
+        for x in 0..BOARD_SIZE as u32 {
+            let pos = Position::new(x, y);
+            if !topology.contains(&pos) {
+                blocked.insert(pos, HitType::Miss);
+            }
+        }
+    }
+
+    let mut sides = players.map(|player| Side {
+        player,
+        state: rules.fleet(&topology, rng),
+        hits: blocked.clone(),
+        stats: Stats::default(),
+    });
+
+    let mut turn = first;
+    let mut turns = 0;
+    loop {
+        let [a, b] = &mut sides;
+        let (attacker, defender) = if turn == 0 { (a, b) } else { (b, a) };
+        take_turn(rules, &topology, attacker, defender, rng);
+        turns += 1;
+
+        if defeated(&defender.state, &rules.spans) {
+            return Outcome {
+                winner: turn,
+                first,
+                turns,
+                stats: sides.map(|side| side.stats),
+            };
+        }
+        turn = 1 - turn;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::rules::Board;
+
+    const HUNTERS: [Player; 2] = [
+        Player { strategy: Strategy::Hunt, abilities: true },
+        Player { strategy: Strategy::Random, abilities: true },
+    ];
+
+    /// Plays a few games, checking that each ends with the loser's fleet sunk.
+    fn play_games(rules: &Rules, players: [Player; 2]) -> Vec<Outcome> {
+        (0..4)
+            .map(|seed| {
+                let outcome = play(rules, players, seed as usize % 2, &mut StdRng::seed_from_u64(seed));
+                assert!(outcome.turns > 0);
+                outcome
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classic_games_take_a_turn_per_shot() {
+        let rules = Rules { scouts: 0, clusters: 0, ..Rules::default() };
+        let total = rules.spans.iter().sum::<usize>() as u32;
+        for outcome in play_games(&rules, HUNTERS) {
+            let winner = outcome.stats[outcome.winner];
+            // One shot a turn, and the winner found every ship cell with them
+            assert_eq!(winner.shots.uses, (outcome.turns + 1 - (outcome.first != outcome.winner) as u32) / 2);
+            assert_eq!(winner.shots.found, total);
+        }
+    }
+
+    #[test]
+    fn salvo_fires_a_shot_per_surviving_ship() {
+        let rules = Rules { scouts: 0, clusters: 0, mode: Mode::Salvo, ..Rules::default() };
+        for outcome in play_games(&rules, HUNTERS) {
+            let winner = outcome.stats[outcome.winner];
+            let winner_turns = (outcome.turns + 1 - (outcome.first != outcome.winner) as u32) / 2;
+            assert!(winner.shots.uses > winner_turns);
+        }
+    }
+
+    #[test]
+    fn abilities_are_spent_only_when_allowed() {
+        let players = [
+            Player { strategy: Strategy::Hunt, abilities: true },
+            Player { strategy: Strategy::Hunt, abilities: false },
+        ];
+        let outcomes = play_games(&Rules::default(), players);
+        for outcome in &outcomes {
+            assert!(outcome.stats[0].scouts.uses <= 1 && outcome.stats[0].clusters.uses <= 1);
+            assert_eq!(outcome.stats[1].scouts.uses + outcome.stats[1].clusters.uses, 0);
+        }
+        assert!(outcomes.iter().any(|outcome| outcome.stats[0].scouts.uses == 1));
+    }
+
+    #[test]
+    fn custom_spans_are_sunk() {
+        let rules = Rules { spans: [5, 1, 2, 2], scouts: 0, clusters: 0, ..Rules::default() };
+        for outcome in play_games(&rules, HUNTERS) {
+            assert_eq!(outcome.stats[outcome.winner].shots.found, 10);
+        }
+    }
+
+    #[test]
+    fn hex_board_with_asteroids_plays_to_the_end() {
+        let rules = Rules { scouts: 0, clusters: 0, board: Board::Hex, asteroids: 8, ..Rules::default() };
+        play_games(&rules, HUNTERS);
+    }
+
+    #[test]
+    fn heat_strategy_plays_to_the_end() {
+        let players = [Player { strategy: Strategy::Heat, abilities: true }, HUNTERS[0]];
+        play_games(&Rules::default(), players);
+    }
+}
@@ -0,0 +1,209 @@
+mod game;
+mod rules;
+mod strategy;
+
+use std::process::exit;
+
+use rand::{rngs::StdRng, SeedableRng};
+use sagittarius_game::NUM_SHIPS;
+
+use crate::game::{play, Player, Stats, Usage};
+use crate::rules::{Board, Mode, Rules};
+use crate::strategy::Strategy;
+
+const USAGE: &str = "\
+Usage: sagittarius-sim [options]
+  --games N           games to play (1000)
+  --seed N            seed of the first game, game i uses seed + i (0)
+  --a STRATEGY        strategy of player A: random, hunt or heat (heat)
+  --b STRATEGY        strategy of player B (hunt)
+  --abilities WHO     who spends scouts and cluster charges: a, b, both or none (both)
+  --spans A,B,C,D     ship spans (4,3,3,2)
+  --scouts N          scout charges per player (1)
+  --clusters N        cluster charges per player (1)
+  --mode MODE         classic or salvo (classic)
+  --board BOARD       square or hex (square)
+  --asteroids N       blocked cells per game (0)";
+
+struct Config {
+    games: u64,
+    seed: u64,
+    players: [Player; 2],
+    rules: Rules,
+}
+
+fn parse_spans(value: &str) -> Option<[usize; NUM_SHIPS]> {
+    let spans = value.split(',').map(|span| span.trim().parse().ok()).collect::<Option<Vec<usize>>>()?;
+    spans.try_into().ok()
+}
+
+fn parse_args(args: &[String]) -> Result<Config, String> {
+    let mut config = Config {
+        games: 1000,
+        seed: 0,
+        players: [
+            Player { strategy: Strategy::Heat, abilities: true },
+            Player { strategy: Strategy::Hunt, abilities: true },
+        ],
+        rules: Rules::default(),
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+        let invalid = || format!("invalid value for {}: {}", flag, value);
+        let number = || value.parse::<u64>().map_err(|_| invalid());
+        match flag.as_str() {
+            "--games" => config.games = number()?,
+            "--seed" => config.seed = number()?,
+            "--a" => config.players[0].strategy = Strategy::from_name(value).ok_or_else(invalid)?,
+            "--b" => config.players[1].strategy = Strategy::from_name(value).ok_or_else(invalid)?,
+            "--abilities" => {
+                let (a, b) = match value.as_str() {
+                    "a" => (true, false),
+                    "b" => (false, true),
+                    "both" => (true, true),
+                    "none" => (false, false),
+                    _ => return Err(invalid()),
+                };
+                config.players[0].abilities = a;
+                config.players[1].abilities = b;
+            },
+            "--spans" => config.rules.spans = parse_spans(value).ok_or_else(invalid)?,
+            "--scouts" => config.rules.scouts = value.parse().map_err(|_| invalid())?,
+            "--clusters" => config.rules.clusters = value.parse().map_err(|_| invalid())?,
+            "--mode" => {
+                config.rules.mode = match value.as_str() {
+                    "classic" => Mode::Classic,
+                    "salvo" => Mode::Salvo,
+                    _ => return Err(invalid()),
+                }
+            },
+            "--board" => {
+                config.rules.board = match value.as_str() {
+                    "square" => Board::Square,
+                    "hex" => Board::Hex,
+                    _ => return Err(invalid()),
+                }
+            },
+            "--asteroids" => config.rules.asteroids = number()? as usize,
+            _ => return Err(format!("unknown option {}", flag)),
+        }
+    }
+
+    config.rules.check()?;
+    // The advisor only knows horizontal and vertical ships
+    if config.rules.board == Board::Hex && config.players.iter().any(|player| player.strategy == Strategy::Heat) {
+        return Err("the heat strategy only plays on the square board".to_string());
+    }
+    Ok(config)
+}
+
+fn percent(part: u64, total: u64) -> String {
+    format!("{:.1}%", 100.0 * part as f64 / total.max(1) as f64)
+}
+
+fn yield_per_use(usage: &Usage) -> String {
+    if usage.uses == 0 {
+        return "-".to_string();
+    }
+    format!("{:.2} ({} uses)", usage.found as f64 / usage.uses as f64, usage.uses)
+}
+
+/// Results of all games, by player.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Totals {
+    wins: [u64; 2],
+    /// Turns of the games each player won
+    turns: [u64; 2],
+    first_wins: u64,
+    stats: [Stats; 2],
+}
+
+fn simulate(config: &Config) -> Totals {
+    let mut totals = Totals::default();
+    for i in 0..config.games {
+        // Every game is reproducible on its own from the seed, and players take turns moving first
+        let mut rng = StdRng::seed_from_u64(config.seed.wrapping_add(i));
+        let outcome = play(&config.rules, config.players, (i % 2) as usize, &mut rng);
+
+        totals.wins[outcome.winner] += 1;
+        totals.turns[outcome.winner] += outcome.turns as u64;
+        totals.first_wins += (outcome.winner == outcome.first) as u64;
+        for (total, game) in totals.stats.iter_mut().zip(outcome.stats.iter()) {
+            total.add(game);
+        }
+    }
+    totals
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    let config = match parse_args(&args) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            exit(1);
+        },
+    };
+
+    let Totals { wins, turns, first_wins, stats } = simulate(&config);
+
+    let rules = &config.rules;
+    let [a, b] = config.players;
+    let games = config.games;
+    println!(
+        "{} games, A: {} vs B: {}, {:?} mode on a {:?} board, spans {:?}, {} scouts, {} clusters, {} asteroids, seed {}",
+        games, a.strategy, b.strategy, rules.mode, rules.board, rules.spans, rules.scouts, rules.clusters, rules.asteroids, config.seed
+    );
+    println!();
+    println!("{:<24} {:>16} {:>16}", "", "A", "B");
+    println!("{:<24} {:>16} {:>16}", "Abilities", a.abilities, b.abilities);
+    println!("{:<24} {:>16} {:>16}", "Win rate", percent(wins[0], games), percent(wins[1], games));
+    println!(
+        "{:<24} {:>16.1} {:>16.1}",
+        "Turns per win",
+        turns[0] as f64 / wins[0].max(1) as f64,
+        turns[1] as f64 / wins[1].max(1) as f64
+    );
+    println!("Ship cells found per use:");
+    println!("{:<24} {:>16} {:>16}", "  Shot", yield_per_use(&stats[0].shots), yield_per_use(&stats[1].shots));
+    println!("{:<24} {:>16} {:>16}", "  Scout", yield_per_use(&stats[0].scouts), yield_per_use(&stats[1].scouts));
+    println!("{:<24} {:>16} {:>16}", "  Cluster charge", yield_per_use(&stats[0].clusters), yield_per_use(&stats[1].clusters));
+    println!();
+    println!("Average game length: {:.1} turns", (turns[0] + turns[1]) as f64 / games.max(1) as f64);
+    println!("First player wins: {}", percent(first_wins, games));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(args: &[&str]) -> Config {
+        parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_results() {
+        let args = ["--games", "6", "--seed", "42", "--a", "heat", "--b", "hunt"];
+        let first = simulate(&config(&args));
+        assert_eq!(first, simulate(&config(&args)));
+        assert_eq!(first.wins[0] + first.wins[1], 6);
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        let parse = |args: &[&str]| parse_args(&args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>());
+        assert!(parse(&["--seed", "-1"]).is_err());
+        assert!(parse(&["--spans", "4,3,3"]).is_err());
+        assert!(parse(&["--spans", "9,3,3,2"]).is_err());
+        assert!(parse(&["--mode", "blitz"]).is_err());
+        assert!(parse(&["--board", "hex"]).is_err());
+        assert!(parse(&["--board", "hex", "--a", "hunt", "--b", "random", "--scouts", "0", "--clusters", "0"]).is_ok());
+        assert!(parse(&["--games"]).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use rand::{seq::SliceRandom, Rng};
+use sagittarius_game::{
+    state::{GameCheck, GameState, Ship, ShipDirection},
+    topology::{Asteroids, Hex, Square, Topology},
+    types::Position,
+    BOARD_SIZE, MAX_CLUSTER_BOMBS, MAX_SCOUTS, NUM_SHIPS, SHIP_SPANS,
+};
+
+/// Attempts at placing one ship before the whole fleet is placed again
+const PLACEMENT_ATTEMPTS: usize = 1000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    Classic,
+    Salvo,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Board {
+    Square,
+    Hex,
+}
+
+impl Board {
+    fn base(&self) -> &'static dyn Topology {
+        match self {
+            Board::Square => &Square,
+            Board::Hex => &Hex,
+        }
+    }
+}
+
+impl Topology for Board {
+    fn contains(&self, pos: &Position) -> bool {
+        self.base().contains(pos)
+    }
+
+    fn directions(&self) -> &[ShipDirection] {
+        self.base().directions()
+    }
+
+    fn neighbours(&self, pos: &Position) -> Vec<Position> {
+        self.base().neighbours(pos)
+    }
+}
+
+/// Everything a simulation can tune, defaulting to the proven rules.
+#[derive(Clone, Debug)]
+pub struct Rules {
+    pub spans: [usize; NUM_SHIPS],
+    pub scouts: u8,
+    pub clusters: u8,
+    pub mode: Mode,
+    pub board: Board,
+    /// Blocked cells per game, shared by both boards
+    pub asteroids: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            spans: SHIP_SPANS,
+            scouts: MAX_SCOUTS,
+            clusters: MAX_CLUSTER_BOMBS,
+            mode: Mode::Classic,
+            board: Board::Square,
+            asteroids: 0,
+        }
+    }
+}
+
+impl Rules {
+    pub fn check(&self) -> Result<(), String> {
+        if let Some(span) = self.spans.iter().find(|span| !(1..=BOARD_SIZE).contains(*span)) {
+            return Err(format!("ship span {} doesn't fit on the board", span));
+        }
+        if self.asteroids > BOARD_SIZE * BOARD_SIZE / 4 {
+            return Err(format!("at most {} asteroids", BOARD_SIZE * BOARD_SIZE / 4));
+        }
+        if self.board == Board::Hex && (self.scouts > 0 || self.clusters > 0) {
+            return Err("scouts and cluster charges are only defined for the square board, pass --scouts 0 --clusters 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// The board of one game, with freshly scattered asteroids.
+    pub fn topology(&self, rng: &mut impl Rng) -> Asteroids<Board> {
+        let mut cells = (0..BOARD_SIZE as u32)
+            .flat_map(|y| (0..BOARD_SIZE as u32).map(move |x| Position::new(x, y)))
+            .collect::<Vec<_>>();
+        cells.shuffle(rng);
+        cells.truncate(self.asteroids);
+        Asteroids::new(self.board, &cells)
+    }
+
+    /// A random fleet that passes `GameState::check_on` for the topology.
+    pub fn fleet(&self, topology: &impl Topology, rng: &mut impl Rng) -> GameState {
+        'fleet: loop {
+            let mut game_check = GameCheck::new();
+            let mut ships = vec![];
+            for span in self.spans {
+                let ship = (0..PLACEMENT_ATTEMPTS)
+                    .map(|_| {
+                        let dir = topology.directions().choose(rng).unwrap().clone();
+                        Ship::new(rng.gen_range(0..BOARD_SIZE as u32), rng.gen_range(0..BOARD_SIZE as u32), dir)
+                    })
+                    .find(|ship| ship.check_on(topology, span) && game_check.check(ship, span, false));
+                match ship {
+                    Some(ship) => {
+                        game_check.commit(&ship, span);
+                        ships.push(ship);
+                    },
+                    None => continue 'fleet,
+                }
+            }
+
+            let mut state = GameState::new(ships.try_into().unwrap(), rng.gen());
+            state.scouts = self.scouts;
+            state.cluster_bombs = self.clusters;
+            return state;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn default_rules_are_the_proven_ones() {
+        let rules = Rules::default();
+        assert!(rules.check().is_ok());
+        assert_eq!(rules.spans, SHIP_SPANS);
+        assert_eq!((rules.scouts, rules.clusters), (MAX_SCOUTS, MAX_CLUSTER_BOMBS));
+    }
+
+    #[test]
+    fn unplayable_rules_are_rejected() {
+        assert!(Rules { spans: [0, 3, 3, 2], ..Rules::default() }.check().is_err());
+        assert!(Rules { spans: [BOARD_SIZE + 1, 3, 3, 2], ..Rules::default() }.check().is_err());
+        assert!(Rules { asteroids: BOARD_SIZE * BOARD_SIZE / 4 + 1, ..Rules::default() }.check().is_err());
+        assert!(Rules { board: Board::Hex, ..Rules::default() }.check().is_err());
+        assert!(Rules { board: Board::Hex, scouts: 0, clusters: 0, ..Rules::default() }.check().is_ok());
+    }
+
+    #[test]
+    fn topology_blocks_the_asteroids() {
+        let rules = Rules { asteroids: 10, ..Rules::default() };
+        let topology = rules.topology(&mut StdRng::seed_from_u64(1));
+        let blocked = (0..BOARD_SIZE as u32)
+            .flat_map(|y| (0..BOARD_SIZE as u32).map(move |x| Position::new(x, y)))
+            .filter(|pos| !topology.contains(pos))
+            .count();
+        assert_eq!(blocked, 10);
+    }
+
+    #[test]
+    fn fleets_fit_every_board() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for board in [Board::Square, Board::Hex] {
+            for asteroids in [0, 16] {
+                let rules = Rules { board, asteroids, scouts: 0, clusters: 2, ..Rules::default() };
+                for _ in 0..20 {
+                    let topology = rules.topology(&mut rng);
+                    let state = rules.fleet(&topology, &mut rng);
+                    assert_eq!((state.scouts, state.cluster_bombs), (0, 2));
+                    // `check_on` also wants the charges of the proven rules
+                    let proven = GameState { scouts: MAX_SCOUTS, cluster_bombs: MAX_CLUSTER_BOMBS, ..state };
+                    assert!(proven.check_on(&topology), "{:?} board with {} asteroids", board, asteroids);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fleets_use_the_configured_spans() {
+        let rules = Rules { spans: [1, 1, 5, 2], ..Rules::default() };
+        let mut rng = StdRng::seed_from_u64(3);
+        let state = rules.fleet(&Board::Square, &mut rng);
+        for (ship, span) in state.ships.iter().zip(rules.spans) {
+            assert!(ship.check_on(&Board::Square, span));
+        }
+    }
+}
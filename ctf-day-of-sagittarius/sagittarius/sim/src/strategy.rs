@@ -0,0 +1,156 @@
+use std::fmt::Display;
+
+use rand::{seq::SliceRandom, Rng};
+use sagittarius_game::{advisor::HeatMap, topology::Topology, types::{HitType, Position}, BOARD_SIZE};
+use sagittarius_render::HitMap;
+
+/// Placements the heat strategy enumerates before estimating. Small enough to play thousands of games
+const HEAT_BUDGET: u64 = 100_000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strategy {
+    /// Any cell that wasn't shot yet
+    Random,
+    /// Checkerboard until something is hit, then the cells around it
+    Hunt,
+    /// The advisor's most likely cell, see `sagittarius_game::advisor::HeatMap`
+    Heat,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 3] = [Strategy::Random, Strategy::Hunt, Strategy::Heat];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Strategy::ALL.iter().copied().find(|strategy| strategy.to_string() == name)
+    }
+
+    /// Cells still worth shooting at, best first. Ties are broken randomly.
+    pub fn rank(&self, topology: &impl Topology, hits: &HitMap, spans: &[usize], rng: &mut impl Rng) -> Vec<Position> {
+        let mut open = (0..BOARD_SIZE as u32)
+            .flat_map(|y| (0..BOARD_SIZE as u32).map(move |x| Position::new(x, y)))
+            .filter(|pos| topology.contains(pos) && matches!(hits.get(pos), None | Some(HitType::Revealed)))
+            .collect::<Vec<_>>();
+        open.shuffle(rng);
+
+        match self {
+            Strategy::Random => {},
+            Strategy::Hunt => open.sort_by_key(|pos| hunt_priority(topology, hits, pos)),
+            Strategy::Heat => {
+                let heat = HeatMap::with_budget(hits, spans, HEAT_BUDGET);
+                open.sort_by(|a, b| heat.probability(b).total_cmp(&heat.probability(a)));
+            },
+        }
+        open
+    }
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Strategy::Random => write!(f, "random"),
+            Strategy::Hunt => write!(f, "hunt"),
+            Strategy::Heat => write!(f, "heat"),
+        }
+    }
+}
+
+/// Lower goes first: revealed ships, cells next to a hit, then every other cell of a checkerboard.
+fn hunt_priority(topology: &impl Topology, hits: &HitMap, pos: &Position) -> u8 {
+    if hits.get(pos) == Some(&HitType::Revealed) {
+        0
+    } else if topology.neighbours(pos).iter().any(|cell| hits.get(cell) == Some(&HitType::Hit)) {
+        1
+    } else if (pos.x + pos.y) % 2 == 0 {
+        2
+    } else {
+        3
+    }
+}
+
+/// Whether we know of a ship that is still afloat: a revealed cell, or more hit cells than the sunk ships cover.
+pub fn wounded(hits: &HitMap, spans: &[usize]) -> bool {
+    let revealed = hits.values().any(|hit| *hit == HitType::Revealed);
+    let struck = hits.values().filter(|hit| matches!(hit, HitType::Hit | HitType::Destroyed(_))).count();
+    let sunk = hits
+        .values()
+        .filter_map(|hit| match hit {
+            HitType::Destroyed(i) => spans.get(*i as usize),
+            _ => None,
+        })
+        .sum::<usize>();
+    revealed || struck > sunk
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use sagittarius_game::{topology::Square, SHIP_SPANS};
+
+    use super::*;
+
+    fn rank(strategy: Strategy, hits: &HitMap) -> Vec<Position> {
+        strategy.rank(&Square, hits, &SHIP_SPANS, &mut StdRng::seed_from_u64(0))
+    }
+
+    #[test]
+    fn every_strategy_ranks_only_open_cells() {
+        let mut hits = HitMap::new();
+        hits.insert(Position::new(0, 0), HitType::Miss);
+        hits.insert(Position::new(4, 4), HitType::Hit);
+        hits.insert(Position::new(2, 6), HitType::Revealed);
+
+        for strategy in Strategy::ALL {
+            let ranked = rank(strategy, &hits);
+            assert_eq!(ranked.len(), BOARD_SIZE * BOARD_SIZE - 2, "{}", strategy);
+            assert!(!ranked.contains(&Position::new(0, 0)) && !ranked.contains(&Position::new(4, 4)));
+            assert!(ranked.contains(&Position::new(2, 6)));
+        }
+    }
+
+    #[test]
+    fn hunt_finishes_known_ships_before_searching() {
+        let mut hits = HitMap::new();
+        hits.insert(Position::new(4, 4), HitType::Hit);
+        hits.insert(Position::new(2, 6), HitType::Revealed);
+
+        let ranked = rank(Strategy::Hunt, &hits);
+        assert_eq!(ranked[0], Position::new(2, 6));
+        let mut around = ranked[1..5].to_vec();
+        around.sort_by_key(|pos| (pos.y, pos.x));
+        assert_eq!(around, [Position::new(4, 3), Position::new(3, 4), Position::new(5, 4), Position::new(4, 5)]);
+        // Then the checkerboard
+        assert!(ranked[5..].iter().take(20).all(|pos| (pos.x + pos.y) % 2 == 0));
+    }
+
+    #[test]
+    fn heat_follows_up_a_hit() {
+        let mut hits = HitMap::new();
+        hits.insert(Position::new(0, 0), HitType::Hit);
+        hits.insert(Position::new(1, 0), HitType::Miss);
+
+        // The ship hit in the corner can only continue downwards
+        assert_eq!(rank(Strategy::Heat, &hits)[0], Position::new(0, 1));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for strategy in Strategy::ALL {
+            assert_eq!(Strategy::from_name(&strategy.to_string()), Some(strategy));
+        }
+        assert_eq!(Strategy::from_name("psychic"), None);
+    }
+
+    #[test]
+    fn wounded_until_the_hits_are_accounted_for() {
+        let mut hits = HitMap::new();
+        assert!(!wounded(&hits, &SHIP_SPANS));
+
+        hits.insert(Position::new(0, 0), HitType::Hit);
+        assert!(wounded(&hits, &SHIP_SPANS));
+        hits.insert(Position::new(1, 0), HitType::Destroyed(3));
+        assert!(!wounded(&hits, &SHIP_SPANS));
+
+        hits.insert(Position::new(5, 5), HitType::Revealed);
+        assert!(wounded(&hits, &SHIP_SPANS));
+    }
+}
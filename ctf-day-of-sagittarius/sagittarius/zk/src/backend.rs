@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use sagittarius_game::{
+    abilities::{ClusterBombParams, ClusterCommit, SalvoCommit, SalvoParams, ScoutCommit, ScoutParams, ShotCommit, ShotParams},
+    state::GameState,
+    types::Digest,
+};
+use serde::{Deserialize, Serialize};
+
+/// Which guest program a proof belongs to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum TransitionKind {
+    Init,
+    Turn,
+    Scout,
+    Cluster,
+    Salvo,
+}
+
+/// A game transition together with its private input.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Transition {
+    Init(GameState),
+    Turn(ShotParams),
+    Scout(ScoutParams),
+    Cluster(ClusterBombParams),
+    Salvo(SalvoParams),
+}
+
+impl Transition {
+    pub fn kind(&self) -> TransitionKind {
+        match self {
+            Transition::Init(_) => TransitionKind::Init,
+            Transition::Turn(_) => TransitionKind::Turn,
+            Transition::Scout(_) => TransitionKind::Scout,
+            Transition::Cluster(_) => TransitionKind::Cluster,
+            Transition::Salvo(_) => TransitionKind::Salvo,
+        }
+    }
+}
+
+/// What a proof commits to, decoded from the backend's own journal format.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Commit {
+    /// Digest of the proven board
+    Init(Digest),
+    Turn(ShotCommit),
+    Scout(ScoutCommit),
+    Cluster(ClusterCommit),
+    Salvo(SalvoCommit),
+}
+
+impl Commit {
+    pub fn kind(&self) -> TransitionKind {
+        match self {
+            Commit::Init(_) => TransitionKind::Init,
+            Commit::Turn(_) => TransitionKind::Turn,
+            Commit::Scout(_) => TransitionKind::Scout,
+            Commit::Cluster(_) => TransitionKind::Cluster,
+            Commit::Salvo(_) => TransitionKind::Salvo,
+        }
+    }
+}
+
+/// A proof and the commit it proves.
+#[derive(Debug)]
+pub struct Proven {
+    pub proof: Vec<u8>,
+    pub commit: Commit,
+}
+
+/// Why a backend could not prove or verify a transition.
+#[derive(Debug)]
+pub enum Error {
+    /// The transition breaks the game rules, so there is nothing to prove
+    Invalid(String),
+    /// A proof, journal or input could not be encoded or decoded
+    Encoding(String),
+    /// The proof is well formed but does not verify
+    Rejected(String),
+    /// The prover itself failed
+    Prover(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Invalid(reason) => write!(f, "invalid transition: {}", reason),
+            Error::Encoding(reason) => write!(f, "encoding error: {}", reason),
+            Error::Rejected(reason) => write!(f, "proof rejected: {}", reason),
+            Error::Prover(reason) => write!(f, "prover failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Something that can prove game transitions and check those proofs.
+///
+/// Proofs are opaque bytes so they can be sent over the wire as is. Each backend decodes its
+/// own journal into a `Commit`, so every backend is checked by the same code in this crate.
+/// State digests are backend specific too, both players must use the same backend.
+pub trait Backend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn prove(&self, transition: &Transition) -> Result<Proven, Error>;
+
+    /// Returns the commit if `proof` is a valid proof of a `kind` transition.
+    fn verify(&self, kind: TransitionKind, proof: &[u8]) -> Result<Commit, Error>;
+
+    /// Digest of a game state, as committed to by this backend's proofs.
+    fn state_digest(&self, state: &GameState) -> Result<Digest, Error>;
+}
@@ -1,156 +1,95 @@
 use std::fs::File;
 use std::io::Write;
-use sagittarius_game::{state::{GameState, ShipDirection}, abilities::{ShotParams, ShotCommit, ClusterBombParams, ClusterCommit, ScoutParams, ScoutResult}, types::{Position, Digest, HitType}, BOARD_SIZE, SHIP_SPANS};
-use sagittarius_methods::{INIT_ID, INIT_ELF, TURN_ID, TURN_ELF, CLUSTER_ID, CLUSTER_ELF, SCOUT_ELF, SCOUT_ID};
-use risc0_zkvm::{Result, Receipt, serde, Prover};
+use anyhow::{bail, ensure, Result};
+use sagittarius_game::{state::GameState, abilities::{ShotParams, ClusterBombParams, ScoutParams, SalvoParams}, types::{Position, Digest, HitType}};
+use sagittarius_render::{player_grid, text};
 
-fn empty_board() -> [[String; BOARD_SIZE]; BOARD_SIZE] {
-    let mut board: [[String; BOARD_SIZE]; BOARD_SIZE] = Default::default();
+pub mod backend;
+pub mod reference;
+pub mod risc0;
 
-    for i in 0..BOARD_SIZE {
-        for j in 0..BOARD_SIZE {
-            board[i][j] = "   ".to_string();
-        }
-    }
-
-    board
-}
-
-fn render_player_cells(state: &GameState) -> [[String; BOARD_SIZE]; BOARD_SIZE] {
-    let mut board = empty_board();
-
-    for (i, ship) in state.ships.iter().enumerate() {
-        let ship_symbol = format!(" {} ", (b'A' + i as u8) as char);
-        for j in 0..SHIP_SPANS[i] {
-            let (x, y) = match ship.dir {
-                ShipDirection::Horizontal => (ship.pos.x as usize + j, ship.pos.y as usize),
-                ShipDirection::Vertical => (ship.pos.x as usize, ship.pos.y as usize + j),
-            };
-
-            if x < BOARD_SIZE && y < BOARD_SIZE {
-                if ship.hit_mask & (1 << j) != 0 {
-                    board[y][x] = " X ".to_string();
-                } else {
-                    board[y][x] = ship_symbol.clone();
-                }
-            }
-        }
-    }
-
-    board
-}
-
-fn create_board_representation(title: String, cells: [[String; BOARD_SIZE]; BOARD_SIZE]) -> Vec<String> {
-    let mut result = Vec::new();
-
-    // Calculate total width of the board
-    let total_width = BOARD_SIZE * 4 + 4;  // 2 characters per cell + 1 for the vertical divider
-
-    // Print title
-    let padded_title = format!("{0: ^1$}", title, total_width - 2);  // Center the title with padding
-    let title_frame = format!("┏{}┓", "━".repeat(total_width - 2));
-    let title_frame_bot = format!("┣━━┳{}━━━┫", "━━━┳".repeat(BOARD_SIZE - 1));
-    let title_line = format!("┃{}┃", padded_title);
-    result.push(title_frame);
-    result.push(title_line);
-    result.push(title_frame_bot);
-
-    // Print column letters
-    let letters_line = (b'a'..=b'a' + (BOARD_SIZE - 1) as u8).map(char::from).map(|c| format!(" {} ┃", c)).collect::<String>();
-    result.push(format!("┃  ┃{}", letters_line));
-
-    // Print board
-    for (i, row) in cells.iter().enumerate() {
-        let row_line = format!("┃ {}┃{}┃", i + 1, row.join("│"));
-        result.push("┣━━╋".to_string() + &"───┼".repeat(BOARD_SIZE - 1) + "───┨");
-        result.push(row_line);
-    }
-
-    // Add bottom border
-    result.push("┗━━┷".to_string() + &"━━━┷".repeat(BOARD_SIZE - 1) + "━━━┛");
-
-    result
-}
+pub use backend::{Backend, Commit, Transition, TransitionKind};
+pub use reference::ReferenceBackend;
+pub use risc0::Risc0Backend;
 
 // Init
 
-pub fn create_init_proof(input: &GameState) -> Result<Receipt> {
-    let mut prover = Prover::new(&INIT_ELF)?;
-    let vec = serde::to_vec(&input).unwrap();
-    prover.add_input_u32_slice(vec.as_slice());
-    prover.run()
+pub fn create_init_proof(backend: &dyn Backend, input: &GameState) -> Result<Vec<u8>> {
+    Ok(backend.prove(&Transition::Init(input.clone()))?.proof)
 }
 
-pub fn check_init_proof(receipt: &Receipt) -> Result<()> {
-    receipt.verify(&INIT_ID)
+pub fn check_init_proof(backend: &dyn Backend, proof: &[u8]) -> Result<Digest> {
+    let Commit::Init(digest) = backend.verify(TransitionKind::Init, proof)? else { bail!("Expected an init commit") };
+    Ok(digest)
 }
 
 // Simple shot proof
 
-pub fn create_turn_proof(input: &ShotParams) -> Result<Receipt> {
-    let mut prover = Prover::new(&TURN_ELF)?;
-    let vec = serde::to_vec(&input).unwrap();
-    prover.add_input_u32_slice(vec.as_slice());
-    prover.run()
+pub fn create_turn_proof(backend: &dyn Backend, input: &ShotParams) -> Result<Vec<u8>> {
+    Ok(backend.prove(&Transition::Turn(input.clone()))?.proof)
 }
 
-pub fn check_turn_proof(receipt: Receipt, shot: &Position, old_state: &Digest) -> Result<(HitType, Digest)> {
-    receipt.verify(&TURN_ID)?;
-    let journal = receipt.get_journal_bytes(); 
-    let commit = serde::from_slice::<ShotCommit, u8>(&journal).unwrap();
+pub fn check_turn_proof(backend: &dyn Backend, proof: &[u8], shot: &Position, old_state: &Digest) -> Result<(HitType, Digest)> {
+    let Commit::Turn(commit) = backend.verify(TransitionKind::Turn, proof)? else { bail!("Expected a turn commit") };
     // Make sure the prior state matches the current state
-    assert!(old_state == &commit.old_state_digest);
+    ensure!(old_state == &commit.old_state_digest, "Proof is for a different board state");
     // Make sure the response matches the prior shot
-    assert!(commit.shot == shot.clone());
+    ensure!(&commit.shot == shot, "Proof is for a different shot");
     
     Ok((commit.hit, commit.new_state_digest))
 }
 
+// Salvo proof
+
+pub fn create_salvo_proof(backend: &dyn Backend, input: &SalvoParams) -> Result<Vec<u8>> {
+    Ok(backend.prove(&Transition::Salvo(input.clone()))?.proof)
+}
+
+pub fn check_salvo_proof(backend: &dyn Backend, proof: &[u8], shots: &[Position], old_state: &Digest) -> Result<(Vec<HitType>, Digest)> {
+    let Commit::Salvo(commit) = backend.verify(TransitionKind::Salvo, proof)? else { bail!("Expected a salvo commit") };
+    // Make sure the prior state matches the current state
+    ensure!(old_state == &commit.old_state_digest, "Proof is for a different board state");
+    // Make sure the response matches the fired shots, in order
+    ensure!(commit.shots.as_slice() == shots, "Proof is for different shots");
+
+    Ok((commit.hits, commit.new_state_digest))
+}
+
 // Scout
-pub fn create_scout_proof(input: &ScoutParams) -> Result<Receipt> {
-    let mut prover = Prover::new(&SCOUT_ELF)?;
-    let vec = serde::to_vec(&input).unwrap();
-    prover.add_input_u32_slice(vec.as_slice());
-    prover.run()
+pub fn create_scout_proof(backend: &dyn Backend, input: &ScoutParams) -> Result<Vec<u8>> {
+    Ok(backend.prove(&Transition::Scout(input.clone()))?.proof)
 }
 
-pub fn check_scout_proof(receipt: Receipt, shot: &Position) -> Result<Vec<HitType>> {
-    receipt.verify(&SCOUT_ID)?;
-    let journal = receipt.get_journal_bytes(); 
-    let results = serde::from_slice::<ScoutResult, u8>(&journal).unwrap();
+pub fn check_scout_proof(backend: &dyn Backend, proof: &[u8], shot: &Position, old_state: &Digest) -> Result<(Vec<HitType>, Digest)> {
+    let Commit::Scout(commit) = backend.verify(TransitionKind::Scout, proof)? else { bail!("Expected a scout commit") };
+    // Make sure the prior state matches the current state
+    ensure!(old_state == &commit.old_state_digest, "Proof is for a different board state");
+    ensure!(&commit.shot == shot, "Proof is for a different scout position");
 
-    assert!(&results.shot == shot);
-    Ok(results.cells.to_vec())
+    Ok((commit.cells.to_vec(), commit.new_state_digest))
 }
 
 // Cluster bomb proof
 
-pub fn create_cluster_proof(input: &ClusterBombParams) -> Result<(Receipt, Vec<Position>)> {
-    let mut prover = Prover::new(&CLUSTER_ELF)?;
-    let vec = serde::to_vec(&input).unwrap();
-    prover.add_input_u32_slice(vec.as_slice());
-    let receipt = prover.run()?;
-
-    let journal = receipt.get_journal_bytes(); 
-    let commit = serde::from_slice::<ClusterCommit, u8>(&journal).unwrap();
+pub fn create_cluster_proof(backend: &dyn Backend, input: &ClusterBombParams) -> Result<(Vec<u8>, Vec<Position>)> {
+    let proven = backend.prove(&Transition::Cluster(input.clone()))?;
+    let Commit::Cluster(commit) = proven.commit else { bail!("Expected a cluster commit") };
 
-    Ok((receipt, commit.shots))
+    Ok((proven.proof, commit.shots))
 }
 
-pub fn check_cluster_proof(receipt: Receipt, ul: Position, dr: Position, seed: u8, old_state: Digest) -> Result<(Vec<Position>, Vec<HitType>, Digest)> {
-    receipt.verify(&CLUSTER_ID)?;
-    let journal = receipt.get_journal_bytes(); 
-    let commit = serde::from_slice::<ClusterCommit, u8>(&journal).unwrap();
+pub fn check_cluster_proof(backend: &dyn Backend, proof: &[u8], ul: Position, dr: Position, seed: u8, old_state: Digest) -> Result<(Vec<Position>, Vec<HitType>, Digest)> {
+    let Commit::Cluster(commit) = backend.verify(TransitionKind::Cluster, proof)? else { bail!("Expected a cluster commit") };
     // Make sure the prior state matches the current state
-    assert!(old_state == commit.old_state_digest);
+    ensure!(old_state == commit.old_state_digest, "Proof is for a different board state");
     // Make sure the response matches the Cluster config
-    assert!(commit.config.upper_left_coordinates == ul);
-    assert!(commit.config.down_right_coordinates == dr);
-    assert!(commit.config.seed == seed);
+    ensure!(commit.config.upper_left_coordinates == ul, "Proof is for a different area");
+    ensure!(commit.config.down_right_coordinates == dr, "Proof is for a different area");
+    ensure!(commit.config.seed == seed, "Proof is for a different seed");
 
     let path = "enemy.txt";
     let mut output = File::create(path)?;
-    let player_board = create_board_representation("Enemy Board".to_string(), render_player_cells(&commit.config.state));
+    let player_board = text::render("Enemy Board", &player_grid(&commit.config.state));
     for player_line in player_board.iter() {
         writeln!(output, "{}", player_line)?;
     }
@@ -0,0 +1,137 @@
+use sagittarius_game::{
+    abilities::{ClusterCommit, SalvoCommit, ScoutCommit, ShotCommit},
+    state::GameState,
+    types::{Digest, Position},
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+
+use crate::backend::{Backend, Commit, Error, Proven, Transition, TransitionKind};
+
+/// The "proof" is the witness itself: checking it replays the game rules in plain Rust.
+///
+/// It reveals the prover's board and is only meant for tests and local games
+/// where waiting for the zkVM is not worth it. State digests are SHA-256 of the CBOR encoded state.
+pub struct ReferenceBackend;
+
+#[derive(Deserialize, Serialize)]
+struct ReferenceProof {
+    transition: Transition,
+    /// Cells picked by a cluster charge. The guest picks them with host randomness, so they are part of the witness
+    shots: Vec<Position>,
+}
+
+impl ReferenceBackend {
+    /// Applies the same rules as the guests, returning an error wherever a guest would panic.
+    fn execute(&self, proof: &ReferenceProof) -> Result<Commit, Error> {
+        match &proof.transition {
+            Transition::Init(state) => {
+                ensure(state.check(), "Invalid GameState")?;
+                Ok(Commit::Init(self.state_digest(state)?))
+            }
+            Transition::Turn(params) => {
+                ensure(params.shot.check(), "Shot is off the board")?;
+                let result = params.process();
+                Ok(Commit::Turn(ShotCommit {
+                    old_state_digest: self.state_digest(&params.state)?,
+                    new_state_digest: self.state_digest(&result.state)?,
+                    shot: params.shot.clone(),
+                    hit: result.hit,
+                }))
+            }
+            Transition::Scout(params) => {
+                ensure(params.state.scouts > 0, "No scout charges left")?;
+                ensure(params.shot.check(), "Scout is off the board")?;
+                let result = params.process();
+                Ok(Commit::Scout(ScoutCommit {
+                    old_state_digest: self.state_digest(&params.state)?,
+                    new_state_digest: self.state_digest(&result.state)?,
+                    shot: result.shot,
+                    cells: result.cells,
+                }))
+            }
+            Transition::Cluster(params) => {
+                ensure(params.state.cluster_bombs > 0, "No cluster charges left")?;
+                let number_of_shots = params.check().map_err(|e| Error::Invalid(format!("cluster area: {}", e)))?;
+                let area = params.area();
+                ensure(proof.shots.len() == number_of_shots, "Wrong number of cluster shots")?;
+                ensure(proof.shots.iter().all(|shot| area.contains(shot)), "Cluster shot outside the area")?;
+                ensure(
+                    proof.shots.iter().enumerate().all(|(i, shot)| !proof.shots[..i].contains(shot)),
+                    "Repeated cluster shot",
+                )?;
+
+                let result = params.process(&proof.shots);
+                Ok(Commit::Cluster(ClusterCommit {
+                    old_state_digest: self.state_digest(&params.state)?,
+                    new_state_digest: self.state_digest(&result.state)?,
+                    config: params.clone(),
+                    shots: proof.shots.clone(),
+                    hits: result.hits,
+                }))
+            }
+            Transition::Salvo(params) => {
+                ensure(params.check(), "Invalid salvo")?;
+                let result = params.process();
+                Ok(Commit::Salvo(SalvoCommit {
+                    old_state_digest: self.state_digest(&params.state)?,
+                    new_state_digest: self.state_digest(&result.state)?,
+                    shots: params.shots.clone(),
+                    hits: result.hits,
+                }))
+            }
+        }
+    }
+}
+
+impl Backend for ReferenceBackend {
+    fn name(&self) -> &'static str {
+        "reference"
+    }
+
+    fn prove(&self, transition: &Transition) -> Result<Proven, Error> {
+        // Spread the cluster shots over the area from the seed, like the guest does with random bytes
+        let shots = match transition {
+            Transition::Cluster(params) => {
+                let number_of_shots = params.check().map_err(|e| Error::Invalid(format!("cluster area: {}", e)))?;
+                let mut area = params.area().positions();
+                (0..number_of_shots)
+                    .map(|i| area.remove((params.seed as usize + 7 * i) % area.len()))
+                    .collect()
+            }
+            _ => vec![],
+        };
+        let proof = ReferenceProof { transition: transition.clone(), shots };
+
+        Ok(Proven {
+            commit: self.execute(&proof)?,
+            proof: serde_cbor::to_vec(&proof).map_err(|e| Error::Encoding(e.to_string()))?,
+        })
+    }
+
+    fn verify(&self, kind: TransitionKind, proof: &[u8]) -> Result<Commit, Error> {
+        let proof: ReferenceProof = serde_cbor::from_slice(proof).map_err(|e| Error::Encoding(e.to_string()))?;
+        if proof.transition.kind() != kind {
+            return Err(Error::Rejected(format!("expected a {:?} proof", kind)));
+        }
+        self.execute(&proof)
+    }
+
+    fn state_digest(&self, state: &GameState) -> Result<Digest, Error> {
+        let encoded = serde_cbor::to_vec(state).map_err(|e| Error::Encoding(e.to_string()))?;
+        let mut digest = [0u32; 8];
+        for (word, bytes) in digest.iter_mut().zip(Sha256::digest(encoded).chunks(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+        Ok(digest)
+    }
+}
+
+/// Rejects the transition with `reason` unless `rule` holds.
+fn ensure(rule: bool, reason: &str) -> Result<(), Error> {
+    if rule {
+        Ok(())
+    } else {
+        Err(Error::Invalid(reason.to_string()))
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::Debug;
+
+use risc0_zkvm::{serde::{from_slice, to_vec}, sha::{Impl, Sha256}, Prover, Receipt};
+use sagittarius_game::{state::GameState, types::Digest};
+use sagittarius_methods::{
+    CLUSTER_ELF, CLUSTER_ID, INIT_ELF, INIT_ID, SALVO_ELF, SALVO_ID, SCOUT_ELF, SCOUT_ID, TURN_ELF, TURN_ID,
+};
+
+use crate::backend::{Backend, Commit, Error, Proven, Transition, TransitionKind};
+
+/// Proves transitions with the guests in `sagittarius-methods`. Proofs are CBOR encoded receipts.
+///
+/// Journals and state digests are in the RISC Zero serde format the guests commit with.
+pub struct Risc0Backend;
+
+impl Risc0Backend {
+    fn image(kind: TransitionKind) -> (&'static [u8], [u32; 8]) {
+        match kind {
+            TransitionKind::Init => (INIT_ELF, INIT_ID),
+            TransitionKind::Turn => (TURN_ELF, TURN_ID),
+            TransitionKind::Scout => (SCOUT_ELF, SCOUT_ID),
+            TransitionKind::Cluster => (CLUSTER_ELF, CLUSTER_ID),
+            TransitionKind::Salvo => (SALVO_ELF, SALVO_ID),
+        }
+    }
+
+    /// The guest input, serialized the way `env::read` expects it.
+    fn input(transition: &Transition) -> Result<Vec<u32>, Error> {
+        let input = match transition {
+            Transition::Init(state) => to_vec(state),
+            Transition::Turn(params) => to_vec(params),
+            Transition::Scout(params) => to_vec(params),
+            Transition::Cluster(params) => to_vec(params),
+            Transition::Salvo(params) => to_vec(params),
+        };
+        input.map_err(encoding)
+    }
+
+    /// Decodes the journal the `kind` guest commits.
+    fn commit(kind: TransitionKind, journal: &[u8]) -> Result<Commit, Error> {
+        let commit = match kind {
+            TransitionKind::Init => Commit::Init(from_slice(journal).map_err(encoding)?),
+            TransitionKind::Turn => Commit::Turn(from_slice(journal).map_err(encoding)?),
+            TransitionKind::Scout => Commit::Scout(from_slice(journal).map_err(encoding)?),
+            TransitionKind::Cluster => Commit::Cluster(from_slice(journal).map_err(encoding)?),
+            TransitionKind::Salvo => Commit::Salvo(from_slice(journal).map_err(encoding)?),
+        };
+        Ok(commit)
+    }
+}
+
+fn encoding(e: impl Debug) -> Error {
+    Error::Encoding(format!("{:?}", e))
+}
+
+impl Backend for Risc0Backend {
+    fn name(&self) -> &'static str {
+        "risc0"
+    }
+
+    fn prove(&self, transition: &Transition) -> Result<Proven, Error> {
+        let kind = transition.kind();
+        let mut prover = Prover::new(Self::image(kind).0).map_err(|e| Error::Prover(e.to_string()))?;
+        prover.add_input_u32_slice(Self::input(transition)?.as_slice());
+        let receipt = prover.run().map_err(|e| Error::Prover(e.to_string()))?;
+
+        Ok(Proven {
+            commit: Self::commit(kind, receipt.get_journal_bytes())?,
+            proof: serde_cbor::to_vec(&receipt).map_err(encoding)?,
+        })
+    }
+
+    fn verify(&self, kind: TransitionKind, proof: &[u8]) -> Result<Commit, Error> {
+        let receipt: Receipt = serde_cbor::from_slice(proof).map_err(encoding)?;
+        receipt.verify(&Self::image(kind).1).map_err(|e| Error::Rejected(e.to_string()))?;
+        Self::commit(kind, receipt.get_journal_bytes())
+    }
+
+    /// SHA-256 of the state in RISC Zero serde words, as computed by the guests.
+    fn state_digest(&self, state: &GameState) -> Result<Digest, Error> {
+        let mut digest = [0u32; 8];
+        digest.copy_from_slice(Impl::hash_words(to_vec(state).map_err(encoding)?.as_slice()).as_words());
+        Ok(digest)
+    }
+}
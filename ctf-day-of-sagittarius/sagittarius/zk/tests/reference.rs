@@ -0,0 +1,100 @@
+use sagittarius_game::{
+    abilities::{ClusterBombParams, SalvoParams, ScoutParams, ShotParams},
+    state::{GameState, Ship, ShipDirection},
+    types::{HitType, Position},
+};
+use sagittarius_zk::{
+    check_init_proof, check_salvo_proof, check_scout_proof, check_turn_proof,
+    create_cluster_proof, create_init_proof, create_salvo_proof, create_scout_proof, create_turn_proof,
+    Backend, ReferenceBackend, TransitionKind,
+};
+
+fn board() -> GameState {
+    GameState::new(
+        [
+            Ship::new(0, 0, ShipDirection::Horizontal),
+            Ship::new(0, 2, ShipDirection::Vertical),
+            Ship::new(2, 2, ShipDirection::Horizontal),
+            Ship::new(7, 6, ShipDirection::Vertical),
+        ],
+        42,
+    )
+}
+
+#[test]
+fn init_proof_commits_to_the_board() {
+    let state = board();
+    let proof = create_init_proof(&ReferenceBackend, &state).unwrap();
+    assert_eq!(check_init_proof(&ReferenceBackend, &proof).unwrap(), ReferenceBackend.state_digest(&state).unwrap());
+}
+
+#[test]
+fn invalid_board_cannot_be_proven() {
+    let mut state = board();
+    state.ships[1] = Ship::new(0, 0, ShipDirection::Vertical);
+    assert!(create_init_proof(&ReferenceBackend, &state).is_err());
+}
+
+#[test]
+fn turns_chain_state_digests() {
+    let state = board();
+    let digest = ReferenceBackend.state_digest(&state).unwrap();
+
+    let params = ShotParams::new(state, 7, 6);
+    let proof = create_turn_proof(&ReferenceBackend, &params).unwrap();
+    let (hit, digest) = check_turn_proof(&ReferenceBackend, &proof, &params.shot, &digest).unwrap();
+    assert_eq!(hit, HitType::Hit);
+
+    let state = params.process().state;
+    let params = ShotParams::new(state, 7, 7);
+    let proof = create_turn_proof(&ReferenceBackend, &params).unwrap();
+    let (hit, _) = check_turn_proof(&ReferenceBackend, &proof, &params.shot, &digest).unwrap();
+    assert_eq!(hit, HitType::Destroyed(3));
+}
+
+#[test]
+fn proof_for_another_state_or_shot_is_rejected() {
+    let state = board();
+    let params = ShotParams::new(state.clone(), 1, 0);
+    let proof = create_turn_proof(&ReferenceBackend, &params).unwrap();
+
+    let stale = ReferenceBackend.state_digest(&params.process().state).unwrap();
+    assert!(check_turn_proof(&ReferenceBackend, &proof, &params.shot, &stale).is_err());
+
+    let digest = ReferenceBackend.state_digest(&state).unwrap();
+    assert!(check_turn_proof(&ReferenceBackend, &proof, &Position::new(2, 0), &digest).is_err());
+}
+
+#[test]
+fn proof_kind_is_checked() {
+    let params = ShotParams::new(board(), 1, 0);
+    let proof = create_turn_proof(&ReferenceBackend, &params).unwrap();
+    assert!(ReferenceBackend.verify(TransitionKind::Scout, &proof).is_err());
+}
+
+#[test]
+fn scout_and_salvo_proofs_verify() {
+    let state = board();
+    let digest = ReferenceBackend.state_digest(&state).unwrap();
+
+    let params = ScoutParams { state: state.clone(), shot: Position::new(1, 1) };
+    let proof = create_scout_proof(&ReferenceBackend, &params).unwrap();
+    let (cells, _) = check_scout_proof(&ReferenceBackend, &proof, &params.shot, &digest).unwrap();
+    assert_eq!(cells, params.process().cells.to_vec());
+
+    let shots = vec![Position::new(0, 0), Position::new(5, 5)];
+    let params = SalvoParams::new(state, shots.clone());
+    let proof = create_salvo_proof(&ReferenceBackend, &params).unwrap();
+    let (hits, _) = check_salvo_proof(&ReferenceBackend, &proof, &shots, &digest).unwrap();
+    assert_eq!(hits, vec![HitType::Hit, HitType::Miss]);
+}
+
+#[test]
+fn cluster_shots_stay_in_the_area() {
+    let params = ClusterBombParams::new(board(), Position::new(1, 1), Position::new(3, 3), 200);
+    let (proof, shots) = create_cluster_proof(&ReferenceBackend, &params).unwrap();
+
+    assert_eq!(shots.len(), params.check().unwrap());
+    assert!(shots.iter().all(|shot| params.area().contains(shot)));
+    assert!(ReferenceBackend.verify(TransitionKind::Cluster, &proof).is_ok());
+}
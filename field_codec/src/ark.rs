@@ -0,0 +1,32 @@
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::{bytes_to_text, text_to_bytes, Error};
+
+/// Bytes needed for any element of `F`
+pub fn width<F: PrimeField>() -> usize {
+    (F::size_in_bits() + 7) / 8
+}
+
+/// Canonical big-endian bytes of `x`, exactly `width::<F>()` of them.
+pub fn to_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    let bytes = x.into_repr().to_bytes_be();
+    // The limbs may be wider than the modulus, those bytes are always zero
+    bytes[bytes.len() - width::<F>()..].to_vec()
+}
+
+/// Inverse of `to_bytes`, rejecting anything that isn't the encoding of an element.
+pub fn from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, Error> {
+    let x = F::from_be_bytes_mod_order(bytes);
+    if to_bytes(&x) != bytes {
+        return Err(Error::NotCanonical);
+    }
+    Ok(x)
+}
+
+pub fn to_text<F: PrimeField>(x: &F) -> String {
+    bytes_to_text(&to_bytes(x))
+}
+
+pub fn from_text<F: PrimeField>(text: &str) -> Result<F, Error> {
+    from_bytes(&text_to_bytes(text, width::<F>())?)
+}
@@ -0,0 +1,52 @@
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    traits::ByteConversion,
+};
+
+use crate::{bytes_to_text, text_to_bytes, Error};
+
+/// Bytes of the representation of `F`, the modulus may be narrower
+pub fn width<F: IsField>() -> usize
+where
+    FieldElement<F>: ByteConversion,
+{
+    FieldElement::<F>::zero().to_bytes_be().len()
+}
+
+/// Canonical big-endian bytes of `x`, exactly `width::<F>()` of them.
+pub fn to_bytes<F: IsField>(x: &FieldElement<F>) -> Vec<u8>
+where
+    FieldElement<F>: ByteConversion,
+{
+    x.to_bytes_be()
+}
+
+/// Inverse of `to_bytes`, rejecting anything that isn't the encoding of an element.
+pub fn from_bytes<F: IsField>(bytes: &[u8]) -> Result<FieldElement<F>, Error>
+where
+    FieldElement<F>: ByteConversion,
+{
+    if bytes.len() != width::<F>() {
+        return Err(Error::NotCanonical);
+    }
+    // Values above the modulus are reduced, which the round trip catches
+    let x = FieldElement::<F>::from_bytes_be(bytes).map_err(|_| Error::NotCanonical)?;
+    if to_bytes(&x) != bytes {
+        return Err(Error::NotCanonical);
+    }
+    Ok(x)
+}
+
+pub fn to_text<F: IsField>(x: &FieldElement<F>) -> String
+where
+    FieldElement<F>: ByteConversion,
+{
+    bytes_to_text(&to_bytes(x))
+}
+
+pub fn from_text<F: IsField>(text: &str) -> Result<FieldElement<F>, Error>
+where
+    FieldElement<F>: ByteConversion,
+{
+    from_bytes(&text_to_bytes(text, width::<F>())?)
+}
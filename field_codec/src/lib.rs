@@ -0,0 +1,48 @@
+//! Converts between field elements and the short strings the challenges hide in them.
+//!
+//! A string is read as the canonical big-endian bytes of the element, one Latin-1 character per byte.
+//! The bytes are left-padded with zeros up to the width of the field, so leading zero bytes are padding
+//! and are dropped when decoding. It follows that a string can't start with a NUL character, and can be
+//! at most as long as the field is wide, less if its value would not be below the modulus.
+//!
+//! The byte rules live here, the `ark` and `lambdaworks` features add the conversions for each library.
+
+#[cfg(feature = "ark")]
+pub mod ark;
+#[cfg(feature = "lambdaworks")]
+pub mod lambdaworks;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// More characters than the field has bytes
+    TooLong { len: usize, width: usize },
+    /// Only characters up to U+00FF have a single byte
+    NotLatin1(char),
+    /// Would be dropped as padding when decoding
+    LeadingNul,
+    /// The bytes are not below the modulus, or not as wide as the field
+    NotCanonical,
+}
+
+/// Strips the padding from canonical big-endian bytes and reads the rest as text.
+pub fn bytes_to_text(bytes: &[u8]) -> String {
+    bytes.iter().skip_while(|&&b| b == 0).map(|&b| b as char).collect()
+}
+
+/// The bytes of `text`, left-padded with zeros to `width`.
+pub fn text_to_bytes(text: &str, width: usize) -> Result<Vec<u8>, Error> {
+    if text.starts_with('\0') {
+        return Err(Error::LeadingNul);
+    }
+    let text = text
+        .chars()
+        .map(|c| u8::try_from(u32::from(c)).map_err(|_| Error::NotLatin1(c)))
+        .collect::<Result<Vec<u8>, _>>()?;
+    if text.len() > width {
+        return Err(Error::TooLong { len: text.len(), width });
+    }
+
+    let mut bytes = vec![0; width - text.len()];
+    bytes.extend(text);
+    Ok(bytes)
+}
@@ -0,0 +1,56 @@
+use ark_bls12_381::Fr as F;
+use ark_ff::{BigInteger, FpParameters, One, PrimeField, Zero};
+use field_codec::{
+    ark::{from_bytes, from_text, to_bytes, to_text, width},
+    Error,
+};
+
+#[test]
+fn text_round_trips() {
+    for text in ["", "A", "Flag", "ZK_R3scue!", "\u{e9}t\u{e9}", "a\0b"] {
+        assert_eq!(to_text(&from_text::<F>(text).unwrap()), text);
+    }
+}
+
+#[test]
+fn bytes_round_trip() {
+    for x in [F::zero(), F::one(), F::from(0x4142u64), -F::one()] {
+        let bytes = to_bytes(&x);
+        assert_eq!(bytes.len(), width::<F>());
+        assert_eq!(from_bytes::<F>(&bytes).unwrap(), x);
+    }
+}
+
+#[test]
+fn text_is_left_padded() {
+    assert_eq!(width::<F>(), 32);
+
+    let mut bytes = vec![0; 30];
+    bytes.extend(b"AB");
+    assert_eq!(to_bytes(&from_text::<F>("AB").unwrap()), bytes);
+    assert_eq!(from_text::<F>("AB").unwrap(), F::from(0x4142u64));
+}
+
+#[test]
+fn leading_zeros_are_padding() {
+    // Printed as hex this has an odd number of digits
+    assert_eq!(to_text(&F::from(0x141u64)), "\u{1}A");
+    assert_eq!(to_text(&F::zero()), "");
+}
+
+#[test]
+fn bad_text_is_rejected() {
+    assert_eq!(from_text::<F>(&"A".repeat(33)), Err(Error::TooLong { len: 33, width: 32 }));
+    assert_eq!(from_text::<F>("\u{263a}"), Err(Error::NotLatin1('\u{263a}')));
+    assert_eq!(from_text::<F>("\0A"), Err(Error::LeadingNul));
+    // Fits in the width but not below the modulus
+    assert_eq!(from_text::<F>(&"\u{ff}".repeat(32)), Err(Error::NotCanonical));
+}
+
+#[test]
+fn non_canonical_bytes_are_rejected() {
+    let modulus = F::Params::MODULUS.to_bytes_be();
+    assert_eq!(from_bytes::<F>(&modulus), Err(Error::NotCanonical));
+    assert_eq!(from_bytes::<F>(&[1]), Err(Error::NotCanonical));
+    assert_eq!(from_bytes::<F>(&[0; 33]), Err(Error::NotCanonical));
+}
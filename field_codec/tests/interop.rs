@@ -0,0 +1,16 @@
+use ark_bls12_381::Fr;
+use field_codec::{ark, lambdaworks};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+/// Both libraries agree on the bytes of the BLS12-381 scalar field
+#[test]
+fn same_encoding_in_both_libraries() {
+    for text in ["", "A", "Flag", "ZK_R3scue!"] {
+        let x: Fr = ark::from_text(text).unwrap();
+        let y: FrElement = lambdaworks::from_text(text).unwrap();
+        assert_eq!(ark::to_bytes(&x), lambdaworks::to_bytes(&y));
+    }
+
+    let minus_one = ark::to_bytes(&-Fr::from(1u64));
+    assert_eq!(lambdaworks::from_bytes(&minus_one), Ok(-FrElement::one()));
+}
@@ -0,0 +1,48 @@
+use field_codec::{
+    lambdaworks::{from_bytes, from_text, to_bytes, to_text},
+    Error,
+};
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrElement;
+
+#[test]
+fn text_round_trips() {
+    for text in ["", "A", "Flag", "ZK_R3scue!", "\u{e9}t\u{e9}", "a\0b"] {
+        let x: FrElement = from_text(text).unwrap();
+        assert_eq!(to_text(&x), text);
+    }
+}
+
+#[test]
+fn bytes_round_trip() {
+    for x in [FrElement::zero(), FrElement::one(), FrElement::from(0x4142), -FrElement::one()] {
+        let bytes = to_bytes(&x);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(from_bytes(&bytes), Ok(x));
+    }
+}
+
+#[test]
+fn text_is_left_padded() {
+    let mut bytes = vec![0; 30];
+    bytes.extend(b"AB");
+    let x: FrElement = from_text("AB").unwrap();
+    assert_eq!(to_bytes(&x), bytes);
+    assert_eq!(x, FrElement::from(0x4142));
+}
+
+#[test]
+fn leading_zeros_are_padding() {
+    // The old decoder split this hex into the wrong pairs
+    assert_eq!(to_text(&FrElement::from(0x141)), "\u{1}A");
+    assert_eq!(to_text(&FrElement::zero()), "");
+}
+
+#[test]
+fn bad_input_is_rejected() {
+    let decode = |text: &str| from_text(text).map(|x: FrElement| x);
+    assert_eq!(decode(&"A".repeat(33)), Err(Error::TooLong { len: 33, width: 32 }));
+    assert_eq!(decode("\0A"), Err(Error::LeadingNul));
+    // Fits in the width but not below the modulus
+    assert_eq!(decode(&"\u{ff}".repeat(32)), Err(Error::NotCanonical));
+    assert_eq!(from_bytes(&[1]).map(|x: FrElement| x), Err(Error::NotCanonical));
+}
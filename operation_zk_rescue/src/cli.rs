@@ -0,0 +1,220 @@
+use ark_poly::{univariate::DensePolynomial, EvaluationDomain, GeneralEvaluationDomain, UVPolynomial};
+use ark_poly_commit::{LabeledPolynomial, PolynomialCommitment};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::RngCore;
+use std::{fs::{self, File}, io::{BufReader, BufWriter}, time::Instant};
+use sumcheck::{domain_sum, format::{self, Kind}, Proof, Statement, SumcheckProver, SumcheckVerifier};
+
+use crate::{flag_check::Pace, identity_evaluations, setup, Ipa, Marlin, Sonic, F, FS, MAX_DEGREE};
+
+pub const USAGE: &str = "\
+Usage:
+    puzzle                                              play the puzzle
+    puzzle [--fast] [--message <text> | --message-file <path>]
+                                                        play without pauses or story, with the message for HQ
+                                                        given instead of typed in
+    puzzle prove [--pc <backend>] <statement> <proof>   prove the sum of the Forger's identity, writing both files
+    puzzle verify [--pc <backend>] <statement> <proof>  verify a proof read from disk
+    puzzle compare                                      prove and verify over every backend
+
+Backends: marlin (default), sonic, ipa
+";
+
+/// Polynomial commitment schemes selectable with `--pc`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Backend {
+    Marlin,
+    Sonic,
+    Ipa,
+}
+
+impl Backend {
+    const ALL: [Backend; 3] = [Backend::Marlin, Backend::Sonic, Backend::Ipa];
+
+    fn name(self) -> &'static str {
+        match self {
+            Backend::Marlin => "marlin",
+            Backend::Sonic => "sonic",
+            Backend::Ipa => "ipa",
+        }
+    }
+
+    /// Marlin and IPA enforce degree bounds with a second, shifted commitment. Sonic's never carry one
+    fn shifted_commitments(self) -> bool {
+        !matches!(self, Backend::Sonic)
+    }
+}
+
+/// Plays the puzzle, `--fast` without the pauses and story, and with a given message without prompting for it.
+/// Fails with the reason if the sumcheck or the message is rejected.
+pub fn rescue(args: &[String]) -> Result<(), String> {
+    let mut pace = Pace::Story;
+    let mut message = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--fast" => pace = Pace::Fast,
+            "--message" => message = Some(args.next().ok_or_else(|| USAGE.to_string())?.clone()),
+            "--message-file" => {
+                let path = args.next().ok_or_else(|| USAGE.to_string())?;
+                message = Some(fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path, e))?);
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    crate::rescue(pace, message)
+}
+
+/// Splits `--pc <backend>` off the arguments, the remaining ones must be the statement and proof paths.
+fn parse(args: &[String]) -> Result<(Backend, &str, &str), String> {
+    let mut backend = Backend::Marlin;
+    let mut paths = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--pc" {
+            let name = args.next().ok_or_else(|| USAGE.to_string())?;
+            backend = *Backend::ALL
+                .iter()
+                .find(|backend| backend.name() == name)
+                .ok_or_else(|| format!("Unknown backend {}\n\n{}", name, USAGE))?;
+        } else {
+            paths.push(arg.as_str());
+        }
+    }
+
+    match paths[..] {
+        [statement, proof] => Ok((backend, statement, proof)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// The statement that the identity polynomial sums to its true sum, and a proof of it.
+fn identity_proof<PC: PolynomialCommitment<F, DensePolynomial<F>>>(
+    ck: PC::CommitterKey,
+    domain: GeneralEvaluationDomain<F>,
+    rng: &mut impl RngCore,
+) -> Result<(Statement<F, PC>, Proof<F, PC>), String> {
+    let f = DensePolynomial::from_coefficients_vec(domain.ifft(&identity_evaluations()));
+    let sum = domain_sum(&f, domain);
+    let f = LabeledPolynomial::new("f".into(), f, None, Some(1));
+    let (f_commitment, f_rand) = PC::commit(&ck, &[f.clone()], Some(&mut *rng)).map_err(|e| format!("Commitment failed: {:?}", e))?;
+    let statement = Statement { domain, max_degree: MAX_DEGREE, f: f_commitment[0].commitment().clone(), sum };
+
+    let prover = SumcheckProver::<F, PC, FS>::new(ck);
+    let proof = prover.prove(&statement, &f, &f_rand[0], rng).map_err(|e| format!("Proving failed: {:?}", e))?;
+    Ok((statement, proof))
+}
+
+fn verify_proof<PC: PolynomialCommitment<F, DensePolynomial<F>>>(
+    verifier: &SumcheckVerifier<F, PC, FS>,
+    statement: &Statement<F, PC>,
+    proof: &Proof<F, PC>,
+    rng: &mut impl RngCore,
+) -> Result<(), String> {
+    verifier
+        .verify(statement, proof, rng)
+        .map_err(|e| format!("Sumcheck validation failure! {:?}", e))
+}
+
+/// Proves the true sum of the identity polynomial. Keys are deterministic, so `verify` rebuilds them.
+pub fn prove(args: &[String]) -> Result<(), String> {
+    match parse(args)? {
+        (Backend::Marlin, statement, proof) => prove_with::<Marlin>(statement, proof),
+        (Backend::Sonic, statement, proof) => prove_with::<Sonic>(statement, proof),
+        (Backend::Ipa, statement, proof) => prove_with::<Ipa>(statement, proof),
+    }
+}
+
+fn prove_with<PC>(statement_path: &str, proof_path: &str) -> Result<(), String>
+where
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalSerialize,
+    PC::BatchProof: CanonicalSerialize,
+{
+    let (ck, _, domain, mut rng) = setup::<PC>();
+    let (statement, proof) = identity_proof::<PC>(ck, domain, &mut rng)?;
+
+    write_file(statement_path, Kind::Statement, &statement)?;
+    write_file(proof_path, Kind::Proof, &proof)?;
+
+    println!("Proof written to {}", proof_path);
+    Ok(())
+}
+
+/// Writes `value` to a new file, flushing it so a failed write isn't lost when the buffer is dropped.
+fn write_file<T: CanonicalSerialize>(path: &str, kind: Kind, value: &T) -> Result<(), String> {
+    let mut file = File::create(path).map(BufWriter::new).map_err(|e| format!("Can't create {}: {}", path, e))?;
+    format::write(kind, value, &mut file).map_err(|e| format!("Can't write {}: {:?}", path, e))?;
+    file.into_inner().map_err(|e| format!("Can't write {}: {}", path, e.error()))?;
+    Ok(())
+}
+
+/// Verifies with the keys of the chosen backend, which must be the one the proof was made with.
+pub fn verify(args: &[String]) -> Result<(), String> {
+    let (backend, statement, proof) = parse(args)?;
+    match backend {
+        Backend::Marlin => verify_with::<Marlin>(backend, statement, proof),
+        Backend::Sonic => verify_with::<Sonic>(backend, statement, proof),
+        Backend::Ipa => verify_with::<Ipa>(backend, statement, proof),
+    }
+}
+
+fn verify_with<PC>(backend: Backend, statement_path: &str, proof_path: &str) -> Result<(), String>
+where
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalDeserialize,
+    PC::BatchProof: CanonicalDeserialize,
+{
+    let open = |path: &str| File::open(path).map(BufReader::new).map_err(|e| format!("Can't open {}: {}", path, e));
+    let statement: Statement<F, PC> =
+        format::read(Kind::Statement, open(statement_path)?).map_err(|e| format!("Can't read {}: {:?}", statement_path, e))?;
+    let proof = format::read(Kind::Proof, open(proof_path)?).map_err(|e| format!("Can't read {}: {:?}", proof_path, e))?;
+
+    // The files may come from anyone, so commitments missing their shifted part are rejected before checking them
+    let (_, vk, _, mut rng) = setup::<PC>();
+    let mut verifier = SumcheckVerifier::new(vk);
+    if backend.shifted_commitments() {
+        verifier = verifier.with_shifted_commitments();
+    }
+    verify_proof(&verifier, &statement, &proof, &mut rng)?;
+    println!("Sumcheck validation successful!");
+    Ok(())
+}
+
+/// Proves and verifies the identity's sum over every backend, reporting proof sizes and timings.
+/// Key generation is left out of the timings.
+pub fn compare() -> Result<(), String> {
+    println!("{:<8} {:>12} {:>12} {:>12}", "backend", "proof bytes", "prove ms", "verify ms");
+    for backend in Backend::ALL {
+        let (size, prove, verify) = match backend {
+            Backend::Marlin => measure::<Marlin>()?,
+            Backend::Sonic => measure::<Sonic>()?,
+            Backend::Ipa => measure::<Ipa>()?,
+        };
+        println!("{:<8} {:>12} {:>12.1} {:>12.1}", backend.name(), size, prove, verify);
+    }
+    Ok(())
+}
+
+/// Proof size in bytes, then proving and verification times in milliseconds.
+fn measure<PC>() -> Result<(usize, f64, f64), String>
+where
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalSerialize,
+    PC::BatchProof: CanonicalSerialize,
+{
+    let (ck, vk, domain, mut rng) = setup::<PC>();
+
+    let start = Instant::now();
+    let (statement, proof) = identity_proof::<PC>(ck, domain, &mut rng)?;
+    let prove = start.elapsed();
+
+    let verifier = SumcheckVerifier::new(vk);
+    let start = Instant::now();
+    verify_proof(&verifier, &statement, &proof, &mut rng)?;
+    let verify = start.elapsed();
+
+    let millis = |duration: std::time::Duration| duration.as_secs_f64() * 1000.0;
+    Ok((proof.serialized_size(), millis(prove), millis(verify)))
+}
@@ -1,78 +1,112 @@
 use std::{thread, time,io};
 use sha2::{Sha256,Digest};
 
-pub fn flag_quest(){
-    //Woe Jinx Validation
-    thread::sleep(time::Duration::from_secs(2));
-    println!("\n\nWoe Jinx: You have not been Jinxed");
-    thread::sleep(time::Duration::from_secs(2));
-    println!("Identity verified and message authenticated.");
-    thread::sleep(time::Duration::from_secs(2));
-    println!("Communication terminated.\n");
+/// How the rescue is played out. Fast skips the pauses and the story, leaving only the checks
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pace {
+    Story,
+    Fast,
+}
 
-    //Security loophole window: HQ must act fast and make contact
-    for _ in 0..10 {
-        thread::sleep(time::Duration::from_secs(1));
-        println!(" ");
+impl Pace {
+    pub fn pause(self, secs: u64) {
+        if self == Pace::Story {
+            thread::sleep(time::Duration::from_secs(secs));
+        }
+    }
+
+    pub fn is_fast(self) -> bool {
+        self == Pace::Fast
+    }
+}
+
+/// Checks the message for HQ, read from stdin unless given. Fails if it's the wrong one
+pub fn flag_quest(pace: Pace, message: Option<String>) -> Result<(), String> {
+    if !pace.is_fast() {
+        //Woe Jinx Validation
+        pace.pause(2);
+        println!("\n\nWoe Jinx: You have not been Jinxed");
+        pace.pause(2);
+        println!("Identity verified and message authenticated.");
+        pace.pause(2);
+        println!("Communication terminated.\n");
+
+        //Security loophole window: HQ must act fast and make contact
+        for _ in 0..10 {
+            pace.pause(1);
+            println!(" ");
+        }
+        pace.pause(5);
+        println!("Beep.. Beep..Incoming transmission....");
+        pace.pause(5);
     }
-    thread::sleep(time::Duration::from_secs(5));
-    println!("Beep.. Beep..Incoming transmission....");
-    thread::sleep(time::Duration::from_secs(5));
     println!("HQ: Awaiting status zulu");
+    let message = match message {
+        Some(message) => message,
+        None => {
+            let mut message = String::new();
+            io::stdin()
+                .read_line(&mut message)
+                .map_err(|e| format!("Failed to read line: {}", e))?;
+            message
+        }
+    };
     let mut hasher = Sha256::new();
-    let mut message = String::new();
-    io::stdin()
-        .read_line(&mut message)
-        .expect("Failed to read line");
     hasher.update(message.trim().as_bytes());
     let res_hash = hasher.finalize();
     let comp= hex::encode(res_hash).to_string();
     let expected_msg = "25b565e4cd3a97540a07210ff3805e20da57fb925927b08af54f241360e75d19";
-    if comp == expected_msg{
-        thread::sleep(time::Duration::from_secs(5));
-        println!("HQ: Good job!, Flag success!! we are on our way!, stay safe.");
-        for _ in 0..10 {
-            thread::sleep(time::Duration::from_secs(1));
-            println!(" ");
-        }
-        println!("Red: Hello there! Seems like you have proven your mettle!\n");
-        thread::sleep(time::Duration::from_secs(2));
-        println!("Have you heard about the evolution of ZK?, Does it seem like a Philosophical question?\n");
-        thread::sleep(time::Duration::from_secs(3)); 
-        println!("Even for the most philosophical questions, the answer is often right in front of you.\n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("As you probably know by now, your HQ guys call me a concierge of crime.\n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("I prefer the term connoisseur, since I am a man of good taste, even in crime.\n");
-        thread::sleep(time::Duration::from_secs(4));
-        println!("31 years ago, I was at the Louvre, trying to find the real Mona Lisa smile \n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("I was treated with exquisite wine, and the most delicately sauteed french toast ever,\n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("not to mention the assortment of cheeses. Did I tell you that I stumbled when I saw her at first?\n"); 
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("Then it hit me, the answer was there staring at me right from the beginning of my journey.\n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("She wasn't even smiling, in fact she is not even who you think she is.\n");
-        thread::sleep(time::Duration::from_secs(4)); 
-        println!("In fact, I have visited her every year since then.\n"); 
-        thread::sleep(time::Duration::from_secs(2));
-        println!("Anyways, Good job with the message and everything. I knew it anyway. \n and what you get from all this is entirely up to you. \n");
+    if comp != expected_msg {
+        return Err("HQ: This must be a mistake, mission aborted, Flag Failure!".to_string());
     }
-    else {
-        println!("HQ: This must be a mistake, mission aborted, Flag Failure!");
+
+    pace.pause(5);
+    println!("HQ: Good job!, Flag success!! we are on our way!, stay safe.");
+    if pace.is_fast() {
+        return Ok(());
     }
+    for _ in 0..10 {
+        pace.pause(1);
+        println!(" ");
+    }
+    println!("Red: Hello there! Seems like you have proven your mettle!\n");
+    pace.pause(2);
+    println!("Have you heard about the evolution of ZK?, Does it seem like a Philosophical question?\n");
+    pace.pause(3);
+    println!("Even for the most philosophical questions, the answer is often right in front of you.\n");
+    pace.pause(4);
+    println!("As you probably know by now, your HQ guys call me a concierge of crime.\n");
+    pace.pause(4);
+    println!("I prefer the term connoisseur, since I am a man of good taste, even in crime.\n");
+    pace.pause(4);
+    println!("31 years ago, I was at the Louvre, trying to find the real Mona Lisa smile \n");
+    pace.pause(4);
+    println!("I was treated with exquisite wine, and the most delicately sauteed french toast ever,\n");
+    pace.pause(4);
+    println!("not to mention the assortment of cheeses. Did I tell you that I stumbled when I saw her at first?\n");
+    pace.pause(4);
+    println!("Then it hit me, the answer was there staring at me right from the beginning of my journey.\n");
+    pace.pause(4);
+    println!("She wasn't even smiling, in fact she is not even who you think she is.\n");
+    pace.pause(4);
+    println!("In fact, I have visited her every year since then.\n");
+    pace.pause(2);
+    println!("Anyways, Good job with the message and everything. I knew it anyway. \n and what you get from all this is entirely up to you. \n");
+    Ok(())
 }
 
 //Unfortunately you die if he discovers that you are working for HQ
-pub fn woe_jinx_death() {
+pub fn woe_jinx_death(pace: Pace) {
+    if pace.is_fast() {
+        return;
+    }
     println!("\nWoe Jinx: Well, Well , Well...\n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     println!("If it moves like a rat \n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     println!("and smells like a rat \n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     println!("It must be a rat! \n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     println!("End of the line for you!!");
-}
\ No newline at end of file
+}
@@ -1,54 +1,33 @@
-pub mod data_structures;
-pub mod error;
-pub mod prover;
-pub mod rng;
-pub mod verifier;
-
-use ark_bls12_381::{Bls12_381, Fr as F};
-use ark_ff::{Zero, BigInteger256};
+use ark_bls12_381::{Bls12_381, Fr as F, G1Affine};
+use ark_ff::BigInteger256;
 use ark_poly::{
     Polynomial, univariate::DensePolynomial, EvaluationDomain, GeneralEvaluationDomain,UVPolynomial,
 };
-use ark_poly_commit::{marlin_pc::MarlinKZG10, LabeledPolynomial, PolynomialCommitment};
+use ark_poly_commit::{
+    ipa_pc::InnerProductArgPC, marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10, LabeledPolynomial, PolynomialCommitment,
+};
 use ark_std::{rand::rngs::StdRng, test_rng};
 use blake2::Blake2s;
+use field_codec::ark::to_text;
 use prompt::{puzzle};
-use rand_chacha::ChaChaRng;
-use rng::SimpleHashFiatShamirRng;
+use sumcheck::{degree_bounds, rng::Transcript, Statement, SumcheckProver, SumcheckVerifier};
+mod cli;
 mod flag_check;
-use crate::{data_structures::Statement, prover::prove, verifier::verify,flag_check::{woe_jinx_death,flag_quest}};
-use std::{thread, time};
-
-pub const PROTOCOL_NAME: &'static [u8] = b"OPERATION_ZK_RESCUE";
-pub type PC = MarlinKZG10<Bls12_381, DensePolynomial<F>>;
-type FS = SimpleHashFiatShamirRng<Blake2s, ChaChaRng>;
-
-fn decode(num: &F) -> String {
-    let binding = num.to_string();
-    let num_str = binding.strip_prefix("Fp256 \"(").unwrap().strip_suffix(")\"").unwrap().to_string();
-    (0..num_str.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&num_str[i..i + 2], 16).unwrap_or_default() as char)
-            .collect()
-}
+use crate::flag_check::{woe_jinx_death,flag_quest,Pace};
+use std::{env, process};
 
-fn main() {
-    puzzle(PUZZLE_DESCRIPTION);
+/// Commitment schemes the sumcheck can run over. Marlin is the one Jinx uses
+pub type Marlin = MarlinKZG10<Bls12_381, DensePolynomial<F>>;
+pub type Sonic = SonicKZG10<Bls12_381, DensePolynomial<F>>;
+/// Transparent, no trusted setup but linear verification
+pub type Ipa = InnerProductArgPC<G1Affine, Blake2s, DensePolynomial<F>>;
+type FS = Transcript<Blake2s>;
 
-    let domain_size = 32;
-    let domain= GeneralEvaluationDomain::<F>::new(domain_size).unwrap();
-    let max_degree = 32;
-
-    // for p in domain.elements() {
-    //     println!("{}", p);
-    // }
-
-    let mut rng = test_rng();
-    let srs = PC::setup(max_degree, None, &mut rng).unwrap();
+const DOMAIN_SIZE: usize = 32;
+const MAX_DEGREE: usize = 32;
 
-    let (ck, vk) = PC::trim(&srs, max_degree, 1, Some(&[domain_size - 2])).unwrap();
-
-    let poly_evals = 
+/// Evaluations over the domain of the identity the Forger crafted
+fn identity_evaluations() -> Vec<F> {
     vec![F::new(BigInteger256([1121870363005239449, 9833063431898617369, 11114025489853872418, 4089459679630506955])), 
     F::new(BigInteger256([18389056416795951153, 12776612665753701770, 10601712950505962344, 7802313184811879854])), 
     F::new(BigInteger256([16067594266876709090, 8470427502427084384, 16443615647861417996, 4625471000544485246])), 
@@ -80,56 +59,115 @@ fn main() {
     F::new(BigInteger256([5690401103993610694, 11013490713874630348, 17189187543924385957, 5595667519177664099])), 
     F::new(BigInteger256([163440685443930, 1168097028314557606, 6312152460365060130, 8010052176547349905])), 
     F::new(BigInteger256([11918697147346910008, 9233808242802061551, 14395435043740768310, 4872975318810306762])), 
-    F::new(BigInteger256([12098040391674830792, 13279254240913572044, 17820649906232228079, 6653899853934111562]))];
+    F::new(BigInteger256([12098040391674830792, 13279254240913572044, 17820649906232228079, 6653899853934111562]))]
+}
+
+/// Deterministic keys, the same on every run so proofs written by one run verify in another.
+/// Also returns the rng to keep using after the setup
+fn setup<PC: PolynomialCommitment<F, DensePolynomial<F>>>() -> (PC::CommitterKey, PC::VerifierKey, GeneralEvaluationDomain<F>, StdRng) {
+    let domain = GeneralEvaluationDomain::<F>::new(DOMAIN_SIZE).unwrap();
+
+    let mut rng = test_rng();
+    let srs = PC::setup(MAX_DEGREE, None, &mut rng).unwrap();
+    let (ck, vk) = PC::trim(&srs, MAX_DEGREE, 1, Some(&degree_bounds(DOMAIN_SIZE, MAX_DEGREE))).unwrap();
+
+    (ck, vk, domain, rng)
+}
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let result = match args.get(1).map(String::as_str) {
+        Some("prove") => cli::prove(&args[2..]),
+        Some("verify") => cli::verify(&args[2..]),
+        Some("compare") => cli::compare(),
+        Some(arg) if arg.starts_with("--") => cli::rescue(&args[1..]),
+        Some(_) => Err(cli::USAGE.to_string()),
+        None => rescue(Pace::Story, None),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+/// Plays the puzzle, taking the message for HQ from `message` if given rather than from stdin.
+/// Fails if the sumcheck or the message is rejected
+fn rescue(pace: Pace, message: Option<String>) -> Result<(), String> {
+    if !pace.is_fast() {
+        puzzle(PUZZLE_DESCRIPTION);
+    }
+
+    let domain_size = DOMAIN_SIZE;
+    let (ck, vk, domain, mut rng) = setup::<Marlin>();
+
+    // for p in domain.elements() {
+    //     println!("{}", p);
+    // }
+
+    let poly_evals = identity_evaluations();
 
     for i in 0..domain_size {
-        println!("{}", decode(&poly_evals[i]));
+        println!("{}", to_text(&poly_evals[i]));
     }
     println!();
 
     let eval_sum = poly_evals[..domain_size].iter().sum();
-    println!("Flag: {}\n", decode(&eval_sum));
+    println!("Flag: {}\n", to_text(&eval_sum));
 
     println!("Sumcheck preparation..\n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     //interpolate to coeff form using ifft 
     println!("Encoding identities..\n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     let poly_coeffs = GeneralEvaluationDomain::ifft(&domain,&poly_evals);
     let f = DensePolynomial::from_coefficients_slice(&poly_coeffs);
 
-    println!("Flag: {}\n", decode(&f.clone().evaluate_over_domain(domain).evals.into_iter().sum::<F>()));
+    println!("Flag: {}\n", to_text(&f.clone().evaluate_over_domain(domain).evals.into_iter().sum::<F>()));
 
-    let sum = F::zero();
+    // The verifier checks the mask sum, so only the true sum of f can be proven
+    let sum = eval_sum;
 
     let f = LabeledPolynomial::new("f".into(), f.clone(), None, Some(1));
-    let (f_commitment, f_rand) = PC::commit(&ck, &[f.clone()], Some(&mut rng)).unwrap();
+    let (f_commitment, f_rand) = Marlin::commit(&ck, &[f.clone()], Some(&mut rng)).unwrap();
     println!("Preparing the statement:\n");
-    thread::sleep(time::Duration::from_secs(2));
+    pace.pause(2);
     let statement = Statement {
         domain,
+        max_degree: MAX_DEGREE,
         f: f_commitment[0].commitment().clone(),
         sum,
     };
     println!("Beep! Beep! Incoming transmission: \n");
-    thread::sleep(time::Duration::from_secs(2));
-    puzzle(FORGER);
+    pace.pause(2);
+    if !pace.is_fast() {
+        puzzle(FORGER);
+    }
     
-    let proof = prove::<F, PC, FS, StdRng>(&ck, &statement, &f, &f_rand[0], &mut rng).unwrap();
+    println!("Begin Proof generation.. \n");
+    pace.pause(2);
+    let prover = SumcheckProver::<F, Marlin, FS>::new(ck);
+    let proof = prover.prove(&statement, &f, &f_rand[0], &mut rng).unwrap();
+    println!("End Proof generation.. \n");
+    pace.pause(2);
 
     println!("Verifying the proof");
-    let res = verify::<F, PC, FS, StdRng>(&vk, &statement, &proof, &mut rng);
-    thread::sleep(time::Duration::from_secs(2));
-
-    if res.is_ok() {
-        println!("Sumcheck validation successful!");
-        thread::sleep(time::Duration::from_secs(4));
-        flag_quest();
-    } else {
-        println!("Sumcheck validation failure!");
-        thread::sleep(time::Duration::from_secs(4));
-        woe_jinx_death();
-    };
+    let verifier = SumcheckVerifier::<F, Marlin, FS>::new(vk);
+    let res = verifier.verify(&statement, &proof, &mut rng);
+    pace.pause(2);
+
+    match res {
+        Ok(()) => {
+            println!("Sumcheck validation successful!");
+            pace.pause(4);
+            flag_quest(pace, message)
+        }
+        Err(e) => {
+            pace.pause(4);
+            woe_jinx_death(pace);
+            Err(format!("Sumcheck validation failure! {:?}", e))
+        }
+    }
 }
 
 const PUZZLE_DESCRIPTION: &str = "\
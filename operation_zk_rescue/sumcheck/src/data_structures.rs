@@ -0,0 +1,253 @@
+use ark_ec::PairingEngine;
+use ark_ff::{FftField, Field, ToBytes};
+use ark_poly::{univariate::DensePolynomial, EvaluationDomain, GeneralEvaluationDomain};
+use ark_poly_commit::{multilinear_pc::data_structures as multilinear_pc, PolynomialCommitment};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+use crate::h_degree_bound;
+
+pub struct Statement<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    pub domain: GeneralEvaluationDomain<F>,
+    /// Largest degree of `f`, which bounds the degrees of the mask `s` and the quotient `h`
+    pub max_degree: usize,
+    pub f: PC::Commitment,
+    pub sum: F,
+}
+
+/// The domain is stored as its size and rebuilt on deserialization.
+impl<F, PC> CanonicalSerialize for Statement<F, PC>
+where
+    F: FftField,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalSerialize,
+{
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        (self.domain.size() as u64).serialize(&mut writer)?;
+        (self.max_degree as u64).serialize(&mut writer)?;
+        self.f.serialize(&mut writer)?;
+        self.sum.serialize(&mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        2 * 0u64.serialized_size() + self.f.serialized_size() + self.sum.serialized_size()
+    }
+}
+
+impl<F, PC> CanonicalDeserialize for Statement<F, PC>
+where
+    F: FftField,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalDeserialize,
+{
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let size = u64::deserialize(&mut reader)?;
+        // Domains round up to a supported size, anything else wasn't written by us. A single point
+        // can't be proven over, `g` needs at least two
+        let domain = GeneralEvaluationDomain::new(size as usize)
+            .filter(|domain| size >= 2 && domain.size() as u64 == size)
+            .ok_or(SerializationError::InvalidData)?;
+
+        Ok(Statement {
+            domain,
+            max_degree: u64::deserialize(&mut reader)? as usize,
+            f: CanonicalDeserialize::deserialize(&mut reader)?,
+            sum: CanonicalDeserialize::deserialize(&mut reader)?,
+        })
+    }
+}
+
+pub struct Proof<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    pub f_opening: F,
+    pub s: PC::Commitment,
+    pub s_opening: F,
+    /// Claimed sum of the mask `s` over the domain, bound into the transcript before the mask challenge
+    pub s_sum: F,
+    pub g: PC::Commitment,
+    pub g_opening: F,
+    pub h: PC::Commitment,
+    pub h_opening: F,
+    pub pc_proof: PC::BatchProof,
+}
+
+impl<F, PC> CanonicalSerialize for Proof<F, PC>
+where
+    F: Field,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalSerialize,
+    PC::BatchProof: CanonicalSerialize,
+{
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.f_opening.serialize(&mut writer)?;
+        self.s.serialize(&mut writer)?;
+        self.s_opening.serialize(&mut writer)?;
+        self.s_sum.serialize(&mut writer)?;
+        self.g.serialize(&mut writer)?;
+        self.g_opening.serialize(&mut writer)?;
+        self.h.serialize(&mut writer)?;
+        self.h_opening.serialize(&mut writer)?;
+        self.pc_proof.serialize(&mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.f_opening.serialized_size()
+            + self.s.serialized_size()
+            + self.s_opening.serialized_size()
+            + self.s_sum.serialized_size()
+            + self.g.serialized_size()
+            + self.g_opening.serialized_size()
+            + self.h.serialized_size()
+            + self.h_opening.serialized_size()
+            + self.pc_proof.serialized_size()
+    }
+}
+
+impl<F, PC> CanonicalDeserialize for Proof<F, PC>
+where
+    F: Field,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    PC::Commitment: CanonicalDeserialize,
+    PC::BatchProof: CanonicalDeserialize,
+{
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Ok(Proof {
+            f_opening: CanonicalDeserialize::deserialize(&mut reader)?,
+            s: CanonicalDeserialize::deserialize(&mut reader)?,
+            s_opening: CanonicalDeserialize::deserialize(&mut reader)?,
+            s_sum: CanonicalDeserialize::deserialize(&mut reader)?,
+            g: CanonicalDeserialize::deserialize(&mut reader)?,
+            g_opening: CanonicalDeserialize::deserialize(&mut reader)?,
+            h: CanonicalDeserialize::deserialize(&mut reader)?,
+            h_opening: CanonicalDeserialize::deserialize(&mut reader)?,
+            pc_proof: CanonicalDeserialize::deserialize(&mut reader)?,
+        })
+    }
+}
+
+/// Claims that each committed polynomial `fs[i]` sums to `sums[i]` over one domain.
+pub struct BatchStatement<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    pub domain: GeneralEvaluationDomain<F>,
+    /// Largest degree of any of the `fs`
+    pub max_degree: usize,
+    pub fs: Vec<PC::Commitment>,
+    pub sums: Vec<F>,
+}
+
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> ToBytes for BatchStatement<F, PC> {
+    fn write<W: ark_std::io::Write>(&self, mut writer: W) -> ark_std::io::Result<()> {
+        (self.domain.size() as u64).write(&mut writer)?;
+        (self.max_degree as u64).write(&mut writer)?;
+        (self.fs.len() as u64).write(&mut writer)?;
+        for f in &self.fs {
+            f.write(&mut writer)?;
+        }
+        for sum in &self.sums {
+            sum.write(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> BatchStatement<F, PC> {
+    /// Degree bound of the mask `s`, which may be as large as the polynomials it hides
+    pub fn s_degree_bound(&self) -> usize {
+        self.max_degree
+    }
+
+    /// Degree bound of the quotient of the masked polynomial by the vanishing polynomial
+    pub fn h_degree_bound(&self) -> usize {
+        h_degree_bound(self.domain.size(), self.max_degree)
+    }
+
+    /// Degree bound of `g`, the remainder without its constant term
+    pub fn g_degree_bound(&self) -> usize {
+        self.domain.size() - 2
+    }
+}
+
+/// A single statement is proven as a batch of one.
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> From<&Statement<F, PC>> for BatchStatement<F, PC> {
+    fn from(statement: &Statement<F, PC>) -> Self {
+        BatchStatement {
+            domain: statement.domain,
+            max_degree: statement.max_degree,
+            fs: vec![statement.f.clone()],
+            sums: vec![statement.sum],
+        }
+    }
+}
+
+/// Like `Proof`, with one opening per polynomial of the batch. The polynomials are combined with
+/// challenges from the transcript, so a single mask and a single `g`/`h` pair cover all of them.
+pub struct BatchProof<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    pub f_openings: Vec<F>,
+    pub s: PC::Commitment,
+    pub s_opening: F,
+    pub s_sum: F,
+    pub g: PC::Commitment,
+    pub g_opening: F,
+    pub h: PC::Commitment,
+    pub h_opening: F,
+    pub pc_proof: PC::BatchProof,
+}
+
+impl<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> From<&Proof<F, PC>> for BatchProof<F, PC> {
+    fn from(proof: &Proof<F, PC>) -> Self {
+        BatchProof {
+            f_openings: vec![proof.f_opening],
+            s: proof.s.clone(),
+            s_opening: proof.s_opening,
+            s_sum: proof.s_sum,
+            g: proof.g.clone(),
+            g_opening: proof.g_opening,
+            h: proof.h.clone(),
+            h_opening: proof.h_opening,
+            pc_proof: proof.pc_proof.clone(),
+        }
+    }
+}
+
+/// Only a batch of exactly one polynomial is a single proof, anything else is handed back.
+impl<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> TryFrom<BatchProof<F, PC>> for Proof<F, PC> {
+    type Error = BatchProof<F, PC>;
+
+    fn try_from(proof: BatchProof<F, PC>) -> Result<Self, Self::Error> {
+        let f_opening = match proof.f_openings[..] {
+            [f_opening] => f_opening,
+            _ => return Err(proof),
+        };
+        Ok(Proof {
+            f_opening,
+            s: proof.s,
+            s_opening: proof.s_opening,
+            s_sum: proof.s_sum,
+            g: proof.g,
+            g_opening: proof.g_opening,
+            h: proof.h,
+            h_opening: proof.h_opening,
+            pc_proof: proof.pc_proof,
+        })
+    }
+}
+
+/// Claims that the committed multilinear polynomial `f` sums to `sum` over the boolean hypercube.
+pub struct MultilinearStatement<E: PairingEngine> {
+    pub num_vars: usize,
+    pub f: multilinear_pc::Commitment<E>,
+    pub sum: E::Fr,
+}
+
+impl<E: PairingEngine> ToBytes for MultilinearStatement<E> {
+    fn write<W: ark_std::io::Write>(&self, mut writer: W) -> ark_std::io::Result<()> {
+        (self.num_vars as u64).write(&mut writer)?;
+        self.f.g_product.write(&mut writer)?;
+        self.sum.write(&mut writer)
+    }
+}
+
+pub struct MultilinearProof<E: PairingEngine> {
+    /// Round polynomials, one per variable. Each is linear and given by its values at 0 and 1
+    pub rounds: Vec<[E::Fr; 2]>,
+    pub f_opening: E::Fr,
+    pub pc_proof: multilinear_pc::Proof<E>,
+}
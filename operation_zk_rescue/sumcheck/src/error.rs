@@ -0,0 +1,34 @@
+use ark_serialize::SerializationError;
+
+#[derive(Debug)]
+pub enum Error<E> {
+    OpeningError,
+    PolynomialCommitmentError(E),
+    IncorrectSum,
+    /// The proof doesn't have the shape the statement calls for
+    MalformedProof,
+}
+
+impl<E> Error<E> {
+    pub fn from_pc_err(err: E) -> Self {
+        Error::PolynomialCommitmentError(err)
+    }
+}
+
+/// Reading or writing a statement or proof file failed
+#[derive(Debug)]
+pub enum FormatError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    /// A proof where a statement was expected or the other way around
+    WrongKind,
+    /// More bytes follow the serialized statement or proof
+    TrailingBytes,
+    Serialization(SerializationError),
+}
+
+impl From<SerializationError> for FormatError {
+    fn from(err: SerializationError) -> Self {
+        FormatError::Serialization(err)
+    }
+}
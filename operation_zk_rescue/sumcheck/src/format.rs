@@ -0,0 +1,45 @@
+//! Files holding a statement or a proof: a magic, the format version and the kind of content,
+//! followed by the canonical serialization. Keys aren't stored, both sides must agree on them.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+use crate::error::FormatError;
+
+pub const MAGIC: [u8; 4] = *b"ZKSC";
+/// Bumped whenever the layout of a statement or proof changes
+pub const VERSION: u8 = 2;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    Statement = 1,
+    Proof = 2,
+}
+
+pub fn write<T: CanonicalSerialize, W: Write>(kind: Kind, value: &T, mut writer: W) -> Result<(), FormatError> {
+    writer.write_all(&MAGIC).map_err(SerializationError::from)?;
+    writer.write_all(&[VERSION, kind as u8]).map_err(SerializationError::from)?;
+    value.serialize(&mut writer)?;
+    Ok(())
+}
+
+pub fn read<T: CanonicalDeserialize, R: Read>(kind: Kind, mut reader: R) -> Result<T, FormatError> {
+    let mut header = [0u8; 6];
+    reader.read_exact(&mut header).map_err(SerializationError::from)?;
+
+    if header[..4] != MAGIC {
+        return Err(FormatError::BadMagic);
+    }
+    if header[4] != VERSION {
+        return Err(FormatError::UnsupportedVersion(header[4]));
+    }
+    if header[5] != kind as u8 {
+        return Err(FormatError::WrongKind);
+    }
+
+    let value = T::deserialize(&mut reader)?;
+    if reader.read(&mut [0u8; 1]).map_err(SerializationError::from)? != 0 {
+        return Err(FormatError::TrailingBytes);
+    }
+    Ok(value)
+}
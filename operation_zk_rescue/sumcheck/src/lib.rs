@@ -0,0 +1,39 @@
+//! Zero-knowledge univariate sumcheck over a multiplicative subgroup, generic over the field and the
+//! polynomial commitment scheme. See `../doc/univariate_sumcheck.pdf` for the protocol.
+//! The classic multilinear sumcheck over the boolean hypercube lives in `multilinear`.
+
+pub mod data_structures;
+pub mod error;
+pub mod format;
+pub mod multilinear;
+pub mod prover;
+pub mod rng;
+pub mod verifier;
+
+pub use data_structures::{BatchProof, BatchStatement, MultilinearProof, MultilinearStatement, Proof, Statement};
+pub use error::{Error, FormatError};
+pub use multilinear::{MultilinearProver, MultilinearVerifier};
+pub use prover::{domain_sum, SumcheckProver};
+pub use verifier::SumcheckVerifier;
+
+/// Domain separator absorbed into every transcript
+pub const PROTOCOL_NAME: &[u8] = b"OPERATION_ZK_RESCUE";
+
+/// Degree bounds the committer key must be trimmed with to prove statements over a domain of
+/// `domain_size` for polynomials of degree up to `max_degree`: those of `g`, `s` and `h`.
+pub fn degree_bounds(domain_size: usize, max_degree: usize) -> Vec<usize> {
+    let mut bounds = vec![domain_size - 2, max_degree, h_degree_bound(domain_size, max_degree)];
+    bounds.sort_unstable();
+    bounds.dedup();
+    bounds
+}
+
+/// Commitment schemes don't support a bound of 0, so a vanishing `h` is bounded by 1.
+pub(crate) fn h_degree_bound(domain_size: usize, max_degree: usize) -> usize {
+    max_degree.saturating_sub(domain_size).max(1)
+}
+
+/// Label of the `i`-th polynomial of a batch, shared by the prover and the verifier
+pub(crate) fn f_label(i: usize) -> String {
+    format!("f{}", i)
+}
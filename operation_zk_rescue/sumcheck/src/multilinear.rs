@@ -0,0 +1,121 @@
+use ark_ec::PairingEngine;
+use ark_ff::to_bytes;
+use ark_poly::{DenseMultilinearExtension, MultilinearExtension};
+use ark_poly_commit::multilinear_pc::{
+    data_structures::{CommitterKey, VerifierKey},
+    MultilinearPC,
+};
+use ark_std::{convert::Infallible, marker::PhantomData};
+
+use crate::{
+    data_structures::{MultilinearProof, MultilinearStatement},
+    error::Error,
+    rng::FiatShamirRng,
+    PROTOCOL_NAME,
+};
+
+/// Proves the classic sumcheck of a multilinear polynomial over the boolean hypercube.
+/// Unlike the univariate protocol nothing is masked, so the proof reveals evaluations of `f`.
+pub struct MultilinearProver<E: PairingEngine, FS: FiatShamirRng> {
+    ck: CommitterKey<E>,
+    #[doc(hidden)]
+    fs: PhantomData<FS>,
+}
+
+impl<E: PairingEngine, FS: FiatShamirRng> MultilinearProver<E, FS> {
+    /// `ck` must be trimmed to exactly the number of variables of the proven polynomials.
+    pub fn new(ck: CommitterKey<E>) -> Self {
+        MultilinearProver { ck, fs: PhantomData }
+    }
+
+    pub fn prove(
+        &self,
+        statement: &MultilinearStatement<E>,
+        f: &DenseMultilinearExtension<E::Fr>,
+    ) -> Result<MultilinearProof<E>, Error<Infallible>> {
+        // `MultilinearPC::open` panics on a key for another number of variables
+        let num_vars = statement.num_vars;
+        if f.num_vars != num_vars || statement.f.nv != num_vars || self.ck.nv != num_vars {
+            return Err(Error::MalformedProof);
+        }
+
+        let mut transcript = FS::new(PROTOCOL_NAME);
+        transcript.append(b"statement", statement);
+
+        let mut table = f.clone();
+        let mut rounds = Vec::with_capacity(f.num_vars);
+        let mut point = Vec::with_capacity(f.num_vars);
+        for _ in 0..f.num_vars {
+            // The next variable is the lowest bit of the index into the evaluations
+            let at_zero: E::Fr = table.evaluations.iter().step_by(2).sum();
+            let at_one: E::Fr = table.evaluations.iter().skip(1).step_by(2).sum();
+            transcript.append_message(b"round", &to_bytes![at_zero, at_one].unwrap());
+
+            let challenge: E::Fr = transcript.challenge_scalar(b"challenge");
+            table = table.fix_variables(&[challenge]);
+            rounds.push([at_zero, at_one]);
+            point.push(challenge);
+        }
+
+        Ok(MultilinearProof {
+            rounds,
+            f_opening: table.evaluations[0],
+            pc_proof: MultilinearPC::open(&self.ck, f, &point),
+        })
+    }
+}
+
+/// Checks proofs produced by `MultilinearProver`. Polynomial commitment errors can't happen here,
+/// a bad opening is reported as `Error::OpeningError`.
+pub struct MultilinearVerifier<E: PairingEngine, FS: FiatShamirRng> {
+    vk: VerifierKey<E>,
+    #[doc(hidden)]
+    fs: PhantomData<FS>,
+}
+
+impl<E: PairingEngine, FS: FiatShamirRng> MultilinearVerifier<E, FS> {
+    /// Only statements over as many variables as `vk` was trimmed to are accepted.
+    pub fn new(vk: VerifierKey<E>) -> Self {
+        MultilinearVerifier { vk, fs: PhantomData }
+    }
+
+    pub fn verify(&self, statement: &MultilinearStatement<E>, proof: &MultilinearProof<E>) -> Result<(), Error<Infallible>> {
+        // `MultilinearPC::check` indexes the opening and the key by variable, and panics if they are short
+        let num_vars = statement.num_vars;
+        if proof.rounds.len() != num_vars
+            || statement.f.nv != num_vars
+            || self.vk.nv != num_vars
+            || proof.pc_proof.proofs.len() != num_vars
+        {
+            return Err(Error::MalformedProof);
+        }
+
+        let mut transcript = FS::new(PROTOCOL_NAME);
+        transcript.append(b"statement", statement);
+
+        // Each round polynomial must sum to the previous claim over {0, 1}, and its value at the
+        // challenge becomes the next claim
+        let mut claim = statement.sum;
+        let mut point = Vec::with_capacity(statement.num_vars);
+        for [at_zero, at_one] in proof.rounds.iter() {
+            if *at_zero + at_one != claim {
+                return Err(Error::IncorrectSum);
+            }
+            transcript.append_message(b"round", &to_bytes![at_zero, at_one].unwrap());
+
+            let challenge: E::Fr = transcript.challenge_scalar(b"challenge");
+            claim = *at_zero + challenge * (*at_one - at_zero);
+            point.push(challenge);
+        }
+
+        // The last claim is checked against f itself at the random point
+        if !MultilinearPC::check(&self.vk, &statement.f, &point, proof.f_opening, &proof.pc_proof) {
+            return Err(Error::OpeningError);
+        }
+        if proof.f_opening != claim {
+            return Err(Error::IncorrectSum);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use ark_ff::FftField;
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain, UVPolynomial};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::{LabeledPolynomial, PolynomialCommitment, LabeledCommitment, QuerySet};
+use ark_std::{marker::PhantomData, rand::RngCore, slice};
+use crate::{
+    data_structures::{BatchProof, BatchStatement, Proof, Statement},
+    error::Error,
+    f_label,
+    rng::FiatShamirRng,
+    PROTOCOL_NAME,
+};
+
+fn scaled<F: FftField>(p: &DensePolynomial<F>, c: F) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(p.coeffs.iter().map(|coeff| *coeff * c).collect())
+}
+
+/// Sum of `p` over the domain. Only the coefficients of `x^{k|H|}` survive, each summing to `|H|`.
+pub fn domain_sum<F: FftField>(p: &DensePolynomial<F>, domain: GeneralEvaluationDomain<F>) -> F {
+    let sum: F = p.coeffs.iter().step_by(domain.size()).sum();
+    sum * domain.size_as_field_element()
+}
+
+/// Proves that a committed polynomial sums to the claimed value over the statement domain.
+pub struct SumcheckProver<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>, FS: FiatShamirRng> {
+    ck: PC::CommitterKey,
+    #[doc(hidden)]
+    fs: PhantomData<FS>,
+}
+
+impl<F, PC, FS> SumcheckProver<F, PC, FS>
+where
+    F: FftField,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    FS: FiatShamirRng,
+{
+    /// `ck` must be trimmed with the bounds from `degree_bounds` enforced.
+    pub fn new(ck: PC::CommitterKey) -> Self {
+        SumcheckProver { ck, fs: PhantomData }
+    }
+
+    pub fn prove<R: RngCore>(
+        &self,
+        statement: &Statement<F, PC>,
+        f: &LabeledPolynomial<F, DensePolynomial<F>>,
+        f_rand: &PC::Randomness,
+        rng: &mut R,
+    ) -> Result<Proof<F, PC>, Error<PC::Error>> {
+        // A random mask of the largest degree hides f, its sum is sent in the clear
+        let s = DensePolynomial::rand(statement.max_degree, rng);
+        let s_sum = domain_sum(&s, statement.domain);
+        let s = LabeledPolynomial::new("s".into(), s, Some(statement.max_degree), Some(1));
+
+        self.prove_with_mask(statement, f, f_rand, &s, s_sum, rng)
+    }
+
+    /// Proves the statement with the given mask `s` and its claimed sum over the domain.
+    /// `s` must carry the degree bound `statement.max_degree`.
+    /// `prove` picks both honestly, anything else is rejected by the verifier.
+    pub fn prove_with_mask<R: RngCore>(
+        &self,
+        statement: &Statement<F, PC>,
+        f: &LabeledPolynomial<F, DensePolynomial<F>>,
+        f_rand: &PC::Randomness,
+        s: &LabeledPolynomial<F, DensePolynomial<F>>,
+        s_sum: F,
+        rng: &mut R,
+    ) -> Result<Proof<F, PC>, Error<PC::Error>> {
+        self.prove_batch_with_mask(&statement.into(), slice::from_ref(f), slice::from_ref(f_rand), s, s_sum, rng)
+            .and_then(|proof| Proof::try_from(proof).map_err(|_| Error::MalformedProof))
+    }
+
+    /// Proves the sums of all polynomials of the batch at once. `fs` and `f_rands` follow `statement.fs`.
+    pub fn prove_batch<R: RngCore>(
+        &self,
+        statement: &BatchStatement<F, PC>,
+        fs: &[LabeledPolynomial<F, DensePolynomial<F>>],
+        f_rands: &[PC::Randomness],
+        rng: &mut R,
+    ) -> Result<BatchProof<F, PC>, Error<PC::Error>> {
+        let s = DensePolynomial::rand(statement.s_degree_bound(), rng);
+        let s_sum = domain_sum(&s, statement.domain);
+        let s = LabeledPolynomial::new("s".into(), s, Some(statement.s_degree_bound()), Some(1));
+
+        self.prove_batch_with_mask(statement, fs, f_rands, &s, s_sum, rng)
+    }
+
+    /// Like `prove_with_mask`, for a batch.
+    pub fn prove_batch_with_mask<R: RngCore>(
+        &self,
+        statement: &BatchStatement<F, PC>,
+        fs: &[LabeledPolynomial<F, DensePolynomial<F>>],
+        f_rands: &[PC::Randomness],
+        s: &LabeledPolynomial<F, DensePolynomial<F>>,
+        s_sum: F,
+        rng: &mut R,
+    ) -> Result<BatchProof<F, PC>, Error<PC::Error>> {
+        self.prove_batch_with_bounds(statement, fs, f_rands, s, s_sum, statement.h_degree_bound(), rng)
+    }
+
+    /// Like `prove_batch_with_mask`, committing to `h` under `h_bound` instead of the statement's bound.
+    /// Only for testing that the verifier rejects any other bound.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_batch_with_bounds<R: RngCore>(
+        &self,
+        statement: &BatchStatement<F, PC>,
+        fs: &[LabeledPolynomial<F, DensePolynomial<F>>],
+        f_rands: &[PC::Randomness],
+        s: &LabeledPolynomial<F, DensePolynomial<F>>,
+        s_sum: F,
+        h_bound: usize,
+        rng: &mut R,
+    ) -> Result<BatchProof<F, PC>, Error<PC::Error>> {
+        if fs.len() != statement.fs.len() || f_rands.len() != statement.fs.len() {
+            return Err(Error::MalformedProof);
+        }
+        let ck = &self.ck;
+
+        // Initialize the Fiat-Shamir transcript for non-interactive proofs
+        let mut transcript = FS::new(PROTOCOL_NAME);
+        transcript.append(b"statement", statement);
+
+        // The batch is combined with coefficients fixed by the statement alone
+        let coefficients = (0..fs.len()).map(|_| transcript.challenge_scalar(b"batch")).collect::<Vec<F>>();
+
+        // Commit to the mask and its sum before learning how it is combined with f
+        let (s_commitments, s_rands) = PC::commit(ck, &[s.clone()], Some(rng)).map_err(Error::from_pc_err)?;
+        let s_commitment = s_commitments[0].clone();
+        transcript.append(b"s", s_commitment.commitment());
+        transcript.append(b"s_sum", &s_sum);
+        let mask_challenge: F = transcript.challenge_scalar(b"mask");
+
+        // The masked polynomial sum(a_i * f_i) + c * s sums to sum(a_i * sum_i) + c * s_sum over the domain
+        let masked = fs
+            .iter()
+            .zip(coefficients.iter())
+            .fold(scaled(s.polynomial(), mask_challenge), |acc, (f, coefficient)| &acc + &scaled(f.polynomial(), *coefficient));
+
+        // masked = h * Z_H + r with deg(r) < |H|, and r = x * g + sum / |H|
+        let (h, r) = masked.divide_by_vanishing_poly(statement.domain).unwrap();
+        let g = DensePolynomial::from_coefficients_slice(r.coeffs.get(1..).unwrap_or(&[]));
+        let h = LabeledPolynomial::new("h".into(), h, Some(h_bound), Some(1));
+        let g = LabeledPolynomial::new("g".into(), g, Some(statement.g_degree_bound()), Some(1));
+
+        // Generate polynomial commitments for the polynomials h, g
+        let (commitments, rands) = PC::commit(ck, &[h.clone(), g.clone()], Some(rng)).map_err(Error::from_pc_err)?;
+        let h_commitment = commitments[0].clone();
+        let g_commitment = commitments[1].clone();
+
+        // Progress the transcript
+        transcript.append(b"h", h_commitment.commitment());
+        transcript.append(b"g", g_commitment.commitment());
+
+        // The caller's labels are replaced with the ones the verifier uses
+        let fs = fs
+            .iter()
+            .enumerate()
+            .map(|(i, f)| LabeledPolynomial::new(f_label(i), f.polynomial().clone(), f.degree_bound(), f.hiding_bound()))
+            .collect::<Vec<_>>();
+        let f_commitments = statement
+            .fs
+            .iter()
+            .enumerate()
+            .map(|(i, f)| LabeledCommitment::new(f_label(i), f.clone(), None))
+            .collect::<Vec<_>>();
+
+        // The openings are absorbed before the challenge that batches them
+        let xi: F = transcript.challenge_scalar(b"xi");
+        let f_openings = fs.iter().map(|f| f.evaluate(&xi)).collect::<Vec<_>>();
+        let (s_opening, h_opening, g_opening) = (s.evaluate(&xi), h.evaluate(&xi), g.evaluate(&xi));
+        transcript.append(b"f_openings", &f_openings);
+        transcript.append(b"openings", &[s_opening, h_opening, g_opening].to_vec());
+        let opening_challenge = transcript.challenge_scalar(b"opening");
+
+        let point_label = String::from("xi");
+        let query_set: QuerySet<F> = fs
+            .iter()
+            .map(|f| f.label().clone())
+            .chain(["s".into(), "h".into(), "g".into()])
+            .map(|label| (label, (point_label.clone(), xi)))
+            .collect();
+
+        let polynomials = fs.iter().chain([s, &h, &g]);
+        let commitments = f_commitments.iter().chain([&s_commitment, &h_commitment, &g_commitment]);
+        let rands = f_rands.iter().chain([&s_rands[0], &rands[0], &rands[1]]);
+
+        let pc_proof = PC::batch_open(
+            ck,
+            polynomials,
+            commitments,
+            &query_set,
+            opening_challenge,
+            rands,
+            Some(rng),
+        ).map_err(Error::from_pc_err)?;
+
+        Ok(BatchProof {
+            f_openings,
+            s: s_commitment.commitment().clone(),
+            s_opening,
+            s_sum,
+            h: h_commitment.commitment().clone(),
+            h_opening,
+            g: g_commitment.commitment().clone(),
+            g_opening,
+            pc_proof,
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use ark_ff::{to_bytes, Field, ToBytes};
+use ark_std::marker::PhantomData;
+use ark_std::rand::RngCore;
+use digest::Digest;
+
+use ark_std::vec::Vec;
+
+/// An RNG suitable for Fiat-Shamir transforms, driven by labelled messages
+pub trait FiatShamirRng: RngCore {
+    /// Start a transcript for the given protocol
+    fn new(protocol: &'static [u8]) -> Self;
+
+    /// Absorb a message under a label. Different labels, orders or splits of the same bytes give
+    /// different challenges
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Absorb anything with a byte encoding, like commitments and field elements
+    fn append<T: ToBytes>(&mut self, label: &'static [u8], value: &T) {
+        self.append_message(label, &to_bytes![value].expect("failed to convert to bytes"));
+    }
+
+    /// Derive a challenge from everything absorbed so far
+    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F {
+        self.append_message(b"challenge", label);
+        F::rand(self)
+    }
+}
+
+/// A hash-chained transcript in the style of Merlin: every message is absorbed together with its label
+/// and both lengths, and random bytes are squeezed from the current state with a counter.
+pub struct Transcript<D: Digest> {
+    state: Vec<u8>,
+    counter: u64,
+    #[doc(hidden)]
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest> FiatShamirRng for Transcript<D> {
+    fn new(protocol: &'static [u8]) -> Self {
+        let mut transcript = Transcript { state: Vec::new(), counter: 0, digest: PhantomData };
+        transcript.append_message(b"protocol", protocol);
+        transcript
+    }
+
+    /// Set `state = H(state || |label| || label || |message| || message)`.
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        let mut bytes = Vec::with_capacity(self.state.len() + label.len() + message.len() + 16);
+        bytes.extend_from_slice(&self.state);
+        bytes.extend_from_slice(&(label.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(label);
+        bytes.extend_from_slice(&(message.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(message);
+
+        self.state = D::digest(&bytes).to_vec();
+        self.counter = 0;
+    }
+}
+
+impl<D: Digest> RngCore for Transcript<D> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Fill with blocks `H(state || "squeeze" || counter)`, leaving the state untouched.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(<D as Digest>::output_size()) {
+            let mut bytes = self.state.clone();
+            bytes.extend_from_slice(b"squeeze");
+            bytes.extend_from_slice(&self.counter.to_le_bytes());
+            self.counter += 1;
+
+            let block = D::digest(&bytes);
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ark_std::rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
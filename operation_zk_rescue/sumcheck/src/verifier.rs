@@ -0,0 +1,144 @@
+use ark_ff::FftField;
+use ark_poly::{univariate::DensePolynomial, EvaluationDomain};
+use ark_poly_commit::{Evaluations, LabeledCommitment, PCCommitment, PolynomialCommitment, QuerySet};
+use ark_std::{marker::PhantomData, rand::RngCore};
+
+use crate::{
+    data_structures::{BatchProof, BatchStatement, Proof, Statement},
+    error::Error,
+    f_label,
+    rng::FiatShamirRng,
+    PROTOCOL_NAME,
+};
+
+/// Checks proofs produced by `SumcheckProver` with the matching key and transcript.
+pub struct SumcheckVerifier<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>, FS: FiatShamirRng> {
+    vk: PC::VerifierKey,
+    /// Whether bounded commitments must come with their shifted part
+    shifted: bool,
+    #[doc(hidden)]
+    fs: PhantomData<FS>,
+}
+
+impl<F, PC, FS> SumcheckVerifier<F, PC, FS>
+where
+    F: FftField,
+    PC: PolynomialCommitment<F, DensePolynomial<F>>,
+    FS: FiatShamirRng,
+{
+    pub fn new(vk: PC::VerifierKey) -> Self {
+        SumcheckVerifier { vk, shifted: false, fs: PhantomData }
+    }
+
+    /// For schemes like Marlin and IPA, which enforce a degree bound with a second, shifted commitment
+    /// and panic when checking a bounded commitment without one. Proofs whose `s`, `h` or `g` lack it are
+    /// then rejected as malformed. Sonic's commitments never carry the bound, so it must not use this.
+    pub fn with_shifted_commitments(self) -> Self {
+        SumcheckVerifier { shifted: true, ..self }
+    }
+
+    pub fn verify<R: RngCore>(
+        &self,
+        statement: &Statement<F, PC>,
+        proof: &Proof<F, PC>,
+        rng: &mut R,
+    ) -> Result<(), Error<PC::Error>> {
+        self.verify_batch(&statement.into(), &proof.into(), rng)
+    }
+
+    pub fn verify_batch<R: RngCore>(
+        &self,
+        statement: &BatchStatement<F, PC>,
+        proof: &BatchProof<F, PC>,
+        rng: &mut R,
+    ) -> Result<(), Error<PC::Error>> {
+        // A domain of a single point leaves no room for `g`, whose degree bound would underflow
+        if statement.domain.size() < 2
+            || statement.sums.len() != statement.fs.len()
+            || proof.f_openings.len() != statement.fs.len()
+        {
+            return Err(Error::MalformedProof);
+        }
+        if self.shifted && ![&proof.s, &proof.h, &proof.g].iter().all(|c| c.has_degree_bound()) {
+            return Err(Error::MalformedProof);
+        }
+        let vk = &self.vk;
+
+        let mut transcript = FS::new(PROTOCOL_NAME);
+        transcript.append(b"statement", statement);
+        let coefficients = (0..statement.fs.len()).map(|_| transcript.challenge_scalar(b"batch")).collect::<Vec<F>>();
+
+        // The mask and its sum are fixed before the challenge that combines the mask with f
+        transcript.append(b"s", &proof.s);
+        transcript.append(b"s_sum", &proof.s_sum);
+        let mask_challenge: F = transcript.challenge_scalar(b"mask");
+
+        transcript.append(b"h", &proof.h);
+        transcript.append(b"g", &proof.g);
+        let mut commitments = statement
+            .fs
+            .iter()
+            .enumerate()
+            .map(|(i, f)| LabeledCommitment::new(f_label(i), f.clone(), None))
+            .collect::<Vec<_>>();
+        // Bounds on the mask and the quotient keep a prover from hiding a forgery in high degree terms
+        commitments.push(LabeledCommitment::new("s".into(), proof.s.clone(), Some(statement.s_degree_bound())));
+        commitments.push(LabeledCommitment::new("h".into(), proof.h.clone(), Some(statement.h_degree_bound())));
+        commitments.push(LabeledCommitment::new(
+            "g".into(),
+            proof.g.clone(),
+            Some(statement.g_degree_bound()),
+        ));
+
+        let xi: F = transcript.challenge_scalar(b"xi");
+        transcript.append(b"f_openings", &proof.f_openings);
+        transcript.append(b"openings", &[proof.s_opening, proof.h_opening, proof.g_opening].to_vec());
+        let opening_challenge = transcript.challenge_scalar(b"opening");
+
+        let point_label = String::from("xi");
+        let query_set: QuerySet<F> = commitments
+            .iter()
+            .map(|c| (c.label().clone(), (point_label.clone(), xi)))
+            .collect();
+
+        let openings = proof.f_openings.iter().chain([&proof.s_opening, &proof.h_opening, &proof.g_opening]);
+        let evaluations: Evaluations<F, F> = commitments
+            .iter()
+            .zip(openings)
+            .map(|(c, opening)| ((c.label().clone(), xi), *opening))
+            .collect();
+
+        let res = PC::batch_check(
+            vk,
+            &commitments,
+            &query_set,
+            &evaluations,
+            &proof.pc_proof,
+            opening_challenge,
+            rng,
+        )
+        .map_err(Error::from_pc_err)?;
+
+        if !res {
+            return Err(Error::OpeningError);
+        }
+
+        // sum(a_i * f_i) + c * s sums to sum(a_i * sum_i) + c * s_sum. A mask that cancels the batch only
+        // passes if the prover lies about s_sum, and then only for a single c
+        let combine = |values: &[F]| values.iter().zip(coefficients.iter()).map(|(value, a)| *value * a).sum::<F>();
+        let card_inverse = statement.domain.size_as_field_element().inverse().unwrap();
+        let lhs = combine(&proof.f_openings) + mask_challenge * proof.s_opening;
+        let rhs = {
+            let x_gx = xi * proof.g_opening;
+            let zh_eval = statement.domain.evaluate_vanishing_polynomial(xi);
+
+            x_gx + proof.h_opening * zh_eval + (combine(&statement.sums) + mask_challenge * proof.s_sum) * card_inverse
+        };
+
+        if lhs != rhs {
+            return Err(Error::IncorrectSum);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use ark_bls12_381::{Bls12_381, Fr as F, G1Affine};
+use ark_ff::{One, Zero};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::{ipa_pc::InnerProductArgPC, marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10, PolynomialCommitment};
+use blake2::Blake2s;
+use common::{Univariate, MAX_DEGREE};
+use sumcheck::Error;
+
+type Marlin = MarlinKZG10<Bls12_381, DensePolynomial<F>>;
+type Sonic = SonicKZG10<Bls12_381, DensePolynomial<F>>;
+type Ipa = InnerProductArgPC<G1Affine, Blake2s, DensePolynomial<F>>;
+
+/// Proves the sum of a random polynomial over `PC`, claiming the true sum plus `offset`.
+fn prove_and_verify<PC: PolynomialCommitment<F, DensePolynomial<F>>>(offset: F) -> Result<(), Error<PC::Error>> {
+    let mut fixture = Univariate::<PC>::new(MAX_DEGREE, &[MAX_DEGREE]);
+    let f = fixture.commit("f", 12);
+    let statement = f.statement(MAX_DEGREE, offset);
+
+    let proof = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng).unwrap();
+    fixture.verifier.verify(&statement, &proof, &mut fixture.rng)
+}
+
+#[test]
+fn marlin() {
+    assert!(prove_and_verify::<Marlin>(F::zero()).is_ok());
+    assert!(matches!(prove_and_verify::<Marlin>(F::one()), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn sonic() {
+    assert!(prove_and_verify::<Sonic>(F::zero()).is_ok());
+    assert!(matches!(prove_and_verify::<Sonic>(F::one()), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn ipa() {
+    assert!(prove_and_verify::<Ipa>(F::zero()).is_ok());
+    assert!(matches!(prove_and_verify::<Ipa>(F::one()), Err(Error::IncorrectSum)));
+}
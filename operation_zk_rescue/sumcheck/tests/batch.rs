@@ -0,0 +1,102 @@
+mod common;
+
+use ark_bls12_381::Fr as F;
+use ark_ff::{One, Zero};
+use ark_poly::{univariate::DensePolynomial, EvaluationDomain, GeneralEvaluationDomain};
+use ark_poly_commit::{LabeledPolynomial, PolynomialCommitment};
+use common::*;
+use sumcheck::{domain_sum, BatchProof, BatchStatement, Error};
+
+const BATCH: usize = 3;
+
+/// The polynomials of a batch and the randomness of their commitments
+type Polynomials = (
+    Vec<LabeledPolynomial<F, DensePolynomial<F>>>,
+    Vec<<PC as PolynomialCommitment<F, DensePolynomial<F>>>::Randomness>,
+);
+
+/// A batch of polynomials of different degrees, some above the domain size, and the statement of their true sums.
+fn batch(fixture: &mut Univariate<PC>) -> (Polynomials, BatchStatement<F, PC>) {
+    let committed = (0..BATCH).map(|i| fixture.commit(&format!("p{}", i), 4 + 4 * i)).collect::<Vec<_>>();
+    let statement = BatchStatement {
+        domain: domain(),
+        max_degree: MAX_DEGREE,
+        fs: committed.iter().map(|c| c.commitment.clone()).collect(),
+        sums: committed.iter().map(|c| domain_sum(c.f.polynomial(), domain())).collect(),
+    };
+    let fs = committed.iter().map(|c| c.f.clone()).collect();
+    ((fs, committed.into_iter().map(|c| c.rand).collect()), statement)
+}
+
+fn prove(fixture: &mut Univariate<PC>, (fs, f_rands): &Polynomials, statement: &BatchStatement<F, PC>) -> BatchProof<F, PC> {
+    fixture.prover.prove_batch(statement, fs, f_rands, &mut fixture.rng).unwrap()
+}
+
+#[test]
+fn honest_batch_verifies() {
+    let mut fixture = fixture();
+    let (polynomials, statement) = batch(&mut fixture);
+    let proof = prove(&mut fixture, &polynomials, &statement);
+    assert!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng).is_ok());
+}
+
+#[test]
+fn one_wrong_sum_is_rejected() {
+    let mut fixture = fixture();
+    let (polynomials, mut statement) = batch(&mut fixture);
+    statement.sums[1] += F::one();
+    let proof = prove(&mut fixture, &polynomials, &statement);
+    assert!(matches!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn errors_cancelling_out_are_rejected() {
+    let mut fixture = fixture();
+    let (polynomials, mut statement) = batch(&mut fixture);
+    statement.sums[0] += F::one();
+    statement.sums[2] -= F::one();
+    let proof = prove(&mut fixture, &polynomials, &statement);
+    assert!(matches!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn negated_mask_forgery_is_rejected() {
+    let mut fixture = fixture();
+    let ((fs, f_rands), mut statement) = batch(&mut fixture);
+    statement.sums = vec![F::zero(); BATCH];
+    let s = fs.iter().fold(DensePolynomial::zero(), |acc, f| &acc - f.polynomial());
+    let s = LabeledPolynomial::new("s".into(), s, Some(MAX_DEGREE), Some(1));
+
+    let proof = fixture
+        .prover
+        .prove_batch_with_mask(&statement, &fs, &f_rands, &s, F::zero(), &mut fixture.rng)
+        .unwrap();
+    assert!(matches!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn missing_sum_is_rejected() {
+    let mut fixture = fixture();
+    let (polynomials, mut statement) = batch(&mut fixture);
+    let proof = prove(&mut fixture, &polynomials, &statement);
+    statement.sums.pop();
+    assert!(matches!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn single_point_domain_is_rejected() {
+    let mut fixture = fixture();
+    let (polynomials, mut statement) = batch(&mut fixture);
+    let proof = prove(&mut fixture, &polynomials, &statement);
+    statement.domain = GeneralEvaluationDomain::new(1).unwrap();
+    assert!(matches!(fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn prover_rejects_a_missing_polynomial() {
+    let mut fixture = fixture();
+    let ((mut fs, f_rands), statement) = batch(&mut fixture);
+    fs.pop();
+    let result = fixture.prover.prove_batch(&statement, &fs, &f_rands, &mut fixture.rng);
+    assert!(matches!(result, Err(Error::MalformedProof)));
+}
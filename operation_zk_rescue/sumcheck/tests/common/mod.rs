@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+
+use ark_bls12_381::{Bls12_381, Fr as F};
+use ark_poly::{
+    univariate::DensePolynomial, DenseMultilinearExtension, EvaluationDomain, GeneralEvaluationDomain, MultilinearExtension,
+    UVPolynomial,
+};
+use ark_poly_commit::{
+    marlin_pc::MarlinKZG10,
+    multilinear_pc::{data_structures::CommitterKey, MultilinearPC},
+    LabeledPolynomial, PolynomialCommitment,
+};
+use ark_std::{
+    rand::{rngs::StdRng, RngCore},
+    test_rng,
+};
+use blake2::Blake2s;
+use sumcheck::{
+    degree_bounds, domain_sum, rng::Transcript, MultilinearProver, MultilinearStatement, MultilinearVerifier, Statement,
+    SumcheckProver, SumcheckVerifier,
+};
+
+pub type PC = MarlinKZG10<Bls12_381, DensePolynomial<F>>;
+pub type FS = Transcript<Blake2s>;
+
+pub const DOMAIN_SIZE: usize = 8;
+pub const MAX_DEGREE: usize = 16;
+
+pub fn domain() -> GeneralEvaluationDomain<F> {
+    GeneralEvaluationDomain::new(DOMAIN_SIZE).unwrap()
+}
+
+/// A prover and a verifier sharing keys, the committer key to build statements with, and the rng the
+/// tests draw from.
+pub struct Fixture<CK, P, V> {
+    pub ck: CK,
+    pub prover: P,
+    pub verifier: V,
+    pub rng: StdRng,
+}
+
+pub type Univariate<PC> = Fixture<
+    <PC as PolynomialCommitment<F, DensePolynomial<F>>>::CommitterKey,
+    SumcheckProver<F, PC, FS>,
+    SumcheckVerifier<F, PC, FS>,
+>;
+
+pub type Multilinear = Fixture<CommitterKey<Bls12_381>, MultilinearProver<Bls12_381, FS>, MultilinearVerifier<Bls12_381, FS>>;
+
+impl<PC: PolynomialCommitment<F, DensePolynomial<F>>> Fixture<PC::CommitterKey, SumcheckProver<F, PC, FS>, SumcheckVerifier<F, PC, FS>> {
+    /// Keys for polynomials up to `key_degree`, trimmed with the bounds of statements claiming any of `max_degrees`.
+    pub fn new(key_degree: usize, max_degrees: &[usize]) -> Self {
+        let mut rng = test_rng();
+        let srs = PC::setup(key_degree, None, &mut rng).unwrap();
+        let mut bounds = max_degrees.iter().flat_map(|max_degree| degree_bounds(DOMAIN_SIZE, *max_degree)).collect::<Vec<_>>();
+        bounds.sort_unstable();
+        bounds.dedup();
+        let (ck, vk) = PC::trim(&srs, key_degree, 1, Some(&bounds)).unwrap();
+
+        Fixture { prover: SumcheckProver::new(ck.clone()), verifier: SumcheckVerifier::new(vk), ck, rng }
+    }
+
+    /// Commits to a random polynomial of the given degree.
+    pub fn commit(&mut self, label: &str, degree: usize) -> Committed<PC> {
+        let f = DensePolynomial::rand(degree, &mut self.rng);
+        Committed::new(&self.ck, label, f, &mut self.rng)
+    }
+
+    /// A random mask of the given degree, committed under `bound`, and its sum over `domain()`.
+    pub fn mask(&mut self, degree: usize, bound: usize) -> (LabeledPolynomial<F, DensePolynomial<F>>, F) {
+        let s = DensePolynomial::rand(degree, &mut self.rng);
+        let s_sum = domain_sum(&s, domain());
+        (LabeledPolynomial::new("s".into(), s, Some(bound), Some(1)), s_sum)
+    }
+}
+
+impl Multilinear {
+    /// Keys for exactly `num_vars` variables.
+    pub fn new(num_vars: usize) -> Self {
+        let mut rng = test_rng();
+        let params = MultilinearPC::<Bls12_381>::setup(num_vars, &mut rng);
+        let (ck, vk) = MultilinearPC::trim(&params, num_vars);
+
+        Fixture { prover: MultilinearProver::new(ck.clone()), verifier: MultilinearVerifier::new(vk), ck, rng }
+    }
+
+    /// A random polynomial over the key's variables, with a statement claiming its true sum.
+    pub fn statement(&mut self) -> (DenseMultilinearExtension<F>, MultilinearStatement<Bls12_381>) {
+        let f = DenseMultilinearExtension::rand(self.ck.nv, &mut self.rng);
+        let statement = MultilinearStatement {
+            num_vars: self.ck.nv,
+            f: MultilinearPC::commit(&self.ck, &f),
+            sum: f.evaluations.iter().sum(),
+        };
+        (f, statement)
+    }
+}
+
+/// Marlin keys for statements claiming `MAX_DEGREE`.
+pub fn fixture() -> Univariate<PC> {
+    Univariate::new(MAX_DEGREE, &[MAX_DEGREE])
+}
+
+/// A polynomial to be summed, with its commitment.
+pub struct Committed<PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    pub f: LabeledPolynomial<F, DensePolynomial<F>>,
+    pub commitment: PC::Commitment,
+    pub rand: PC::Randomness,
+}
+
+impl<PC: PolynomialCommitment<F, DensePolynomial<F>>> Committed<PC> {
+    pub fn new(ck: &PC::CommitterKey, label: &str, f: DensePolynomial<F>, rng: &mut impl RngCore) -> Self {
+        let f = LabeledPolynomial::new(label.into(), f, None, Some(1));
+        let (commitments, rands) = PC::commit(ck, &[f.clone()], Some(rng)).unwrap();
+        Committed { f, commitment: commitments[0].commitment().clone(), rand: rands[0].clone() }
+    }
+
+    /// Statement over `domain()` that the polynomial sums to its true sum plus `offset`.
+    pub fn statement(&self, max_degree: usize, offset: F) -> Statement<F, PC> {
+        let domain = domain();
+        Statement { domain, max_degree, f: self.commitment.clone(), sum: domain_sum(self.f.polynomial(), domain) + offset }
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use ark_bls12_381::Fr as F;
+use ark_ff::Zero;
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::LabeledPolynomial;
+use ark_std::slice;
+use common::{Committed, Univariate, DOMAIN_SIZE, PC};
+use sumcheck::{BatchStatement, Error, Statement};
+
+/// Degree the statements claim
+const MAX_DEGREE: usize = 12;
+/// Degree the keys support, so oversized polynomials can be committed at all
+const KEY_DEGREE: usize = 16;
+/// Bound of `h` for statements claiming `KEY_DEGREE`, which the keys support as well
+const LOOSE_H_BOUND: usize = KEY_DEGREE - DOMAIN_SIZE;
+
+/// Keys with the bounds for both degrees, so a prover can pick the looser ones.
+fn loose_keys() -> Univariate<PC> {
+    Univariate::new(KEY_DEGREE, &[MAX_DEGREE, KEY_DEGREE])
+}
+
+/// Proves with `h` committed under `h_bound`, then verifies against the bounds of the statement.
+fn verify_with_h_bound(
+    fixture: &mut Univariate<PC>,
+    statement: &Statement<F, PC>,
+    f: &Committed<PC>,
+    s: &LabeledPolynomial<F, DensePolynomial<F>>,
+    s_sum: F,
+    h_bound: usize,
+) -> Result<(), Error<ark_poly_commit::Error>> {
+    let statement = BatchStatement::from(statement);
+    let (fs, f_rands) = (slice::from_ref(&f.f), slice::from_ref(&f.rand));
+    let proof =
+        fixture.prover.prove_batch_with_bounds(&statement, fs, f_rands, s, s_sum, h_bound, &mut fixture.rng).unwrap();
+    fixture.verifier.verify_batch(&statement, &proof, &mut fixture.rng)
+}
+
+#[test]
+fn polynomials_within_bounds_verify() {
+    let mut fixture = loose_keys();
+    let f = fixture.commit("f", MAX_DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let proof = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng).unwrap();
+    assert!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng).is_ok());
+}
+
+#[test]
+fn oversized_mask_cannot_be_committed() {
+    let mut fixture = loose_keys();
+    let f = fixture.commit("f", MAX_DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE + 2, MAX_DEGREE);
+
+    let result = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, s_sum, &mut fixture.rng);
+    assert!(matches!(result, Err(Error::PolynomialCommitmentError(_))));
+}
+
+#[test]
+fn mask_with_a_looser_bound_is_rejected() {
+    let mut fixture = loose_keys();
+    let f = fixture.commit("f", MAX_DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    // The mask itself is small enough, only its commitment claims the looser bound
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, KEY_DEGREE);
+
+    let proof = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, s_sum, &mut fixture.rng).unwrap();
+    let result = fixture.verifier.verify(&statement, &proof, &mut fixture.rng);
+    assert!(matches!(result, Err(Error::OpeningError)));
+}
+
+#[test]
+fn statement_h_bound_verifies() {
+    let mut fixture = loose_keys();
+    let f = fixture.commit("f", MAX_DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, MAX_DEGREE);
+
+    // The bound the prover would pick, so the rejections below come from the looser bounds alone
+    assert!(verify_with_h_bound(&mut fixture, &statement, &f, &s, s_sum, MAX_DEGREE - DOMAIN_SIZE).is_ok());
+}
+
+#[test]
+fn oversized_mask_under_a_looser_bound_is_rejected() {
+    let mut fixture = loose_keys();
+    let f = fixture.commit("f", MAX_DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    // Too large for the statement, but within the bounds of one claiming KEY_DEGREE. h grows with it
+    let (s, s_sum) = fixture.mask(MAX_DEGREE + 2, KEY_DEGREE);
+
+    let result = verify_with_h_bound(&mut fixture, &statement, &f, &s, s_sum, LOOSE_H_BOUND);
+    assert!(matches!(result, Err(Error::OpeningError)));
+}
+
+#[test]
+fn oversized_quotient_under_a_looser_bound_is_rejected() {
+    let mut fixture = loose_keys();
+    // f is above the claimed degree, so h is above its bound while the mask is honest
+    let f = fixture.commit("f", MAX_DEGREE + 2);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, MAX_DEGREE);
+
+    let result = verify_with_h_bound(&mut fixture, &statement, &f, &s, s_sum, LOOSE_H_BOUND);
+    assert!(matches!(result, Err(Error::OpeningError)));
+}
+
+#[test]
+fn polynomial_above_the_claimed_degree_is_rejected() {
+    let mut fixture = loose_keys();
+    // h would be larger than the statement allows
+    let f = fixture.commit("f", MAX_DEGREE + 2);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+
+    let result = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng);
+    assert!(matches!(result, Err(Error::PolynomialCommitmentError(_))));
+}
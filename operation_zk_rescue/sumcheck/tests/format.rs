@@ -0,0 +1,112 @@
+mod common;
+
+use ark_bls12_381::Fr as F;
+use ark_ff::Zero;
+use ark_poly::EvaluationDomain;
+use ark_serialize::CanonicalSerialize;
+use ark_std::test_rng;
+use common::*;
+use sumcheck::{
+    format::{self, Kind, MAGIC, VERSION},
+    Error, FormatError, Proof, Statement, SumcheckVerifier,
+};
+
+/// Statement and proof files for a random polynomial, and a verifier for them.
+fn files() -> (Vec<u8>, Vec<u8>, SumcheckVerifier<F, PC, FS>) {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", 12);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let proof = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng).unwrap();
+
+    let (mut statement_file, mut proof_file) = (vec![], vec![]);
+    format::write(Kind::Statement, &statement, &mut statement_file).unwrap();
+    format::write(Kind::Proof, &proof, &mut proof_file).unwrap();
+    (statement_file, proof_file, fixture.verifier)
+}
+
+#[test]
+fn written_proof_verifies_after_reading() {
+    let (statement_file, proof_file, verifier) = files();
+    let statement: Statement<F, PC> = format::read(Kind::Statement, &statement_file[..]).unwrap();
+    let proof: Proof<F, PC> = format::read(Kind::Proof, &proof_file[..]).unwrap();
+
+    assert_eq!(statement.domain.size(), DOMAIN_SIZE);
+    assert!(verifier.verify(&statement, &proof, &mut test_rng()).is_ok());
+
+    // Reading and writing again gives back the same bytes
+    let mut rewritten = vec![];
+    format::write(Kind::Proof, &proof, &mut rewritten).unwrap();
+    assert_eq!(rewritten, proof_file);
+    assert_eq!(proof_file.len(), 6 + proof.serialized_size());
+}
+
+#[test]
+fn header_is_checked() {
+    let (statement_file, proof_file, _) = files();
+    assert_eq!(statement_file[..4], MAGIC);
+    assert_eq!(statement_file[4], VERSION);
+
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &proof_file[..]);
+    assert!(matches!(result, Err(FormatError::WrongKind)));
+
+    let mut corrupted = statement_file.clone();
+    corrupted[0] ^= 1;
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &corrupted[..]);
+    assert!(matches!(result, Err(FormatError::BadMagic)));
+
+    let mut future = statement_file;
+    future[4] = VERSION + 1;
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &future[..]);
+    assert!(matches!(result, Err(FormatError::UnsupportedVersion(v)) if v == VERSION + 1));
+}
+
+#[test]
+fn truncated_files_are_rejected() {
+    let (statement_file, proof_file, _) = files();
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &statement_file[..statement_file.len() - 1]);
+    assert!(matches!(result, Err(FormatError::Serialization(_))));
+    let result = format::read::<Proof<F, PC>, _>(Kind::Proof, &proof_file[..3]);
+    assert!(matches!(result, Err(FormatError::Serialization(_))));
+}
+
+#[test]
+fn odd_domain_sizes_are_rejected() {
+    let (mut statement_file, _, _) = files();
+    // The domain size follows the header, little-endian
+    statement_file[6] = DOMAIN_SIZE as u8 + 1;
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &statement_file[..]);
+    assert!(matches!(result, Err(FormatError::Serialization(_))));
+
+    // A supported size, but too small to prove anything over
+    statement_file[6] = 1;
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &statement_file[..]);
+    assert!(matches!(result, Err(FormatError::Serialization(_))));
+}
+
+#[test]
+fn trailing_bytes_are_rejected() {
+    let (mut statement_file, mut proof_file, _) = files();
+    statement_file.push(0);
+    let result = format::read::<Statement<F, PC>, _>(Kind::Statement, &statement_file[..]);
+    assert!(matches!(result, Err(FormatError::TrailingBytes)));
+
+    proof_file.extend_from_slice(b"more");
+    let result = format::read::<Proof<F, PC>, _>(Kind::Proof, &proof_file[..]);
+    assert!(matches!(result, Err(FormatError::TrailingBytes)));
+}
+
+#[test]
+fn bounded_commitment_without_its_shifted_part_is_rejected() {
+    let (statement_file, proof_file, verifier) = files();
+    let statement: Statement<F, PC> = format::read(Kind::Statement, &statement_file[..]).unwrap();
+    let mut proof: Proof<F, PC> = format::read(Kind::Proof, &proof_file[..]).unwrap();
+
+    // A file from someone else, with `h` written as if it had no degree bound
+    proof.h.shifted_comm = None;
+    let mut stripped = vec![];
+    format::write(Kind::Proof, &proof, &mut stripped).unwrap();
+    let proof: Proof<F, PC> = format::read(Kind::Proof, &stripped[..]).unwrap();
+
+    let verifier = verifier.with_shifted_commitments();
+    assert!(matches!(verifier.verify(&statement, &proof, &mut test_rng()), Err(Error::MalformedProof)));
+}
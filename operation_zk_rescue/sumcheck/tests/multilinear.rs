@@ -0,0 +1,98 @@
+mod common;
+
+use ark_bls12_381::{Bls12_381, Fr as F};
+use ark_ff::One;
+use ark_poly::{DenseMultilinearExtension, MultilinearExtension};
+use ark_poly_commit::multilinear_pc::MultilinearPC;
+use ark_std::test_rng;
+use common::{Multilinear, FS};
+use sumcheck::{Error, MultilinearProver, MultilinearVerifier};
+
+const NUM_VARS: usize = 4;
+
+#[test]
+fn honest_proof_verifies() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+    let proof = fixture.prover.prove(&statement, &f).unwrap();
+    assert!(fixture.verifier.verify(&statement, &proof).is_ok());
+}
+
+#[test]
+fn wrong_sum_is_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, mut statement) = fixture.statement();
+    statement.sum += F::one();
+    let proof = fixture.prover.prove(&statement, &f).unwrap();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn consistent_but_forged_rounds_are_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, mut statement) = fixture.statement();
+    statement.sum += F::one();
+    let mut proof = fixture.prover.prove(&statement, &f).unwrap();
+
+    // The first round now sums to the false claim, but the next one no longer follows from it
+    proof.rounds[0][0] += F::one();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn wrong_opening_is_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+    let mut proof = fixture.prover.prove(&statement, &f).unwrap();
+    proof.f_opening += F::one();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof), Err(Error::OpeningError)));
+}
+
+#[test]
+fn missing_rounds_are_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+    let mut proof = fixture.prover.prove(&statement, &f).unwrap();
+    proof.rounds.pop();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn short_opening_is_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+    let mut proof = fixture.prover.prove(&statement, &f).unwrap();
+    proof.pc_proof.proofs.pop();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn key_for_another_number_of_variables_is_rejected() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+    let proof = fixture.prover.prove(&statement, &f).unwrap();
+
+    let params = MultilinearPC::<Bls12_381>::setup(NUM_VARS, &mut test_rng());
+    let (_, vk) = MultilinearPC::trim(&params, NUM_VARS - 1);
+    let verifier = MultilinearVerifier::<Bls12_381, FS>::new(vk);
+    assert!(matches!(verifier.verify(&statement, &proof), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn prover_rejects_a_polynomial_over_other_variables() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (_, statement) = fixture.statement();
+    let f = DenseMultilinearExtension::rand(NUM_VARS - 1, &mut fixture.rng);
+    assert!(matches!(fixture.prover.prove(&statement, &f), Err(Error::MalformedProof)));
+}
+
+#[test]
+fn prover_rejects_a_key_for_another_number_of_variables() {
+    let mut fixture = Multilinear::new(NUM_VARS);
+    let (f, statement) = fixture.statement();
+
+    let params = MultilinearPC::<Bls12_381>::setup(NUM_VARS, &mut test_rng());
+    let (ck, _) = MultilinearPC::trim(&params, NUM_VARS - 1);
+    let prover = MultilinearProver::<Bls12_381, FS>::new(ck);
+    assert!(matches!(prover.prove(&statement, &f), Err(Error::MalformedProof)));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use ark_bls12_381::Fr as F;
+use ark_ff::{One, Zero};
+use ark_poly_commit::LabeledPolynomial;
+use common::*;
+use sumcheck::{domain_sum, Error};
+
+/// Degree of the summed polynomials, higher than the domain so h is not trivially zero
+const DEGREE: usize = 12;
+
+#[test]
+fn domain_sum_matches_evaluations() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let evals = f.f.polynomial().clone().evaluate_over_domain(domain()).evals;
+    assert_eq!(domain_sum(f.f.polynomial(), domain()), evals.into_iter().sum::<F>());
+}
+
+#[test]
+fn honest_proof_verifies() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, MAX_DEGREE);
+
+    let proof = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, s_sum, &mut fixture.rng).unwrap();
+    assert!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng).is_ok());
+}
+
+#[test]
+fn prove_picks_an_honest_mask() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+
+    let proof = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng).unwrap();
+    assert!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng).is_ok());
+}
+
+#[test]
+fn wrong_sum_is_rejected() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::one());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, MAX_DEGREE);
+
+    let proof = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, s_sum, &mut fixture.rng).unwrap();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn negated_mask_forgery_is_rejected() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let true_sum = domain_sum(f.f.polynomial(), domain());
+    let statement = f.statement(MAX_DEGREE, -true_sum);
+    let s = LabeledPolynomial::new("s".into(), -f.f.polynomial().clone(), Some(MAX_DEGREE), Some(1));
+
+    // Hiding the mask sum, so f + s looks like it sums to zero
+    let proof = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, F::zero(), &mut fixture.rng).unwrap();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+
+    // Admitting the mask sum
+    let proof = fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, -true_sum, &mut fixture.rng).unwrap();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn lying_about_the_mask_sum_is_rejected() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+    let (s, s_sum) = fixture.mask(MAX_DEGREE, MAX_DEGREE);
+
+    let proof =
+        fixture.prover.prove_with_mask(&statement, &f.f, &f.rand, &s, s_sum + F::one(), &mut fixture.rng).unwrap();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng), Err(Error::IncorrectSum)));
+}
+
+#[test]
+fn tampered_opening_is_rejected() {
+    let mut fixture = fixture();
+    let f = fixture.commit("f", DEGREE);
+    let statement = f.statement(MAX_DEGREE, F::zero());
+
+    let mut proof = fixture.prover.prove(&statement, &f.f, &f.rand, &mut fixture.rng).unwrap();
+    proof.g_opening += F::one();
+    assert!(matches!(fixture.verifier.verify(&statement, &proof, &mut fixture.rng), Err(Error::OpeningError)));
+}
@@ -0,0 +1,57 @@
+use ark_bls12_381::Fr as F;
+use blake2::Blake2s;
+use sumcheck::rng::{FiatShamirRng, Transcript};
+
+type FS = Transcript<Blake2s>;
+
+/// Challenge after appending the given labelled messages in order.
+fn challenge(messages: &[(&'static str, &str)]) -> F {
+    let mut transcript = FS::new(b"test");
+    for &(label, message) in messages {
+        transcript.append_message(label.as_bytes(), message.as_bytes());
+    }
+    transcript.challenge_scalar(b"challenge")
+}
+
+#[test]
+fn same_messages_give_same_challenge() {
+    let messages = [("a", "one"), ("b", "two")];
+    assert_eq!(challenge(&messages), challenge(&messages));
+}
+
+#[test]
+fn reordering_changes_challenge() {
+    assert_ne!(challenge(&[("a", "one"), ("b", "two")]), challenge(&[("b", "two"), ("a", "one")]));
+}
+
+#[test]
+fn omitting_changes_challenge() {
+    assert_ne!(challenge(&[("a", "one"), ("b", "two")]), challenge(&[("a", "one")]));
+    assert_ne!(challenge(&[("a", "one")]), challenge(&[]));
+}
+
+#[test]
+fn labels_separate_domains() {
+    assert_ne!(challenge(&[("a", "one")]), challenge(&[("b", "one")]));
+    // Moving bytes between the label and the message isn't the same transcript
+    assert_ne!(challenge(&[("ab", "c")]), challenge(&[("a", "bc")]));
+    // Neither is splitting one message in two
+    assert_ne!(challenge(&[("a", "onetwo")]), challenge(&[("a", "one"), ("a", "two")]));
+}
+
+#[test]
+fn protocols_are_separated() {
+    let mut one = FS::new(b"one");
+    let mut two = FS::new(b"two");
+    assert_ne!(one.challenge_scalar::<F>(b"challenge"), two.challenge_scalar::<F>(b"challenge"));
+}
+
+#[test]
+fn successive_challenges_differ() {
+    let mut transcript = FS::new(b"test");
+    let first: F = transcript.challenge_scalar(b"challenge");
+    let second: F = transcript.challenge_scalar(b"challenge");
+    let other: F = transcript.challenge_scalar(b"other");
+    assert_ne!(first, second);
+    assert_ne!(second, other);
+}